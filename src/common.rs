@@ -790,9 +790,15 @@ pub enum FileTypeFixedStruct {
     Acct,
     AcctV3,
     Lastlog,
+    /// `lastlog2` SQLite database
+    Lastlog2,
     Lastlogx,
     Utmp,
     Utmpx,
+    /// FreeBSD `utx.log` of length-prefixed `futx` records
+    UtxLog,
+    /// `wtmpdb` SQLite database
+    Wtmpdb,
 }
 
 /// Text encoding type.
@@ -1037,6 +1043,10 @@ pub enum FileType {
     ///
     /// [Event Trace Log]: https://learn.microsoft.com/en-us/windows-hardware/test/wpt/opening-and-analyzing-etl-files-in-wpa
     Etl { archival_type: FileTypeArchive },
+    /// a legacy [Windows Event Log] file (Windows NT through Windows 2003)
+    ///
+    /// [Windows Event Log]: https://github.com/libyal/libevt/blob/main/documentation/Windows%20Event%20Log%20(EVT)%20format.asciidoc
+    Evt { archival_type: FileTypeArchive },
     /// a [Windows XML EventLog] file
     ///
     /// [Windows XML EventLog]: https://github.com/libyal/libevtx/blob/main/documentation/Windows%20XML%20Event%20Log%20(EVTX).asciidoc
@@ -1050,14 +1060,27 @@ pub enum FileType {
     ///
     /// [systemd Journal file]: https://systemd.io/JOURNAL_FILE_FORMAT/
     Journal { archival_type: FileTypeArchive },
+    /// an [Android logcat] binary log buffer, e.g. from `logcat -B`
+    ///
+    /// [Android logcat]: https://android.googlesource.com/platform/system/logging/+/refs/heads/main/liblog/include/log/log_read.h
+    Logcat { archival_type: FileTypeArchive },
     /// OneDrive log file
     Odl { archival_type: FileTypeArchive, odl_sub_type: OdlSubType },
+    /// a [pcap] or [pcapng] network packet capture file of syslog traffic
+    ///
+    /// [pcap]: https://www.tcpdump.org/manpages/pcap-savefile.5.html
+    /// [pcapng]: https://datatracker.ietf.org/doc/draft-ietf-opsawg-pcapng/
+    Pcap { archival_type: FileTypeArchive },
     /// a plain vanilla file, e.g. `file.log`. Presumed to be a "syslog" file
     /// as the term is loosely used in this project.
     Text {
         archival_type: FileTypeArchive,
         encoding_type: FileTypeTextEncoding,
     },
+    /// an [Apple Unified Logging] `.tracev3` file
+    ///
+    /// [Apple Unified Logging]: https://github.com/libyal/dtformats/blob/main/documentation/Apple%20Unified%20Logging%20and%20Activity%20Tracing%20formats.asciidoc
+    Tracev3 { archival_type: FileTypeArchive },
     /// a file type known to be unparsable
     Unparsable,
 }
@@ -1070,16 +1093,23 @@ impl std::fmt::Display for FileType {
         match self {
             FileType::Asl{ .. } => write!(f, "ASL"),
             FileType::Etl{ .. } => write!(f, "ETL"),
+            FileType::Evt{ .. } => write!(f, "EVT"),
             FileType::Evtx{ .. } => write!(f, "EVTX"),
             FileType::FixedStruct{ fixedstruct_type:FileTypeFixedStruct::Acct, .. } => write!(f, "ACCT"),
             FileType::FixedStruct{ fixedstruct_type:FileTypeFixedStruct::AcctV3, .. } => write!(f, "ACCT_V3"),
             FileType::FixedStruct{ fixedstruct_type:FileTypeFixedStruct::Lastlog, .. } => write!(f, "LASTLOG"),
+            FileType::FixedStruct{ fixedstruct_type:FileTypeFixedStruct::Lastlog2, .. } => write!(f, "LASTLOG2"),
             FileType::FixedStruct{ fixedstruct_type:FileTypeFixedStruct::Lastlogx, .. } => write!(f, "LASTLOGX"),
             FileType::FixedStruct{ fixedstruct_type:FileTypeFixedStruct::Utmp, .. } => write!(f, "UTMP/WTMP"),
             FileType::FixedStruct{ fixedstruct_type:FileTypeFixedStruct::Utmpx, .. } => write!(f, "UTMPX/WTMPX"),
+            FileType::FixedStruct{ fixedstruct_type:FileTypeFixedStruct::UtxLog, .. } => write!(f, "UTX.LOG"),
+            FileType::FixedStruct{ fixedstruct_type:FileTypeFixedStruct::Wtmpdb, .. } => write!(f, "WTMPDB"),
             FileType::Journal{ .. } => write!(f, "JOURNAL"),
             FileType::Odl{ .. } => write!(f, "ODL"),
+            FileType::Pcap{ .. } => write!(f, "PCAP"),
             FileType::Text{ .. } => write!(f, "TEXT"),
+            FileType::Logcat{ .. } => write!(f, "LOGCAT"),
+            FileType::Tracev3{ .. } => write!(f, "TRACEV3"),
             FileType::Unparsable => write!(f, "UNPARSABLE"),
         }
     }
@@ -1101,6 +1131,12 @@ impl FileType {
             FileType::Etl{ archival_type: FileTypeArchive::Lz4 } => true,
             FileType::Etl{ archival_type: FileTypeArchive::Tar } => false,
             FileType::Etl{ archival_type: FileTypeArchive::Xz } => true,
+            FileType::Evt{ archival_type: FileTypeArchive::Normal } => false,
+            FileType::Evt{ archival_type: FileTypeArchive::Bz2 } => true,
+            FileType::Evt{ archival_type: FileTypeArchive::Gz } => true,
+            FileType::Evt{ archival_type: FileTypeArchive::Lz4 } => true,
+            FileType::Evt{ archival_type: FileTypeArchive::Tar } => false,
+            FileType::Evt{ archival_type: FileTypeArchive::Xz } => true,
             FileType::Evtx{ archival_type: FileTypeArchive::Normal } => false,
             FileType::Evtx{ archival_type: FileTypeArchive::Bz2 } => true,
             FileType::Evtx{ archival_type: FileTypeArchive::Gz } => true,
@@ -1119,18 +1155,36 @@ impl FileType {
             FileType::Journal{ archival_type: FileTypeArchive::Lz4 } => true,
             FileType::Journal{ archival_type: FileTypeArchive::Tar } => false,
             FileType::Journal{ archival_type: FileTypeArchive::Xz } => true,
+            FileType::Logcat{ archival_type: FileTypeArchive::Normal } => false,
+            FileType::Logcat{ archival_type: FileTypeArchive::Bz2 } => true,
+            FileType::Logcat{ archival_type: FileTypeArchive::Gz } => true,
+            FileType::Logcat{ archival_type: FileTypeArchive::Lz4 } => true,
+            FileType::Logcat{ archival_type: FileTypeArchive::Tar } => false,
+            FileType::Logcat{ archival_type: FileTypeArchive::Xz } => true,
             FileType::Odl{ archival_type: FileTypeArchive::Normal, .. } => false,
             FileType::Odl{ archival_type: FileTypeArchive::Bz2, .. } => true,
             FileType::Odl{ archival_type: FileTypeArchive::Gz, .. } => true,
             FileType::Odl{ archival_type: FileTypeArchive::Lz4, .. } => true,
             FileType::Odl{ archival_type: FileTypeArchive::Tar, .. } => true,
             FileType::Odl{ archival_type: FileTypeArchive::Xz, .. } => true,
+            FileType::Pcap{ archival_type: FileTypeArchive::Normal } => false,
+            FileType::Pcap{ archival_type: FileTypeArchive::Bz2 } => true,
+            FileType::Pcap{ archival_type: FileTypeArchive::Gz } => true,
+            FileType::Pcap{ archival_type: FileTypeArchive::Lz4 } => true,
+            FileType::Pcap{ archival_type: FileTypeArchive::Tar } => false,
+            FileType::Pcap{ archival_type: FileTypeArchive::Xz } => true,
             FileType::Text{ archival_type: FileTypeArchive::Normal, .. } => false,
             FileType::Text{ archival_type: FileTypeArchive::Bz2, .. } => true,
             FileType::Text{ archival_type: FileTypeArchive::Gz, .. } => true,
             FileType::Text{ archival_type: FileTypeArchive::Lz4, .. } => true,
             FileType::Text{ archival_type: FileTypeArchive::Tar, .. } => false,
             FileType::Text{ archival_type: FileTypeArchive::Xz, .. } => true,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Normal } => false,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Bz2 } => true,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Gz } => true,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Lz4 } => true,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Tar } => false,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Xz } => true,
             FileType::Unparsable => false,
         }
     }
@@ -1150,6 +1204,12 @@ impl FileType {
             FileType::Etl{ archival_type: FileTypeArchive::Lz4 } => false,
             FileType::Etl{ archival_type: FileTypeArchive::Tar } => true,
             FileType::Etl{ archival_type: FileTypeArchive::Xz } => false,
+            FileType::Evt{ archival_type: FileTypeArchive::Normal } => false,
+            FileType::Evt{ archival_type: FileTypeArchive::Bz2 } => false,
+            FileType::Evt{ archival_type: FileTypeArchive::Gz } => false,
+            FileType::Evt{ archival_type: FileTypeArchive::Lz4 } => false,
+            FileType::Evt{ archival_type: FileTypeArchive::Tar } => true,
+            FileType::Evt{ archival_type: FileTypeArchive::Xz } => false,
             FileType::Evtx{ archival_type: FileTypeArchive::Normal } => false,
             FileType::Evtx{ archival_type: FileTypeArchive::Bz2 } => false,
             FileType::Evtx{ archival_type: FileTypeArchive::Gz } => false,
@@ -1168,18 +1228,36 @@ impl FileType {
             FileType::Journal{ archival_type: FileTypeArchive::Lz4 } => false,
             FileType::Journal{ archival_type: FileTypeArchive::Tar } => true,
             FileType::Journal{ archival_type: FileTypeArchive::Xz } => false,
+            FileType::Logcat{ archival_type: FileTypeArchive::Normal } => false,
+            FileType::Logcat{ archival_type: FileTypeArchive::Bz2 } => false,
+            FileType::Logcat{ archival_type: FileTypeArchive::Gz } => false,
+            FileType::Logcat{ archival_type: FileTypeArchive::Lz4 } => false,
+            FileType::Logcat{ archival_type: FileTypeArchive::Tar } => true,
+            FileType::Logcat{ archival_type: FileTypeArchive::Xz } => false,
             FileType::Odl{ archival_type: FileTypeArchive::Normal, .. } => false,
             FileType::Odl{ archival_type: FileTypeArchive::Bz2, .. } => false,
             FileType::Odl{ archival_type: FileTypeArchive::Gz, .. } => false,
             FileType::Odl{ archival_type: FileTypeArchive::Lz4, .. } => false,
             FileType::Odl{ archival_type: FileTypeArchive::Tar, .. } => true,
             FileType::Odl{ archival_type: FileTypeArchive::Xz, .. } => false,
+            FileType::Pcap{ archival_type: FileTypeArchive::Normal } => false,
+            FileType::Pcap{ archival_type: FileTypeArchive::Bz2 } => false,
+            FileType::Pcap{ archival_type: FileTypeArchive::Gz } => false,
+            FileType::Pcap{ archival_type: FileTypeArchive::Lz4 } => false,
+            FileType::Pcap{ archival_type: FileTypeArchive::Tar } => true,
+            FileType::Pcap{ archival_type: FileTypeArchive::Xz } => false,
             FileType::Text{ archival_type: FileTypeArchive::Normal, ..} => false,
             FileType::Text{ archival_type: FileTypeArchive::Bz2, .. } => false,
             FileType::Text{ archival_type: FileTypeArchive::Gz, .. } => false,
             FileType::Text{ archival_type: FileTypeArchive::Lz4, .. } => false,
             FileType::Text{ archival_type: FileTypeArchive::Tar, .. } => true,
             FileType::Text{ archival_type: FileTypeArchive::Xz, .. } => false,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Normal } => false,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Bz2 } => false,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Gz } => false,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Lz4 } => false,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Tar } => true,
+            FileType::Tracev3{ archival_type: FileTypeArchive::Xz } => false,
             FileType::Unparsable => false,
         }
     }
@@ -1189,11 +1267,15 @@ impl FileType {
         match self {
             FileType::Asl { .. } => true,
             FileType::Etl { .. } => true,
+            FileType::Evt { .. } => true,
             FileType::Evtx { .. } => true,
             FileType::FixedStruct { .. } => true,
             FileType::Journal { .. } => true,
             FileType::Odl { .. } => true,
+            FileType::Pcap { .. } => true,
             FileType::Text { .. } => true,
+            FileType::Logcat { .. } => true,
+            FileType::Tracev3 { .. } => true,
             FileType::Unparsable => false,
         }
     }
@@ -1203,11 +1285,15 @@ impl FileType {
         match self {
             FileType::Asl { .. } => LogMessageType::PyEvent,
             FileType::Etl { .. } => LogMessageType::PyEvent,
+            FileType::Evt { .. } => LogMessageType::Evtx,
             FileType::Evtx { .. } => LogMessageType::Evtx,
             FileType::FixedStruct { .. } => LogMessageType::FixedStruct,
             FileType::Journal { .. } => LogMessageType::Journal,
             FileType::Odl { .. } => LogMessageType::PyEvent,
+            FileType::Pcap { .. } => LogMessageType::PyEvent,
             FileType::Text { .. } => LogMessageType::Sysline,
+            FileType::Logcat { .. } => LogMessageType::PyEvent,
+            FileType::Tracev3 { .. } => LogMessageType::PyEvent,
             FileType::Unparsable => {
                 debug_panic!("FileType::Unparsable should not be converted to LogMessageType");
 
@@ -1221,11 +1307,15 @@ impl FileType {
         match self {
             FileType::Asl { archival_type } => *archival_type,
             FileType::Etl { archival_type } => *archival_type,
+            FileType::Evt { archival_type } => *archival_type,
             FileType::Evtx { archival_type } => *archival_type,
             FileType::FixedStruct { archival_type, .. } => *archival_type,
             FileType::Journal { archival_type } => *archival_type,
             FileType::Odl { archival_type, .. } => *archival_type,
+            FileType::Pcap { archival_type } => *archival_type,
             FileType::Text { archival_type, .. } => *archival_type,
+            FileType::Logcat { archival_type } => *archival_type,
+            FileType::Tracev3 { archival_type } => *archival_type,
             FileType::Unparsable => {
                 debug_panic!("FileType::Unparsable should not be converted to FileTypeArchive");
 
@@ -1242,6 +1332,10 @@ impl FileType {
         matches!(self, FileType::Etl { .. })
     }
 
+    pub const fn is_evt(&self) -> bool {
+        matches!(self, FileType::Evt { .. })
+    }
+
     pub const fn is_evtx(&self) -> bool {
         matches!(self, FileType::Evtx { .. })
     }
@@ -1254,14 +1348,26 @@ impl FileType {
         matches!(self, FileType::Journal { .. })
     }
 
+    pub const fn is_logcat(&self) -> bool {
+        matches!(self, FileType::Logcat { .. })
+    }
+
     pub const fn is_odl(&self) -> bool {
         matches!(self, FileType::Odl { .. })
     }
 
+    pub const fn is_pcap(&self) -> bool {
+        matches!(self, FileType::Pcap { .. })
+    }
+
     pub const fn is_text(&self) -> bool {
         matches!(self, FileType::Text { .. })
     }
 
+    pub const fn is_tracev3(&self) -> bool {
+        matches!(self, FileType::Tracev3 { .. })
+    }
+
     pub const fn is_unparsable(&self) -> bool {
         matches!(self, FileType::Unparsable)
     }
@@ -1270,11 +1376,15 @@ impl FileType {
         match self {
             FileType::Asl { archival_type } => *archival_type,
             FileType::Etl { archival_type } => *archival_type,
+            FileType::Evt { archival_type } => *archival_type,
             FileType::Evtx { archival_type } => *archival_type,
             FileType::FixedStruct { archival_type, .. } => *archival_type,
             FileType::Journal { archival_type } => *archival_type,
             FileType::Odl { archival_type, .. } => *archival_type,
+            FileType::Pcap { archival_type } => *archival_type,
             FileType::Text { archival_type, .. } => *archival_type,
+            FileType::Logcat { archival_type } => *archival_type,
+            FileType::Tracev3 { archival_type } => *archival_type,
             FileType::Unparsable => FileTypeArchive::Normal,
         }
     }
@@ -1290,11 +1400,15 @@ impl FileType {
         match self {
             FileType::Asl { .. } => "Apple System Log",
             FileType::Etl { .. } => "Windows Event Trace Log",
+            FileType::Evt { .. } => "Windows Event Log",
             FileType::Evtx { .. } => "Windows XML EventLog",
             FileType::FixedStruct { .. } => "Unix accounting log (acct/lastlog/lastlogx/utmp/utmpx)",
             FileType::Journal { .. } => "systemd Journal",
             FileType::Odl { .. } => "OneDrive Log",
+            FileType::Pcap { .. } => "packet capture syslog",
             FileType::Text { .. } => "text log",
+            FileType::Logcat { .. } => "Android logcat",
+            FileType::Tracev3 { .. } => "Apple Unified Log",
             FileType::Unparsable => "Unparsable",
         }
     }
@@ -1321,9 +1435,10 @@ pub enum LogMessageType {
     // / [lastlog/lastlogx/utmp/utmpx format]: https://web.archive.org/web/20231216015325/https://man.freebsd.org/cgi/man.cgi?query=lastlog&sektion=5&manpath=NetBSD+9.3
     // / [`FixedStruct`]: crate::data::fixedstruct::FixedStruct
     FixedStruct,
-    /// A [Windows XML EventLog] file.
+    /// A [Windows XML EventLog] file, or legacy [Windows Event Log] file.
     ///
     /// [Windows XML EventLog]: https://github.com/libyal/libevtx/blob/main/documentation/Windows%20XML%20Event%20Log%20(EVTX).asciidoc
+    /// [Windows Event Log]: https://github.com/libyal/libevt/blob/main/documentation/Windows%20Event%20Log%20(EVT)%20format.asciidoc
     Evtx,
     /// A [systemd Journal file].
     ///
@@ -1348,7 +1463,7 @@ impl std::fmt::Display for LogMessageType {
         f: &mut std::fmt::Formatter,
     ) -> std::fmt::Result {
         match self {
-            LogMessageType::Evtx => write!(f, "EVTX entries (Windows XML EventLog)"),
+            LogMessageType::Evtx => write!(f, "EVT/EVTX entries (Windows EventLog)"),
             LogMessageType::FixedStruct => write!(f, "fixedstruct entries (Unix acct/lastlog/lastlogx/utmp/utmpx)"),
             LogMessageType::Journal => write!(f, "systemd journal entries"),
             LogMessageType::PyEvent => write!(f, "Python parsed events (ASL/ETL/ODL)"),
@@ -1732,6 +1847,78 @@ pub const fn max16(
     max2(max15(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o), p)
 }
 
+#[allow(clippy::too_many_arguments)]
+pub const fn max17(
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+    e: usize,
+    f: usize,
+    g: usize,
+    h: usize,
+    i: usize,
+    j: usize,
+    k: usize,
+    l: usize,
+    m: usize,
+    n: usize,
+    o: usize,
+    p: usize,
+    q: usize,
+) -> usize {
+    max2(max16(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p), q)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub const fn max18(
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+    e: usize,
+    f: usize,
+    g: usize,
+    h: usize,
+    i: usize,
+    j: usize,
+    k: usize,
+    l: usize,
+    m: usize,
+    n: usize,
+    o: usize,
+    p: usize,
+    q: usize,
+    r: usize,
+) -> usize {
+    max2(max17(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q), r)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub const fn max19(
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+    e: usize,
+    f: usize,
+    g: usize,
+    h: usize,
+    i: usize,
+    j: usize,
+    k: usize,
+    l: usize,
+    m: usize,
+    n: usize,
+    o: usize,
+    p: usize,
+    q: usize,
+    r: usize,
+    s: usize,
+) -> usize {
+    max2(max18(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q, r), s)
+}
+
 /// local `const` helper to return the minimum of two `usize` values
 ///
 /// Credit to <https://stackoverflow.com/a/53646925/471376>
@@ -1959,3 +2146,75 @@ pub const fn min16(
 ) -> usize {
     min2(min15(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o), p)
 }
+
+#[allow(clippy::too_many_arguments)]
+pub const fn min17(
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+    e: usize,
+    f: usize,
+    g: usize,
+    h: usize,
+    i: usize,
+    j: usize,
+    k: usize,
+    l: usize,
+    m: usize,
+    n: usize,
+    o: usize,
+    p: usize,
+    q: usize,
+) -> usize {
+    min2(min16(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p), q)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub const fn min18(
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+    e: usize,
+    f: usize,
+    g: usize,
+    h: usize,
+    i: usize,
+    j: usize,
+    k: usize,
+    l: usize,
+    m: usize,
+    n: usize,
+    o: usize,
+    p: usize,
+    q: usize,
+    r: usize,
+) -> usize {
+    min2(min17(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q), r)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub const fn min19(
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+    e: usize,
+    f: usize,
+    g: usize,
+    h: usize,
+    i: usize,
+    j: usize,
+    k: usize,
+    l: usize,
+    m: usize,
+    n: usize,
+    o: usize,
+    p: usize,
+    q: usize,
+    r: usize,
+    s: usize,
+) -> usize {
+    min2(min18(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q, r), s)
+}
@@ -0,0 +1,179 @@
+// src/data/acctsummary.rs
+
+//! Implement [`AcctSummary`] to summarize process accounting `acct`
+//! [`FixedStruct`] entries per command and per user, similar to the
+//! program [`sa`].
+//!
+//! [`FixedStruct`]: crate::data::fixedstruct::FixedStruct
+//! [`sa`]: https://man7.org/linux/man-pages/man8/sa.8.html
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::common::Count;
+use crate::data::fixedstruct::{
+    AcctFields,
+    FixedStruct,
+};
+
+/// Totals of many `acct` entries in an [`AcctSummary`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AcctTotals {
+    /// count of processes
+    pub count: Count,
+    /// sum of user and system CPU seconds
+    pub cpu: f64,
+    /// sum of elapsed seconds
+    pub elapsed: f64,
+    /// sum of average memory usage kilobytes
+    pub mem: u64,
+    /// count of processes killed by a signal or that dumped core
+    pub abnormal: Count,
+}
+
+impl AcctTotals {
+    /// Add one `acct` entry.
+    pub fn add(
+        &mut self,
+        fields: &AcctFields,
+    ) {
+        self.count += 1;
+        self.cpu += fields.cpu;
+        self.elapsed += fields.elapsed;
+        self.mem += fields.mem;
+        if fields.is_abnormal() {
+            self.abnormal += 1;
+        }
+    }
+
+    /// Average CPU seconds per process.
+    pub fn cpu_avg(&self) -> f64 {
+        match self.count {
+            0 => 0.0,
+            count => self.cpu / count as f64,
+        }
+    }
+
+    /// Average memory usage kilobytes per process.
+    pub fn mem_avg(&self) -> u64 {
+        match self.count {
+            0 => 0,
+            count => self.mem / count,
+        }
+    }
+}
+
+/// Summary of process accounting `acct` entries per command and per user.
+#[derive(Debug, Default)]
+pub struct AcctSummary {
+    commands: BTreeMap<String, AcctTotals>,
+    users: BTreeMap<u32, AcctTotals>,
+}
+
+impl AcctSummary {
+    pub fn new() -> AcctSummary {
+        AcctSummary::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Process an `acct` `FixedStruct`. Other entries are ignored.
+    pub fn process(
+        &mut self,
+        fixedstruct: &FixedStruct,
+    ) {
+        if let Some(fields) = fixedstruct.acct_fields() {
+            self.process_fields(&fields);
+        }
+    }
+
+    /// Process the `AcctFields` of an `acct` entry.
+    pub fn process_fields(
+        &mut self,
+        fields: &AcctFields,
+    ) {
+        self.commands
+            .entry(fields.comm.clone())
+            .or_default()
+            .add(fields);
+        self.users
+            .entry(fields.uid)
+            .or_default()
+            .add(fields);
+    }
+
+    /// The totals per command, most CPU seconds first.
+    pub fn commands(&self) -> Vec<(&String, &AcctTotals)> {
+        let mut totals: Vec<(&String, &AcctTotals)> = self.commands.iter().collect();
+        totals.sort_by(|a, b| b.1.cpu.total_cmp(&a.1.cpu));
+
+        totals
+    }
+
+    /// The totals per user ID, most CPU seconds first.
+    pub fn users(&self) -> Vec<(&u32, &AcctTotals)> {
+        let mut totals: Vec<(&u32, &AcctTotals)> = self.users.iter().collect();
+        totals.sort_by(|a, b| b.1.cpu.total_cmp(&a.1.cpu));
+
+        totals
+    }
+}
+
+/// Write one row of an [`AcctSummary`] table.
+fn write_row(
+    f: &mut fmt::Formatter,
+    totals: &AcctTotals,
+    name: &dyn fmt::Display,
+) -> fmt::Result {
+    writeln!(
+        f,
+        "{:>8} {:>12.2} {:>10.2} {:>12.2} {:>8}k {:>8} {}",
+        totals.count,
+        totals.cpu,
+        totals.cpu_avg(),
+        totals.elapsed,
+        totals.mem_avg(),
+        totals.abnormal,
+        name,
+    )
+}
+
+/// Print the summary as two tables, per command and per user, most CPU
+/// seconds first, e.g.
+///
+/// ```text
+/// Process accounting by command:
+///    count      cpu sec    avg cpu  elapsed sec  avg mem abnormal command
+///       12         3.04       0.25        60.10     2048k        1 gcc
+/// Process accounting by user:
+///    count      cpu sec    avg cpu  elapsed sec  avg mem abnormal uid
+///       12         3.04       0.25        60.10     2048k        1 1000
+/// ```
+///
+/// "abnormal" is the count of processes killed by a signal (`AXSIG`) or that
+/// dumped core (`ACORE`).
+impl fmt::Display for AcctSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let header = |f: &mut fmt::Formatter, name: &str| -> fmt::Result {
+            writeln!(
+                f,
+                "{:>8} {:>12} {:>10} {:>12} {:>9} {:>8} {}",
+                "count", "cpu sec", "avg cpu", "elapsed sec", "avg mem", "abnormal", name,
+            )
+        };
+        writeln!(f, "Process accounting by command:")?;
+        header(f, "command")?;
+        for (comm, totals) in self.commands() {
+            write_row(f, totals, comm)?;
+        }
+        writeln!(f, "Process accounting by user:")?;
+        header(f, "uid")?;
+        for (uid, totals) in self.users() {
+            write_row(f, totals, uid)?;
+        }
+
+        Ok(())
+    }
+}
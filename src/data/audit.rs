@@ -0,0 +1,656 @@
+// src/data/audit.rs
+
+//! Implement [`AuditGrouper`] to group Linux audit records from [`Sysline`]s
+//! into [`AuditEvent`]s by audit serial number, similar to the program
+//! [`ausearch`].
+//! Implement [`AuditFilter`] to filter `AuditEvent`s by record type and key.
+//! Implement [`AuditInterpreter`] to interpret audit records similar to
+//! `ausearch --interpret`.
+//!
+//! An audit record looks like
+//!
+//! ```text
+//! type=SYSCALL msg=audit(1681160194.293:6): arch=c000003e syscall=44 success=yes exit=60 … key=(null)
+//! ```
+//!
+//! where `1681160194.293` is the epoch time and `6` is the serial number.
+//! One audit event is many records, e.g. `SYSCALL`, `EXECVE`, `CWD`, `PATH`,
+//! and `PROCTITLE`, with the same time and serial number.
+//!
+//! [`Sysline`]: crate::data::sysline::Sysline
+//! [`ausearch`]: https://man7.org/linux/man-pages/man8/ausearch.8.html
+
+use std::collections::{
+    BTreeMap,
+    HashMap,
+};
+use std::fmt;
+
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::common::PathId;
+use crate::data::datetime::DateTimeL;
+use crate::data::sysline::SyslineP;
+
+/// Record type that ends an audit event.
+pub const AUDIT_TYPE_EOE: &str = "EOE";
+
+/// Separator of the raw fields and the interpreted fields of a record in
+/// the `enriched` log format.
+pub const AUDIT_ENRICHED_SEP: char = '\x1d';
+
+/// Separator of many keys within one hex-encoded `key` field.
+pub const AUDIT_KEY_SEP: char = '\x01';
+
+/// An unset `auid`, `uid`, or `ses`, i.e. `(uint32_t)-1`.
+pub const AUDIT_UNSET: &str = "4294967295";
+
+/// Fields that are user IDs.
+const FIELDS_UID: [&str; 13] = [
+    "auid", "uid", "euid", "suid", "fsuid", "ouid", "oauid", "obj_uid", "iuid", "inode_uid", "sauid", "old-auid",
+    "new-auid",
+];
+
+/// Fields that are group IDs.
+const FIELDS_GID: [&str; 9] = [
+    "gid", "egid", "sgid", "fsgid", "ogid", "obj_gid", "igid", "inode_gid", "new_gid",
+];
+
+/// Fields that may be hex-encoded, i.e. when the value has a space, a quote,
+/// or a control character.
+const FIELDS_ENCODED: [&str; 10] = [
+    "proctitle", "cmd", "comm", "exe", "cwd", "name", "path", "key", "acct", "data",
+];
+
+/// Linux x86_64 system call names, indexed by system call number 0 to 334.
+const SYSCALLS_X86_64: [&str; 335] = [
+    "read", "write", "open", "close", "stat", "fstat", "lstat", "poll", "lseek", "mmap",
+    "mprotect", "munmap", "brk", "rt_sigaction", "rt_sigprocmask", "rt_sigreturn", "ioctl", "pread64", "pwrite64", "readv",
+    "writev", "access", "pipe", "select", "sched_yield", "mremap", "msync", "mincore", "madvise", "shmget",
+    "shmat", "shmctl", "dup", "dup2", "pause", "nanosleep", "getitimer", "alarm", "setitimer", "getpid",
+    "sendfile", "socket", "connect", "accept", "sendto", "recvfrom", "sendmsg", "recvmsg", "shutdown", "bind",
+    "listen", "getsockname", "getpeername", "socketpair", "setsockopt", "getsockopt", "clone", "fork", "vfork", "execve",
+    "exit", "wait4", "kill", "uname", "semget", "semop", "semctl", "shmdt", "msgget", "msgsnd",
+    "msgrcv", "msgctl", "fcntl", "flock", "fsync", "fdatasync", "truncate", "ftruncate", "getdents", "getcwd",
+    "chdir", "fchdir", "rename", "mkdir", "rmdir", "creat", "link", "unlink", "symlink", "readlink",
+    "chmod", "fchmod", "chown", "fchown", "lchown", "umask", "gettimeofday", "getrlimit", "getrusage", "sysinfo",
+    "times", "ptrace", "getuid", "syslog", "getgid", "setuid", "setgid", "geteuid", "getegid", "setpgid",
+    "getppid", "getpgrp", "setsid", "setreuid", "setregid", "getgroups", "setgroups", "setresuid", "getresuid", "setresgid",
+    "getresgid", "getpgid", "setfsuid", "setfsgid", "getsid", "capget", "capset", "rt_sigpending", "rt_sigtimedwait", "rt_sigqueueinfo",
+    "rt_sigsuspend", "sigaltstack", "utime", "mknod", "uselib", "personality", "ustat", "statfs", "fstatfs", "sysfs",
+    "getpriority", "setpriority", "sched_setparam", "sched_getparam", "sched_setscheduler", "sched_getscheduler", "sched_get_priority_max", "sched_get_priority_min", "sched_rr_get_interval", "mlock",
+    "munlock", "mlockall", "munlockall", "vhangup", "modify_ldt", "pivot_root", "_sysctl", "prctl", "arch_prctl", "adjtimex",
+    "setrlimit", "chroot", "sync", "acct", "settimeofday", "mount", "umount2", "swapon", "swapoff", "reboot",
+    "sethostname", "setdomainname", "iopl", "ioperm", "create_module", "init_module", "delete_module", "get_kernel_syms", "query_module", "quotactl",
+    "nfsservctl", "getpmsg", "putpmsg", "afs_syscall", "tuxcall", "security", "gettid", "readahead", "setxattr", "lsetxattr",
+    "fsetxattr", "getxattr", "lgetxattr", "fgetxattr", "listxattr", "llistxattr", "flistxattr", "removexattr", "lremovexattr", "fremovexattr",
+    "tkill", "time", "futex", "sched_setaffinity", "sched_getaffinity", "set_thread_area", "io_setup", "io_destroy", "io_getevents", "io_submit",
+    "io_cancel", "get_thread_area", "lookup_dcookie", "epoll_create", "epoll_ctl_old", "epoll_wait_old", "remap_file_pages", "getdents64", "set_tid_address", "restart_syscall",
+    "semtimedop", "fadvise64", "timer_create", "timer_settime", "timer_gettime", "timer_getoverrun", "timer_delete", "clock_settime", "clock_gettime", "clock_getres",
+    "clock_nanosleep", "exit_group", "epoll_wait", "epoll_ctl", "tgkill", "utimes", "vserver", "mbind", "set_mempolicy", "get_mempolicy",
+    "mq_open", "mq_unlink", "mq_timedsend", "mq_timedreceive", "mq_notify", "mq_getsetattr", "kexec_load", "waitid", "add_key", "request_key",
+    "keyctl", "ioprio_set", "ioprio_get", "inotify_init", "inotify_add_watch", "inotify_rm_watch", "migrate_pages", "openat", "mkdirat", "mknodat",
+    "fchownat", "futimesat", "newfstatat", "unlinkat", "renameat", "linkat", "symlinkat", "readlinkat", "fchmodat", "faccessat",
+    "pselect6", "ppoll", "unshare", "set_robust_list", "get_robust_list", "splice", "tee", "sync_file_range", "vmsplice", "move_pages",
+    "utimensat", "epoll_pwait", "signalfd", "timerfd_create", "eventfd", "fallocate", "timerfd_settime", "timerfd_gettime", "accept4", "signalfd4",
+    "eventfd2", "epoll_create1", "dup3", "pipe2", "inotify_init1", "preadv", "pwritev", "rt_tgsigqueueinfo", "perf_event_open", "recvmmsg",
+    "fanotify_init", "fanotify_mark", "prlimit64", "name_to_handle_at", "open_by_handle_at", "clock_adjtime", "syncfs", "sendmmsg", "setns", "getcpu",
+    "process_vm_readv", "process_vm_writev", "kcmp", "finit_module", "sched_setattr", "sched_getattr", "renameat2", "seccomp", "getrandom", "memfd_create",
+    "kexec_file_load", "bpf", "execveat", "userfaultfd", "membarrier", "mlock2", "copy_file_range", "preadv2", "pwritev2", "pkey_mprotect",
+    "pkey_alloc", "pkey_free", "statx", "io_pgetevents", "rseq",
+];
+
+/// Linux system call names common to all architectures, indexed by system
+/// call number 424 to 450.
+const SYSCALLS_424: [&str; 27] = [
+    "pidfd_send_signal", "io_uring_setup", "io_uring_enter", "io_uring_register", "open_tree", "move_mount", "fsopen",
+    "fsconfig", "fsmount", "fspick", "pidfd_open", "clone3", "close_range", "openat2", "pidfd_getfd", "faccessat2",
+    "process_madvise", "epoll_pwait2", "mount_setattr", "quotactl_fd", "landlock_create_ruleset", "landlock_add_rule",
+    "landlock_restrict_self", "memfd_secret", "process_mrelease", "futex_waitv", "set_mempolicy_home_node",
+];
+
+/// Linux x86_64 system call name of system call number `syscall`.
+pub fn syscall_name_x86_64(syscall: u32) -> Option<&'static str> {
+    match syscall {
+        0..=334 => Some(SYSCALLS_X86_64[syscall as usize]),
+        424..=450 => Some(SYSCALLS_424[(syscall - 424) as usize]),
+        _ => None,
+    }
+}
+
+/// Name of the audit `arch` field value, e.g. `c000003e` is `x86_64`.
+///
+/// Values are the `AUDIT_ARCH_*` constants of
+/// `include/uapi/linux/audit.h`. The kernel prints them in hexadecimal
+/// without leading zeros, e.g. big-endian ARM is `28`.
+pub fn arch_name(arch: &str) -> Option<&'static str> {
+    match arch.to_ascii_lowercase().trim_start_matches('0') {
+        "c000003e" => Some("x86_64"),
+        "40000003" => Some("i386"),
+        "c00000b7" => Some("aarch64"),
+        "40000028" => Some("arm"),
+        "28" => Some("armeb"),
+        "80000016" => Some("s390x"),
+        "16" => Some("s390"),
+        "80000015" => Some("ppc64"),
+        "c0000015" => Some("ppc64le"),
+        "c00000f3" => Some("riscv64"),
+        _ => None,
+    }
+}
+
+/// The record type, the serial number, and the byte offset of the fields of
+/// an audit record, e.g. for record
+/// `type=PATH msg=audit(1681160194.293:6): item=0 name="/etc"`
+/// return `("PATH", 6, 36)`.
+///
+/// A record may begin with a `node=` field.
+pub fn audit_header(record: &str) -> Option<(&str, u64, usize)> {
+    let mut at: usize = 0;
+    if record.starts_with("node=") {
+        at = record.find(' ')? + 1;
+    }
+    let rest: &str = record[at..].strip_prefix("type=")?;
+    at += "type=".len();
+    let type_end: usize = rest.find(' ')?;
+    let type_: &str = &rest[..type_end];
+    at += type_end + 1;
+    let rest: &str = record[at..].strip_prefix("msg=audit(")?;
+    at += "msg=audit(".len();
+    let close: usize = rest.find("):")?;
+    let (_epoch, serial) = rest[..close].split_once(':')?;
+    let serial: u64 = serial.parse().ok()?;
+    at += close + 2;
+    if record[at..].starts_with(' ') {
+        at += 1;
+    }
+
+    Some((type_, serial, at))
+}
+
+/// Split the fields of an audit record into `(key, value)` pairs. A value
+/// may be in double quotes or single quotes and have spaces. A word without
+/// a `=` is returned as `("", word)`.
+pub fn audit_fields(fields: &str) -> Vec<(&str, &str)> {
+    let mut out: Vec<(&str, &str)> = Vec::new();
+    let bytes: &[u8] = fields.as_bytes();
+    let mut at: usize = 0;
+    while at < bytes.len() {
+        if bytes[at] == b' ' {
+            at += 1;
+            continue;
+        }
+        let beg: usize = at;
+        let mut eq: Option<usize> = None;
+        while at < bytes.len() && bytes[at] != b' ' {
+            if eq.is_none() && bytes[at] == b'=' {
+                eq = Some(at);
+                if let Some(&quote) = bytes.get(at + 1)
+                    && (quote == b'"' || quote == b'\'')
+                {
+                    at = match fields[at + 2..].find(quote as char) {
+                        Some(close) => at + 2 + close,
+                        None => bytes.len() - 1,
+                    };
+                }
+            }
+            at += 1;
+        }
+        match eq {
+            Some(eq) => out.push((&fields[beg..eq], &fields[eq + 1..at])),
+            None => out.push(("", &fields[beg..at])),
+        }
+    }
+
+    out
+}
+
+/// Remove the double quotes or single quotes around `value`.
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(value_) = value
+            .strip_prefix(quote)
+            .and_then(|v| v.strip_suffix(quote))
+        {
+            return value_;
+        }
+    }
+
+    value
+}
+
+/// Decode an unquoted hex-encoded field value, e.g. `2F62696E2F6C73` is
+/// `/bin/ls`. `None` if `value` is not hex-encoded.
+pub fn hex_decode(value: &str) -> Option<String> {
+    if value.len() < 2
+        || !value.len().is_multiple_of(2)
+        || !value.bytes().all(|b| b.is_ascii_digit() || (b'A'..=b'F').contains(&b))
+    {
+        return None;
+    }
+    let bytes: Vec<u8> = (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&value[i..i + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .ok()?;
+
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// The keys of the `key` field of an audit record. A `key` field of many
+/// keys is hex-encoded with the keys separated by `\x01`.
+/// A `key=(null)` field is no key.
+pub fn audit_keys(record: &str) -> Vec<String> {
+    let fields_at: usize = match audit_header(record) {
+        Some((_, _, at)) => at,
+        None => return Vec::with_capacity(0),
+    };
+    let raw: &str = match record[fields_at..].split_once(AUDIT_ENRICHED_SEP) {
+        Some((raw, _)) => raw,
+        None => &record[fields_at..],
+    };
+    let mut keys: Vec<String> = Vec::new();
+    for (key, value) in audit_fields(raw) {
+        if key != "key" || value == "(null)" {
+            continue;
+        }
+        match hex_decode(value) {
+            Some(decoded) if !value.starts_with('"') => {
+                keys.extend(decoded.split(AUDIT_KEY_SEP).map(String::from));
+            }
+            _ => keys.push(String::from(unquote(value))),
+        }
+    }
+
+    keys
+}
+
+/// One audit event; one or more consecutive audit records with the same
+/// serial number.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditEvent {
+    /// datetime of the first record
+    pub dt: DateTimeL,
+    /// `None` if the records are not audit records
+    pub serial: Option<u64>,
+    /// the records without the ending newline
+    pub records: Vec<String>,
+}
+
+impl AuditEvent {
+    pub const fn dt(&self) -> &DateTimeL {
+        &self.dt
+    }
+
+    /// The record types, e.g. `SYSCALL`, `PATH`.
+    pub fn types(&self) -> Vec<&str> {
+        self.records
+            .iter()
+            .filter_map(|record| audit_header(record).map(|(type_, _, _)| type_))
+            .collect()
+    }
+
+    /// The keys of all records.
+    pub fn keys(&self) -> Vec<String> {
+        self.records
+            .iter()
+            .flat_map(|record| audit_keys(record))
+            .collect()
+    }
+}
+
+/// Print an `AuditEvent` as the records, one per line.
+impl fmt::Display for AuditEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for record in self.records.iter() {
+            writeln!(f, "{}", record)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Groups consecutive audit records with the same serial number into
+/// [`AuditEvent`]s.
+///
+/// Records of each file are grouped separately. Records must be passed in
+/// file order.
+/// An event is returned when a record with a different serial number is
+/// passed, when an `EOE` record is passed, or when the file is finished.
+/// A line that is not an audit record is an event by itself.
+#[derive(Debug, Default)]
+pub struct AuditGrouper {
+    /// the event being grouped for each file
+    events: BTreeMap<PathId, AuditEvent>,
+}
+
+impl AuditGrouper {
+    pub fn new() -> AuditGrouper {
+        AuditGrouper::default()
+    }
+
+    /// Count of events being grouped.
+    pub fn count_open(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Process the records of a `Sysline` of file `pathid`.
+    /// Return the events ended by the records, in file order.
+    pub fn process(
+        &mut self,
+        pathid: PathId,
+        syslinep: &SyslineP,
+    ) -> Vec<AuditEvent> {
+        let data: String = String::from_utf8_lossy(&syslinep.to_bytes()).into_owned();
+        let mut ended: Vec<AuditEvent> = Vec::new();
+        for record in data.lines() {
+            ended.extend(self.process_record(pathid, syslinep.dt(), record));
+        }
+
+        ended
+    }
+
+    /// Process one record at datetime `dt` of file `pathid`.
+    /// Return the events ended by the record.
+    pub fn process_record(
+        &mut self,
+        pathid: PathId,
+        dt: &DateTimeL,
+        record: &str,
+    ) -> Vec<AuditEvent> {
+        defn!("({}, {:?})", pathid, record);
+        let mut ended: Vec<AuditEvent> = Vec::with_capacity(2);
+        let header: Option<(&str, u64, usize)> = audit_header(record);
+        let serial: Option<u64> = header.map(|(_, serial, _)| serial);
+        match self.events.get_mut(&pathid) {
+            Some(event) if serial.is_some() && event.serial == serial => {
+                event.records.push(String::from(record));
+            }
+            _ => {
+                let event = AuditEvent {
+                    dt: *dt,
+                    serial,
+                    records: vec![String::from(record)],
+                };
+                ended.extend(self.events.insert(pathid, event));
+            }
+        }
+        if serial.is_none() || matches!(header, Some((AUDIT_TYPE_EOE, _, _))) {
+            ended.extend(self.events.remove(&pathid));
+        }
+        defx!("ended {}", ended.len());
+
+        ended
+    }
+
+    /// Return the event being grouped for file `pathid`.
+    pub fn finish_path(
+        &mut self,
+        pathid: PathId,
+    ) -> Option<AuditEvent> {
+        self.events.remove(&pathid)
+    }
+
+    /// Return all events being grouped, in order of `PathId`.
+    pub fn finish(&mut self) -> Vec<AuditEvent> {
+        std::mem::take(&mut self.events)
+            .into_values()
+            .collect()
+    }
+}
+
+/// Filter [`AuditEvent`]s by record type and key, similar to
+/// `ausearch --message` and `ausearch --key`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AuditFilter {
+    /// upper case record types; an event passes if any record has one of
+    /// these types
+    pub types: Vec<String>,
+    /// an event passes if any record has one of these keys
+    pub keys: Vec<String>,
+}
+
+impl AuditFilter {
+    /// Create a new `AuditFilter` from comma-separated lists of record types,
+    /// e.g. `"SYSCALL,USER_LOGIN"`, and keys.
+    pub fn new(
+        types: Option<&str>,
+        keys: Option<&str>,
+    ) -> AuditFilter {
+        fn split(list: Option<&str>) -> Vec<String> {
+            list.unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+                .collect()
+        }
+
+        AuditFilter {
+            types: split(types)
+                .into_iter()
+                .map(|t| t.to_ascii_uppercase())
+                .collect(),
+            keys: split(keys),
+        }
+    }
+
+    /// Does this filter pass all events?
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty() && self.keys.is_empty()
+    }
+
+    /// Does `event` pass this filter?
+    pub fn matches(
+        &self,
+        event: &AuditEvent,
+    ) -> bool {
+        if !self.types.is_empty()
+            && !event
+                .types()
+                .iter()
+                .any(|type_| self.types.iter().any(|t| t == type_))
+        {
+            return false;
+        }
+        if !self.keys.is_empty()
+            && !event
+                .keys()
+                .iter()
+                .any(|key| self.keys.contains(key))
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// Parse the `/etc/passwd` or `/etc/group` file `data` to a map of ID to
+/// name, i.e. the third field to the first field.
+pub fn parse_id_file(data: &str) -> HashMap<u32, String> {
+    let mut map: HashMap<u32, String> = HashMap::new();
+    for line in data.lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split(':');
+        let name: &str = match fields.next() {
+            Some(name) if !name.is_empty() => name,
+            _ => continue,
+        };
+        if let Some(id) = fields.nth(1).and_then(|id| id.trim().parse::<u32>().ok()) {
+            map.entry(id).or_insert_with(|| String::from(name));
+        }
+    }
+
+    map
+}
+
+/// Interpret audit records similar to `ausearch --interpret`.
+///
+/// * Field values of the `enriched` log format are used when present, e.g.
+///   `UID="root"`.
+/// * `arch` is named, e.g. `c000003e` is `x86_64`.
+/// * `syscall` of an x86_64 record is named, e.g. `59` is `execve`.
+/// * user IDs and group IDs are named from the passed `passwd` and `group`
+///   files. `4294967295` is `unset`.
+/// * hex-encoded values are decoded, e.g. `proctitle` and the `a0` … `aN`
+///   of an `EXECVE` record. The null characters separating the arguments of
+///   a `proctitle` are replaced with spaces.
+/// * quotes are removed.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AuditInterpreter {
+    users: HashMap<u32, String>,
+    groups: HashMap<u32, String>,
+}
+
+impl AuditInterpreter {
+    /// Create a new `AuditInterpreter` with the data of a `passwd` file and
+    /// a `group` file.
+    pub fn new(
+        passwd: Option<&str>,
+        group: Option<&str>,
+    ) -> AuditInterpreter {
+        AuditInterpreter {
+            users: passwd.map(parse_id_file).unwrap_or_default(),
+            groups: group.map(parse_id_file).unwrap_or_default(),
+        }
+    }
+
+    /// Interpret one audit record. A record that is not an audit record is
+    /// returned unchanged.
+    pub fn interpret(
+        &self,
+        record: &str,
+    ) -> String {
+        let (type_, fields_at): (&str, usize) = match audit_header(record) {
+            Some((type_, _, at)) => (type_, at),
+            None => return String::from(record),
+        };
+        let (raw, enriched): (&str, &str) = record[fields_at..]
+            .split_once(AUDIT_ENRICHED_SEP)
+            .unwrap_or((&record[fields_at..], ""));
+        let enriched: HashMap<&str, &str> = audit_fields(enriched)
+            .into_iter()
+            .map(|(key, value)| (key, unquote(value)))
+            .collect();
+        let mut out: String = String::with_capacity(record.len());
+        out.push_str(record[..fields_at].trim_end());
+        out.push(' ');
+        out.push_str(&self.interpret_fields(type_, raw, &enriched));
+
+        out
+    }
+
+    /// Interpret all records of `event`.
+    pub fn interpret_event(
+        &self,
+        event: AuditEvent,
+    ) -> AuditEvent {
+        AuditEvent {
+            records: event
+                .records
+                .iter()
+                .map(|record| self.interpret(record))
+                .collect(),
+            ..event
+        }
+    }
+
+    /// Interpret the fields of a record of type `type_`.
+    fn interpret_fields(
+        &self,
+        type_: &str,
+        fields: &str,
+        enriched: &HashMap<&str, &str>,
+    ) -> String {
+        let fields: Vec<(&str, &str)> = audit_fields(fields);
+        let is_x86_64: bool = fields
+            .iter()
+            .any(|(key, value)| *key == "arch" && arch_name(value) == Some("x86_64"));
+        let mut out: Vec<String> = Vec::with_capacity(fields.len());
+        for (key, value) in fields.iter() {
+            if key.is_empty() {
+                out.push(String::from(*value));
+                continue;
+            }
+            // a user message of fields within single quotes
+            if *key == "msg" && value.starts_with('\'') {
+                out.push(format!("{}='{}'", key, self.interpret_fields(type_, unquote(value), enriched)));
+                continue;
+            }
+            let value_: String = self.interpret_value(type_, key, value, is_x86_64, enriched);
+            out.push(format!("{}={}", key, value_));
+        }
+
+        out.join(" ")
+    }
+
+    /// Interpret the `value` of field `key` of a record of type `type_`.
+    fn interpret_value(
+        &self,
+        type_: &str,
+        key: &str,
+        value: &str,
+        is_x86_64: bool,
+        enriched: &HashMap<&str, &str>,
+    ) -> String {
+        if let Some(value_) = enriched.get(key.to_ascii_uppercase().as_str()) {
+            return String::from(*value_);
+        }
+        let is_execve_arg: bool = type_ == "EXECVE"
+            && key.len() > 1
+            && key.starts_with('a')
+            && key[1..].bytes().all(|b| b.is_ascii_digit());
+        if (is_execve_arg || FIELDS_ENCODED.contains(&key))
+            && let Some(decoded) = hex_decode(value)
+        {
+            if key == "proctitle" {
+                return decoded.trim_end_matches('\0').replace('\0', " ");
+            }
+            if key == "key" {
+                return decoded.replace(AUDIT_KEY_SEP, ",");
+            }
+            return decoded;
+        }
+        match key {
+            "arch" => {
+                if let Some(name) = arch_name(value) {
+                    return String::from(name);
+                }
+            }
+            "syscall" if is_x86_64 => {
+                if let Some(name) = value.parse::<u32>().ok().and_then(syscall_name_x86_64) {
+                    return String::from(name);
+                }
+            }
+            "ses" if value == AUDIT_UNSET => return String::from("unset"),
+            _ if FIELDS_UID.contains(&key) => {
+                if value == AUDIT_UNSET {
+                    return String::from("unset");
+                }
+                if let Some(name) = value.parse::<u32>().ok().and_then(|id| self.users.get(&id)) {
+                    return name.clone();
+                }
+            }
+            _ if FIELDS_GID.contains(&key) => {
+                if value == AUDIT_UNSET {
+                    return String::from("unset");
+                }
+                if let Some(name) = value.parse::<u32>().ok().and_then(|id| self.groups.get(&id)) {
+                    return name.clone();
+                }
+            }
+            _ => {}
+        }
+
+        String::from(unquote(value))
+    }
+}
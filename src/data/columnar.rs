@@ -0,0 +1,371 @@
+// src/data/columnar.rs
+
+//! Implement [`ColumnarHeader`] to find the datetime of a line of a
+//! [W3C Extended Log File Format] file or a CSV file by the datetime columns
+//! named by the header, and [`ColumnarTime`] to select the datetime columns.
+//!
+//! A W3C extended log file, e.g. an IIS log or a Windows Firewall
+//! `pfirewall.log`, looks like
+//!
+//! ```text
+//! #Software: Microsoft Internet Information Services 10.0
+//! #Version: 1.0
+//! #Date: 2024-01-02 03:04:05
+//! #Fields: date time s-ip cs-method cs-uri-stem sc-status
+//! 2024-01-02 03:04:05 10.0.0.1 GET /index.html 200
+//! ```
+//!
+//! The `#Fields` directive may be written again later in the file, e.g. when
+//! IIS restarts, changing the columns of the following lines.
+//!
+//! A CSV file with a header row looks like
+//!
+//! ```text
+//! id,when,level,message
+//! 1,2024-01-02 03:04:05,info,"hello, world"
+//! ```
+//!
+//! The datetime columns of a CSV file must be selected, e.g. `when`, by the
+//! `--csv-time-columns` command-line option.
+//!
+//! [W3C Extended Log File Format]: https://www.w3.org/TR/WD-logfile.html
+
+use std::fmt;
+
+use ::chrono::FixedOffset;
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::data::datetime::DateTimeL;
+use crate::data::keyvalue::keyvalue_value_to_datetime;
+
+/// The directives that may begin a W3C extended log file.
+pub const W3C_DIRECTIVES: [&str; 7] = [
+    "#Software:",
+    "#Version:",
+    "#Date:",
+    "#Fields:",
+    "#Remark:",
+    "#Start-Date:",
+    "#End-Date:",
+];
+
+/// The `#Fields` directive of a W3C extended log file.
+pub const W3C_FIELDS: &str = "#Fields:";
+
+/// The default W3C datetime columns, tried in order.
+const W3C_TIME_COLUMNS_DEFAULT: [&[&str]; 3] = [
+    &["date", "time"],
+    &["datetime"],
+    &["timestamp"],
+];
+
+/// The default CSV delimiter.
+pub const CSV_DELIMITER_DEFAULT: char = ',';
+
+/// The format of a columnar file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnarFormat {
+    /// W3C Extended Log File Format; columns separated by whitespace
+    W3c,
+    /// Comma-separated values, or another delimiter
+    Csv,
+}
+
+impl fmt::Display for ColumnarFormat {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            ColumnarFormat::W3c => write!(f, "W3C"),
+            ColumnarFormat::Csv => write!(f, "CSV"),
+        }
+    }
+}
+
+/// A datetime column selected by the `--csv-time-columns` command-line
+/// option.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnarColumn {
+    /// a column named by the header, case-insensitive
+    Name(String),
+    /// a column number, beginning at 1
+    Number(usize),
+}
+
+/// Is `line` a W3C extended log file directive?
+pub fn is_w3c_directive(line: &str) -> bool {
+    W3C_DIRECTIVES
+        .iter()
+        .any(|directive| line.starts_with(directive))
+}
+
+/// The byte offsets of each column of `line`, excluding the quotes of a
+/// quoted column. A `delimiter` of `None` separates columns by runs of
+/// whitespace.
+pub fn columnar_split(
+    line: &str,
+    delimiter: Option<u8>,
+) -> Vec<(usize, usize)> {
+    let bytes: &[u8] = line.as_bytes();
+    let is_delimiter = |b: u8| -> bool {
+        match delimiter {
+            Some(delimiter) => b == delimiter,
+            None => b.is_ascii_whitespace(),
+        }
+    };
+    let mut spans: Vec<(usize, usize)> = Vec::new();
+    let mut at: usize = 0;
+    if delimiter.is_none() {
+        while at < bytes.len() && is_delimiter(bytes[at]) {
+            at += 1;
+        }
+    }
+    while at < bytes.len() {
+        let (beg, end): (usize, usize);
+        if bytes[at] == b'"' {
+            // quoted column; `""` is a quote
+            beg = at + 1;
+            at += 1;
+            while at < bytes.len() {
+                if bytes[at] == b'"' {
+                    if at + 1 < bytes.len() && bytes[at + 1] == b'"' {
+                        at += 2;
+                        continue;
+                    }
+                    break;
+                }
+                at += 1;
+            }
+            end = at.min(bytes.len());
+            while at < bytes.len() && !is_delimiter(bytes[at]) {
+                at += 1;
+            }
+        } else {
+            beg = at;
+            while at < bytes.len() && !is_delimiter(bytes[at]) {
+                at += 1;
+            }
+            end = at;
+        }
+        spans.push((beg, end));
+        if at >= bytes.len() {
+            break;
+        }
+        // skip the delimiter
+        at += 1;
+        match delimiter {
+            Some(_) => {
+                if at == bytes.len() {
+                    // a trailing delimiter is an empty last column
+                    spans.push((at, at));
+                }
+            }
+            None => {
+                while at < bytes.len() && is_delimiter(bytes[at]) {
+                    at += 1;
+                }
+            }
+        }
+    }
+
+    spans
+}
+
+/// Selects the datetime columns of columnar files.
+///
+/// Set by the `--csv-time-columns` and `--csv-delimiter` command-line
+/// options.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ColumnarTime {
+    /// the datetime columns of CSV and W3C files; W3C files without these
+    /// columns use the default W3C datetime columns. When empty, CSV files
+    /// are not parsed
+    pub columns: Vec<ColumnarColumn>,
+    /// the CSV delimiter
+    pub delimiter: u8,
+}
+
+impl Default for ColumnarTime {
+    fn default() -> ColumnarTime {
+        ColumnarTime {
+            columns: Vec::with_capacity(0),
+            delimiter: CSV_DELIMITER_DEFAULT as u8,
+        }
+    }
+}
+
+impl ColumnarTime {
+    /// Create a new `ColumnarTime` from a comma-separated list of column
+    /// names or column numbers beginning at 1, e.g. `"date,time"` or `"2"`,
+    /// and an ASCII CSV `delimiter`.
+    pub fn new(
+        columns: Option<&str>,
+        delimiter: Option<char>,
+    ) -> std::result::Result<ColumnarTime, String> {
+        let delimiter: char = delimiter.unwrap_or(CSV_DELIMITER_DEFAULT);
+        if !delimiter.is_ascii() || delimiter == '"' || delimiter == '\n' {
+            return Err(format!("Invalid CSV delimiter {:?}; expected an ASCII character", delimiter));
+        }
+        let mut columns_: Vec<ColumnarColumn> = Vec::new();
+        for column in columns
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            match column.parse::<usize>() {
+                Ok(0) => return Err(format!("Invalid column number {:?}; column numbers begin at 1", column)),
+                Ok(number) => columns_.push(ColumnarColumn::Number(number)),
+                Err(_) => columns_.push(ColumnarColumn::Name(String::from(column))),
+            }
+        }
+
+        Ok(ColumnarTime {
+            columns: columns_,
+            delimiter: delimiter as u8,
+        })
+    }
+
+    /// Are CSV files parsed, i.e. were datetime columns selected?
+    pub fn is_csv(&self) -> bool {
+        !self.columns.is_empty()
+    }
+
+    /// The indexes of `self.columns` within header `fields`.
+    fn time_indexes(
+        &self,
+        fields: &[String],
+    ) -> Option<Vec<usize>> {
+        self.columns
+            .iter()
+            .map(|column| match column {
+                ColumnarColumn::Name(name) => fields
+                    .iter()
+                    .position(|field| field.eq_ignore_ascii_case(name)),
+                ColumnarColumn::Number(number) => Some(number - 1),
+            })
+            .collect()
+    }
+}
+
+/// The columns of a columnar file from a W3C `#Fields` directive or a CSV
+/// header row, and the indexes of the datetime columns.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ColumnarHeader {
+    pub format: ColumnarFormat,
+    /// the column names; empty for a CSV file without a header row
+    pub fields: Vec<String>,
+    /// the indexes of the datetime columns, in order
+    pub time_indexes: Vec<usize>,
+    /// the column delimiter, `None` is whitespace
+    pub delimiter: Option<u8>,
+}
+
+impl ColumnarHeader {
+    /// Create a `ColumnarHeader` from a W3C `#Fields` directive `line`, e.g.
+    /// `#Fields: date time s-ip cs-method`.
+    /// The datetime columns are the selected columns of `columnar_time`, if
+    /// all are found, else the default `date` and `time` columns.
+    /// Return `None` if `line` is not a `#Fields` directive or no datetime
+    /// column is found.
+    pub fn from_w3c_fields(
+        line: &str,
+        columnar_time: &ColumnarTime,
+    ) -> Option<ColumnarHeader> {
+        let fields: Vec<String> = line
+            .strip_prefix(W3C_FIELDS)?
+            .split_ascii_whitespace()
+            .map(String::from)
+            .collect();
+        // the selected datetime columns, else the default datetime columns
+        let time_indexes: Vec<usize> = match columnar_time.is_csv() {
+            true => columnar_time.time_indexes(&fields),
+            false => None,
+        }
+        .or_else(|| {
+            W3C_TIME_COLUMNS_DEFAULT
+                .iter()
+                .find_map(|columns| {
+                    columns
+                        .iter()
+                        .map(|column| fields.iter().position(|field| field.eq_ignore_ascii_case(column)))
+                        .collect::<Option<Vec<usize>>>()
+                })
+        })?;
+        defñ!("fields {:?} time_indexes {:?}", fields, time_indexes);
+
+        Some(ColumnarHeader {
+            format: ColumnarFormat::W3c,
+            fields,
+            time_indexes,
+            delimiter: None,
+        })
+    }
+
+    /// Create a `ColumnarHeader` from the CSV header row `line`.
+    /// Datetime columns selected by number do not need a header row.
+    /// Return `None` if CSV files are not parsed or a datetime column name is
+    /// not found.
+    pub fn from_csv(
+        line: &str,
+        columnar_time: &ColumnarTime,
+    ) -> Option<ColumnarHeader> {
+        if !columnar_time.is_csv() {
+            return None;
+        }
+        let line: &str = line.trim_end_matches(['\r', '\n']);
+        let fields: Vec<String> = columnar_split(line, Some(columnar_time.delimiter))
+            .into_iter()
+            .map(|(beg, end)| String::from(line[beg..end].trim()))
+            .collect();
+        let time_indexes: Vec<usize> = columnar_time.time_indexes(&fields)?;
+        defñ!("fields {:?} time_indexes {:?}", fields, time_indexes);
+
+        Some(ColumnarHeader {
+            format: ColumnarFormat::Csv,
+            fields,
+            time_indexes,
+            delimiter: Some(columnar_time.delimiter),
+        })
+    }
+
+    /// Find the datetime of `line` by the datetime columns joined by a space.
+    /// Return the byte offsets of the datetime columns within `line` and the
+    /// datetime.
+    pub fn find_datetime(
+        &self,
+        line: &str,
+        tz_offset: &FixedOffset,
+    ) -> Option<(usize, usize, DateTimeL)> {
+        defn!("({:?})", line);
+        let line: &str = line.trim_end_matches(['\r', '\n']);
+        if line.starts_with('#') {
+            defx!("return None; directive or comment");
+            return None;
+        }
+        let spans: Vec<(usize, usize)> = columnar_split(line, self.delimiter);
+        let mut value: String = String::with_capacity(32);
+        let mut beg: usize = usize::MAX;
+        let mut end: usize = 0;
+        for index in self.time_indexes.iter() {
+            let (beg_, end_) = *spans.get(*index)?;
+            if !value.is_empty() {
+                value.push(' ');
+            }
+            value.push_str(line[beg_..end_].trim());
+            beg = beg.min(beg_);
+            end = end.max(end_);
+        }
+        let dt: DateTimeL = keyvalue_value_to_datetime(&value, None, tz_offset)?;
+        defx!("return ({}, {}, {:?})", beg, end, dt);
+
+        Some((beg, end, dt))
+    }
+}
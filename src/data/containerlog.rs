@@ -0,0 +1,504 @@
+// src/data/containerlog.rs
+
+//! Implement [`containerlog_parse`] to parse a line of a container log file,
+//! [`ContainerLogJoiner`] to gather the `Sysline`s of a container log message
+//! split into partial lines, and [`ContainerLog`] to join the partial lines of
+//! a container log message into one line.
+//!
+//! A Kubernetes [CRI] container log file, e.g. under `/var/log/pods`, looks
+//! like
+//!
+//! ```text
+//! 2024-01-02T03:04:05.123456789Z stdout P first part of a long line
+//! 2024-01-02T03:04:05.123456789Z stdout F , the last part
+//! 2024-01-02T03:04:06.000000000Z stderr F an error
+//! ```
+//!
+//! A line tagged `P` is a partial line continued by the following line of the
+//! same stream, up to the line tagged `F`.
+//!
+//! A Docker [json-file] container log file looks like
+//!
+//! ```text
+//! {"log":"first part of a long line","stream":"stdout","time":"2024-01-02T03:04:05.123456789Z"}
+//! {"log":", the last part\n","stream":"stdout","time":"2024-01-02T03:04:05.123456789Z"}
+//! ```
+//!
+//! Docker splits a long line into records of 16 KiB; a `log` value without
+//! an ending newline is a partial line continued by the following record of
+//! the same stream.
+//!
+//! The lines of the stdout and stderr streams are interleaved, so the line
+//! continuing a partial line is not necessarily the next line of the file.
+//!
+//! [CRI]: https://github.com/kubernetes/design-proposals-archive/blob/main/node/kubelet-cri-logging.md
+//! [json-file]: https://docs.docker.com/engine/logging/drivers/json-file/
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use ::chrono::{
+    DateTime,
+    FixedOffset,
+};
+use ::serde_json::{
+    Map,
+    Value,
+};
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::data::datetime::DateTimeL;
+use crate::data::jsonline::{
+    jsonline_parse,
+    jsonline_value_span,
+    jsonline_value_to_datetime,
+};
+use crate::data::line::Lines;
+use crate::data::sysline::{
+    Sysline,
+    SyslineP,
+};
+
+/// The beginning of a Docker json-file container log line.
+pub const CONTAINERLOG_DOCKER_PREFIX: &str = "{\"log\":";
+
+/// How lines of text files are parsed as container log lines.
+///
+/// Set by the `--container-logs` command-line option.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ::clap::ValueEnum)]
+pub enum ContainerLogMode {
+    /// If the first lines of the file are mostly container log lines then a
+    /// CRI or Docker json-file line is a container log line, other lines are
+    /// searched for a datetime as usual.
+    /// Otherwise lines are not parsed as container log lines.
+    #[default]
+    Auto,
+    /// Only a CRI or Docker json-file line begins a log message.
+    Always,
+    /// Lines are not parsed as container log lines.
+    Never,
+}
+
+impl fmt::Display for ContainerLogMode {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            ContainerLogMode::Auto => write!(f, "auto"),
+            ContainerLogMode::Always => write!(f, "always"),
+            ContainerLogMode::Never => write!(f, "never"),
+        }
+    }
+}
+
+/// The stream of a container log line.
+///
+/// Set by the `--container-stream` command-line option.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ::clap::ValueEnum)]
+pub enum ContainerStream {
+    Stdout,
+    Stderr,
+}
+
+impl ContainerStream {
+    /// Parse the `stream` of a container log line.
+    pub fn from_str_stream(stream: &str) -> Option<ContainerStream> {
+        match stream {
+            "stdout" => Some(ContainerStream::Stdout),
+            "stderr" => Some(ContainerStream::Stderr),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ContainerStream {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            ContainerStream::Stdout => write!(f, "stdout"),
+            ContainerStream::Stderr => write!(f, "stderr"),
+        }
+    }
+}
+
+/// The format of a container log line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContainerLogFormat {
+    /// Kubernetes CRI, e.g. containerd and CRI-O
+    Cri,
+    /// Docker json-file logging driver
+    Docker,
+}
+
+/// A parsed line of a container log file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContainerLogLine {
+    pub format: ContainerLogFormat,
+    pub stream: ContainerStream,
+    /// the line is continued by the following line of the same stream
+    pub partial: bool,
+    pub dt: DateTimeL,
+    /// the byte offset of the beginning of the datetime
+    pub dt_beg: usize,
+    /// the byte offset of the end of the datetime
+    pub dt_end: usize,
+    /// the datetime substring of a CRI line
+    pub time: String,
+    /// the logged text without the ending newline of a Docker line
+    pub content: String,
+    /// the JSON object of a Docker line
+    pub object: Option<Map<String, Value>>,
+}
+
+impl ContainerLogLine {
+    /// Is `self` continued by `next`?
+    pub fn is_continued_by(
+        &self,
+        next: &ContainerLogLine,
+    ) -> bool {
+        self.partial && self.format == next.format && self.stream == next.stream
+    }
+
+    /// The line of `self`; the CRI tag is `F` unless `self` is partial.
+    pub fn to_line(&self) -> String {
+        match self.format {
+            ContainerLogFormat::Cri => format!(
+                "{} {} {} {}",
+                self.time,
+                self.stream,
+                if self.partial { 'P' } else { 'F' },
+                self.content,
+            ),
+            ContainerLogFormat::Docker => {
+                let mut object: Map<String, Value> = self.object.clone().unwrap_or_default();
+                let mut log: String = self.content.clone();
+                if !self.partial {
+                    log.push('\n');
+                }
+                object.insert(String::from("log"), Value::String(log));
+                Value::Object(object).to_string()
+            }
+        }
+    }
+}
+
+/// Parse a CRI container log `line`, e.g.
+/// `2024-01-02T03:04:05.123456789Z stdout P text`.
+fn containerlog_parse_cri(line: &str) -> Option<ContainerLogLine> {
+    if !line.as_bytes().first()?.is_ascii_digit() {
+        return None;
+    }
+    let mut parts = line.splitn(4, ' ');
+    let time: &str = parts.next()?;
+    let stream: ContainerStream = ContainerStream::from_str_stream(parts.next()?)?;
+    // the tag may be followed by more tags separated by `:`
+    let partial: bool = match parts.next()?.split(':').next()? {
+        "P" => true,
+        "F" => false,
+        _ => return None,
+    };
+    let content: &str = parts.next().unwrap_or_default();
+    let dt: DateTimeL = DateTime::<FixedOffset>::parse_from_rfc3339(time).ok()?;
+
+    Some(ContainerLogLine {
+        format: ContainerLogFormat::Cri,
+        stream,
+        partial,
+        dt,
+        dt_beg: 0,
+        dt_end: time.len(),
+        time: String::from(time),
+        content: String::from(content),
+        object: None,
+    })
+}
+
+/// Parse a Docker json-file container log `line`, e.g.
+/// `{"log":"text\n","stream":"stdout","time":"2024-01-02T03:04:05Z"}`.
+fn containerlog_parse_docker(
+    line: &str,
+    tz_offset: &FixedOffset,
+) -> Option<ContainerLogLine> {
+    if !line.starts_with(CONTAINERLOG_DOCKER_PREFIX) {
+        return None;
+    }
+    let object: Map<String, Value> = jsonline_parse(line)?;
+    let log: &str = object.get("log")?.as_str()?;
+    let stream: ContainerStream = ContainerStream::from_str_stream(object.get("stream")?.as_str()?)?;
+    let dt: DateTimeL = jsonline_value_to_datetime(object.get("time")?, tz_offset)?;
+    let (dt_beg, dt_end) = jsonline_value_span(line, "/time")?;
+    let (content, partial): (&str, bool) = match log.strip_suffix('\n') {
+        Some(content) => (content, false),
+        None => (log, true),
+    };
+
+    Some(ContainerLogLine {
+        format: ContainerLogFormat::Docker,
+        stream,
+        partial,
+        dt,
+        dt_beg,
+        dt_end,
+        time: String::with_capacity(0),
+        content: String::from(content),
+        object: Some(object),
+    })
+}
+
+/// Parse a CRI or Docker json-file container log `line`.
+/// The ending newline of `line` is ignored.
+pub fn containerlog_parse(
+    line: &str,
+    tz_offset: &FixedOffset,
+) -> Option<ContainerLogLine> {
+    let line: &str = line.trim_end_matches(['\r', '\n']);
+    match line.starts_with('{') {
+        true => containerlog_parse_docker(line, tz_offset),
+        false => containerlog_parse_cri(line),
+    }
+}
+
+/// Parse the first `Line` of `syslinep` as a CRI or Docker json-file
+/// container log line.
+pub fn containerlog_parse_sysline(syslinep: &SyslineP) -> Option<ContainerLogLine> {
+    let linep = syslinep.lines.first()?;
+    let bytes: Vec<u8> = linep
+        .lineparts
+        .iter()
+        .flat_map(|linepart| linepart.as_slice().iter())
+        .copied()
+        .collect();
+    let line: &str = std::str::from_utf8(&bytes).ok()?;
+
+    containerlog_parse(line, syslinep.dt().offset())
+}
+
+/// A container log message held by a [`ContainerLogJoiner`].
+#[derive(Debug)]
+struct ContainerLogHeld {
+    /// the `Sysline`s of the message, in file order
+    syslines: Vec<SyslineP>,
+    /// the format and stream of a partial message, waiting for the lines
+    /// continuing it
+    pending: Option<(ContainerLogFormat, ContainerStream)>,
+}
+
+impl ContainerLogHeld {
+    /// One `Sysline` of the `Line`s of all `self.syslines`, with the datetime
+    /// of the first `Sysline`.
+    fn into_syslinep(mut self) -> SyslineP {
+        if self.syslines.len() == 1 {
+            return self.syslines.pop().unwrap();
+        }
+        let first: &SyslineP = &self.syslines[0];
+        let lines: Lines = self
+            .syslines
+            .iter()
+            .flat_map(|syslinep| syslinep.lines.iter().cloned())
+            .collect();
+
+        SyslineP::new(Sysline::from_parts_with_offsets(
+            lines,
+            first.encoding_type,
+            first.dt_beg_actual,
+            first.dt_end_actual,
+            first.dt_beg_utf8,
+            first.dt_end_utf8,
+            *first.dt(),
+        ))
+    }
+}
+
+/// Gather the `Sysline` of a partial container log line with the `Sysline`s
+/// continuing it, the following lines of the same format and stream, into
+/// one `Sysline`. One partial message is pending per stream.
+///
+/// The `Sysline`s of a file are pushed in file order and released in file
+/// order of the first line of each message, so a `Sysline` after a pending
+/// message is held until the pending message ends. At most
+/// [`ContainerLogJoiner::HELD_MAX`] `Sysline`s are held; beyond that the
+/// oldest pending message is released as it is.
+#[derive(Debug, Default)]
+pub struct ContainerLogJoiner {
+    held: VecDeque<ContainerLogHeld>,
+    /// `Sysline`s held in `held`
+    count: usize,
+}
+
+impl ContainerLogJoiner {
+    /// Maximum number of held `Sysline`s.
+    pub const HELD_MAX: usize = 64;
+
+    pub fn new() -> ContainerLogJoiner {
+        ContainerLogJoiner::default()
+    }
+
+    /// Push the next `Sysline` of the file. Return the released `Sysline`s,
+    /// in file order.
+    pub fn push(
+        &mut self,
+        syslinep: SyslineP,
+    ) -> Vec<SyslineP> {
+        let parsed: Option<ContainerLogLine> = containerlog_parse_sysline(&syslinep);
+        defn!("({:?})", parsed.as_ref().map(|parsed| (parsed.format, parsed.stream, parsed.partial)));
+        match parsed {
+            Some(parsed) => {
+                let key = Some((parsed.format, parsed.stream));
+                match self
+                    .held
+                    .iter_mut()
+                    .find(|held| held.pending == key)
+                {
+                    Some(held) => {
+                        held.syslines.push(syslinep);
+                        if !parsed.partial {
+                            held.pending = None;
+                        }
+                    }
+                    None => {
+                        self.held.push_back(ContainerLogHeld {
+                            syslines: vec![syslinep],
+                            pending: key.filter(|_| parsed.partial),
+                        });
+                    }
+                }
+            }
+            None => {
+                self.held.push_back(ContainerLogHeld {
+                    syslines: vec![syslinep],
+                    pending: None,
+                });
+            }
+        }
+        self.count += 1;
+        let released: Vec<SyslineP> = self.release(Self::HELD_MAX);
+        defx!("released {}, held {}", released.len(), self.count);
+
+        released
+    }
+
+    /// Release all held `Sysline`s, in file order.
+    pub fn finish(&mut self) -> Vec<SyslineP> {
+        self.release(0)
+    }
+
+    /// Release the messages at the front that are not pending, and the
+    /// oldest messages while more than `max` `Sysline`s are held.
+    fn release(
+        &mut self,
+        max: usize,
+    ) -> Vec<SyslineP> {
+        let mut released: Vec<SyslineP> = Vec::new();
+        while let Some(held) = self.held.front() {
+            if held.pending.is_some() && self.count <= max {
+                break;
+            }
+            let held: ContainerLogHeld = self.held.pop_front().unwrap();
+            self.count -= held.syslines.len();
+            released.push(held.into_syslinep());
+        }
+
+        released
+    }
+
+    /// Count of held `Sysline`s.
+    pub fn count_held(&self) -> usize {
+        self.count
+    }
+}
+
+/// A container log message with the partial lines joined into one line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContainerLog {
+    pub dt: DateTimeL,
+    /// the stream of the first line, if it is a container log line
+    pub stream: Option<ContainerStream>,
+    /// the lines without the ending newline
+    pub lines: Vec<String>,
+    /// were partial lines joined?
+    pub joined: bool,
+}
+
+impl ContainerLog {
+    /// Create a `ContainerLog` from the `text` of a `Sysline` at datetime
+    /// `dt`, joining each partial line with the lines continuing it.
+    pub fn new(
+        dt: DateTimeL,
+        text: &str,
+    ) -> ContainerLog {
+        defn!("({:?})", text);
+        let tz_offset: FixedOffset = *dt.offset();
+        let mut stream: Option<ContainerStream> = None;
+        let mut lines: Vec<String> = Vec::new();
+        let mut joined: bool = false;
+        let mut pending: Option<ContainerLogLine> = None;
+        for (at, line) in text.lines().enumerate() {
+            let line: &str = line.trim_end_matches('\r');
+            let parsed: Option<ContainerLogLine> = containerlog_parse(line, &tz_offset);
+            if at == 0 {
+                stream = parsed.as_ref().map(|parsed| parsed.stream);
+            }
+            match (pending.as_mut(), parsed) {
+                (Some(pending_), Some(parsed)) if pending_.is_continued_by(&parsed) => {
+                    pending_.content.push_str(&parsed.content);
+                    pending_.partial = parsed.partial;
+                    joined = true;
+                }
+                (_, Some(parsed)) => {
+                    if let Some(pending_) = pending.take() {
+                        lines.push(pending_.to_line());
+                    }
+                    pending = Some(parsed);
+                }
+                (_, None) => {
+                    if let Some(pending_) = pending.take() {
+                        lines.push(pending_.to_line());
+                    }
+                    lines.push(String::from(line));
+                }
+            }
+        }
+        if let Some(pending_) = pending.take() {
+            lines.push(pending_.to_line());
+        }
+        defx!("stream {:?}, joined {}", stream, joined);
+
+        ContainerLog {
+            dt,
+            stream,
+            lines,
+            joined,
+        }
+    }
+
+    /// Create a `ContainerLog` from a `Sysline`.
+    pub fn from_syslinep(syslinep: &SyslineP) -> ContainerLog {
+        let text: String = String::from_utf8_lossy(&syslinep.to_bytes()).into_owned();
+
+        ContainerLog::new(*syslinep.dt(), &text)
+    }
+
+    pub const fn dt(&self) -> &DateTimeL {
+        &self.dt
+    }
+
+    /// Does the stream of `self` match `stream`? A `None` matches all.
+    pub fn matches(
+        &self,
+        stream: Option<ContainerStream>,
+    ) -> bool {
+        match stream {
+            Some(stream) => self.stream == Some(stream),
+            None => true,
+        }
+    }
+}
@@ -1961,6 +1961,19 @@ pub fn bytes_to_regex_to_datetime(
             return None;
         }
     };
+    // `captures_to_buffer_bytes` wrote whole seconds of the uptime plus
+    // `systemtime_at_uptime_zero`, so add back the fractional second of
+    // `systemtime_at_uptime_zero`, e.g. of an X.Org server log header time
+    let dt = match (&dtpd.dtfs.uptime, systemtime_at_uptime_zero) {
+        (DTFS_Uptime::u, Some(uptime_zero)) => {
+            let nanos: u32 = uptime_zero
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |dur| dur.subsec_nanos());
+
+            dt + Duration::nanoseconds(nanos as i64)
+        }
+        _ => dt,
+    };
 
     // derive the `LineIndex` bounds of the datetime substring within `data`
     let cgi_first: LineIndex = match GROUP_NAMES_MAP_STR
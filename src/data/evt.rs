@@ -0,0 +1,412 @@
+// src/data/evt.rs
+
+//! Implement [`EvtRecord`] for the legacy Windows Event Log [`.evt` format]
+//! `EVENTLOGRECORD` structure.
+//!
+//! An `EvtRecord` is rendered in the same forms as an `.evtx` record so it may
+//! be filtered by an [`EvtxFilter`] and printed as an [`Evtx`].
+//!
+//! [`.evt` format]: https://github.com/libyal/libevt/blob/main/documentation/Windows%20Event%20Log%20(EVT)%20format.asciidoc
+//! [`EvtxFilter`]: crate::data::evtx::EvtxFilter
+//! [`Evtx`]: crate::data::evtx::Evtx
+
+use std::fmt::Write;
+
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::common::NLc;
+use crate::data::evtx::{
+    json_value_to_short,
+    EvtxFilter,
+    EvtxOutput,
+    EvtxRS,
+    RecordId,
+    Timestamp,
+};
+
+/// Signature of the file header and every `EVENTLOGRECORD`, `"LfLe"`.
+pub const EVT_SIGNATURE: &[u8; 4] = b"LfLe";
+/// Size of the fixed part of an `EVENTLOGRECORD`, before the source name.
+pub const EVT_RECORD_HEADER_SIZE: usize = 56;
+/// Offset of the signature within an `EVENTLOGRECORD`.
+pub const EVT_RECORD_SIGNATURE_OFFSET: usize = 4;
+
+/// `EventType` values.
+const EVENTLOG_ERROR_TYPE: u16 = 0x0001;
+const EVENTLOG_WARNING_TYPE: u16 = 0x0002;
+const EVENTLOG_AUDIT_SUCCESS: u16 = 0x0008;
+const EVENTLOG_AUDIT_FAILURE: u16 = 0x0010;
+
+/// `System/Keywords` used by Windows when it renders a classic event.
+const KEYWORDS_CLASSIC: &str = "0x80000000000000";
+const KEYWORDS_AUDIT_SUCCESS: &str = "0x8020000000000000";
+const KEYWORDS_AUDIT_FAILURE: &str = "0x8010000000000000";
+
+const XML_DECLARATION: &str = r#"<?xml version="1.0" encoding="utf-8"?>"#;
+const XML_EVENT_NAMESPACE: &str = "http://schemas.microsoft.com/win/2004/08/events/event";
+
+fn u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    let b = data.get(offset..offset + 2)?;
+
+    Some(u16::from_le_bytes([b[0], b[1]]))
+}
+
+pub(crate) fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    let b = data.get(offset..offset + 4)?;
+
+    Some(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// Read a NUL-terminated UTF-16LE string at `offset`. Return the string and
+/// the offset after the NUL.
+fn utf16_at(data: &[u8], offset: usize) -> Option<(String, usize)> {
+    let mut units: Vec<u16> = Vec::new();
+    let mut at: usize = offset;
+    loop {
+        let unit: u16 = u16_at(data, at)?;
+        at += 2;
+        if unit == 0 {
+            break;
+        }
+        units.push(unit);
+    }
+
+    Some((String::from_utf16_lossy(&units), at))
+}
+
+/// Convert a binary [SID] to the string form, e.g. `S-1-5-18`.
+///
+/// [SID]: https://learn.microsoft.com/en-us/windows/win32/secauthz/sid-components
+pub fn sid_to_string(sid: &[u8]) -> Option<String> {
+    let revision: u8 = *sid.first()?;
+    let count: usize = *sid.get(1)? as usize;
+    if sid.len() != 8 + count * 4 {
+        return None;
+    }
+    let authority: u64 = sid[2..8]
+        .iter()
+        .fold(0u64, |a, b| (a << 8) | u64::from(*b));
+    let mut s: String = format!("S-{}-{}", revision, authority);
+    for i in 0..count {
+        let sub: u32 = u32_at(sid, 8 + i * 4)?;
+        write!(s, "-{}", sub).ok()?;
+    }
+
+    Some(s)
+}
+
+/// Escape `value` for XML text or an XML attribute value.
+fn push_xml_escaped(
+    buffer: &mut String,
+    value: &str,
+) {
+    for c in value.chars() {
+        match c {
+            '&' => buffer.push_str("&amp;"),
+            '<' => buffer.push_str("&lt;"),
+            '>' => buffer.push_str("&gt;"),
+            '"' => buffer.push_str("&quot;"),
+            '\'' => buffer.push_str("&apos;"),
+            c => buffer.push(c),
+        }
+    }
+}
+
+/// A decoded legacy Windows Event Log `EVENTLOGRECORD`.
+///
+/// See [EVENTLOGRECORD structure].
+///
+/// [EVENTLOGRECORD structure]: https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-eventlogrecord
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EvtRecord {
+    pub record_number: u32,
+    /// Seconds since the Unix epoch when the event was submitted.
+    pub time_generated: u32,
+    /// Seconds since the Unix epoch when the event was written to the file.
+    pub time_written: u32,
+    /// The full event identifier; the upper 16 bits are the "qualifiers".
+    pub event_id: u32,
+    pub event_type: u16,
+    pub event_category: u16,
+    pub source_name: String,
+    pub computer_name: String,
+    /// The user SID in string form, if any.
+    pub user_sid: Option<String>,
+    /// The insertion strings.
+    pub strings: Vec<String>,
+    /// The event-specific binary data.
+    pub data: Vec<u8>,
+}
+
+impl EvtRecord {
+    /// Decode the `EVENTLOGRECORD` in `record`. `record` must be exactly the
+    /// record length.
+    ///
+    /// Returns `None` if the record is not valid.
+    pub fn from_bytes(record: &[u8]) -> Option<EvtRecord> {
+        let length: usize = u32_at(record, 0)? as usize;
+        if length != record.len() || length < EVT_RECORD_HEADER_SIZE + 4 {
+            return None;
+        }
+        if &record[EVT_RECORD_SIGNATURE_OFFSET..EVT_RECORD_SIGNATURE_OFFSET + 4] != EVT_SIGNATURE {
+            return None;
+        }
+        if u32_at(record, length - 4)? as usize != length {
+            return None;
+        }
+        // the variable-sized fields may not overlap the trailing length copy
+        let body: &[u8] = &record[..length - 4];
+        let record_number: u32 = u32_at(body, 8)?;
+        let time_generated: u32 = u32_at(body, 12)?;
+        let time_written: u32 = u32_at(body, 16)?;
+        let event_id: u32 = u32_at(body, 20)?;
+        let event_type: u16 = u16_at(body, 24)?;
+        let num_strings: u16 = u16_at(body, 26)?;
+        let event_category: u16 = u16_at(body, 28)?;
+        let string_offset: usize = u32_at(body, 36)? as usize;
+        let user_sid_length: usize = u32_at(body, 40)? as usize;
+        let user_sid_offset: usize = u32_at(body, 44)? as usize;
+        let data_length: usize = u32_at(body, 48)? as usize;
+        let data_offset: usize = u32_at(body, 52)? as usize;
+
+        let (source_name, at) = utf16_at(body, EVT_RECORD_HEADER_SIZE)?;
+        let (computer_name, _at) = utf16_at(body, at)?;
+        let user_sid: Option<String> = match user_sid_length {
+            0 => None,
+            _ => sid_to_string(body.get(user_sid_offset..user_sid_offset.checked_add(user_sid_length)?)?),
+        };
+        let mut strings: Vec<String> = Vec::with_capacity(num_strings as usize);
+        let mut at: usize = string_offset;
+        for _ in 0..num_strings {
+            let (s, at_) = utf16_at(body, at)?;
+            strings.push(s);
+            at = at_;
+        }
+        let data: Vec<u8> = body
+            .get(data_offset..data_offset.checked_add(data_length)?)?
+            .to_vec();
+
+        Some(EvtRecord {
+            record_number,
+            time_generated,
+            time_written,
+            event_id,
+            event_type,
+            event_category,
+            source_name,
+            computer_name,
+            user_sid,
+            strings,
+            data,
+        })
+    }
+
+    /// The `System/EventID` value; the lower 16 bits of `event_id`.
+    pub const fn event_id_code(&self) -> u32 {
+        self.event_id & 0xFFFF
+    }
+
+    /// The `System/EventID[@Qualifiers]` value; the upper 16 bits of
+    /// `event_id`.
+    pub const fn event_id_qualifiers(&self) -> u32 {
+        self.event_id >> 16
+    }
+
+    /// The `System/Level` equivalent of `event_type`.
+    pub const fn level(&self) -> u64 {
+        match self.event_type {
+            EVENTLOG_ERROR_TYPE => 2,
+            EVENTLOG_WARNING_TYPE => 3,
+            EVENTLOG_AUDIT_SUCCESS | EVENTLOG_AUDIT_FAILURE => 0,
+            // `EVENTLOG_INFORMATION_TYPE` and `EVENTLOG_SUCCESS`
+            _ => 4,
+        }
+    }
+
+    /// The `System/Keywords` equivalent of `event_type`.
+    pub const fn keywords(&self) -> &'static str {
+        match self.event_type {
+            EVENTLOG_AUDIT_SUCCESS => KEYWORDS_AUDIT_SUCCESS,
+            EVENTLOG_AUDIT_FAILURE => KEYWORDS_AUDIT_FAILURE,
+            _ => KEYWORDS_CLASSIC,
+        }
+    }
+
+    /// `time_generated` as a [`Timestamp`].
+    pub fn timestamp(&self) -> Timestamp {
+        Timestamp::from_second(i64::from(self.time_generated)).unwrap_or_default()
+    }
+
+    /// `time_written` as a [`Timestamp`].
+    pub fn timestamp_written(&self) -> Timestamp {
+        Timestamp::from_second(i64::from(self.time_written)).unwrap_or_default()
+    }
+
+    /// `data` as upper-case hexadecimal, as written by crate `evtx` for
+    /// `EventData/Binary`.
+    fn data_hex(&self) -> String {
+        let mut s: String = String::with_capacity(self.data.len() * 2);
+        for b in self.data.iter() {
+            write!(s, "{:02X}", b).unwrap_or_default();
+        }
+
+        s
+    }
+
+    /// Create the JSON value in the shape written by crate `evtx`.
+    ///
+    /// `.evt` records do not store a channel so `channel` is passed by the
+    /// caller.
+    /// `System/TimeWritten` is not part of the `.evtx` schema.
+    pub fn to_json_value(
+        &self,
+        channel: &str,
+    ) -> ::serde_json::Value {
+        let mut system = ::serde_json::Map::new();
+        system.insert(
+            String::from("Provider"),
+            ::serde_json::json!({"#attributes": {"Name": self.source_name}}),
+        );
+        system.insert(
+            String::from("EventID"),
+            ::serde_json::json!({
+                "#attributes": {"Qualifiers": self.event_id_qualifiers()},
+                "#text": self.event_id_code(),
+            }),
+        );
+        system.insert(String::from("Level"), ::serde_json::json!(self.level()));
+        system.insert(String::from("Task"), ::serde_json::json!(self.event_category));
+        system.insert(String::from("Keywords"), ::serde_json::json!(self.keywords()));
+        system.insert(
+            String::from("TimeCreated"),
+            ::serde_json::json!({"#attributes": {"SystemTime": self.timestamp().to_string()}}),
+        );
+        system.insert(
+            String::from("TimeWritten"),
+            ::serde_json::json!({"#attributes": {"SystemTime": self.timestamp_written().to_string()}}),
+        );
+        system.insert(String::from("EventRecordID"), ::serde_json::json!(self.record_number));
+        system.insert(String::from("Channel"), ::serde_json::json!(channel));
+        system.insert(String::from("Computer"), ::serde_json::json!(self.computer_name));
+        if let Some(sid) = self.user_sid.as_ref() {
+            system.insert(
+                String::from("Security"),
+                ::serde_json::json!({"#attributes": {"UserID": sid}}),
+            );
+        }
+        let mut event = ::serde_json::Map::new();
+        event.insert(
+            String::from("#attributes"),
+            ::serde_json::json!({"xmlns": XML_EVENT_NAMESPACE}),
+        );
+        event.insert(String::from("System"), ::serde_json::Value::Object(system));
+        let mut eventdata = ::serde_json::Map::new();
+        if !self.strings.is_empty() {
+            eventdata.insert(String::from("Data"), ::serde_json::json!({"#text": self.strings}));
+        }
+        if !self.data.is_empty() {
+            eventdata.insert(String::from("Binary"), ::serde_json::json!(self.data_hex()));
+        }
+        if !eventdata.is_empty() {
+            event.insert(String::from("EventData"), ::serde_json::Value::Object(eventdata));
+        }
+
+        ::serde_json::json!({"Event": event})
+    }
+
+    /// Create the XML in the layout written by crate `evtx`.
+    pub fn to_xml(
+        &self,
+        channel: &str,
+    ) -> String {
+        let mut x: String = String::with_capacity(1024);
+        x.push_str(XML_DECLARATION);
+        x.push(NLc);
+        writeln!(x, "<Event xmlns=\"{}\">", XML_EVENT_NAMESPACE).unwrap_or_default();
+        x.push_str("  <System>\n    <Provider Name=\"");
+        push_xml_escaped(&mut x, &self.source_name);
+        x.push_str("\">\n    </Provider>\n");
+        writeln!(
+            x,
+            "    <EventID Qualifiers=\"{}\">{}</EventID>",
+            self.event_id_qualifiers(),
+            self.event_id_code(),
+        ).unwrap_or_default();
+        writeln!(x, "    <Level>{}</Level>", self.level()).unwrap_or_default();
+        writeln!(x, "    <Task>{}</Task>", self.event_category).unwrap_or_default();
+        writeln!(x, "    <Keywords>{}</Keywords>", self.keywords()).unwrap_or_default();
+        writeln!(x, "    <TimeCreated SystemTime=\"{}\">\n    </TimeCreated>", self.timestamp()).unwrap_or_default();
+        writeln!(x, "    <TimeWritten SystemTime=\"{}\">\n    </TimeWritten>", self.timestamp_written()).unwrap_or_default();
+        writeln!(x, "    <EventRecordID>{}</EventRecordID>", self.record_number).unwrap_or_default();
+        x.push_str("    <Channel>");
+        push_xml_escaped(&mut x, channel);
+        x.push_str("</Channel>\n    <Computer>");
+        push_xml_escaped(&mut x, &self.computer_name);
+        x.push_str("</Computer>\n");
+        if let Some(sid) = self.user_sid.as_ref() {
+            writeln!(x, "    <Security UserID=\"{}\">\n    </Security>", sid).unwrap_or_default();
+        }
+        x.push_str("  </System>\n");
+        if !self.strings.is_empty() || !self.data.is_empty() {
+            x.push_str("  <EventData>\n");
+            for s in self.strings.iter() {
+                x.push_str("    <Data>");
+                push_xml_escaped(&mut x, s);
+                x.push_str("</Data>\n");
+            }
+            if !self.data.is_empty() {
+                writeln!(x, "    <Binary>{}</Binary>", self.data_hex()).unwrap_or_default();
+            }
+            x.push_str("  </EventData>\n");
+        }
+        x.push_str("</Event>\n");
+
+        x
+    }
+
+    /// Convert to an [`EvtxRS`] in the form of `evtx_output`.
+    /// Return `None` if the record does not pass `evtx_filter`.
+    pub fn to_evtxrs(
+        &self,
+        channel: &str,
+        evtx_output: EvtxOutput,
+        evtx_filter: &EvtxFilter,
+    ) -> Option<EvtxRS> {
+        let timestamp: Timestamp = self.timestamp();
+        let value: Option<::serde_json::Value> = match (evtx_output, evtx_filter.is_empty()) {
+            (EvtxOutput::Xml, true) => None,
+            _ => Some(self.to_json_value(channel)),
+        };
+        if let Some(value) = value.as_ref()
+            && !evtx_filter.matches(value)
+        {
+            return None;
+        }
+        let data: String = match (evtx_output, value) {
+            (EvtxOutput::Json, Some(value)) => {
+                let mut data: String = value.to_string();
+                data.push(NLc);
+                data
+            }
+            (EvtxOutput::JsonPretty, Some(value)) => {
+                let mut data: String = ::serde_json::to_string_pretty(&value).unwrap_or_default();
+                data.push(NLc);
+                data
+            }
+            (EvtxOutput::Short, Some(value)) => json_value_to_short(&value, &timestamp),
+            (EvtxOutput::Xml, _) | (_, None) => self.to_xml(channel),
+        };
+
+        Some(EvtxRS {
+            event_record_id: self.record_number as RecordId,
+            timestamp,
+            data,
+        })
+    }
+}
@@ -10,9 +10,11 @@ use std::io::{
     Error,
     ErrorKind,
 };
+use std::str::FromStr;
 
 pub(crate) use ::evtx::{
     err::EvtxError,
+    EvtxRecord,
     SerializedEvtxRecord,
     Timestamp,
 };
@@ -71,9 +73,561 @@ pub type EvtxRS = SerializedEvtxRecord<String>;
 ///
 /// [`Result`]: std::result::Result
 pub type ResultEvtxRS = std::result::Result<EvtxRS, EvtxError>;
+/// [`Result`] of an optional [`EvtxRS`]; `None` if rejected by an
+/// [`EvtxFilter`].
+///
+/// [`Result`]: std::result::Result
+pub type ResultEvtxRSOpt = std::result::Result<Option<EvtxRS>, EvtxError>;
+
+/// [`SerializedEvtxRecord`] with [`serde_json::Value`] as the data type.
+///
+/// [`SerializedEvtxRecord`]: https://docs.rs/evtx/0.8.1/evtx/struct.SerializedEvtxRecord.html
+/// [`serde_json::Value`]: https://docs.rs/serde_json/latest/serde_json/enum.Value.html
+pub type EvtxRSJson = SerializedEvtxRecord<::serde_json::Value>;
 
 const TIMECREATED_BEG_SUBSTR: &str = "<TimeCreated SystemTime=\"";
 const TIMECREATED_END_SUBCHAR: char = '\"';
+/// JSON key of the `TimeCreated` attribute, e.g.
+/// `"TimeCreated": { "#attributes": { "SystemTime": "2023-03-16T20:20:23.130640Z" } }`
+const SYSTEMTIME_JSON_KEY: &str = "\"SystemTime\":";
+/// JSON key of XML attributes written by crate `evtx`.
+const JSON_ATTRIBUTES_KEY: &str = "#attributes";
+/// JSON key of XML text written by crate `evtx`.
+const JSON_TEXT_KEY: &str = "#text";
+
+/// The format of the printed [`Evtx`].
+///
+/// Set by the `--evtx-output` command-line option.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ::clap::ValueEnum)]
+pub enum EvtxOutput {
+    /// The XML of the record, as written by `evtx_dump`.
+    #[default]
+    Xml,
+    /// The record as JSON on one line.
+    Json,
+    /// The record as indented JSON.
+    JsonPretty,
+    /// One line derived from the `System` and `EventData` sections, e.g.
+    ///
+    /// ```text
+    /// 2023-03-16T20:20:23.130640Z host1 OpenSSH[2]: process=sshd.exe payload="error: kex_exchange_identification: Connection closed by remote host"
+    /// ```
+    Short,
+}
+
+impl fmt::Display for EvtxOutput {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            EvtxOutput::Xml => write!(f, "xml"),
+            EvtxOutput::Json => write!(f, "json"),
+            EvtxOutput::JsonPretty => write!(f, "json-pretty"),
+            EvtxOutput::Short => write!(f, "short"),
+        }
+    }
+}
+
+impl FromStr for EvtxOutput {
+    type Err = ();
+    fn from_str(input: &str) -> std::result::Result<EvtxOutput, Self::Err> {
+        match input {
+            "xml" => Ok(EvtxOutput::Xml),
+            "json" => Ok(EvtxOutput::Json),
+            "json-pretty" => Ok(EvtxOutput::JsonPretty),
+            "short" => Ok(EvtxOutput::Short),
+            _ => Err(()),
+        }
+    }
+}
+
+impl EvtxOutput {
+    pub fn iterator() -> std::slice::Iter<'static, EvtxOutput> {
+        static EVTX_OUTPUTS: [EvtxOutput; (EvtxOutput::Short as usize) + 1] = [
+            EvtxOutput::Xml,
+            EvtxOutput::Json,
+            EvtxOutput::JsonPretty,
+            EvtxOutput::Short,
+        ];
+        EVTX_OUTPUTS.iter()
+    }
+}
+
+/// Return the JSON value at `keys` within `value`.
+fn json_get<'a>(
+    value: &'a ::serde_json::Value,
+    keys: &[&str],
+) -> Option<&'a ::serde_json::Value> {
+    let mut v: &::serde_json::Value = value;
+    for key in keys.iter() {
+        v = v.get(key)?;
+    }
+
+    Some(v)
+}
+
+/// Return the JSON value as a plain `String`. An XML element with attributes
+/// is written by crate `evtx` as an object with the element text at key
+/// `"#text"`.
+fn json_to_string(value: &::serde_json::Value) -> String {
+    match value {
+        ::serde_json::Value::Null => String::new(),
+        ::serde_json::Value::String(s) => s.clone(),
+        ::serde_json::Value::Object(map) => match map.get(JSON_TEXT_KEY) {
+            Some(v) => json_to_string(v),
+            None => value.to_string(),
+        },
+        ::serde_json::Value::Array(arr) => arr
+            .iter()
+            .map(json_to_string)
+            .collect::<Vec<String>>()
+            .join(","),
+        _ => value.to_string(),
+    }
+}
+
+/// Write `value` to `buffer` so it remains one `key=value` token on one line;
+/// quote values with whitespace, quotes, or `=`, and escape control
+/// characters.
+fn push_short_value(
+    buffer: &mut String,
+    value: &str,
+) {
+    let quote: bool = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || c == '"' || c == '=');
+    if quote {
+        buffer.push('"');
+    }
+    for c in value.chars() {
+        match c {
+            '"' if quote => buffer.push_str("\\\""),
+            '\\' if quote => buffer.push_str("\\\\"),
+            '\n' => buffer.push_str("\\n"),
+            '\r' => buffer.push_str("\\r"),
+            '\t' => buffer.push_str("\\t"),
+            c if c.is_control() => buffer.push_str(&c.escape_unicode().to_string()),
+            c => buffer.push(c),
+        }
+    }
+    if quote {
+        buffer.push('"');
+    }
+}
+
+/// Write the `key=value` pairs of `value` to `buffer`. Nested objects use
+/// dotted keys. XML attributes are skipped.
+fn push_short_pairs(
+    buffer: &mut String,
+    prefix: &str,
+    value: &::serde_json::Value,
+) {
+    match value {
+        ::serde_json::Value::Object(map) => {
+            for (key, v) in map.iter() {
+                if key == JSON_ATTRIBUTES_KEY {
+                    continue;
+                }
+                if key == JSON_TEXT_KEY {
+                    push_short_pairs(buffer, prefix, v);
+                    continue;
+                }
+                let key_: String = match prefix.is_empty() {
+                    true => key.clone(),
+                    false => format!("{}.{}", prefix, key),
+                };
+                push_short_pairs(buffer, &key_, v);
+            }
+        }
+        _ => {
+            buffer.push(' ');
+            buffer.push_str(prefix);
+            buffer.push('=');
+            push_short_value(buffer, &json_to_string(value));
+        }
+    }
+}
+
+/// Create the [`EvtxOutput::Short`] form of an `evtx` JSON record.
+///
+/// ```text
+/// TIMESTAMP COMPUTER PROVIDER[EventID]: key=value...
+/// ```
+///
+/// The `key=value` pairs are from the `EventData` section, or if not present
+/// then the `UserData` section.
+pub fn json_value_to_short(
+    value: &::serde_json::Value,
+    timestamp: &Timestamp,
+) -> String {
+    let mut buffer: String = String::with_capacity(256);
+    let system: Option<&::serde_json::Value> = json_get(value, &["Event", "System"]);
+    match system.and_then(|v| json_get(v, &["TimeCreated", JSON_ATTRIBUTES_KEY, "SystemTime"])) {
+        Some(::serde_json::Value::String(s)) => buffer.push_str(s),
+        _ => buffer.push_str(&timestamp.to_string()),
+    }
+    if let Some(computer) = system.and_then(|v| v.get("Computer")) {
+        buffer.push(' ');
+        buffer.push_str(&json_to_string(computer));
+    }
+    let provider: Option<&::serde_json::Value> =
+        system.and_then(|v| json_get(v, &["Provider", JSON_ATTRIBUTES_KEY, "Name"]));
+    let event_id: Option<&::serde_json::Value> = system.and_then(|v| v.get("EventID"));
+    if provider.is_some() || event_id.is_some() {
+        buffer.push(' ');
+    }
+    if let Some(provider) = provider {
+        buffer.push_str(&json_to_string(provider));
+    }
+    if let Some(event_id) = event_id {
+        buffer.push('[');
+        buffer.push_str(&json_to_string(event_id));
+        buffer.push(']');
+    }
+    let len_head: usize = buffer.len();
+    match json_get(value, &["Event", "EventData"]) {
+        Some(eventdata) => push_short_pairs(&mut buffer, "", eventdata),
+        None => {
+            // `UserData` has one child element named by the provider
+            if let Some(::serde_json::Value::Object(map)) = json_get(value, &["Event", "UserData"]) {
+                for v in map.values() {
+                    push_short_pairs(&mut buffer, "", v);
+                }
+            }
+        }
+    }
+    if buffer.len() != len_head {
+        buffer.insert(len_head, ':');
+    }
+    buffer.push(NLc);
+
+    buffer
+}
+
+/// Convert a record from [`EvtxParser::records_json_value`] to an [`EvtxRS`]
+/// in the form of [`EvtxOutput::Short`].
+///
+/// [`EvtxParser::records_json_value`]: https://docs.rs/evtx/0.8.1/evtx/struct.EvtxParser.html#method.records_json_value
+pub fn evtxrsjson_to_short(record: EvtxRSJson) -> EvtxRS {
+    let data: String = json_value_to_short(&record.data, &record.timestamp);
+
+    EvtxRS {
+        event_record_id: record.event_record_id,
+        timestamp: record.timestamp,
+        data,
+    }
+}
+
+/// Convert a record from [`EvtxParser::records_json_value`] to indented
+/// JSON text ending with a newline.
+///
+/// [`EvtxParser::records_json_value`]: https://docs.rs/evtx/0.8.1/evtx/struct.EvtxParser.html#method.records_json_value
+pub fn evtxrsjson_to_pretty(record: EvtxRSJson) -> EvtxRS {
+    let mut data: String = ::serde_json::to_string_pretty(&record.data).unwrap_or_default();
+    data.push(NLc);
+
+    EvtxRS {
+        event_record_id: record.event_record_id,
+        timestamp: record.timestamp,
+        data,
+    }
+}
+
+/// Append a newline to a record from [`EvtxParser::records_json`]; crate
+/// `evtx` does not end JSON records with a newline.
+///
+/// [`EvtxParser::records_json`]: https://docs.rs/evtx/0.8.1/evtx/struct.EvtxParser.html#method.records_json
+pub fn evtxrs_json_newline(mut record: EvtxRS) -> EvtxRS {
+    if !record.data.ends_with(NLc) {
+        record.data.push(NLc);
+    }
+
+    record
+}
+
+/// Filters of EVTX records by the record `System` and `EventData` sections.
+///
+/// Each field is a list of accepted values. An empty list accepts any value.
+/// A record must match every non-empty field. Within `event_ids`,
+/// `providers`, `channels`, `levels` and `computers` a record must match any
+/// one value. Every `event_data` pair must match.
+///
+/// Set by the `--evtx-*` filter command-line options.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EvtxFilter {
+    /// Inclusive ranges of `System/EventID`.
+    pub event_ids: Vec<(u64, u64)>,
+    /// `System/Provider[@Name]`, compared case-insensitively.
+    pub providers: Vec<String>,
+    /// `System/Channel`, compared case-insensitively.
+    pub channels: Vec<String>,
+    /// `System/Level`.
+    pub levels: Vec<u64>,
+    /// `System/Computer`, compared case-insensitively.
+    pub computers: Vec<String>,
+    /// `EventData/Data[@Name='X']=value` as pairs `(X, value)`.
+    pub event_data: Vec<(String, String)>,
+}
+
+/// Windows Event Log `Level` names and values.
+const EVTX_LEVELS: [(&str, u64); 6] = [
+    ("logalways", 0),
+    ("critical", 1),
+    ("error", 2),
+    ("warning", 3),
+    ("information", 4),
+    ("verbose", 5),
+];
+
+/// Split a comma-separated list, trimming whitespace and dropping empty
+/// entries.
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Remove one pair of matching single or double quotes around `value`.
+fn unquote(value: &str) -> &str {
+    for q in ['\'', '"'] {
+        if value.len() >= 2 && value.starts_with(q) && value.ends_with(q) {
+            return &value[1..value.len() - 1];
+        }
+    }
+
+    value
+}
+
+impl EvtxFilter {
+    /// Create a new `EvtxFilter` from the command-line option values.
+    ///
+    /// * `event_ids` is a comma-separated list of EventIDs or ranges of
+    ///   EventIDs, e.g. `"4624,4625,4700-4799"`
+    /// * `providers`, `channels` and `computers` are comma-separated lists
+    /// * `levels` is a comma-separated list of Level numbers or names, e.g.
+    ///   `"1,error,warning"`
+    /// * `event_data` are `EventData/Data[@Name='X']=value` matches; the
+    ///   shorter forms `Data[@Name='X']=value` and `X=value` are also accepted
+    pub fn new(
+        event_ids: Option<&str>,
+        providers: Option<&str>,
+        channels: Option<&str>,
+        levels: Option<&str>,
+        computers: Option<&str>,
+        event_data: &[String],
+    ) -> std::result::Result<EvtxFilter, String> {
+        let mut filter = EvtxFilter::default();
+        for id in split_list(event_ids.unwrap_or_default()).iter() {
+            filter.event_ids.push(Self::parse_event_id_range(id)?);
+        }
+        filter.providers = split_list(providers.unwrap_or_default());
+        filter.channels = split_list(channels.unwrap_or_default());
+        for level in split_list(levels.unwrap_or_default()).iter() {
+            filter.levels.push(Self::parse_level(level)?);
+        }
+        filter.computers = split_list(computers.unwrap_or_default());
+        for data in event_data.iter() {
+            filter.event_data.push(Self::parse_event_data(data)?);
+        }
+
+        Ok(filter)
+    }
+
+    /// Parse an EventID `"4624"` or EventID range `"4700-4799"`.
+    fn parse_event_id_range(value: &str) -> std::result::Result<(u64, u64), String> {
+        let parse = |s: &str| -> std::result::Result<u64, String> {
+            s.trim()
+                .parse::<u64>()
+                .map_err(|err| format!("Invalid EventID {:?}; {}", s, err))
+        };
+        match value.split_once('-') {
+            Some((a, b)) => {
+                let (a, b) = (parse(a)?, parse(b)?);
+                if a > b {
+                    return Err(format!("Invalid EventID range {:?}", value));
+                }
+                Ok((a, b))
+            }
+            None => {
+                let a = parse(value)?;
+                Ok((a, a))
+            }
+        }
+    }
+
+    /// Parse a Level number `"2"` or name `"error"`.
+    fn parse_level(value: &str) -> std::result::Result<u64, String> {
+        if let Ok(level) = value.parse::<u64>() {
+            return Ok(level);
+        }
+        let value_lc: String = value.to_lowercase();
+        match EVTX_LEVELS.iter().find(|(name, _)| *name == value_lc) {
+            Some((_, level)) => Ok(*level),
+            None => Err(format!(
+                "Invalid Level {:?}; expected a number or one of {}",
+                value,
+                EVTX_LEVELS
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            )),
+        }
+    }
+
+    /// Parse `EventData/Data[@Name='X']=value`, `Data[@Name='X']=value` or
+    /// `X=value`.
+    fn parse_event_data(value: &str) -> std::result::Result<(String, String), String> {
+        let err = || format!("Invalid EventData match {:?}; expected EventData/Data[@Name='X']=value", value);
+        let data: &str = value.trim();
+        let data: &str = data.strip_prefix("EventData/").unwrap_or(data);
+        let (name, rhs) = match data.strip_prefix("Data[@Name=") {
+            Some(rest) => {
+                let (name, rest) = rest.split_once(']').ok_or_else(err)?;
+                let rhs = rest.strip_prefix('=').ok_or_else(err)?;
+                (unquote(name.trim()), rhs)
+            }
+            None => {
+                let (name, rhs) = data.split_once('=').ok_or_else(err)?;
+                (name.trim(), rhs)
+            }
+        };
+        if name.is_empty() {
+            return Err(err());
+        }
+
+        Ok((String::from(name), String::from(unquote(rhs.trim()))))
+    }
+
+    /// Does this filter accept all records?
+    pub fn is_empty(&self) -> bool {
+        self.event_ids.is_empty()
+            && self.providers.is_empty()
+            && self.channels.is_empty()
+            && self.levels.is_empty()
+            && self.computers.is_empty()
+            && self.event_data.is_empty()
+    }
+
+    /// Does the `evtx` JSON record `value` pass this filter?
+    pub fn matches(
+        &self,
+        value: &::serde_json::Value,
+    ) -> bool {
+        let system: Option<&::serde_json::Value> = json_get(value, &["Event", "System"]);
+        let system_string = |keys: &[&str]| -> Option<String> {
+            system
+                .and_then(|v| json_get(v, keys))
+                .map(json_to_string)
+        };
+        let any_eq_ignore_case = |accepted: &Vec<String>, keys: &[&str]| -> bool {
+            if accepted.is_empty() {
+                return true;
+            }
+            match system_string(keys) {
+                Some(s) => accepted.iter().any(|a| a.eq_ignore_ascii_case(&s)),
+                None => false,
+            }
+        };
+
+        if !self.event_ids.is_empty() {
+            match system_string(&["EventID"]).and_then(|s| s.parse::<u64>().ok()) {
+                Some(id) => {
+                    if !self.event_ids.iter().any(|(a, b)| *a <= id && id <= *b) {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
+        if !any_eq_ignore_case(&self.providers, &["Provider", JSON_ATTRIBUTES_KEY, "Name"])
+            || !any_eq_ignore_case(&self.channels, &["Channel"])
+            || !any_eq_ignore_case(&self.computers, &["Computer"])
+        {
+            return false;
+        }
+        if !self.levels.is_empty() {
+            match system_string(&["Level"]).and_then(|s| s.parse::<u64>().ok()) {
+                Some(level) => {
+                    if !self.levels.contains(&level) {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
+        if !self.event_data.is_empty() {
+            // `UserData` has one child element named by the provider
+            let data: Option<&::serde_json::Value> = match json_get(value, &["Event", "EventData"]) {
+                Some(eventdata) => Some(eventdata),
+                None => match json_get(value, &["Event", "UserData"]) {
+                    Some(::serde_json::Value::Object(map)) => map.values().next(),
+                    _ => None,
+                },
+            };
+            let data: &::serde_json::Value = match data {
+                Some(data) => data,
+                None => return false,
+            };
+            for (name, expect) in self.event_data.iter() {
+                match data.get(name.as_str()) {
+                    Some(v) => {
+                        if &json_to_string(v) != expect {
+                            return false;
+                        }
+                    }
+                    None => return false,
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// Convert a [`EvtxRecord`] to an [`EvtxRS`] in the form of `evtx_output`.
+/// Return `None` if the record does not pass `evtx_filter`.
+///
+/// [`EvtxRecord`]: https://docs.rs/evtx/0.8.1/evtx/struct.EvtxRecord.html
+pub fn evtxrecord_to_evtxrs(
+    record: EvtxRecord<'_>,
+    evtx_output: EvtxOutput,
+    evtx_filter: &EvtxFilter,
+) -> ResultEvtxRSOpt {
+    match evtx_output {
+        EvtxOutput::Xml
+        | EvtxOutput::Json => {
+            if !evtx_filter.is_empty() {
+                let record_json: EvtxRSJson = record.clone().into_json_value()?;
+                if !evtx_filter.matches(&record_json.data) {
+                    return Ok(None);
+                }
+            }
+            match evtx_output {
+                EvtxOutput::Xml => record.into_xml().map(Some),
+                _ => record
+                    .into_json()
+                    .map(|r| Some(evtxrs_json_newline(r))),
+            }
+        }
+        EvtxOutput::JsonPretty
+        | EvtxOutput::Short => {
+            let record_json: EvtxRSJson = record.into_json_value()?;
+            if !evtx_filter.matches(&record_json.data) {
+                return Ok(None);
+            }
+            match evtx_output {
+                EvtxOutput::JsonPretty => Ok(Some(evtxrsjson_to_pretty(record_json))),
+                _ => Ok(Some(evtxrsjson_to_short(record_json))),
+            }
+        }
+    }
+}
 
 /// Convert a `evtx` "timestamp" (`jiff::Timestamp`)
 /// to a `s4` "datetime" (`chrono::DateTime<Local>`).
@@ -203,6 +757,7 @@ impl Evtx {
     pub fn from_resultserializedrecord(
         record: ResultEvtxRS,
         fixed_offset: &FixedOffset,
+        evtx_output: EvtxOutput,
     ) -> Result<Evtx, Error> {
         match record {
             Ok(record) => {
@@ -211,6 +766,7 @@ impl Evtx {
                         DateTimeLOpt::None,
                         fixed_offset,
                         record,
+                        evtx_output,
                     )
                 )
             }
@@ -226,10 +782,13 @@ impl Evtx {
     }
 
     /// Create a new `Evtx`.
+    ///
+    /// The `record.data` must be in the form of the `evtx_output`.
     pub fn from_evtxrs(
         datetime_opt: DateTimeLOpt,
         fixed_offset: &FixedOffset,
         record: EvtxRS,
+        evtx_output: EvtxOutput,
     ) -> Evtx {
         let dt: DateTimeL = match datetime_opt {
             Some(dt) => dt,
@@ -244,7 +803,7 @@ impl Evtx {
                         DateTimeLOpt::Some(dt) => dt,
                     }
         };
-        let dt_beg_end = Self::get_dt_beg_end(&record.data);
+        let dt_beg_end = Self::get_dt_beg_end(&record.data, evtx_output);
         Evtx {
             dt,
             dt_beg_end,
@@ -261,13 +820,35 @@ impl Evtx {
     ///
     /// would return byte offset of the first `'2'` and the closing `'"'`.
     ///
+    /// For JSON the substring is the value of key `"SystemTime"`.
+    /// For [`EvtxOutput::Short`] the substring is the first word.
+    ///
     /// Returns `None` if the substring is not found.
     pub(crate) fn get_dt_beg_end(
         data: &str,
+        evtx_output: EvtxOutput,
     ) -> DtBegEndPairOpt {
-        let dt_beg: usize = match data.find(TIMECREATED_BEG_SUBSTR) {
-            Some(dt_beg) => dt_beg + TIMECREATED_BEG_SUBSTR.len(),
-            None => { return None; },
+        let dt_beg: usize = match evtx_output {
+            EvtxOutput::Xml => match data.find(TIMECREATED_BEG_SUBSTR) {
+                Some(dt_beg) => dt_beg + TIMECREATED_BEG_SUBSTR.len(),
+                None => { return None; },
+            },
+            EvtxOutput::Json | EvtxOutput::JsonPretty => {
+                let key_end: usize = match data.find(SYSTEMTIME_JSON_KEY) {
+                    Some(a) => a + SYSTEMTIME_JSON_KEY.len(),
+                    None => { return None; },
+                };
+                match data[key_end..].find(TIMECREATED_END_SUBCHAR) {
+                    Some(a) => key_end + a + TIMECREATED_END_SUBCHAR.len_utf8(),
+                    None => { return None; },
+                }
+            }
+            EvtxOutput::Short => {
+                return match data.find(' ') {
+                    Some(0) | None => None,
+                    Some(dt_end) => Some((0, dt_end)),
+                };
+            }
         };
         let dt_end: usize = match data[dt_beg..].find(TIMECREATED_END_SUBCHAR) {
             Some(dt_end) => dt_beg + dt_end,
@@ -305,6 +886,10 @@ impl Evtx {
         self.record.data.as_bytes()
     }
 
+    pub const fn as_str(&self) -> &str {
+        self.record.data.as_str()
+    }
+
     /// Does this `Evtx` end in a newline character?
     ///
     /// By default, "yes", but it's nice to provide this.
@@ -49,8 +49,8 @@ use numtoa::NumToA; // adds `numtoa` method to numbers
 
 #[doc(hidden)]
 use crate::common::{
-    max16,
-    min16,
+    max19,
+    min19,
     FileOffset,
     FileSz,
     FileTypeFixedStruct,
@@ -164,6 +164,39 @@ macro_rules! defo_tv_pair {
     }};
 }
 
+/// The byte order of the numeric fields of a [`FixedStructType`].
+///
+/// The structs in this module are read in the byte order of the host, which
+/// is presumed to be little-endian. Records copied from a big-endian system,
+/// e.g. Linux on s390x, PowerPC, or MIPS, have the same layout but each
+/// numeric field is byte-swapped. Those are swapped with
+/// [`FixedStructType::swap_endian`] before being read.
+///
+/// The byte order of a file is determined by scoring; see
+/// [`FixedStructReader::score_file`].
+///
+/// [`FixedStructReader::score_file`]: crate::readers::fixedstructreader::FixedStructReader::score_file
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Endian {
+    #[default]
+    Little,
+    Big,
+}
+
+/// All [`Endian`]s in the order they are scored. The first is preferred
+/// when scores are equal.
+pub const ENDIANS: [Endian; 2] = [Endian::Little, Endian::Big];
+
+impl Endian {
+    /// Is this the byte order of the host?
+    pub const fn is_native(&self) -> bool {
+        match self {
+            Endian::Little => cfg!(target_endian = "little"),
+            Endian::Big => cfg!(target_endian = "big"),
+        }
+    }
+}
+
 /// FixedStruct Implementation Type (name `FixedStructType` is taken).
 ///
 /// The specific implementation of the FixedStruct. Each implementation of,
@@ -192,6 +225,12 @@ pub enum FixedStructType {
     #[allow(non_camel_case_types)]
     Fs_Linux_Arm64Aarch64_Utmpx,
 
+    // Linux SQLite databases
+
+    /// corresponds to [`linux_sqlite::lastlog2`]
+    #[allow(non_camel_case_types)]
+    Fs_Linux_Sqlite_Lastlog2,
+
     // Linux x86
 
     /// corresponds to [`linux_x86::acct`]
@@ -210,6 +249,16 @@ pub enum FixedStructType {
     #[allow(non_camel_case_types)]
     Fs_Linux_x86_Utmpx,
 
+    // macOS x86_64, arm64
+
+    /// corresponds to [`macos_x8664::lastlogx`]
+    #[allow(non_camel_case_types)]
+    Fs_Macos_x8664_Lastlogx,
+
+    /// corresponds to [`macos_x8664::utmpx`]
+    #[allow(non_camel_case_types)]
+    Fs_Macos_x8664_Utmpx,
+
     // NetBSD
 
     // NetBSD 9 x86_32 (i686)
@@ -262,10 +311,13 @@ impl FixedStructType {
             FixedStructType::Fs_Freebsd_x8664_Utmpx => freebsd_x8664::UTMPX_SZ,
             FixedStructType::Fs_Linux_Arm64Aarch64_Lastlog => linux_arm64aarch64::LASTLOG_SZ,
             FixedStructType::Fs_Linux_Arm64Aarch64_Utmpx => linux_arm64aarch64::UTMPX_SZ,
+            FixedStructType::Fs_Linux_Sqlite_Lastlog2 => linux_sqlite::LASTLOG2_SZ,
             FixedStructType::Fs_Linux_x86_Acct => linux_x86::ACCT_SZ,
             FixedStructType::Fs_Linux_x86_Acct_v3 => linux_x86::ACCT_V3_SZ,
             FixedStructType::Fs_Linux_x86_Lastlog => linux_x86::LASTLOG_SZ,
             FixedStructType::Fs_Linux_x86_Utmpx => linux_x86::UTMPX_SZ,
+            FixedStructType::Fs_Macos_x8664_Lastlogx => macos_x8664::LASTLOGX_SZ,
+            FixedStructType::Fs_Macos_x8664_Utmpx => macos_x8664::UTMPX_SZ,
             FixedStructType::Fs_Netbsd_x8632_Acct => netbsd_x8632::ACCT_SZ,
             FixedStructType::Fs_Netbsd_x8632_Lastlogx => netbsd_x8632::LASTLOGX_SZ,
             FixedStructType::Fs_Netbsd_x8632_Utmpx => netbsd_x8632::UTMPX_SZ,
@@ -285,10 +337,13 @@ impl FixedStructType {
             FixedStructType::Fs_Freebsd_x8664_Utmpx => freebsd_x8664::UTMPX_TIMEVALUE_OFFSET,
             FixedStructType::Fs_Linux_Arm64Aarch64_Lastlog => linux_arm64aarch64::LASTLOG_TIMEVALUE_OFFSET,
             FixedStructType::Fs_Linux_Arm64Aarch64_Utmpx => linux_arm64aarch64::UTMPX_TIMEVALUE_OFFSET,
+            FixedStructType::Fs_Linux_Sqlite_Lastlog2 => linux_sqlite::LASTLOG2_TIMEVALUE_OFFSET,
             FixedStructType::Fs_Linux_x86_Acct => linux_x86::ACCT_TIMEVALUE_OFFSET,
             FixedStructType::Fs_Linux_x86_Acct_v3 => linux_x86::ACCT_V3_TIMEVALUE_OFFSET,
             FixedStructType::Fs_Linux_x86_Lastlog => linux_x86::LASTLOG_TIMEVALUE_OFFSET,
             FixedStructType::Fs_Linux_x86_Utmpx => linux_x86::UTMPX_TIMEVALUE_OFFSET,
+            FixedStructType::Fs_Macos_x8664_Lastlogx => macos_x8664::LASTLOGX_TIMEVALUE_OFFSET,
+            FixedStructType::Fs_Macos_x8664_Utmpx => macos_x8664::UTMPX_TIMEVALUE_OFFSET,
             FixedStructType::Fs_Netbsd_x8632_Acct => netbsd_x8632::ACCT_TIMEVALUE_OFFSET,
             FixedStructType::Fs_Netbsd_x8632_Lastlogx => netbsd_x8632::LASTLOGX_TIMEVALUE_OFFSET,
             FixedStructType::Fs_Netbsd_x8632_Utmpx => netbsd_x8632::UTMPX_TIMEVALUE_OFFSET,
@@ -308,10 +363,13 @@ impl FixedStructType {
             FixedStructType::Fs_Freebsd_x8664_Utmpx => freebsd_x8664::UTMPX_TIMEVALUE_SZ,
             FixedStructType::Fs_Linux_Arm64Aarch64_Lastlog => linux_arm64aarch64::LASTLOG_TIMEVALUE_SZ,
             FixedStructType::Fs_Linux_Arm64Aarch64_Utmpx => linux_arm64aarch64::UTMPX_TIMEVALUE_SZ,
+            FixedStructType::Fs_Linux_Sqlite_Lastlog2 => linux_sqlite::LASTLOG2_TIMEVALUE_SZ,
             FixedStructType::Fs_Linux_x86_Acct => linux_x86::ACCT_TIMEVALUE_SZ,
             FixedStructType::Fs_Linux_x86_Acct_v3 => linux_x86::ACCT_V3_TIMEVALUE_SZ,
             FixedStructType::Fs_Linux_x86_Lastlog => linux_x86::LASTLOG_TIMEVALUE_SZ,
             FixedStructType::Fs_Linux_x86_Utmpx => linux_x86::UTMPX_TIMEVALUE_SZ,
+            FixedStructType::Fs_Macos_x8664_Lastlogx => macos_x8664::LASTLOGX_TIMEVALUE_SZ,
+            FixedStructType::Fs_Macos_x8664_Utmpx => macos_x8664::UTMPX_TIMEVALUE_SZ,
             FixedStructType::Fs_Netbsd_x8632_Acct => netbsd_x8632::ACCT_TIMEVALUE_SZ,
             FixedStructType::Fs_Netbsd_x8632_Lastlogx => netbsd_x8632::LASTLOGX_TIMEVALUE_SZ,
             FixedStructType::Fs_Netbsd_x8632_Utmpx => netbsd_x8632::UTMPX_TIMEVALUE_SZ,
@@ -324,6 +382,51 @@ impl FixedStructType {
         }
     }
 
+    /// return the associated `FixedStructType`'s numeric fields as pairs of
+    /// offset and size in bytes. These are the fields that differ among
+    /// [`Endian`]s.
+    pub const fn numeric_fields(&self) -> &'static [(usize, usize)] {
+        match self {
+            FixedStructType::Fs_Freebsd_x8664_Utmpx => freebsd_x8664::UTMPX_NUMERIC_FIELDS,
+            FixedStructType::Fs_Linux_Arm64Aarch64_Lastlog => linux_arm64aarch64::LASTLOG_NUMERIC_FIELDS,
+            FixedStructType::Fs_Linux_Arm64Aarch64_Utmpx => linux_arm64aarch64::UTMPX_NUMERIC_FIELDS,
+            FixedStructType::Fs_Linux_Sqlite_Lastlog2 => linux_sqlite::LASTLOG2_NUMERIC_FIELDS,
+            FixedStructType::Fs_Linux_x86_Acct => linux_x86::ACCT_NUMERIC_FIELDS,
+            FixedStructType::Fs_Linux_x86_Acct_v3 => linux_x86::ACCT_V3_NUMERIC_FIELDS,
+            FixedStructType::Fs_Linux_x86_Lastlog => linux_x86::LASTLOG_NUMERIC_FIELDS,
+            FixedStructType::Fs_Linux_x86_Utmpx => linux_x86::UTMPX_NUMERIC_FIELDS,
+            FixedStructType::Fs_Macos_x8664_Lastlogx => macos_x8664::LASTLOGX_NUMERIC_FIELDS,
+            FixedStructType::Fs_Macos_x8664_Utmpx => macos_x8664::UTMPX_NUMERIC_FIELDS,
+            FixedStructType::Fs_Netbsd_x8632_Acct => netbsd_x8632::ACCT_NUMERIC_FIELDS,
+            FixedStructType::Fs_Netbsd_x8632_Lastlogx => netbsd_x8632::LASTLOGX_NUMERIC_FIELDS,
+            FixedStructType::Fs_Netbsd_x8632_Utmpx => netbsd_x8632::UTMPX_NUMERIC_FIELDS,
+            FixedStructType::Fs_Netbsd_x8664_Lastlog => netbsd_x8664::LASTLOG_NUMERIC_FIELDS,
+            FixedStructType::Fs_Netbsd_x8664_Lastlogx => netbsd_x8664::LASTLOGX_NUMERIC_FIELDS,
+            FixedStructType::Fs_Netbsd_x8664_Utmp => netbsd_x8664::UTMP_NUMERIC_FIELDS,
+            FixedStructType::Fs_Netbsd_x8664_Utmpx => netbsd_x8664::UTMPX_NUMERIC_FIELDS,
+            FixedStructType::Fs_Openbsd_x86_Lastlog => openbsd_x86::LASTLOG_NUMERIC_FIELDS,
+            FixedStructType::Fs_Openbsd_x86_Utmp => openbsd_x86::UTMP_NUMERIC_FIELDS,
+        }
+    }
+
+    /// Reverse the byte order of the numeric fields within `buffer`.
+    /// `buffer` holds the bytes of this `FixedStructType` beginning at
+    /// `offset` into the struct, e.g. `offset` is `0` for an entire struct
+    /// and [`offset_tv`] for only the time value.
+    /// Numeric fields not entirely within `buffer` are ignored.
+    ///
+    /// [`offset_tv`]: FixedStructType::offset_tv
+    pub fn swap_endian(&self, buffer: &mut [u8], offset: usize) {
+        let end: usize = offset + buffer.len();
+        for (field_offset, field_sz) in self.numeric_fields().iter() {
+            if *field_offset < offset || field_offset + field_sz > end {
+                continue;
+            }
+            let at: usize = field_offset - offset;
+            buffer[at..at + field_sz].reverse();
+        }
+    }
+
     /// the datetime field as a pair of seconds and microseconds taken from
     /// raw bytes
     pub fn tv_pair_from_buffer(&self, buffer: &[u8]) -> Option<tv_pair_type> {
@@ -345,6 +448,11 @@ impl FixedStructType {
                 buffer_to_timeval!(linux_arm64aarch64::timeval, size, buffer, tv_sec, tv_usec);
                 defo_tv_pair!(tv_sec, tv_usec);
             }
+            FixedStructType::Fs_Linux_Sqlite_Lastlog2 => {
+                buffer_to_time_t!(linux_sqlite::ll_time_t, size, buffer, tv_sec);
+                tv_usec = 0;
+                defo_tv_pair!(tv_sec, tv_usec);
+            }
             FixedStructType::Fs_Linux_x86_Acct => {
                 buffer_to_time_t!(linux_x86::b_time_t, size, buffer, tv_sec);
                 tv_usec = 0;
@@ -364,6 +472,14 @@ impl FixedStructType {
                 buffer_to_timeval!(linux_x86::__timeval, size, buffer, tv_sec, tv_usec);
                 defo_tv_pair!(tv_sec, tv_usec);
             }
+            FixedStructType::Fs_Macos_x8664_Lastlogx => {
+                buffer_to_timeval!(macos_x8664::timeval, size, buffer, tv_sec, tv_usec);
+                defo_tv_pair!(tv_sec, tv_usec);
+            }
+            FixedStructType::Fs_Macos_x8664_Utmpx => {
+                buffer_to_timeval!(macos_x8664::timeval, size, buffer, tv_sec, tv_usec);
+                defo_tv_pair!(tv_sec, tv_usec);
+            }
             FixedStructType::Fs_Netbsd_x8632_Acct => {
                 buffer_to_time_t!(netbsd_x8632::time_t, size, buffer, tv_sec);
                 tv_usec = 0;
@@ -553,6 +669,14 @@ pub mod freebsd_x8664 {
     assertcp_eq!(offset_of!(utmpx, ut_host), 84);
     assertcp_eq!(offset_of!(utmpx, __ut_spare), 212);
 
+    /// Numeric fields of `utmpx` as (offset, size)
+    pub const UTMPX_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(utmpx, ut_type), size_of::<c_short>()),
+        (UTMPX_TIMEVALUE_OFFSET_TV_SEC, size_of::<time_t>()),
+        (UTMPX_TIMEVALUE_OFFSET_TV_USEC, size_of::<subseconds_t>()),
+        (offset_of!(utmpx, ut_pid), size_of::<pid_t>()),
+    ];
+
     /// Helpers for use in `fmt::Debug` trait.
     impl utmpx {
         pub fn ut_id(&self) -> &CStr {
@@ -594,6 +718,67 @@ pub mod freebsd_x8664 {
     pub const UT_TYPES: [c_short; 9] = [
         0, 1, 2, 3, 4, 5, 6, 7, 8
     ];
+
+    // futx
+
+    /// From [`lib/libc/gen/utxdb.h`], FreeBSD 14.0
+    ///
+    /// ```C
+    /// struct futx {
+    ///     uint8_t         fu_type;
+    ///     uint64_t        fu_tv;
+    ///     char            fu_id[8];
+    ///     uint32_t        fu_pid;
+    ///     char            fu_user[32];
+    ///     char            fu_line[16];
+    ///     char            fu_host[128];
+    /// } __packed;
+    /// ```
+    ///
+    /// The on-disk format of the `utx.active`, `utx.lastlogin`, and `utx.log`
+    /// databases. Numeric fields are always big-endian. `fu_tv` is
+    /// microseconds since the Unix epoch.
+    ///
+    /// In `utx.log` each `futx` is prefixed by a big-endian `uint16_t` length
+    /// and the trailing zero bytes of the `futx` are not written.
+    /// These records are read by [`crate::readers::utxlog`].
+    ///
+    /// ---
+    ///
+    /// ```text
+    /// futx                   sizeof 197
+    /// futx.fu_type      @  0 sizeof   1
+    /// futx.fu_tv        @  1 sizeof   8
+    /// futx.fu_id        @  9 sizeof   8
+    /// futx.fu_pid       @ 17 sizeof   4
+    /// futx.fu_user      @ 21 sizeof  32
+    /// futx.fu_line      @ 53 sizeof  16
+    /// futx.fu_host      @ 69 sizeof 128
+    /// ```
+    ///
+    /// [`lib/libc/gen/utxdb.h`]: https://cgit.freebsd.org/src/tree/lib/libc/gen/utxdb.h?h=stable/14
+    #[derive(Clone, Copy)]
+    #[allow(non_camel_case_types)]
+    #[repr(C, packed)]
+    pub struct futx {
+        pub fu_type: u8,
+        pub fu_tv: u64,
+        pub fu_id: [c_char; UT_IDSIZE],
+        pub fu_pid: u32,
+        pub fu_user: [c_char; UT_USERSIZE],
+        pub fu_line: [c_char; UT_LINESIZE],
+        pub fu_host: [c_char; UT_HOSTSIZE],
+    }
+
+    pub const FUTX_SZ: usize = size_of::<futx>();
+    assertcp_eq!(FUTX_SZ, 197);
+    assertcp_eq!(offset_of!(futx, fu_type), 0);
+    assertcp_eq!(offset_of!(futx, fu_tv), 1);
+    assertcp_eq!(offset_of!(futx, fu_id), 9);
+    assertcp_eq!(offset_of!(futx, fu_pid), 17);
+    assertcp_eq!(offset_of!(futx, fu_user), 21);
+    assertcp_eq!(offset_of!(futx, fu_line), 53);
+    assertcp_eq!(offset_of!(futx, fu_host), 69);
 }
 
 /// FixedStruct definitions found on Linux running on ARM64 (aarch64)
@@ -673,6 +858,11 @@ pub mod linux_arm64aarch64 {
     assertcp_eq!(offset_of!(lastlog, ll_line), 8);
     assertcp_eq!(offset_of!(lastlog, ll_host), 40);
 
+    /// Numeric fields of `lastlog` as (offset, size)
+    pub const LASTLOG_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(lastlog, ll_time), size_of::<ll_time_t>()),
+    ];
+
     // utmp == utmpx
 
     /// From [`man utmpx`]
@@ -816,6 +1006,17 @@ pub mod linux_arm64aarch64 {
     assertcp_eq!(offset_of!(utmpx, ut_addr_v6), 360);
     assertcp_eq!(offset_of!(utmpx, __glibc_reserved), 376);
 
+    /// Numeric fields of `utmpx` as (offset, size)
+    pub const UTMPX_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(utmpx, ut_type), size_of::<c_short>()),
+        (offset_of!(utmpx, ut_pid), size_of::<pid_t>()),
+        (offset_of!(utmpx, ut_exit), size_of::<c_short>()),
+        (offset_of!(utmpx, ut_exit) + size_of::<c_short>(), size_of::<c_short>()),
+        (offset_of!(utmpx, ut_session), size_of::<i64>()),
+        (UTMPX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_sec), size_of::<i64>()),
+        (UTMPX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_usec), size_of::<i64>()),
+    ];
+
     /// From [`utmpx.h`], Linux 6.1
     /// ```C
     /// #define EMPTY           0
@@ -835,6 +1036,106 @@ pub mod linux_arm64aarch64 {
     ];
 }
 
+/// FixedStruct definitions for records read from the SQLite databases that
+/// replace `wtmp` and `lastlog` on newer Linux distributions, i.e. [`wtmpdb`]
+/// `/var/lib/wtmpdb/wtmp.db` and [`lastlog2`]
+/// `/var/lib/lastlog/lastlog2.db`.
+///
+/// These are not an on-disk format. The database rows are exported to a
+/// temporary file of these structs by [`logindb`]. `wtmpdb` rows are
+/// exported as [`linux_arm64aarch64::utmpx`], which has a 64-bit `ut_tv`.
+/// `lastlog2` rows are exported as [`lastlog2`] because the user name is not
+/// implied by the file offset as in `lastlog`.
+///
+/// [`wtmpdb`]: https://github.com/thkukuk/wtmpdb
+/// [`lastlog2`]: https://github.com/util-linux/util-linux/tree/master/liblastlog2
+/// [`logindb`]: crate::readers::logindb
+/// [`linux_arm64aarch64::utmpx`]: crate::data::fixedstruct::linux_arm64aarch64::utmpx
+#[allow(non_camel_case_types, unused)]
+pub mod linux_sqlite {
+    use crate::common::FileOffset;
+    use std::ffi::CStr;
+    use std::mem::size_of;
+    use ::const_format::assertcp_eq;
+    use ::memoffset::offset_of;
+
+    pub type c_char = std::ffi::c_char;
+    pub type ll_time_t = i64;
+
+    pub const LL_NAMESIZE: usize = 256;
+    pub const LL_LINESIZE: usize = 32;
+    pub const LL_HOSTSIZE: usize = 256;
+    pub const LL_SERVICESIZE: usize = 32;
+
+    // lastlog2
+
+    /// A row of the `Lastlog2` table of [`lastlog2.db`]
+    ///
+    /// ```SQL
+    /// CREATE TABLE Lastlog2(
+    ///     Name TEXT PRIMARY KEY,
+    ///     Time INTEGER,
+    ///     TTY TEXT,
+    ///     RemoteHost TEXT,
+    ///     Service TEXT
+    /// );
+    /// ```
+    ///
+    /// ---
+    ///
+    /// ```text
+    /// lastlog2                  sizeof 584
+    /// lastlog2.ll_time     @  0 sizeof   8
+    /// lastlog2.ll_name     @  8 sizeof 256
+    /// lastlog2.ll_line     @264 sizeof  32
+    /// lastlog2.ll_host     @296 sizeof 256
+    /// lastlog2.ll_service  @552 sizeof  32
+    /// ```
+    ///
+    /// [`lastlog2.db`]: https://github.com/util-linux/util-linux/blob/master/liblastlog2/src/lastlog2.c
+    #[derive(Clone, Copy)]
+    #[repr(C, align(8))]
+    #[allow(non_camel_case_types)]
+    pub struct lastlog2 {
+        pub ll_time: ll_time_t,
+        pub ll_name: [c_char; LL_NAMESIZE],
+        pub ll_line: [c_char; LL_LINESIZE],
+        pub ll_host: [c_char; LL_HOSTSIZE],
+        pub ll_service: [c_char; LL_SERVICESIZE],
+    }
+
+    impl lastlog2 {
+        pub fn ll_name(&self) -> &CStr {
+            unsafe { CStr::from_ptr(self.ll_name[..LL_NAMESIZE].as_ptr()) }
+        }
+        pub fn ll_line(&self) -> &CStr {
+            unsafe { CStr::from_ptr(self.ll_line[..LL_LINESIZE].as_ptr()) }
+        }
+        pub fn ll_host(&self) -> &CStr {
+            unsafe { CStr::from_ptr(self.ll_host[..LL_HOSTSIZE].as_ptr()) }
+        }
+        pub fn ll_service(&self) -> &CStr {
+            unsafe { CStr::from_ptr(self.ll_service[..LL_SERVICESIZE].as_ptr()) }
+        }
+    }
+
+    pub const LASTLOG2_SZ: usize = size_of::<lastlog2>();
+    pub const LASTLOG2_SZ_FO: FileOffset = LASTLOG2_SZ as FileOffset;
+    pub const LASTLOG2_TIMEVALUE_OFFSET: usize = offset_of!(lastlog2, ll_time);
+    pub const LASTLOG2_TIMEVALUE_SZ: usize = size_of::<ll_time_t>();
+    assertcp_eq!(LASTLOG2_SZ, 584);
+    assertcp_eq!(offset_of!(lastlog2, ll_time), 0);
+    assertcp_eq!(offset_of!(lastlog2, ll_name), 8);
+    assertcp_eq!(offset_of!(lastlog2, ll_line), 264);
+    assertcp_eq!(offset_of!(lastlog2, ll_host), 296);
+    assertcp_eq!(offset_of!(lastlog2, ll_service), 552);
+
+    /// Numeric fields of `lastlog2` as (offset, size)
+    pub const LASTLOG2_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(lastlog2, ll_time), size_of::<ll_time_t>()),
+    ];
+}
+
 /// FixedStruct definitions found in `lastlog.h`, `utmp.h`, `utmpx.h`
 /// from GNU glibc for Linux, architectures amd64 (x86_64), i686 (x86_32),
 /// ARM6 (aarch64), and RISC-V (riscv64).
@@ -852,6 +1153,12 @@ pub mod linux_arm64aarch64 {
 /// * Debian 11 ARM6 (aarch64) Linux 6.1
 /// * Debian 13 RISC-V (riscv64) Linux 6.1
 ///
+/// The same sizes and offsets are used by glibc on 32-bit ARM (armel, armhf),
+/// where `long` and `struct timeval` are 4 bytes each, so 32-bit ARM Linux
+/// `utmp`, `wtmp`, and `lastlog` files are read as these structs.
+/// Big-endian Linux, e.g. s390x, PowerPC, and MIPS, also uses these sizes and
+/// offsets though the numeric fields are byte-swapped; see [`Endian`].
+///
 /// However, `struct timeval` differs on in size on x86_64/32 and ARM6. Yet the
 /// `utmpx.ut_tv`, which is sometimes defined in-place and sometimes typed as a
 /// `struct timeval` or `struct __timeval`, is the same size on both.
@@ -866,6 +1173,7 @@ pub mod linux_arm64aarch64 {
 /// different names. This mod defines `utmpx` and not `utmp`.
 ///
 /// [code comment in `utmpx.h`]: https://elixir.bootlin.com/glibc/latest/source/sysdeps/gnu/bits/utmpx.h
+/// [`Endian`]: crate::data::fixedstruct::Endian
 #[allow(non_camel_case_types)]
 pub mod linux_x86 {
     use crate::common::FileOffset;
@@ -896,6 +1204,9 @@ pub mod linux_x86 {
 
     pub const AC_FLAGS_MASK: c_char = AFORK | ASU | ACOMPAT | ACORE | AXSIG;
 
+    /// Units per second of `comp_t` values; `AHZ` of `include/linux/acct.h`.
+    pub const AHZ: u64 = 100;
+
     /// from [`/usr/include/uapi/linux/acct.h`] on Ubuntu 22.04
     ///
     /// [`/usr/include/uapi/linux/acct.h`]: https://github.com/torvalds/linux/blob/v5.15/include/uapi/linux/acct.h#L75-L101
@@ -993,6 +1304,24 @@ pub mod linux_x86 {
     assertcp_eq!(offset_of!(acct, ac_comm), 36);
     assertcp_eq!(offset_of!(acct, ac_pad), 53);
 
+    /// Numeric fields of `acct` as (offset, size)
+    pub const ACCT_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(acct, ac_uid), size_of::<uint16_t>()),
+        (offset_of!(acct, ac_gid), size_of::<uint16_t>()),
+        (offset_of!(acct, ac_tty), size_of::<uint16_t>()),
+        (offset_of!(acct, ac_btime), size_of::<b_time_t>()),
+        (offset_of!(acct, ac_utime), size_of::<comp_t>()),
+        (offset_of!(acct, ac_stime), size_of::<comp_t>()),
+        (offset_of!(acct, ac_etime), size_of::<comp_t>()),
+        (offset_of!(acct, ac_mem), size_of::<comp_t>()),
+        (offset_of!(acct, ac_io), size_of::<comp_t>()),
+        (offset_of!(acct, ac_rw), size_of::<comp_t>()),
+        (offset_of!(acct, ac_minflt), size_of::<comp_t>()),
+        (offset_of!(acct, ac_majflt), size_of::<comp_t>()),
+        (offset_of!(acct, ac_swaps), size_of::<comp_t>()),
+        (offset_of!(acct, ac_exitcode), size_of::<u32>()),
+    ];
+
     impl acct {
         pub fn ac_comm(&self) -> &CStr {
             unsafe { CStr::from_ptr(self.ac_comm.as_ptr()) }
@@ -1083,8 +1412,8 @@ pub mod linux_x86 {
         pub ac_swaps: comp_t,
         pub ac_comm: [c_char; ACCT_COMM],
     }
-    // XXX: not handling ACCT_BYTEORDER which changes the byte order
-    //      of the file
+    // XXX: `ACCT_BYTEORDER` of `ac_version` is not checked; the byte order
+    //      of the file is determined by scoring each `Endian`
 
     pub const ACCT_V3_SZ: usize = size_of::<acct_v3>();
     pub const ACCT_V3_SZ_FO: FileOffset = ACCT_V3_SZ as FileOffset;
@@ -1111,6 +1440,26 @@ pub mod linux_x86 {
     assertcp_eq!(offset_of!(acct_v3, ac_swaps), 46);
     assertcp_eq!(offset_of!(acct_v3, ac_comm), 48);
 
+    /// Numeric fields of `acct_v3` as (offset, size)
+    pub const ACCT_V3_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(acct_v3, ac_tty), size_of::<u16>()),
+        (offset_of!(acct_v3, ac_exitcode), size_of::<u32>()),
+        (offset_of!(acct_v3, ac_uid), size_of::<u32>()),
+        (offset_of!(acct_v3, ac_gid), size_of::<u32>()),
+        (offset_of!(acct_v3, ac_pid), size_of::<u32>()),
+        (offset_of!(acct_v3, ac_ppid), size_of::<u32>()),
+        (offset_of!(acct_v3, ac_btime), size_of::<b_time_t>()),
+        (offset_of!(acct_v3, ac_etime), size_of::<f32>()),
+        (offset_of!(acct_v3, ac_utime), size_of::<comp_t>()),
+        (offset_of!(acct_v3, ac_stime), size_of::<comp_t>()),
+        (offset_of!(acct_v3, ac_mem), size_of::<comp_t>()),
+        (offset_of!(acct_v3, ac_io), size_of::<comp_t>()),
+        (offset_of!(acct_v3, ac_rw), size_of::<comp_t>()),
+        (offset_of!(acct_v3, ac_minflt), size_of::<comp_t>()),
+        (offset_of!(acct_v3, ac_majflt), size_of::<comp_t>()),
+        (offset_of!(acct_v3, ac_swaps), size_of::<comp_t>()),
+    ];
+
     impl acct_v3 {
         pub fn ac_comm(&self) -> &CStr {
             unsafe { CStr::from_ptr(self.ac_comm.as_ptr()) }
@@ -1149,6 +1498,11 @@ pub mod linux_x86 {
     assertcp_eq!(offset_of!(lastlog, ll_line), 4);
     assertcp_eq!(offset_of!(lastlog, ll_host), 36);
 
+    /// Numeric fields of `lastlog` as (offset, size)
+    pub const LASTLOG_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(lastlog, ll_time), size_of::<ll_time_t>()),
+    ];
+
     impl lastlog {
         pub const fn ll_line(&self) -> &CStr {
             unsafe { CStr::from_ptr(self.ll_line.as_ptr()) }
@@ -1306,6 +1660,17 @@ pub mod linux_x86 {
     assertcp_eq!(offset_of!(utmpx, ut_addr_v6), 348);
     assertcp_eq!(offset_of!(utmpx, __glibc_reserved), 364);
 
+    /// Numeric fields of `utmpx` as (offset, size)
+    pub const UTMPX_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(utmpx, ut_type), size_of::<c_short>()),
+        (offset_of!(utmpx, ut_pid), size_of::<pid_t>()),
+        (offset_of!(utmpx, ut_exit) + offset_of!(__exit_status, e_termination), size_of::<i16>()),
+        (offset_of!(utmpx, ut_exit) + offset_of!(__exit_status, e_exit), size_of::<i16>()),
+        (offset_of!(utmpx, ut_session), size_of::<i32>()),
+        (UTMPX_TIMEVALUE_OFFSET + offset_of!(__timeval, tv_sec), size_of::<i32>()),
+        (UTMPX_TIMEVALUE_OFFSET + offset_of!(__timeval, tv_usec), size_of::<i32>()),
+    ];
+
     /// helpers for `fmt::Debug` trait
     ///
     /// The slicing in each `CStr` function below is to due to
@@ -1345,6 +1710,230 @@ pub mod linux_x86 {
     ];
 }
 
+/// FixedStruct definitions found on macOS 13 x86_64 and macOS 13 arm64
+/// (Apple silicon). The sizes and offsets are the same on both.
+///
+/// See [`utmpx.h`] from Apple's `Libc`.
+///
+/// [`utmpx.h`]: https://github.com/apple-oss-distributions/Libc/blob/Libc-1534.40.2/include/utmpx.h
+#[allow(non_camel_case_types, unused)]
+pub mod macos_x8664 {
+    use crate::common::FileOffset;
+    use std::ffi::CStr;
+    use std::mem::size_of;
+    use ::const_format::assertcp_eq;
+    use ::memoffset::offset_of;
+
+    pub type c_char = std::ffi::c_char;
+    pub type c_short = std::ffi::c_short;
+    pub type pid_t = std::ffi::c_int;
+    pub type suseconds_t = std::ffi::c_int;
+    // XXX: use `i64` to satisfy various cross-compilation targets
+    pub type time_t = i64;
+
+    // timeval
+
+    /// From `/usr/include/sys/_types/_timeval.h`
+    ///
+    /// ```C
+    /// _STRUCT_TIMEVAL
+    /// {
+    ///     __darwin_time_t         tv_sec;         /* seconds */
+    ///     __darwin_suseconds_t    tv_usec;        /* and microseconds */
+    /// };
+    /// ```
+    ///
+    /// ---
+    ///
+    /// ```text
+    /// timeval               sizeof  16
+    /// timeval.tv_sec   @  0 sizeof   8
+    /// timeval.tv_usec  @  8 sizeof   4
+    /// ```
+    #[derive(Clone, Copy)]
+    #[repr(C, align(8))]
+    #[allow(non_camel_case_types)]
+    pub struct timeval {
+        pub tv_sec: time_t,
+        pub tv_usec: suseconds_t,
+    }
+
+    pub const TIMEVAL_SZ: usize = size_of::<timeval>();
+    assertcp_eq!(TIMEVAL_SZ, 16);
+    assertcp_eq!(offset_of!(timeval, tv_sec), 0);
+    assertcp_eq!(offset_of!(timeval, tv_usec), 8);
+
+    pub const UTX_USERSIZE: usize = 256;
+    pub const UTX_IDSIZE: usize = 4;
+    pub const UTX_LINESIZE: usize = 32;
+    pub const UTX_HOSTSIZE: usize = 256;
+
+    // lastlogx
+
+    /// From [`utmpx.h`]
+    ///
+    /// ```C
+    /// struct lastlogx {
+    ///     struct timeval ll_tv;           /* time entry was created */
+    ///     char ll_line[_UTX_LINESIZE];    /* tty name */
+    ///     char ll_host[_UTX_HOSTSIZE];    /* host name */
+    /// };
+    /// ```
+    ///
+    /// ---
+    ///
+    /// ```text
+    /// lastlogx               sizeof 304
+    /// lastlogx.ll_tv    @  0 sizeof  16
+    /// lastlogx.ll_line  @ 16 sizeof  32
+    /// lastlogx.ll_host  @ 48 sizeof 256
+    /// ```
+    ///
+    /// [`utmpx.h`]: https://github.com/apple-oss-distributions/Libc/blob/Libc-1534.40.2/include/utmpx.h
+    #[derive(Clone, Copy)]
+    #[repr(C, align(8))]
+    #[allow(non_camel_case_types)]
+    pub struct lastlogx {
+        pub ll_tv: timeval,
+        pub ll_line: [c_char; UTX_LINESIZE],
+        pub ll_host: [c_char; UTX_HOSTSIZE],
+    }
+
+    pub const LASTLOGX_SZ: usize = size_of::<lastlogx>();
+    pub const LASTLOGX_SZ_FO: FileOffset = LASTLOGX_SZ as FileOffset;
+    pub const LASTLOGX_TIMEVALUE_OFFSET: usize = offset_of!(lastlogx, ll_tv);
+    pub const LASTLOGX_TIMEVALUE_SZ: usize = TIMEVAL_SZ;
+    assertcp_eq!(LASTLOGX_SZ, 304);
+    assertcp_eq!(offset_of!(lastlogx, ll_tv), 0);
+    assertcp_eq!(offset_of!(lastlogx, ll_line), 16);
+    assertcp_eq!(offset_of!(lastlogx, ll_host), 48);
+
+    /// Numeric fields of `lastlogx` as (offset, size)
+    pub const LASTLOGX_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (LASTLOGX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_sec), size_of::<time_t>()),
+        (LASTLOGX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_usec), size_of::<suseconds_t>()),
+    ];
+
+    impl lastlogx {
+        pub fn ll_line(&self) -> &CStr {
+            unsafe { CStr::from_ptr(self.ll_line[..UTX_LINESIZE].as_ptr()) }
+        }
+        pub fn ll_host(&self) -> &CStr {
+            unsafe { CStr::from_ptr(self.ll_host[..UTX_HOSTSIZE].as_ptr()) }
+        }
+    }
+
+    // utmpx
+
+    /// From [`utmpx.h`]
+    ///
+    /// ```C
+    /// struct utmpx {
+    ///     char ut_user[_UTX_USERSIZE];    /* login name */
+    ///     char ut_id[_UTX_IDSIZE];        /* id */
+    ///     char ut_line[_UTX_LINESIZE];    /* tty name */
+    ///     pid_t ut_pid;                   /* process id creating the entry */
+    ///     short ut_type;                  /* type of this entry */
+    ///     struct timeval ut_tv;           /* time entry was created */
+    ///     char ut_host[_UTX_HOSTSIZE];    /* host name */
+    ///     __uint32_t ut_pad[16];          /* reserved for future use */
+    /// };
+    /// ```
+    ///
+    /// ---
+    ///
+    /// ```text
+    /// utmpx                   sizeof 640
+    /// utmpx.ut_user      @  0 sizeof 256
+    /// utmpx.ut_id        @256 sizeof   4
+    /// utmpx.ut_line      @260 sizeof  32
+    /// utmpx.ut_pid       @292 sizeof   4
+    /// utmpx.ut_type      @296 sizeof   2
+    /// utmpx.ut_tv        @304 sizeof  16
+    /// utmpx.ut_tv.tv_sec @304 sizeof   8
+    /// utmpx.ut_tv.tv_usec@312 sizeof   4
+    /// utmpx.ut_host      @320 sizeof 256
+    /// utmpx.ut_pad       @576 sizeof  64
+    /// ```
+    ///
+    /// [`utmpx.h`]: https://github.com/apple-oss-distributions/Libc/blob/Libc-1534.40.2/include/utmpx.h
+    #[derive(Clone, Copy)]
+    #[repr(C, align(8))]
+    #[allow(non_camel_case_types)]
+    pub struct utmpx {
+        pub ut_user: [c_char; UTX_USERSIZE],
+        pub ut_id: [c_char; UTX_IDSIZE],
+        pub ut_line: [c_char; UTX_LINESIZE],
+        pub ut_pid: pid_t,
+        pub ut_type: c_short,
+        pub __gap1: [u8; 6],
+        pub ut_tv: timeval,
+        pub ut_host: [c_char; UTX_HOSTSIZE],
+        pub ut_pad: [u32; 16],
+    }
+
+    pub const UTMPX_SZ: usize = size_of::<utmpx>();
+    pub const UTMPX_SZ_FO: FileOffset = UTMPX_SZ as FileOffset;
+    pub const UTMPX_TIMEVALUE_OFFSET: usize = offset_of!(utmpx, ut_tv);
+    pub const UTMPX_TIMEVALUE_SZ: usize = TIMEVAL_SZ;
+    assertcp_eq!(UTMPX_SZ, 640);
+    assertcp_eq!(offset_of!(utmpx, ut_user), 0);
+    assertcp_eq!(offset_of!(utmpx, ut_id), 256);
+    assertcp_eq!(offset_of!(utmpx, ut_line), 260);
+    assertcp_eq!(offset_of!(utmpx, ut_pid), 292);
+    assertcp_eq!(offset_of!(utmpx, ut_type), 296);
+    assertcp_eq!(offset_of!(utmpx, __gap1), 298);
+    assertcp_eq!(offset_of!(utmpx, ut_tv), 304);
+    assertcp_eq!(offset_of!(utmpx, ut_host), 320);
+    assertcp_eq!(offset_of!(utmpx, ut_pad), 576);
+
+    /// Numeric fields of `utmpx` as (offset, size)
+    pub const UTMPX_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(utmpx, ut_pid), size_of::<pid_t>()),
+        (offset_of!(utmpx, ut_type), size_of::<c_short>()),
+        (UTMPX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_sec), size_of::<time_t>()),
+        (UTMPX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_usec), size_of::<suseconds_t>()),
+    ];
+
+    pub const PATH_UTMPX: &str = "/var/run/utmpx";
+
+    impl utmpx {
+        pub fn ut_user(&self) -> &CStr {
+            unsafe { CStr::from_ptr(self.ut_user[..UTX_USERSIZE].as_ptr()) }
+        }
+        pub fn ut_id(&self) -> &CStr {
+            unsafe { CStr::from_ptr(self.ut_id[..UTX_IDSIZE].as_ptr()) }
+        }
+        pub fn ut_line(&self) -> &CStr {
+            unsafe { CStr::from_ptr(self.ut_line[..UTX_LINESIZE].as_ptr()) }
+        }
+        pub fn ut_host(&self) -> &CStr {
+            unsafe { CStr::from_ptr(self.ut_host[..UTX_HOSTSIZE].as_ptr()) }
+        }
+    }
+
+    /// From [`utmpx.h`]
+    /// ```C
+    /// #define EMPTY           0
+    /// #define RUN_LVL         1
+    /// #define BOOT_TIME       2
+    /// #define OLD_TIME        3
+    /// #define NEW_TIME        4
+    /// #define INIT_PROCESS    5
+    /// #define LOGIN_PROCESS   6
+    /// #define USER_PROCESS    7
+    /// #define DEAD_PROCESS    8
+    /// #define ACCOUNTING      9
+    /// #define SIGNATURE       10
+    /// #define SHUTDOWN_TIME   11
+    /// ```
+    ///
+    /// [`utmpx.h`]: https://github.com/apple-oss-distributions/Libc/blob/Libc-1534.40.2/include/utmpx.h
+    pub const UT_TYPES: [c_short; 12] = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11
+    ];
+}
+
 /// FixedStruct definitions found on NetBSD 9.3 i686 (x86_32).
 /// These are slightly different than amd64 (x86_64).
 #[allow(non_camel_case_types, unused)]
@@ -1376,6 +1965,9 @@ pub mod netbsd_x8632 {
 
     pub const AC_FLAGS_MASK: u8 = AFORK | ASU | ACOMPAT | ACORE | AXSIG;
 
+    /// Units per second of `comp_t` values.
+    pub const AHZ: u64 = 64;
+
     pub const ACCT_COMM_SIZE: usize = 16;
 
     /// From [`/usr/include/sys/acct.h`] on NetBSD 9.3:
@@ -1469,6 +2061,19 @@ pub mod netbsd_x8632 {
     assertcp_eq!(offset_of!(acct, ac_tty), 44);
     assertcp_eq!(offset_of!(acct, ac_flag), 52);
 
+    /// Numeric fields of `acct` as (offset, size)
+    pub const ACCT_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(acct, ac_utime), size_of::<comp_t>()),
+        (offset_of!(acct, ac_stime), size_of::<comp_t>()),
+        (offset_of!(acct, ac_etime), size_of::<comp_t>()),
+        (offset_of!(acct, ac_btime), size_of::<time_t>()),
+        (offset_of!(acct, ac_uid), size_of::<uid_t>()),
+        (offset_of!(acct, ac_gid), size_of::<gid_t>()),
+        (offset_of!(acct, ac_mem), size_of::<uint16_t>()),
+        (offset_of!(acct, ac_io), size_of::<comp_t>()),
+        (offset_of!(acct, ac_tty), size_of::<dev_t>()),
+    ];
+
     impl acct {
         pub fn ac_comm(&self) -> &CStr {
             unsafe { CStr::from_ptr(self.ac_comm.as_ptr()) }
@@ -1541,6 +2146,12 @@ pub mod netbsd_x8632 {
     assertcp_eq!(offset_of!(lastlogx, ll_host), 44);
     assertcp_eq!(offset_of!(lastlogx, ll_ss), 300);
 
+    /// Numeric fields of `lastlogx` as (offset, size)
+    pub const LASTLOGX_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (LASTLOGX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_sec), size_of::<i64>()),
+        (LASTLOGX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_usec), size_of::<i32>()),
+    ];
+
     pub const PATH_LASTLOGX: &str = "/var/log/lastlogx";
 
     impl lastlogx {
@@ -1620,6 +2231,17 @@ pub mod netbsd_x8632 {
     assertcp_eq!(offset_of!(utmpx, ut_tv), 464);
     assertcp_eq!(offset_of!(utmpx, ut_pad), 476);
 
+    /// Numeric fields of `utmpx` as (offset, size)
+    pub const UTMPX_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(utmpx, ut_session), size_of::<uint16_t>()),
+        (offset_of!(utmpx, ut_type), size_of::<uint16_t>()),
+        (offset_of!(utmpx, ut_pid), size_of::<pid_t>()),
+        (offset_of!(utmpx, ut_exit) + offset_of!(ut_exit, e_termination), size_of::<uint16_t>()),
+        (offset_of!(utmpx, ut_exit) + offset_of!(ut_exit, e_exit), size_of::<uint16_t>()),
+        (UTMPX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_sec), size_of::<i64>()),
+        (UTMPX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_usec), size_of::<i32>()),
+    ];
+
     pub const PATH_UTMPX: &str = "/var/run/utmpx";
     pub const PATH_WTMPX: &str = "/var/log/wtmpx";
 
@@ -1761,6 +2383,11 @@ pub mod netbsd_x8664 {
     assertcp_eq!(offset_of!(lastlog, ll_line), 8);
     assertcp_eq!(offset_of!(lastlog, ll_host), 16);
 
+    /// Numeric fields of `lastlog` as (offset, size)
+    pub const LASTLOG_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(lastlog, ll_time), size_of::<time_t>()),
+    ];
+
     pub const PATH_LASTLOG: &str = "/var/log/lastlog";
 
     impl lastlog {
@@ -1835,6 +2462,12 @@ pub mod netbsd_x8664 {
     assertcp_eq!(offset_of!(lastlogx, ll_host), 48);
     assertcp_eq!(offset_of!(lastlogx, ll_ss), 304);
 
+    /// Numeric fields of `lastlogx` as (offset, size)
+    pub const LASTLOGX_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (LASTLOGX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_sec), size_of::<i64>()),
+        (LASTLOGX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_usec), size_of::<i32>()),
+    ];
+
     pub const PATH_LASTLOGX: &str = "/var/log/lastlogx";
 
     impl lastlogx {
@@ -1895,6 +2528,11 @@ pub mod netbsd_x8664 {
     assertcp_eq!(offset_of!(utmp, ut_host), 16);
     assertcp_eq!(offset_of!(utmp, ut_time), 32);
 
+    /// Numeric fields of `utmp` as (offset, size)
+    pub const UTMP_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(utmp, ut_time), size_of::<time_t>()),
+    ];
+
     pub const PATH_UTMP: &str = "/var/run/utmp";
     pub const PATH_WTMP: &str = "/var/log/wtmp";
 
@@ -2028,6 +2666,17 @@ pub mod netbsd_x8664 {
     assertcp_eq!(offset_of!(utmpx, ut_tv), 464);
     assertcp_eq!(offset_of!(utmpx, ut_pad), 480);
 
+    /// Numeric fields of `utmpx` as (offset, size)
+    pub const UTMPX_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(utmpx, ut_session), size_of::<uint16_t>()),
+        (offset_of!(utmpx, ut_type), size_of::<uint16_t>()),
+        (offset_of!(utmpx, ut_pid), size_of::<pid_t>()),
+        (offset_of!(utmpx, ut_exit) + offset_of!(ut_exit, e_termination), size_of::<uint16_t>()),
+        (offset_of!(utmpx, ut_exit) + offset_of!(ut_exit, e_exit), size_of::<uint16_t>()),
+        (UTMPX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_sec), size_of::<i64>()),
+        (UTMPX_TIMEVALUE_OFFSET + offset_of!(timeval, tv_usec), size_of::<i32>()),
+    ];
+
     pub const PATH_UTMPX: &str = "/var/run/utmpx";
     pub const PATH_WTMPX: &str = "/var/log/wtmpx";
 
@@ -2141,6 +2790,11 @@ pub mod openbsd_x86 {
     assertcp_eq!(offset_of!(lastlog, ll_line), 8);
     assertcp_eq!(offset_of!(lastlog, ll_host), 16);
 
+    /// Numeric fields of `lastlog` as (offset, size)
+    pub const LASTLOG_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(lastlog, ll_time), size_of::<time_t>()),
+    ];
+
     pub const PATH_LASTLOG: &str = "/var/log/lastlog";
 
     /// Helpers for use in `fmt::Debug` trait.
@@ -2211,6 +2865,11 @@ pub mod openbsd_x86 {
     assertcp_eq!(offset_of!(utmp, ut_host), 40);
     assertcp_eq!(offset_of!(utmp, ut_time), 296);
 
+    /// Numeric fields of `utmp` as (offset, size)
+    pub const UTMP_NUMERIC_FIELDS: &[(usize, usize)] = &[
+        (offset_of!(utmp, ut_time), size_of::<time_t>()),
+    ];
+
     pub const PATH_UTMP: &str = "/var/run/utmp";
     pub const PATH_WTMP: &str = "/var/log/wtmp";
 
@@ -2241,14 +2900,17 @@ pub mod openbsd_x86 {
 }
 
 /// Maximum size among all `acct`/`lastlog`/`utmp`/etc. C structs
-pub const ENTRY_SZ_MAX: usize = max16(
+pub const ENTRY_SZ_MAX: usize = max19(
     freebsd_x8664::UTMPX_SZ,
     linux_arm64aarch64::LASTLOG_SZ,
     linux_arm64aarch64::UTMPX_SZ,
+    linux_sqlite::LASTLOG2_SZ,
     linux_x86::ACCT_SZ,
     linux_x86::ACCT_V3_SZ,
     linux_x86::LASTLOG_SZ,
     linux_x86::UTMPX_SZ,
+    macos_x8664::LASTLOGX_SZ,
+    macos_x8664::UTMPX_SZ,
     netbsd_x8632::ACCT_SZ,
     netbsd_x8632::LASTLOGX_SZ,
     netbsd_x8632::UTMPX_SZ,
@@ -2261,14 +2923,17 @@ pub const ENTRY_SZ_MAX: usize = max16(
 );
 
 /// Minimum size among all `acct`/`lastlog`/`utmp`/etc. C structs
-pub const ENTRY_SZ_MIN: usize = min16(
+pub const ENTRY_SZ_MIN: usize = min19(
     freebsd_x8664::UTMPX_SZ,
     linux_arm64aarch64::LASTLOG_SZ,
     linux_arm64aarch64::UTMPX_SZ,
+    linux_sqlite::LASTLOG2_SZ,
     linux_x86::ACCT_SZ,
     linux_x86::ACCT_V3_SZ,
     linux_x86::LASTLOG_SZ,
     linux_x86::UTMPX_SZ,
+    macos_x8664::LASTLOGX_SZ,
+    macos_x8664::UTMPX_SZ,
     netbsd_x8632::ACCT_SZ,
     netbsd_x8632::LASTLOGX_SZ,
     netbsd_x8632::UTMPX_SZ,
@@ -2281,14 +2946,17 @@ pub const ENTRY_SZ_MIN: usize = min16(
 );
 
 /// Maximum size among all time values for all C structs
-pub const TIMEVAL_SZ_MAX: usize = max16(
+pub const TIMEVAL_SZ_MAX: usize = max19(
     freebsd_x8664::TIMEVAL_SZ,
     linux_arm64aarch64::LASTLOG_TIMEVALUE_SZ,
     linux_arm64aarch64::UTMPX_TIMEVALUE_SZ,
+    linux_sqlite::LASTLOG2_TIMEVALUE_SZ,
     linux_x86::ACCT_TIMEVALUE_SZ,
     linux_x86::ACCT_V3_TIMEVALUE_SZ,
     linux_x86::LASTLOG_TIMEVALUE_SZ,
     linux_x86::UTMPX_TIMEVALUE_SZ,
+    macos_x8664::LASTLOGX_TIMEVALUE_SZ,
+    macos_x8664::UTMPX_TIMEVALUE_SZ,
     netbsd_x8632::ACCT_TIMEVALUE_SZ,
     netbsd_x8632::LASTLOGX_TIMEVALUE_SZ,
     netbsd_x8632::UTMPX_TIMEVALUE_SZ,
@@ -2398,10 +3066,13 @@ where Self: Send,
     fn as_freebsd_x8664_utmpx(&self) -> &freebsd_x8664::utmpx;
     fn as_linux_arm64aarch64_lastlog(&self) -> &linux_arm64aarch64::lastlog;
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx;
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2;
     fn as_linux_x86_acct(&self) -> &linux_x86::acct;
     fn as_linux_x86_acct_v3(&self) -> &linux_x86::acct_v3;
     fn as_linux_x86_lastlog(&self) -> &linux_x86::lastlog;
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx;
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx;
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx;
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct;
     fn as_netbsd_x8632_lastlogx(&self) -> &netbsd_x8632::lastlogx;
     fn as_netbsd_x8632_utmpx(&self) -> &netbsd_x8632::utmpx;
@@ -2425,6 +3096,9 @@ impl fmt::Debug for dyn FixedStructTrait {
             FixedStructType::Fs_Linux_Arm64Aarch64_Utmpx => {
                 self.as_linux_arm64aarch64_utmpx().fmt(f)
             }
+            FixedStructType::Fs_Linux_Sqlite_Lastlog2 => {
+                self.as_linux_sqlite_lastlog2().fmt(f)
+            }
             FixedStructType::Fs_Linux_x86_Acct => {
                 self.as_linux_x86_acct().fmt(f)
             }
@@ -2437,6 +3111,12 @@ impl fmt::Debug for dyn FixedStructTrait {
             FixedStructType::Fs_Linux_x86_Utmpx => {
                 self.as_linux_x86_utmpx().fmt(f)
             }
+            FixedStructType::Fs_Macos_x8664_Lastlogx => {
+                self.as_macos_x8664_lastlogx().fmt(f)
+            }
+            FixedStructType::Fs_Macos_x8664_Utmpx => {
+                self.as_macos_x8664_utmpx().fmt(f)
+            }
             FixedStructType::Fs_Netbsd_x8632_Acct => {
                 self.as_netbsd_x8632_acct().fmt(f)
             }
@@ -2487,6 +3167,9 @@ impl FixedStructTrait for freebsd_x8664::utmpx {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on freebsd_x8664::utmpx");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on freebsd_x8664::utmpx");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on freebsd_x8664::utmpx");
     }
@@ -2499,6 +3182,12 @@ impl FixedStructTrait for freebsd_x8664::utmpx {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on freebsd_x8664::utmpx");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on freebsd_x8664::utmpx");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on freebsd_x8664::utmpx");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on freebsd_x8664::utmpx");
     }
@@ -2568,6 +3257,9 @@ impl FixedStructTrait for linux_arm64aarch64::lastlog {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on freebsd_x8664::utmpx");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on linux_arm64aarch64::lastlog");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on freebsd_x8664::utmpx");
     }
@@ -2580,6 +3272,12 @@ impl FixedStructTrait for linux_arm64aarch64::lastlog {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on freebsd_x8664::utmpx");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on linux_arm64aarch64::lastlog");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on linux_arm64aarch64::lastlog");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on freebsd_x8664::utmpx");
     }
@@ -2639,6 +3337,9 @@ impl FixedStructTrait for linux_arm64aarch64::utmpx {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         self
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on linux_arm64aarch64::utmpx");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on linux_arm64aarch64::utmpx");
     }
@@ -2651,6 +3352,12 @@ impl FixedStructTrait for linux_arm64aarch64::utmpx {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on linux_arm64aarch64::utmpx");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on linux_arm64aarch64::utmpx");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on linux_arm64aarch64::utmpx");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on linux_arm64aarch64::utmpx");
     }
@@ -2697,6 +3404,88 @@ impl fmt::Debug for linux_arm64aarch64::utmpx {
     }
 }
 
+// linux_sqlite::lastlog2
+
+impl FixedStructTrait for linux_sqlite::lastlog2 {
+    fn fixedstruct_type(&self) -> FixedStructType {
+        FixedStructType::Fs_Linux_Sqlite_Lastlog2
+    }
+    fn size(&self) -> usize {
+        linux_sqlite::LASTLOG2_SZ
+    }
+
+    fn as_freebsd_x8664_utmpx(&self) -> &freebsd_x8664::utmpx {
+        panic!("as_freebsd_x8664_utmpx() called on linux_sqlite::lastlog2");
+    }
+    fn as_linux_arm64aarch64_lastlog(&self) -> &linux_arm64aarch64::lastlog {
+        panic!("as_linux_arm64aarch64_lastlog() called on linux_sqlite::lastlog2");
+    }
+    fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
+        panic!("as_linux_arm64aarch64_utmpx() called on linux_sqlite::lastlog2");
+    }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        self
+    }
+    fn as_linux_x86_acct(&self) -> &linux_x86::acct {
+        panic!("as_linux_x86_acct() called on linux_sqlite::lastlog2");
+    }
+    fn as_linux_x86_acct_v3(&self) -> &linux_x86::acct_v3 {
+        panic!("as_linux_x86_acct_v3() called on linux_sqlite::lastlog2");
+    }
+    fn as_linux_x86_lastlog(&self) -> &linux_x86::lastlog {
+        panic!("as_linux_x86_lastlog() called on linux_sqlite::lastlog2");
+    }
+    fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
+        panic!("as_linux_x86_utmpx() called on linux_sqlite::lastlog2");
+    }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on linux_sqlite::lastlog2");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on linux_sqlite::lastlog2");
+    }
+    fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
+        panic!("as_netbsd_x8632_acct() called on linux_sqlite::lastlog2");
+    }
+    fn as_netbsd_x8632_lastlogx(&self) -> &netbsd_x8632::lastlogx {
+        panic!("as_netbsd_x8632_lastlogx() called on linux_sqlite::lastlog2");
+    }
+    fn as_netbsd_x8632_utmpx(&self) -> &netbsd_x8632::utmpx {
+        panic!("as_netbsd_x8632_utmpx() called on linux_sqlite::lastlog2");
+    }
+    fn as_netbsd_x8664_lastlog(&self) -> &netbsd_x8664::lastlog {
+        panic!("as_netbsd_x8664_lastlog() called on linux_sqlite::lastlog2");
+    }
+    fn as_netbsd_x8664_lastlogx(&self) -> &netbsd_x8664::lastlogx {
+        panic!("as_netbsd_x8664_lastlogx() called on linux_sqlite::lastlog2");
+    }
+    fn as_netbsd_x8664_utmp(&self) -> &netbsd_x8664::utmp {
+        panic!("as_netbsd_x8664_utmp() called on linux_sqlite::lastlog2");
+    }
+    fn as_netbsd_x8664_utmpx(&self) -> &netbsd_x8664::utmpx {
+        panic!("as_netbsd_x8664_utmpx() called on linux_sqlite::lastlog2");
+    }
+    fn as_openbsd_x86_lastlog(&self) -> &openbsd_x86::lastlog {
+        panic!("as_openbsd_x86_lastlog() called on linux_sqlite::lastlog2");
+    }
+    fn as_openbsd_x86_utmp(&self) -> &openbsd_x86::utmp {
+        panic!("as_openbsd_x86_utmp() called on linux_sqlite::lastlog2");
+    }
+}
+
+impl fmt::Debug for linux_sqlite::lastlog2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("linux_sqlite::lastlog2")
+            .field("size", &self.size())
+            .field("ll_time", &self.ll_time)
+            .field("ll_name", &self.ll_name())
+            .field("ll_line", &self.ll_line())
+            .field("ll_host", &self.ll_host())
+            .field("ll_service", &self.ll_service())
+            .finish()
+    }
+}
+
 // linux_x86::acct
 
 impl FixedStructTrait for linux_x86::acct {
@@ -2716,6 +3505,9 @@ impl FixedStructTrait for linux_x86::acct {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on linux_x86::acct");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on linux_x86::acct");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         self
     }
@@ -2728,6 +3520,12 @@ impl FixedStructTrait for linux_x86::acct {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on linux_x86::acct");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on linux_x86::acct");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on linux_x86::acct");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on linux_x86::acct");
     }
@@ -2800,6 +3598,9 @@ impl FixedStructTrait for linux_x86::acct_v3 {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on linux_x86::acct_v3");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on linux_x86::acct_v3");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on linux_x86::acct_v3");
     }
@@ -2812,6 +3613,12 @@ impl FixedStructTrait for linux_x86::acct_v3 {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on linux_x86::acct_v3");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on linux_x86::acct_v3");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on linux_x86::acct_v3");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on linux_x86::acct_v3");
     }
@@ -2886,6 +3693,9 @@ impl FixedStructTrait for linux_x86::lastlog {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on linux_x86::lastlog");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on linux_x86::lastlog");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on linux_x86::lastlog");
     }
@@ -2898,6 +3708,12 @@ impl FixedStructTrait for linux_x86::lastlog {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on linux_x86::lastlog");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on linux_x86::lastlog");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on linux_x86::lastlog");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on linux_x86::lastlog");
     }
@@ -2957,6 +3773,9 @@ impl FixedStructTrait for linux_x86::utmpx {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on linux_x86::utmpx");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on linux_x86::utmpx");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on linux_x86::utmpx");
     }
@@ -2969,6 +3788,12 @@ impl FixedStructTrait for linux_x86::utmpx {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         self
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on linux_x86::utmpx");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on linux_x86::utmpx");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on linux_x86::utmpx");
     }
@@ -3021,6 +3846,177 @@ impl fmt::Debug for linux_x86::utmpx {
     }
 }
 
+// macos_x8664::lastlogx
+
+impl FixedStructTrait for macos_x8664::lastlogx {
+    fn fixedstruct_type(&self) -> FixedStructType {
+        FixedStructType::Fs_Macos_x8664_Lastlogx
+    }
+    fn size(&self) -> usize {
+        macos_x8664::LASTLOGX_SZ
+    }
+
+    fn as_freebsd_x8664_utmpx(&self) -> &freebsd_x8664::utmpx {
+        panic!("as_freebsd_x8664_utmpx() called on macos_x8664::lastlogx");
+    }
+    fn as_linux_arm64aarch64_lastlog(&self) -> &linux_arm64aarch64::lastlog {
+        panic!("as_linux_arm64aarch64_lastlog() called on macos_x8664::lastlogx");
+    }
+    fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
+        panic!("as_linux_arm64aarch64_utmpx() called on macos_x8664::lastlogx");
+    }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on macos_x8664::lastlogx");
+    }
+    fn as_linux_x86_acct(&self) -> &linux_x86::acct {
+        panic!("as_linux_x86_acct() called on macos_x8664::lastlogx");
+    }
+    fn as_linux_x86_acct_v3(&self) -> &linux_x86::acct_v3 {
+        panic!("as_linux_x86_acct_v3() called on macos_x8664::lastlogx");
+    }
+    fn as_linux_x86_lastlog(&self) -> &linux_x86::lastlog {
+        panic!("as_linux_x86_lastlog() called on macos_x8664::lastlogx");
+    }
+    fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
+        panic!("as_linux_x86_utmpx() called on macos_x8664::lastlogx");
+    }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        self
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on macos_x8664::lastlogx");
+    }
+    fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
+        panic!("as_netbsd_x8632_acct() called on macos_x8664::lastlogx");
+    }
+    fn as_netbsd_x8632_lastlogx(&self) -> &netbsd_x8632::lastlogx {
+        panic!("as_netbsd_x8632_lastlogx() called on macos_x8664::lastlogx");
+    }
+    fn as_netbsd_x8632_utmpx(&self) -> &netbsd_x8632::utmpx {
+        panic!("as_netbsd_x8632_utmpx() called on macos_x8664::lastlogx");
+    }
+    fn as_netbsd_x8664_lastlog(&self) -> &netbsd_x8664::lastlog {
+        panic!("as_netbsd_x8664_lastlog() called on macos_x8664::lastlogx");
+    }
+    fn as_netbsd_x8664_lastlogx(&self) -> &netbsd_x8664::lastlogx {
+        panic!("as_netbsd_x8664_lastlogx() called on macos_x8664::lastlogx");
+    }
+    fn as_netbsd_x8664_utmp(&self) -> &netbsd_x8664::utmp {
+        panic!("as_netbsd_x8664_utmp() called on macos_x8664::lastlogx");
+    }
+    fn as_netbsd_x8664_utmpx(&self) -> &netbsd_x8664::utmpx {
+        panic!("as_netbsd_x8664_utmpx() called on macos_x8664::lastlogx");
+    }
+    fn as_openbsd_x86_lastlog(&self) -> &openbsd_x86::lastlog {
+        panic!("as_openbsd_x86_lastlog() called on macos_x8664::lastlogx");
+    }
+    fn as_openbsd_x86_utmp(&self) -> &openbsd_x86::utmp {
+        panic!("as_openbsd_x86_utmp() called on macos_x8664::lastlogx");
+    }
+}
+
+impl fmt::Debug for macos_x8664::lastlogx {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("macos_x8664::lastlogx")
+            .field("size", &self.size())
+            .field("ll_tv.tv_sec", &self.ll_tv.tv_sec)
+            .field("ll_tv.tv_usec", &self.ll_tv.tv_usec)
+            .field("ll_line", &self.ll_line())
+            .field("ll_host", &self.ll_host())
+            .finish()
+    }
+}
+
+// macos_x8664::utmpx
+
+impl FixedStructTrait for macos_x8664::utmpx {
+    fn fixedstruct_type(&self) -> FixedStructType {
+        FixedStructType::Fs_Macos_x8664_Utmpx
+    }
+    fn size(&self) -> usize {
+        macos_x8664::UTMPX_SZ
+    }
+
+    fn as_freebsd_x8664_utmpx(&self) -> &freebsd_x8664::utmpx {
+        panic!("as_freebsd_x8664_utmpx() called on macos_x8664::utmpx");
+    }
+    fn as_linux_arm64aarch64_lastlog(&self) -> &linux_arm64aarch64::lastlog {
+        panic!("as_linux_arm64aarch64_lastlog() called on macos_x8664::utmpx");
+    }
+    fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
+        panic!("as_linux_arm64aarch64_utmpx() called on macos_x8664::utmpx");
+    }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on macos_x8664::utmpx");
+    }
+    fn as_linux_x86_acct(&self) -> &linux_x86::acct {
+        panic!("as_linux_x86_acct() called on macos_x8664::utmpx");
+    }
+    fn as_linux_x86_acct_v3(&self) -> &linux_x86::acct_v3 {
+        panic!("as_linux_x86_acct_v3() called on macos_x8664::utmpx");
+    }
+    fn as_linux_x86_lastlog(&self) -> &linux_x86::lastlog {
+        panic!("as_linux_x86_lastlog() called on macos_x8664::utmpx");
+    }
+    fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
+        panic!("as_linux_x86_utmpx() called on macos_x8664::utmpx");
+    }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on macos_x8664::utmpx");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        self
+    }
+    fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
+        panic!("as_netbsd_x8632_acct() called on macos_x8664::utmpx");
+    }
+    fn as_netbsd_x8632_lastlogx(&self) -> &netbsd_x8632::lastlogx {
+        panic!("as_netbsd_x8632_lastlogx() called on macos_x8664::utmpx");
+    }
+    fn as_netbsd_x8632_utmpx(&self) -> &netbsd_x8632::utmpx {
+        panic!("as_netbsd_x8632_utmpx() called on macos_x8664::utmpx");
+    }
+    fn as_netbsd_x8664_lastlog(&self) -> &netbsd_x8664::lastlog {
+        panic!("as_netbsd_x8664_lastlog() called on macos_x8664::utmpx");
+    }
+    fn as_netbsd_x8664_lastlogx(&self) -> &netbsd_x8664::lastlogx {
+        panic!("as_netbsd_x8664_lastlogx() called on macos_x8664::utmpx");
+    }
+    fn as_netbsd_x8664_utmp(&self) -> &netbsd_x8664::utmp {
+        panic!("as_netbsd_x8664_utmp() called on macos_x8664::utmpx");
+    }
+    fn as_netbsd_x8664_utmpx(&self) -> &netbsd_x8664::utmpx {
+        panic!("as_netbsd_x8664_utmpx() called on macos_x8664::utmpx");
+    }
+    fn as_openbsd_x86_lastlog(&self) -> &openbsd_x86::lastlog {
+        panic!("as_openbsd_x86_lastlog() called on macos_x8664::utmpx");
+    }
+    fn as_openbsd_x86_utmp(&self) -> &openbsd_x86::utmp {
+        panic!("as_openbsd_x86_utmp() called on macos_x8664::utmpx");
+    }
+}
+
+impl fmt::Debug for macos_x8664::utmpx {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ut_type_s = match self.ut_type {
+            x if (0..UT_TYPE_VAL_TO_STR_LEN_i16).contains(&x) =>
+                format!("{} ({})", self.ut_type, UT_TYPE_VAL_TO_STR[self.ut_type as usize]),
+            _ => format!("{} (UNKNOWN)", self.ut_type),
+        };
+        f.debug_struct("macos_x8664::utmpx")
+            .field("size", &self.size())
+            .field("ut_user", &self.ut_user())
+            .field("ut_id", &self.ut_id())
+            .field("ut_line", &self.ut_line())
+            .field("ut_pid", &self.ut_pid)
+            .field("ut_type", &format_args!("{}", ut_type_s))
+            .field("ut_tv.tv_sec", &self.ut_tv.tv_sec)
+            .field("ut_tv.tv_usec", &self.ut_tv.tv_usec)
+            .field("ut_host", &self.ut_host())
+            .finish()
+    }
+}
+
 // netbsd_x8632::acct
 
 impl FixedStructTrait for netbsd_x8632::acct {
@@ -3040,6 +4036,9 @@ impl FixedStructTrait for netbsd_x8632::acct {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on netbsd_x8632::lastlog");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on netbsd_x8632::acct");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on netbsd_x8632::lastlog");
     }
@@ -3052,6 +4051,12 @@ impl FixedStructTrait for netbsd_x8632::acct {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on netbsd_x8632::lastlog");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on netbsd_x8632::acct");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on netbsd_x8632::acct");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         self
     }
@@ -3129,6 +4134,9 @@ impl FixedStructTrait for netbsd_x8632::lastlogx {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on netbsd_x8632::lastlogx");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on netbsd_x8632::lastlogx");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on netbsd_x8632::lastlogx");
     }
@@ -3141,6 +4149,12 @@ impl FixedStructTrait for netbsd_x8632::lastlogx {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on netbsd_x8632::lastlogx");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on netbsd_x8632::lastlogx");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on netbsd_x8632::lastlogx");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on netbsd_x8632::lastlogx");
     }
@@ -3206,6 +4220,9 @@ impl FixedStructTrait for netbsd_x8632::utmpx {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on netbsd_x8632::utmpx");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on netbsd_x8632::utmpx");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on netbsd_x8632::utmpx");
     }
@@ -3218,6 +4235,12 @@ impl FixedStructTrait for netbsd_x8632::utmpx {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on netbsd_x8632::utmpx");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on netbsd_x8632::utmpx");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on netbsd_x8632::utmpx");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on netbsd_x8632::utmpx");
     }
@@ -3291,6 +4314,9 @@ impl FixedStructTrait for netbsd_x8664::lastlog {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on netbsd_x8664::lastlog");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on netbsd_x8664::lastlog");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on netbsd_x8664::lastlog");
     }
@@ -3303,6 +4329,12 @@ impl FixedStructTrait for netbsd_x8664::lastlog {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on netbsd_x8664::lastlog");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on netbsd_x8664::lastlog");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on netbsd_x8664::lastlog");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on netbsd_x8664::lastlog");
     }
@@ -3362,6 +4394,9 @@ impl FixedStructTrait for netbsd_x8664::lastlogx {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on netbsd_x8664::lastlogx");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on netbsd_x8664::lastlogx");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on netbsd_x8664::lastlogx");
     }
@@ -3374,6 +4409,12 @@ impl FixedStructTrait for netbsd_x8664::lastlogx {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on netbsd_x8664::lastlogx");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on netbsd_x8664::lastlogx");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on netbsd_x8664::lastlogx");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on netbsd_x8664::lastlogx");
     }
@@ -3435,6 +4476,9 @@ impl FixedStructTrait for netbsd_x8664::utmp {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on netbsd_x8664::utmp");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on netbsd_x8664::utmp");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on netbsd_x8664::utmp");
     }
@@ -3447,6 +4491,12 @@ impl FixedStructTrait for netbsd_x8664::utmp {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on netbsd_x8664::utmp");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on netbsd_x8664::utmp");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on netbsd_x8664::utmp");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on netbsd_x8664::utmp");
     }
@@ -3507,6 +4557,9 @@ impl FixedStructTrait for netbsd_x8664::utmpx {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on netbsd_x8664::utmpx");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on netbsd_x8664::utmpx");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on netbsd_x8664::utmpx");
     }
@@ -3519,6 +4572,12 @@ impl FixedStructTrait for netbsd_x8664::utmpx {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on netbsd_x8664::utmpx");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on netbsd_x8664::utmpx");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on netbsd_x8664::utmpx");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on netbsd_x8664::utmpx");
     }
@@ -3586,6 +4645,9 @@ impl FixedStructTrait for openbsd_x86::lastlog {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on openbsd_x86::lastlog");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on openbsd_x86::lastlog");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on openbsd_x86::lastlog");
     }
@@ -3598,6 +4660,12 @@ impl FixedStructTrait for openbsd_x86::lastlog {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on openbsd_x86::lastlog");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on openbsd_x86::lastlog");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on openbsd_x86::lastlog");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on openbsd_x86::lastlog");
     }
@@ -3657,6 +4725,9 @@ impl FixedStructTrait for openbsd_x86::utmp {
     fn as_linux_arm64aarch64_utmpx(&self) -> &linux_arm64aarch64::utmpx {
         panic!("as_linux_arm64aarch64_utmpx() called on openbsd_x86::utmp");
     }
+    fn as_linux_sqlite_lastlog2(&self) -> &linux_sqlite::lastlog2 {
+        panic!("as_linux_sqlite_lastlog2() called on openbsd_x86::utmp");
+    }
     fn as_linux_x86_acct(&self) -> &linux_x86::acct {
         panic!("as_linux_x86_acct() called on openbsd_x86::utmp");
     }
@@ -3669,6 +4740,12 @@ impl FixedStructTrait for openbsd_x86::utmp {
     fn as_linux_x86_utmpx(&self) -> &linux_x86::utmpx {
         panic!("as_linux_x86_utmpx() called on openbsd_x86::utmp");
     }
+    fn as_macos_x8664_lastlogx(&self) -> &macos_x8664::lastlogx {
+        panic!("as_macos_x8664_lastlogx() called on openbsd_x86::utmp");
+    }
+    fn as_macos_x8664_utmpx(&self) -> &macos_x8664::utmpx {
+        panic!("as_macos_x8664_utmpx() called on openbsd_x86::utmp");
+    }
     fn as_netbsd_x8632_acct(&self) -> &netbsd_x8632::acct {
         panic!("as_netbsd_x8632_acct() called on openbsd_x86::utmp");
     }
@@ -3728,6 +4805,46 @@ pub(crate) fn filesz_to_types(filesz: FileSz, file_type_fixed_struct: &FileTypeF
 
     const BONUS: Score = 15;
 
+    // the SQLite databases are exported by `logindb` and the FreeBSD
+    // `utx.log` is exported by `utxlog` to a file of known structs so there
+    // is only one possible type; see `linux_sqlite` and `freebsd_x8664::futx`
+    match file_type_fixed_struct {
+        FileTypeFixedStruct::Lastlog2 => {
+            if filesz.is_multiple_of(linux_sqlite::LASTLOG2_SZ_FO) {
+                set.insert(FixedStructType::Fs_Linux_Sqlite_Lastlog2, BONUS);
+            }
+            if set.is_empty() {
+                defx!("return None; Lastlog2 set.is_empty");
+                return None;
+            }
+            defx!("return {} types; Lastlog2", set.len());
+            return Some(set);
+        }
+        FileTypeFixedStruct::Wtmpdb => {
+            if filesz.is_multiple_of(linux_arm64aarch64::UTMPX_SZ_FO) {
+                set.insert(FixedStructType::Fs_Linux_Arm64Aarch64_Utmpx, BONUS);
+            }
+            if set.is_empty() {
+                defx!("return None; Wtmpdb set.is_empty");
+                return None;
+            }
+            defx!("return {} types; Wtmpdb", set.len());
+            return Some(set);
+        }
+        FileTypeFixedStruct::UtxLog => {
+            if filesz.is_multiple_of(freebsd_x8664::UTMPX_SZ_FO) {
+                set.insert(FixedStructType::Fs_Freebsd_x8664_Utmpx, BONUS);
+            }
+            if set.is_empty() {
+                defx!("return None; UtxLog set.is_empty");
+                return None;
+            }
+            defx!("return {} types; UtxLog", set.len());
+            return Some(set);
+        }
+        _ => {}
+    }
+
     // if the given `FileTypeFixedStruct` matches the size offset then
     // it gets a bonus score.
     match file_type_fixed_struct {
@@ -3758,7 +4875,13 @@ pub(crate) fn filesz_to_types(filesz: FileSz, file_type_fixed_struct: &FileTypeF
                 set.insert(FixedStructType::Fs_Openbsd_x86_Lastlog, BONUS);
             }
         }
+        FileTypeFixedStruct::Lastlog2
+        | FileTypeFixedStruct::UtxLog
+        | FileTypeFixedStruct::Wtmpdb => {}
         FileTypeFixedStruct::Lastlogx => {
+            if filesz.is_multiple_of(macos_x8664::LASTLOGX_SZ_FO) {
+                set.insert(FixedStructType::Fs_Macos_x8664_Lastlogx, BONUS);
+            }
             if filesz % netbsd_x8632::LASTLOGX_SZ_FO == 0 {
                 set.insert(FixedStructType::Fs_Netbsd_x8632_Lastlogx, BONUS);
             }
@@ -3781,7 +4904,9 @@ pub(crate) fn filesz_to_types(filesz: FileSz, file_type_fixed_struct: &FileTypeF
             if filesz % linux_x86::UTMPX_SZ_FO == 0 {
                 set.insert(FixedStructType::Fs_Linux_x86_Utmpx, BONUS);
             }
-
+            if filesz.is_multiple_of(macos_x8664::UTMPX_SZ_FO) {
+                set.insert(FixedStructType::Fs_Macos_x8664_Utmpx, BONUS);
+            }
             if filesz % netbsd_x8632::UTMPX_SZ_FO == 0 {
                 set.insert(FixedStructType::Fs_Netbsd_x8632_Utmpx, BONUS);
             }
@@ -3815,6 +4940,14 @@ pub(crate) fn filesz_to_types(filesz: FileSz, file_type_fixed_struct: &FileTypeF
     if filesz % linux_x86::UTMPX_SZ_FO == 0 {
         set.entry(FixedStructType::Fs_Linux_x86_Utmpx).or_insert(0);
     }
+    // `macos_x8664::lastlogx` is the same size as `openbsd_x86::utmp`;
+    // `score_fixedstruct` distinguishes them
+    if filesz.is_multiple_of(macos_x8664::LASTLOGX_SZ_FO) {
+        set.entry(FixedStructType::Fs_Macos_x8664_Lastlogx).or_insert(0);
+    }
+    if filesz.is_multiple_of(macos_x8664::UTMPX_SZ_FO) {
+        set.entry(FixedStructType::Fs_Macos_x8664_Utmpx).or_insert(0);
+    }
     if filesz % netbsd_x8632::ACCT_SZ_FO == 0 {
         set.entry(FixedStructType::Fs_Netbsd_x8632_Acct).or_insert(0);
     }
@@ -3850,6 +4983,211 @@ pub(crate) fn filesz_to_types(filesz: FileSz, file_type_fixed_struct: &FileTypeF
     Some(set)
 }
 
+/// The kind of a `utmp` or `utmpx` entry, normalized across platforms.
+///
+/// The value of `ut_type` varies among platforms, e.g. FreeBSD
+/// `USER_PROCESS` is `4` while Linux `USER_PROCESS` is `7`. Older BSD `utmp`
+/// structs have no `ut_type` so the kind is derived from the `ut_line` and
+/// `ut_name`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UtmpKind {
+    Empty,
+    RunLevel,
+    BootTime,
+    /// system shutdown; Linux `RUN_LVL` with user "shutdown",
+    /// FreeBSD `SHUTDOWN_TIME`, NetBSD `DOWN_TIME`
+    Shutdown,
+    OldTime,
+    NewTime,
+    InitProcess,
+    LoginProcess,
+    UserProcess,
+    DeadProcess,
+    /// NetBSD `ACCOUNTING`, `SIGNATURE`, or an unknown `ut_type`
+    Other,
+}
+
+/// The login accounting fields of a `utmp` or `utmpx` entry.
+///
+/// Returned by [`FixedStruct::utmp_fields`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UtmpFields {
+    pub kind: UtmpKind,
+    /// `ut_pid`; `0` for structs without a `ut_pid`
+    pub pid: i64,
+    pub line: String,
+    /// `ut_id`; empty for structs without a `ut_id`
+    pub id: String,
+    /// `ut_user` or `ut_name`
+    pub user: String,
+    pub host: String,
+}
+
+/// Copy the C string `cstr` up to the first null or the end of the array.
+fn cstrn_to_string(cstr: &[std::ffi::c_char]) -> String {
+    let bytes: Vec<u8> = cstr
+        .iter()
+        .take_while(|c| **c != 0)
+        .map(|c| *c as u8)
+        .collect();
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Map the System V `ut_type` values used by Linux and NetBSD to a
+/// [`UtmpKind`].
+const fn ut_type_sysv_to_utmpkind(ut_type: i64) -> UtmpKind {
+    match ut_type {
+        0 => UtmpKind::Empty,
+        1 => UtmpKind::RunLevel,
+        2 => UtmpKind::BootTime,
+        3 => UtmpKind::NewTime,
+        4 => UtmpKind::OldTime,
+        5 => UtmpKind::InitProcess,
+        6 => UtmpKind::LoginProcess,
+        7 => UtmpKind::UserProcess,
+        8 => UtmpKind::DeadProcess,
+        11 => UtmpKind::Shutdown,
+        _ => UtmpKind::Other,
+    }
+}
+
+/// Map the FreeBSD `ut_type` values to a [`UtmpKind`].
+const fn ut_type_freebsd_to_utmpkind(ut_type: i64) -> UtmpKind {
+    match ut_type {
+        0 => UtmpKind::Empty,
+        1 => UtmpKind::BootTime,
+        2 => UtmpKind::OldTime,
+        3 => UtmpKind::NewTime,
+        4 => UtmpKind::UserProcess,
+        5 => UtmpKind::InitProcess,
+        6 => UtmpKind::LoginProcess,
+        7 => UtmpKind::DeadProcess,
+        8 => UtmpKind::Shutdown,
+        _ => UtmpKind::Other,
+    }
+}
+
+/// Map the macOS `ut_type` values to a [`UtmpKind`].
+///
+/// Like System V except `OLD_TIME` is `3` and `NEW_TIME` is `4`.
+const fn ut_type_macos_to_utmpkind(ut_type: i64) -> UtmpKind {
+    match ut_type {
+        0 => UtmpKind::Empty,
+        1 => UtmpKind::RunLevel,
+        2 => UtmpKind::BootTime,
+        3 => UtmpKind::OldTime,
+        4 => UtmpKind::NewTime,
+        5 => UtmpKind::InitProcess,
+        6 => UtmpKind::LoginProcess,
+        7 => UtmpKind::UserProcess,
+        8 => UtmpKind::DeadProcess,
+        11 => UtmpKind::Shutdown,
+        _ => UtmpKind::Other,
+    }
+}
+
+/// Derive the [`UtmpKind`] of an older BSD `utmp` entry which has no
+/// `ut_type`. See [`last.c`] of OpenBSD.
+///
+/// [`last.c`]: https://github.com/openbsd/src/blob/master/usr.bin/last/last.c
+fn utmp_bsd_to_utmpkind(line: &str, name: &str) -> UtmpKind {
+    match (line, name) {
+        ("~", "reboot") => UtmpKind::BootTime,
+        ("~", "shutdown") => UtmpKind::Shutdown,
+        ("|", _) => UtmpKind::OldTime,
+        ("{", _) => UtmpKind::NewTime,
+        ("", _) => UtmpKind::Empty,
+        (_, "") => UtmpKind::DeadProcess,
+        _ => UtmpKind::UserProcess,
+    }
+}
+
+/// Create the [`UtmpFields`] of a `utmpx` struct with System V `ut_type`
+/// values.
+macro_rules! utmpfields_sysv {
+    ($utmpx:expr, $user:ident) => ({{
+        let user: String = cstrn_to_string(&$utmpx.$user);
+        let ut_type = $utmpx.ut_type;
+        let mut kind: UtmpKind = ut_type_sysv_to_utmpkind(ut_type as i64);
+        if kind == UtmpKind::RunLevel && user == "shutdown" {
+            kind = UtmpKind::Shutdown;
+        }
+        let ut_pid = $utmpx.ut_pid;
+        UtmpFields {
+            kind,
+            pid: ut_pid as i64,
+            line: cstrn_to_string(&$utmpx.ut_line),
+            id: cstrn_to_string(&$utmpx.ut_id),
+            user,
+            host: cstrn_to_string(&$utmpx.ut_host),
+        }
+    }})
+}
+
+/// Create the [`UtmpFields`] of an older BSD `utmp` struct.
+macro_rules! utmpfields_bsd {
+    ($utmp:expr) => ({{
+        let line: String = cstrn_to_string(&$utmp.ut_line);
+        let user: String = cstrn_to_string(&$utmp.ut_name);
+        UtmpFields {
+            kind: utmp_bsd_to_utmpkind(&line, &user),
+            pid: 0,
+            line,
+            id: String::new(),
+            user,
+            host: cstrn_to_string(&$utmp.ut_host),
+        }
+    }})
+}
+
+/// The process accounting fields of an `acct` entry, normalized across
+/// platforms. Times are in seconds.
+///
+/// Returned by [`FixedStruct::acct_fields`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcctFields {
+    /// `ac_comm`
+    pub comm: String,
+    /// `ac_uid`
+    pub uid: u32,
+    /// `ac_flag`, e.g. [`linux_x86::AXSIG`]
+    pub flag: u8,
+    /// `ac_exitcode`; `None` for structs without a `ac_exitcode`
+    pub exitcode: Option<u32>,
+    /// `ac_utime` plus `ac_stime`
+    pub cpu: f64,
+    /// `ac_etime`
+    pub elapsed: f64,
+    /// `ac_mem`; average memory usage in kilobytes
+    pub mem: u64,
+}
+
+/// Bit of `ac_flag` for a process killed by a signal. The same for all
+/// platforms.
+pub const ACCT_AXSIG: u8 = 0x10;
+/// Bit of `ac_flag` for a process that dumped core. The same for all
+/// platforms.
+pub const ACCT_ACORE: u8 = 0x08;
+
+impl AcctFields {
+    /// Was the process killed by a signal or did it dump core?
+    pub const fn is_abnormal(&self) -> bool {
+        self.flag & (ACCT_AXSIG | ACCT_ACORE) != 0
+    }
+}
+
+/// Decode a `comp_t`, a 3 bit base 8 exponent and 13 bit fraction
+/// "floating point" number.
+pub const fn comp_t_to_u64(comp: u16) -> u64 {
+    ((comp & 0x1FFF) as u64) << (3 * (comp >> 13) as u64)
+}
+
+/// Decode a `comp_t` of `ahz` units per second to seconds.
+fn comp_t_to_secs(comp: u16, ahz: u64) -> f64 {
+    comp_t_to_u64(comp) as f64 / ahz as f64
+}
+
 /// An entry for [pointing to a fixed-size C struct] with additional derived
 /// information.
 ///
@@ -3888,6 +5226,9 @@ pub fn copy_fixedstructptr(entry: &FixedStructDynPtr) -> FixedStructDynPtr {
         FixedStructType::Fs_Linux_Arm64Aarch64_Utmpx => {
             Box::new(*entry.as_linux_arm64aarch64_utmpx())
         }
+        FixedStructType::Fs_Linux_Sqlite_Lastlog2 => {
+            Box::new(*entry.as_linux_sqlite_lastlog2())
+        }
         FixedStructType::Fs_Linux_x86_Acct => {
             Box::new(*entry.as_linux_x86_acct())
         }
@@ -3900,6 +5241,12 @@ pub fn copy_fixedstructptr(entry: &FixedStructDynPtr) -> FixedStructDynPtr {
         FixedStructType::Fs_Linux_x86_Utmpx => {
             Box::new(*entry.as_linux_x86_utmpx())
         }
+        FixedStructType::Fs_Macos_x8664_Lastlogx => {
+            Box::new(*entry.as_macos_x8664_lastlogx())
+        }
+        FixedStructType::Fs_Macos_x8664_Utmpx => {
+            Box::new(*entry.as_macos_x8664_utmpx())
+        }
         FixedStructType::Fs_Netbsd_x8632_Acct => {
             Box::new(*entry.as_netbsd_x8632_acct())
         }
@@ -4218,6 +5565,13 @@ pub fn buffer_to_fixedstructptr(buffer: &[u8], fixedstructtype: FixedStructType)
                 )
             }
         }
+        FixedStructType::Fs_Linux_Sqlite_Lastlog2 => {
+            unsafe {
+                Box::new(
+                    std::ptr::read_unaligned(slice_.as_ptr().cast::<linux_sqlite::lastlog2>())
+                )
+            }
+        }
         FixedStructType::Fs_Linux_x86_Acct => {
             unsafe {
                 Box::new(
@@ -4246,6 +5600,20 @@ pub fn buffer_to_fixedstructptr(buffer: &[u8], fixedstructtype: FixedStructType)
                 )
             }
         }
+        FixedStructType::Fs_Macos_x8664_Lastlogx => {
+            unsafe {
+                Box::new(
+                    std::ptr::read_unaligned(slice_.as_ptr().cast::<macos_x8664::lastlogx>())
+                )
+            }
+        }
+        FixedStructType::Fs_Macos_x8664_Utmpx => {
+            unsafe {
+                Box::new(
+                    std::ptr::read_unaligned(slice_.as_ptr().cast::<macos_x8664::utmpx>())
+                )
+            }
+        }
         FixedStructType::Fs_Netbsd_x8632_Acct => {
             unsafe {
                 Box::new(
@@ -4353,6 +5721,16 @@ pub fn buffer_to_fixedstructptr(buffer: &[u8], fixedstructtype: FixedStructType)
             deo_field_dump!(utmpx, ut_addr_v6, buffer);
             deo_field_dump!(utmpx, __glibc_reserved, buffer);
         }
+        FixedStructType::Fs_Linux_Sqlite_Lastlog2 => {
+            let lastlog2: &linux_sqlite::lastlog2 = entry.as_linux_sqlite_lastlog2();
+
+            deo!("linux_sqlite::lastlog2 offsets and bytes, size {}", lastlog2.size());
+            deo_field_dump_num!(lastlog2, ll_time, buffer, linux_sqlite::ll_time_t);
+            deo_field_dump!(lastlog2, ll_name, buffer);
+            deo_field_dump!(lastlog2, ll_line, buffer);
+            deo_field_dump!(lastlog2, ll_host, buffer);
+            deo_field_dump!(lastlog2, ll_service, buffer);
+        }
         FixedStructType::Fs_Linux_x86_Acct => {
             let acct: &linux_x86::acct = entry.as_linux_x86_acct();
 
@@ -4422,6 +5800,27 @@ pub fn buffer_to_fixedstructptr(buffer: &[u8], fixedstructtype: FixedStructType)
             deo_field_dump!(utmpx, ut_addr_v6, buffer);
             deo_field_dump!(utmpx, __glibc_reserved, buffer);
         }
+        FixedStructType::Fs_Macos_x8664_Lastlogx => {
+            let lastlogx: &macos_x8664::lastlogx = entry.as_macos_x8664_lastlogx();
+
+            deo!("macos_x8664::lastlogx offsets and bytes, size {}", lastlogx.size());
+            deo_field_dump!(lastlogx, ll_tv, buffer);
+            deo_field_dump!(lastlogx, ll_line, buffer);
+            deo_field_dump!(lastlogx, ll_host, buffer);
+        }
+        FixedStructType::Fs_Macos_x8664_Utmpx => {
+            let utmpx: &macos_x8664::utmpx = entry.as_macos_x8664_utmpx();
+
+            deo!("macos_x8664::utmpx offsets and bytes, size {}", utmpx.size());
+            deo_field_dump!(utmpx, ut_user, buffer);
+            deo_field_dump!(utmpx, ut_id, buffer);
+            deo_field_dump!(utmpx, ut_line, buffer);
+            deo_field_dump_num!(utmpx, ut_pid, buffer, macos_x8664::pid_t);
+            deo_field_dump_num!(utmpx, ut_type, buffer, macos_x8664::c_short);
+            deo_field_dump!(utmpx, ut_tv, buffer);
+            deo_field_dump!(utmpx, ut_host, buffer);
+            deo_field_dump!(utmpx, ut_pad, buffer);
+        }
         FixedStructType::Fs_Netbsd_x8632_Acct => {
             let acct: &netbsd_x8632::acct = entry.as_netbsd_x8632_acct();
 
@@ -5009,6 +6408,12 @@ impl FixedStruct
                 tv_sec = tv_or_err_tv_sec!(fixedstructptr, fixedstructptr.ut_tv.tv_sec);
                 tv_usec = tv_or_err_tv_sec!(fixedstructptr, fixedstructptr.ut_tv.tv_usec);
             }
+            FixedStructType::Fs_Linux_Sqlite_Lastlog2 => {
+                filetypefixedstruct = FileTypeFixedStruct::Lastlog2;
+                let fixedstructptr: &linux_sqlite::lastlog2 = fixedstructptr.as_linux_sqlite_lastlog2();
+                tv_sec = tv_or_err_tv_sec!(fixedstructptr, fixedstructptr.ll_time);
+                tv_usec = 0;
+            }
             FixedStructType::Fs_Linux_x86_Acct => {
                 filetypefixedstruct = FileTypeFixedStruct::Acct;
                 let fixedstructptr: &linux_x86::acct = fixedstructptr.as_linux_x86_acct();
@@ -5033,6 +6438,18 @@ impl FixedStruct
                 tv_sec = tv_or_err_tv_sec!(fixedstructptr, fixedstructptr.ut_tv.tv_sec);
                 tv_usec = tv_or_err_tv_sec!(fixedstructptr, fixedstructptr.ut_tv.tv_usec);
             }
+            FixedStructType::Fs_Macos_x8664_Lastlogx => {
+                filetypefixedstruct = FileTypeFixedStruct::Lastlogx;
+                let fixedstructptr: &macos_x8664::lastlogx = fixedstructptr.as_macos_x8664_lastlogx();
+                tv_sec = tv_or_err_tv_sec!(fixedstructptr, fixedstructptr.ll_tv.tv_sec);
+                tv_usec = tv_or_err_tv_sec!(fixedstructptr, fixedstructptr.ll_tv.tv_usec);
+            }
+            FixedStructType::Fs_Macos_x8664_Utmpx => {
+                filetypefixedstruct = FileTypeFixedStruct::Utmpx;
+                let fixedstructptr: &macos_x8664::utmpx = fixedstructptr.as_macos_x8664_utmpx();
+                tv_sec = tv_or_err_tv_sec!(fixedstructptr, fixedstructptr.ut_tv.tv_sec);
+                tv_usec = tv_or_err_tv_sec!(fixedstructptr, fixedstructptr.ut_tv.tv_usec);
+            }
             FixedStructType::Fs_Netbsd_x8632_Acct => {
                 filetypefixedstruct = FileTypeFixedStruct::Acct;
                 let fixedstructptr: &netbsd_x8632::acct = fixedstructptr.as_netbsd_x8632_acct();
@@ -5182,6 +6599,153 @@ impl FixedStruct
         &self.tv_pair
     }
 
+    /// Return the UID of a `lastlog` entry.
+    /// A `lastlog` file is an array of entries indexed by UID so the UID is
+    /// implied by the entry's file offset.
+    /// Return `None` for other entries, including `lastlogx` and `lastlog2`.
+    pub fn lastlog_uid(self: &FixedStruct) -> Option<u64>
+    {
+        match self.fixedstructtype {
+            FixedStructType::Fs_Linux_Arm64Aarch64_Lastlog
+            | FixedStructType::Fs_Linux_x86_Lastlog
+            | FixedStructType::Fs_Netbsd_x8664_Lastlog
+            | FixedStructType::Fs_Openbsd_x86_Lastlog => {
+                Some(self.fileoffset / self.fixedstructtype.size() as FileOffset)
+            }
+            _ => None,
+        }
+    }
+
+    /// Return the [`UtmpFields`] of a `utmp` or `utmpx` entry.
+    /// Return `None` for other entries, e.g. `acct` or `lastlog`.
+    pub fn utmp_fields(self: &FixedStruct) -> Option<UtmpFields>
+    {
+        let entry: &FixedStructDynPtr = &self.fixedstructptr;
+        match entry.fixedstruct_type() {
+            FixedStructType::Fs_Freebsd_x8664_Utmpx => {
+                let utmpx: &freebsd_x8664::utmpx = entry.as_freebsd_x8664_utmpx();
+                Some(UtmpFields {
+                    kind: ut_type_freebsd_to_utmpkind(utmpx.ut_type as i64),
+                    pid: utmpx.ut_pid as i64,
+                    line: cstrn_to_string(&utmpx.ut_line),
+                    id: cstrn_to_string(&utmpx.ut_id),
+                    user: cstrn_to_string(&utmpx.ut_user),
+                    host: cstrn_to_string(&utmpx.ut_host),
+                })
+            }
+            FixedStructType::Fs_Linux_Arm64Aarch64_Utmpx => {
+                Some(utmpfields_sysv!(entry.as_linux_arm64aarch64_utmpx(), ut_user))
+            }
+            FixedStructType::Fs_Linux_x86_Utmpx => {
+                Some(utmpfields_sysv!(entry.as_linux_x86_utmpx(), ut_user))
+            }
+            FixedStructType::Fs_Macos_x8664_Utmpx => {
+                let utmpx: &macos_x8664::utmpx = entry.as_macos_x8664_utmpx();
+                Some(UtmpFields {
+                    kind: ut_type_macos_to_utmpkind(utmpx.ut_type as i64),
+                    pid: utmpx.ut_pid as i64,
+                    line: cstrn_to_string(&utmpx.ut_line),
+                    id: cstrn_to_string(&utmpx.ut_id),
+                    user: cstrn_to_string(&utmpx.ut_user),
+                    host: cstrn_to_string(&utmpx.ut_host),
+                })
+            }
+            FixedStructType::Fs_Netbsd_x8632_Utmpx => {
+                Some(utmpfields_sysv!(entry.as_netbsd_x8632_utmpx(), ut_name))
+            }
+            FixedStructType::Fs_Netbsd_x8664_Utmpx => {
+                Some(utmpfields_sysv!(entry.as_netbsd_x8664_utmpx(), ut_user))
+            }
+            FixedStructType::Fs_Netbsd_x8664_Utmp => {
+                Some(utmpfields_bsd!(entry.as_netbsd_x8664_utmp()))
+            }
+            FixedStructType::Fs_Openbsd_x86_Utmp => {
+                Some(utmpfields_bsd!(entry.as_openbsd_x86_utmp()))
+            }
+            FixedStructType::Fs_Linux_Arm64Aarch64_Lastlog
+            | FixedStructType::Fs_Linux_Sqlite_Lastlog2
+            | FixedStructType::Fs_Linux_x86_Acct
+            | FixedStructType::Fs_Linux_x86_Acct_v3
+            | FixedStructType::Fs_Linux_x86_Lastlog
+            | FixedStructType::Fs_Macos_x8664_Lastlogx
+            | FixedStructType::Fs_Netbsd_x8632_Acct
+            | FixedStructType::Fs_Netbsd_x8632_Lastlogx
+            | FixedStructType::Fs_Netbsd_x8664_Lastlog
+            | FixedStructType::Fs_Netbsd_x8664_Lastlogx
+            | FixedStructType::Fs_Openbsd_x86_Lastlog => None,
+        }
+    }
+
+    /// Return the [`AcctFields`] of an `acct` entry.
+    /// Return `None` for other entries, e.g. `utmpx` or `lastlog`.
+    pub fn acct_fields(self: &FixedStruct) -> Option<AcctFields>
+    {
+        let entry: &FixedStructDynPtr = &self.fixedstructptr;
+        match entry.fixedstruct_type() {
+            FixedStructType::Fs_Linux_x86_Acct => {
+                let acct: &linux_x86::acct = entry.as_linux_x86_acct();
+                Some(AcctFields {
+                    comm: cstrn_to_string(&acct.ac_comm),
+                    uid: acct.ac_uid as u32,
+                    flag: acct.ac_flag as u8,
+                    exitcode: Some(acct.ac_exitcode),
+                    cpu: comp_t_to_secs(acct.ac_utime, linux_x86::AHZ)
+                        + comp_t_to_secs(acct.ac_stime, linux_x86::AHZ),
+                    elapsed: comp_t_to_secs(acct.ac_etime, linux_x86::AHZ),
+                    mem: comp_t_to_u64(acct.ac_mem),
+                })
+            }
+            FixedStructType::Fs_Linux_x86_Acct_v3 => {
+                let acct: &linux_x86::acct_v3 = entry.as_linux_x86_acct_v3();
+                Some(AcctFields {
+                    comm: cstrn_to_string(&acct.ac_comm),
+                    uid: acct.ac_uid,
+                    flag: acct.ac_flag as u8,
+                    exitcode: Some(acct.ac_exitcode),
+                    cpu: comp_t_to_secs(acct.ac_utime, linux_x86::AHZ)
+                        + comp_t_to_secs(acct.ac_stime, linux_x86::AHZ),
+                    // `acct_v3.ac_etime` is a `float`
+                    elapsed: acct.ac_etime.max(0.0) as f64 / linux_x86::AHZ as f64,
+                    mem: comp_t_to_u64(acct.ac_mem),
+                })
+            }
+            FixedStructType::Fs_Netbsd_x8632_Acct => {
+                let acct: &netbsd_x8632::acct = entry.as_netbsd_x8632_acct();
+                let ac_utime = acct.ac_utime;
+                let ac_stime = acct.ac_stime;
+                let ac_etime = acct.ac_etime;
+                let ac_uid = acct.ac_uid;
+                let ac_mem = acct.ac_mem;
+                Some(AcctFields {
+                    comm: cstrn_to_string(&acct.ac_comm),
+                    uid: ac_uid,
+                    flag: acct.ac_flag,
+                    exitcode: None,
+                    cpu: comp_t_to_secs(ac_utime, netbsd_x8632::AHZ)
+                        + comp_t_to_secs(ac_stime, netbsd_x8632::AHZ),
+                    elapsed: comp_t_to_secs(ac_etime, netbsd_x8632::AHZ),
+                    mem: ac_mem as u64,
+                })
+            }
+            FixedStructType::Fs_Freebsd_x8664_Utmpx
+            | FixedStructType::Fs_Linux_Arm64Aarch64_Lastlog
+            | FixedStructType::Fs_Linux_Arm64Aarch64_Utmpx
+            | FixedStructType::Fs_Linux_Sqlite_Lastlog2
+            | FixedStructType::Fs_Linux_x86_Lastlog
+            | FixedStructType::Fs_Linux_x86_Utmpx
+            | FixedStructType::Fs_Macos_x8664_Lastlogx
+            | FixedStructType::Fs_Macos_x8664_Utmpx
+            | FixedStructType::Fs_Netbsd_x8632_Lastlogx
+            | FixedStructType::Fs_Netbsd_x8632_Utmpx
+            | FixedStructType::Fs_Netbsd_x8664_Lastlog
+            | FixedStructType::Fs_Netbsd_x8664_Lastlogx
+            | FixedStructType::Fs_Netbsd_x8664_Utmp
+            | FixedStructType::Fs_Netbsd_x8664_Utmpx
+            | FixedStructType::Fs_Openbsd_x86_Lastlog
+            | FixedStructType::Fs_Openbsd_x86_Utmp => None,
+        }
+    }
+
     /// Create a score for this FixedStruct entry
     ///
     /// The scoring system is a simple heuristic to determine the likelihood that
@@ -5260,6 +6824,24 @@ impl FixedStruct
 
                 score_fixedstruct_ut_type!(score, utmpx.ut_type, linux_arm64aarch64::UT_TYPES);
             }
+            FixedStructType::Fs_Linux_Sqlite_Lastlog2 => {
+                let lastlog2: &linux_sqlite::lastlog2 = fixedstructptr.as_linux_sqlite_lastlog2();
+
+                score_fixedstruct_cstr!(score, lastlog2.ll_name());
+                score_fixedstruct_cstr_no_data_after_null!(score, lastlog2.ll_name);
+                score_fixedstruct_cstr_null_terminator!(score, lastlog2.ll_name);
+
+                score_fixedstruct_cstr!(score, lastlog2.ll_line());
+                score_fixedstruct_cstr_no_data_after_null!(score, lastlog2.ll_line);
+
+                score_fixedstruct_cstr!(score, lastlog2.ll_host());
+                score_fixedstruct_cstr_no_data_after_null!(score, lastlog2.ll_host);
+
+                score_fixedstruct_cstr!(score, lastlog2.ll_service());
+                score_fixedstruct_cstr_no_data_after_null!(score, lastlog2.ll_service);
+
+                score_fixedstruct_time_range!(score, lastlog2.ll_time);
+            }
             FixedStructType::Fs_Linux_x86_Acct => {
                 let acct: &linux_x86::acct = fixedstructptr.as_linux_x86_acct();
 
@@ -5319,6 +6901,41 @@ impl FixedStruct
 
                 score_fixedstruct_ut_type!(score, utmpx.ut_type, linux_x86::UT_TYPES);
             }
+            FixedStructType::Fs_Macos_x8664_Lastlogx => {
+                let lastlogx: &macos_x8664::lastlogx = fixedstructptr.as_macos_x8664_lastlogx();
+
+                score_fixedstruct_cstr!(score, lastlogx.ll_line());
+                score_fixedstruct_cstr_no_data_after_null!(score, lastlogx.ll_line);
+
+                score_fixedstruct_cstr!(score, lastlogx.ll_host());
+                score_fixedstruct_cstr_no_data_after_null!(score, lastlogx.ll_host);
+                score_fixedstruct_cstr_null_terminator!(score, lastlogx.ll_host);
+
+                score_fixedstruct_time_range!(score, lastlogx.ll_tv.tv_sec);
+            }
+            FixedStructType::Fs_Macos_x8664_Utmpx => {
+                let utmpx: &macos_x8664::utmpx = fixedstructptr.as_macos_x8664_utmpx();
+
+                score_fixedstruct_cstr!(score, utmpx.ut_user());
+                score_fixedstruct_cstr_no_data_after_null!(score, utmpx.ut_user);
+                score_fixedstruct_cstr_null_terminator!(score, utmpx.ut_user);
+
+                score_fixedstruct_cstr!(score, utmpx.ut_id());
+
+                score_fixedstruct_cstr!(score, utmpx.ut_line());
+                score_fixedstruct_cstr_no_data_after_null!(score, utmpx.ut_line);
+
+                score_fixedstruct_cstr!(score, utmpx.ut_host());
+                score_fixedstruct_cstr_no_data_after_null!(score, utmpx.ut_host);
+                score_fixedstruct_cstr_null_terminator!(score, utmpx.ut_host);
+
+                score_fixedstruct_time_range!(score, utmpx.ut_tv.tv_sec);
+
+                score_fixedstruct_buffer_all_null!(score, utmpx.__gap1);
+                score_fixedstruct_buffer_all_null!(score, utmpx.ut_pad);
+
+                score_fixedstruct_ut_type!(score, utmpx.ut_type, macos_x8664::UT_TYPES);
+            }
             FixedStructType::Fs_Netbsd_x8632_Acct => {
                 let acct: &netbsd_x8632::acct = fixedstructptr.as_netbsd_x8632_acct();
 
@@ -5503,7 +7120,7 @@ impl FixedStruct
                 set_buffer_at_or_err_str!(buffer, at, " ut_user '");
                 set_buffer_at_or_err_cstrn!(buffer, at, utmpx.ut_user);
                 // ut_line
-                set_buffer_at_or_err_str!(buffer, at, "' ut_line ");
+                set_buffer_at_or_err_str!(buffer, at, "' ut_line '");
                 set_buffer_at_or_err_cstrn!(buffer, at, utmpx.ut_line);
                 // ut_host
                 set_buffer_at_or_err_str!(buffer, at, "' ut_host '");
@@ -5513,8 +7130,11 @@ impl FixedStruct
             FixedStructType::Fs_Linux_Arm64Aarch64_Lastlog => {
                 let lastlog: &linux_arm64aarch64::lastlog = entry.as_linux_arm64aarch64_lastlog();
 
+                // uid
+                set_buffer_at_or_err_str!(buffer, at, "uid ");
+                set_buffer_at_or_err_number!(buffer, at, self.lastlog_uid().unwrap_or_default(), u64);
                 // ll_time
-                set_buffer_at_or_err_str!(buffer, at, "ll_time ");
+                set_buffer_at_or_err_str!(buffer, at, " ll_time ");
                 dt_beg = at;
                 set_buffer_at_or_err_number!(buffer, at, lastlog.ll_time, linux_arm64aarch64::ll_time_t);
                 dt_end = at;
@@ -5572,6 +7192,28 @@ impl FixedStruct
                     set_buffer_at_or_err_ipv6!(buffer, at, utmpx.ut_addr_v6);
                 }
             }
+            FixedStructType::Fs_Linux_Sqlite_Lastlog2 => {
+                let lastlog2: &linux_sqlite::lastlog2 = entry.as_linux_sqlite_lastlog2();
+
+                // ll_time
+                set_buffer_at_or_err_str!(buffer, at, "ll_time ");
+                dt_beg = at;
+                set_buffer_at_or_err_number!(buffer, at, lastlog2.ll_time, linux_sqlite::ll_time_t);
+                dt_end = at;
+                // ll_name
+                set_buffer_at_or_err_str!(buffer, at, " ll_name '");
+                set_buffer_at_or_err_cstrn!(buffer, at, lastlog2.ll_name);
+                // ll_line
+                set_buffer_at_or_err_str!(buffer, at, "' ll_line '");
+                set_buffer_at_or_err_cstrn!(buffer, at, lastlog2.ll_line);
+                // ll_host
+                set_buffer_at_or_err_str!(buffer, at, "' ll_host '");
+                set_buffer_at_or_err_cstrn!(buffer, at, lastlog2.ll_host);
+                // ll_service
+                set_buffer_at_or_err_str!(buffer, at, "' ll_service '");
+                set_buffer_at_or_err_cstrn!(buffer, at, lastlog2.ll_service);
+                set_buffer_at_or_err_u8!(buffer, at, b'\'');
+            }
             FixedStructType::Fs_Linux_x86_Acct => {
                 let acct: &linux_x86::acct = entry.as_linux_x86_acct();
 
@@ -5740,8 +7382,11 @@ impl FixedStruct
             FixedStructType::Fs_Linux_x86_Lastlog => {
                 let lastlog: &linux_x86::lastlog = entry.as_linux_x86_lastlog();
 
+                // uid
+                set_buffer_at_or_err_str!(buffer, at, "uid ");
+                set_buffer_at_or_err_number!(buffer, at, self.lastlog_uid().unwrap_or_default(), u64);
                 // ll_time
-                set_buffer_at_or_err_str!(buffer, at, "ll_time ");
+                set_buffer_at_or_err_str!(buffer, at, " ll_time ");
                 dt_beg = at;
                 set_buffer_at_or_err_number!(buffer, at, lastlog.ll_time, linux_x86::ll_time_t);
                 dt_end = at;
@@ -5802,6 +7447,55 @@ impl FixedStruct
                     set_buffer_at_or_err_ipv6!(buffer, at, utmpx.ut_addr_v6);
                 }
             }
+            FixedStructType::Fs_Macos_x8664_Lastlogx => {
+                let lastlogx: &macos_x8664::lastlogx = entry.as_macos_x8664_lastlogx();
+
+                // ll_tv.tv_sec
+                set_buffer_at_or_err_str!(buffer, at, "ll_tv ");
+                dt_beg = at;
+                set_buffer_at_or_err_number!(buffer, at, lastlogx.ll_tv.tv_sec, macos_x8664::time_t);
+                set_buffer_at_or_err_u8!(buffer, at, b'.');
+                // ll_tv.tv_usec
+                set_buffer_at_or_err_number!(buffer, at, lastlogx.ll_tv.tv_usec, macos_x8664::suseconds_t);
+                dt_end = at;
+                // ll_line
+                set_buffer_at_or_err_str!(buffer, at, " ll_line '");
+                set_buffer_at_or_err_cstrn!(buffer, at, lastlogx.ll_line);
+                // ll_host
+                set_buffer_at_or_err_str!(buffer, at, "' ll_host '");
+                set_buffer_at_or_err_cstrn!(buffer, at, lastlogx.ll_host);
+                set_buffer_at_or_err_u8!(buffer, at, b'\'');
+            }
+            FixedStructType::Fs_Macos_x8664_Utmpx => {
+                let utmpx: &macos_x8664::utmpx = entry.as_macos_x8664_utmpx();
+
+                // ut_type
+                set_buffer_at_or_err_str!(buffer, at, "ut_type ");
+                set_buffer_at_or_err_ut_type_i16!(buffer, at, utmpx.ut_type);
+                // ut_tv
+                set_buffer_at_or_err_str!(buffer, at, " ut_tv ");
+                dt_beg = at;
+                set_buffer_at_or_err_number!(buffer, at, utmpx.ut_tv.tv_sec, macos_x8664::time_t);
+                set_buffer_at_or_err_u8!(buffer, at, b'.');
+                set_buffer_at_or_err_number!(buffer, at, utmpx.ut_tv.tv_usec, macos_x8664::suseconds_t);
+                dt_end = at;
+                // ut_id
+                set_buffer_at_or_err_str!(buffer, at, " ut_id '");
+                set_buffer_at_or_err_cstrn!(buffer, at, utmpx.ut_id);
+                // ut_pid
+                set_buffer_at_or_err_str!(buffer, at, "' ut_pid ");
+                set_buffer_at_or_err_number!(buffer, at, utmpx.ut_pid, macos_x8664::pid_t);
+                // ut_user
+                set_buffer_at_or_err_str!(buffer, at, " ut_user '");
+                set_buffer_at_or_err_cstrn!(buffer, at, utmpx.ut_user);
+                // ut_line
+                set_buffer_at_or_err_str!(buffer, at, "' ut_line '");
+                set_buffer_at_or_err_cstrn!(buffer, at, utmpx.ut_line);
+                // ut_host
+                set_buffer_at_or_err_str!(buffer, at, "' ut_host '");
+                set_buffer_at_or_err_cstrn!(buffer, at, utmpx.ut_host);
+                set_buffer_at_or_err_u8!(buffer, at, b'\'');
+            }
             FixedStructType::Fs_Netbsd_x8632_Acct => {
                 let acct: &netbsd_x8632::acct = entry.as_netbsd_x8632_acct();
 
@@ -5937,8 +7631,11 @@ impl FixedStruct
             FixedStructType::Fs_Netbsd_x8664_Lastlog => {
                 let lastlog: &netbsd_x8664::lastlog = entry.as_netbsd_x8664_lastlog();
 
+                // uid
+                set_buffer_at_or_err_str!(buffer, at, "uid ");
+                set_buffer_at_or_err_number!(buffer, at, self.lastlog_uid().unwrap_or_default(), u64);
                 // ll_time
-                set_buffer_at_or_err_str!(buffer, at, "ll_time ");
+                set_buffer_at_or_err_str!(buffer, at, " ll_time ");
                 dt_beg = at;
                 set_buffer_at_or_err_number!(buffer, at, lastlog.ll_time, netbsd_x8664::time_t);
                 dt_end = at;
@@ -6030,8 +7727,11 @@ impl FixedStruct
             FixedStructType::Fs_Openbsd_x86_Lastlog => {
                 let lastlog: &openbsd_x86::lastlog = entry.as_openbsd_x86_lastlog();
 
+                // uid
+                set_buffer_at_or_err_str!(buffer, at, "uid ");
+                set_buffer_at_or_err_number!(buffer, at, self.lastlog_uid().unwrap_or_default(), u64);
                 // ll_time
-                set_buffer_at_or_err_str!(buffer, at, "ll_time ");
+                set_buffer_at_or_err_str!(buffer, at, " ll_time ");
                 dt_beg = at;
                 set_buffer_at_or_err_number!(buffer, at, lastlog.ll_time, openbsd_x86::time_t);
                 dt_end = at;
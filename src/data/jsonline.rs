@@ -0,0 +1,547 @@
+// src/data/jsonline.rs
+
+//! Implement [`JsonLineTime`] to find the datetime of a line of a
+//! [JSON lines] file by parsing the line as a JSON object and selecting the
+//! datetime field by [JSON pointer].
+//! Implement [`JsonLineFilter`] to filter JSON lines by field value, and
+//! [`JsonLine`] to render a JSON line as `key=value` pairs.
+//!
+//! A line of a JSON lines file looks like
+//!
+//! ```text
+//! {"@timestamp":"2024-01-02T03:04:05.678Z","level":"info","msg":"hello world","http":{"status":200}}
+//! ```
+//!
+//! The datetime field value may be a datetime string, e.g.
+//! `"2024-01-02T03:04:05.678Z"`, or a Unix epoch number or numeric string in
+//! seconds, milliseconds, microseconds, or nanoseconds, e.g. `1704164645.678`
+//! or `1704164645678`.
+//!
+//! [JSON lines]: https://jsonlines.org/
+//! [JSON pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+
+use std::fmt;
+
+use ::chrono::{
+    DateTime,
+    FixedOffset,
+    TimeZone,
+};
+use ::serde_json::{
+    Map,
+    Value,
+};
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::data::datetime::{
+    datetime_parse_from_str,
+    DateTimeL,
+    DateTimePattern_str,
+};
+use crate::data::sysline::SyslineP;
+
+/// The default JSON pointers of the datetime field, tried in order.
+pub const JSONLINE_TIME_POINTERS_DEFAULT: [&str; 4] = [
+    "/@timestamp",
+    "/time",
+    "/ts",
+    "/timestamp",
+];
+
+/// Patterns of a datetime string value with a timezone, tried after
+/// RFC 3339.
+const JSONLINE_PATTERNS_TZ: [&DateTimePattern_str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f%:z",
+    "%Y-%m-%d %H:%M:%S%.f%:z",
+    "%Y-%m-%dT%H:%M:%S%.f%z",
+    "%Y-%m-%d %H:%M:%S%.f %z",
+];
+
+/// Patterns of a datetime string value without a timezone.
+/// A pattern ending with `Z` is UTC.
+const JSONLINE_PATTERNS: [&DateTimePattern_str; 5] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y/%m/%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M:%S%.fZ",
+    "%Y-%m-%d %H:%M:%S%.fZ",
+];
+
+/// How lines of text files are parsed as JSON lines.
+///
+/// Set by the `--json-lines` command-line option.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ::clap::ValueEnum)]
+pub enum JsonLinesMode {
+    /// If the first lines of the file are mostly JSON lines then a line that
+    /// is a JSON object with a datetime field is a JSON line, other lines are
+    /// searched for a datetime as usual.
+    /// Otherwise lines are not parsed as JSON.
+    #[default]
+    Auto,
+    /// Only a line that is a JSON object with a datetime field begins a
+    /// log message.
+    Always,
+    /// Lines are not parsed as JSON.
+    Never,
+}
+
+impl fmt::Display for JsonLinesMode {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            JsonLinesMode::Auto => write!(f, "auto"),
+            JsonLinesMode::Always => write!(f, "always"),
+            JsonLinesMode::Never => write!(f, "never"),
+        }
+    }
+}
+
+/// The format of a printed JSON line.
+///
+/// Set by the `--json-output` command-line option.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ::clap::ValueEnum)]
+pub enum JsonLineOutput {
+    /// The line as it is.
+    #[default]
+    Json,
+    /// The fields as `key=value` pairs, nested keys joined with `.`.
+    ///
+    /// For example,
+    ///
+    /// ```text
+    /// @timestamp=2024-01-02T03:04:05.678Z level=info msg="hello world" http.status=200
+    /// ```
+    KeyValue,
+}
+
+impl fmt::Display for JsonLineOutput {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            JsonLineOutput::Json => write!(f, "json"),
+            JsonLineOutput::KeyValue => write!(f, "key-value"),
+        }
+    }
+}
+
+/// A JSON pointer of `key`; a `key` beginning with `/` is already a JSON
+/// pointer, otherwise `key` is the name of a top-level field, e.g.
+/// `@timestamp` is pointer `/@timestamp`.
+pub fn jsonline_pointer(key: &str) -> String {
+    if key.starts_with('/') {
+        return String::from(key);
+    }
+
+    format!("/{}", key.replace('~', "~0").replace('/', "~1"))
+}
+
+/// Parse the first line of `text` as a JSON object.
+pub fn jsonline_parse(text: &str) -> Option<Map<String, Value>> {
+    let line: &str = text.lines().next()?.trim();
+    if !line.starts_with('{') {
+        return None;
+    }
+    match ::serde_json::from_str::<Value>(line) {
+        Ok(Value::Object(object)) => Some(object),
+        _ => None,
+    }
+}
+
+/// The datetime of a Unix epoch `value` in seconds, milliseconds,
+/// microseconds, or nanoseconds, distinguished by magnitude.
+pub fn jsonline_epoch_to_datetime(
+    value: f64,
+    tz_offset: &FixedOffset,
+) -> Option<DateTimeL> {
+    if !value.is_finite() {
+        return None;
+    }
+    let scale: f64 = match value.abs() {
+        v if v < 1e11 => 1e9,
+        v if v < 1e14 => 1e6,
+        v if v < 1e17 => 1e3,
+        _ => 1.0,
+    };
+    let nanos: f64 = (value * scale).round();
+    if nanos.abs() >= i64::MAX as f64 {
+        return None;
+    }
+
+    Some(tz_offset.timestamp_nanos(nanos as i64))
+}
+
+/// The datetime of an epoch integer `value`, like
+/// [`jsonline_epoch_to_datetime`] without the loss of precision of `f64`.
+fn jsonline_epoch_i64_to_datetime(
+    value: i64,
+    tz_offset: &FixedOffset,
+) -> Option<DateTimeL> {
+    let scale: i64 = match value.unsigned_abs() {
+        v if v < 100_000_000_000 => 1_000_000_000,
+        v if v < 100_000_000_000_000 => 1_000_000,
+        v if v < 100_000_000_000_000_000 => 1_000,
+        _ => 1,
+    };
+
+    Some(tz_offset.timestamp_nanos(value.checked_mul(scale)?))
+}
+
+/// The datetime of a JSON datetime field `value`; a datetime string or a
+/// Unix epoch number or numeric string.
+/// A datetime without a timezone is at `tz_offset`.
+pub fn jsonline_value_to_datetime(
+    value: &Value,
+    tz_offset: &FixedOffset,
+) -> Option<DateTimeL> {
+    match value {
+        Value::Number(number) => match number.as_i64() {
+            Some(number) => jsonline_epoch_i64_to_datetime(number, tz_offset),
+            None => jsonline_epoch_to_datetime(number.as_f64()?, tz_offset),
+        },
+        Value::String(s) => {
+            let s: &str = s.trim();
+            if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+                return match s.parse::<i64>() {
+                    Ok(number) => jsonline_epoch_i64_to_datetime(number, tz_offset),
+                    Err(_) => jsonline_epoch_to_datetime(s.parse::<f64>().ok()?, tz_offset),
+                };
+            }
+            if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+                return Some(dt);
+            }
+            for pattern in JSONLINE_PATTERNS_TZ.iter() {
+                if let Some(dt) = datetime_parse_from_str(s, pattern, true, tz_offset) {
+                    return Some(dt);
+                }
+            }
+            for pattern in JSONLINE_PATTERNS.iter() {
+                if let Some(dt) = datetime_parse_from_str(s, pattern, false, tz_offset) {
+                    if pattern.ends_with('Z') {
+                        return Some(dt.naive_local().and_utc().with_timezone(tz_offset));
+                    }
+                    return Some(dt);
+                }
+            }
+
+            None
+        }
+        _ => None,
+    }
+}
+
+/// The byte offsets of the value of the last key of JSON `pointer` within
+/// `line`, excluding the quotes of a string value.
+/// A best effort; the key is found by text search.
+pub fn jsonline_value_span(
+    line: &str,
+    pointer: &str,
+) -> Option<(usize, usize)> {
+    let key: String = pointer
+        .rsplit('/')
+        .next()?
+        .replace("~1", "/")
+        .replace("~0", "~");
+    let key_json: String = ::serde_json::to_string(&key).ok()?;
+    let bytes: &[u8] = line.as_bytes();
+    let skip_blanks = |mut at: usize| -> usize {
+        while at < bytes.len() && bytes[at].is_ascii_whitespace() {
+            at += 1;
+        }
+        at
+    };
+    let mut from: usize = 0;
+    while let Some(found) = line[from..].find(key_json.as_str()) {
+        let at: usize = skip_blanks(from + found + key_json.len());
+        from += found + key_json.len();
+        if at >= bytes.len() || bytes[at] != b':' {
+            continue;
+        }
+        let beg: usize = skip_blanks(at + 1);
+        if beg >= bytes.len() {
+            return None;
+        }
+        if bytes[beg] == b'"' {
+            let mut end: usize = beg + 1;
+            while end < bytes.len() && bytes[end] != b'"' {
+                end += if bytes[end] == b'\\' { 2 } else { 1 };
+            }
+            return Some((beg + 1, end.min(bytes.len())));
+        }
+        let mut end: usize = beg;
+        while end < bytes.len() && matches!(bytes[end], b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E') {
+            end += 1;
+        }
+        return Some((beg, end));
+    }
+
+    None
+}
+
+/// Finds the datetime of a JSON line by the datetime field.
+///
+/// Set by the `--json-lines` and `--json-time` command-line options.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JsonLineTime {
+    /// JSON pointers of the datetime field, tried in order
+    pub pointers: Vec<String>,
+    /// Only a JSON line begins a log message, see [`JsonLinesMode::Always`]
+    pub always: bool,
+}
+
+impl Default for JsonLineTime {
+    fn default() -> JsonLineTime {
+        JsonLineTime {
+            pointers: JSONLINE_TIME_POINTERS_DEFAULT
+                .iter()
+                .map(|pointer| String::from(*pointer))
+                .collect(),
+            always: false,
+        }
+    }
+}
+
+impl JsonLineTime {
+    /// Create a new `JsonLineTime` for `mode` from a comma-separated list of
+    /// JSON pointers or top-level keys of the datetime field, e.g.
+    /// `"/@timestamp,time"`. Without `pointers` the
+    /// [`JSONLINE_TIME_POINTERS_DEFAULT`] are used.
+    ///
+    /// Return `None` for [`JsonLinesMode::Never`].
+    pub fn new(
+        mode: JsonLinesMode,
+        pointers: Option<&str>,
+    ) -> Option<JsonLineTime> {
+        let mut jsonline_time: JsonLineTime = match mode {
+            JsonLinesMode::Never => return None,
+            JsonLinesMode::Auto => JsonLineTime::default(),
+            JsonLinesMode::Always => JsonLineTime {
+                always: true,
+                ..JsonLineTime::default()
+            },
+        };
+        let pointers: Vec<String> = pointers
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(jsonline_pointer)
+            .collect();
+        if !pointers.is_empty() {
+            jsonline_time.pointers = pointers;
+        }
+
+        Some(jsonline_time)
+    }
+
+    /// Find the datetime of `line` by the first datetime field found.
+    /// Return the byte offsets of the datetime field value within `line`
+    /// and the datetime.
+    pub fn find_datetime(
+        &self,
+        line: &str,
+        tz_offset: &FixedOffset,
+    ) -> Option<(usize, usize, DateTimeL)> {
+        defn!("({:?})", line);
+        let object: Value = Value::Object(jsonline_parse(line)?);
+        for pointer in self.pointers.iter() {
+            let value: &Value = match object.pointer(pointer) {
+                Some(value) => value,
+                None => continue,
+            };
+            let dt: DateTimeL = match jsonline_value_to_datetime(value, tz_offset) {
+                Some(dt) => dt,
+                None => continue,
+            };
+            // the line may be indented
+            let indent: usize = line.len() - line.trim_start().len();
+            let (beg, end) = jsonline_value_span(line.trim_start(), pointer)
+                .map(|(beg, end)| (indent + beg, indent + end))
+                .unwrap_or((0, 0));
+            defx!("return ({}, {}, {:?}) for {:?}", beg, end, dt, pointer);
+            return Some((beg, end, dt));
+        }
+        defx!("return None");
+
+        None
+    }
+}
+
+/// The text of JSON `value` to compare to a filter value or to render;
+/// a string is not quoted.
+fn jsonline_value_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Filter JSON lines by field value.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct JsonLineFilter {
+    /// a line passes if the field at each JSON pointer has the value
+    pub fields: Vec<(String, String)>,
+}
+
+impl JsonLineFilter {
+    /// Create a new `JsonLineFilter` from `KEY=VALUE` pairs, where `KEY` is a
+    /// JSON pointer or a top-level key, e.g. `"level=error"` or
+    /// `"/http/status=500"`.
+    pub fn new(fields: &[String]) -> std::result::Result<JsonLineFilter, String> {
+        let mut fields_: Vec<(String, String)> = Vec::with_capacity(fields.len());
+        for field in fields.iter() {
+            match field.split_once('=') {
+                Some((key, value)) if !key.trim().is_empty() => {
+                    fields_.push((jsonline_pointer(key.trim()), String::from(value)));
+                }
+                _ => return Err(format!("Invalid JSON field filter {:?}; expected KEY=VALUE", field)),
+            }
+        }
+
+        Ok(JsonLineFilter { fields: fields_ })
+    }
+
+    /// Does this filter pass all lines?
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Does JSON `object` pass this filter? A line that is not a JSON
+    /// object, `None`, only passes an empty filter.
+    pub fn matches(
+        &self,
+        object: Option<&Map<String, Value>>,
+    ) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        let object: &Map<String, Value> = match object {
+            Some(object) => object,
+            None => return false,
+        };
+        self.fields.iter().all(|(pointer, value)| {
+            // `Map` has no `pointer` so look up the top-level key first
+            let mut tokens = pointer
+                .split('/')
+                .skip(1)
+                .map(|token| token.replace("~1", "/").replace("~0", "~"));
+            let first: String = match tokens.next() {
+                Some(first) => first,
+                None => return false,
+            };
+            let mut found: Option<&Value> = object.get(&first);
+            for token in tokens {
+                found = match found {
+                    Some(Value::Object(object_)) => object_.get(&token),
+                    Some(Value::Array(array)) => token
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|index| array.get(index)),
+                    _ => None,
+                };
+            }
+            found.is_some_and(|found| jsonline_value_text(found) == *value)
+        })
+    }
+}
+
+/// Append the `key=value` pairs of `value` to `out`, nested object keys
+/// joined with `.`.
+fn jsonline_keyvalue_append(
+    out: &mut String,
+    prefix: &str,
+    value: &Value,
+) {
+    if let Value::Object(object) = value {
+        for (key, value_) in object.iter() {
+            let key_: String = match prefix.is_empty() {
+                true => key.clone(),
+                false => format!("{}.{}", prefix, key),
+            };
+            jsonline_keyvalue_append(out, &key_, value_);
+        }
+        return;
+    }
+    let text: String = jsonline_value_text(value);
+    let quote: bool = matches!(value, Value::String(_))
+        && (text.is_empty()
+            || text
+                .chars()
+                .any(|c| c.is_whitespace() || c.is_control() || c == '=' || c == '"'));
+    if !out.is_empty() {
+        out.push(' ');
+    }
+    out.push_str(prefix);
+    out.push('=');
+    match quote {
+        true => out.push_str(&Value::String(text).to_string()),
+        false => out.push_str(&text),
+    }
+}
+
+/// Render JSON `object` as `key=value` pairs, see
+/// [`JsonLineOutput::KeyValue`].
+pub fn jsonline_keyvalue(object: &Map<String, Value>) -> String {
+    let mut out: String = String::new();
+    for (key, value) in object.iter() {
+        jsonline_keyvalue_append(&mut out, key, value);
+    }
+
+    out
+}
+
+/// A JSON line `Sysline` to filter and print.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonLine {
+    pub dt: DateTimeL,
+    /// the JSON object of the first line, if it is one
+    pub object: Option<Map<String, Value>>,
+    /// the lines without the ending newline
+    pub lines: Vec<String>,
+}
+
+impl JsonLine {
+    /// Create a `JsonLine` from the `text` of a `Sysline` at datetime `dt`.
+    pub fn new(
+        dt: DateTimeL,
+        text: &str,
+    ) -> JsonLine {
+        let lines: Vec<String> = text
+            .lines()
+            .map(|line| String::from(line.trim_end_matches('\r')))
+            .collect();
+
+        JsonLine {
+            dt,
+            object: jsonline_parse(text),
+            lines,
+        }
+    }
+
+    /// Create a `JsonLine` from a `Sysline`.
+    pub fn from_syslinep(syslinep: &SyslineP) -> JsonLine {
+        let text: String = String::from_utf8_lossy(&syslinep.to_bytes()).into_owned();
+
+        JsonLine::new(*syslinep.dt(), &text)
+    }
+
+    pub const fn dt(&self) -> &DateTimeL {
+        &self.dt
+    }
+
+    /// Replace the first line, a JSON object, with the `key=value` pairs.
+    pub fn to_keyvalue(&mut self) {
+        if let (Some(object), Some(first)) = (self.object.as_ref(), self.lines.first_mut()) {
+            *first = jsonline_keyvalue(object);
+        }
+    }
+}
@@ -0,0 +1,736 @@
+// src/data/keyvalue.rs
+
+//! Implement [`KeyValueTime`] to find the datetime of a key-value log line,
+//! i.e. [logfmt], [ArcSight CEF], or [IBM LEEF], by the value of a named
+//! datetime key.
+//! Implement [`KeyValueFilter`] to filter key-value log lines by key value.
+//!
+//! A logfmt line looks like
+//!
+//! ```text
+//! time=2024-01-02T03:04:05.678Z level=info msg="hello world" status=200
+//! ```
+//!
+//! A CEF line, here with a syslog header, looks like
+//!
+//! ```text
+//! Jan 02 03:04:05 host CEF:0|Vendor|Product|1.0|100|Login failed|5|rt=1704164645678 src=10.0.0.1 msg=bad password
+//! ```
+//!
+//! A LEEF line, the attributes delimited by tab or a declared delimiter,
+//! looks like
+//!
+//! ```text
+//! LEEF:2.0|Vendor|Product|1.0|100|^|devTime=Jan 02 2024 03:04:05.678 UTC^src=10.0.0.1
+//! ```
+//!
+//! The datetime value may be a datetime string, e.g.
+//! `2024-01-02T03:04:05.678Z` or `Jan 02 2024 03:04:05.678 UTC`, or a Unix
+//! epoch number in seconds, milliseconds, microseconds, or nanoseconds, e.g.
+//! `1704164645678`. A LEEF `devTimeFormat` attribute, a Java date format, is
+//! used for the `devTime` value.
+//!
+//! [logfmt]: https://brandur.org/logfmt
+//! [ArcSight CEF]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.4/pdfdoc/cef-implementation-standard/cef-implementation-standard.pdf
+//! [IBM LEEF]: https://www.ibm.com/docs/en/dsm?topic=leef-overview
+
+use std::fmt;
+
+use ::chrono::FixedOffset;
+use ::serde_json::Value;
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::data::datetime::{
+    datetime_parse_from_str,
+    DateTimeL,
+    DateTimePattern_str,
+    MAP_TZZ_TO_TZz,
+};
+use crate::data::jsonline::jsonline_value_to_datetime;
+
+/// The default keys of the datetime value, tried in order.
+pub const KEYVALUE_TIME_KEYS_DEFAULT: [&str; 5] = [
+    "time",
+    "ts",
+    "timestamp",
+    "rt",
+    "devTime",
+];
+
+/// Patterns of a CEF or LEEF datetime value with a timezone offset, tried
+/// after the patterns of [`jsonline_value_to_datetime`].
+const KEYVALUE_PATTERNS_TZ: [&DateTimePattern_str; 2] = [
+    "%b %d %Y %H:%M:%S%.f %:z",
+    "%b %d %Y %H:%M:%S%.f %z",
+];
+
+/// Patterns of a CEF or LEEF datetime value without a timezone.
+const KEYVALUE_PATTERNS: [&DateTimePattern_str; 2] = [
+    "%b %d %Y %H:%M:%S%.f",
+    "%d/%b/%Y:%H:%M:%S%.f",
+];
+
+/// The key of a LEEF attribute declaring the Java date format of `devTime`.
+const LEEF_DEVTIMEFORMAT: &str = "devTimeFormat";
+
+/// The keys of the CEF header fields, in order.
+pub const CEF_HEADER_KEYS: [&str; 7] = [
+    "cefVersion",
+    "deviceVendor",
+    "deviceProduct",
+    "deviceVersion",
+    "deviceEventClassId",
+    "name",
+    "severity",
+];
+
+/// The keys of the LEEF header fields, in order.
+pub const LEEF_HEADER_KEYS: [&str; 5] = [
+    "leefVersion",
+    "vendor",
+    "product",
+    "version",
+    "eventId",
+];
+
+/// How lines of text files are parsed as key-value lines.
+///
+/// Set by the `--kv-lines` command-line option.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ::clap::ValueEnum)]
+pub enum KeyValueMode {
+    /// If the first lines of the file are mostly key-value lines then a
+    /// logfmt, CEF, or LEEF line with a datetime key is a key-value line,
+    /// other lines are searched for a datetime as usual.
+    /// Otherwise lines are not parsed as key-value lines.
+    #[default]
+    Auto,
+    /// Only a logfmt, CEF, or LEEF line with a datetime key begins a log
+    /// message.
+    Always,
+    /// Lines are not parsed as key-value lines.
+    Never,
+}
+
+impl fmt::Display for KeyValueMode {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            KeyValueMode::Auto => write!(f, "auto"),
+            KeyValueMode::Always => write!(f, "always"),
+            KeyValueMode::Never => write!(f, "never"),
+        }
+    }
+}
+
+/// The format of a key-value line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyValueFormat {
+    /// `key=value key="quoted value"`
+    Logfmt,
+    /// ArcSight Common Event Format `CEF:0|…|key=value key=value`
+    Cef,
+    /// IBM Log Event Extended Format `LEEF:1.0|…|key=value<TAB>key=value`
+    Leef,
+}
+
+impl fmt::Display for KeyValueFormat {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            KeyValueFormat::Logfmt => write!(f, "logfmt"),
+            KeyValueFormat::Cef => write!(f, "CEF"),
+            KeyValueFormat::Leef => write!(f, "LEEF"),
+        }
+    }
+}
+
+/// One key and value of a key-value line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyValue {
+    pub key: String,
+    /// the value, unquoted and unescaped
+    pub value: String,
+    /// byte offset of the value within the line, excluding quotes
+    pub beg: usize,
+    /// byte offset one past the value within the line, excluding quotes
+    pub end: usize,
+}
+
+/// The keys and values of a key-value line, in order.
+/// The header fields of a CEF or LEEF line are keys [`CEF_HEADER_KEYS`] and
+/// [`LEEF_HEADER_KEYS`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyValues {
+    pub format: KeyValueFormat,
+    pub pairs: Vec<KeyValue>,
+}
+
+impl KeyValues {
+    /// The first `KeyValue` of `key`.
+    pub fn get(
+        &self,
+        key: &str,
+    ) -> Option<&KeyValue> {
+        self.pairs
+            .iter()
+            .find(|pair| pair.key == key)
+    }
+}
+
+/// Is `b` a byte of a CEF extension key or LEEF attribute key?
+const fn is_extension_key_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'_' | b'.' | b'-' | b'[' | b']')
+}
+
+/// Unescape a CEF or LEEF value; `\=`, `\|`, `\\`, `\n`, `\r`.
+fn keyvalue_unescape(value: &str) -> String {
+    if !value.contains('\\') {
+        return String::from(value);
+    }
+    let mut out: String = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some(c_) => out.push(c_),
+            None => out.push('\\'),
+        }
+    }
+
+    out
+}
+
+/// Parse `line` as a logfmt line.
+/// The first key must have a value; a later key without a value has an empty
+/// value.
+pub fn keyvalue_parse_logfmt(line: &str) -> Option<Vec<KeyValue>> {
+    let bytes: &[u8] = line.as_bytes();
+    let mut pairs: Vec<KeyValue> = Vec::new();
+    let mut at: usize = 0;
+    loop {
+        while at < bytes.len() && bytes[at].is_ascii_whitespace() {
+            at += 1;
+        }
+        if at >= bytes.len() {
+            break;
+        }
+        let key_beg: usize = at;
+        while at < bytes.len() && !bytes[at].is_ascii_whitespace() && !matches!(bytes[at], b'=' | b'"') {
+            at += 1;
+        }
+        if at == key_beg {
+            return None;
+        }
+        let key: &str = &line[key_beg..at];
+        if at >= bytes.len() || bytes[at] != b'=' {
+            if pairs.is_empty() || (at < bytes.len() && bytes[at] == b'"') {
+                return None;
+            }
+            pairs.push(KeyValue { key: String::from(key), value: String::new(), beg: at, end: at });
+            continue;
+        }
+        at += 1;
+        if at < bytes.len() && bytes[at] == b'"' {
+            let quote: usize = at;
+            at += 1;
+            while at < bytes.len() && bytes[at] != b'"' {
+                at += if bytes[at] == b'\\' { 2 } else { 1 };
+            }
+            if at >= bytes.len() {
+                return None;
+            }
+            let value: String = ::serde_json::from_str::<String>(&line[quote..=at])
+                .unwrap_or_else(|_| String::from(&line[quote + 1..at]));
+            pairs.push(KeyValue { key: String::from(key), value, beg: quote + 1, end: at });
+            at += 1;
+            continue;
+        }
+        let value_beg: usize = at;
+        while at < bytes.len() && !bytes[at].is_ascii_whitespace() {
+            at += 1;
+        }
+        pairs.push(KeyValue {
+            key: String::from(key),
+            value: String::from(&line[value_beg..at]),
+            beg: value_beg,
+            end: at,
+        });
+    }
+    if pairs.is_empty() {
+        return None;
+    }
+
+    Some(pairs)
+}
+
+/// Parse the CEF extension or space-delimited LEEF attributes
+/// `line[beg..]`; `key=value` pairs separated by spaces where a value may
+/// contain spaces and an `=` within a value is escaped.
+fn keyvalue_parse_extension(
+    line: &str,
+    beg: usize,
+    pairs: &mut Vec<KeyValue>,
+) {
+    let bytes: &[u8] = line.as_bytes();
+    // (key begin, value begin) of each key
+    let mut keys: Vec<(usize, usize)> = Vec::new();
+    let mut at: usize = beg;
+    while at < bytes.len() {
+        if at == beg || bytes[at - 1] == b' ' {
+            let mut end: usize = at;
+            while end < bytes.len() && is_extension_key_byte(bytes[end]) {
+                end += 1;
+            }
+            if end > at && end < bytes.len() && bytes[end] == b'=' {
+                keys.push((at, end + 1));
+                at = end + 1;
+                continue;
+            }
+        }
+        at += if bytes[at] == b'\\' { 2 } else { 1 };
+    }
+    for (index, (key_beg, value_beg)) in keys.iter().enumerate() {
+        let value_end: usize = match keys.get(index + 1) {
+            Some((key_beg_next, _)) => *key_beg_next,
+            None => bytes.len(),
+        };
+        let value: &str = line[*value_beg..value_end].trim_end();
+        pairs.push(KeyValue {
+            key: String::from(&line[*key_beg..value_beg - 1]),
+            value: keyvalue_unescape(value),
+            beg: *value_beg,
+            end: value_beg + value.len(),
+        });
+    }
+}
+
+/// Split `line[beg..]` at an unescaped `|` for each of `keys`. Append the
+/// header fields as `KeyValue`s of `keys` and return the offset after the
+/// last `|`.
+fn keyvalue_parse_header(
+    line: &str,
+    beg: usize,
+    keys: &[&str],
+    pairs: &mut Vec<KeyValue>,
+) -> Option<usize> {
+    let bytes: &[u8] = line.as_bytes();
+    let mut field_beg: usize = beg;
+    let mut at: usize = beg;
+    for key in keys.iter() {
+        while at < bytes.len() && bytes[at] != b'|' {
+            at += if bytes[at] == b'\\' { 2 } else { 1 };
+        }
+        if at >= bytes.len() {
+            return None;
+        }
+        pairs.push(KeyValue {
+            key: String::from(*key),
+            value: keyvalue_unescape(&line[field_beg..at]),
+            beg: field_beg,
+            end: at,
+        });
+        at += 1;
+        field_beg = at;
+    }
+
+    Some(at)
+}
+
+/// Parse `line[beg..]`, beginning with `CEF:`, as a CEF line.
+pub fn keyvalue_parse_cef(
+    line: &str,
+    beg: usize,
+) -> Option<Vec<KeyValue>> {
+    let mut pairs: Vec<KeyValue> = Vec::new();
+    let extension: usize = keyvalue_parse_header(line, beg + "CEF:".len(), &CEF_HEADER_KEYS, &mut pairs)?;
+    keyvalue_parse_extension(line, extension, &mut pairs);
+
+    Some(pairs)
+}
+
+/// The LEEF 2.0 attribute delimiter declared by header field `field`, e.g.
+/// `^`, `x5E`, or `0x5E`. An empty `field` is a tab.
+fn leef_delimiter(field: &str) -> Option<char> {
+    let hex: Option<&str> = field
+        .strip_prefix("0x")
+        .or_else(|| field.strip_prefix("x"))
+        .or_else(|| field.strip_prefix("0X"))
+        .or_else(|| field.strip_prefix("X"));
+    match hex {
+        Some(hex) if !hex.is_empty() => u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(char::from_u32),
+        _ => {
+            let mut chars = field.chars();
+            match (chars.next(), chars.next()) {
+                (None, _) => Some('\t'),
+                (Some(c), None) => Some(c),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Parse `line[beg..]`, beginning with `LEEF:`, as a LEEF line.
+pub fn keyvalue_parse_leef(
+    line: &str,
+    beg: usize,
+) -> Option<Vec<KeyValue>> {
+    let mut pairs: Vec<KeyValue> = Vec::new();
+    let mut attributes: usize = keyvalue_parse_header(line, beg + "LEEF:".len(), &LEEF_HEADER_KEYS, &mut pairs)?;
+    let mut delimiter: char = '\t';
+    if pairs[0].value.starts_with('2') {
+        // LEEF 2.0 may declare the delimiter in the next header field
+        if let Some(end) = line[attributes..].find('|') {
+            let field: &str = &line[attributes..attributes + end];
+            if !field.contains('=') {
+                delimiter = leef_delimiter(field)?;
+                attributes += end + 1;
+            }
+        }
+    }
+    let text: &str = &line[attributes..];
+    if delimiter == '\t' && !text.contains('\t') {
+        // some LEEF writers delimit attributes by space like CEF
+        keyvalue_parse_extension(line, attributes, &mut pairs);
+        return Some(pairs);
+    }
+    let mut at: usize = attributes;
+    for attribute in text.split(delimiter) {
+        if let Some((key, value)) = attribute.split_once('=') {
+            let key_: &str = key.trim();
+            if !key_.is_empty() {
+                let beg_: usize = at + key.len() + 1;
+                pairs.push(KeyValue {
+                    key: String::from(key_),
+                    value: keyvalue_unescape(value),
+                    beg: beg_,
+                    end: beg_ + value.len(),
+                });
+            }
+        }
+        at += attribute.len() + delimiter.len_utf8();
+    }
+
+    Some(pairs)
+}
+
+/// The offset of `marker`, e.g. `CEF:`, at the beginning of `line` or after
+/// whitespace, i.e. after a syslog header.
+fn keyvalue_find_marker(
+    line: &str,
+    marker: &str,
+) -> Option<usize> {
+    let mut from: usize = 0;
+    while let Some(found) = line[from..].find(marker) {
+        let at: usize = from + found;
+        if at == 0 || line.as_bytes()[at - 1].is_ascii_whitespace() {
+            return Some(at);
+        }
+        from = at + marker.len();
+    }
+
+    None
+}
+
+/// Parse the first line of `text` as a CEF, LEEF, or logfmt line.
+pub fn keyvalue_parse(text: &str) -> Option<KeyValues> {
+    let line: &str = text.lines().next()?;
+    let line: &str = line.trim_end_matches('\r');
+    if let Some(pairs) = keyvalue_find_marker(line, "CEF:").and_then(|at| keyvalue_parse_cef(line, at)) {
+        return Some(KeyValues { format: KeyValueFormat::Cef, pairs });
+    }
+    if let Some(pairs) = keyvalue_find_marker(line, "LEEF:").and_then(|at| keyvalue_parse_leef(line, at)) {
+        return Some(KeyValues { format: KeyValueFormat::Leef, pairs });
+    }
+    let pairs: Vec<KeyValue> = keyvalue_parse_logfmt(line)?;
+
+    Some(KeyValues { format: KeyValueFormat::Logfmt, pairs })
+}
+
+/// Convert a Java date format, e.g. `MMM dd yyyy HH:mm:ss.SSS z`, to a
+/// `strftime` pattern. A named timezone `z` is `%Z`, see
+/// [`keyvalue_replace_tz_name`].
+pub fn keyvalue_java_to_strftime(format: &str) -> Option<String> {
+    let chars: Vec<char> = format.chars().collect();
+    let mut out: String = String::with_capacity(format.len() * 2);
+    let mut at: usize = 0;
+    while at < chars.len() {
+        let c: char = chars[at];
+        let mut len: usize = 1;
+        while at + len < chars.len() && chars[at + len] == c {
+            len += 1;
+        }
+        if c == '\'' {
+            // quoted literal text; `''` is a quote
+            if len == 2 {
+                out.push('\'');
+                at += 2;
+                continue;
+            }
+            at += 1;
+            while at < chars.len() && chars[at] != '\'' {
+                match chars[at] {
+                    '%' => out.push_str("%%"),
+                    c_ => out.push(c_),
+                }
+                at += 1;
+            }
+            at += 1;
+            continue;
+        }
+        if !c.is_ascii_alphabetic() {
+            match c {
+                '%' => out.push_str("%%"),
+                c_ => out.push(c_),
+            }
+            at += 1;
+            continue;
+        }
+        let spec: &str = match (c, len) {
+            ('y', 2) => "%y",
+            ('y', _) => "%Y",
+            ('M', 1..=2) => "%m",
+            ('M', 3) => "%b",
+            ('M', _) => "%B",
+            ('d', _) => "%d",
+            ('H', _) => "%H",
+            ('h', _) => "%I",
+            ('m', _) => "%M",
+            ('s', _) => "%S",
+            ('S', 3) => "%3f",
+            ('S', 6) => "%6f",
+            ('S', 9) => "%9f",
+            ('a', _) => "%p",
+            ('E', 1..=3) => "%a",
+            ('E', _) => "%A",
+            ('Z', _) | ('X', 1..=2) => "%z",
+            ('X', _) => "%:z",
+            ('z', _) => "%Z",
+            _ => return None,
+        };
+        out.push_str(spec);
+        at += len;
+    }
+
+    Some(out)
+}
+
+/// Replace a trailing named timezone of `value`, e.g. `UTC` of
+/// `Jan 02 2024 03:04:05 UTC`, with the timezone offset, e.g. `+00:00`.
+pub fn keyvalue_replace_tz_name(value: &str) -> Option<String> {
+    let (head, name) = value.trim_end().rsplit_once(' ')?;
+    if name.is_empty() || !name.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+    let offset: &str = match name {
+        "Z" | "UTC" | "GMT" => "+00:00",
+        _ => MAP_TZZ_TO_TZz.get(name)?,
+    };
+    if offset.is_empty() {
+        return None;
+    }
+
+    Some(format!("{} {}", head, offset))
+}
+
+/// The datetime of a key-value datetime `value`; a datetime string or a
+/// Unix epoch number, or a datetime of Java date `format`.
+/// A datetime without a timezone is at `tz_offset`.
+pub fn keyvalue_value_to_datetime(
+    value: &str,
+    format: Option<&str>,
+    tz_offset: &FixedOffset,
+) -> Option<DateTimeL> {
+    let value: &str = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if let Some(pattern) = format.and_then(keyvalue_java_to_strftime) {
+        let (value_, pattern_): (String, String) = match pattern.contains("%Z") {
+            true => (keyvalue_replace_tz_name(value)?, pattern.replace("%Z", "%:z")),
+            false => (String::from(value), pattern),
+        };
+        let has_tz: bool = pattern_.contains("%z") || pattern_.contains("%:z");
+        if let Some(dt) = datetime_parse_from_str(&value_, &pattern_, has_tz, tz_offset) {
+            return Some(dt);
+        }
+    }
+    if let Some(dt) = jsonline_value_to_datetime(&Value::String(String::from(value)), tz_offset) {
+        return Some(dt);
+    }
+    if let Some(value_) = keyvalue_replace_tz_name(value) {
+        for pattern in KEYVALUE_PATTERNS_TZ.iter() {
+            if let Some(dt) = datetime_parse_from_str(&value_, pattern, true, tz_offset) {
+                return Some(dt);
+            }
+        }
+    }
+    for pattern in KEYVALUE_PATTERNS_TZ.iter() {
+        if let Some(dt) = datetime_parse_from_str(value, pattern, true, tz_offset) {
+            return Some(dt);
+        }
+    }
+    for pattern in KEYVALUE_PATTERNS.iter() {
+        if let Some(dt) = datetime_parse_from_str(value, pattern, false, tz_offset) {
+            return Some(dt);
+        }
+    }
+
+    None
+}
+
+/// Finds the datetime of a key-value line by the datetime key.
+///
+/// Set by the `--kv-lines` and `--kv-time` command-line options.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyValueTime {
+    /// keys of the datetime value, tried in order
+    pub keys: Vec<String>,
+    /// Only a key-value line begins a log message, see
+    /// [`KeyValueMode::Always`]
+    pub always: bool,
+}
+
+impl Default for KeyValueTime {
+    fn default() -> KeyValueTime {
+        KeyValueTime {
+            keys: KEYVALUE_TIME_KEYS_DEFAULT
+                .iter()
+                .map(|key| String::from(*key))
+                .collect(),
+            always: false,
+        }
+    }
+}
+
+impl KeyValueTime {
+    /// Create a new `KeyValueTime` for `mode` from a comma-separated list of
+    /// keys of the datetime value, e.g. `"rt,end"`. Without `keys` the
+    /// [`KEYVALUE_TIME_KEYS_DEFAULT`] are used.
+    ///
+    /// Return `None` for [`KeyValueMode::Never`].
+    pub fn new(
+        mode: KeyValueMode,
+        keys: Option<&str>,
+    ) -> Option<KeyValueTime> {
+        let mut keyvalue_time: KeyValueTime = match mode {
+            KeyValueMode::Never => return None,
+            KeyValueMode::Auto => KeyValueTime::default(),
+            KeyValueMode::Always => KeyValueTime {
+                always: true,
+                ..KeyValueTime::default()
+            },
+        };
+        let keys: Vec<String> = keys
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+            .collect();
+        if !keys.is_empty() {
+            keyvalue_time.keys = keys;
+        }
+
+        Some(keyvalue_time)
+    }
+
+    /// Find the datetime of `line` by the first datetime key found.
+    /// Return the byte offsets of the datetime value within `line` and the
+    /// datetime.
+    pub fn find_datetime(
+        &self,
+        line: &str,
+        tz_offset: &FixedOffset,
+    ) -> Option<(usize, usize, DateTimeL)> {
+        defn!("({:?})", line);
+        let keyvalues: KeyValues = keyvalue_parse(line)?;
+        let format: Option<&str> = match keyvalues.format {
+            KeyValueFormat::Leef => keyvalues
+                .get(LEEF_DEVTIMEFORMAT)
+                .map(|pair| pair.value.as_str()),
+            _ => None,
+        };
+        for key in self.keys.iter() {
+            let pair: &KeyValue = match keyvalues.get(key) {
+                Some(pair) => pair,
+                None => continue,
+            };
+            let dt: DateTimeL = match keyvalue_value_to_datetime(&pair.value, format, tz_offset) {
+                Some(dt) => dt,
+                None => continue,
+            };
+            defx!("return ({}, {}, {:?}) for {:?} {}", pair.beg, pair.end, dt, key, keyvalues.format);
+            return Some((pair.beg, pair.end, dt));
+        }
+        defx!("return None");
+
+        None
+    }
+}
+
+/// Filter key-value lines by key value.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct KeyValueFilter {
+    /// a line passes if each key has the value
+    pub fields: Vec<(String, String)>,
+}
+
+impl KeyValueFilter {
+    /// Create a new `KeyValueFilter` from `KEY=VALUE` pairs, e.g.
+    /// `"level=error"` or `"severity=10"`.
+    pub fn new(fields: &[String]) -> std::result::Result<KeyValueFilter, String> {
+        let mut fields_: Vec<(String, String)> = Vec::with_capacity(fields.len());
+        for field in fields.iter() {
+            match field.split_once('=') {
+                Some((key, value)) if !key.trim().is_empty() => {
+                    fields_.push((String::from(key.trim()), String::from(value)));
+                }
+                _ => return Err(format!("Invalid key-value field filter {:?}; expected KEY=VALUE", field)),
+            }
+        }
+
+        Ok(KeyValueFilter { fields: fields_ })
+    }
+
+    /// Does this filter pass all lines?
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Does `keyvalues` pass this filter? A line that is not a key-value
+    /// line, `None`, only passes an empty filter.
+    pub fn matches(
+        &self,
+        keyvalues: Option<&KeyValues>,
+    ) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        let keyvalues: &KeyValues = match keyvalues {
+            Some(keyvalues) => keyvalues,
+            None => return false,
+        };
+        self.fields.iter().all(|(key, value)| {
+            keyvalues
+                .get(key)
+                .is_some_and(|pair| pair.value == *value)
+        })
+    }
+}
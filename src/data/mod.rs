@@ -45,6 +45,26 @@
 //!
 //! It is not necessarily referring to an [RFC 5424] compliant log message.
 //!
+//! Linux audit records of `Sysline`s may be grouped into audit events by
+//! an [`AuditGrouper`].
+//!
+//! The interrupted system calls of `strace` output `Sysline`s may be joined
+//! by a [`StraceGrouper`].
+//!
+//! The datetime of a JSON lines `Sysline` may be found by the datetime field
+//! of the JSON object by a [`JsonLineTime`], and the datetime of a logfmt,
+//! CEF, or LEEF `Sysline` by the datetime key by a [`KeyValueTime`].
+//!
+//! The datetime of a W3C extended log or CSV `Sysline` may be found by the
+//! datetime columns named by the file header by a [`ColumnarHeader`].
+//!
+//! The partial lines of a CRI or Docker json-file container log `Sysline`
+//! may be joined into one line by a [`ContainerLog`].
+//!
+//! The datetime of a `Sysline` introduced by a line of only a datetime, e.g.
+//! a bash history `#1700000000` line, may be found by
+//! [`timestampline_parse`].
+//!
 //! ### Syslog
 //!
 //! A "syslog" is a file that:
@@ -65,6 +85,11 @@
 //!
 //! A `FixedStruct` entry represents a "log message".
 //!
+//! `utmp`, `utmpx`, and `wtmp` entries may be paired into login sessions by a
+//! [`UtmpSessionTracker`].
+//! `acct` entries may be summarized per command and per user by an
+//! [`AcctSummary`].
+//!
 //! ### Evtx
 //!
 //! A [`Evtx`] is information about a processed [`evtx`] structure
@@ -72,6 +97,9 @@
 //!
 //! An `Evtx` represents a "log message".
 //!
+//! A legacy `.evt` file record is decoded to an [`EvtRecord`] and then
+//! converted to an `Evtx`.
+//!
 //! ### Journal
 //!
 //! A [`JournalEntry`] is information about a processed [systemd journal entry].
@@ -102,18 +130,38 @@
 //! [RFC 5424]: https://www.rfc-editor.org/rfc/rfc5424.html
 //! [a certain minimum]: static@crate::readers::syslogprocessor::BLOCKZERO_ANALYSIS_SYSLINE_COUNT_MIN_MAP
 //! [`FixedStruct`]: crate::data::fixedstruct::FixedStruct
+//! [`AuditGrouper`]: crate::data::audit::AuditGrouper
+//! [`StraceGrouper`]: crate::data::strace::StraceGrouper
+//! [`JsonLineTime`]: crate::data::jsonline::JsonLineTime
+//! [`KeyValueTime`]: crate::data::keyvalue::KeyValueTime
+//! [`ColumnarHeader`]: crate::data::columnar::ColumnarHeader
+//! [`ContainerLog`]: crate::data::containerlog::ContainerLog
+//! [`timestampline_parse`]: crate::data::timestampline::timestampline_parse
+//! [`UtmpSessionTracker`]: crate::data::utmpsession::UtmpSessionTracker
+//! [`AcctSummary`]: crate::data::acctsummary::AcctSummary
 //! [`Evtx`]: crate::data::evtx::Evtx
 //! [`evtx`]: crate::data::evtx::SerializedEvtxRecord
+//! [`EvtRecord`]: crate::data::evt::EvtRecord
 //! [`JournalEntry`]: crate::data::journal::JournalEntry
 //! [systemd journal entry]: https://systemd.io/JOURNAL_FILE_FORMAT/
 //! [`Read`]: std::io::Read
 
+pub mod acctsummary;
+pub mod audit;
+pub mod columnar;
 pub mod common;
+pub mod containerlog;
 pub mod datetime;
+pub mod evt;
 pub mod evtx;
 pub mod fixedstruct;
 pub mod journal;
+pub mod jsonline;
+pub mod keyvalue;
 pub mod line;
 pub mod pydataevent;
 pub mod slice_contains;
+pub mod strace;
 pub mod sysline;
+pub mod timestampline;
+pub mod utmpsession;
@@ -0,0 +1,581 @@
+// src/data/strace.rs
+
+//! Implement [`StraceGrouper`] to reassemble the system calls of [`strace`]
+//! output from [`Sysline`]s into [`StraceCall`]s.
+//! Implement [`StraceFilter`] to filter `StraceCall`s by system call name,
+//! PID, and error return.
+//!
+//! A system call of `strace -ttt` output looks like
+//!
+//! ```text
+//! 1716854961.676098 access("/etc/ld.so.preload", R_OK) = -1 ENOENT (No such file or directory)
+//! ```
+//!
+//! With option `-f` each line is prefixed with the PID, e.g.
+//! `[pid  1234] 1716854961.676098 …` or `1234  1716854961.676098 …`.
+//! With option `-ff` each PID is written to a separate file `NAME.PID`.
+//!
+//! When a system call of one thread is interrupted by a system call of
+//! another thread then `strace` prints the system call in two halves,
+//!
+//! ```text
+//! [pid  1234] 1716854961.676098 read(3,  <unfinished ...>
+//! [pid  1235] 1716854961.676100 close(4) = 0
+//! [pid  1234] 1716854961.676205 <... read resumed>"abc", 4096) = 3
+//! ```
+//!
+//! The two halves are joined into one system call with the duration. The
+//! joined system call is printed at the datetime of the second half,
+//!
+//! ```text
+//! [pid  1234] 1716854961.676098 read(3, "abc", 4096) = 3 <0.000107>
+//! ```
+//!
+//! [`Sysline`]: crate::data::sysline::Sysline
+//! [`strace`]: https://www.man7.org/linux/man-pages/man1/strace.1.html
+
+use std::collections::hash_map::Entry;
+use std::collections::{
+    BTreeMap,
+    HashMap,
+};
+use std::fmt;
+
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::common::PathId;
+use crate::data::datetime::DateTimeL;
+use crate::data::sysline::SyslineP;
+
+/// Suffix of the first half of an interrupted system call.
+pub const STRACE_UNFINISHED: &str = " <unfinished ...>";
+
+/// Prefix of the second half of an interrupted system call, followed by
+/// the system call name and [`STRACE_RESUMED`].
+pub const STRACE_RESUMED_PREFIX: &str = "<... ";
+
+/// Follows the system call name of the second half of an interrupted
+/// system call.
+pub const STRACE_RESUMED: &str = " resumed>";
+
+/// Separates the system call and the return value.
+pub const STRACE_RETURN_SEP: &str = " = ";
+
+/// The kind of a line of `strace` output.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StraceKind {
+    /// a whole system call, e.g. `close(3) = 0`
+    Call,
+    /// the first half of an interrupted system call,
+    /// e.g. `read(3,  <unfinished ...>`
+    Unfinished,
+    /// the second half of an interrupted system call,
+    /// e.g. `<... read resumed>"abc", 4096) = 3`
+    Resumed,
+    /// a signal, e.g. `--- SIGCHLD {si_signo=SIGCHLD, …} ---`
+    Signal,
+    /// the exit of a process, e.g. `+++ exited with 0 +++`
+    Exit,
+    /// anything else
+    Other,
+}
+
+/// Is `s` a system call name, e.g. `openat`, `_llseek`?
+fn is_syscall_name(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+        && !s.as_bytes()[0].is_ascii_digit()
+}
+
+/// The PID of the `strace -f` prefix of `line` and the byte offset of the
+/// system call, i.e. after the PID prefix, the timestamp, and bracketed
+/// fields of options `-i` and `-n`.
+///
+/// For example, for line
+/// `[pid  1234] 1716854961.676098 [  21] close(3) = 0`
+/// return `(Some(1234), 36)`.
+pub fn strace_prefix(line: &str) -> (Option<u32>, usize) {
+    let mut pid: Option<u32> = None;
+    let mut at: usize = 0;
+    let bytes: &[u8] = line.as_bytes();
+    let skip_blanks = |mut at: usize| -> usize {
+        while at < bytes.len() && (bytes[at] == b' ' || bytes[at] == b'\t') {
+            at += 1;
+        }
+        at
+    };
+    let digits_end = |mut at: usize| -> usize {
+        while at < bytes.len() && bytes[at].is_ascii_digit() {
+            at += 1;
+        }
+        at
+    };
+    if let Some(rest) = line.strip_prefix("[pid") {
+        if let Some(close) = rest.find(']') {
+            pid = rest[..close].trim().parse::<u32>().ok();
+            if pid.is_some() {
+                at = skip_blanks("[pid".len() + close + 1);
+            }
+        }
+    } else {
+        // `strace -f -o FILE` prefix "PID  TIMESTAMP"
+        let end: usize = digits_end(0);
+        let next: usize = skip_blanks(end);
+        if end > 0 && next > end && next < bytes.len() && bytes[next].is_ascii_digit() {
+            pid = line[..end].parse::<u32>().ok();
+            if pid.is_some() {
+                at = next;
+            }
+        }
+    }
+    // the timestamp, e.g. "1716854961.676098"
+    let end: usize = digits_end(at);
+    if end > at {
+        let mut end_: usize = end;
+        if end_ < bytes.len() && (bytes[end_] == b'.' || bytes[end_] == b',') {
+            end_ = digits_end(end_ + 1);
+        }
+        if end_ == bytes.len() || bytes[end_] == b' ' {
+            at = skip_blanks(end_);
+        }
+    }
+    // fields of options `-n` and `-i`, e.g. "[  21]" and "[00007f2d62a9808b]"
+    while at < bytes.len() && bytes[at] == b'[' {
+        match line[at..].find(']') {
+            Some(close) => at = skip_blanks(at + close + 1),
+            None => break,
+        }
+    }
+
+    (pid, at)
+}
+
+/// The kind and the system call name of the system call text `call`, e.g.
+/// for `<... read resumed>"abc", 4096) = 3` return
+/// `(StraceKind::Resumed, Some("read"))`.
+pub fn strace_kind(call: &str) -> (StraceKind, Option<&str>) {
+    if call.starts_with("--- ") {
+        return (StraceKind::Signal, None);
+    }
+    if call.starts_with("+++ ") {
+        return (StraceKind::Exit, None);
+    }
+    if let Some(rest) = call.strip_prefix(STRACE_RESUMED_PREFIX) {
+        if let Some(end) = rest.find(STRACE_RESUMED) {
+            let name: &str = &rest[..end];
+            if is_syscall_name(name) {
+                return (StraceKind::Resumed, Some(name));
+            }
+        }
+        return (StraceKind::Other, None);
+    }
+    let name: Option<&str> = match call.find('(') {
+        Some(paren) if is_syscall_name(&call[..paren]) => Some(&call[..paren]),
+        _ => None,
+    };
+    match name {
+        Some(_) if call.ends_with(STRACE_UNFINISHED) => (StraceKind::Unfinished, name),
+        Some(_) => (StraceKind::Call, name),
+        None => (StraceKind::Other, None),
+    }
+}
+
+/// The duration of option `-T` at the end of system call text `call`, e.g.
+/// for `close(3) = 0 <0.000012>` return `"0.000012"`.
+pub fn strace_duration(call: &str) -> Option<&str> {
+    let call: &str = call.trim_end();
+    let open: usize = call.rfind(" <")?;
+    let duration: &str = call[open + 2..].strip_suffix('>')?;
+    if duration.is_empty() || !duration.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        return None;
+    }
+
+    Some(duration)
+}
+
+/// The return value and the error name of system call text `call`, e.g.
+/// for `access("/etc/ld.so.preload", R_OK) = -1 ENOENT (No such file or directory)`
+/// return `("-1", Some("ENOENT"))`.
+pub fn strace_return(call: &str) -> Option<(&str, Option<&str>)> {
+    let at: usize = call.rfind(STRACE_RETURN_SEP)?;
+    let mut words = call[at + STRACE_RETURN_SEP.len()..].split_ascii_whitespace();
+    let retval: &str = words.next()?;
+    let errno: Option<&str> = words.next().filter(|word| {
+        word.len() > 1
+            && word.starts_with('E')
+            && word
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+    });
+
+    Some((retval, errno))
+}
+
+/// The PID of a file written by `strace -ff -o NAME`, i.e. the `PID` of
+/// the file name `NAME.PID`.
+pub fn strace_path_pid(path: &str) -> Option<u32> {
+    let name: &str = path.rsplit(['/', '\\']).next()?;
+    let (_, pid) = name.rsplit_once('.')?;
+    if pid.is_empty() || !pid.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    pid.parse::<u32>().ok()
+}
+
+/// One system call, signal, or other line of `strace` output.
+/// The two halves of an interrupted system call are joined into one
+/// `StraceCall`, see [`join`].
+///
+/// [`join`]: StraceCall::join
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StraceCall {
+    /// datetime of the system call; of the second half of a joined
+    /// interrupted system call
+    pub dt: DateTimeL,
+    /// the PID of the `-f` prefix or of the `-ff` file name
+    pub pid: Option<u32>,
+    pub kind: StraceKind,
+    /// the system call name, e.g. `read`
+    pub syscall: Option<String>,
+    /// the lines without the ending newline; the first line is the system
+    /// call, other lines are e.g. the stack traces of option `-k`
+    pub lines: Vec<String>,
+    /// byte offset of the system call within the first line
+    pub call_at: usize,
+}
+
+impl StraceCall {
+    /// Create a `StraceCall` from the `text` of a `Sysline` at datetime `dt`.
+    /// If the line of a system call, signal, or exit has no PID prefix then
+    /// prefix `pid`.
+    pub fn new(
+        dt: DateTimeL,
+        text: &str,
+        pid: Option<u32>,
+    ) -> StraceCall {
+        let mut lines: Vec<String> = text
+            .lines()
+            .map(|line| String::from(line.trim_end_matches('\r')))
+            .collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        let (pid_, mut call_at) = strace_prefix(&lines[0]);
+        let (kind, syscall) = strace_kind(&lines[0][call_at..]);
+        let syscall: Option<String> = syscall.map(String::from);
+        let pid: Option<u32> = match (pid_, pid) {
+            (Some(pid_), _) => Some(pid_),
+            (None, Some(pid)) if kind != StraceKind::Other => {
+                let prefix: String = format!("[pid {}] ", pid);
+                call_at += prefix.len();
+                lines[0].insert_str(0, &prefix);
+                Some(pid)
+            }
+            (None, _) => None,
+        };
+
+        StraceCall {
+            dt,
+            pid,
+            kind,
+            syscall,
+            lines,
+            call_at,
+        }
+    }
+
+    pub const fn dt(&self) -> &DateTimeL {
+        &self.dt
+    }
+
+    /// The system call text of the first line, e.g. `close(3) = 0`.
+    pub fn call(&self) -> &str {
+        &self.lines[0][self.call_at..]
+    }
+
+    /// The return value, e.g. `-1`.
+    pub fn retval(&self) -> Option<&str> {
+        strace_return(self.call()).map(|(retval, _)| retval)
+    }
+
+    /// The error name of a failed system call, e.g. `ENOENT`.
+    pub fn errno(&self) -> Option<&str> {
+        strace_return(self.call()).and_then(|(_, errno)| errno)
+    }
+
+    /// The duration of option `-T`, e.g. `0.000012`.
+    pub fn duration(&self) -> Option<&str> {
+        strace_duration(self.call())
+    }
+
+    /// Join this second half of an interrupted system call to the first half
+    /// `unfinished`. The joined first line is the first line of `unfinished`
+    /// followed by the rest of this second half. Without a duration of option
+    /// `-T` the duration is the time between the two halves.
+    /// The datetime remains the datetime of this second half.
+    pub fn join(
+        &mut self,
+        unfinished: StraceCall,
+    ) {
+        debug_assert_eq!(self.kind, StraceKind::Resumed);
+        debug_assert_eq!(unfinished.kind, StraceKind::Unfinished);
+        let rest: &str = match self.call().find(STRACE_RESUMED) {
+            Some(at) => &self.call()[at + STRACE_RESUMED.len()..],
+            None => self.call(),
+        };
+        let mut first: String = String::from(
+            unfinished.lines[0]
+                .strip_suffix(STRACE_UNFINISHED)
+                .unwrap_or(&unfinished.lines[0])
+        );
+        first.push_str(rest);
+        if strace_duration(rest).is_none() {
+            let micros: i64 = (self.dt - unfinished.dt)
+                .num_microseconds()
+                .unwrap_or(0)
+                .max(0);
+            first.push_str(&format!(" <{}.{:06}>", micros / 1_000_000, micros % 1_000_000));
+        }
+        let mut lines: Vec<String> = Vec::with_capacity(unfinished.lines.len() + self.lines.len() - 1);
+        lines.push(first);
+        lines.extend(unfinished.lines.into_iter().skip(1));
+        lines.extend(self.lines.drain(..).skip(1));
+        self.lines = lines;
+        self.call_at = unfinished.call_at;
+        self.kind = StraceKind::Call;
+    }
+}
+
+/// Print a `StraceCall` as the lines, one per line.
+impl fmt::Display for StraceCall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for line in self.lines.iter() {
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The state of one file of `strace` output.
+#[derive(Debug, Default)]
+struct StraceFile {
+    /// the PID of the `-ff` file name
+    pid: Option<u32>,
+    /// the unfinished call of each PID
+    unfinished: HashMap<Option<u32>, StraceCall>,
+}
+
+/// Reassembles [`StraceCall`]s of `strace` output.
+///
+/// The first half of an interrupted system call of a PID,
+/// `<unfinished ...>`, is kept. When the second half, `<... resumed>`,
+/// is processed then the two halves are returned joined into one
+/// `StraceCall` with the duration, see [`StraceCall::join`].
+/// Every other sysline is returned immediately so calls stay in datetime
+/// order with other log messages; later calls are never held back.
+/// A line without a PID prefix of a file written by `strace -ff` is prefixed
+/// with the PID of the file name, see [`set_path_pid`].
+///
+/// Syslines of each file are reassembled separately. Syslines must be passed
+/// in file order. Only the last unfinished call of each PID is kept; an
+/// unfinished call that never resumes is returned as it is when it is
+/// abandoned, when its process exits, or by [`finish_path`].
+///
+/// [`finish_path`]: StraceGrouper::finish_path
+///
+/// [`set_path_pid`]: StraceGrouper::set_path_pid
+#[derive(Debug, Default)]
+pub struct StraceGrouper {
+    files: BTreeMap<PathId, StraceFile>,
+}
+
+impl StraceGrouper {
+    pub fn new() -> StraceGrouper {
+        StraceGrouper::default()
+    }
+
+    /// Set the PID of the lines without a PID prefix of file `pathid`, i.e.
+    /// the PID of the file name of a file written by `strace -ff`.
+    pub fn set_path_pid(
+        &mut self,
+        pathid: PathId,
+        pid: Option<u32>,
+    ) {
+        self.files.entry(pathid).or_default().pid = pid;
+    }
+
+    /// Count of unfinished calls kept.
+    pub fn count_open(&self) -> usize {
+        self.files
+            .values()
+            .map(|file| file.unfinished.len())
+            .sum()
+    }
+
+    /// Process a `Sysline` of file `pathid`.
+    /// Return the calls to print now.
+    pub fn process(
+        &mut self,
+        pathid: PathId,
+        syslinep: &SyslineP,
+    ) -> Vec<StraceCall> {
+        let text: String = String::from_utf8_lossy(&syslinep.to_bytes()).into_owned();
+
+        self.process_text(pathid, syslinep.dt(), &text)
+    }
+
+    /// Process the `text` of one sysline at datetime `dt` of file `pathid`.
+    /// Return the calls to print now; none for the first half of an
+    /// interrupted system call.
+    pub fn process_text(
+        &mut self,
+        pathid: PathId,
+        dt: &DateTimeL,
+        text: &str,
+    ) -> Vec<StraceCall> {
+        defn!("({}, {:?})", pathid, text);
+        let file: &mut StraceFile = self.files.entry(pathid).or_default();
+        let mut call: StraceCall = StraceCall::new(*dt, text, file.pid);
+        let mut calls: Vec<StraceCall> = Vec::with_capacity(2);
+        match call.kind {
+            StraceKind::Resumed => {
+                if let Entry::Occupied(entry) = file.unfinished.entry(call.pid)
+                    && entry.get().syscall == call.syscall
+                {
+                    call.join(entry.remove());
+                }
+                calls.push(call);
+            }
+            StraceKind::Unfinished => {
+                // a PID has one unfinished call; an earlier one is abandoned
+                calls.extend(file.unfinished.insert(call.pid, call));
+            }
+            StraceKind::Exit => {
+                // an unfinished call of an exited process never resumes
+                calls.extend(file.unfinished.remove(&call.pid));
+                calls.push(call);
+            }
+            _ => calls.push(call),
+        }
+        defx!("return {} calls", calls.len());
+
+        calls
+    }
+
+    /// Return the unfinished calls kept for file `pathid` as they are,
+    /// in datetime order, i.e. after the last sysline of the file.
+    pub fn finish_path(
+        &mut self,
+        pathid: PathId,
+    ) -> Vec<StraceCall> {
+        let mut calls: Vec<StraceCall> = match self.files.get_mut(&pathid) {
+            Some(file) => file.unfinished.drain().map(|(_pid, call)| call).collect(),
+            None => return Vec::with_capacity(0),
+        };
+        calls.sort_by_key(|call| call.dt);
+
+        calls
+    }
+}
+
+/// Filter [`StraceCall`]s by system call name, PID, and error return,
+/// similar to `strace --trace`, `strace --pid`, and `strace --status=failed`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StraceFilter {
+    /// a call passes if it is one of these system calls
+    pub syscalls: Vec<String>,
+    /// a call passes if it is of one of these PIDs
+    pub pids: Vec<u32>,
+    /// a call passes if it returned an error
+    pub failed: bool,
+    /// a call passes if it returned one of these errors, e.g. `ENOENT`
+    pub errnos: Vec<String>,
+}
+
+impl StraceFilter {
+    /// Create a new `StraceFilter` from comma-separated lists of system call
+    /// names, e.g. `"open,openat"`, PIDs, e.g. `"1234,1235"`, and error
+    /// names, e.g. `"ENOENT,EACCES"`.
+    pub fn new(
+        syscalls: Option<&str>,
+        pids: Option<&str>,
+        failed: bool,
+        errnos: Option<&str>,
+    ) -> std::result::Result<StraceFilter, String> {
+        fn split(list: Option<&str>) -> Vec<String> {
+            list.unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+                .collect()
+        }
+
+        let mut pids_: Vec<u32> = Vec::new();
+        for pid in split(pids).iter() {
+            match pid.parse::<u32>() {
+                Ok(pid_) => pids_.push(pid_),
+                Err(err) => return Err(format!("Invalid PID {:?}; {}", pid, err)),
+            }
+        }
+
+        Ok(StraceFilter {
+            syscalls: split(syscalls),
+            pids: pids_,
+            failed,
+            errnos: split(errnos)
+                .into_iter()
+                .map(|e| e.to_ascii_uppercase())
+                .collect(),
+        })
+    }
+
+    /// Does this filter pass all calls?
+    pub fn is_empty(&self) -> bool {
+        self.syscalls.is_empty() && self.pids.is_empty() && !self.failed && self.errnos.is_empty()
+    }
+
+    /// Does `call` pass this filter?
+    pub fn matches(
+        &self,
+        call: &StraceCall,
+    ) -> bool {
+        if !self.syscalls.is_empty()
+            && !call
+                .syscall
+                .as_ref()
+                .is_some_and(|syscall| self.syscalls.contains(syscall))
+        {
+            return false;
+        }
+        if !self.pids.is_empty()
+            && !call
+                .pid
+                .is_some_and(|pid| self.pids.contains(&pid))
+        {
+            return false;
+        }
+        let errno: Option<&str> = match call.kind {
+            StraceKind::Call | StraceKind::Unfinished | StraceKind::Resumed => call.errno(),
+            _ => None,
+        };
+        if self.failed && errno.is_none() {
+            return false;
+        }
+        if !self.errnos.is_empty()
+            && !errno.is_some_and(|errno| self.errnos.iter().any(|e| e == errno))
+        {
+            return false;
+        }
+
+        true
+    }
+}
@@ -495,6 +495,18 @@ impl Sysline {
         s_
     }
 
+    /// Copy the bytes of all `Line`s of this `Sysline`.
+    pub fn to_bytes(self: &Sysline) -> Bytes {
+        let mut buf: Bytes = Bytes::with_capacity(self.len());
+        for linep in &self.lines {
+            for linepart in linep.lineparts.iter() {
+                buf.extend_from_slice(linepart.as_slice());
+            }
+        }
+
+        buf
+    }
+
     /// `Sysline` to `String`.
     ///
     /// inefficient; only for debugging or testing
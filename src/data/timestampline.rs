@@ -0,0 +1,213 @@
+// src/data/timestampline.rs
+
+//! Implement [`timestampline_parse`] to find the datetime of a timestamp
+//! line, a line that introduces the following lines of a log message, e.g.
+//! the lines of a shell history file.
+//!
+//! A bash history file written with `HISTTIMEFORMAT` set looks like
+//!
+//! ```text
+//! #1700000000
+//! cd /tmp
+//! #1700000010
+//! date -d 2024-01-02
+//! ```
+//!
+//! A zsh history file written with `EXTENDED_HISTORY` set looks like
+//!
+//! ```text
+//! : 1700000000:0;cd /tmp
+//! : 1700000010:2;date -d 2024-01-02
+//! ```
+//!
+//! Other tools write a line of only a datetime followed by the lines of the
+//! record, e.g.
+//!
+//! ```text
+//! 2024-01-02 03:04:05
+//! payload line 1
+//! payload line 2
+//! ```
+//!
+//! A file of these lines is not found automatically, as any log file may
+//! begin with a datetime header line.
+//!
+//! Only a timestamp line begins a log message, so a line of a log message
+//! that has a datetime, e.g. `date -d 2024-01-02`, does not begin a log
+//! message.
+
+use std::fmt;
+
+use ::chrono::FixedOffset;
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::data::datetime::DateTimeL;
+use crate::data::jsonline::jsonline_epoch_to_datetime;
+use crate::data::keyvalue::keyvalue_value_to_datetime;
+
+/// The minimum count of digits of a Unix epoch of a timestamp line,
+/// i.e. after the year 1973.
+pub const TIMESTAMPLINE_EPOCH_DIGITS_MIN: usize = 9;
+
+/// How timestamp lines begin log messages.
+///
+/// Set by the `--timestamp-lines` command-line option.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ::clap::ValueEnum)]
+pub enum TimestampLineMode {
+    /// If the first lines of a file are bash or zsh history, i.e. the first
+    /// line is a bash or zsh history timestamp line and at least a quarter of
+    /// the first lines are timestamp lines of the same format, then only
+    /// timestamp lines of the same format begin a log message.
+    #[default]
+    Auto,
+    /// Only timestamp lines of any format begin a log message, including
+    /// lines of only a datetime.
+    Always,
+    /// Lines are not parsed as timestamp lines.
+    Never,
+}
+
+impl fmt::Display for TimestampLineMode {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            TimestampLineMode::Auto => write!(f, "auto"),
+            TimestampLineMode::Always => write!(f, "always"),
+            TimestampLineMode::Never => write!(f, "never"),
+        }
+    }
+}
+
+/// The format of a timestamp line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimestampLineFormat {
+    /// `#` and a Unix epoch, e.g. bash history `#1700000000`
+    Epoch,
+    /// zsh extended history `: 1700000000:0;command`
+    Zsh,
+    /// only a datetime, optionally after `#`, e.g. `2024-01-02 03:04:05`
+    DateTime,
+}
+
+impl TimestampLineFormat {
+    /// Is a file of timestamp lines of `self` found automatically, by the
+    /// first lines of the file?
+    pub const fn is_auto(&self) -> bool {
+        matches!(self, TimestampLineFormat::Epoch | TimestampLineFormat::Zsh)
+    }
+}
+
+impl fmt::Display for TimestampLineFormat {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            TimestampLineFormat::Epoch => write!(f, "epoch"),
+            TimestampLineFormat::Zsh => write!(f, "zsh"),
+            TimestampLineFormat::DateTime => write!(f, "datetime"),
+        }
+    }
+}
+
+/// Is `value` a Unix epoch of a timestamp line?
+fn timestampline_is_epoch(value: &str) -> bool {
+    value.len() >= TIMESTAMPLINE_EPOCH_DIGITS_MIN && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// The datetime of Unix epoch `value`.
+fn timestampline_epoch_to_datetime(
+    value: &str,
+    tz_offset: &FixedOffset,
+) -> Option<DateTimeL> {
+    jsonline_epoch_to_datetime(value.parse::<f64>().ok()?, tz_offset)
+}
+
+/// Parse a zsh extended history `line`, e.g. `: 1700000000:0;command`.
+fn timestampline_parse_zsh(
+    line: &str,
+    tz_offset: &FixedOffset,
+) -> Option<(usize, usize, DateTimeL)> {
+    let rest: &str = line.strip_prefix(": ")?;
+    let (epoch, rest) = rest.split_once(':')?;
+    let (elapsed, _command) = rest.split_once(';')?;
+    if !timestampline_is_epoch(epoch) || !elapsed.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let dt: DateTimeL = timestampline_epoch_to_datetime(epoch, tz_offset)?;
+
+    Some((2, 2 + epoch.len(), dt))
+}
+
+/// Parse a timestamp `line` of only `#` and a Unix epoch, or only a
+/// datetime optionally after `#`.
+fn timestampline_parse_comment(
+    line: &str,
+    tz_offset: &FixedOffset,
+) -> Option<(usize, usize, DateTimeL, TimestampLineFormat)> {
+    let trimmed: &str = line.trim_start();
+    let mut beg: usize = line.len() - trimmed.len();
+    let (value, comment): (&str, bool) = match trimmed.strip_prefix('#') {
+        Some(rest) => {
+            let value: &str = rest.trim_start();
+            beg += 1 + rest.len() - value.len();
+            (value, true)
+        }
+        None => (trimmed, false),
+    };
+    let value: &str = value.trim_end();
+    if value.is_empty() {
+        return None;
+    }
+    let end: usize = beg + value.len();
+    if timestampline_is_epoch(value) {
+        // an epoch is only a timestamp after `#`; a line of only a number is
+        // too common
+        if !comment {
+            return None;
+        }
+        let dt: DateTimeL = timestampline_epoch_to_datetime(value, tz_offset)?;
+        return Some((beg, end, dt, TimestampLineFormat::Epoch));
+    }
+    // skip an easy case; a datetime begins with a digit or a month name
+    if !value.as_bytes()[0].is_ascii_alphanumeric() || value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let dt: DateTimeL = keyvalue_value_to_datetime(value, None, tz_offset)?;
+
+    Some((beg, end, dt, TimestampLineFormat::DateTime))
+}
+
+/// Parse a timestamp `line`. Return the byte offsets of the datetime within
+/// `line`, the datetime, and the format of `line`.
+/// If `format` is `Some` then only a timestamp line of `format` is parsed.
+/// The ending newline of `line` is ignored.
+pub fn timestampline_parse(
+    line: &str,
+    format: Option<TimestampLineFormat>,
+    tz_offset: &FixedOffset,
+) -> Option<(usize, usize, DateTimeL, TimestampLineFormat)> {
+    let line: &str = line.trim_end_matches(['\r', '\n']);
+    if line.starts_with(": ") {
+        if matches!(format, Some(format_) if format_ != TimestampLineFormat::Zsh) {
+            return None;
+        }
+        let (beg, end, dt) = timestampline_parse_zsh(line, tz_offset)?;
+        return Some((beg, end, dt, TimestampLineFormat::Zsh));
+    }
+    let (beg, end, dt, format_) = timestampline_parse_comment(line, tz_offset)?;
+    if matches!(format, Some(format) if format != format_) {
+        return None;
+    }
+    defñ!("({:?}) return {:?}", line, (beg, end, dt, format_));
+
+    Some((beg, end, dt, format_))
+}
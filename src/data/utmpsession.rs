@@ -0,0 +1,575 @@
+// src/data/utmpsession.rs
+
+//! Implement [`UtmpSessionTracker`] to reconstruct login sessions from
+//! `utmp`, `utmpx`, and `wtmp` [`FixedStruct`] entries, similar to the
+//! program [`last`].
+//! Implement [`FailedLogins`] to summarize `btmp` entries, similar to the
+//! program [`lastb`].
+//!
+//! [`FixedStruct`]: crate::data::fixedstruct::FixedStruct
+//! [`last`]: https://man7.org/linux/man-pages/man1/last.1.html
+//! [`lastb`]: https://man7.org/linux/man-pages/man1/lastb.1.html
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::common::{
+    Count,
+    FPath,
+    FileOffset,
+    FileType,
+    FileTypeFixedStruct,
+    PathId,
+    SUBPATH_SEP,
+};
+use crate::data::datetime::{
+    DateTimeL,
+    DateTimeLOpt,
+    Duration,
+    FixedOffset,
+};
+use crate::data::fixedstruct::{
+    FixedStruct,
+    UtmpFields,
+    UtmpKind,
+    ENTRY_SZ_MAX,
+};
+use crate::readers::blockreader::BlockSz;
+use crate::readers::fixedstructreader::{
+    FixedStructReader,
+    ResultFindFixedStruct,
+    ResultFixedStructReaderNew,
+};
+
+/// `strftime` format of datetimes in a printed [`UtmpSession`] or
+/// [`FailedLogins`].
+pub const UTMPSESSION_DT_FORMAT: &str = "%Y-%m-%d %H:%M:%S %:z";
+
+/// `user` of a system boot session, same as `ut_user` of a Linux
+/// `BOOT_TIME` entry.
+pub const UTMPSESSION_BOOT_USER: &str = "reboot";
+
+/// `line` of a system boot session.
+pub const UTMPSESSION_BOOT_LINE: &str = "system boot";
+
+/// How a [`UtmpSession`] ended.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UtmpSessionEnd {
+    /// a `DEAD_PROCESS` entry matched the session
+    Logout,
+    /// a later login on the same `ut_line` without a logout
+    Gone,
+    /// the system booted without a shutdown
+    Crash,
+    /// the system shutdown
+    Down,
+    /// no entry ended the session
+    StillLoggedIn,
+}
+
+/// A login session; a login paired with the entry that ended it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UtmpSession {
+    pub user: String,
+    pub line: String,
+    pub host: String,
+    /// `ut_pid` of the login entry
+    pub pid: i64,
+    /// `ut_id` of the login entry
+    pub id: String,
+    pub login: DateTimeL,
+    /// datetime of the entry that ended the session
+    pub logout: Option<DateTimeL>,
+    pub end: UtmpSessionEnd,
+}
+
+impl UtmpSession {
+    /// Is this a system boot session, i.e. from a `BOOT_TIME` entry?
+    pub fn is_boot(&self) -> bool {
+        self.line == UTMPSESSION_BOOT_LINE
+    }
+
+    /// The datetime used for sorting among other log messages; the end of the
+    /// session, or the login if the session has not ended.
+    pub fn dt(&self) -> &DateTimeL {
+        match &self.logout {
+            Some(dt) => dt,
+            None => &self.login,
+        }
+    }
+
+    /// Duration of the session. `None` if the session has not ended.
+    pub fn duration(&self) -> Option<Duration> {
+        self.logout
+            .as_ref()
+            .map(|logout| std::cmp::max(*logout - self.login, Duration::zero()))
+    }
+
+    /// End this session.
+    fn close(
+        mut self,
+        dt: &DateTimeL,
+        end: UtmpSessionEnd,
+    ) -> UtmpSession {
+        self.logout = Some(*dt);
+        self.end = end;
+
+        self
+    }
+}
+
+/// Format a `Duration` like `last`, i.e. `HH:MM:SS` prefixed with days
+/// `D+` if one day or more.
+pub fn duration_to_string(duration: &Duration) -> String {
+    let secs: i64 = duration.num_seconds().max(0);
+    let days: i64 = secs / 86400;
+    let hms: String = format!("{:02}:{:02}:{:02}", (secs / 3600) % 24, (secs / 60) % 60, secs % 60);
+    if days > 0 {
+        return format!("{}+{}", days, hms);
+    }
+
+    hms
+}
+
+/// Print a `UtmpSession` as one line similar to `last`, e.g.
+///
+/// ```text
+/// alice    pts/0        192.168.1.10     2024-01-01 10:00:00 +00:00 - 2024-01-01 12:00:00 +00:00 (02:00:00)
+/// ```
+impl fmt::Display for UtmpSession {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:<8} {:<12} {:<16} {}",
+            self.user,
+            self.line,
+            self.host,
+            self.login.format(UTMPSESSION_DT_FORMAT),
+        )?;
+        let (logout, duration) = match (&self.logout, self.duration()) {
+            (Some(logout), Some(duration)) => (logout, duration),
+            _ => {
+                if self.is_boot() {
+                    return write!(f, "   still running");
+                }
+                return write!(f, "   still logged in");
+            }
+        };
+        write!(
+            f,
+            " - {} ({})",
+            logout.format(UTMPSESSION_DT_FORMAT),
+            duration_to_string(&duration),
+        )?;
+        match self.end {
+            UtmpSessionEnd::Logout | UtmpSessionEnd::StillLoggedIn => Ok(()),
+            UtmpSessionEnd::Gone => write!(f, " gone - no logout"),
+            UtmpSessionEnd::Crash => write!(f, " crash"),
+            UtmpSessionEnd::Down => write!(f, " down"),
+        }
+    }
+}
+
+/// Pairs login and logout entries of `utmp`, `utmpx`, and `wtmp` files into
+/// [`UtmpSession`]s.
+///
+/// Entries must be passed in datetime order. Entries may be from many files,
+/// e.g. a rotated `wtmp.1` and `wtmp`.
+///
+/// A `USER_PROCESS` entry begins a session. A `DEAD_PROCESS` entry ends
+/// the open session with the same `ut_pid` and `ut_line`, else the same
+/// `ut_line`, else the same `ut_id`.
+/// A `BOOT_TIME` entry ends all open sessions as a crash, and begins a
+/// system boot session. A shutdown entry ends all open sessions.
+///
+/// A session is returned when it ends so it is ordered by [`UtmpSession::dt`],
+/// not by the login. Sessions that did not end are returned by [`finish`],
+/// or, if passed to [`set_still_open`], at their login.
+///
+/// [`finish`]: UtmpSessionTracker::finish
+/// [`set_still_open`]: UtmpSessionTracker::set_still_open
+#[derive(Debug, Default)]
+pub struct UtmpSessionTracker {
+    /// open login sessions in order of login
+    sessions: Vec<UtmpSession>,
+    /// open system boot session
+    boot: Option<UtmpSession>,
+    /// sessions that no entry ends, not yet returned at their login
+    still_open: Vec<UtmpSession>,
+    /// sessions that no entry ends, returned at their login
+    still_open_returned: Vec<UtmpSession>,
+}
+
+impl UtmpSessionTracker {
+    pub fn new() -> UtmpSessionTracker {
+        UtmpSessionTracker::default()
+    }
+
+    /// Count of open sessions, including a system boot session.
+    pub fn count_open(&self) -> usize {
+        self.sessions.len() + self.boot.iter().count()
+    }
+
+    /// Set the sessions that no entry ends, e.g. from
+    /// [`utmpsessions_still_open`]. Each is returned by [`process`] at its
+    /// login instead of by [`finish`].
+    ///
+    /// [`process`]: UtmpSessionTracker::process
+    /// [`finish`]: UtmpSessionTracker::finish
+    pub fn set_still_open(
+        &mut self,
+        sessions: Vec<UtmpSession>,
+    ) {
+        self.still_open = sessions;
+    }
+
+    /// Process a `utmp` or `utmpx` `FixedStruct`.
+    /// Return the sessions ended by the entry.
+    /// Other `FixedStruct` entries, e.g. `acct`, are ignored.
+    pub fn process(
+        &mut self,
+        fixedstruct: &FixedStruct,
+    ) -> Vec<UtmpSession> {
+        match fixedstruct.utmp_fields() {
+            Some(fields) => self.process_fields(&fields, fixedstruct.dt()),
+            None => Vec::with_capacity(0),
+        }
+    }
+
+    /// Process the `UtmpFields` of an entry at datetime `dt`.
+    /// Return the sessions ended by the entry, in order of login, then the
+    /// session begun by the entry if it is one of the sessions passed to
+    /// [`set_still_open`].
+    ///
+    /// [`set_still_open`]: UtmpSessionTracker::set_still_open
+    pub fn process_fields(
+        &mut self,
+        fields: &UtmpFields,
+        dt: &DateTimeL,
+    ) -> Vec<UtmpSession> {
+        defn!("{:?} {:?}", fields.kind, fields.line);
+        let mut ended: Vec<UtmpSession> = Vec::new();
+        match fields.kind {
+            UtmpKind::BootTime => {
+                ended = self.close_all(dt, UtmpSessionEnd::Crash);
+                self.boot = Some(UtmpSession {
+                    user: String::from(UTMPSESSION_BOOT_USER),
+                    line: String::from(UTMPSESSION_BOOT_LINE),
+                    host: fields.host.clone(),
+                    pid: fields.pid,
+                    id: fields.id.clone(),
+                    login: *dt,
+                    logout: None,
+                    end: UtmpSessionEnd::StillLoggedIn,
+                });
+                ended.extend(self.still_open_begun(self.boot.clone()));
+            }
+            UtmpKind::Shutdown => {
+                ended = self.close_all(dt, UtmpSessionEnd::Down);
+            }
+            UtmpKind::UserProcess if !fields.user.is_empty() => {
+                // a prior session on the same line was never logged out
+                if !fields.line.is_empty()
+                    && let Some(at) = self.sessions.iter().position(|s| s.line == fields.line)
+                {
+                    ended.push(self.sessions.remove(at).close(dt, UtmpSessionEnd::Gone));
+                }
+                self.sessions.push(UtmpSession {
+                    user: fields.user.clone(),
+                    line: fields.line.clone(),
+                    host: fields.host.clone(),
+                    pid: fields.pid,
+                    id: fields.id.clone(),
+                    login: *dt,
+                    logout: None,
+                    end: UtmpSessionEnd::StillLoggedIn,
+                });
+                ended.extend(self.still_open_begun(self.sessions.last().cloned()));
+            }
+            UtmpKind::DeadProcess => {
+                let at: Option<usize> = self
+                    .sessions
+                    .iter()
+                    .position(|s| fields.pid != 0 && s.pid == fields.pid && s.line == fields.line)
+                    .or_else(|| {
+                        self.sessions
+                            .iter()
+                            .position(|s| !fields.line.is_empty() && s.line == fields.line)
+                    })
+                    .or_else(|| {
+                        self.sessions
+                            .iter()
+                            .position(|s| !fields.id.is_empty() && s.id == fields.id)
+                    });
+                if let Some(at) = at {
+                    ended.push(self.sessions.remove(at).close(dt, UtmpSessionEnd::Logout));
+                }
+            }
+            _ => {}
+        }
+        defx!("ended {}", ended.len());
+
+        ended
+    }
+
+    /// Return the just begun `session` if it is one of the sessions passed
+    /// to [`set_still_open`].
+    ///
+    /// [`set_still_open`]: UtmpSessionTracker::set_still_open
+    fn still_open_begun(
+        &mut self,
+        session: Option<UtmpSession>,
+    ) -> Option<UtmpSession> {
+        let session: UtmpSession = session?;
+        let at: usize = self.still_open.iter().position(|s| s == &session)?;
+        self.still_open.remove(at);
+        self.still_open_returned.push(session.clone());
+
+        Some(session)
+    }
+
+    /// End all open sessions.
+    fn close_all(
+        &mut self,
+        dt: &DateTimeL,
+        end: UtmpSessionEnd,
+    ) -> Vec<UtmpSession> {
+        let mut ended: Vec<UtmpSession> = Vec::with_capacity(self.count_open());
+        if let Some(boot) = self.boot.take() {
+            ended.push(boot.close(dt, end));
+        }
+        for session in self.sessions.drain(..) {
+            ended.push(session.close(dt, end));
+        }
+
+        ended
+    }
+
+    /// Return all open sessions, in order of login. These are still logged
+    /// in. Sessions already returned at their login by [`process`] are not
+    /// returned again.
+    ///
+    /// [`process`]: UtmpSessionTracker::process
+    pub fn finish(&mut self) -> Vec<UtmpSession> {
+        let mut open: Vec<UtmpSession> = Vec::with_capacity(self.count_open());
+        open.extend(self.boot.take());
+        open.append(&mut self.sessions);
+        let mut returned: Vec<UtmpSession> = std::mem::take(&mut self.still_open_returned);
+        open.retain(|session| match returned.iter().position(|s| s == session) {
+            Some(at) => {
+                returned.remove(at);
+                false
+            }
+            None => true,
+        });
+        self.still_open.clear();
+
+        open
+    }
+}
+
+/// Return the sessions of the `utmp`, `utmpx`, and `wtmp` files `paths`
+/// that no entry ends, i.e. what [`UtmpSessionTracker::finish`] returns
+/// after all entries are processed.
+///
+/// The entries are read with the same datetime filters as the file
+/// processing threads, and are processed in the same order as the main
+/// processing loop prints them; by datetime, then by `PathId`.
+/// A file that cannot be read is skipped; the file processing thread will
+/// report the error.
+pub fn utmpsessions_still_open(
+    paths: &[(PathId, FPath, FileType)],
+    blocksz: BlockSz,
+    tz_offset: FixedOffset,
+    filter_dt_after_opt: &DateTimeLOpt,
+    filter_dt_before_opt: &DateTimeLOpt,
+) -> Vec<UtmpSession> {
+    defn!("({} paths)", paths.len());
+    let mut entries: Vec<(DateTimeL, PathId, UtmpFields)> = Vec::new();
+    let mut buffer: [u8; ENTRY_SZ_MAX] = [0; ENTRY_SZ_MAX];
+    for (path_id, path, filetype) in paths.iter() {
+        let mut fixedstructreader: FixedStructReader = match FixedStructReader::new(
+            *path_id,
+            path.clone(),
+            *filetype,
+            blocksz,
+            tz_offset,
+            *filter_dt_after_opt,
+            *filter_dt_before_opt,
+        ) {
+            ResultFixedStructReaderNew::FileOk(val) => val,
+            _ => {
+                defo!("skip {:?}", path);
+                continue;
+            }
+        };
+        let mut fo: FileOffset = match fixedstructreader.fileoffset_first() {
+            Some(fo) => fo,
+            None => continue,
+        };
+        loop {
+            match fixedstructreader.process_entry_at(fo, &mut buffer) {
+                ResultFindFixedStruct::Found((fo_next, fixedstruct)) => {
+                    if matches!(
+                        fixedstruct.filetypefixedstruct,
+                        FileTypeFixedStruct::Utmp | FileTypeFixedStruct::Utmpx
+                    )
+                        && let Some(fields) = fixedstruct.utmp_fields()
+                    {
+                        entries.push((*fixedstruct.dt(), *path_id, fields));
+                    }
+                    fo = fo_next;
+                }
+                ResultFindFixedStruct::Done => break,
+                ResultFindFixedStruct::Err((Some(fo_next), _err)) => {
+                    defo!("process_entry_at({}) error {:?}", fo, _err);
+                    fo = fo_next;
+                }
+                ResultFindFixedStruct::Err((None, _err)) => {
+                    defo!("process_entry_at({}) error {:?}", fo, _err);
+                    break;
+                }
+            }
+        }
+    }
+    // a stable sort keeps the order of entries within a file
+    entries.sort_by_key(|(dt, path_id, _fields)| (*dt, *path_id));
+    let mut tracker: UtmpSessionTracker = UtmpSessionTracker::new();
+    for (dt, _path_id, fields) in entries.iter() {
+        tracker.process_fields(fields, dt);
+    }
+    let still_open: Vec<UtmpSession> = tracker.finish();
+    defx!("return {} sessions", still_open.len());
+
+    still_open
+}
+
+/// Failed login attempts for one user from one host (or `ut_line`) in
+/// [`FailedLogins`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FailedLogin {
+    pub user: String,
+    /// the `ut_host`, or `ut_line` if there is no `ut_host`
+    pub from: String,
+    pub count: Count,
+    pub first: DateTimeL,
+    pub last: DateTimeL,
+}
+
+/// Summary of failed login attempts from `btmp` files.
+#[derive(Debug, Default)]
+pub struct FailedLogins {
+    map: BTreeMap<(String, String), FailedLogin>,
+}
+
+impl FailedLogins {
+    pub fn new() -> FailedLogins {
+        FailedLogins::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Process a `btmp` `FixedStruct`.
+    pub fn process(
+        &mut self,
+        fixedstruct: &FixedStruct,
+    ) {
+        if let Some(fields) = fixedstruct.utmp_fields() {
+            self.process_fields(&fields, fixedstruct.dt());
+        }
+    }
+
+    /// Process the `UtmpFields` of a `btmp` entry at datetime `dt`.
+    pub fn process_fields(
+        &mut self,
+        fields: &UtmpFields,
+        dt: &DateTimeL,
+    ) {
+        if !matches!(fields.kind, UtmpKind::LoginProcess | UtmpKind::UserProcess) {
+            return;
+        }
+        let from: &String = match fields.host.is_empty() {
+            true => &fields.line,
+            false => &fields.host,
+        };
+        self.map
+            .entry((fields.user.clone(), from.clone()))
+            .and_modify(|fl| {
+                fl.count += 1;
+                fl.first = std::cmp::min(fl.first, *dt);
+                fl.last = std::cmp::max(fl.last, *dt);
+            })
+            .or_insert_with(|| FailedLogin {
+                user: fields.user.clone(),
+                from: from.clone(),
+                count: 1,
+                first: *dt,
+                last: *dt,
+            });
+    }
+
+    /// The failed logins, most attempts first.
+    pub fn failed_logins(&self) -> Vec<&FailedLogin> {
+        let mut fls: Vec<&FailedLogin> = self.map.values().collect();
+        fls.sort_by_key(|fl| std::cmp::Reverse(fl.count));
+
+        fls
+    }
+}
+
+/// Print the failed logins as a table, most attempts first, e.g.
+///
+/// ```text
+/// Failed logins:
+///    count user             from                     first                      last
+///       12 root             203.0.113.5              2024-01-01 10:00:00 +00:00 2024-01-01 10:05:00 +00:00
+/// ```
+impl fmt::Display for FailedLogins {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Failed logins:")?;
+        writeln!(
+            f,
+            "{:>8} {:<16} {:<24} {:<26} last",
+            "count", "user", "from", "first",
+        )?;
+        for fl in self.failed_logins() {
+            writeln!(
+                f,
+                "{:>8} {:<16} {:<24} {} {}",
+                fl.count,
+                fl.user,
+                fl.from,
+                fl.first.format(UTMPSESSION_DT_FORMAT),
+                fl.last.format(UTMPSESSION_DT_FORMAT),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Is the file name of `path` a `btmp` file, e.g. `btmp`, `btmp.1`,
+/// `btmp-20240101.gz`, `btmpx`?
+pub fn fpath_is_btmp(path: &FPath) -> bool {
+    let path: &str = match path.rsplit_once(SUBPATH_SEP) {
+        Some((_, subpath)) => subpath,
+        None => path.as_str(),
+    };
+    match std::path::Path::new(path).file_name() {
+        Some(name) => name
+            .to_string_lossy()
+            .to_ascii_lowercase()
+            .starts_with("btmp"),
+        None => false,
+    }
+}
@@ -46,6 +46,7 @@ use crate::common::{
     FPath,
     FileTypeTextEncoding,
     NLu8,
+    NLu8a,
     SUBPATH_SEP,
     SUBPATH_SEP_DISPLAY_STR,
     summary_stat,
@@ -56,6 +57,10 @@ use crate::data::datetime::{
     FixedOffset,
 };
 use crate::data::pydataevent::PyDataEvent;
+use crate::data::audit::AuditEvent;
+use crate::data::strace::StraceCall;
+use crate::data::containerlog::ContainerLog;
+use crate::data::jsonline::JsonLine;
 use crate::data::evtx::Evtx;
 use crate::data::fixedstruct::{
     FixedStruct,
@@ -67,6 +72,7 @@ use crate::data::line::{
     LineP,
 };
 use crate::data::sysline::SyslineP;
+use crate::data::utmpsession::UtmpSession;
 use crate::debug::printers::de_err;
 use crate::readers::helpers::basename;
 
@@ -946,6 +952,144 @@ impl PrinterLogMessage {
         }
     }
 
+    /// Print a `UtmpSession` based on [`PrinterLogMessage`] settings.
+    ///
+    /// Users should call this function.
+    pub fn print_utmpsession(
+        &mut self,
+        session: &UtmpSession,
+    ) -> PrinterLogMessageResult {
+        let mut printed: usize = 0;
+        let mut flushed: usize = 0;
+        let prepend_date_s: String = match self.do_prepend_date {
+            true => session
+                .dt()
+                .with_timezone(&self.prepend_date_offset)
+                .format(self.prepend_date_format.as_str())
+                .to_string(),
+            false => String::with_capacity(0),
+        };
+        let data: String = format!("{}\n", session);
+        #[allow(unused_mut, unused_variables)]
+        let mut stdout_lock = self.stdout.lock();
+        let _si_lock = debug_print_guard();
+        if !self.do_color {
+            if let Some(prepend_file) = self.prepend_file.as_ref() {
+                buffer_write_or_return!(stdout_lock, self.buffer, prepend_file.as_bytes(), printed, flushed);
+            }
+            buffer_write_or_return!(stdout_lock, self.buffer, prepend_date_s.as_bytes(), printed, flushed);
+            buffer_write_or_return!(stdout_lock, self.buffer, data.as_bytes(), printed, flushed);
+            buffer_flush_or_return!(stdout_lock, self.buffer, printed, flushed);
+
+            return PrinterLogMessageResult::Ok((printed, flushed));
+        }
+        setcolor_or_return!(self.stdout_color, self.buffer, self.color_spec_default, self.color_spec_last, printed, flushed);
+        if let Some(prepend_file) = self.prepend_file.as_ref() {
+            buffer_write_or_return!(self.stdout_color, self.buffer, prepend_file.as_bytes(), printed, flushed);
+        }
+        buffer_write_or_return!(self.stdout_color, self.buffer, prepend_date_s.as_bytes(), printed, flushed);
+        setcolor_or_return!(self.stdout_color, self.buffer, self.color_spec_sysline, self.color_spec_last, printed, flushed);
+        buffer_write_or_return!(self.stdout_color, self.buffer, data.as_bytes(), printed, flushed);
+        buffer_flush_or_return!(self.stdout_color, self.buffer, printed, flushed);
+        setcolor_or_return!(self.stdout_color, self.buffer, self.color_spec_default, self.color_spec_last, printed, flushed);
+        black_box(&stdout_lock);
+
+        PrinterLogMessageResult::Ok((printed, flushed))
+    }
+
+    /// Print an `AuditEvent` based on [`PrinterLogMessage`] settings.
+    /// Each record is one line, prepended like the lines of a `Sysline`.
+    ///
+    /// Users should call this function.
+    pub fn print_auditevent(
+        &mut self,
+        event: &AuditEvent,
+    ) -> PrinterLogMessageResult {
+        self.print_dt_lines(event.dt(), &event.records)
+    }
+
+    /// Print a `StraceCall` based on [`PrinterLogMessage`] settings.
+    /// Each line is prepended like the lines of a `Sysline`.
+    ///
+    /// Users should call this function.
+    pub fn print_stracecall(
+        &mut self,
+        call: &StraceCall,
+    ) -> PrinterLogMessageResult {
+        self.print_dt_lines(call.dt(), &call.lines)
+    }
+
+    /// Print a `JsonLine` based on [`PrinterLogMessage`] settings.
+    /// Each line is prepended like the lines of a `Sysline`.
+    ///
+    /// Users should call this function.
+    pub fn print_jsonline(
+        &mut self,
+        jsonline: &JsonLine,
+    ) -> PrinterLogMessageResult {
+        self.print_dt_lines(jsonline.dt(), &jsonline.lines)
+    }
+
+    /// Print a `ContainerLog` based on [`PrinterLogMessage`] settings.
+    /// Each line is prepended like the lines of a `Sysline`.
+    ///
+    /// Users should call this function.
+    pub fn print_containerlog(
+        &mut self,
+        containerlog: &ContainerLog,
+    ) -> PrinterLogMessageResult {
+        self.print_dt_lines(containerlog.dt(), &containerlog.lines)
+    }
+
+    /// Print `lines` of datetime `dt`, each line prepended like the lines of
+    /// a `Sysline`.
+    fn print_dt_lines(
+        &mut self,
+        dt: &DateTimeL,
+        lines: &[String],
+    ) -> PrinterLogMessageResult {
+        let mut printed: usize = 0;
+        let mut flushed: usize = 0;
+        let prepend_date_s: String = match self.do_prepend_date {
+            true => dt
+                .with_timezone(&self.prepend_date_offset)
+                .format(self.prepend_date_format.as_str())
+                .to_string(),
+            false => String::with_capacity(0),
+        };
+        #[allow(unused_mut, unused_variables)]
+        let mut stdout_lock = self.stdout.lock();
+        let _si_lock = debug_print_guard();
+        if !self.do_color {
+            for line in lines.iter() {
+                if let Some(prepend_file) = self.prepend_file.as_ref() {
+                    buffer_write_or_return!(stdout_lock, self.buffer, prepend_file.as_bytes(), printed, flushed);
+                }
+                buffer_write_or_return!(stdout_lock, self.buffer, prepend_date_s.as_bytes(), printed, flushed);
+                buffer_write_or_return!(stdout_lock, self.buffer, line.as_bytes(), printed, flushed);
+                buffer_write_or_return!(stdout_lock, self.buffer, &NLu8a, printed, flushed);
+            }
+            buffer_flush_or_return!(stdout_lock, self.buffer, printed, flushed);
+
+            return PrinterLogMessageResult::Ok((printed, flushed));
+        }
+        for line in lines.iter() {
+            setcolor_or_return!(self.stdout_color, self.buffer, self.color_spec_default, self.color_spec_last, printed, flushed);
+            if let Some(prepend_file) = self.prepend_file.as_ref() {
+                buffer_write_or_return!(self.stdout_color, self.buffer, prepend_file.as_bytes(), printed, flushed);
+            }
+            buffer_write_or_return!(self.stdout_color, self.buffer, prepend_date_s.as_bytes(), printed, flushed);
+            setcolor_or_return!(self.stdout_color, self.buffer, self.color_spec_sysline, self.color_spec_last, printed, flushed);
+            buffer_write_or_return!(self.stdout_color, self.buffer, line.as_bytes(), printed, flushed);
+            buffer_write_or_return!(self.stdout_color, self.buffer, &NLu8a, printed, flushed);
+        }
+        buffer_flush_or_return!(self.stdout_color, self.buffer, printed, flushed);
+        setcolor_or_return!(self.stdout_color, self.buffer, self.color_spec_default, self.color_spec_last, printed, flushed);
+        black_box(&stdout_lock);
+
+        PrinterLogMessageResult::Ok((printed, flushed))
+    }
+
     /// Helper function to transform [`sysline.dt`] to a `String`.
     ///
     /// [`sysline.dt`]: crate::data::sysline::Sysline#method.dt
@@ -183,6 +183,12 @@ pub struct SummaryPrinted {
     pub journalentries: Count,
     /// count of `PyDataEvent` printed for .odl files
     pub odlentries: Count,
+    /// count of `PyDataEvent` printed for logcat binary files
+    pub logcatentries: Count,
+    /// count of `PyDataEvent` printed for pcap and pcapng files
+    pub pcapentries: Count,
+    /// count of `PyDataEvent` printed for .tracev3 files
+    pub tracev3entries: Count,
     /// last datetime printed
     pub dt_first: DateTimeLOpt,
     pub dt_last: DateTimeLOpt,
@@ -246,6 +252,9 @@ impl SummaryPrinted {
             evtxentries: 0,
             journalentries: 0,
             odlentries: 0,
+            logcatentries: 0,
+            pcapentries: 0,
+            tracev3entries: 0,
             dt_first: None,
             dt_last: None,
         }
@@ -587,6 +596,15 @@ impl SummaryPrinted {
             PyEventType::Odl { .. } => {
                 self.odlentries += 1;
             }
+            PyEventType::Logcat => {
+                self.logcatentries += 1;
+            }
+            PyEventType::Pcap => {
+                self.pcapentries += 1;
+            }
+            PyEventType::Tracev3 => {
+                self.tracev3entries += 1;
+            }
         }
         self.bytes += printed;
         self.flushed += flushed;
@@ -942,6 +960,9 @@ pub fn print_summary(
     // TODO: [2024/02/25] eprint count of FixedStruct files "out of order".
     eprintln!("Printed Journal events : {}", summaryprinted.journalentries);
     eprintln!("Printed ODL events     : {}", summaryprinted.odlentries);
+    eprintln!("Printed logcat events  : {}", summaryprinted.logcatentries);
+    eprintln!("Printed pcap events    : {}", summaryprinted.pcapentries);
+    eprintln!("Printed tracev3 events : {}", summaryprinted.tracev3entries);
     eprintln!("Line Maximum                   : {}", LINE_SEARCH_MAX);
     eprintln!("Line longest                   : {}", longest_line);
     eprintln!("Sysline longest                : {}", longest_sysline);
@@ -1158,8 +1179,12 @@ fn print_file_about(
         FileType::Asl { .. }
         | FileType::FixedStruct { .. }
         | FileType::Etl { .. }
+        | FileType::Evt { .. }
         | FileType::Evtx { .. }
         | FileType::Journal { .. }
+        | FileType::Logcat { .. }
+        | FileType::Pcap { .. }
+        | FileType::Tracev3 { .. }
         | FileType::Unparsable
         => {
             eprint!("{}", filetype);
@@ -1169,11 +1194,15 @@ fn print_file_about(
     match filetype {
         FileType::Asl { archival_type: at }
         | FileType::Etl { archival_type: at }
+        | FileType::Evt { archival_type: at }
         | FileType::Evtx { archival_type: at }
         | FileType::FixedStruct { archival_type: at, .. }
         | FileType::Journal { archival_type: at }
         | FileType::Odl { archival_type: at , .. }
         | FileType::Text { archival_type: at, .. }
+        | FileType::Logcat { archival_type: at }
+        | FileType::Pcap { archival_type: at }
+        | FileType::Tracev3 { archival_type: at }
         => {
             match at {
                 FileTypeArchive::Normal => {}
@@ -1340,6 +1369,9 @@ fn print_summary_opt_processed(
             eprintln!("{}Entry size    : {} (bytes)",
                 indent2, summaryfixedstructreader.fixedstructreader_fixedstruct_size
             );
+            eprintln!("{}Entry endian  : {:?}",
+                indent2, summaryfixedstructreader.fixedstructreader_endian
+            );
             eprintln!("{}Entry hi-score: {}",
                 indent2, summaryfixedstructreader.fixedstructreader_high_score
             );
@@ -1363,34 +1395,37 @@ fn print_summary_opt_processed(
             );
             eprintln!("{}Events processed   : {}", indent2, summarypyeventreader.pyeventreader_events_processed);
             eprintln!("{}Events accepted    : {}", indent2, summarypyeventreader.pyeventreader_events_accepted);
-            eprintln!("{}Events read max    : {}", indent2, summarypyeventreader.pyeventreader_events_read_max);
-            eprintln!("{}Events queue high  : {}", indent2, summarypyeventreader.pyeventreader_events_held_max);
-            eprintln!("{}Python process polls       : {}", indent2, summarypyeventreader.pyeventreader_python_count_proc_polls);
-            eprintln!("{}Python process reads stdout: {}", indent2, summarypyeventreader.pyeventreader_python_count_proc_reads_stdout);
-            eprintln!("{}Python process reads stderr: {}", indent2, summarypyeventreader.pyeventreader_python_count_proc_reads_stderr);
-            eprintln!("{}Python process writes stdin: {}", indent2, summarypyeventreader.pyeventreader_python_count_proc_writes);
-            eprintln!("{}Python pipe recv stdout    : {}", indent2, summarypyeventreader.pyeventreader_python_count_pipe_recv_stdout);
-            eprintln!("{}Python pipe recv stderr    : {}", indent2, summarypyeventreader.pyeventreader_python_count_pipe_recv_stderr);
-            eprintln!("{}Python pipe size stdout    : {}", indent2, summarypyeventreader.pyeventreader_pipe_sz_stdout);
-            eprintln!("{}Python pipe size stderr    : {}", indent2, summarypyeventreader.pyeventreader_pipe_sz_stderr);
-            eprintln!("{}Python pipe chan max stdout: {}", indent2, summarypyeventreader.pyeventreader_pipe_channel_max_stdout);
-            eprintln!("{}Python pipe chan max stderr: {}", indent2, summarypyeventreader.pyeventreader_pipe_channel_max_stderr);
-            // drop sub-milliseconds data, milliseconds is precise enough
-            let dur_ms_wait: Duration = Duration::from_millis(summarypyeventreader.pyeventreader_duration_proc_wait.as_millis() as u64);
-            eprintln!("{}Python process waits       : {:?}", indent2, dur_ms_wait);
-            let dur_ms_run: Duration = Duration::from_millis(summarypyeventreader.pyeventreader_duration_proc_run.as_millis() as u64);
-            eprintln!("{}Python process runtime     : {:?}", indent2, dur_ms_run);
-            eprint!("{}Python exit status         : ", indent2);
-            let exit_status: &ExitStatus = &summarypyeventreader.pyeventreader_python_exit_status.unwrap_or_default();
-            // remove "exit status: " from the ExitStatus display string
-            let es_s: String = format!("{}", exit_status).replace("exit status: ", "");
-            eprintln_display_color_error(
-                &es_s,
-                |_| { !exit_status.success() },
-                color_choice,
-            );
-            let args: String = summarypyeventreader.pyeventreader_python_arguments.join(" ");
-            eprintln!("{}Python script arguments    : {}", indent2, args);
+            // the native `AslReader` does not run a Python process
+            if !summarypyeventreader.pyeventreader_python_arguments.is_empty() {
+                eprintln!("{}Events read max    : {}", indent2, summarypyeventreader.pyeventreader_events_read_max);
+                eprintln!("{}Events queue high  : {}", indent2, summarypyeventreader.pyeventreader_events_held_max);
+                eprintln!("{}Python process polls       : {}", indent2, summarypyeventreader.pyeventreader_python_count_proc_polls);
+                eprintln!("{}Python process reads stdout: {}", indent2, summarypyeventreader.pyeventreader_python_count_proc_reads_stdout);
+                eprintln!("{}Python process reads stderr: {}", indent2, summarypyeventreader.pyeventreader_python_count_proc_reads_stderr);
+                eprintln!("{}Python process writes stdin: {}", indent2, summarypyeventreader.pyeventreader_python_count_proc_writes);
+                eprintln!("{}Python pipe recv stdout    : {}", indent2, summarypyeventreader.pyeventreader_python_count_pipe_recv_stdout);
+                eprintln!("{}Python pipe recv stderr    : {}", indent2, summarypyeventreader.pyeventreader_python_count_pipe_recv_stderr);
+                eprintln!("{}Python pipe size stdout    : {}", indent2, summarypyeventreader.pyeventreader_pipe_sz_stdout);
+                eprintln!("{}Python pipe size stderr    : {}", indent2, summarypyeventreader.pyeventreader_pipe_sz_stderr);
+                eprintln!("{}Python pipe chan max stdout: {}", indent2, summarypyeventreader.pyeventreader_pipe_channel_max_stdout);
+                eprintln!("{}Python pipe chan max stderr: {}", indent2, summarypyeventreader.pyeventreader_pipe_channel_max_stderr);
+                // drop sub-milliseconds data, milliseconds is precise enough
+                let dur_ms_wait: Duration = Duration::from_millis(summarypyeventreader.pyeventreader_duration_proc_wait.as_millis() as u64);
+                eprintln!("{}Python process waits       : {:?}", indent2, dur_ms_wait);
+                let dur_ms_run: Duration = Duration::from_millis(summarypyeventreader.pyeventreader_duration_proc_run.as_millis() as u64);
+                eprintln!("{}Python process runtime     : {:?}", indent2, dur_ms_run);
+                eprint!("{}Python exit status         : ", indent2);
+                let exit_status: &ExitStatus = &summarypyeventreader.pyeventreader_python_exit_status.unwrap_or_default();
+                // remove "exit status: " from the ExitStatus display string
+                let es_s: String = format!("{}", exit_status).replace("exit status: ", "");
+                eprintln_display_color_error(
+                    &es_s,
+                    |_| { !exit_status.success() },
+                    color_choice,
+                );
+                let args: String = summarypyeventreader.pyeventreader_python_arguments.join(" ");
+                eprintln!("{}Python script arguments    : {}", indent2, args);
+            }
         }
         SummaryReaderData::Etvx(summaryevtxreader) => {
             eprintln!(
@@ -1401,6 +1436,7 @@ fn print_summary_opt_processed(
             //       tracking in `EvtxReader` (small `EvtxReader` refactor)
             //       and `SummaryEvtxReader`.
             eprintln!("{}Events processed   : {}", indent2, summaryevtxreader.evtxreader_events_processed);
+            eprintln!("{}Events filtered    : {}", indent2, summaryevtxreader.evtxreader_events_filtered);
             eprintln!("{}Events accepted    : {}", indent2, summaryevtxreader.evtxreader_events_accepted);
             if let Some(dt) = summaryevtxreader.evtxreader_datetime_first_processed {
                 eprint!("{}Datetime first     : ", indent2);
@@ -1418,6 +1454,24 @@ fn print_summary_opt_processed(
                 |n| *n != 0,
                 color_choice,
             );
+            // print records carved in recovery mode
+            eprintln!(
+                "{}records recovered  : {}",
+                indent2, summaryevtxreader.evtxreader_records_recovered,
+            );
+            // print damaged chunks and dropped records. If there are any, print in red.
+            eprint!("{}records dropped    : ", indent2);
+            eprintln_display_color_error(
+                &summaryevtxreader.evtxreader_records_dropped,
+                |n| *n != 0,
+                color_choice,
+            );
+            eprint!("{}chunks damaged     : ", indent2);
+            eprintln_display_color_error(
+                &summaryevtxreader.evtxreader_chunks_damaged,
+                |n| *n != 0,
+                color_choice,
+            );
             // for evtx files, nothing left to print about it so return
             return;
         }
@@ -1458,6 +1512,24 @@ fn print_summary_opt_processed(
                 |n| *n != 0,
                 color_choice,
             );
+            // print entries read without `libsystemd` from a damaged file
+            eprintln!(
+                "{}recovered     : {}",
+                indent2, summaryjournalreader.journalreader_entries_recovered,
+            );
+            // print damaged entries and objects. If there are any, print in red.
+            eprint!("{}damaged entry : ", indent2);
+            eprintln_display_color_error(
+                &summaryjournalreader.journalreader_entries_damaged,
+                |n| *n != 0,
+                color_choice,
+            );
+            eprint!("{}damaged object: ", indent2);
+            eprintln_display_color_error(
+                &summaryjournalreader.journalreader_objects_damaged,
+                |n| *n != 0,
+                color_choice,
+            );
             return;
         }
     }
@@ -1583,9 +1655,13 @@ fn print_summary_opt_processed_summaryblockreader(
         }
         FileType::Asl{..}
         | FileType::Etl{..}
+        | FileType::Evt{..}
         | FileType::Evtx{..}
         | FileType::Journal{..}
         | FileType::Odl { .. }
+        | FileType::Logcat { .. }
+        | FileType::Pcap { .. }
+        | FileType::Tracev3 { .. }
         | FileType::Unparsable
         => {
             debug_panic!("unexpected filetype {:?}", summary.filetype);
@@ -1979,9 +2055,13 @@ fn print_drop_stats(summary_opt: &SummaryOpt) {
         Some(filetype_) => match filetype_ {
             FileType::Asl { .. }
             | FileType::Etl { .. }
+            | FileType::Evt { .. }
             | FileType::Evtx { .. }
             | FileType::Journal { .. }
-            | FileType::Odl { .. } => {
+            | FileType::Odl { .. }
+            | FileType::Logcat { .. }
+            | FileType::Pcap { .. }
+            | FileType::Tracev3 { .. } => {
                 return;
             }
             FileType::FixedStruct { .. }
@@ -0,0 +1,797 @@
+// src/readers/aslreader.rs
+
+//! Implements an [`AslReader`], the driver of deriving [`PyDataEvent`s] from
+//! an [Apple System Log] database file (`.asl`).
+//!
+//! The file format is read natively. The printed events are the same as
+//! those printed by the Python script `ccl_asldb.py`.
+//!
+//! [`PyDataEvent`s]: crate::data::pydataevent::PyDataEvent
+//! [Apple System Log]: https://github.com/apple-oss-distributions/syslog/blob/syslog-377.0.1/libsystem_asl.tproj/src/asl_file.c
+
+use std::fmt;
+use std::io::{
+    Error,
+    ErrorKind,
+    Read,
+    Result,
+    Seek,
+    SeekFrom,
+};
+use std::path::Path;
+
+use ::chrono::{
+    DateTime,
+    TimeZone,
+};
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    def1n,
+    def1o,
+    def1x,
+    def1ñ,
+    def2ñ,
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+use ::tempfile::TempPath;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileMetadata,
+    FileOffset,
+    FileSz,
+    FileType,
+    PathId,
+    ResultFind4,
+    summary_stat,
+    summary_stats_enabled,
+};
+use crate::data::datetime::{
+    DateTimeL,
+    DateTimeLOpt,
+    FixedOffset,
+    Result_Filter_DateTime2,
+    SystemTime,
+    dt_pass_filters,
+};
+use crate::data::pydataevent::{
+    DtBegEndPairOpt,
+    EventBytes,
+    PyDataEvent,
+};
+use crate::de_err;
+use crate::readers::filedecompressor::decompress_to_ntf;
+use crate::readers::filehandlemanager::{
+    FILE_HANDLE_MANAGER,
+    FileHandleManaged,
+    FileHandleRole,
+    OpenOptionsManaged,
+};
+use crate::readers::helpers::path_to_fpath;
+use crate::readers::pyeventreader::{
+    PyDataEventReader,
+    ResultNextPyDataEvent,
+    SummaryPyEventReader,
+};
+use crate::readers::summary::Summary;
+
+/// Magic bytes at the start of an ASL database file.
+pub const ASL_MAGIC: &[u8; 12] = b"ASL DB\0\0\0\0\0\0";
+
+/// Bytes of the ASL database file header that are read.
+/// The header is followed by padding.
+const ASL_HEADER_SZ: usize = 45;
+/// Offset of the first record offset within the file header.
+const ASL_HEADER_FIRST_OFFSET: usize = 16;
+/// Offset of the last record offset within the file header.
+const ASL_HEADER_LAST_OFFSET: usize = 37;
+
+/// Bytes of the fixed portion of a record.
+const ASL_RECORD_SZ: usize = 114;
+/// Offset of the "next record" offset within a record.
+const ASL_RECORD_NEXT_OFFSET: u64 = 6;
+/// Offset of the timestamp seconds within a record.
+const ASL_RECORD_TIME_OFFSET: u64 = 22;
+
+/// Tag at the start of a string stored in the file.
+const ASL_STRING_TAG: &[u8; 2] = b"\x00\x01";
+/// Top bit of a string reference marks an inline string.
+const ASL_STRING_INLINE: u64 = 0x8000_0000_0000_0000;
+
+/// Separator of fields in a printed record.
+/// Must match `record_field_sep` in `ccl_asldb.py`.
+const RECORD_FIELD_SEP: &str = "  ";
+
+/// Must match `MESSAGE_LEVELS` in `ccl_asldb.py`.
+const MESSAGE_LEVELS: [&str; 8] = [
+    "Emergency",
+    "Alert",
+    "Critical",
+    "Error",
+    "Warning",
+    "Notice",
+    "Info",
+    "Debug",
+];
+
+#[inline(always)]
+fn u16_be(
+    buffer: &[u8],
+    at: usize,
+) -> u16 {
+    u16::from_be_bytes([buffer[at], buffer[at + 1]])
+}
+
+#[inline(always)]
+fn u32_be(
+    buffer: &[u8],
+    at: usize,
+) -> u32 {
+    let mut b: [u8; 4] = [0; 4];
+    b.copy_from_slice(&buffer[at..at + 4]);
+
+    u32::from_be_bytes(b)
+}
+
+#[inline(always)]
+fn u64_be(
+    buffer: &[u8],
+    at: usize,
+) -> u64 {
+    let mut b: [u8; 8] = [0; 8];
+    b.copy_from_slice(&buffer[at..at + 8]);
+
+    u64::from_be_bytes(b)
+}
+
+/// Convert the record timestamp to microseconds since the Unix epoch.
+///
+/// Follows the Python conversion
+/// `timedelta(seconds=sec + (nano / 1000000000))` so the fractional
+/// seconds are rounded the same way.
+pub fn asl_time_to_micros(
+    sec: u64,
+    nano: u32,
+) -> Option<i64> {
+    let secs: f64 = sec as f64 + (nano as f64 / 1_000_000_000.0);
+    if !secs.is_finite() || secs >= i64::MAX as f64 / 1_000_000.0 {
+        return None;
+    }
+    let whole: f64 = secs.trunc();
+    let micros: f64 = ((secs - whole) * 1_000_000.0).round_ties_even();
+
+    Some(whole as i64 * 1_000_000 + micros as i64)
+}
+
+/// One record of an ASL database file.
+#[derive(Debug, Default)]
+struct AslRecord {
+    id: u64,
+    /// Microseconds since the Unix epoch.
+    micros: i64,
+    level: u16,
+    pid: u32,
+    gid: u32,
+    ruid: u32,
+    rgid: u32,
+    host: String,
+    sender: String,
+    facility: String,
+    message: String,
+    refproc: String,
+    session: String,
+    /// Keys are unique, the last value for a key is kept.
+    key_values: Vec<(String, String)>,
+}
+
+impl AslRecord {
+    /// Return the printable record and the end index of the datetime
+    /// substring.
+    ///
+    /// Must match `AslRecord.__repr__` in `ccl_asldb.py`.
+    fn to_event_string(&self) -> Option<(String, usize)> {
+        let dt = DateTime::from_timestamp_micros(self.micros)?.naive_utc();
+        let mut s: String = dt.format("%Y-%m-%dT%H:%M:%S").to_string();
+        let micros: i64 = self.micros.rem_euclid(1_000_000);
+        if micros != 0 {
+            s.push_str(&format!(".{:06}", micros));
+        }
+        let dt_end: usize = s.len();
+        let level: &str = MESSAGE_LEVELS.get(self.level as usize).unwrap_or(&"Other");
+        let mut fields: Vec<String> = vec![
+            s,
+            format!("id={}", self.id),
+            format!("level={}", level),
+            format!("pid={}", self.pid),
+            format!("gid={}", self.gid),
+            format!("read_uid={}", self.ruid),
+            format!("read_gid={}", self.rgid),
+        ];
+        if !self.host.is_empty() {
+            fields.push(format!("host={}", self.host));
+        }
+        if !self.refproc.is_empty() {
+            fields.push(format!("RefProc={}", self.refproc));
+        }
+        if !self.session.is_empty() {
+            fields.push(format!("session={}", self.session));
+        }
+        fields.push(format!("sender={}", self.sender));
+        fields.push(format!("facility={}", self.facility));
+        fields.push(format!("message='{}'", self.message));
+        for (key, value) in self.key_values.iter() {
+            fields.push(format!("{}={}", key, value));
+        }
+        let mut event: String = fields.join(RECORD_FIELD_SEP);
+        // remove a trailing separator left by an empty final value
+        if event.ends_with(RECORD_FIELD_SEP) {
+            event.truncate(event.len() - RECORD_FIELD_SEP.len());
+        }
+        // same as `s4_event_bytes` in the Python `s4_event_readers` package
+        let mut event: String = event.replace('\0', " ");
+        event.push('\n');
+
+        Some((event, dt_end))
+    }
+}
+
+/// A reader of [Apple System Log] database files.
+///
+/// The file header has the offsets of the first and last records. Each
+/// record has the offset of the next record. Strings of up to seven bytes
+/// are stored inline within the string reference. Longer strings are stored
+/// elsewhere in the file.
+///
+/// [Apple System Log]: https://github.com/apple-oss-distributions/syslog/blob/syslog-377.0.1/libsystem_asl.tproj/src/asl_file.c
+pub struct AslReader {
+    /// The file being read.
+    file: FileHandleManaged,
+    /// The `FPath` of the file being read.
+    path: FPath,
+    /// Unique identifier for the file processing instance.
+    path_id: PathId,
+    /// The type of file being read.
+    file_type: FileType,
+    /// If necessary, the extracted file as a temporary file.
+    named_temp_file: Option<TempPath>,
+    /// Conversion of record timestamps to `DateTimeL` with this timezone offset.
+    fixed_offset: FixedOffset,
+    /// Offset of the first record.
+    fo_first: FileOffset,
+    /// Offset of the last record.
+    fo_last: FileOffset,
+    /// Offset of the next record to read. `None` when all records are read.
+    fo_next: Option<FileOffset>,
+    /// Has the first call to `next` been done?
+    started: bool,
+    /// Summary statistic.
+    /// `Count` of records processed.
+    events_processed: Count,
+    /// Summary statistic.
+    /// `Count` of records accepted by the datetime filters.
+    events_accepted: Count,
+    /// Summary statistic.
+    /// First (soonest) accepted (printed) `DateTimeL`.
+    dt_first_accepted: DateTimeLOpt,
+    /// Summary statistic.
+    /// Last (latest) accepted (printed) `DateTimeL`.
+    dt_last_accepted: DateTimeLOpt,
+    /// Summary statistic.
+    /// First (soonest) processed `DateTimeL`.
+    dt_first_processed: DateTimeLOpt,
+    /// Summary statistic.
+    /// Last (latest) processed `DateTimeL`.
+    dt_last_processed: DateTimeLOpt,
+    /// File Size of the file being read in bytes.
+    filesz: FileSz,
+    /// file Last Modified time from file-system metadata
+    mtime: SystemTime,
+    /// Out of chronological order.
+    out_of_order: Count,
+    /// The last [`Error`], if any, as a `String`
+    error: Option<String>,
+}
+
+impl fmt::Debug for AslReader {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        f.debug_struct("AslReader")
+            .field("Path", &self.path)
+            .field("fo_first", &self.fo_first)
+            .field("fo_last", &self.fo_last)
+            .field("Error?", &self.error)
+            .finish()
+    }
+}
+
+/// Implement the AslReader.
+impl AslReader {
+    /// Create a new `AslReader`.
+    ///
+    /// The file header is read and checked.
+    pub fn new(
+        path_id: PathId,
+        path: FPath,
+        file_type: FileType,
+        fixed_offset: FixedOffset,
+    ) -> Result<AslReader> {
+        def1n!("({}, {:?}, {:?}, {:?})", path_id, path, file_type, fixed_offset);
+        debug_assert!(file_type.is_asl(), "AslReader only supports FileType::Asl, given {:?}", file_type);
+
+        let path_std: &Path = Path::new(&path);
+        let named_temp_file: Option<TempPath>;
+        let mtime_opt: Option<SystemTime>;
+        (named_temp_file, mtime_opt) = match decompress_to_ntf(path_id, path_std, &file_type) {
+            Ok(ntf_mtime) => match ntf_mtime {
+                Some((ntf, mtime_opt, _filesz)) => (Some(ntf), mtime_opt),
+                None => (None, None),
+            },
+            Err(err) => {
+                def1x!("decompress_to_ntf({:?}, {:?}) Error, return {:?}", path, file_type, err);
+                return Err(err);
+            }
+        };
+        let path_actual: &Path = match named_temp_file {
+            Some(ref ntf) => ntf.as_ref(),
+            None => path_std,
+        };
+        def1o!("path_actual {:?}", path_actual);
+        let file: FileHandleManaged = FILE_HANDLE_MANAGER.request_open_managed(
+            path_id,
+            FileHandleRole::PrimaryRead,
+            path_actual,
+            OpenOptionsManaged::read_only(),
+        )?;
+        let metadata: FileMetadata = file.metadata()?;
+        let mtime: SystemTime = match mtime_opt {
+            Some(val) => val,
+            None => match metadata.modified() {
+                Ok(val) => val,
+                Err(_err) => {
+                    de_err!("metadata.modified() failed {}", _err);
+                    SystemTime::UNIX_EPOCH
+                }
+            },
+        };
+        let filesz: FileSz = metadata.len() as FileSz;
+        def1o!("filesz {}", filesz);
+
+        let mut header: [u8; ASL_HEADER_SZ] = [0; ASL_HEADER_SZ];
+        if filesz < ASL_HEADER_SZ as FileSz {
+            def1x!("file too small {}, return Err", filesz);
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("file size {} is too small for an ASL database header, file {:?}", filesz, path),
+            ));
+        }
+        (&file).seek(SeekFrom::Start(0))?;
+        (&file).read_exact(&mut header)?;
+        if &header[..ASL_MAGIC.len()] != ASL_MAGIC {
+            def1x!("bad magic {:?}, return Err", &header[..ASL_MAGIC.len()]);
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid ASL database header, file {:?}", path),
+            ));
+        }
+        let fo_first: FileOffset = u64_be(&header, ASL_HEADER_FIRST_OFFSET) as FileOffset;
+        let fo_last: FileOffset = u64_be(&header, ASL_HEADER_LAST_OFFSET) as FileOffset;
+        def1o!("fo_first {}, fo_last {}", fo_first, fo_last);
+        // an empty database has no records
+        let fo_next: Option<FileOffset> = match fo_first {
+            0 => None,
+            fo => Some(fo),
+        };
+
+        def1x!("return Ok(AslReader)");
+
+        Ok(AslReader {
+            file,
+            path,
+            path_id,
+            file_type,
+            named_temp_file,
+            fixed_offset,
+            fo_first,
+            fo_last,
+            fo_next,
+            started: false,
+            events_processed: 0,
+            events_accepted: 0,
+            dt_first_accepted: DateTimeLOpt::None,
+            dt_last_accepted: DateTimeLOpt::None,
+            dt_first_processed: DateTimeLOpt::None,
+            dt_last_processed: DateTimeLOpt::None,
+            filesz,
+            mtime,
+            out_of_order: 0,
+            error: None,
+        })
+    }
+
+    pub const fn mtime(&self) -> SystemTime {
+        self.mtime
+    }
+
+    pub const fn path_id(&self) -> PathId {
+        self.path_id
+    }
+
+    #[inline(always)]
+    pub const fn path(&self) -> &FPath {
+        &self.path
+    }
+
+    #[inline(always)]
+    pub const fn filetype(&self) -> FileType {
+        self.file_type
+    }
+
+    /// File size in bytes
+    #[inline(always)]
+    pub const fn filesz(&self) -> FileSz {
+        self.filesz
+    }
+
+    /// Offset of the first record, from the file header.
+    pub const fn fileoffset_first(&self) -> FileOffset {
+        self.fo_first
+    }
+
+    /// Offset of the last record, from the file header.
+    pub const fn fileoffset_last(&self) -> FileOffset {
+        self.fo_last
+    }
+
+    /// Read `buffer.len()` bytes at `fileoffset`.
+    fn read_at(
+        &self,
+        fileoffset: FileOffset,
+        buffer: &mut [u8],
+    ) -> Result<()> {
+        if fileoffset as FileSz + buffer.len() as FileSz > self.filesz {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "read of {} bytes at offset {} is beyond file size {}",
+                    buffer.len(), fileoffset, self.filesz,
+                ),
+            ));
+        }
+        let mut file: &FileHandleManaged = &self.file;
+        file.seek(SeekFrom::Start(fileoffset))?;
+
+        file.read_exact(buffer)
+    }
+
+    /// Return an `InvalidData` error if `len` bytes of `what` at
+    /// `fileoffset` would reach beyond the file.
+    ///
+    /// Lengths read from the file are checked before their buffers are
+    /// allocated.
+    fn check_len(
+        &self,
+        fileoffset: FileOffset,
+        len: usize,
+        what: &str,
+    ) -> Result<()> {
+        match (fileoffset as FileSz).checked_add(len as FileSz) {
+            Some(end) if end <= self.filesz => Ok(()),
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "{} of {} bytes at offset {} is beyond file size {}",
+                    what, len, fileoffset, self.filesz,
+                ),
+            )),
+        }
+    }
+
+    /// Return the `DateTimeL` of the record at `fileoffset`.
+    fn record_datetime(
+        &self,
+        fileoffset: FileOffset,
+    ) -> Result<DateTimeLOpt> {
+        let mut buffer: [u8; 12] = [0; 12];
+        self.read_at(fileoffset + ASL_RECORD_TIME_OFFSET as FileOffset, &mut buffer)?;
+        let micros: Option<i64> = asl_time_to_micros(u64_be(&buffer, 0), u32_be(&buffer, 8));
+
+        Ok(micros.and_then(|us| self.micros_to_datetime(us)))
+    }
+
+    /// Convert microseconds since the Unix epoch to a `DateTimeL` with
+    /// millisecond precision, the same precision as the Python script.
+    fn micros_to_datetime(
+        &self,
+        micros: i64,
+    ) -> DateTimeLOpt {
+        let dt = DateTime::from_timestamp_millis(micros.div_euclid(1_000))?;
+
+        Some(self.fixed_offset.from_utc_datetime(&dt.naive_utc()))
+    }
+
+    /// Return the string for the string reference `val`.
+    ///
+    /// Must match `AslDb._parse_asl_str` in `ccl_asldb.py`.
+    fn string(
+        &self,
+        val: u64,
+    ) -> Result<String> {
+        if val == 0 {
+            return Ok(String::new());
+        }
+        if val & ASL_STRING_INLINE != 0 {
+            let bytes: [u8; 8] = val.to_be_bytes();
+            let len: usize = ((bytes[0] & 0x7F) as usize).min(7);
+
+            return Ok(String::from_utf8_lossy(&bytes[1..1 + len]).into_owned());
+        }
+        let fileoffset: FileOffset = val as FileOffset;
+        let mut tag_len: [u8; 6] = [0; 6];
+        self.read_at(fileoffset, &mut tag_len)?;
+        if &tag_len[..2] != ASL_STRING_TAG {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("string at offset {} does not begin with {:?}", fileoffset, ASL_STRING_TAG),
+            ));
+        }
+        // the stored length includes the terminating nul
+        let len: usize = (u32_be(&tag_len, 2) as usize).saturating_sub(1);
+        self.check_len(fileoffset + 6, len, "string")?;
+        let mut buffer: Vec<u8> = vec![0; len];
+        self.read_at(fileoffset + 6, &mut buffer)?;
+
+        Ok(String::from_utf8_lossy(&buffer).into_owned())
+    }
+
+    /// Read the record at `fileoffset`.
+    /// Return the record and the offset of the next record.
+    ///
+    /// Must match `AslDb._parse_record` in `ccl_asldb.py`.
+    fn record(
+        &self,
+        fileoffset: FileOffset,
+    ) -> Result<(AslRecord, FileOffset)> {
+        def1n!("({})", fileoffset);
+        let mut buffer: [u8; ASL_RECORD_SZ] = [0; ASL_RECORD_SZ];
+        self.read_at(fileoffset, &mut buffer)?;
+        let fo_next: FileOffset = u64_be(&buffer, ASL_RECORD_NEXT_OFFSET as usize) as FileOffset;
+        let kv_count: usize = u32_be(&buffer, 62) as usize;
+        let mut record = AslRecord {
+            id: u64_be(&buffer, 14),
+            micros: 0,
+            level: u16_be(&buffer, 34),
+            pid: u32_be(&buffer, 38),
+            gid: u32_be(&buffer, 46),
+            ruid: u32_be(&buffer, 50),
+            rgid: u32_be(&buffer, 54),
+            ..Default::default()
+        };
+        record.micros = asl_time_to_micros(u64_be(&buffer, 22), u32_be(&buffer, 30))
+            .ok_or_else(|| Error::new(
+                ErrorKind::InvalidData,
+                format!("record at offset {} has an invalid timestamp", fileoffset),
+            ))?;
+        record.host = self.string(u64_be(&buffer, 66))?;
+        record.sender = self.string(u64_be(&buffer, 74))?;
+        record.facility = self.string(u64_be(&buffer, 82))?;
+        record.message = self.string(u64_be(&buffer, 90))?;
+        record.refproc = self.string(u64_be(&buffer, 98))?;
+        record.session = self.string(u64_be(&buffer, 106))?;
+
+        // key/value string references follow the fixed portion of the record
+        let kv_len: usize = (kv_count / 2) * 16;
+        self.check_len(fileoffset + ASL_RECORD_SZ as FileOffset, kv_len, "key/value table")?;
+        let mut kv_buffer: Vec<u8> = vec![0; kv_len];
+        self.read_at(fileoffset + ASL_RECORD_SZ as FileOffset, &mut kv_buffer)?;
+        for kv in kv_buffer.chunks_exact(16) {
+            let key: String = self.string(u64_be(kv, 0))?;
+            let value: String = self.string(u64_be(kv, 8))?;
+            match record.key_values.iter_mut().find(|(k, _)| k == &key) {
+                Some((_, v)) => *v = value,
+                None => record.key_values.push((key, value)),
+            }
+        }
+        def1x!("return record id {}, next {}", record.id, fo_next);
+
+        Ok((record, fo_next))
+    }
+
+    /// Return `true` if the records between the header's first and last
+    /// record times cannot pass the datetime filters.
+    fn skip_file(
+        &self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> bool {
+        if dt_filter_after.is_some()
+            && let Ok(Some(dt_last)) = self.record_datetime(self.fo_last)
+            && dt_pass_filters(&dt_last, dt_filter_after, &None) == Result_Filter_DateTime2::BeforeRange
+        {
+            def1ñ!("last record {} is before the filter", dt_last);
+            return true;
+        }
+        if dt_filter_before.is_some()
+            && let Ok(Some(dt_first)) = self.record_datetime(self.fo_first)
+            && dt_pass_filters(&dt_first, &None, dt_filter_before) == Result_Filter_DateTime2::AfterRange
+        {
+            def1ñ!("first record {} is after the filter", dt_first);
+            return true;
+        }
+
+        false
+    }
+
+    /// Return the next record that passes the datetime filters.
+    ///
+    /// The first call compares the times of the first and last records
+    /// to the datetime filters. If no record can pass then the
+    /// remaining records are not read.
+    pub fn next(
+        &mut self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> ResultNextPyDataEvent {
+        def1n!("({:?}, {:?})", dt_filter_after, dt_filter_before);
+        if !self.started {
+            self.started = true;
+            if self.fo_next.is_some() && self.skip_file(dt_filter_after, dt_filter_before) {
+                self.fo_next = None;
+            }
+        }
+        while let Some(fileoffset) = self.fo_next {
+            let (record, fo_next) = match self.record(fileoffset) {
+                Ok(val) => val,
+                Err(err) => {
+                    def1x!("record({}) error {}", fileoffset, err);
+                    self.fo_next = None;
+                    self.error = Some(err.to_string());
+                    return ResultFind4::Err(err);
+                }
+            };
+            // follow the chain to the last record; guard against loops
+            self.fo_next = match fo_next {
+                _ if fileoffset == self.fo_last => None,
+                fo if fo <= fileoffset => None,
+                fo => Some(fo),
+            };
+            self.events_processed += 1;
+            let dt: DateTimeL = match self.micros_to_datetime(record.micros) {
+                Some(dt) => dt,
+                None => continue,
+            };
+            summary_stat!(self.dm_first_last_update_processed(&dt));
+            if dt_pass_filters(&dt, dt_filter_after, dt_filter_before) != Result_Filter_DateTime2::InRange {
+                continue;
+            }
+            let (event, dt_end) = match record.to_event_string() {
+                Some(val) => val,
+                None => continue,
+            };
+            summary_stat!(self.dm_first_last_update_accepted(&dt));
+            self.events_accepted += 1;
+            def1x!("return Found record at {}", fileoffset);
+
+            return ResultFind4::Found(PyDataEvent::new(
+                EventBytes::from(event.as_bytes()),
+                dt,
+                DtBegEndPairOpt::Some((0, dt_end)),
+            ));
+        }
+        def1x!("return Done");
+
+        ResultFind4::Done
+    }
+
+    /// Update the two statistic `DateTimeL` of
+    /// `self.dt_first_processed` and `self.dt_last_processed`.
+    fn dm_first_last_update_processed(
+        &mut self,
+        dt: &DateTimeL,
+    ) {
+        if !summary_stats_enabled() {
+            return;
+        }
+        match self.dt_first_processed {
+            Some(dt_) if &dt_ <= dt => {}
+            Some(_) => {
+                self.dt_first_processed = Some(*dt);
+                self.out_of_order += 1;
+            }
+            None => self.dt_first_processed = Some(*dt),
+        }
+        match self.dt_last_processed {
+            Some(dt_) if &dt_ >= dt => {}
+            _ => self.dt_last_processed = Some(*dt),
+        }
+    }
+
+    /// Update the two statistic `DateTimeL` of
+    /// `self.dt_first_accepted` and `self.dt_last_accepted`.
+    fn dm_first_last_update_accepted(
+        &mut self,
+        dt: &DateTimeL,
+    ) {
+        if !summary_stats_enabled() {
+            return;
+        }
+        match self.dt_first_accepted {
+            Some(dt_) if &dt_ <= dt => {}
+            _ => self.dt_first_accepted = Some(*dt),
+        }
+        match self.dt_last_accepted {
+            Some(dt_) if &dt_ >= dt => {}
+            _ => self.dt_last_accepted = Some(*dt),
+        }
+    }
+
+    /// Return an up-to-date `SummaryPyEventReader` instance for this
+    /// `AslReader`. The Python process statistics are left at defaults.
+    pub fn summary(&self) -> SummaryPyEventReader {
+        SummaryPyEventReader {
+            pyeventreader_events_processed: self.events_processed,
+            pyeventreader_events_accepted: self.events_accepted,
+            pyeventreader_datetime_first_accepted: self.dt_first_accepted,
+            pyeventreader_datetime_last_accepted: self.dt_last_accepted,
+            pyeventreader_datetime_first_processed: self.dt_first_processed,
+            pyeventreader_datetime_last_processed: self.dt_last_processed,
+            pyeventreader_filesz: self.filesz,
+            pyeventreader_out_of_order: self.out_of_order,
+            ..Default::default()
+        }
+    }
+
+    /// Return an up-to-date [`Summary`] instance for this `AslReader`.
+    ///
+    /// [`Summary`]: crate::readers::summary::Summary
+    pub fn summary_complete(&self) -> Summary {
+        let path = self.path().clone();
+        let path_ntf: Option<FPath> = self
+            .named_temp_file
+            .as_ref()
+            .map(|ntf| path_to_fpath(ntf.as_ref()));
+        let filetype = self.filetype();
+        let logmessagetype = filetype.to_logmessagetype();
+        let summaryaslreader: SummaryPyEventReader = self.summary();
+        let error: Option<String> = self.error.clone();
+
+        Summary::new(
+            path,
+            path_ntf,
+            filetype,
+            logmessagetype,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(summaryaslreader),
+            None,
+            None,
+            error,
+        )
+    }
+}
+
+impl PyDataEventReader for AslReader {
+    fn next(
+        &mut self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> ResultNextPyDataEvent {
+        AslReader::next(self, dt_filter_after, dt_filter_before)
+    }
+
+    fn mtime(&self) -> SystemTime {
+        AslReader::mtime(self)
+    }
+
+    fn summary_complete(&self) -> Summary {
+        AslReader::summary_complete(self)
+    }
+}
@@ -1314,6 +1314,9 @@ impl BlockReader {
             FileType::Etl { .. } => {
                 panic!("BlockerReader::new FileType::Etl does not use a BlockReader")
             }
+            FileType::Evt { .. } => {
+                panic!("BlockerReader::new FileType::Evt does not use a BlockReader")
+            }
             FileType::Evtx { .. } => {
                 panic!("BlockerReader::new FileType::Evtx does not use a BlockReader")
             }
@@ -2342,6 +2345,15 @@ impl BlockReader {
                 });
                 def1o!("FileXz: created {:?}", xz_opt.as_ref().unwrap());
             }
+            FileType::Logcat { .. } => {
+                panic!("BlockerReader::new FileType::Logcat does not use a BlockReader")
+            }
+            FileType::Pcap { .. } => {
+                panic!("BlockerReader::new FileType::Pcap does not use a BlockReader")
+            }
+            FileType::Tracev3 { .. } => {
+                panic!("BlockerReader::new FileType::Tracev3 does not use a BlockReader")
+            }
             FileType::Unparsable => {
                 debug_panic!("BlockReader::new bad filetype {:?} for file {:?}", filetype, path);
 
@@ -2467,6 +2479,24 @@ impl BlockReader {
             FileType::Etl {
                 archival_type: FileTypeArchive::Xz,
             } => self.filesz_actual,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Normal,
+            } => self.filesz,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Bz2,
+            } => self.filesz_actual,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Gz,
+            } => self.filesz_actual,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Lz4,
+            } => self.filesz_actual,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Tar,
+            } => self.filesz_actual,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Xz,
+            } => self.filesz_actual,
             FileType::Evtx {
                 archival_type: FileTypeArchive::Normal,
             } => self.filesz,
@@ -2575,6 +2605,60 @@ impl BlockReader {
                 archival_type: FileTypeArchive::Xz,
                 ..
             } => self.filesz_actual,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Normal,
+            } => self.filesz,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Normal,
+            } => self.filesz,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Normal,
+            } => self.filesz,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Bz2,
+            } => self.filesz_actual,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Bz2,
+            } => self.filesz_actual,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Bz2,
+            } => self.filesz_actual,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Gz,
+            } => self.filesz_actual,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Gz,
+            } => self.filesz_actual,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Gz,
+            } => self.filesz_actual,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Lz4,
+            } => self.filesz_actual,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Lz4,
+            } => self.filesz_actual,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Lz4,
+            } => self.filesz_actual,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Tar,
+            } => self.filesz_actual,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Tar,
+            } => self.filesz_actual,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Tar,
+            } => self.filesz_actual,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Xz,
+            } => self.filesz_actual,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Xz,
+            } => self.filesz_actual,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Xz,
+            } => self.filesz_actual,
             FileType::Unparsable => panic!("BlockerReader::filesz Unexpected Unparsable"),
         }
     }
@@ -2638,6 +2722,9 @@ impl BlockReader {
             FileType::Etl { .. } => {
                 panic!("BlockerReader::mtime FileType::Etl does not use a BlockReader")
             }
+            FileType::Evt { .. } => {
+                panic!("BlockerReader::mtime FileType::Evt does not use a BlockReader")
+            }
             FileType::Evtx { .. } => {
                 panic!("BlockerReader::mtime FileType::Evtx does not use a BlockReader")
             }
@@ -2733,6 +2820,15 @@ impl BlockReader {
                     self.file_metadata_modified
                 }
             }
+            FileType::Logcat { .. } => {
+                panic!("BlockerReader::mtime FileType::Logcat does not use a BlockReader")
+            }
+            FileType::Pcap { .. } => {
+                panic!("BlockerReader::mtime FileType::Pcap does not use a BlockReader")
+            }
+            FileType::Tracev3 { .. } => {
+                panic!("BlockerReader::mtime FileType::Tracev3 does not use a BlockReader")
+            }
             FileType::Unparsable => panic!("BlockerReader::mtime unexpected Unparsable"),
         }
     }
@@ -3020,6 +3116,24 @@ impl BlockReader {
             FileType::Etl {
                 archival_type: FileTypeArchive::Xz,
             } => true,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Normal,
+            } => false,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Bz2,
+            } => true,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Gz,
+            } => true,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Lz4,
+            } => true,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Tar,
+            } => true,
+            FileType::Evt {
+                archival_type: FileTypeArchive::Xz,
+            } => true,
             FileType::Evtx {
                 archival_type: FileTypeArchive::Normal,
             } => false,
@@ -3128,6 +3242,60 @@ impl BlockReader {
                 archival_type: FileTypeArchive::Xz,
                 ..
             } => true,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Normal,
+            } => false,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Normal,
+            } => false,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Normal,
+            } => false,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Bz2,
+            } => true,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Bz2,
+            } => true,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Bz2,
+            } => true,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Gz,
+            } => true,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Gz,
+            } => true,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Gz,
+            } => true,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Lz4,
+            } => true,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Lz4,
+            } => true,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Lz4,
+            } => true,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Tar,
+            } => true,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Tar,
+            } => true,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Tar,
+            } => true,
+            FileType::Logcat {
+                archival_type: FileTypeArchive::Xz,
+            } => true,
+            FileType::Pcap {
+                archival_type: FileTypeArchive::Xz,
+            } => true,
+            FileType::Tracev3 {
+                archival_type: FileTypeArchive::Xz,
+            } => true,
             FileType::Unparsable => false,
         }
     }
@@ -4686,6 +4854,12 @@ impl BlockReader {
                     self.filetype, self.path,
                 )
             }
+            FileType::Evt { .. } => {
+                panic!(
+                    "BlockReader::read_block unsupported filetype {:?}; path {:?}",
+                    self.filetype, self.path,
+                )
+            }
             FileType::Evtx { .. } => {
                 panic!(
                     "BlockReader::read_block unsupported filetype {:?}; path {:?}",
@@ -4752,6 +4926,24 @@ impl BlockReader {
                 archival_type: FileTypeArchive::Xz,
                 ..
             } => self.read_block_FileXz(blockoffset),
+            FileType::Logcat { .. } => {
+                panic!(
+                    "BlockReader::read_block unsupported filetype {:?}; path {:?}",
+                    self.filetype, self.path,
+                )
+            }
+            FileType::Pcap { .. } => {
+                panic!(
+                    "BlockReader::read_block unsupported filetype {:?}; path {:?}",
+                    self.filetype, self.path,
+                )
+            }
+            FileType::Tracev3 { .. } => {
+                panic!(
+                    "BlockReader::read_block unsupported filetype {:?}; path {:?}",
+                    self.filetype, self.path,
+                )
+            }
             FileType::Unparsable => {
                 panic!(
                     "BlockReader::read_block bad filetype {:?}; path {:?}",
@@ -5033,6 +5225,73 @@ impl BlockReader {
         ResultReadDataToBuffer::Found(at)
     }
 
+    /// Return the `FileOffset` of the first non-zero byte at or after
+    /// `fileoffset`. Returns `None` if the remainder of the file is all zeros.
+    ///
+    /// For a [`FileTypeArchive::Normal`] file, the holes of a sparse file
+    /// found by [`FileHandleManaged::seek_data`] are never read.
+    /// Otherwise each [`Block`] is read and a `Block` of all zeros is skipped.
+    /// A `Block` of all zeros is dropped if the file is not streamed.
+    ///
+    /// Allows a caller to skip the unused entries of a huge sparse file, like
+    /// a `lastlog` file with an entry for a high UID.
+    ///
+    /// [`Block`]: crate::readers::blockreader::Block
+    pub fn fileoffset_nonzero(
+        &mut self,
+        fileoffset: FileOffset,
+    ) -> Result<Option<FileOffset>> {
+        defn!("({})", fileoffset);
+        let seek_data: bool = matches!(self.filetype.archival_type(), FileTypeArchive::Normal);
+        let mut fo: FileOffset = fileoffset;
+        // end of the region of data that `fo` is within
+        let mut data_end: FileOffset = 0;
+        while fo < self.filesz() {
+            if seek_data && fo >= data_end {
+                match self.file_handle.seek_data(fo) {
+                    Ok(Some((data, hole))) => {
+                        defo!("seek_data({}) returned data {}, hole {}", fo, data, hole);
+                        fo = fo.max(data);
+                        data_end = hole;
+                        continue;
+                    }
+                    Ok(None) => {
+                        defx!("seek_data({}) returned None; return None", fo);
+                        return Ok(None);
+                    }
+                    Err(err) => {
+                        defx!("seek_data({}) returned Err {}", fo, err);
+                        return Err(err);
+                    }
+                }
+            }
+            let bo: BlockOffset = self.block_offset_at_file_offset_self(fo);
+            let bi: BlockIndex = self.block_index_at_file_offset_self(fo);
+            let blockp: BlockP = match self.read_block(bo) {
+                ResultFindReadBlock::Found(blockp) => blockp,
+                ResultFindReadBlock::Done => break,
+                ResultFindReadBlock::Err(err) => {
+                    defx!("read_block({}) returned Err {}", bo, err);
+                    return Err(err);
+                }
+            };
+            if let Some(at) = blockp[bi..].iter().position(|b| *b != 0) {
+                let fo_nonzero: FileOffset = fo + at as FileOffset;
+                defx!("return Some({})", fo_nonzero);
+                return Ok(Some(fo_nonzero));
+            }
+            if !self.is_streamed_file() && blockp[..bi].iter().all(|b| *b == 0) {
+                defo!("drop block {} of all zeros", bo);
+                drop(blockp);
+                self.drop_block(bo);
+            }
+            fo = self.file_offset_at_block_offset_self(bo + 1);
+        }
+        defx!("return None");
+
+        Ok(None)
+    }
+
     /// Helper function to open a `.tar` file.
     pub fn open_tar(
         path_id: PathId,
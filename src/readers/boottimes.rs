@@ -0,0 +1,470 @@
+// src/readers/boottimes.rs
+
+//! Gather system boot times from log files. The boot times anchor the
+//! uptime-only datetimes of `dmesg`-style syslog files to wall-clock time.
+//!
+//! A `dmesg` line like `[  123.456789] usb 1-1: new device` only has the
+//! seconds since system boot. Without a known boot time, a
+//! [`SyslogProcessor`] presumes the last line of the file was written at the
+//! file modified time (`mtime`). That presumption is wrong for copied or
+//! archived files. A `dmesg` file may also hold several boots, each starting
+//! again from uptime zero.
+//!
+//! Boot times are gathered from
+//! * `utmp`, `utmpx`, and `wtmp` `BOOT_TIME` records;
+//!   see [`boot_times_fixedstruct`].
+//! * `systemd` journal files, the first entry of each `_BOOT_ID` as
+//!   `__REALTIME_TIMESTAMP` minus `__MONOTONIC_TIMESTAMP`;
+//!   see [`boot_times_journal`].
+//! * syslog files with a wall-clock datetime, the
+//!   `kernel: Linux version` lines; see [`boot_times_syslog`].
+//!
+//! Each boot of an uptime-only file is then matched to a boot time by
+//! [`uptime_zeros`].
+//!
+//! An X.Org server log, e.g. `Xorg.0.log`, is also uptime-only but has a
+//! header line with the wall-clock time of the server start,
+//! e.g. `(==) Log file: "/var/log/Xorg.0.log", Time: Mon Feb 17 12:50:58 2020`.
+//! The header line anchors its server session; see [`xorg_header_time`].
+//! The X.Org server logs written by `lightdm`, e.g.
+//! `/var/log/lightdm/x-0.log`, have the same header line.
+//! `lightdm.log` itself has no wall-clock time, only the seconds since
+//! `lightdm` started, e.g. `[+0.52s]`. Weston logs have time-of-day stamps
+//! and `Date:` lines instead of uptimes. Neither is anchored here.
+//!
+//! [`SyslogProcessor`]: crate::readers::syslogprocessor::SyslogProcessor
+
+use std::collections::HashMap;
+use std::io::Result;
+use std::time::Duration as StdDuration;
+
+use ::memchr::memmem;
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::common::{
+    FPath,
+    FileOffset,
+    FileType,
+    PathId,
+};
+use crate::data::datetime::{
+    FixedOffset,
+    NaiveDateTime,
+    SystemTime,
+    TimeZone,
+};
+use crate::data::fixedstruct::{
+    UtmpKind,
+    ENTRY_SZ_MAX,
+};
+use crate::data::journal::EpochMicroseconds;
+use crate::readers::blockreader::BlockSz;
+use crate::readers::fixedstructreader::{
+    FixedStructReader,
+    ResultFindFixedStruct,
+    ResultFixedStructReaderNew,
+};
+use crate::readers::journalreader::{
+    JournalOutput,
+    JournalReader,
+    ResultNext,
+    KEY__MONOTONIC_TIMESTAMP_BYTES,
+    KEY__REALTIME_TIMESTAMP_BYTES,
+};
+use crate::readers::syslinereader::ResultFindSysline;
+use crate::readers::syslogprocessor::{
+    FileProcessingResultBlockZero,
+    SyslogProcessor,
+};
+
+/// System boot times in ascending order.
+pub type BootTimes = Vec<SystemTime>;
+
+/// Boot times within this duration of each other are the same boot.
+///
+/// Boot records are usually written some seconds after the kernel started,
+/// e.g. `systemd-update-utmp` writes the `wtmp` `BOOT_TIME` record and a
+/// syslog daemon reads the kernel `Linux version` message once it is running.
+pub const BOOT_TIMES_MERGE_WINDOW: StdDuration = StdDuration::from_secs(30);
+
+/// The syslog identifier of kernel messages.
+const KERNEL: &[u8] = b"kernel: ";
+
+/// The kernel message logged at uptime zero of each boot.
+const LINUX_VERSION: &[u8] = b"Linux version ";
+
+/// Journal field of the boot ID.
+const KEY__BOOT_ID_BYTES: &[u8] = b"_BOOT_ID";
+
+/// X.Org server log header preceding the log file path.
+const XORG_LOG_FILE: &[u8] = b"Log file: \"";
+
+/// X.Org server log header preceding the server start time.
+const XORG_TIME: &[u8] = b"\", Time: ";
+
+/// `strftime` format of the X.Org server log header time, i.e. `ctime`
+/// with single spaces.
+const XORG_TIME_FORMAT: &str = "%a %b %d %H:%M:%S %Y";
+
+/// One boot of an uptime-only syslog file, or one server session of an
+/// X.Org server log.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UptimeSegment {
+    /// `FileOffset` where the segment's syslines begin.
+    pub fileoffset: FileOffset,
+    /// Greatest uptime of the segment's syslines.
+    pub uptime_max: StdDuration,
+    /// `SystemTime` at uptime zero known from a header line within the
+    /// segment. See [`xorg_header_time`].
+    pub zero: Option<SystemTime>,
+}
+
+/// Sort the `boot_times` and merge boot times within
+/// [`BOOT_TIMES_MERGE_WINDOW`] of the earliest boot time, i.e. the same boot
+/// gathered from different files.
+pub fn boot_times_merge(mut boot_times: BootTimes) -> BootTimes {
+    boot_times.sort_unstable();
+    let mut merged: BootTimes = BootTimes::with_capacity(boot_times.len());
+    for boot_time in boot_times.into_iter() {
+        match merged.last() {
+            Some(prev) if boot_time
+                .duration_since(*prev)
+                .is_ok_and(|diff| diff <= BOOT_TIMES_MERGE_WINDOW) => {}
+            _ => merged.push(boot_time),
+        }
+    }
+
+    merged
+}
+
+/// Return the `SystemTime` at uptime zero for each segment of an
+/// uptime-only syslog file.
+///
+/// `segments` is each boot of the file in file order.
+/// The last boot presumably ended at the file `mtime`. Each prior boot
+/// presumably ended when the next boot started.
+///
+/// From the last boot to the first boot, a segment with a known
+/// [`UptimeSegment::zero`] uses that. Otherwise, the latest of the
+/// `boot_times` that is before the end of the boot and leaves room for the
+/// greatest uptime of the boot is the boot's uptime zero. If there is no
+/// such boot time then the end of the boot minus the greatest uptime is the
+/// boot's uptime zero.
+///
+/// Returns `None` if a `SystemTime` cannot be calculated.
+pub fn uptime_zeros(
+    segments: &[UptimeSegment],
+    mtime: SystemTime,
+    boot_times: &[SystemTime],
+) -> Option<Vec<(FileOffset, SystemTime)>> {
+    defn!("({:?}, {:?}, {:?})", segments, mtime, boot_times);
+    let mut zeros: Vec<(FileOffset, SystemTime)> = Vec::with_capacity(segments.len());
+    let mut end: SystemTime = mtime;
+    for segment in segments.iter().rev() {
+        let uptime: StdDuration = segment.uptime_max;
+        let end_slack: SystemTime = end.checked_add(BOOT_TIMES_MERGE_WINDOW)?;
+        let zero: SystemTime = match segment.zero {
+            Some(zero) => zero,
+            None => match boot_times
+                .iter()
+                .rev()
+                .find(|boot_time|
+                    **boot_time < end
+                    && boot_time
+                        .checked_add(uptime)
+                        .is_some_and(|boot_end| boot_end <= end_slack)
+                )
+            {
+                Some(boot_time) => *boot_time,
+                None => end.checked_sub(uptime)?,
+            },
+        };
+        defo!("fileoffset {} uptime {:?} zero {:?}", segment.fileoffset, uptime, zero);
+        zeros.push((segment.fileoffset, zero));
+        end = zero;
+    }
+    zeros.reverse();
+    defx!("return {:?}", zeros);
+
+    Some(zeros)
+}
+
+/// Return the `_BOOT_ID` and the boot time of a journal entry in
+/// `--output=export` format.
+/// The boot time is `__REALTIME_TIMESTAMP` minus `__MONOTONIC_TIMESTAMP`.
+pub fn journal_export_boot_time(data: &[u8]) -> Option<(&[u8], SystemTime)> {
+    let mut boot_id: Option<&[u8]> = None;
+    let mut realtime: Option<EpochMicroseconds> = None;
+    let mut monotonic: Option<EpochMicroseconds> = None;
+    for line in data.split(|b| *b == b'\n') {
+        let at: usize = match line.iter().position(|b| *b == b'=') {
+            Some(at) => at,
+            None => continue,
+        };
+        let (key, value) = (&line[..at], &line[at + 1..]);
+        let number = || -> Option<EpochMicroseconds> {
+            std::str::from_utf8(value).ok()?.parse::<EpochMicroseconds>().ok()
+        };
+        match key {
+            KEY__BOOT_ID_BYTES => boot_id = Some(value),
+            KEY__REALTIME_TIMESTAMP_BYTES => realtime = number(),
+            KEY__MONOTONIC_TIMESTAMP_BYTES => monotonic = number(),
+            _ => {}
+        }
+    }
+    let boot_us: EpochMicroseconds = realtime?.checked_sub(monotonic?)?;
+
+    Some((boot_id?, SystemTime::UNIX_EPOCH.checked_add(StdDuration::from_micros(boot_us))?))
+}
+
+/// Return the server start time of an X.Org server log header line `data`,
+/// e.g. `[    91.203] (==) Log file: "/var/log/Xorg.0.log", Time: Mon Feb 17 12:50:58 2020`.
+///
+/// The header time is the local time of the system so it is interpreted
+/// with `tz_offset`.
+pub fn xorg_header_time(
+    data: &[u8],
+    tz_offset: &FixedOffset,
+) -> Option<SystemTime> {
+    let at: usize = memmem::find(data, XORG_LOG_FILE)? + XORG_LOG_FILE.len();
+    let at: usize = at + memmem::find(&data[at..], XORG_TIME)? + XORG_TIME.len();
+    let time: &str = std::str::from_utf8(&data[at..]).ok()?;
+    // `ctime` pads the day of month with a space, e.g. "Mon Feb  3"
+    let time: String = time
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ");
+    let ndt: NaiveDateTime = NaiveDateTime::parse_from_str(time.as_str(), XORG_TIME_FORMAT).ok()?;
+
+    Some(SystemTime::from(tz_offset.from_local_datetime(&ndt).single()?))
+}
+
+/// Does the syslog message `data` hold the kernel `Linux version` message,
+/// e.g. `kernel: Linux version 6.2.9` or
+/// `kernel: [    0.000000] Linux version 6.2.9`?
+pub fn is_kernel_linux_version(data: &[u8]) -> bool {
+    let at: usize = match memmem::find(data, KERNEL) {
+        Some(at) => at + KERNEL.len(),
+        None => return false,
+    };
+    let mut message: &[u8] = &data[at..];
+    // skip a kernel uptime
+    if message.first() == Some(&b'[') {
+        message = match message.iter().position(|b| *b == b']') {
+            Some(at) => message[at + 1..].trim_ascii_start(),
+            None => return false,
+        };
+    }
+
+    message.starts_with(LINUX_VERSION)
+}
+
+/// Return the `BOOT_TIME` records of a `utmp`, `utmpx`, or `wtmp` file.
+pub fn boot_times_fixedstruct(
+    path_id: PathId,
+    path: &FPath,
+    filetype: FileType,
+    blocksz: BlockSz,
+    tz_offset: FixedOffset,
+) -> Result<BootTimes> {
+    defn!("({:?})", path);
+    let mut fixedstructreader: FixedStructReader = match FixedStructReader::new(
+        path_id,
+        path.clone(),
+        filetype,
+        blocksz,
+        tz_offset,
+        None,
+        None,
+    ) {
+        ResultFixedStructReaderNew::FileOk(val) => val,
+        ResultFixedStructReaderNew::FileErrIo(err) => {
+            defx!("return Err {:?}", err);
+            return Err(err);
+        }
+        _ => {
+            defx!("return empty");
+            return Ok(BootTimes::new());
+        }
+    };
+    let mut boot_times: BootTimes = BootTimes::new();
+    let mut fo: FileOffset = match fixedstructreader.fileoffset_first() {
+        Some(fo) => fo,
+        None => {
+            defx!("return empty");
+            return Ok(boot_times);
+        }
+    };
+    let mut buffer: [u8; ENTRY_SZ_MAX] = [0; ENTRY_SZ_MAX];
+    loop {
+        match fixedstructreader.process_entry_at(fo, &mut buffer) {
+            ResultFindFixedStruct::Found((fo_next, fixedstruct)) => {
+                if let Some(utmpfields) = fixedstruct.utmp_fields()
+                    && utmpfields.kind == UtmpKind::BootTime
+                {
+                    defo!("BOOT_TIME {:?}", fixedstruct.dt());
+                    boot_times.push(SystemTime::from(*fixedstruct.dt()));
+                }
+                fo = fo_next;
+            }
+            ResultFindFixedStruct::Done => break,
+            ResultFindFixedStruct::Err((Some(fo_next), _err)) => {
+                defo!("process_entry_at({}) error {:?}", fo, _err);
+                fo = fo_next;
+            }
+            ResultFindFixedStruct::Err((None, err)) => {
+                defx!("return Err {:?}", err);
+                return Err(err);
+            }
+        }
+    }
+    defx!("return {} boot times", boot_times.len());
+
+    Ok(boot_times)
+}
+
+/// Return the boot time of each `_BOOT_ID` of a `systemd` journal file.
+/// The boot time is taken from the first entry of each `_BOOT_ID`.
+///
+/// The `libsystemd` library must already be loaded.
+pub fn boot_times_journal(
+    path_id: PathId,
+    path: &FPath,
+    filetype: FileType,
+    tz_offset: FixedOffset,
+) -> Result<BootTimes> {
+    defn!("({:?})", path);
+    let mut journalreader: JournalReader = JournalReader::new(
+        path_id,
+        path.clone(),
+        JournalOutput::Export,
+        tz_offset,
+        filetype,
+    )?;
+    journalreader.analyze(&None)?;
+    let mut boots: HashMap<Vec<u8>, SystemTime> = HashMap::new();
+    loop {
+        match journalreader.next(&None) {
+            ResultNext::Found(journalentry) => {
+                if let Some((boot_id, boot_time)) = journal_export_boot_time(journalentry.as_bytes()) {
+                    boots.entry(boot_id.to_vec()).or_insert(boot_time);
+                }
+            }
+            ResultNext::Done => break,
+            ResultNext::Err(err) => {
+                defx!("return Err {:?}", err);
+                return Err(err);
+            }
+            ResultNext::ErrIgnore(_err) => {
+                defo!("next() error ignored {:?}", _err);
+            }
+        }
+    }
+    defx!("return {} boot times", boots.len());
+
+    Ok(boots.into_values().collect())
+}
+
+/// Return the datetimes of the `kernel: Linux version` syslines of a syslog
+/// file. Uptime-only syslog files are skipped.
+pub fn boot_times_syslog(
+    path_id: PathId,
+    path: &FPath,
+    filetype: FileType,
+    blocksz: BlockSz,
+    tz_offset: FixedOffset,
+) -> Result<BootTimes> {
+    defn!("({:?})", path);
+    let mut syslogproc: SyslogProcessor = SyslogProcessor::new(
+        path_id,
+        path.clone(),
+        filetype,
+        blocksz,
+        tz_offset,
+        None,
+        None,
+    )?;
+    let mut boot_times: BootTimes = BootTimes::new();
+    for stage in [
+        SyslogProcessor::process_stage0_valid_file_check,
+        SyslogProcessor::process_stage1_blockzero_analysis,
+    ] {
+        match stage(&mut syslogproc) {
+            FileProcessingResultBlockZero::FileOk => {}
+            FileProcessingResultBlockZero::FileErrIo(err)
+            | FileProcessingResultBlockZero::FileErrIoPath(err) => {
+                defx!("return Err {:?}", err);
+                return Err(err);
+            }
+            _ => {
+                defx!("return empty");
+                return Ok(boot_times);
+            }
+        }
+    }
+    if syslogproc.dt_pattern_uptime() {
+        defx!("return empty; uptime-only file");
+        return Ok(boot_times);
+    }
+    match syslogproc.process_stage2_find_dt(&None) {
+        FileProcessingResultBlockZero::FileOk => {}
+        FileProcessingResultBlockZero::FileErrIo(err)
+        | FileProcessingResultBlockZero::FileErrIoPath(err) => {
+            defx!("return Err {:?}", err);
+            return Err(err);
+        }
+        _ => {
+            defx!("return empty");
+            return Ok(boot_times);
+        }
+    }
+    syslogproc.process_stage3_stream_syslines();
+    let mut fo: FileOffset = 0;
+    loop {
+        match syslogproc.find_sysline_between_datetime_filters(fo) {
+            ResultFindSysline::Found((fo_next, syslinep)) => {
+                if is_kernel_linux_version(syslinep.to_bytes().as_slice()) {
+                    defo!("Linux version {:?}", syslinep.dt());
+                    boot_times.push(SystemTime::from(*syslinep.dt()));
+                }
+                if syslogproc.is_sysline_last(&syslinep) {
+                    break;
+                }
+                syslogproc.drop_data_try(&syslinep);
+                fo = fo_next;
+            }
+            ResultFindSysline::Done => break,
+            ResultFindSysline::Err(err) => {
+                defx!("return Err {:?}", err);
+                return Err(err);
+            }
+        }
+    }
+    defx!("return {} boot times", boot_times.len());
+
+    Ok(boot_times)
+}
+
+/// Return the boot times found in the file at `path`, dispatching on the
+/// `filetype`. File types that do not hold boot times return an empty
+/// `BootTimes`.
+pub fn boot_times_path(
+    path_id: PathId,
+    path: &FPath,
+    filetype: FileType,
+    blocksz: BlockSz,
+    tz_offset: FixedOffset,
+) -> Result<BootTimes> {
+    match filetype {
+        FileType::FixedStruct { .. } => boot_times_fixedstruct(path_id, path, filetype, blocksz, tz_offset),
+        FileType::Journal { .. } => boot_times_journal(path_id, path, filetype, tz_offset),
+        FileType::Text { .. } => boot_times_syslog(path_id, path, filetype, blocksz, tz_offset),
+        _ => Ok(BootTimes::new()),
+    }
+}
@@ -0,0 +1,403 @@
+// src/readers/evtparser.rs
+
+//! Implements an [`EvtParser`], a reader of [`EvtRecord`]s from a legacy
+//! [Windows Event Log `.evt` format] file (Windows NT through Windows 2003).
+//!
+//! An `.evt` file is a 48 byte file header followed by a circular buffer of
+//! `EVENTLOGRECORD`s. The oldest record is at the file header `StartOffset`.
+//! The "end of file" record, `ELF_EOF_RECORD`, follows the newest record.
+//! When the buffer is full, new records overwrite the oldest records and a
+//! record that does not fit before the end of the file continues at the
+//! first byte after the file header.
+//!
+//! The file header is only updated when the log is closed cleanly. A file
+//! copied from a running or crashed system has the "dirty" flag set and the
+//! file header offsets may be stale. So the `ELF_EOF_RECORD` offsets are
+//! preferred to the file header offsets.
+//!
+//! The `EvtParser` is used by an [`EvtxReader`].
+//!
+//! [`EvtRecord`]: crate::data::evt::EvtRecord
+//! [Windows Event Log `.evt` format]: https://github.com/libyal/libevt/blob/main/documentation/Windows%20Event%20Log%20(EVT)%20format.asciidoc
+//! [`EvtxReader`]: crate::readers::evtxreader::EvtxReader
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{
+    Error,
+    ErrorKind,
+    Read,
+    Result,
+};
+use std::path::Path;
+
+use ::memchr::memmem;
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    def1n,
+    def1o,
+    def1x,
+    def1ñ,
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::common::{
+    Count,
+    FPath,
+    SUBPATH_SEP,
+};
+use crate::data::evt::{
+    u32_at,
+    EvtRecord,
+    EVT_RECORD_HEADER_SIZE,
+    EVT_RECORD_SIGNATURE_OFFSET,
+    EVT_SIGNATURE,
+};
+
+/// Size of the `.evt` file header, `ELF_LOGFILE_HEADER`, in bytes.
+pub const EVT_FILE_HEADER_SIZE: usize = 0x30;
+/// Size of the `ELF_EOF_RECORD` in bytes.
+pub const EVT_EOF_RECORD_SIZE: usize = 0x28;
+/// The `ELF_EOF_RECORD` signature that follows the record size.
+pub const EVT_EOF_SIGNATURE: &[u8; 16] = b"\x11\x11\x11\x11\x22\x22\x22\x22\x33\x33\x33\x33\x44\x44\x44\x44";
+/// `ELF_LOGFILE_HEADER` flag; the file header was not written when the log was
+/// closed.
+pub const ELF_LOGFILE_HEADER_DIRTY: u32 = 0x1;
+/// `ELF_LOGFILE_HEADER` flag; the circular buffer has wrapped.
+pub const ELF_LOGFILE_HEADER_WRAP: u32 = 0x2;
+
+/// Channel names of the well-known `.evt` file names.
+const EVT_CHANNELS: [(&str, &str); 3] = [
+    ("appevent", "Application"),
+    ("secevent", "Security"),
+    ("sysevent", "System"),
+];
+
+/// Return the `System/Channel` for an `.evt` file. `.evt` records do not
+/// store a channel so derive it from the file name, e.g. `SysEvent.Evt` is
+/// channel `System`.
+pub fn evt_channel(path: &FPath) -> String {
+    // the file name within an archive follows the last `SUBPATH_SEP`
+    let name: &str = path
+        .rsplit(SUBPATH_SEP)
+        .next()
+        .unwrap_or_default();
+    let name: &str = Path::new(name)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+    let stem: &str = name.split('.').next().unwrap_or_default();
+    for (file, channel) in EVT_CHANNELS.iter() {
+        if stem.eq_ignore_ascii_case(file) {
+            return String::from(*channel);
+        }
+    }
+
+    String::from(stem)
+}
+
+/// The `.evt` file header, `ELF_LOGFILE_HEADER`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EvtFileHeader {
+    /// Offset of the oldest record.
+    pub start_offset: u32,
+    /// Offset of the `ELF_EOF_RECORD`.
+    pub end_offset: u32,
+    pub current_record_number: u32,
+    pub oldest_record_number: u32,
+    pub max_size: u32,
+    pub flags: u32,
+}
+
+impl EvtFileHeader {
+    /// Read the file header at the start of `data`.
+    ///
+    /// Returns `None` if the header size, signature or version is not valid.
+    pub fn from_bytes(data: &[u8]) -> Option<EvtFileHeader> {
+        if u32_at(data, 0)? as usize != EVT_FILE_HEADER_SIZE
+            || data.get(4..8)? != EVT_SIGNATURE
+            || u32_at(data, 8)? != 1
+            || u32_at(data, 12)? != 1
+            || u32_at(data, 44)? as usize != EVT_FILE_HEADER_SIZE
+        {
+            return None;
+        }
+
+        Some(EvtFileHeader {
+            start_offset: u32_at(data, 16)?,
+            end_offset: u32_at(data, 20)?,
+            current_record_number: u32_at(data, 24)?,
+            oldest_record_number: u32_at(data, 28)?,
+            max_size: u32_at(data, 32)?,
+            flags: u32_at(data, 36)?,
+        })
+    }
+
+    pub const fn is_dirty(&self) -> bool {
+        self.flags & ELF_LOGFILE_HEADER_DIRTY != 0
+    }
+
+    pub const fn is_wrapped(&self) -> bool {
+        self.flags & ELF_LOGFILE_HEADER_WRAP != 0
+    }
+}
+
+/// The "end of file" record, `ELF_EOF_RECORD`, that follows the newest
+/// record.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EvtEofRecord {
+    /// Offset of this `ELF_EOF_RECORD` in the file.
+    pub offset: usize,
+    /// Offset of the oldest record.
+    pub begin_record: u32,
+    /// Offset of this `ELF_EOF_RECORD` as written.
+    pub end_record: u32,
+    pub current_record_number: u32,
+    pub oldest_record_number: u32,
+}
+
+impl EvtEofRecord {
+    /// Find the `ELF_EOF_RECORD` in `data`.
+    pub fn find(data: &[u8]) -> Option<EvtEofRecord> {
+        for at in memmem::find_iter(data, EVT_EOF_SIGNATURE) {
+            let offset: usize = match at.checked_sub(4) {
+                Some(offset) => offset,
+                None => continue,
+            };
+            if u32_at(data, offset) != Some(EVT_EOF_RECORD_SIZE as u32)
+                || u32_at(data, offset + EVT_EOF_RECORD_SIZE - 4) != Some(EVT_EOF_RECORD_SIZE as u32)
+            {
+                continue;
+            }
+            return Some(EvtEofRecord {
+                offset,
+                begin_record: u32_at(data, offset + 20)?,
+                end_record: u32_at(data, offset + 24)?,
+                current_record_number: u32_at(data, offset + 28)?,
+                oldest_record_number: u32_at(data, offset + 32)?,
+            });
+        }
+
+        None
+    }
+}
+
+/// Records, and offsets of record signatures that could not be decoded,
+/// found by [`walk`].
+type Walked = (Vec<(usize, EvtRecord)>, Vec<usize>);
+
+/// Read the consecutive records in `data`. Skip forward to the next record
+/// signature after bytes that are not a valid record.
+fn walk(data: &[u8]) -> Walked {
+    let mut records: Vec<(usize, EvtRecord)> = Vec::new();
+    let mut failed: Vec<usize> = Vec::new();
+    let finder = memmem::Finder::new(EVT_SIGNATURE);
+    let mut at: usize = 0;
+    while at + EVT_RECORD_HEADER_SIZE <= data.len() {
+        if &data[at + EVT_RECORD_SIGNATURE_OFFSET..at + EVT_RECORD_SIGNATURE_OFFSET + 4] == EVT_SIGNATURE {
+            let length: usize = u32_at(data, at).unwrap_or_default() as usize;
+            if let Some(record) = data
+                .get(at..at.saturating_add(length))
+                .and_then(EvtRecord::from_bytes)
+            {
+                records.push((at, record));
+                at += length;
+                continue;
+            }
+            defo!("record signature at 0x{:08X} is not a valid record", at);
+            failed.push(at);
+        }
+        // skip to the next record signature
+        let from: usize = at + EVT_RECORD_SIGNATURE_OFFSET + 1;
+        match finder.find(&data[from..]) {
+            Some(i) => at = from + i - EVT_RECORD_SIGNATURE_OFFSET,
+            None => break,
+        }
+    }
+
+    (records, failed)
+}
+
+/// Reads the [`EvtRecord`]s of an `.evt` file.
+///
+/// The entire file is read into memory.
+///
+/// [`EvtRecord`]: crate::data::evt::EvtRecord
+pub struct EvtParser {
+    /// The entire file.
+    data: Vec<u8>,
+    /// The file header; `None` if not valid.
+    header: Option<EvtFileHeader>,
+    /// Recovery mode; also read records outside of the circular buffer's
+    /// live records, e.g. overwritten records, and do not require a valid
+    /// file header.
+    recover: bool,
+    /// Summary statistic.
+    /// Count of records found outside of the live records in recovery mode.
+    pub(crate) records_recovered: Count,
+    /// Summary statistic.
+    /// Count of record signatures that could not be decoded.
+    pub(crate) records_dropped: Count,
+}
+
+impl fmt::Debug for EvtParser {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        f.debug_struct("EvtParser")
+            .field("len", &self.data.len())
+            .field("header", &self.header)
+            .field("recover", &self.recover)
+            .finish()
+    }
+}
+
+impl EvtParser {
+    /// Read the entire file at `path`.
+    pub fn open(
+        path: &Path,
+        recover: bool,
+    ) -> Result<EvtParser> {
+        def1n!("({:?}, {:?})", path, recover);
+        let mut data: Vec<u8> = Vec::new();
+        std::fs::File::open(path)?.read_to_end(&mut data)?;
+        let ret = Self::from_vec(data, recover);
+        def1x!("return {:?}", ret);
+
+        ret
+    }
+
+    /// Create an `EvtParser` from the file bytes.
+    ///
+    /// Returns an `Error` if not `recover` and the file header is not valid.
+    pub fn from_vec(
+        data: Vec<u8>,
+        recover: bool,
+    ) -> Result<EvtParser> {
+        let header: Option<EvtFileHeader> = EvtFileHeader::from_bytes(&data);
+        if header.is_none() && !recover {
+            return Err(
+                Error::new(
+                    ErrorKind::InvalidData,
+                    "not a Windows Event Log (.evt) file; bad file header",
+                )
+            );
+        }
+
+        Ok(EvtParser {
+            data,
+            header,
+            recover,
+            records_recovered: 0,
+            records_dropped: 0,
+        })
+    }
+
+    pub const fn header(&self) -> Option<&EvtFileHeader> {
+        self.header.as_ref()
+    }
+
+    /// Return the offsets of the oldest record and the `ELF_EOF_RECORD`.
+    fn live_offsets(&self) -> Option<(usize, usize)> {
+        let header: &EvtFileHeader = self.header.as_ref()?;
+        let valid = |offset: usize| (EVT_FILE_HEADER_SIZE..self.data.len()).contains(&offset);
+        if let Some(eof) = EvtEofRecord::find(&self.data)
+            && valid(eof.begin_record as usize)
+        {
+            defo!("{:?}", eof);
+            return Some((eof.begin_record as usize, eof.offset));
+        }
+        let start: usize = header.start_offset as usize;
+        let end: usize = header.end_offset as usize;
+        if valid(start) && valid(end) {
+            return Some((start, end));
+        }
+
+        None
+    }
+
+    /// Read the live records, oldest to newest, from the circular buffer.
+    /// The returned offsets are file offsets.
+    fn walk_live(&self) -> Walked {
+        let (begin, end): (usize, usize) = match self.live_offsets() {
+            Some(offsets) => offsets,
+            None => return (Vec::new(), Vec::new()),
+        };
+        defo!("begin 0x{:08X} end 0x{:08X}", begin, end);
+        // join the circular buffer into one slice
+        let mut ring: Vec<u8> = Vec::new();
+        let tail: usize;
+        if begin <= end {
+            ring.extend_from_slice(&self.data[begin..end]);
+            tail = ring.len();
+        } else {
+            ring.extend_from_slice(&self.data[begin..]);
+            tail = ring.len();
+            ring.extend_from_slice(&self.data[EVT_FILE_HEADER_SIZE..end]);
+        }
+        let to_file_offset = |at: usize| match at < tail {
+            true => begin + at,
+            false => EVT_FILE_HEADER_SIZE + at - tail,
+        };
+        let (records, failed) = walk(&ring);
+
+        (
+            records
+                .into_iter()
+                .map(|(at, record)| (to_file_offset(at), record))
+                .collect(),
+            failed
+                .into_iter()
+                .map(to_file_offset)
+                .collect(),
+        )
+    }
+
+    /// Read the records.
+    ///
+    /// The live records are returned oldest to newest. In recovery mode, the
+    /// records found elsewhere in the file follow.
+    pub fn records(&mut self) -> Vec<EvtRecord> {
+        defn!();
+        let (live, failed) = self.walk_live();
+        self.records_dropped = failed.len() as Count;
+        if !self.recover {
+            defx!("return {} records", live.len());
+            return live
+                .into_iter()
+                .map(|(_at, record)| record)
+                .collect();
+        }
+
+        let mut seen: HashSet<(u32, u32)> = HashSet::with_capacity(live.len());
+        let mut read_at: HashSet<usize> = HashSet::with_capacity(live.len());
+        let mut records: Vec<EvtRecord> = Vec::with_capacity(live.len());
+        for (at, record) in live.into_iter() {
+            seen.insert((record.record_number, record.time_generated));
+            read_at.insert(at);
+            records.push(record);
+        }
+        let skip: usize = std::cmp::min(EVT_FILE_HEADER_SIZE, self.data.len());
+        let (found, failed_all) = walk(&self.data[skip..]);
+        for (_at, record) in found.into_iter() {
+            if seen.insert((record.record_number, record.time_generated)) {
+                self.records_recovered += 1;
+                records.push(record);
+            }
+        }
+        // a live record that wraps around the end of the file is split in the
+        // file so do not count it
+        self.records_dropped += failed_all
+            .into_iter()
+            .map(|at| at + skip)
+            .filter(|at| !read_at.contains(at) && !failed.contains(at))
+            .count() as Count;
+        defx!("return {} records", records.len());
+
+        records
+    }
+}
@@ -9,10 +9,15 @@
 //!
 //! Implements [Issue #87] and [Issue #86].
 //!
+//! Also reads legacy [Windows Event Log `.evt` format] files using an
+//! [`EvtParser`].
+//!
 //! [`EvtxReader`]: self::EvtxReader
 //! [`Etmpx`s]: crate::data::evtx::Evtx
 //! [`EvtxParser`]: https://docs.rs/evtx/0.8.1/evtx/struct.EvtxParser.html
 //! [Windows Event Log `.evtx` format]: https://github.com/libyal/libevtx/blob/main/documentation/Windows%20XML%20Event%20Log%20(EVTX).asciidoc
+//! [Windows Event Log `.evt` format]: https://github.com/libyal/libevt/blob/main/documentation/Windows%20Event%20Log%20(EVT)%20format.asciidoc
+//! [`EvtParser`]: crate::readers::evtparser::EvtParser
 //! [`SyslogProcessor`]: crate::readers::syslogprocessor::SyslogProcessor
 //! [Issue #86]: https://github.com/jtmoon79/super-speedy-syslog-searcher/issues/86
 //! [Issue #87]: https://github.com/jtmoon79/super-speedy-syslog-searcher/issues/87
@@ -21,10 +26,10 @@ use std::collections::BTreeMap;
 use std::fmt;
 use std::io::{
     Error,
-    ErrorKind,
     Result,
 };
 use std::path::Path;
+use std::sync::Arc;
 
 use ::evtx::{
     EvtxParser,
@@ -78,12 +83,21 @@ use crate::data::datetime::{
 };
 use crate::data::evtx::{
     Evtx,
+    EvtxFilter,
+    EvtxOutput,
+    ResultEvtxRSOpt,
     Timestamp,
     TimestampOpt,
+    evtxrecord_to_evtxrs,
     timestamp_to_datetimelopt,
     datetimelopt_to_timestampopt,
 };
 use crate::de_err;
+use crate::readers::evtparser::{
+    evt_channel,
+    EvtParser,
+};
+use crate::readers::evtxrecovery::EvtxRecovery;
 use crate::readers::filedecompressor::decompress_to_ntf;
 use crate::readers::filehandlemanager::{
     FileHandleManaged,
@@ -183,9 +197,10 @@ pub fn ts_pass_filters(
 /// [Issue #86]: https://github.com/jtmoon79/super-speedy-syslog-searcher/issues/86
 pub struct EvtxReader {
     /// The internal [`EvtxParser`] that does the heavy lifting.
+    /// `None` in recovery mode or for a `.evt` file.
     ///
     /// [`EvtxParser`]: https://docs.rs/evtx/0.8.1/evtx/struct.EvtxParser.html
-    evtxparser: EvtxParser<FileHandleManaged>,
+    evtxparser: Option<EvtxParser<FileHandleManaged>>,
     /// The [`Evtx`]s read from the file, sorted by timestamp and then by
     /// enumeration order.
     events: Events,
@@ -197,6 +212,14 @@ pub struct EvtxReader {
     path_id: PathId,
     /// The `FixedOffset` to use for converting `Timestamp`s to `DateTimeL`s.
     fixed_offset: FixedOffset,
+    /// The format of the printed [`Evtx`]s.
+    evtx_output: EvtxOutput,
+    /// Recovery mode; carve records with an [`EvtxRecovery`] instead of
+    /// using the [`EvtxParser`]. For a `.evt` file, the [`EvtParser`] also
+    /// reads records outside of the live records.
+    ///
+    /// [`EvtxParser`]: https://docs.rs/evtx/0.8.1/evtx/struct.EvtxParser.html
+    evtx_recover: bool,
     /// If necessary, the extracted evtx file as a temporary file.
     named_temp_file: Option<TempPath>,
     /// Summary statistic.
@@ -206,6 +229,9 @@ pub struct EvtxReader {
     //pub(super) events_processed: Box<Count>,
     pub(crate) events_processed: Count,
     /// Summary statistic.
+    /// `Count` of records rejected by the [`EvtxFilter`].
+    pub(crate) events_filtered: Count,
+    /// Summary statistic.
     /// `Count` of [`Evtx`s] accepted by the datetime filters.
     ///
     /// [`Evtx`s]: crate::data::evtx::Evtx
@@ -237,6 +263,15 @@ pub struct EvtxReader {
     /// Summary statistic.
     /// Count of EVTX entries found to be out of order.
     out_of_order: Count,
+    /// Summary statistic.
+    /// Count of damaged chunks found in recovery mode.
+    pub(crate) chunks_damaged: Count,
+    /// Summary statistic.
+    /// Count of records carved in recovery mode.
+    pub(crate) records_recovered: Count,
+    /// Summary statistic.
+    /// Count of records that could not be parsed in recovery mode.
+    pub(crate) records_dropped: Count,
     /// has `self.analyze()` been called?
     analyzed: bool,
     /// The last [`Error`], if any, as a `String`
@@ -269,6 +304,7 @@ impl fmt::Debug for EvtxReader {
 #[derive(Clone, Default, Eq, PartialEq, Debug)]
 pub struct SummaryEvtxReader {
     pub evtxreader_events_processed: Count,
+    pub evtxreader_events_filtered: Count,
     pub evtxreader_events_accepted: Count,
     pub evtxreader_event_largest_processed: Count,
     pub evtxreader_event_largest_accepted: Count,
@@ -282,21 +318,35 @@ pub struct SummaryEvtxReader {
     pub evtxreader_datetime_last_accepted: DateTimeLOpt,
     pub evtxreader_filesz: FileSz,
     pub evtxreader_out_of_order: Count,
+    /// damaged chunks found in recovery mode
+    pub evtxreader_chunks_damaged: Count,
+    /// records carved in recovery mode
+    pub evtxreader_records_recovered: Count,
+    /// records that could not be parsed in recovery mode
+    pub evtxreader_records_dropped: Count,
 }
 
 /// Implement the EvtxReader.
 impl EvtxReader {
     /// Create a new `EvtxReader`.
     ///
+    /// If `evtx_recover` then records are carved from the file by an
+    /// [`EvtxRecovery`]. The file header is not read.
+    ///
+    /// If `filetype` is [`FileType::Evt`] then records are read by an
+    /// [`EvtParser`] during `analyze`.
+    ///
     /// **NOTE:** should not attempt any file reads here, similar to other
     /// `*Readers::new()`
     pub fn new(
         path_id: PathId,
         path: FPath,
+        evtx_output: EvtxOutput,
+        evtx_recover: bool,
         filetype: FileType,
         fixed_offset: FixedOffset,
     ) -> Result<EvtxReader> {
-        def1n!("({}, {:?}, {:?})", path_id, path, filetype);
+        def1n!("({}, {:?}, {:?}, {:?}, {:?})", path_id, path, evtx_output, evtx_recover, filetype);
 
         let path_std: &Path = Path::new(&path);
         let named_temp_file: Option<TempPath>;
@@ -361,12 +411,16 @@ impl EvtxReader {
         def1o!("filesz {:?}", filesz);
 
         // create the EvtxParser
-        let settings = ParserSettings::default().num_threads(0);
-        def1o!("EvtxParser::from_read_seek({:?})", path_actual);
-        let evtxparser: EvtxParser<FileHandleManaged> = match EvtxParser::from_read_seek(file) {
-            Ok(evtxparser) => evtxparser.with_configuration(settings),
-            Err(err) => {
-                return Err(Error::new(ErrorKind::Other, format!("EvtxParser::from_read_seek({:?}): {}", path_actual, err)));
+        let evtxparser: Option<EvtxParser<FileHandleManaged>> = match evtx_recover || filetype.is_evt() {
+            true => None,
+            false => {
+                def1o!("EvtxParser::from_read_seek({:?})", path_actual);
+                match EvtxParser::from_read_seek(file) {
+                    Ok(evtxparser) => Some(evtxparser.with_configuration(Self::parser_settings())),
+                    Err(err) => {
+                        return Err(Error::other(format!("EvtxParser::from_read_seek({:?}): {}", path_actual, err)));
+                    }
+                }
             }
         };
         def1x!("return Ok(EvtxReader)");
@@ -377,8 +431,11 @@ impl EvtxReader {
             path,
             path_id,
             fixed_offset,
+            evtx_output,
+            evtx_recover,
             named_temp_file,
             events_processed: 0,
+            events_filtered: 0,
             events_accepted: 0,
             event_largest_processed: 0,
             event_largest_accepted: 0,
@@ -390,6 +447,9 @@ impl EvtxReader {
             filesz,
             mtime,
             out_of_order: 0,
+            chunks_damaged: 0,
+            records_recovered: 0,
+            records_dropped: 0,
             analyzed: false,
             error: None,
         })
@@ -416,18 +476,69 @@ impl EvtxReader {
         &mut self,
         dt_filter_after: &DateTimeLOpt,
         dt_filter_before: &DateTimeLOpt,
+        evtx_filter: &EvtxFilter,
     ) {
-        defn!("({:?}, {:?})", dt_filter_after, dt_filter_before);
+        defn!("({:?}, {:?}, {:?})", dt_filter_after, dt_filter_before, evtx_filter);
         let ts_filter_after: TimestampOpt = datetimelopt_to_timestampopt(dt_filter_after);
         let ts_filter_before: TimestampOpt = datetimelopt_to_timestampopt(dt_filter_before);
         let mut timestamp_last: TimestampOpt = TimestampOpt::None;
-        for (index, result) in self
-            .evtxparser
-            .records()
-            .enumerate()
-        {
+        let evtx_output: EvtxOutput = self.evtx_output;
+        let evtx_filter: Arc<EvtxFilter> = Arc::new(evtx_filter.clone());
+        let records: Box<dyn Iterator<Item = ResultEvtxRSOpt> + '_> = match self.evtxparser.as_mut() {
+            None if self.filetype.is_evt() => {
+                let path_actual: &Path = match self.named_temp_file {
+                    Some(ref ntf) => ntf.as_ref(),
+                    None => Path::new(&self.path),
+                };
+                match EvtParser::open(path_actual, self.evtx_recover) {
+                    Ok(mut evtparser) => {
+                        let channel: String = evt_channel(&self.path);
+                        let records = evtparser.records();
+                        self.records_recovered = evtparser.records_recovered;
+                        self.records_dropped = evtparser.records_dropped;
+                        Box::new(records.into_iter().map(move |record| {
+                            Ok(record.to_evtxrs(&channel, evtx_output, &evtx_filter))
+                        }))
+                    }
+                    Err(err) => {
+                        self.error = Some(err.to_string());
+                        Box::new(std::iter::empty())
+                    }
+                }
+            }
+            Some(evtxparser) => Box::new(evtxparser.serialized_records(move |result| {
+                result.and_then(|record| evtxrecord_to_evtxrs(record, evtx_output, &evtx_filter))
+            })),
+            None => {
+                let path_actual: &Path = match self.named_temp_file {
+                    Some(ref ntf) => ntf.as_ref(),
+                    None => Path::new(&self.path),
+                };
+                match EvtxRecovery::open(path_actual) {
+                    Ok(mut evtxrecovery) => {
+                        let records = evtxrecovery.records(
+                            Arc::new(Self::parser_settings()),
+                            |record| evtxrecord_to_evtxrs(record, evtx_output, &evtx_filter),
+                        );
+                        self.chunks_damaged = evtxrecovery.chunks_damaged;
+                        self.records_recovered = evtxrecovery.records_recovered;
+                        self.records_dropped = evtxrecovery.records_dropped;
+                        Box::new(records.into_iter())
+                    }
+                    Err(err) => {
+                        self.error = Some(err.to_string());
+                        Box::new(std::iter::empty())
+                    }
+                }
+            }
+        };
+        for (index, result) in records.enumerate() {
             match result {
-                Ok(record) => {
+                Ok(None) => {
+                    defo!("rejected by evtx_filter");
+                    summary_stat!(self.events_filtered += 1);
+                }
+                Ok(Some(record)) => {
                     let timestamp: Timestamp = record.timestamp;
                     let record_data_len: Count = record.data.len() as Count;
 
@@ -494,6 +605,7 @@ impl EvtxReader {
                         Some(datetime_.clone()),
                         &self.fixed_offset,
                         record,
+                        evtx_output,
                     );
                     let key: EventsKey = (datetime_, index);
                     if let Some(e) = self.events.insert(key, evtx) {
@@ -552,6 +664,25 @@ impl EvtxReader {
         self.filetype
     }
 
+    #[inline(always)]
+    pub const fn evtx_output(&self) -> EvtxOutput {
+        self.evtx_output
+    }
+
+    #[inline(always)]
+    pub const fn evtx_recover(&self) -> bool {
+        self.evtx_recover
+    }
+
+    /// The [`ParserSettings`] for both the [`EvtxParser`] and the
+    /// [`EvtxRecovery`].
+    ///
+    /// [`ParserSettings`]: https://docs.rs/evtx/0.8.1/evtx/struct.ParserSettings.html
+    /// [`EvtxParser`]: https://docs.rs/evtx/0.8.1/evtx/struct.EvtxParser.html
+    fn parser_settings() -> ParserSettings {
+        ParserSettings::default().num_threads(0)
+    }
+
     #[inline(always)]
     pub const fn filesz(&self) -> FileSz {
         self.filesz
@@ -600,6 +731,7 @@ impl EvtxReader {
     #[allow(non_snake_case)]
     pub fn summary(&self) -> SummaryEvtxReader {
         let evtxreader_events_processed: Count = self.events_processed;
+        let evtxreader_events_filtered: Count = self.events_filtered;
         let evtxreader_events_accepted: Count = self.events_accepted;
         let evtxreader_event_largest_processed: Count = self.event_largest_processed;
         let evtxreader_event_largest_accepted: Count = self.event_largest_accepted;
@@ -609,9 +741,13 @@ impl EvtxReader {
         let evtxreader_datetime_last_accepted = self.dt_last_accepted();
         let evtxreader_filesz = self.filesz();
         let evtxreader_out_of_order = self.out_of_order;
+        let evtxreader_chunks_damaged = self.chunks_damaged;
+        let evtxreader_records_recovered = self.records_recovered;
+        let evtxreader_records_dropped = self.records_dropped;
 
         SummaryEvtxReader {
             evtxreader_events_processed,
+            evtxreader_events_filtered,
             evtxreader_events_accepted,
             evtxreader_event_largest_processed,
             evtxreader_event_largest_accepted,
@@ -621,6 +757,9 @@ impl EvtxReader {
             evtxreader_datetime_last_accepted,
             evtxreader_filesz,
             evtxreader_out_of_order,
+            evtxreader_chunks_damaged,
+            evtxreader_records_recovered,
+            evtxreader_records_dropped,
         }
     }
 
@@ -0,0 +1,519 @@
+// src/readers/evtxrecovery.rs
+
+//! Implements an [`EvtxRecovery`], a carver of records from damaged
+//! [evtx format files].
+//!
+//! The `EvtxRecovery` is used by an [`EvtxReader`] in recovery mode. Crate
+//! `evtx` [`EvtxParser`] requires a valid file header, reads chunks only at
+//! the offsets declared by the file header, stops reading a chunk at the first
+//! damaged record, and ignores the chunk slack space past the chunk
+//! `free_space_offset`. Logs pulled from compromised or crashed systems
+//! commonly have dirty file headers, damaged chunks, or truncated chunks.
+//!
+//! The `EvtxRecovery` does not use the file header. It reads the entire file
+//! and
+//! 1. scans for `ElfChnk` chunk signatures at any offset,
+//! 2. for each chunk with valid checksums, reads the records as `evtx` would,
+//! 3. scans each chunk, including chunks with bad checksums, truncated
+//!    chunks and chunk slack space, for record signatures `**\0\0` that were
+//!    not already read,
+//! 4. scans the rest of the file for orphaned record signatures `**\0\0`
+//!    outside of any chunk.
+//!
+//! The record size and the trailing copy of the record size are checked
+//! before a record is parsed. Records found more than once, e.g. an old copy
+//! of a record in chunk slack space, are returned once.
+//!
+//! [evtx format files]: https://github.com/libyal/libevtx/blob/main/documentation/Windows%20XML%20Event%20Log%20(EVTX).asciidoc
+//! [`EvtxReader`]: crate::readers::evtxreader::EvtxReader
+//! [`EvtxParser`]: https://docs.rs/evtx/0.8.1/evtx/struct.EvtxParser.html
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{
+    Read,
+    Result,
+};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+
+use ::evtx::{
+    EvtxChunk,
+    EvtxChunkData,
+    EvtxChunkHeader,
+    EvtxRecord,
+    EvtxRecordHeader,
+    ParserSettings,
+};
+use ::memchr::memmem;
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    def1n,
+    def1o,
+    def1x,
+    def1ñ,
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+
+use crate::common::Count;
+use crate::data::evtx::{
+    EvtxError,
+    RecordId,
+};
+
+/// Size of the evtx file header in bytes.
+pub const EVTX_FILE_HEADER_SIZE: usize = 4096;
+/// Size of an evtx chunk in bytes.
+pub const EVTX_CHUNK_SIZE: usize = 65536;
+/// Size of an evtx chunk header in bytes. The first record of a chunk is at
+/// this offset.
+pub const EVTX_CHUNK_HEADER_SIZE: usize = 512;
+/// Size of an evtx record header in bytes.
+pub const EVTX_RECORD_HEADER_SIZE: usize = 24;
+/// Size of the trailing copy of the record size in bytes.
+const EVTX_RECORD_SIZE_COPY_SIZE: usize = 4;
+/// evtx chunk signature, first eight bytes of a chunk.
+pub const EVTX_CHUNK_SIGNATURE: &[u8; 8] = b"ElfChnk\x00";
+/// evtx record signature, first four bytes of a record.
+pub const EVTX_RECORD_SIGNATURE: &[u8; 4] = b"\x2a\x2a\x00\x00";
+/// BinXML `EndOfStream` token.
+const BINXML_END_OF_STREAM: u8 = 0x00;
+
+/// Identifies a record; the `RecordId` and the record timestamp in
+/// nanoseconds.
+type RecordKey = (RecordId, i128);
+
+/// A `Vec` of `Result` from the caller's record conversion function.
+pub type RecoveredRecords<U> = Vec<std::result::Result<U, EvtxError>>;
+
+/// Carves records from a damaged evtx file. See the module documentation.
+pub struct EvtxRecovery {
+    /// The entire evtx file.
+    data: Vec<u8>,
+    /// Offsets of `ElfChnk` chunk signatures.
+    chunk_offsets: Vec<usize>,
+    /// Offsets of `**\0\0` record signatures.
+    record_offsets: Vec<usize>,
+    /// `Count` of chunks with valid checksums.
+    pub(crate) chunks_valid: Count,
+    /// `Count` of chunks with bad checksums or that are truncated, and of
+    /// chunk-sized areas with orphaned records.
+    pub(crate) chunks_damaged: Count,
+    /// `Count` of records carved from damaged chunks, chunk slack space, or
+    /// orphaned areas.
+    pub(crate) records_recovered: Count,
+    /// `Count` of records with a valid record header that could not be
+    /// parsed.
+    pub(crate) records_dropped: Count,
+}
+
+impl fmt::Debug for EvtxRecovery {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        f.debug_struct("EvtxRecovery")
+            .field("filesz", &self.data.len())
+            .field("chunk_offsets", &self.chunk_offsets.len())
+            .field("record_offsets", &self.record_offsets.len())
+            .field("chunks_valid", &self.chunks_valid)
+            .field("chunks_damaged", &self.chunks_damaged)
+            .field("records_recovered", &self.records_recovered)
+            .field("records_dropped", &self.records_dropped)
+            .finish()
+    }
+}
+
+/// Return the size of the record at `offset` within `data` if the record
+/// header is valid and the trailing copy of the record size matches.
+pub fn record_size_at(
+    data: &[u8],
+    offset: usize,
+) -> Option<usize> {
+    let header: EvtxRecordHeader = EvtxRecordHeader::from_bytes_at(data, offset).ok()?;
+    let size: usize = header.data_size as usize;
+    if size < EVTX_RECORD_HEADER_SIZE + EVTX_RECORD_SIZE_COPY_SIZE {
+        return None;
+    }
+    let end: usize = offset.checked_add(size)?;
+    if end > data.len() {
+        return None;
+    }
+    let copy: [u8; 4] = data[end - EVTX_RECORD_SIZE_COPY_SIZE..end].try_into().ok()?;
+    if u32::from_le_bytes(copy) as usize != size {
+        return None;
+    }
+
+    Some(size)
+}
+
+/// Is `offset` within any of the `(begin, end)` ranges?
+fn is_covered(
+    ranges: &[(usize, usize)],
+    offset: usize,
+) -> bool {
+    ranges
+        .iter()
+        .any(|(b, e)| *b <= offset && offset < *e)
+}
+
+/// Offsets of the chunk header string and template tables.
+const EVTX_CHUNK_TABLES: Range<usize> = 128..EVTX_CHUNK_HEADER_SIZE;
+
+/// Offsets of the placeholder record written at the first record offset by
+/// [`ChunkArea::carve_record`]; the record header and an `EndOfStream` token.
+const EVTX_PLACEHOLDER_RECORD: Range<usize> = EVTX_CHUNK_HEADER_SIZE..EVTX_CHUNK_HEADER_SIZE + EVTX_RECORD_HEADER_SIZE + 1;
+
+/// Does the chunk `data` build a string cache?
+/// A damaged chunk header may have bad string offsets.
+fn chunk_parses(
+    data: &[u8],
+    settings: &Arc<ParserSettings>,
+) -> bool {
+    match EvtxChunkHeader::from_bytes(data) {
+        Ok(header) => EvtxChunk::new(data, &header, Arc::clone(settings)).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// A chunk, or a chunk-sized area of the file, from which records are
+/// carved.
+struct ChunkArea {
+    /// File offset of the chunk, i.e. the file offset of chunk offset 0.
+    offset: usize,
+    /// Chunk bytes. May be shorter than [`EVTX_CHUNK_SIZE`].
+    data: Vec<u8>,
+    /// File offsets of the record signatures to carve.
+    scan: Range<usize>,
+}
+
+impl ChunkArea {
+    /// Parse the record at chunk offset `offset` with size `size` and pass
+    /// it to `f`. Return `None` if the record could not be parsed.
+    ///
+    /// Crate `evtx` only walks records from the first record of a chunk.
+    /// To start at `offset` the record is parsed from `scratch`, a copy of
+    /// the chunk, with a placeholder record at the first record offset that
+    /// spans up to `offset`.
+    /// The placeholder record overwrites only the first record header which is
+    /// never referenced by other records. Only those bytes of `scratch` differ
+    /// from the chunk so one `scratch` serves every record of the chunk.
+    fn carve_record<U, F>(
+        &self,
+        scratch: &mut [u8],
+        offset: usize,
+        size: usize,
+        settings: &Arc<ParserSettings>,
+        f: &mut F,
+    ) -> Option<std::result::Result<U, EvtxError>>
+    where
+        F: FnMut(EvtxRecord<'_>) -> std::result::Result<U, EvtxError>,
+    {
+        let record_header: EvtxRecordHeader = EvtxRecordHeader::from_bytes_at(&self.data, offset).ok()?;
+        let placeholder: bool = offset > EVTX_CHUNK_HEADER_SIZE;
+        if placeholder {
+            let size_pl: usize = offset - EVTX_CHUNK_HEADER_SIZE;
+            if size_pl < EVTX_RECORD_HEADER_SIZE + EVTX_RECORD_SIZE_COPY_SIZE {
+                return None;
+            }
+            let at: usize = EVTX_CHUNK_HEADER_SIZE;
+            scratch[at..at + 4].copy_from_slice(EVTX_RECORD_SIGNATURE);
+            scratch[at + 4..at + 8].copy_from_slice(&(size_pl as u32).to_le_bytes());
+            scratch[at + 8..at + 16].copy_from_slice(&record_header.event_record_id.wrapping_add(1).to_le_bytes());
+            scratch[at + 16..at + 24].copy_from_slice(&self.data[offset + 16..offset + 24]);
+            scratch[at + EVTX_RECORD_HEADER_SIZE] = BINXML_END_OF_STREAM;
+        } else {
+            // the record is the first record; undo a previous placeholder
+            scratch[EVTX_PLACEHOLDER_RECORD].copy_from_slice(&self.data[EVTX_PLACEHOLDER_RECORD]);
+        }
+        let mut header: EvtxChunkHeader = EvtxChunkHeader::from_bytes(scratch).ok()?;
+        header.free_space_offset = (offset + size) as u32;
+        header.last_event_record_id = record_header.event_record_id;
+        let mut chunk: EvtxChunk = EvtxChunk::new(scratch, &header, Arc::clone(settings)).ok()?;
+        let mut records = chunk.iter();
+        if placeholder {
+            // the placeholder record may or may not parse, it is discarded
+            records.next();
+        }
+        match records.next()? {
+            Ok(record) => {
+                if record.event_record_id != record_header.event_record_id {
+                    return None;
+                }
+                Some(f(record))
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+impl EvtxRecovery {
+    /// Read the evtx file at `path` and find all chunk and record
+    /// signatures.
+    pub fn open(path: &Path) -> Result<EvtxRecovery> {
+        def1n!("({:?})", path);
+        let mut data: Vec<u8> = Vec::new();
+        let mut file = std::fs::File::open(path)?;
+        file.read_to_end(&mut data)?;
+        let er = EvtxRecovery::from_vec(data);
+        def1x!("{:?}", er);
+
+        Ok(er)
+    }
+
+    /// Find all chunk and record signatures within `data`.
+    pub fn from_vec(data: Vec<u8>) -> EvtxRecovery {
+        let chunk_offsets: Vec<usize> = memmem::find_iter(&data, EVTX_CHUNK_SIGNATURE).collect();
+        let record_offsets: Vec<usize> = memmem::find_iter(&data, EVTX_RECORD_SIGNATURE).collect();
+
+        EvtxRecovery {
+            data,
+            chunk_offsets,
+            record_offsets,
+            chunks_valid: 0,
+            chunks_damaged: 0,
+            records_recovered: 0,
+            records_dropped: 0,
+        }
+    }
+
+    /// Return the chunk at file `offset`, if the chunk header can be read.
+    /// The returned `bool` is `true` if the chunk is complete and the chunk
+    /// checksums are valid.
+    fn chunk_at(
+        &self,
+        offset: usize,
+    ) -> Option<(ChunkArea, bool)> {
+        let end: usize = std::cmp::min(offset + EVTX_CHUNK_SIZE, self.data.len());
+        let data: Vec<u8> = self.data[offset..end].to_vec();
+        let chunkdata: EvtxChunkData = EvtxChunkData::new(data, false).ok()?;
+        let free_space_offset: usize = chunkdata.header.free_space_offset as usize;
+        let valid: bool = chunkdata.data.len() == EVTX_CHUNK_SIZE
+            && (EVTX_CHUNK_HEADER_SIZE..=EVTX_CHUNK_SIZE).contains(&free_space_offset)
+            && chunkdata.validate_checksum();
+
+        let scan: Range<usize> = offset + EVTX_CHUNK_HEADER_SIZE..end;
+
+        Some((ChunkArea { offset, data: chunkdata.data, scan }, valid))
+    }
+
+    /// Return a chunk-sized area beginning at file `offset` with a
+    /// placeholder chunk header. Used for orphaned records that are not
+    /// within any chunk.
+    ///
+    /// The area keeps the chunk offsets of a chunk at `offset` that lost its
+    /// chunk signature, so records may still refer to templates earlier in
+    /// that chunk. The placeholder chunk header replaces the first
+    /// [`EVTX_CHUNK_HEADER_SIZE`] bytes; records there are carved from the
+    /// [`orphan_head_area_at`] area.
+    ///
+    /// [`orphan_head_area_at`]: EvtxRecovery::orphan_head_area_at
+    fn orphan_area_at(
+        &self,
+        offset: usize,
+    ) -> Option<ChunkArea> {
+        let end: usize = std::cmp::min(offset + EVTX_CHUNK_SIZE, self.data.len());
+        if end - offset < EVTX_CHUNK_HEADER_SIZE {
+            return None;
+        }
+        let mut data: Vec<u8> = self.data[offset..end].to_vec();
+        data[..EVTX_CHUNK_HEADER_SIZE].fill(0);
+        data[..EVTX_CHUNK_SIGNATURE.len()].copy_from_slice(EVTX_CHUNK_SIGNATURE);
+        let scan: Range<usize> = offset + EVTX_CHUNK_HEADER_SIZE..end;
+
+        Some(ChunkArea { offset, data, scan })
+    }
+
+    /// Return an area for the orphaned records within the first
+    /// [`EVTX_CHUNK_HEADER_SIZE`] bytes at file `offset`. There is no valid
+    /// chunk header at `offset` so a placeholder chunk header is placed
+    /// before `offset` and the records are carved from the start of the area.
+    fn orphan_head_area_at(
+        &self,
+        offset: usize,
+    ) -> Option<ChunkArea> {
+        let end: usize = std::cmp::min(offset + EVTX_CHUNK_SIZE - EVTX_CHUNK_HEADER_SIZE, self.data.len());
+        if end <= offset {
+            return None;
+        }
+        let mut data: Vec<u8> = vec![0; EVTX_CHUNK_HEADER_SIZE];
+        data[..EVTX_CHUNK_SIGNATURE.len()].copy_from_slice(EVTX_CHUNK_SIGNATURE);
+        data.extend_from_slice(&self.data[offset..end]);
+        let scan: Range<usize> = offset..std::cmp::min(offset + EVTX_CHUNK_HEADER_SIZE, end);
+
+        Some(ChunkArea { offset: offset - EVTX_CHUNK_HEADER_SIZE, data, scan })
+    }
+
+    /// Carve every record signature within `area.scan` that is not within
+    /// `covered` chunk offset ranges. Records that could not be parsed are
+    /// added to `dropped`.
+    #[allow(clippy::too_many_arguments)]
+    fn carve_area<U, F>(
+        &mut self,
+        area: &mut ChunkArea,
+        covered: &mut Vec<(usize, usize)>,
+        seen: &mut HashSet<RecordKey>,
+        dropped: &mut HashSet<RecordKey>,
+        settings: &Arc<ParserSettings>,
+        f: &mut F,
+        records: &mut RecoveredRecords<U>,
+    ) where
+        F: FnMut(EvtxRecord<'_>) -> std::result::Result<U, EvtxError>,
+    {
+        if !chunk_parses(&area.data, settings) {
+            defo!("chunk at 0x{:08X} has bad string offsets", area.offset);
+            area.data[EVTX_CHUNK_TABLES].fill(0);
+        }
+        let mut scratch: Vec<u8> = area.data.clone();
+        let beg: usize = self.record_offsets.partition_point(|o| *o < area.scan.start);
+        let end: usize = self.record_offsets.partition_point(|o| *o < area.scan.end);
+        for at in self.record_offsets[beg..end].iter().copied() {
+            let offset: usize = at - area.offset;
+            if is_covered(covered, offset) {
+                continue;
+            }
+            let size: usize = match record_size_at(&area.data, offset) {
+                Some(size) => size,
+                // a signature-like sequence within other data
+                None => continue,
+            };
+            let header: EvtxRecordHeader = match EvtxRecordHeader::from_bytes_at(&area.data, offset) {
+                Ok(header) => header,
+                Err(_) => continue,
+            };
+            let key = (header.event_record_id, header.timestamp.as_nanosecond());
+            if seen.contains(&key) {
+                defo!("record {} at 0x{:08X} already read", header.event_record_id, at);
+                covered.push((offset, offset + size));
+                continue;
+            }
+            match area.carve_record(&mut scratch, offset, size, settings, f) {
+                Some(Ok(u)) => {
+                    defo!("recovered record {} at 0x{:08X}", header.event_record_id, at);
+                    seen.insert(key);
+                    covered.push((offset, offset + size));
+                    records.push(Ok(u));
+                    self.records_recovered += 1;
+                }
+                Some(Err(_err)) => {
+                    defo!("dropped record {} at 0x{:08X}; {}", header.event_record_id, at, _err);
+                    dropped.insert(key);
+                }
+                None => {
+                    defo!("dropped record {} at 0x{:08X}", header.event_record_id, at);
+                    dropped.insert(key);
+                }
+            }
+        }
+    }
+
+    /// Return every record that can be recovered from the file, each passed
+    /// through `f`.
+    ///
+    /// `f` is like the function passed to [`EvtxParser::serialized_records`].
+    ///
+    /// [`EvtxParser::serialized_records`]: https://docs.rs/evtx/0.8.1/evtx/struct.EvtxParser.html#method.serialized_records
+    pub fn records<U, F>(
+        &mut self,
+        settings: Arc<ParserSettings>,
+        mut f: F,
+    ) -> RecoveredRecords<U>
+    where
+        F: FnMut(EvtxRecord<'_>) -> std::result::Result<U, EvtxError>,
+    {
+        defn!();
+        let mut records: RecoveredRecords<U> = RecoveredRecords::new();
+        let mut seen: HashSet<RecordKey> = HashSet::new();
+        // a record may fail to parse from stale chunk slack space yet be
+        // recovered elsewhere, so dropped records are counted last
+        let mut dropped: HashSet<RecordKey> = HashSet::new();
+        // file offset ranges of all chunks
+        let mut chunk_ranges: Vec<(usize, usize)> = Vec::new();
+
+        for chunk_offset in self.chunk_offsets.clone().into_iter() {
+            let (mut area, valid) = match self.chunk_at(chunk_offset) {
+                Some(val) => val,
+                None => {
+                    defo!("chunk at 0x{:08X} header is truncated", chunk_offset);
+                    self.chunks_damaged += 1;
+                    continue;
+                }
+            };
+            chunk_ranges.push((chunk_offset, chunk_offset + area.data.len()));
+            // chunk offset ranges of the records read
+            let mut covered: Vec<(usize, usize)> = Vec::new();
+            if valid {
+                defo!("chunk at 0x{:08X} is valid", chunk_offset);
+                self.chunks_valid += 1;
+                if let Ok(mut chunkdata) = EvtxChunkData::new(area.data.clone(), false)
+                    && let Ok(mut chunk) = chunkdata.parse(Arc::clone(&settings))
+                {
+                    for result in chunk.iter() {
+                        match result {
+                            Ok(record) => {
+                                let offset: usize = record.binxml_offset as usize - EVTX_RECORD_HEADER_SIZE;
+                                let size: usize = record.binxml_size as usize
+                                    + EVTX_RECORD_HEADER_SIZE
+                                    + EVTX_RECORD_SIZE_COPY_SIZE;
+                                covered.push((offset, offset + size));
+                                let key = (record.event_record_id, record.timestamp.as_nanosecond());
+                                if !seen.insert(key) {
+                                    continue;
+                                }
+                                records.push(f(record));
+                            }
+                            Err(_err) => {
+                                defo!("chunk at 0x{:08X}; {}", chunk_offset, _err);
+                            }
+                        }
+                    }
+                }
+            } else {
+                defo!("chunk at 0x{:08X} is damaged", chunk_offset);
+                self.chunks_damaged += 1;
+            }
+            self.carve_area(&mut area, &mut covered, &mut seen, &mut dropped, &settings, &mut f, &mut records);
+        }
+
+        // orphaned records, grouped by the chunk-sized area in which they
+        // would be found
+        let orphans: Vec<usize> = self
+            .record_offsets
+            .iter()
+            .copied()
+            .filter(|o| *o >= EVTX_FILE_HEADER_SIZE && !is_covered(&chunk_ranges, *o))
+            .map(|o| EVTX_FILE_HEADER_SIZE + (o - EVTX_FILE_HEADER_SIZE) / EVTX_CHUNK_SIZE * EVTX_CHUNK_SIZE)
+            .collect();
+        let mut area_offset_last: Option<usize> = None;
+        for area_offset in orphans.into_iter() {
+            if area_offset_last == Some(area_offset) {
+                continue;
+            }
+            area_offset_last = Some(area_offset);
+            let areas: Vec<ChunkArea> = [self.orphan_area_at(area_offset), self.orphan_head_area_at(area_offset)]
+                .into_iter()
+                .flatten()
+                .collect();
+            if areas.is_empty() {
+                continue;
+            }
+            defo!("orphaned records in area at 0x{:08X}", area_offset);
+            self.chunks_damaged += 1;
+            for mut area in areas.into_iter() {
+                let mut covered: Vec<(usize, usize)> = chunk_ranges
+                    .iter()
+                    .map(|(b, e)| (b.saturating_sub(area.offset), e.saturating_sub(area.offset)))
+                    .collect();
+                self.carve_area(&mut area, &mut covered, &mut seen, &mut dropped, &settings, &mut f, &mut records);
+            }
+        }
+        self.records_dropped = dropped.difference(&seen).count() as Count;
+        defx!("{:?}", self);
+
+        records
+    }
+}
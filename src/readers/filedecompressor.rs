@@ -91,6 +91,7 @@ type Lz4FrameReader = lz4_flex::frame::FrameDecoder<BufReaderLz4>;
 
 const SUFFIX_ASL: &str = ".asl";
 const SUFFIX_ETL: &str = ".etl";
+const SUFFIX_EVT: &str = ".evt";
 const SUFFIX_EVTX: &str = ".evtx";
 const SUFFIX_FIXEDSTRUCT: &str = ".wtmp";
 const SUFFIX_JOURNAL: &str = ".journal";
@@ -100,6 +101,9 @@ const SUFFIX_ODLSENT: &str = ".odlsent";
 const SUFFIX_AODL: &str = ".aodl";
 // XXX: ignore .aold files for now, not sure what these are
 const SUFFIX_TEXT: &str = ".log";
+const SUFFIX_TRACEV3: &str = ".tracev3";
+const SUFFIX_LOGCAT: &str = ".logcat";
+const SUFFIX_PCAP: &str = ".pcap";
 
 /// optional tuple value returned by `decompress_to_ntf()`:
 /// - `TempPath` is the temporary file path
@@ -169,6 +173,11 @@ pub fn decompress_to_ntf(
 
             archival_type
         }
+        FileType::Evt { archival_type } => {
+            suffix = SUFFIX_EVT;
+
+            archival_type
+        }
         FileType::Evtx { archival_type } => {
             suffix = SUFFIX_EVTX;
 
@@ -201,6 +210,21 @@ pub fn decompress_to_ntf(
 
             archival_type
         }
+        FileType::Logcat { archival_type } => {
+            suffix = SUFFIX_LOGCAT;
+
+            archival_type
+        }
+        FileType::Pcap { archival_type } => {
+            suffix = SUFFIX_PCAP;
+
+            archival_type
+        }
+        FileType::Tracev3 { archival_type } => {
+            suffix = SUFFIX_TRACEV3;
+
+            archival_type
+        }
         FileType::Unparsable => {
             debug_panic!("Unexpected {:?}", file_type);
 
@@ -222,75 +246,15 @@ pub fn decompress_to_ntf(
     let fpath: FPath = path_to_fpath(path_std);
     defo!("fpath {:?}", fpath);
 
-    // TODO: [2025/11] handle file names too long for $MAX_PATH because this is
-    //       adding a few chars to the name
-    let mut temp_name: String = match path_std.file_name() {
-        Some(val) => val.to_string_lossy().into_owned(),
-        None => {
-            de_err!("path_std.file_stem() None");
-            String::default()
+    let temp_path: TempPath = match create_temp_path(path_std, suffix) {
+        Ok(val) => val,
+        Err(err) => {
+            defx!("create_temp_path Error, return {:?}", err);
+            return Err(err);
         }
     };
-    temp_name = temp_name.replace(SUBPATH_SEP, "_");
-    temp_name.insert_str(0, "s4-");
-    temp_name.push('-');
-    defo!("tempfile::Builder::new({:?}, {:?})", temp_name, suffix);
-    let temp_path: TempPath = {
-        let _tempfile_create_guard = match (&*TEMPFILE_CREATE_LOCK).lock() {
-            Ok(val) => val,
-            Err(err) => {
-                let ioerr = Error::new(ErrorKind::Other, format!("TEMPFILE_CREATE_LOCK.lock() failed: {:?}", err));
-                defx!("TEMPFILE_CREATE_LOCK.lock() failed, return {:?}", err);
-                return err_from_err_path_result_dtn!(&ioerr, &fpath, Some("TEMPFILE_CREATE_LOCK.lock() failed"));
-            }
-        };
-        let ntf: NamedTempFile = loop {
-            match Builder::new()
-                .prefix(&temp_name)
-                .suffix(suffix)
-                .tempfile()
-            {
-                Ok(val) => break val,
-                Err(err) => {
-                    if is_error_too_many_open_files(&err) && FILE_HANDLE_MANAGER.evict_one() {
-                        continue;
-                    }
-                    defx!("tempfile::Builder::new().tempfile() Error, return {:?}", err);
-                    return err_from_err_path_result_dtn!(&err, &fpath, Some("tempfile::Builder::new() failed"));
-                }
-            }
-        };
-        ntf.into_temp_path()
-    };
     let path_ntf = temp_path.as_ref();
     defo!("path_ntf {:?}", path_ntf);
-    let fpath_ntf = path_to_fpath(path_ntf);
-
-    defo!("NAMED_TEMP_FILES.lock()");
-    match (&*NAMED_TEMP_FILES).write() {
-        Ok(ref mut ntfs) => {
-            let ntfs_mut: &mut ListFPaths = ntfs.borrow_mut();
-            defo!("NAMED_TEMP_FILES.push({:?}) {}", fpath_ntf, ntfs_mut.len());
-            ntfs_mut.push_back(fpath_ntf);
-        }
-        Err(err) => {
-            let ioerr = Error::new(ErrorKind::Other, format!("NAMED_TEMP_FILES.lock() failed: {:?}", err));
-            defx!("NAMED_TEMP_FILES.lock() failed, return {:?}", err);
-            return err_from_err_path_result_dtn!(&ioerr, &fpath, Some("NAMED_TEMP_FILES.lock() failed"));
-        }
-    }
-    defo!("NAMED_TEMP_FILES_COUNT.lock()");
-    match (&*NAMED_TEMP_FILES_COUNT).write() {
-        Ok(ref mut count) => {
-            let count_mut: &mut usize = count.borrow_mut();
-            *count_mut += 1;
-            defo!("NAMED_TEMP_FILES_COUNT {}", *count_mut);
-        }
-        Err(err) => {
-            debug_panic!("NAMED_TEMP_FILES_COUNT.lock() failed: {:?}", err);
-            // this counter is not worth failing over
-        }
-    }
 
     defo!("FILE_HANDLE_MANAGER.request_open_managed({:?})", path_ntf);
     let file_ntf: FileHandleManaged = match FILE_HANDLE_MANAGER.request_open_managed(
@@ -800,6 +764,90 @@ pub fn decompress_to_ntf(
     Ok(Some((temp_path, mtime_opt, file_sz)))
 }
 
+/// Create an empty named temporary file for `path_std` with the `suffix`.
+/// The temporary file is registered for removal by
+/// [`remove_temporary_files`].
+pub(crate) fn create_temp_path(
+    path_std: &Path,
+    suffix: &str,
+) -> Result<TempPath> {
+    defn!("({:?}, {:?})", path_std, suffix);
+    let fpath: FPath = path_to_fpath(path_std);
+
+    // TODO: [2025/11] handle file names too long for $MAX_PATH because this is
+    //       adding a few chars to the name
+    let mut temp_name: String = match path_std.file_name() {
+        Some(val) => val.to_string_lossy().into_owned(),
+        None => {
+            de_err!("path_std.file_stem() None");
+            String::default()
+        }
+    };
+    temp_name = temp_name.replace(SUBPATH_SEP, "_");
+    temp_name.insert_str(0, "s4-");
+    temp_name.push('-');
+    defo!("tempfile::Builder::new({:?}, {:?})", temp_name, suffix);
+    let temp_path: TempPath = {
+        let _tempfile_create_guard = match TEMPFILE_CREATE_LOCK.lock() {
+            Ok(val) => val,
+            Err(err) => {
+                let ioerr = Error::other(format!("TEMPFILE_CREATE_LOCK.lock() failed: {:?}", err));
+                defx!("TEMPFILE_CREATE_LOCK.lock() failed, return {:?}", err);
+                return err_from_err_path_result::<TempPath>(&ioerr, &fpath, Some("TEMPFILE_CREATE_LOCK.lock() failed"));
+            }
+        };
+        let ntf: NamedTempFile = loop {
+            match Builder::new()
+                .prefix(&temp_name)
+                .suffix(suffix)
+                .tempfile()
+            {
+                Ok(val) => break val,
+                Err(err) => {
+                    if is_error_too_many_open_files(&err) && FILE_HANDLE_MANAGER.evict_one() {
+                        continue;
+                    }
+                    defx!("tempfile::Builder::new().tempfile() Error, return {:?}", err);
+                    return err_from_err_path_result::<TempPath>(&err, &fpath, Some("tempfile::Builder::new() failed"));
+                }
+            }
+        };
+        ntf.into_temp_path()
+    };
+    let fpath_ntf = path_to_fpath(temp_path.as_ref());
+    defo!("fpath_ntf {:?}", fpath_ntf);
+
+    defo!("NAMED_TEMP_FILES.lock()");
+    match NAMED_TEMP_FILES.write() {
+        Ok(ref mut ntfs) => {
+            let ntfs_mut: &mut ListFPaths = ntfs.borrow_mut();
+            defo!("NAMED_TEMP_FILES.push({:?}) {}", fpath_ntf, ntfs_mut.len());
+            ntfs_mut.push_back(fpath_ntf);
+        }
+        Err(err) => {
+            let ioerr = Error::other(format!("NAMED_TEMP_FILES.lock() failed: {:?}", err));
+            defx!("NAMED_TEMP_FILES.lock() failed, return {:?}", err);
+            return err_from_err_path_result::<TempPath>(&ioerr, &fpath, Some("NAMED_TEMP_FILES.lock() failed"));
+        }
+    }
+    defo!("NAMED_TEMP_FILES_COUNT.lock()");
+    match NAMED_TEMP_FILES_COUNT.write() {
+        Ok(ref mut count) => {
+            let count_mut: &mut usize = count.borrow_mut();
+            *count_mut += 1;
+            defo!("NAMED_TEMP_FILES_COUNT {}", *count_mut);
+        }
+        Err(err) => {
+            debug_panic!("NAMED_TEMP_FILES_COUNT.lock() failed: {:?}", err);
+            // this counter is not worth failing over
+        }
+    }
+
+    defx!("return {:?}", temp_path);
+
+    Ok(temp_path)
+}
+
 /// remove the named temporary files in the global `NAMED_TEMP_FILES` list.
 /// Returns `true` if all files were removed, `false` if there was an error.
 /// The list is cleared after attempting to remove all files.
@@ -134,13 +134,17 @@ const FILE_HANDLE_UNMANAGED_JOURNAL_COUNT: u8 = 1;
 /// counts needed for the `filetype`.
 pub const fn filetype_handle_counts(filetype: FileType) -> (u8, u8) {
     match filetype {
-        FileType::Asl { .. }
-        | FileType::Etl { .. }
-        | FileType::Odl { .. } => (1, FILE_HANDLE_UNMANAGED_PYRUNNER_COUNT),
+        FileType::Etl { .. } => (1, FILE_HANDLE_UNMANAGED_PYRUNNER_COUNT),
         FileType::Journal { .. } => (1, FILE_HANDLE_UNMANAGED_JOURNAL_COUNT),
-        FileType::Evtx { .. }
+        FileType::Asl { .. }
+        | FileType::Evt { .. }
+        | FileType::Evtx { .. }
         | FileType::FixedStruct { .. }
-        | FileType::Text { .. } => (1, 0),
+        | FileType::Odl { .. }
+        | FileType::Text { .. }
+        | FileType::Logcat { .. }
+        | FileType::Pcap { .. }
+        | FileType::Tracev3 { .. } => (1, 0),
         FileType::Unparsable => (0, 0),
     }
 }
@@ -1216,6 +1220,83 @@ impl FileHandleManaged {
 
         result
     }
+
+    /// Return the file offsets `(data, hole)` of the first region of data at
+    /// or after `offset`, i.e. [`lseek`] with `SEEK_DATA` then `SEEK_HOLE`.
+    /// Returns `None` if there is no data at or after `offset`.
+    ///
+    /// Allows skipping the holes of a sparse file. On platforms without
+    /// `SEEK_DATA`, the remainder of the file is one region of data.
+    ///
+    /// [`lseek`]: https://man7.org/linux/man-pages/man2/lseek.2.html
+    pub fn seek_data(
+        &self,
+        offset: u64,
+    ) -> Result<Option<(u64, u64)>> {
+        def1n!("({:?}, {})", self.key, offset);
+        let result = self.with_state_mut(|state| {
+            state.with_file_mut(self.key, |file| file_seek_data(file, offset))
+        });
+        def1x!("{:?}", result);
+
+        result
+    }
+}
+
+/// Helper to [`FileHandleManaged::seek_data`].
+fn file_seek_data(
+    file: &File,
+    offset: u64,
+) -> Result<Option<(u64, u64)>> {
+    cfg_if::cfg_if! {
+        if #[cfg(any(
+            target_os = "android",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "illumos",
+            target_os = "ios",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "solaris",
+        ))] {
+            use ::nix::errno::Errno;
+            use ::nix::unistd::{lseek, Whence};
+
+            let offset_ = match i64::try_from(offset) {
+                Ok(val) => val,
+                Err(_) => return Ok(None),
+            };
+            let data = match lseek(file, offset_, Whence::SeekData) {
+                Ok(data) => data,
+                // no data at or after `offset`
+                Err(Errno::ENXIO) => return Ok(None),
+                // the file system does not support `SEEK_DATA`
+                Err(Errno::EINVAL) => return file_seek_data_all(file, offset),
+                Err(errno) => return Err(Error::from(errno)),
+            };
+            let hole = match lseek(file, data, Whence::SeekHole) {
+                Ok(hole) => hole,
+                Err(errno) => return Err(Error::from(errno)),
+            };
+
+            Ok(Some((data as u64, hole as u64)))
+        } else {
+            file_seek_data_all(file, offset)
+        }
+    }
+}
+
+/// Helper to [`file_seek_data`]; the remainder of the file is data.
+fn file_seek_data_all(
+    file: &File,
+    offset: u64,
+) -> Result<Option<(u64, u64)>> {
+    let filesz: u64 = file.metadata()?.len();
+    if offset >= filesz {
+        return Ok(None);
+    }
+
+    Ok(Some((offset, filesz)))
 }
 
 // Implement traits `Clone`, `Drop`, `Read`, `Write`, and `Seek` for `FileHandleManaged`.
@@ -670,6 +670,13 @@ fn pathbuf_to_filetype_impl(
         .unwrap_or_default()
         .to_ascii_lowercase();
     defo!("file_suffix   {:?}", file_suffix);
+    let file_stem: String = pathbuf_clean
+        .file_stem()
+        .unwrap_or_default()
+        .to_str()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    defo!("file_stem     {:?}", file_stem);
 
     if file_suffix.parse::<i32>().is_ok() {
         defo!("file_suffix   {:?} is a number; remove it", file_suffix);
@@ -713,6 +720,15 @@ fn pathbuf_to_filetype_impl(
             defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
             return ret;
         }
+        "evt" => {
+            let ret = PathToFiletypeResult::Filetype(
+                FileType::Evt {
+                    archival_type: fta,
+                }
+            );
+            defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
+            return ret;
+        }
         "evtx" => {
             let ret = PathToFiletypeResult::Filetype(
                 FileType::Evtx {
@@ -755,6 +771,15 @@ fn pathbuf_to_filetype_impl(
             defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
             return ret;
         }
+        "logcatb" => {
+            let ret = PathToFiletypeResult::Filetype(
+                FileType::Logcat {
+                    archival_type: fta,
+                }
+            );
+            defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
+            return ret;
+        }
         "lz4" => {
             defo!("file_suffix {:?} is lzma4", file_suffix);
             let ret = pathbuf_to_filetype_impl(
@@ -788,6 +813,16 @@ fn pathbuf_to_filetype_impl(
             defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
             return ret;
         }
+        "pcap"
+        | "pcapng" => {
+            let ret = PathToFiletypeResult::Filetype(
+                FileType::Pcap {
+                    archival_type: fta,
+                }
+            );
+            defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
+            return ret;
+        }
         "tar" => {
             defo!("file_suffix {:?} is a tar filetype_archive.is_none()", file_suffix);
             let ret = PathToFiletypeResult::Archive(
@@ -797,6 +832,15 @@ fn pathbuf_to_filetype_impl(
             defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
             return ret;
         }
+        "tracev3" => {
+            let ret = PathToFiletypeResult::Filetype(
+                FileType::Tracev3 {
+                    archival_type: fta,
+                }
+            );
+            defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
+            return ret;
+        }
         "xz" | "xzip" => {
             defo!("file_suffix {:?} is xz", file_suffix);
             let ret = pathbuf_to_filetype_impl(
@@ -807,6 +851,19 @@ fn pathbuf_to_filetype_impl(
             defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
             return ret;
         }
+        // FreeBSD `/var/log/utx.log` is not a text file
+        "log"
+        if file_stem == "utx"
+        => {
+            let ret = PathToFiletypeResult::Filetype(
+                FileType::FixedStruct {
+                    archival_type: fta,
+                    fixedstruct_type: FileTypeFixedStruct::UtxLog,
+                }
+            );
+            defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
+            return ret;
+        }
         "log"
         | "txt"
         | "text"
@@ -871,6 +928,32 @@ fn pathbuf_to_filetype_impl(
             defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
             return ret;
         }
+        // SQLite databases of `wtmpdb` and `lastlog2`, e.g.
+        // `/var/lib/wtmpdb/wtmp.db`, `/var/lib/lastlog/lastlog2.db`
+        "db"
+        if file_stem == "wtmp"
+        => {
+            let ret = PathToFiletypeResult::Filetype(
+                FileType::FixedStruct {
+                    archival_type: fta,
+                    fixedstruct_type: FileTypeFixedStruct::Wtmpdb,
+                }
+            );
+            defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
+            return ret;
+        }
+        "db"
+        if file_stem == "lastlog2"
+        => {
+            let ret = PathToFiletypeResult::Filetype(
+                FileType::FixedStruct {
+                    archival_type: fta,
+                    fixedstruct_type: FileTypeFixedStruct::Lastlog2,
+                }
+            );
+            defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
+            return ret;
+        }
         "acct"
         => {
             let ret = PathToFiletypeResult::Filetype(
@@ -893,6 +976,18 @@ fn pathbuf_to_filetype_impl(
             defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
             return ret;
         }
+        // Android binary logcat buffers, e.g. `logcat.bin` from `logcat -B`
+        "bin"
+        if file_stem.contains("logcat")
+        => {
+            let ret = PathToFiletypeResult::Filetype(
+                FileType::Logcat {
+                    archival_type: fta,
+                }
+            );
+            defx!("matched file_suffix {:?}; return {:?}", file_suffix, ret);
+            return ret;
+        }
         // known unparseable file extensions/suffixes
         // covers some common extensions. not at all exhaustive but still helpful
         "7z"
@@ -1000,6 +1095,20 @@ fn pathbuf_to_filetype_impl(
                 }
             }
         }
+        // Android event log tags file
+        "event-log-tags"
+        => {
+            match unparseable_are_text {
+                true => {
+                    defx!("file_name_s {:?} (logcat event tags), return {:?}", file_name_s, RET_FALLBACK_TEXT);
+                    return RET_FALLBACK_TEXT;
+                }
+                false => {
+                    defx!("file_name_s {:?} (logcat event tags), return {:?}", file_name_s, RET_FALLBACK_UNPARSABLE);
+                    return RET_FALLBACK_UNPARSABLE;
+                }
+            }
+        }
         _ => {}
     }
 
@@ -1325,6 +1434,24 @@ pub fn process_path_tar(
                             fullpath, FileType::Etl { archival_type: FileTypeArchive::Tar }
                         );
                     }
+                    // Evt
+                    FileType::Evt { archival_type: at @ FileTypeArchive::Bz2, .. }
+                    | FileType::Evt { archival_type: at @ FileTypeArchive::Gz, .. }
+                    | FileType::Evt { archival_type: at @ FileTypeArchive::Lz4, .. }
+                    | FileType::Evt{ archival_type: at @ FileTypeArchive::Xz, .. }
+                    | FileType::Evt{ archival_type: at @ FileTypeArchive::Tar, .. }
+                    => {
+                        result = ProcessPathResult::FileErrNotSupported(
+                            fullpath,
+                            Some(format!("cannot extract {} type from a tar archived file", at))
+                        );
+                    }
+                    FileType::Evt { archival_type: FileTypeArchive::Normal, .. }
+                    => {
+                        result = ProcessPathResult::FileValid(
+                            fullpath, FileType::Evt { archival_type: FileTypeArchive::Tar }
+                        );
+                    }
                     // Evtx
                     FileType::Evtx { archival_type: at @ FileTypeArchive::Bz2, .. }
                     | FileType::Evtx { archival_type: at @ FileTypeArchive::Gz, .. }
@@ -1344,6 +1471,21 @@ pub fn process_path_tar(
                         );
                     }
                     // FixedStruct
+                    ft @ FileType::FixedStruct{ fixedstruct_type: FileTypeFixedStruct::Lastlog2, .. }
+                    | ft @ FileType::FixedStruct{ fixedstruct_type: FileTypeFixedStruct::Wtmpdb, .. }
+                    => {
+                        result = ProcessPathResult::FileErrNotSupported(
+                            fullpath,
+                            Some(format!("cannot read {} database from a tar archived file", ft))
+                        );
+                    }
+                    ft @ FileType::FixedStruct{ fixedstruct_type: FileTypeFixedStruct::UtxLog, .. }
+                    => {
+                        result = ProcessPathResult::FileErrNotSupported(
+                            fullpath,
+                            Some(format!("cannot read {} file from a tar archived file", ft))
+                        );
+                    }
                     FileType::FixedStruct{ archival_type: at @ FileTypeArchive::Bz2, .. }
                     | FileType::FixedStruct{ archival_type: at @ FileTypeArchive::Gz, .. }
                     | FileType::FixedStruct{ archival_type: at @ FileTypeArchive::Lz4, .. }
@@ -1421,6 +1563,27 @@ pub fn process_path_tar(
                             }
                         );
                     }
+                    // Logcat
+                    ft @ FileType::Logcat { .. } => {
+                        result = ProcessPathResult::FileErrNotSupported(
+                            fullpath,
+                            Some(format!("cannot read {} file from a tar archived file", ft))
+                        );
+                    }
+                    // Pcap
+                    ft @ FileType::Pcap { .. } => {
+                        result = ProcessPathResult::FileErrNotSupported(
+                            fullpath,
+                            Some(format!("cannot read {} file from a tar archived file", ft))
+                        );
+                    }
+                    // Tracev3
+                    ft @ FileType::Tracev3 { .. } => {
+                        result = ProcessPathResult::FileErrNotSupported(
+                            fullpath,
+                            Some(format!("cannot read {} file from a tar archived file", ft))
+                        );
+                    }
                     FileType::Unparsable => {
                         result = ProcessPathResult::FileErrNotSupported(fullpath, None);
                     }
@@ -1578,6 +1741,28 @@ pub fn process_path(
             }
         };
         match filetype {
+            ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Bz2, fixedstruct_type: FileTypeFixedStruct::Lastlog2 }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Gz, fixedstruct_type: FileTypeFixedStruct::Lastlog2 }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Lz4, fixedstruct_type: FileTypeFixedStruct::Lastlog2 }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Tar, fixedstruct_type: FileTypeFixedStruct::Lastlog2 }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Xz, fixedstruct_type: FileTypeFixedStruct::Lastlog2 }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Bz2, fixedstruct_type: FileTypeFixedStruct::Wtmpdb }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Gz, fixedstruct_type: FileTypeFixedStruct::Wtmpdb }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Lz4, fixedstruct_type: FileTypeFixedStruct::Wtmpdb }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Tar, fixedstruct_type: FileTypeFixedStruct::Wtmpdb }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Xz, fixedstruct_type: FileTypeFixedStruct::Wtmpdb }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Bz2, fixedstruct_type: FileTypeFixedStruct::UtxLog }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Gz, fixedstruct_type: FileTypeFixedStruct::UtxLog }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Lz4, fixedstruct_type: FileTypeFixedStruct::UtxLog }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Tar, fixedstruct_type: FileTypeFixedStruct::UtxLog }
+            | ft @ FileType::FixedStruct{ archival_type: FileTypeArchive::Xz, fixedstruct_type: FileTypeFixedStruct::UtxLog }
+            => {
+                deo!("archived {} is not supported {:?}", ft, std_path_entry);
+                paths.push(ProcessPathResult::FileErrNotSupported(
+                    fpath_entry,
+                    Some(format!("Compressed {} {}", ft, ft.archival_type())),
+                ));
+            }
             FileType::Asl{ archival_type: FileTypeArchive::Normal }
             | FileType::Asl{ archival_type: FileTypeArchive::Bz2 }
             | FileType::Asl{ archival_type: FileTypeArchive::Gz }
@@ -1590,6 +1775,12 @@ pub fn process_path(
             | FileType::Etl{ archival_type: FileTypeArchive::Lz4 }
             | FileType::Etl{ archival_type: FileTypeArchive::Tar }
             | FileType::Etl{ archival_type: FileTypeArchive::Xz }
+            | FileType::Evt{ archival_type: FileTypeArchive::Normal }
+            | FileType::Evt{ archival_type: FileTypeArchive::Bz2 }
+            | FileType::Evt{ archival_type: FileTypeArchive::Gz }
+            | FileType::Evt{ archival_type: FileTypeArchive::Lz4 }
+            | FileType::Evt{ archival_type: FileTypeArchive::Tar }
+            | FileType::Evt{ archival_type: FileTypeArchive::Xz }
             | FileType::Evtx{ archival_type: FileTypeArchive::Normal }
             | FileType::Evtx{ archival_type: FileTypeArchive::Bz2 }
             | FileType::Evtx{ archival_type: FileTypeArchive::Gz }
@@ -1615,6 +1806,24 @@ pub fn process_path(
             | FileType::Text{ archival_type: FileTypeArchive::Lz4, encoding_type: _ }
             | FileType::Text{ archival_type: FileTypeArchive::Tar, encoding_type: _ }
             | FileType::Text{ archival_type: FileTypeArchive::Xz, encoding_type: _ }
+            | FileType::Logcat{ archival_type: FileTypeArchive::Normal }
+            | FileType::Logcat{ archival_type: FileTypeArchive::Bz2 }
+            | FileType::Logcat{ archival_type: FileTypeArchive::Gz }
+            | FileType::Logcat{ archival_type: FileTypeArchive::Lz4 }
+            | FileType::Logcat{ archival_type: FileTypeArchive::Tar }
+            | FileType::Logcat{ archival_type: FileTypeArchive::Xz }
+            | FileType::Pcap{ archival_type: FileTypeArchive::Normal }
+            | FileType::Pcap{ archival_type: FileTypeArchive::Bz2 }
+            | FileType::Pcap{ archival_type: FileTypeArchive::Gz }
+            | FileType::Pcap{ archival_type: FileTypeArchive::Lz4 }
+            | FileType::Pcap{ archival_type: FileTypeArchive::Tar }
+            | FileType::Pcap{ archival_type: FileTypeArchive::Xz }
+            | FileType::Tracev3{ archival_type: FileTypeArchive::Normal }
+            | FileType::Tracev3{ archival_type: FileTypeArchive::Bz2 }
+            | FileType::Tracev3{ archival_type: FileTypeArchive::Gz }
+            | FileType::Tracev3{ archival_type: FileTypeArchive::Lz4 }
+            | FileType::Tracev3{ archival_type: FileTypeArchive::Tar }
+            | FileType::Tracev3{ archival_type: FileTypeArchive::Xz }
             => {
                 deo!("paths.push(FileValid(({:?}, {:?})))", fpath_entry, filetype);
                 paths.push(ProcessPathResult::FileValid(fpath_entry, filetype));
@@ -59,6 +59,7 @@ use ::si_trace_print::{
     pfo,
     pfx,
 };
+use ::tempfile::TempPath;
 
 use crate::common::{
     Count,
@@ -88,11 +89,13 @@ use crate::data::fixedstruct::{
     convert_datetime_tvpair,
     filesz_to_types,
     tv_pair_type,
+    Endian,
     FixedStruct,
     FixedStructDynPtr,
     FixedStructType,
     FixedStructTypeSet,
     Score,
+    ENDIANS,
     ENTRY_SZ_MAX,
     ENTRY_SZ_MIN,
     TIMEVAL_SZ_MAX,
@@ -104,6 +107,9 @@ use crate::readers::blockreader::{
     BlockSz,
     ResultReadDataToBuffer,
 };
+use crate::readers::helpers::path_to_fpath;
+use crate::readers::logindb::export_logindb;
+use crate::readers::utxlog::export_utxlog;
 use crate::readers::summary::Summary;
 use crate::{
     de_err,
@@ -167,9 +173,10 @@ pub type ResultFixedStructReaderNewError = ResultFixedStructReaderNew<Error>;
 
 #[derive(Debug)]
 pub enum ResultFixedStructReaderScoreFile<E> {
-    /// `score_file` was successful; return the `FixedStructType`, `Score`,
-    /// already processed `FixedStrcutDynPtr` entries (with associated offsets)
-    FileOk(FixedStructType, Score, ListFileOffsetFixedStructPtr),
+    /// `score_file` was successful; return the `FixedStructType`, `Endian`,
+    /// `Score`, already processed `FixedStrcutDynPtr` entries (with associated
+    /// offsets)
+    FileOk(FixedStructType, Endian, Score, ListFileOffsetFixedStructPtr),
     FileErrEmpty,
     /// No valid fixedstruct
     FileErrNoValidFixedStruct,
@@ -216,10 +223,25 @@ pub type ResultFixedStructReaderScoreFileError = ResultFixedStructReaderScoreFil
 /// [`process_entry_at`]: FixedStructReader::process_entry_at
 pub struct FixedStructReader {
     pub(crate) blockreader: BlockReader,
+    /// The path of the file. Differs from the `BlockReader` path for a
+    /// `wtmpdb` or `lastlog2` database or a FreeBSD `utx.log`, which the
+    /// `BlockReader` reads from the exported temporary file.
+    path: FPath,
+    /// The temporary file exported by [`export_logindb`] or
+    /// [`export_utxlog`], removed when dropped.
+    ///
+    /// [`export_logindb`]: crate::readers::logindb::export_logindb
+    /// [`export_utxlog`]: crate::readers::utxlog::export_utxlog
+    export_temp_path: Option<TempPath>,
     fixedstruct_type: FixedStructType,
     filetype_fixedstruct: FileTypeFixedStruct,
     /// Size of a single [`FixedStruct`] entry.
     fixedstruct_size: usize,
+    /// Byte order of the entries in the file. Entries that are not
+    /// [native] byte order are swapped before conversion.
+    ///
+    /// [native]: crate::data::fixedstruct::Endian::is_native
+    endian: Endian,
     /// The highest score found during `preprocess_file`.
     /// Used to determine the `FixedStructType` of the file.
     high_score: Score,
@@ -290,6 +312,7 @@ impl fmt::Debug for FixedStructReader {
     ) -> fmt::Result {
         f.debug_struct("FixedStructReader")
             .field("Path", &self.path())
+            .field("TempPath", &self.export_temp_path)
             .field("Entries", &self.cache_entries.len())
             .field("tz_offset", &self.tz_offset)
             .field("Error?", &self.error)
@@ -307,6 +330,7 @@ pub struct SummaryFixedStructReader {
     pub fixedstructreader_fixedstructtype_opt: Option<FixedStructType>,
     pub fixedstructreader_filetypefixedstruct_opt: Option<FileTypeFixedStruct>,
     pub fixedstructreader_fixedstruct_size: usize,
+    pub fixedstructreader_endian: Endian,
     pub fixedstructreader_high_score: Score,
     pub fixedstructreader_utmp_entries: Count,
     pub fixedstructreader_first_entry_fileoffset: FileOffset,
@@ -348,7 +372,36 @@ impl FixedStructReader {
             "({}, {:?}, filetype={:?}, blocksz={:?}, {:?}, {:?}, {:?})",
             path_id, path, filetype, blocksz, tz_offset, dt_filter_after, dt_filter_before,
         );
-        let mut blockreader = match BlockReader::new(path_id, path.clone(), filetype, blocksz) {
+        // a `wtmpdb` or `lastlog2` database or a FreeBSD `utx.log` is
+        // exported to a temporary file of `FixedStruct`s which is then read
+        // like any other fixedstruct file
+        let mut export_temp_path: Option<TempPath> = None;
+        let result_export: Option<Result<(TempPath, Count)>> = match filetype {
+            FileType::FixedStruct {
+                fixedstruct_type: ft @ (FileTypeFixedStruct::Lastlog2 | FileTypeFixedStruct::Wtmpdb),
+                ..
+            } => Some(export_logindb(&path, &ft)),
+            FileType::FixedStruct {
+                fixedstruct_type: FileTypeFixedStruct::UtxLog,
+                ..
+            } => Some(export_utxlog(&path)),
+            _ => None,
+        };
+        let path_blockreader: FPath = match result_export {
+            Some(Ok((temp_path, _count))) => {
+                def1o!("exported {} records to {:?}", _count, temp_path);
+                let fpath_: FPath = path_to_fpath(temp_path.as_ref());
+                export_temp_path = Some(temp_path);
+
+                fpath_
+            }
+            Some(Err(err)) => {
+                def1x!("export Err {}", err);
+                return ResultFixedStructReaderNew::FileErrIo(err);
+            }
+            None => path.clone(),
+        };
+        let mut blockreader = match BlockReader::new(path_id, path_blockreader, filetype, blocksz) {
             Ok(blockreader_) => blockreader_,
             Err(err) => {
                 def1x!("return Err {}", err);
@@ -390,14 +443,15 @@ impl FixedStructReader {
         // search past the first block of data.
         let (
             fixedstruct_type,
+            endian,
             high_score,
             list_entries,
         ) = match FixedStructReader::preprocess_fixedstructtype(
             &mut blockreader, &filetype_fixedstruct, false,
         ) {
             ResultFixedStructReaderScoreFileError::FileOk(
-                fixedstruct_type_, high_score_, list_entries_,
-            ) => (fixedstruct_type_, high_score_, list_entries_),
+                fixedstruct_type_, endian_, high_score_, list_entries_,
+            ) => (fixedstruct_type_, endian_, high_score_, list_entries_),
             ResultFixedStructReaderScoreFileError::FileErrEmpty => {
                 def1x!("return FileErrEmpty");
                 return ResultFixedStructReaderNew::FileErrEmpty;
@@ -422,6 +476,7 @@ impl FixedStructReader {
             match FixedStructReader::preprocess_timevalues(
                 &mut blockreader,
                 fixedstruct_type,
+                endian,
                 &dt_filter_after,
                 &dt_filter_before,
             )
@@ -522,9 +577,12 @@ impl FixedStructReader {
         // now that the `fixedstruct_type` is known, create the FixedStructReader
         let mut fixedstructreader = FixedStructReader {
             blockreader,
+            path,
+            export_temp_path,
             fixedstruct_type,
             filetype_fixedstruct,
             fixedstruct_size: fixedstruct_type.size(),
+            endian,
             high_score,
             tz_offset,
             cache_entries: FoToEntry::new(),
@@ -611,12 +669,10 @@ impl FixedStructReader {
         self.blockreader.filetype()
     }
 
-    /// See [`BlockReader::path`].
-    ///
-    /// [`BlockReader::path`]: crate::readers::blockreader::BlockReader#method.path
+    /// The path of the file.
     #[inline(always)]
     pub const fn path(&self) -> &FPath {
-        self.blockreader.path()
+        &self.path
     }
 
     /// See [`BlockReader::mtime`].
@@ -756,6 +812,12 @@ impl FixedStructReader {
         self.fixedstruct_size
     }
 
+    /// Byte order of the entries in the file.
+    #[inline(always)]
+    pub const fn endian(&self) -> Endian {
+        self.endian
+    }
+
     /// [`fixedstruct_size`] as a `FileOffset`.
     ///
     /// [`fixedstruct_size`]: self::FixedStructReader#method.fixedstruct_size
@@ -979,8 +1041,12 @@ impl FixedStructReader {
     /// Process entries for the file managed by the `BlockReader`.
     /// Find the entry with the highest "score" as judged by `score_fixedstruct`.
     ///
-    /// Returns the highest scored `FixedStructType`, that highest score,
-    /// and any processed [`FixedStruct`] entries (referenced by a
+    /// Each `FixedStructType` is scored as each [`Endian`]; entries of a
+    /// non-native byte order are swapped before scoring. Ties go to the
+    /// first `Endian` scored, little-endian.
+    ///
+    /// Returns the highest scored `FixedStructType` and `Endian`, that highest
+    /// score, and any processed [`FixedStruct`] entries (referenced by a
     /// [`FixedStructDynPtr`]) in a list.
     ///
     /// Each list entry is a tuple of the
@@ -1010,9 +1076,13 @@ impl FixedStructReader {
         let mut _count_total: usize = 0;
         let mut highest_score: Score = 0;
         let mut highest_score_type: Option<FixedStructType> = None;
+        let mut highest_score_endian: Endian = Endian::default();
         let mut highest_score_entries = ListFileOffsetFixedStructPtr::new();
 
-        for (fixedstructtype, bonus) in types_to_bonus.into_iter() {
+        for ((fixedstructtype, bonus), endian) in types_to_bonus
+            .into_iter()
+            .flat_map(|type_bonus| ENDIANS.into_iter().map(move |endian| (type_bonus, endian)))
+        {
             let mut _count_loop: usize = 0;
             let mut count_found_entries: usize = 0;
             let mut high_score: Score = 0;
@@ -1029,8 +1099,8 @@ impl FixedStructReader {
                 let utmp_sz: usize = fixedstructtype.size();
                 let fo_end = fo + utmp_sz as FileOffset;
                 def1o!(
-                    "loop try {} (total {}), fixedstructtype {:?}, zero the buffer (size {}), looking at fileoffset {}‥{} (0x{:08X}‥0x{:08X})",
-                    _count_loop, _count_total, fixedstructtype, buffer.len(), fo, fo_end, fo, fo_end
+                    "loop try {} (total {}), fixedstructtype {:?} {:?}, zero the buffer (size {}), looking at fileoffset {}‥{} (0x{:08X}‥0x{:08X})",
+                    _count_loop, _count_total, fixedstructtype, endian, buffer.len(), fo, fo_end, fo, fo_end
                 );
                 // zero out the buffer
                 // XXX: not strictly necessary to zero buffer but it helps humans
@@ -1063,16 +1133,31 @@ impl FixedStructReader {
                 let fo2 = fo;
                 fo += utmp_sz as FileOffset;
                 // grab the slice of interest
-                let slice_ = &buffer[..buffer_read];
+                let slice_ = &mut buffer[..buffer_read];
+                if !endian.is_native() {
+                    fixedstructtype.swap_endian(&mut slice_[..utmp_sz], 0);
+                }
                 // convert buffer to fixedstruct
                 let fixedstructptr: FixedStructDynPtr = match buffer_to_fixedstructptr(slice_, fixedstructtype) {
                     Some(val) => val,
                     None => {
                         def1o!(
                             "buffer_to_fixedstructptr(buf len {}, {:?}) returned None; continue",
-                            buffer.len(),
+                            buffer_read,
                             fixedstructtype,
                         );
+                        if oneblock {
+                            continue;
+                        }
+                        // skip the null entries of a sparse file
+                        match FixedStructReader::fileoffset_entry_nonzero(blockreader, fo, utmp_sz as FileOffset) {
+                            Ok(Some(fo_)) => fo = fo_,
+                            Ok(None) => break,
+                            Err(err) => {
+                                def1x!("return Err");
+                                return ResultFixedStructReaderScoreFileError::FileErrIo(err);
+                            }
+                        }
                         continue;
                     }
                 };
@@ -1114,6 +1199,7 @@ impl FixedStructReader {
                 }
                 highest_score = high_score;
                 highest_score_type = Some(fixedstructtype);
+                highest_score_endian = endian;
                 highest_score_entries = found_entries;
             } else {
                 def1o!(
@@ -1130,13 +1216,37 @@ impl FixedStructReader {
                 return ResultFixedStructReaderScoreFileError::FileErrNoHighScore;
             }
             Some(highest_score_type) => {
-                def1x!("return Ok(({:?}, {}, found_entries))", highest_score_type, highest_score);
+                def1x!("return Ok(({:?}, {:?}, {}, found_entries))", highest_score_type, highest_score_endian, highest_score);
 
-                ResultFixedStructReaderScoreFileError::FileOk(highest_score_type, highest_score, highest_score_entries)
+                ResultFixedStructReaderScoreFileError::FileOk(
+                    highest_score_type, highest_score_endian, highest_score, highest_score_entries,
+                )
             }
         }
     }
 
+    /// Return the `FileOffset` of the first entry at or after `fo` that is
+    /// not all null bytes. Entries are `entry_sz` bytes from the start of the
+    /// file. Return `None` if all remaining entries are null.
+    ///
+    /// A `lastlog` file is indexed by UID so a `lastlog` file with an entry
+    /// for a high UID is a huge sparse file of null entries. See
+    /// [`BlockReader::fileoffset_nonzero`].
+    fn fileoffset_entry_nonzero(
+        blockreader: &mut BlockReader,
+        fo: FileOffset,
+        entry_sz: FileOffset,
+    ) -> Result<Option<FileOffset>> {
+        let fo_nonzero: FileOffset = match blockreader.fileoffset_nonzero(fo)? {
+            Some(fo_nonzero) => fo_nonzero,
+            None => return Ok(None),
+        };
+        let fo_entry: FileOffset = fo.max(fo_nonzero - fo_nonzero % entry_sz);
+        defñ!("({}, {}) return Some({})", fo, entry_sz, fo_entry);
+
+        Ok(Some(fo_entry))
+    }
+
     /// Determine the `FixedStructType` based on the file size and data.
     ///
     /// 1. Makes best guess about file structure based on size by calling
@@ -1193,6 +1303,7 @@ impl FixedStructReader {
     pub(crate) fn preprocess_timevalues(
         blockreader: &mut BlockReader,
         fixedstruct_type: FixedStructType,
+        endian: Endian,
         dt_filter_after: &DateTimeLOpt,
         dt_filter_before: &DateTimeLOpt,
     ) -> ResultTvFo {
@@ -1251,6 +1362,9 @@ impl FixedStructReader {
                     break;
                 }
             }
+            if !endian.is_native() {
+                fixedstruct_type.swap_endian(slice_, tv_offset);
+            }
             // 3. convert bytes to tv_sec, tv_usec
             let tv_pair: tv_pair_type = match fixedstruct_type.tv_pair_from_buffer(slice_) {
                 Some(pair) => pair,
@@ -1266,6 +1380,15 @@ impl FixedStructReader {
             if tv_pair == tv_pair_type(0, 0) {
                 defo!("tv_pair is (0, 0); continue");
                 fo += entry_sz;
+                // skip the null entries of a sparse file
+                match FixedStructReader::fileoffset_entry_nonzero(blockreader, fo, entry_sz) {
+                    Ok(Some(fo_)) => fo = fo_,
+                    Ok(None) => break,
+                    Err(err) => {
+                        defx!("return Err");
+                        return ResultTvFo::Err(err);
+                    }
+                }
                 continue;
             }
             if let Some(tv_pair_prev) = tv_pair_prev {
@@ -1431,6 +1554,9 @@ impl FixedStructReader {
             }
         };
         debug_assert_eq!(_readn, sz as usize, "read {} bytes, expected {} bytes", _readn, sz);
+        if !self.endian.is_native() {
+            self.fixedstruct_type.swap_endian(slice_, 0);
+        }
 
         // create a FixedStruct from the slice
         let fs: FixedStruct = match FixedStruct::new(
@@ -1519,6 +1645,7 @@ impl FixedStructReader {
             fixedstructreader_fixedstructtype_opt,
             fixedstructreader_filetypefixedstruct_opt,
             fixedstructreader_fixedstruct_size: self.fixedstruct_size(),
+            fixedstructreader_endian: self.endian(),
             fixedstructreader_high_score,
             fixedstructreader_utmp_entries,
             fixedstructreader_first_entry_fileoffset,
@@ -257,6 +257,11 @@ use crate::readers::filehandlemanager::{
     OpenOptionsManaged,
 };
 use crate::readers::helpers::path_to_fpath;
+use crate::readers::journalrecovery::{
+    JournalHeader,
+    JournalRecovery,
+    RecoveredEntry,
+};
 use crate::readers::summary::Summary;
 
 // XXX: ripped from `nix` crate
@@ -767,6 +772,17 @@ pub fn em_after_or_before(
 type EntryBufferKey = (DateTimeL, usize);
 type EntryBuffer = BTreeMap<EntryBufferKey, JournalEntry>;
 
+/// The field values written by `--output=short` and `short*` variations.
+#[derive(Default)]
+struct ShortFields<'b> {
+    hostname: Option<&'b [u8]>,
+    syslog_identifier: Option<&'b [u8]>,
+    syslog_pid: Option<&'b [u8]>,
+    comm: Option<&'b [u8]>,
+    pid: Option<&'b [u8]>,
+    message: Option<&'b [u8]>,
+}
+
 /// A wrapper for parsing a systemd [`.journal` file] using [`libsystemd` API].
 ///
 /// The `JournalReader` also writes the data to byte buffer which is later
@@ -969,6 +985,26 @@ pub struct JournalReader {
     /// Summary statistic.
     /// Out of chronological order.
     out_of_order: Count,
+    /// Reads a damaged journal file without `libsystemd`.
+    /// Set when `libsystemd` fails to open the file, returns an error while
+    /// reading the file, or stops reading entries from a file that was not
+    /// closed cleanly.
+    recovery: Option<JournalRecovery>,
+    /// Index of the next entry to read from `recovery`.
+    recovery_index: usize,
+    /// Has creating `recovery` been attempted?
+    recovery_attempted: bool,
+    /// `sd_journal_next` returned no more entries.
+    api_exhausted: bool,
+    /// The `__REALTIME_TIMESTAMP` of the last entry read via `libsystemd` and
+    /// the count of consecutive entries read having that same timestamp.
+    /// Used to find where `libsystemd` stopped reading.
+    rt_last_api: Option<(EpochMicroseconds, Count)>,
+    /// The `ts_filter_after` passed to `analyze`.
+    ts_filter_after: EpochMicrosecondsOpt,
+    /// Summary statistic.
+    /// Entries read by `recovery`.
+    entries_recovered: Count,
     /// The last [`Error`], if any, as a `String`
     ///
     /// Annoyingly, cannot [Clone or Copy `Error`].
@@ -1034,6 +1070,14 @@ pub struct SummaryJournalReader {
     pub journalreader_api_calls: Count,
     pub journalreader_api_call_errors: Count,
     pub journalreader_out_of_order: Count,
+    /// Entries read without `libsystemd` from a damaged or "online" journal
+    /// file.
+    pub journalreader_entries_recovered: Count,
+    /// Recovered entries missing one or more damaged fields.
+    pub journalreader_entries_damaged: Count,
+    /// Distinct objects skipped because of a bad offset, size, type,
+    /// or hash.
+    pub journalreader_objects_damaged: Count,
 }
 
 /// Implement the JournalReader.
@@ -1146,6 +1190,7 @@ impl<'a> JournalReader {
         let mut journal_handle_ptr: *mut sd_journal = ::std::ptr::null_mut();
         def1o!("*journal_handle @{:?}", journal_handle_ptr);
         let journal_api_ptr: JournalApiPtr = journal_api();
+        let mut recovery: Option<JournalRecovery> = None;
         unsafe {
             //
             // call sd_journal_open_files
@@ -1162,8 +1207,20 @@ impl<'a> JournalReader {
                     errno_to_errorkind(&e),
                     format!("sd_journal_open_files({:?}) returned {}; {:?}", path_cs, r, e),
                 );
-                def1x!("return {:?}", err);
-                return Err(err);
+                // `libsystemd` refused the file; it may be damaged so
+                // attempt to recover entries without `libsystemd`
+                match JournalRecovery::open(path_actual) {
+                    Result::Ok(jr) => {
+                        def1o!("sd_journal_open_files failed; recovering {:?}", jr);
+                        journal_handle_ptr = ::std::ptr::null_mut();
+                        recovery = Some(jr);
+                    }
+                    Result::Err(_err) => {
+                        def1o!("JournalRecovery::open failed {:?}", _err);
+                        def1x!("return {:?}", err);
+                        return Err(err);
+                    }
+                }
             }
         }
         let fill_buffer: EntryBuffer = EntryBuffer::new();
@@ -1196,6 +1253,13 @@ impl<'a> JournalReader {
             api_calls: 1,
             api_call_errors: 0,
             out_of_order: 0,
+            recovery_attempted: recovery.is_some(),
+            recovery,
+            recovery_index: 0,
+            api_exhausted: false,
+            rt_last_api: None,
+            ts_filter_after: None,
+            entries_recovered: 0,
             error: None,
             #[cfg(test)]
             force_error_range_opt: None,
@@ -1220,6 +1284,14 @@ impl<'a> JournalReader {
     ) -> Result<()> {
         def1n!("({:?})", ts_filter_after);
 
+        self.ts_filter_after = *ts_filter_after;
+        if self.recovery.is_some() {
+            // `libsystemd` could not open the file; recovered entries are
+            // filtered as they are read
+            self.analyzed = true;
+            def1x!("recovery");
+            return Result::Ok(());
+        }
         match ts_filter_after {
             Some(ts) => {
                 //
@@ -1787,7 +1859,10 @@ impl<'a> JournalReader {
         rts_filter_before: &EpochMicrosecondsOpt,
     ) -> ResultNext {
         def1ñ!("({:?})", rts_filter_before);
-        match self.journal_output {
+        if self.recovery.is_some() {
+            return self.next_recovered(rts_filter_before);
+        }
+        let result: ResultNext = match self.journal_output {
             JournalOutput::Short => {
                 self.next_short(rts_filter_before, DATETIME_FORMAT_SHORT, false)
             }
@@ -1818,58 +1893,311 @@ impl<'a> JournalReader {
             JournalOutput::Cat => {
                 self.next_cat(rts_filter_before)
             }
+        };
+        match result {
+            ResultNext::Done if self.api_exhausted => {
+                self.recovery_start(rts_filter_before, None)
+            }
+            ResultNext::Err(err) => {
+                self.recovery_start(rts_filter_before, Some(err))
+            }
+            result => result,
         }
     }
 
-    /// Common calls for all `next*` functions.
-    fn next_common(
+    /// `libsystemd` has stopped reading entries; either no more entries were
+    /// found or an error occurred.
+    /// If `libsystemd` returned an error, or the [`JournalHeader`] shows the
+    /// file is truncated or has entries after the last entry read by
+    /// `libsystemd`, then continue reading entries after the last entry read
+    /// by `libsystemd` using a [`JournalRecovery`].
+    /// Otherwise only the header is read.
+    ///
+    /// Returns the next recovered entry, or `Done`, or the passed `err_opt`.
+    fn recovery_start(
         &mut self,
         rts_filter_before: &EpochMicrosecondsOpt,
-    ) -> ResultNextCommon {
-        debug_assert!(self.analyzed, "must call `analyze()` before calling `next_common()`");
-        def1n!("({:?})", rts_filter_before);
+        err_opt: Option<Error>,
+    ) -> ResultNext {
+        def1n!("({:?}, {:?})", rts_filter_before, err_opt);
+        let result_stop: ResultNext = match err_opt {
+            Some(err) => ResultNext::Err(err),
+            None => ResultNext::Done,
+        };
+        if self.recovery_attempted {
+            def1x!("already attempted; return {:?}", result_stop);
+            return result_stop;
+        }
+        self.recovery_attempted = true;
+        let path_actual: &Path = match self.named_temp_file {
+            Some(ref ntf) => ntf.as_ref(),
+            None => Path::new(&self.path),
+        };
+        if matches!(result_stop, ResultNext::Done) {
+            match JournalHeader::read(path_actual) {
+                Result::Ok(header) => {
+                    def1o!("{:?}", header);
+                    let rt_last: Option<EpochMicroseconds> = self.rt_last_api.map(|(rt, _count)| rt);
+                    if !header.is_missing_entries(rt_last, self.ts_filter_after) {
+                        // `libsystemd` read all entries
+                        def1x!("no missing entries; return Done");
+                        return result_stop;
+                    }
+                }
+                Result::Err(_err) => {
+                    de_err!("JournalHeader::read({:?}) failed; {}", path_actual, _err);
+                    def1x!("return {:?}", result_stop);
+                    return result_stop;
+                }
+            }
+        }
+        let jr: JournalRecovery = match JournalRecovery::open(path_actual) {
+            Result::Ok(jr) => jr,
+            Result::Err(_err) => {
+                de_err!("JournalRecovery::open({:?}) failed; {}", path_actual, _err);
+                def1x!("return {:?}", result_stop);
+                return result_stop;
+            }
+        };
+        if let ResultNext::Err(ref _err) = result_stop {
+            de_err!("libsystemd failed, recovering remaining entries; {}", _err);
+        }
+        self.recovery_index = match self.rt_last_api {
+            Some((rt, count)) => jr.index_after(rt, count),
+            None => 0,
+        };
+        def1o!("recovery_index {} of {}", self.recovery_index, jr.count_entries());
+        self.recovery = Some(jr);
+        def1x!();
 
-        // get the next entry
+        self.next_recovered(rts_filter_before)
+    }
 
-        match Self::call_sd_journal_next(
-            &mut self.journal_handle_ptr,
-            &mut self.journal_api_ptr,
-            &mut self.api_calls,
-            &mut self.api_call_errors,
-            &self.path,
-        ) {
-            ResultFind::Found(_) => {}
-            ResultFind::Done => {
-                def1x!("return Done");
-                return ResultNextCommon::Done;
+    /// Read the next entry from `self.recovery` and write it in the form of
+    /// the `JournalOutput` setting.
+    fn next_recovered(
+        &mut self,
+        rts_filter_before: &EpochMicrosecondsOpt,
+    ) -> ResultNext {
+        def1n!("({:?})", rts_filter_before);
+        debug_assert!(self.analyzed, "must call `analyze()` before calling `next_recovered()`");
+        let jr: &mut JournalRecovery = match self.recovery.as_mut() {
+            Some(jr) => jr,
+            None => {
+                def1x!("no recovery; return Done");
+                return ResultNext::Done;
             }
-            ResultFind::Err(err) => {
-                def1x!("return Err {:?}", err);
-                return ResultNextCommon::Err(err);
+        };
+        // skip entries before the `ts_filter_after`
+        let entry: RecoveredEntry = loop {
+            let index = self.recovery_index;
+            let realtime: EpochMicroseconds = match jr.realtime_at(index) {
+                Some(rt) => rt,
+                None => {
+                    def1x!("return Done");
+                    return ResultNext::Done;
+                }
+            };
+            self.recovery_index += 1;
+            if let Some(after) = self.ts_filter_after
+                && realtime < after
+            {
+                continue;
             }
-        }
+            match jr.entry(index) {
+                Some(entry) => break entry,
+                None => continue,
+            }
+        };
+        let seqnum_id: [u8; 16] = *jr.seqnum_id();
         summary_stat!(self.events_processed += 1);
+        summary_stat!(self.entries_recovered += 1);
 
-        // get the realtime usec (epoch microseconds)
+        let mut source_realtime_timestamp: EpochMicrosecondsOpt = None;
+        let mut journal_event_size: Count = 0;
+        for data in entry.data.iter() {
+            journal_event_size += data.len() as Count;
+            if let Some(value) = data.strip_prefix(KEY_SOURCE_REALTIME_TIMESTAMP_BYTES)
+                .and_then(|v| v.strip_prefix(&[FIELD_MID_U8]))
+            {
+                source_realtime_timestamp = value.to_str().ok()
+                    .and_then(|v| EpochMicroseconds::from_str(v).ok());
+            }
+        }
+        let realtime_timestamp: EpochMicroseconds = entry.realtime;
+        let (actual_epoch_usec, dt_uses_source) = Self::select_epoch_usec(
+            realtime_timestamp,
+            source_realtime_timestamp,
+        );
+        self.em_first_last_update_processed(&actual_epoch_usec);
+        summary_stat!(self.journal_event_largest_processed = std::cmp::max(
+            self.journal_event_largest_processed,
+            journal_event_size,
+        ));
+        match em_after_or_before(&actual_epoch_usec, rts_filter_before) {
+            Result_Filter_DateTime1::OccursAtOrAfter => {
+                def1x!("OccursAtOrAfter: return Done");
+                return ResultNext::Done;
+            }
+            Result_Filter_DateTime1::Pass
+            | Result_Filter_DateTime1::OccursBefore => {}
+        }
 
-        let realtime_timestamp = match Self::call_sd_journal_get_realtime_usec(
-            &mut self.journal_handle_ptr,
-            &mut self.journal_api_ptr,
-            &mut self.api_calls,
-            &mut self.api_call_errors,
-            &self.path,
-        ) {
-            Result::Ok(rt) => rt,
-            Result::Err(err) => {
-                de_err!("failed to get realtime_timestamp; {:?}", err);
-                def1x!("return ErrIgnore({:?})", err);
-                return ResultNextCommon::ErrIgnore(err);
+        let dt = realtime_or_source_realtime_timestamp_to_datetimel(
+            &self.fixed_offset,
+            &realtime_timestamp,
+            &source_realtime_timestamp,
+        );
+        // split each `KEY=value`
+        let fields_split: Vec<(&[u8], &[u8])> = entry.data
+            .iter()
+            .map(|data| {
+                let mid: usize = data.find_byte(FIELD_MID_U8).unwrap_or(data.len());
+                let valb = std::cmp::min(mid + 1, data.len());
+
+                (&data[..mid], &data[valb..])
+            })
+            .collect();
+        let field_value = |key: &[u8]| -> Option<&[u8]> {
+            fields_split.iter().find(|(k, _v)| *k == key).map(|(_k, v)| *v)
+        };
+        let journalentry: JournalEntry = match self.journal_output {
+            JournalOutput::Short
+            | JournalOutput::ShortPrecise
+            | JournalOutput::ShortIso
+            | JournalOutput::ShortIsoPrecise
+            | JournalOutput::ShortFull
+            | JournalOutput::ShortMonotonic
+            | JournalOutput::ShortUnix => {
+                let datetime_format: &str = match self.journal_output {
+                    JournalOutput::ShortPrecise => DATETIME_FORMAT_SHORT_PRECISE,
+                    JournalOutput::ShortIso => DATETIME_FORMAT_SHORT_ISO,
+                    JournalOutput::ShortIsoPrecise => DATETIME_FORMAT_SHORT_ISO_PRECISE,
+                    JournalOutput::ShortFull => DATETIME_FORMAT_SHORT_FULL,
+                    JournalOutput::ShortMonotonic => "",
+                    JournalOutput::ShortUnix => DATETIME_FORMAT_SHORT_UNIX,
+                    _ => DATETIME_FORMAT_SHORT,
+                };
+                let fields = ShortFields {
+                    hostname: field_value(KEY_HOSTNAME_BYTES),
+                    syslog_identifier: field_value(KEY_SYSLOG_IDENTIFIER_BYTES),
+                    syslog_pid: field_value(KEY_SYSLOG_PID_BYTES),
+                    comm: field_value(KEY_COMM_BYTES),
+                    pid: field_value(KEY_PID_BYTES),
+                    message: field_value(KEY_MESSAGE_BYTES),
+                };
+                let mut buffer: Vec<u8> = Vec::with_capacity(Self::BUF_DEFAULT_MEDIUM_SZ);
+                let (dt_a, dt_b) = Self::write_short(
+                    &mut buffer,
+                    &dt,
+                    datetime_format,
+                    self.journal_output == JournalOutput::ShortMonotonic,
+                    Some(entry.monotonic),
+                    &fields,
+                );
+                buffer.push(ENTRY_END_U8);
+                JournalEntry::new_with_date(
+                    buffer,
+                    realtime_timestamp,
+                    source_realtime_timestamp,
+                    dt,
+                    dt_uses_source,
+                    dt_a,
+                    dt_b,
+                )
+            }
+            JournalOutput::Verbose => {
+                let mut fields: HashMap<&[u8], &[u8]> = HashMap::new();
+                for (key, mut value) in fields_split.iter().copied() {
+                    // see `next_verbose`
+                    if key == KEY_SELINUX_CONTEXT_BYTES {
+                        while value.ends_with(b"\0")
+                            || value.ends_with(b"\r")
+                            || value.ends_with(b"\n")
+                            || value.ends_with(b" ")
+                        {
+                            value = &value[..value.len() - 1];
+                        }
+                    }
+                    fields.insert(key, value);
+                }
+                let monotonic_usec_str: String = entry.monotonic.to_string();
+                fields.entry(KEY__MONOTONIC_TIMESTAMP_BYTES)
+                    .or_insert(monotonic_usec_str.as_bytes());
+                let cursor: String = entry.cursor(&seqnum_id);
+                let (buffer, dt_a, dt_b) = Self::write_verbose(fields, &dt, Some(cursor.as_bytes()));
+                JournalEntry::from_vec(
+                    buffer,
+                    realtime_timestamp,
+                    source_realtime_timestamp,
+                    dt,
+                    dt_uses_source,
+                    dt_a,
+                    dt_b,
+                )
+            }
+            JournalOutput::Export => {
+                let mut buffer: Vec<u8> = Vec::with_capacity(Self::BUF_DEFAULT_LARGE_SZ);
+                let cursor: String = entry.cursor(&seqnum_id);
+                Self::write_export_head(
+                    &mut buffer,
+                    Some(cursor.as_bytes()),
+                    realtime_timestamp,
+                    Some(entry.monotonic),
+                );
+                for data in entry.data.iter() {
+                    buffer.push_str(data);
+                    buffer.push(FIELD_END_U8);
+                }
+                buffer.push(ENTRY_END_U8);
+                JournalEntry::from_vec_nodt(
+                    buffer,
+                    realtime_timestamp,
+                    source_realtime_timestamp,
+                    dt_uses_source,
+                    &self.fixed_offset,
+                )
+            }
+            JournalOutput::Cat => {
+                let mut buffer: Vec<u8> = Vec::with_capacity(Self::BUF_DEFAULT_SMALL_SZ);
+                if let Some(value) = field_value(KEY_MESSAGE_BYTES) {
+                    buffer.push_str(value);
+                }
+                buffer.push(ENTRY_END_U8);
+                JournalEntry::new_with_date(
+                    buffer,
+                    realtime_timestamp,
+                    source_realtime_timestamp,
+                    dt,
+                    dt_uses_source,
+                    0,
+                    0,
+                )
             }
         };
 
-        // get the optional field `_SOURCE_REALTIME_TIMESTAMP`
+        self.em_first_last_update_accepted_all(
+            dt_uses_source,
+            &realtime_timestamp,
+            &source_realtime_timestamp,
+        );
+        summary_stat!(self.journal_event_largest_accepted = std::cmp::max(
+            self.journal_event_largest_accepted,
+            journal_event_size,
+        ));
+        summary_stat!(self.events_accepted += 1);
+        def1x!();
+
+        ResultNext::Found(journalentry)
+    }
+
+    /// Select the entry timestamp from either the `__REALTIME_TIMESTAMP`
+    /// or the optional `_SOURCE_REALTIME_TIMESTAMP`.
+    fn select_epoch_usec(
+        realtime_timestamp: EpochMicroseconds,
+        source_realtime_timestamp: EpochMicrosecondsOpt,
+    ) -> (EpochMicroseconds, DtUsesSource) {
         let actual_epoch_usec: EpochMicroseconds;
-        let source_realtime_timestamp = self.get_source_realtime_timestamp();
         // first attempt to use `DT_USES_SOURCE_OVERRIDE` if set
         let dt_uses_source: DtUsesSource = match DT_USES_SOURCE_OVERRIDE {
             Some(dt_uses_source) => {
@@ -1906,6 +2234,67 @@ impl<'a> JournalReader {
                 }
             }
         };
+
+        (actual_epoch_usec, dt_uses_source)
+    }
+
+    /// Common calls for all `next*` functions.
+    fn next_common(
+        &mut self,
+        rts_filter_before: &EpochMicrosecondsOpt,
+    ) -> ResultNextCommon {
+        debug_assert!(self.analyzed, "must call `analyze()` before calling `next_common()`");
+        def1n!("({:?})", rts_filter_before);
+
+        // get the next entry
+
+        match Self::call_sd_journal_next(
+            &mut self.journal_handle_ptr,
+            &mut self.journal_api_ptr,
+            &mut self.api_calls,
+            &mut self.api_call_errors,
+            &self.path,
+        ) {
+            ResultFind::Found(_) => {}
+            ResultFind::Done => {
+                self.api_exhausted = true;
+                def1x!("return Done");
+                return ResultNextCommon::Done;
+            }
+            ResultFind::Err(err) => {
+                def1x!("return Err {:?}", err);
+                return ResultNextCommon::Err(err);
+            }
+        }
+        summary_stat!(self.events_processed += 1);
+
+        // get the realtime usec (epoch microseconds)
+
+        let realtime_timestamp = match Self::call_sd_journal_get_realtime_usec(
+            &mut self.journal_handle_ptr,
+            &mut self.journal_api_ptr,
+            &mut self.api_calls,
+            &mut self.api_call_errors,
+            &self.path,
+        ) {
+            Result::Ok(rt) => rt,
+            Result::Err(err) => {
+                de_err!("failed to get realtime_timestamp; {:?}", err);
+                def1x!("return ErrIgnore({:?})", err);
+                return ResultNextCommon::ErrIgnore(err);
+            }
+        };
+        self.rt_last_api = match self.rt_last_api {
+            Some((rt, count)) if rt == realtime_timestamp => Some((rt, count + 1)),
+            _ => Some((realtime_timestamp, 1)),
+        };
+
+        // get the optional field `_SOURCE_REALTIME_TIMESTAMP`
+        let source_realtime_timestamp = self.get_source_realtime_timestamp();
+        let (actual_epoch_usec, dt_uses_source) = Self::select_epoch_usec(
+            realtime_timestamp,
+            source_realtime_timestamp,
+        );
         self.em_first_last_update_processed(&actual_epoch_usec);
         let journal_event_size: Count = self.journal_event_size_current();
         summary_stat!(self.journal_event_largest_processed = std::cmp::max(
@@ -1932,43 +2321,142 @@ impl<'a> JournalReader {
         ResultNextCommon::Found((realtime_timestamp, source_realtime_timestamp, dt_uses_source, journal_event_size))
     }
 
-    /// Journal entry output matching `--output=short` and `short*` variations.
-    ///
-    /// For example, the default `short` is:
-    ///
-    /// ```text
-    /// $ PAGER= journalctl --output=short --lines=1 --all --utc --file=./user-1000.journal
-    /// Apr 01 06:44:32 ubuntu22Acorn ubuntu-appindicators@ubuntu.com[1306]: unable to update icon for livepatch
-    /// ```
-    ///
-    /// The special case of `short-monotonic` is:
-    /// ```text
-    /// $ PAGER= journalctl --output=short-monotonic --lines=1 --all --utc --file=./user-1000.journal
-    /// [   74.212842] ubuntu22Acorn ubuntu-appindicators@ubuntu.com[1306]: unable to update icon for livepatch
-    /// ```
-    ///
-    /// The log message format only varies by the `datetime_format`.
+    /// Write the `--output=short` and `short*` variations of one entry to
+    /// `buffer`, excluding the ending newline.
     ///
-    /// In psuedo-code, the log message format comprises fields:
-    ///     `${DATETIME} ${_HOSTNAME} (${SYSLOG_IDENTIFIER${SYSLOG_PID}?${_COMM}})[${_PID}]: ${MESSAGE}`
-    fn next_short(
-        &mut self,
-        rts_filter_before: &EpochMicrosecondsOpt,
+    /// Returns the datetime substring indexes `(dt_a, dt_b)` within `buffer`.
+    fn write_short(
+        buffer: &mut Vec<u8>,
+        dt: &DateTimeL,
         datetime_format: &str,
         is_monotonic: bool,
-    ) -> ResultNext {
-        def1n!("({:?})", rts_filter_before);
-        debug_assert!(self.analyzed, "must call `analyze()` before calling `next_order_enumerate_data()`");
+        monotonic_usec: Option<MonotonicMicroseconds>,
+        fields: &ShortFields,
+    ) -> (usize, usize) {
+        // field 1 Datetime or Monotonic time since boot
+        let dt_a: usize;
+        let dt_b: usize;
+        if !is_monotonic {
+            def1o!("write field 1 Datetime");
+            debug_assert_ne!(datetime_format, "", "datetime_format must not be empty");
+            let dts: String = dt.format(datetime_format).to_string();
+            let dtsb: &[u8] = dts.as_bytes();
+            buffer.push_str(dtsb);
+            dt_a = 0;
+            dt_b = dtsb.len();
+        } else {
+            def1o!("write field 1 monotonic_usec");
+            debug_assert_eq!(datetime_format, "", "datetime_format must be empty for `short-monotonic`");
+            match monotonic_usec {
+                Some(mu) => {
+                    // [   74.212842]
+                    buffer.push(b'[');
+                    let mud = mu as f64 / 1000000.0;
+                    buffer.push_str(format!("{:>12.6}", mud));
+                    dt_a = buffer.find_byteset(b"0123456789").unwrap_or(1);
+                    dt_b = buffer.len();
+                    buffer.push(b']');
+                },
+                None => {
+                    de_err!("monotonic_usec is None");
+                    // XXX: not sure if this is the best thing to do, maybe return an error?
+                    buffer.push_str("[            ]");
+                    dt_a = 0;
+                    dt_b = 0;
+                }
+            }
+        }
+        debug_assert_le!(dt_a, dt_b, "bad datetime indexes");
 
-        let mut buffer: Vec<u8> = Vec::with_capacity(Self::BUF_DEFAULT_MEDIUM_SZ);
-        let realtime_timestamp: EpochMicroseconds;
-        let source_realtime_timestamp: EpochMicrosecondsOpt;
-        let dt_uses_source: DtUsesSource;
-        let journal_event_size: Count;
+        // field 2 `_HOSTNAME`
+        if let Some(data) = fields.hostname {
+            def1o!("write field 2 _HOSTNAME");
+            buffer.push(b' ');
+            buffer.push_str(data);
+        }
 
-        match self.next_common(rts_filter_before) {
-            ResultNextCommon::Found((rt, srt, dt, event_size)) => {
-                realtime_timestamp = rt;
+        // field 3 `SYSLOG_IDENTIFIER` or `_COMM`
+        match fields.syslog_identifier {
+            Some(data) => {
+                def1o!("write field 3 SYSLOG_IDENTIFIER");
+                buffer.push(b' ');
+                buffer.push_str(data);
+            }
+            None => {
+                if let Some(data) = fields.comm {
+                    def1o!("write field 3 _COMM");
+                    buffer.push(b' ');
+                    buffer.push_str(data);
+                }
+            }
+        }
+
+        // field 4 prefer `_PID`
+        match fields.pid {
+            Some(data) => {
+                def1o!("write field 4 _PID");
+                buffer.push(b'[');
+                buffer.push_str(data);
+                buffer.push(b']');
+            }
+            None => {
+                // field 4 `SYSLOG_PID` if no `_PID`
+                if let Some(data) = fields.syslog_pid {
+                    def1o!("write field 4 SYSLOG_PID");
+                    buffer.push(b'[');
+                    buffer.push_str(data);
+                    buffer.push(b']');
+                }
+            }
+        }
+
+        // field 5 `MESSAGE`
+        if let Some(data) = fields.message {
+            def1o!("write field 5 MESSAGE");
+            buffer.push_str(": ");
+            buffer.push_str(data);
+        }
+
+        (dt_a, dt_b)
+    }
+
+    /// Journal entry output matching `--output=short` and `short*` variations.
+    ///
+    /// For example, the default `short` is:
+    ///
+    /// ```text
+    /// $ PAGER= journalctl --output=short --lines=1 --all --utc --file=./user-1000.journal
+    /// Apr 01 06:44:32 ubuntu22Acorn ubuntu-appindicators@ubuntu.com[1306]: unable to update icon for livepatch
+    /// ```
+    ///
+    /// The special case of `short-monotonic` is:
+    /// ```text
+    /// $ PAGER= journalctl --output=short-monotonic --lines=1 --all --utc --file=./user-1000.journal
+    /// [   74.212842] ubuntu22Acorn ubuntu-appindicators@ubuntu.com[1306]: unable to update icon for livepatch
+    /// ```
+    ///
+    /// The log message format only varies by the `datetime_format`.
+    ///
+    /// In psuedo-code, the log message format comprises fields:
+    ///     `${DATETIME} ${_HOSTNAME} (${SYSLOG_IDENTIFIER${SYSLOG_PID}?${_COMM}})[${_PID}]: ${MESSAGE}`
+    fn next_short(
+        &mut self,
+        rts_filter_before: &EpochMicrosecondsOpt,
+        datetime_format: &str,
+        is_monotonic: bool,
+    ) -> ResultNext {
+        def1n!("({:?})", rts_filter_before);
+        debug_assert!(self.analyzed, "must call `analyze()` before calling `next_order_enumerate_data()`");
+
+        let mut buffer: Vec<u8> = Vec::with_capacity(Self::BUF_DEFAULT_MEDIUM_SZ);
+        let realtime_timestamp: EpochMicroseconds;
+        let source_realtime_timestamp: EpochMicrosecondsOpt;
+        let dt_uses_source: DtUsesSource;
+        let journal_event_size: Count;
+
+        match self.next_common(rts_filter_before) {
+            ResultNextCommon::Found((rt, srt, dt, event_size)) => {
+                realtime_timestamp = rt;
                 source_realtime_timestamp = srt;
                 dt_uses_source = dt;
                 journal_event_size = event_size;
@@ -1987,12 +2475,7 @@ impl<'a> JournalReader {
             }
         }
 
-        let mut data_hostname: Option<&[u8]> = None;
-        let mut data_syslog_identifier: Option<&[u8]> = None;
-        let mut data_syslog_pid: Option<&[u8]> = None;
-        let mut data_comm: Option<&[u8]> = None;
-        let mut data_pid: Option<&[u8]> = None;
-        let mut data_message: Option<&[u8]> = None;
+        let mut fields: ShortFields = ShortFields::default();
         // track which keys have been found, stop calling
         // `sd_journal_enumerate_available_data` when the necessary keys
         // are found
@@ -2051,27 +2534,27 @@ impl<'a> JournalReader {
                 // used to initialize `self.entries_next_short`
                 KEY_HOSTNAME_BYTES => {
                     key_hostname_found = true;
-                    data_hostname = Some(&data[keyn..]);
+                    fields.hostname = Some(&data[keyn..]);
                 }
                 KEY_SYSLOG_IDENTIFIER_BYTES => {
                     key_syslog_identifier_found = true;
-                    data_syslog_identifier = Some(&data[keyn..]);
+                    fields.syslog_identifier = Some(&data[keyn..]);
                 }
                 KEY_SYSLOG_PID_BYTES => {
                     key_syslog_pid_found = true;
-                    data_syslog_pid = Some(&data[keyn..]);
+                    fields.syslog_pid = Some(&data[keyn..]);
                 }
                 KEY_COMM_BYTES => {
                     key_comm_found = true;
-                    data_comm = Some(&data[keyn..]);
+                    fields.comm = Some(&data[keyn..]);
                 }
                 KEY_PID_BYTES => {
                     key_pid_found = true;
-                    data_pid = Some(&data[keyn..]);
+                    fields.pid = Some(&data[keyn..]);
                 }
                 KEY_MESSAGE_BYTES => {
                     key_message_found = true;
-                    data_message = Some(&data[keyn..]);
+                    fields.message = Some(&data[keyn..]);
                 }
                 _ => {}
             }
@@ -2092,97 +2575,23 @@ impl<'a> JournalReader {
             }
         } // end while
 
-        // field 1 Datetime or Monotonic time since boot
-        let dt_a: usize;
-        let dt_b: usize;
         let dt = realtime_or_source_realtime_timestamp_to_datetimel(
             &self.fixed_offset,
             &realtime_timestamp,
             &source_realtime_timestamp,
         );
-        if !is_monotonic {
-            def1o!("write field 1 Datetime");
-            debug_assert_ne!(datetime_format, "", "datetime_format must not be empty");
-            let dts: String = dt.format(datetime_format).to_string();
-            let dtsb: &[u8] = dts.as_bytes();
-            buffer.push_str(dtsb);
-            dt_a = 0;
-            dt_b = dtsb.len();
-        } else {
-            def1o!("write field 1 monotonic_usec");
-            debug_assert_eq!(datetime_format, "", "datetime_format must be empty for `short-monotonic`");
-            match self.get_monotonic_usec() {
-                Some(mu) => {
-                    // [   74.212842]
-                    buffer.push(b'[');
-                    let mud = mu as f64 / 1000000.0;
-                    buffer.push_str(format!("{:>12.6}", mud));
-                    dt_a = match buffer.find_byteset(b"0123456789") {
-                        Some(pos) => pos,
-                        None => 1
-                    };
-                    dt_b = buffer.len();
-                    buffer.push(b']');
-                },
-                None => {
-                    de_err!("get_monotonic_usec() returned None");
-                    // XXX: not sure if this is the best thing to do, maybe return an error?
-                    buffer.push_str("[            ]");
-                    dt_a = 0;
-                    dt_b = 0;
-                }
-            }
-        }
-        debug_assert_le!(dt_a, dt_b, "bad datetime indexes");
-
-        // field 2 `_HOSTNAME`
-        if let Some(data) = data_hostname {
-            def1o!("write field 2 _HOSTNAME");
-            buffer.push(b' ');
-            buffer.push_str(data);
-        }
-
-        // field 3 `SYSLOG_IDENTIFIER` or `_COMM`
-        match data_syslog_identifier {
-            Some(data) => {
-                def1o!("write field 3 SYSLOG_IDENTIFIER");
-                buffer.push(b' ');
-                buffer.push_str(data);
-            }
-            None => {
-                if let Some(data) = data_comm {
-                    def1o!("write field 3 _COMM");
-                    buffer.push(b' ');
-                    buffer.push_str(data);
-                }
-            }
-        }
-
-        // field 4 prefer `_PID`
-        match data_pid {
-            Some(data) => {
-                def1o!("write field 4 _PID");
-                buffer.push(b'[');
-                buffer.push_str(data);
-                buffer.push(b']');
-            }
-            None => {
-                // field 4 `SYSLOG_PID` if no `_PID`
-                if let Some(data) = data_syslog_pid {
-                    def1o!("write field 4 SYSLOG_PID");
-                    buffer.push(b'[');
-                    buffer.push_str(data);
-                    buffer.push(b']');
-                }
-            }
-        }
-
-        // field 5 `MESSAGE`
-        if let Some(data) = data_message {
-            def1o!("write field 5 MESSAGE");
-            buffer.push_str(": ");
-            buffer.push_str(data);
-        }
+        let monotonic_usec: Option<MonotonicMicroseconds> = match is_monotonic {
+            true => self.get_monotonic_usec(),
+            false => None,
+        };
+        let (dt_a, dt_b) = Self::write_short(
+            &mut buffer,
+            &dt,
+            datetime_format,
+            is_monotonic,
+            monotonic_usec,
+            &fields,
+        );
 
         // end of log line
         def1o!("write ENTRY_END_U8");
@@ -2213,6 +2622,52 @@ impl<'a> JournalReader {
         )
     }
 
+    /// Write the first three lines of the `--output=export` form of one entry;
+    /// `__CURSOR`, `__REALTIME_TIMESTAMP`, `__MONOTONIC_TIMESTAMP`.
+    fn write_export_head(
+        buffer: &mut Vec<u8>,
+        cursor: Option<&[u8]>,
+        realtime_timestamp: EpochMicroseconds,
+        monotonic_usec: Option<MonotonicMicroseconds>,
+    ) {
+        // line 1 `__CURSOR`
+
+        match cursor {
+            Some(cursor) => {
+                buffer.push_str(KEY__CURSOR);
+                buffer.push(FIELD_MID_U8);
+                buffer.push_str(cursor);
+                buffer.push(FIELD_END_U8);
+            }
+            None => de_err!("failed to write cursor to buffer"),
+        }
+
+        // line 2 `__REALTIME_TIMESTAMP`
+
+        let mut buffer_numtoa = [0u8; 20];
+
+        buffer.push_str(KEY__REALTIME_TIMESTAMP);
+        buffer.push(FIELD_MID_U8);
+        let buf_: &[u8] = realtime_timestamp.numtoa(10, &mut buffer_numtoa);
+        buffer.push_str(buf_);
+        buffer.push(FIELD_END_U8);
+
+        // line 3 `__MONOTONIC_TIMESTAMP`
+
+        match monotonic_usec {
+            Some(m) => {
+                buffer.push_str(KEY__MONOTONIC_TIMESTAMP_BYTES);
+                buffer.push(FIELD_MID_U8);
+                let buf_: &[u8] = m.numtoa(10, &mut buffer_numtoa);
+                buffer.push_str(buf_);
+                buffer.push(FIELD_END_U8);
+            }
+            None => {
+                de_err!("monotonic_usec is None; cannot write monotonic timestamp to buffer");
+            }
+        };
+    }
+
     /// Journal entry output matching `--output=export`.
     ///
     /// Saves data directly to a `Vec<u8>` buffer; no intermediary or
@@ -2290,47 +2745,14 @@ impl<'a> JournalReader {
             }
         }
 
-        // line 1 `__CURSOR`
-
-        match Self::call_sd_journal_get_cursor(
+        let cursor: Option<&[u8]> = Self::call_sd_journal_get_cursor(
             &mut self.journal_handle_ptr,
             &mut self.journal_api_ptr,
             &mut self.api_calls,
             &mut self.api_call_errors,
-        ) {
-            Some(cursor) => {
-                buffer.push_str(KEY__CURSOR);
-                buffer.push(FIELD_MID_U8);
-                buffer.push_str(cursor);
-                buffer.push(FIELD_END_U8);
-            }
-            None => de_err!("failed to write cursor to buffer"),
-        }
-
-        // line 2 `__REALTIME_TIMESTAMP`
-
-        let mut buffer_numtoa = [0u8; 20];
-
-        buffer.push_str(KEY__REALTIME_TIMESTAMP);
-        buffer.push(FIELD_MID_U8);
-        let buf_: &[u8] = realtime_timestamp.numtoa(10, &mut buffer_numtoa);
-        buffer.push_str(buf_);
-        buffer.push(FIELD_END_U8);
-
-        // line 3 `__MONOTONIC_TIMESTAMP`
-
-        match self.get_monotonic_usec() {
-            Some(m) => {
-                buffer.push_str(KEY__MONOTONIC_TIMESTAMP_BYTES);
-                buffer.push(FIELD_MID_U8);
-                let buf_: &[u8] = m.numtoa(10, &mut buffer_numtoa);
-                buffer.push_str(buf_);
-                buffer.push(FIELD_END_U8);
-            }
-            None => {
-                de_err!("get_monotonic_usec() failed; cannot write monotonic timestamp to buffer");
-            }
-        };
+        );
+        let monotonic_usec: Option<MonotonicMicroseconds> = self.get_monotonic_usec();
+        Self::write_export_head(&mut buffer, cursor, realtime_timestamp, monotonic_usec);
 
         // remaining lines
 
@@ -2503,6 +2925,85 @@ impl<'a> JournalReader {
         KEY__MONOTONIC_TIMESTAMP_BYTES,
     ];
 
+    /// Write the `--output=verbose` form of one entry.
+    ///
+    /// Returns the buffer and the datetime substring indexes `(dt_a, dt_b)`.
+    fn write_verbose(
+        mut fields: HashMap<&[u8], &[u8]>,
+        dt: &DateTimeL,
+        cursor: Option<&[u8]>,
+    ) -> (Vec<u8>, usize, usize) {
+        // write it all to one buffer
+        let mut buffer: Vec<u8> = Vec::with_capacity(Self::BUF_DEFAULT_LARGE_SZ);
+
+        // line 1 Datetime and Cursor
+
+        // field 1 Datetime
+        let dts: String = dt.format(DATETIME_FORMAT_VERBOSE).to_string();
+        let dtsb: &[u8] = dts.as_bytes();
+        def1o!("field 1 datetime");
+        buffer.push_str(dtsb);
+        let dt_a: usize = 0;
+        let dt_b: usize = dtsb.len();
+        debug_assert_le!(dt_a, dt_b, "bad datetime indexes");
+        buffer.push(b' ');
+
+        // field 2 Cursor
+        match cursor {
+            Some(cursor) => {
+                def1o!("field 2 Cursor");
+                buffer.push(b'[');
+                buffer.push_str(cursor);
+                buffer.push(b']');
+            }
+            None => {
+                de_err!("failed to write cursor to buffer");
+            }
+        }
+        buffer.push(FIELD_END_U8);
+
+        // set aside `_SOURCE_REALTIME_TIMESTAMP`
+        let source_realtime_timestamp_field: Option<&[u8]> = fields.remove(KEY_SOURCE_REALTIME_TIMESTAMP_BYTES);
+
+        // write the remaining lines in order of `FIELD_ORDER_VERBOSE`
+        for field in &Self::FIELD_ORDER_VERBOSE {
+            if let Some(value) = fields.remove(field) {
+                def1o!("field from fields {:?}", field.as_bstr());
+                buffer.push_str(FIELD_BEG_VERBOSE);
+                buffer.push_str(field);
+                buffer.push(FIELD_MID_U8);
+                buffer.push_str(value);
+                buffer.push(FIELD_END_U8);
+            }
+        }
+
+        // write any remaining lines in a sorted order
+        // (do not use random order of `HashMap.into_iter()`)
+        for (field, value) in fields.into_iter().sorted() {
+            buffer.push_str(FIELD_BEG_VERBOSE);
+            def1o!("field remaining {:?}", field.as_bstr());
+            buffer.push_str(field);
+            buffer.push(FIELD_MID_U8);
+            buffer.push_str(value);
+            buffer.push(FIELD_END_U8);
+        }
+
+        // write `_SOURCE_REALTIME_TIMESTAMP` last
+        if let Some(s) = source_realtime_timestamp_field {
+            def1o!("field (second-to-last) {:?}", KEY_SOURCE_REALTIME_TIMESTAMP);
+            buffer.push_str(FIELD_BEG_VERBOSE);
+            buffer.push_str(KEY_SOURCE_REALTIME_TIMESTAMP);
+            buffer.push(FIELD_MID_U8);
+            buffer.push_str(s);
+            buffer.push(FIELD_END_U8);
+        } else {
+            de_err!("failed to write _SOURCE_REALTIME_TIMESTAMP to buffer");
+        }
+
+        (buffer, dt_a, dt_b)
+
+    }
+
     /// Journal entry output matching `--output=verbose`.
     ///
     /// Follows approximately the same field ordering as
@@ -2644,89 +3145,18 @@ impl<'a> JournalReader {
         ));
         summary_stat!(self.events_accepted += 1);
 
-        // write it all to one buffer
-        let mut buffer: Vec<u8> = Vec::with_capacity(Self::BUF_DEFAULT_LARGE_SZ);
-
-        // line 1 Datetime and Cursor
-
-        // field 1 Datetime
         let dt = realtime_or_source_realtime_timestamp_to_datetimel(
             &self.fixed_offset,
             &realtime_timestamp,
             &source_realtime_timestamp,
         );
-        let dts: String = dt.format(DATETIME_FORMAT_VERBOSE).to_string();
-        let dtsb: &[u8] = dts.as_bytes();
-        def1o!("field 1 datetime");
-        buffer.push_str(dtsb);
-        let dt_a: usize = 0;
-        let dt_b: usize = dtsb.len();
-        debug_assert_le!(dt_a, dt_b, "bad datetime indexes");
-        buffer.push(b' ');
-
-        // field 2 Cursor
-        match Self::call_sd_journal_get_cursor(
+        let cursor: Option<&[u8]> = Self::call_sd_journal_get_cursor(
             &mut self.journal_handle_ptr,
             &mut self.journal_api_ptr,
             &mut self.api_calls,
             &mut self.api_call_errors,
-        ) {
-            Some(cursor) => {
-                def1o!("field 2 Cursor");
-                buffer.push(b'[');
-                buffer.push_str(cursor);
-                buffer.push(b']');
-            }
-            None => {
-                de_err!("failed to write cursor to buffer");
-            }
-        }
-        buffer.push(FIELD_END_U8);
-
-        // set aside `_SOURCE_REALTIME_TIMESTAMP`
-        let source_realtime_timestamp_field: Option<&[u8]> = match fields.remove(&*KEY_SOURCE_REALTIME_TIMESTAMP_BYTES) {
-            Some(s) => {
-                Some(s)
-            }
-            None => {
-                None
-            }
-        };
-
-        // write the remaining lines in order of `FIELD_ORDER_VERBOSE`
-        for field in &Self::FIELD_ORDER_VERBOSE {
-            if let Some(value) = fields.remove(field) {
-                def1o!("field from fields {:?}", field.as_bstr());
-                buffer.push_str(FIELD_BEG_VERBOSE);
-                buffer.push_str(field);
-                buffer.push(FIELD_MID_U8);
-                buffer.push_str(value);
-                buffer.push(FIELD_END_U8);
-            }
-        }
-
-        // write any remaining lines in a sorted order
-        // (do not use random order of `HashMap.into_iter()`)
-        for (field, value) in fields.into_iter().sorted() {
-            buffer.push_str(FIELD_BEG_VERBOSE);
-            def1o!("field remaining {:?}", field.as_bstr());
-            buffer.push_str(field);
-            buffer.push(FIELD_MID_U8);
-            buffer.push_str(value);
-            buffer.push(FIELD_END_U8);
-        }
-
-        // write `_SOURCE_REALTIME_TIMESTAMP` last
-        if let Some(s) = source_realtime_timestamp_field {
-            def1o!("field (second-to-last) {:?}", KEY_SOURCE_REALTIME_TIMESTAMP);
-            buffer.push_str(FIELD_BEG_VERBOSE);
-            buffer.push_str(KEY_SOURCE_REALTIME_TIMESTAMP);
-            buffer.push(FIELD_MID_U8);
-            buffer.push_str(s);
-            buffer.push(FIELD_END_U8);
-        } else {
-            de_err!("failed to write _SOURCE_REALTIME_TIMESTAMP to buffer");
-        }
+        );
+        let (buffer, dt_a, dt_b) = Self::write_verbose(fields, &dt, cursor);
         def1x!();
 
         ResultNext::Found(
@@ -3036,6 +3466,11 @@ impl<'a> JournalReader {
         let journalreader_api_calls: Count = self.api_calls;
         let journalreader_api_call_errors: Count = self.api_call_errors;
         let journalreader_out_of_order: Count = self.out_of_order;
+        let journalreader_entries_recovered: Count = self.entries_recovered;
+        let (journalreader_entries_damaged, journalreader_objects_damaged) = match self.recovery {
+            Some(ref jr) => (jr.count_entries_damaged(), jr.count_objects_damaged()),
+            None => (0, 0),
+        };
 
         SummaryJournalReader {
             journalreader_events_processed,
@@ -3050,6 +3485,9 @@ impl<'a> JournalReader {
             journalreader_api_calls,
             journalreader_api_call_errors,
             journalreader_out_of_order,
+            journalreader_entries_recovered,
+            journalreader_entries_damaged,
+            journalreader_objects_damaged,
         }
     }
 
@@ -0,0 +1,910 @@
+// src/readers/journalrecovery.rs
+
+//! Implements a [`JournalRecovery`], a defensive walker of the objects within
+//! a systemd [`.journal` file].
+//!
+//! The `JournalRecovery` is used by a [`JournalReader`] when `libsystemd`
+//! cannot make sense of a journal file; for example, a journal file copied
+//! from a crashed host that was left in "online" state or has a truncated
+//! tail. `libsystemd` will either fail to open such a file or will stop
+//! returning entries at the first damaged object.
+//! The cheap [`JournalHeader`] is read first to decide if `libsystemd`
+//! stopped early.
+//!
+//! The `JournalRecovery` does not use `libsystemd`. It maps the journal file
+//! into memory and
+//! 1. walks the chain of `ENTRY_ARRAY` objects starting from the header
+//!    field `entry_array_offset`,
+//! 2. scans the arena object-by-object to find `ENTRY` objects that are not
+//!    referenced by any (possibly damaged) `ENTRY_ARRAY`.
+//!
+//! Every object offset, object size, and object type is checked before use.
+//! The payload of each `DATA` object is checked against the stored hash.
+//! Damaged objects are skipped and counted.
+//!
+//! [`.journal` file]: https://systemd.io/JOURNAL_FILE_FORMAT/
+//! [`JournalReader`]: crate::readers::journalreader::JournalReader
+
+use std::collections::{
+    BTreeSet,
+    HashSet,
+};
+use std::hash::Hasher;
+use std::fs::File;
+use std::io::{
+    Error,
+    ErrorKind,
+    Read,
+    Result,
+};
+use std::ops::Deref;
+use std::path::Path;
+
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    def1n,
+    def1o,
+    def1x,
+    def1ñ,
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+use ::memmap2::Mmap;
+use ::siphasher::sip::SipHasher24;
+
+use crate::common::Count;
+use crate::data::journal::{
+    EpochMicroseconds,
+    MonotonicMicroseconds,
+};
+use crate::subprojects::lzma_rs;
+
+/// Journal file signature, first eight bytes of the file.
+pub const JOURNAL_SIGNATURE: &[u8; 8] = b"LPKSHHRH";
+
+/// Header field `state` value; the file was closed cleanly.
+pub const STATE_OFFLINE: u8 = 0;
+/// Header field `state` value; the file is (or was last) open for writing.
+pub const STATE_ONLINE: u8 = 1;
+/// Header field `state` value; the file was rotated and closed cleanly.
+pub const STATE_ARCHIVED: u8 = 2;
+
+const HEADER_INCOMPATIBLE_COMPRESSED_XZ: u32 = 1 << 0;
+const HEADER_INCOMPATIBLE_COMPRESSED_LZ4: u32 = 1 << 1;
+const HEADER_INCOMPATIBLE_KEYED_HASH: u32 = 1 << 2;
+const HEADER_INCOMPATIBLE_COMPRESSED_ZSTD: u32 = 1 << 3;
+const HEADER_INCOMPATIBLE_COMPACT: u32 = 1 << 4;
+const HEADER_INCOMPATIBLE_SUPPORTED: u32 = HEADER_INCOMPATIBLE_COMPRESSED_XZ
+    | HEADER_INCOMPATIBLE_COMPRESSED_LZ4
+    | HEADER_INCOMPATIBLE_KEYED_HASH
+    | HEADER_INCOMPATIBLE_COMPRESSED_ZSTD
+    | HEADER_INCOMPATIBLE_COMPACT;
+
+const OBJECT_COMPRESSED_XZ: u8 = 1 << 0;
+const OBJECT_COMPRESSED_LZ4: u8 = 1 << 1;
+const OBJECT_COMPRESSED_ZSTD: u8 = 1 << 2;
+const OBJECT_COMPRESSED_MASK: u8 = OBJECT_COMPRESSED_XZ
+    | OBJECT_COMPRESSED_LZ4
+    | OBJECT_COMPRESSED_ZSTD;
+
+const OBJECT_DATA: u8 = 1;
+const OBJECT_FIELD: u8 = 2;
+const OBJECT_ENTRY: u8 = 3;
+const OBJECT_DATA_HASH_TABLE: u8 = 4;
+const OBJECT_FIELD_HASH_TABLE: u8 = 5;
+const OBJECT_ENTRY_ARRAY: u8 = 6;
+const OBJECT_TAG: u8 = 7;
+
+/// Size of the `ObjectHeader`; `type`, `flags`, `reserved`, `size`.
+const OBJECT_HEADER_SZ: u64 = 16;
+/// Smallest possible journal header (systemd v187).
+const HEADER_SZ_MIN: u64 = 208;
+/// Offset of `items` within an `ENTRY` object.
+const ENTRY_ITEMS_OFFSET: u64 = 64;
+/// Offset of `items` within an `ENTRY_ARRAY` object.
+const ENTRY_ARRAY_ITEMS_OFFSET: u64 = 24;
+/// Offset of `payload` within a regular `DATA` object.
+const DATA_PAYLOAD_OFFSET: u64 = 64;
+/// Offset of `payload` within a compact `DATA` object.
+const DATA_PAYLOAD_OFFSET_COMPACT: u64 = 72;
+
+/// Guard against decompression bombs within a single `DATA` object.
+const DATA_DECOMPRESSED_SZ_MAX: usize = 0x1000_0000;
+
+/// A journal entry recovered by a [`JournalRecovery`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RecoveredEntry {
+    pub seqnum: u64,
+    pub realtime: EpochMicroseconds,
+    pub monotonic: MonotonicMicroseconds,
+    pub boot_id: [u8; 16],
+    pub xor_hash: u64,
+    /// The `DATA` payloads of the entry, each like `KEY=value`, in the order
+    /// referenced by the `ENTRY` object.
+    pub data: Vec<Vec<u8>>,
+}
+
+impl RecoveredEntry {
+    /// Return the journal cursor for this entry, in the same form as
+    /// returned by `sd_journal_get_cursor`.
+    pub fn cursor(
+        &self,
+        seqnum_id: &[u8; 16],
+    ) -> String {
+        format!(
+            "s={};i={:x};b={};m={:x};t={:x};x={:x}",
+            id128_to_hex(seqnum_id),
+            self.seqnum,
+            id128_to_hex(&self.boot_id),
+            self.monotonic,
+            self.realtime,
+            self.xor_hash,
+        )
+    }
+}
+
+/// Lowercase hexadecimal of a 128-bit id, as printed by `journalctl`.
+pub fn id128_to_hex(id: &[u8; 16]) -> String {
+    let mut s = String::with_capacity(32);
+    for b in id.iter() {
+        s.push_str(&format!("{:02x}", b));
+    }
+
+    s
+}
+
+/// Jenkins "lookup3" `hashlittle2`.
+///
+/// Ported from the public domain `lookup3.c` by Bob Jenkins, as used by
+/// systemd `src/basic/hash-funcs.c` `jenkins_hash64`.
+fn jenkins_hashlittle2(
+    data: &[u8],
+    pc: &mut u32,
+    pb: &mut u32,
+) {
+    #[inline(always)]
+    fn mix(a: &mut u32, b: &mut u32, c: &mut u32) {
+        *a = a.wrapping_sub(*c); *a ^= c.rotate_left(4); *c = c.wrapping_add(*b);
+        *b = b.wrapping_sub(*a); *b ^= a.rotate_left(6); *a = a.wrapping_add(*c);
+        *c = c.wrapping_sub(*b); *c ^= b.rotate_left(8); *b = b.wrapping_add(*a);
+        *a = a.wrapping_sub(*c); *a ^= c.rotate_left(16); *c = c.wrapping_add(*b);
+        *b = b.wrapping_sub(*a); *b ^= a.rotate_left(19); *a = a.wrapping_add(*c);
+        *c = c.wrapping_sub(*b); *c ^= b.rotate_left(4); *b = b.wrapping_add(*a);
+    }
+    #[inline(always)]
+    fn final_(a: &mut u32, b: &mut u32, c: &mut u32) {
+        *c ^= *b; *c = c.wrapping_sub(b.rotate_left(14));
+        *a ^= *c; *a = a.wrapping_sub(c.rotate_left(11));
+        *b ^= *a; *b = b.wrapping_sub(a.rotate_left(25));
+        *c ^= *b; *c = c.wrapping_sub(b.rotate_left(16));
+        *a ^= *c; *a = a.wrapping_sub(c.rotate_left(4));
+        *b ^= *a; *b = b.wrapping_sub(a.rotate_left(14));
+        *c ^= *b; *c = c.wrapping_sub(b.rotate_left(24));
+    }
+    #[inline(always)]
+    fn le32(k: &[u8]) -> u32 {
+        let mut buf = [0u8; 4];
+        buf[..k.len()].copy_from_slice(k);
+        u32::from_le_bytes(buf)
+    }
+
+    let mut a: u32 = 0xdeadbeef_u32
+        .wrapping_add(data.len() as u32)
+        .wrapping_add(*pc);
+    let mut b: u32 = a;
+    let mut c: u32 = a.wrapping_add(*pb);
+
+    let mut k: &[u8] = data;
+    while k.len() > 12 {
+        a = a.wrapping_add(le32(&k[0..4]));
+        b = b.wrapping_add(le32(&k[4..8]));
+        c = c.wrapping_add(le32(&k[8..12]));
+        mix(&mut a, &mut b, &mut c);
+        k = &k[12..];
+    }
+    if k.is_empty() {
+        *pc = c;
+        *pb = b;
+        return;
+    }
+    a = a.wrapping_add(le32(&k[..k.len().min(4)]));
+    if k.len() > 4 {
+        b = b.wrapping_add(le32(&k[4..k.len().min(8)]));
+    }
+    if k.len() > 8 {
+        c = c.wrapping_add(le32(&k[8..]));
+    }
+    final_(&mut a, &mut b, &mut c);
+    *pc = c;
+    *pb = b;
+}
+
+/// systemd `jenkins_hash64`; the `DATA` object hash for journal files
+/// without the `KEYED_HASH` header flag, and always the hash used for the
+/// `ENTRY` field `xor_hash`.
+pub fn jenkins_hash64(data: &[u8]) -> u64 {
+    let mut a: u32 = 0;
+    let mut b: u32 = 0;
+    jenkins_hashlittle2(data, &mut a, &mut b);
+
+    ((a as u64) << 32) | (b as u64)
+}
+
+/// systemd `siphash24` keyed by the journal header `file_id`; the `DATA`
+/// object hash for journal files with the `KEYED_HASH` header flag.
+pub fn siphash24_keyed(
+    data: &[u8],
+    key: &[u8; 16],
+) -> u64 {
+    let mut hasher = SipHasher24::new_with_key(key);
+    hasher.write(data);
+
+    hasher.finish()
+}
+
+#[inline(always)]
+fn read_u8(data: &[u8], at: u64) -> Option<u8> {
+    data.get(at as usize).copied()
+}
+
+#[inline(always)]
+fn read_le32(data: &[u8], at: u64) -> Option<u32> {
+    let at = usize::try_from(at).ok()?;
+    let b = data.get(at..at.checked_add(4)?)?;
+
+    Some(u32::from_le_bytes(b.try_into().ok()?))
+}
+
+#[inline(always)]
+fn read_le64(data: &[u8], at: u64) -> Option<u64> {
+    let at = usize::try_from(at).ok()?;
+    let b = data.get(at..at.checked_add(8)?)?;
+
+    Some(u64::from_le_bytes(b.try_into().ok()?))
+}
+
+#[inline(always)]
+fn read_id128(data: &[u8], at: u64) -> Option<[u8; 16]> {
+    let at = usize::try_from(at).ok()?;
+    let b = data.get(at..at.checked_add(16)?)?;
+
+    b.try_into().ok()
+}
+
+/// The journal header fields needed to decide if `libsystemd` missed
+/// entries; read without reading the rest of the file.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct JournalHeader {
+    /// Header field `state`.
+    pub state: u8,
+    /// Header field `header_size`.
+    pub header_size: u64,
+    /// Header field `arena_size`.
+    pub arena_size: u64,
+    /// Header field `n_entries`.
+    pub n_entries: u64,
+    /// Header field `tail_entry_realtime`.
+    pub tail_entry_realtime: EpochMicroseconds,
+    /// Size of the journal file.
+    pub filesz: u64,
+}
+
+impl JournalHeader {
+    /// Read only the header of the journal file at `path`.
+    pub fn read(path: &Path) -> Result<JournalHeader> {
+        let mut file = File::open(path)?;
+        let filesz: u64 = file.metadata()?.len();
+        let mut data: Vec<u8> = Vec::with_capacity(HEADER_SZ_MIN as usize);
+        (&mut file).take(HEADER_SZ_MIN).read_to_end(&mut data)?;
+
+        JournalHeader::from_bytes(&data, filesz)
+    }
+
+    /// Parse the header from the first bytes of a journal file of size
+    /// `filesz`.
+    ///
+    /// Returns `Err` if `data` is not the start of a journal file.
+    pub fn from_bytes(
+        data: &[u8],
+        filesz: u64,
+    ) -> Result<JournalHeader> {
+        if data.len() < HEADER_SZ_MIN as usize || &data[..8] != JOURNAL_SIGNATURE {
+            return Result::Err(Error::new(
+                ErrorKind::InvalidData,
+                "not a systemd journal file; bad signature or too short",
+            ));
+        }
+
+        // `HEADER_SZ_MIN` guarantees these reads
+        Result::Ok(JournalHeader {
+            state: read_u8(data, 16).unwrap(),
+            header_size: read_le64(data, 88).unwrap(),
+            arena_size: read_le64(data, 96).unwrap(),
+            n_entries: read_le64(data, 152).unwrap(),
+            tail_entry_realtime: read_le64(data, 192).unwrap(),
+            filesz,
+        })
+    }
+
+    /// The header claims more bytes than the file holds.
+    pub fn is_truncated(&self) -> bool {
+        self.header_size.saturating_add(self.arena_size) > self.filesz
+    }
+
+    /// A reader of this file may have stopped before the last entry.
+    ///
+    /// `rt_last` is the `realtime` of the last entry read, `None` if no entry
+    /// was read. `ts_filter_after` is the earliest `realtime` wanted.
+    pub fn is_missing_entries(
+        &self,
+        rt_last: Option<EpochMicroseconds>,
+        ts_filter_after: Option<EpochMicroseconds>,
+    ) -> bool {
+        if self.is_truncated() {
+            return true;
+        }
+        match rt_last {
+            Some(rt) => rt < self.tail_entry_realtime,
+            None => {
+                self.n_entries != 0
+                    && match ts_filter_after {
+                        Some(after) => after <= self.tail_entry_realtime,
+                        None => true,
+                    }
+            }
+        }
+    }
+}
+
+/// The bytes of a journal file.
+enum JournalData {
+    /// The journal file mapped into memory.
+    Map(Mmap),
+    /// The journal file already in memory.
+    Vec(Vec<u8>),
+}
+
+impl Deref for JournalData {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            JournalData::Map(mmap) => mmap,
+            JournalData::Vec(data) => data,
+        }
+    }
+}
+
+/// Header of one journal object; only valid objects are returned by
+/// [`JournalRecovery::object_at`].
+#[derive(Clone, Copy, Debug)]
+struct ObjectHeader {
+    otype: u8,
+    flags: u8,
+    size: u64,
+}
+
+/// A defensive, read-only walker of a systemd `.journal` file.
+///
+/// See the [module-level documentation].
+///
+/// [module-level documentation]: self
+pub struct JournalRecovery {
+    /// The entire journal file.
+    data: JournalData,
+    /// Header field `state`.
+    state: u8,
+    /// Header field `incompatible_flags`.
+    incompatible_flags: u32,
+    /// Header field `file_id`; the key for `siphash24`.
+    file_id: [u8; 16],
+    /// Header field `seqnum_id`; used in cursors.
+    seqnum_id: [u8; 16],
+    /// Header field `header_size`.
+    header_size: u64,
+    /// End of the readable arena; the lesser of the header
+    /// `header_size + arena_size` and the file size.
+    arena_end: u64,
+    /// Header field `n_entries`.
+    n_entries: u64,
+    /// Offsets of `ENTRY` objects, ordered by sequence number then offset.
+    entries: Vec<u64>,
+    /// Offsets of objects found damaged. A set so a `DATA` object referenced
+    /// by many entries is counted once.
+    damaged: HashSet<u64>,
+    /// Summary statistic.
+    /// `ENTRY` objects that had at least one damaged `DATA` object.
+    entries_damaged: Count,
+}
+
+impl std::fmt::Debug for JournalRecovery {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        f.debug_struct("JournalRecovery")
+            .field("state", &self.state)
+            .field("incompatible_flags", &self.incompatible_flags)
+            .field("header_size", &self.header_size)
+            .field("arena_end", &self.arena_end)
+            .field("filesz", &self.data.len())
+            .field("n_entries", &self.n_entries)
+            .field("entries", &self.entries.len())
+            .field("damaged", &self.damaged.len())
+            .finish()
+    }
+}
+
+impl JournalRecovery {
+    /// Map the journal file at `path` into memory and find all recoverable
+    /// `ENTRY` objects.
+    pub fn open(path: &Path) -> Result<JournalRecovery> {
+        def1n!("({:?})", path);
+        let file = File::open(path)?;
+        // SAFETY: the map is only read. Every read is bounds checked against
+        // the size of the map. A journal file is appended to but is not
+        // truncated in place by `systemd-journald`.
+        let mmap: Mmap = unsafe { Mmap::map(&file)? };
+        let jr = JournalRecovery::from_data(JournalData::Map(mmap));
+        def1x!("{:?}", jr);
+
+        jr
+    }
+
+    /// Parse the journal header from `data` and find all recoverable
+    /// `ENTRY` objects.
+    ///
+    /// Returns `Err` if the header is not usable.
+    pub fn from_vec(data: Vec<u8>) -> Result<JournalRecovery> {
+        JournalRecovery::from_data(JournalData::Vec(data))
+    }
+
+    fn from_data(data: JournalData) -> Result<JournalRecovery> {
+        def1n!("(data len {})", data.len());
+        if data.len() < HEADER_SZ_MIN as usize || &data[..8] != JOURNAL_SIGNATURE {
+            def1x!("bad signature or too short");
+            return Result::Err(Error::new(
+                ErrorKind::InvalidData,
+                "not a systemd journal file; bad signature or too short",
+            ));
+        }
+        // `HEADER_SZ_MIN` guarantees these reads
+        let incompatible_flags: u32 = read_le32(&data, 12).unwrap();
+        let state: u8 = read_u8(&data, 16).unwrap();
+        let file_id: [u8; 16] = read_id128(&data, 24).unwrap();
+        let seqnum_id: [u8; 16] = read_id128(&data, 72).unwrap();
+        let header_size: u64 = read_le64(&data, 88).unwrap();
+        let arena_size: u64 = read_le64(&data, 96).unwrap();
+        let n_entries: u64 = read_le64(&data, 152).unwrap();
+        let entry_array_offset: u64 = read_le64(&data, 176).unwrap();
+        def1o!(
+            "incompatible_flags {:#x}, state {}, header_size {}, arena_size {}, n_entries {}, entry_array_offset {}",
+            incompatible_flags, state, header_size, arena_size, n_entries, entry_array_offset,
+        );
+        if incompatible_flags & !HEADER_INCOMPATIBLE_SUPPORTED != 0 {
+            def1x!("unsupported incompatible_flags {:#x}", incompatible_flags);
+            return Result::Err(Error::new(
+                ErrorKind::Unsupported,
+                format!("journal file has unsupported incompatible flags {:#x}", incompatible_flags),
+            ));
+        }
+        if header_size < HEADER_SZ_MIN || header_size > data.len() as u64 {
+            def1x!("bad header_size {}", header_size);
+            return Result::Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("journal file has bad header_size {}", header_size),
+            ));
+        }
+        let arena_end: u64 = std::cmp::min(
+            header_size.saturating_add(arena_size),
+            data.len() as u64,
+        );
+
+        let mut jr = JournalRecovery {
+            data,
+            state,
+            incompatible_flags,
+            file_id,
+            seqnum_id,
+            header_size,
+            arena_end,
+            n_entries,
+            entries: Vec::new(),
+            damaged: HashSet::new(),
+            entries_damaged: 0,
+        };
+
+        // (seqnum, offset) of every `ENTRY` object found
+        let mut found: BTreeSet<(u64, u64)> = BTreeSet::new();
+        jr.walk_entry_arrays(entry_array_offset, &mut found);
+        jr.scan_arena(&mut found);
+        jr.entries = found.into_iter().map(|(_seqnum, offset)| offset).collect();
+        def1x!("found {} entries", jr.entries.len());
+
+        Result::Ok(jr)
+    }
+
+    /// Header field `state`.
+    pub const fn state(&self) -> u8 {
+        self.state
+    }
+
+    /// Header field `seqnum_id`.
+    pub const fn seqnum_id(&self) -> &[u8; 16] {
+        &self.seqnum_id
+    }
+
+    /// The file was not closed cleanly or is truncated; `libsystemd` may
+    /// have missed entries.
+    pub fn is_unclean(&self) -> bool {
+        self.state != STATE_OFFLINE && self.state != STATE_ARCHIVED
+            || self.is_truncated()
+    }
+
+    /// The header claims more bytes than the file holds.
+    pub fn is_truncated(&self) -> bool {
+        let header_arena_end = read_le64(&self.data, 96)
+            .unwrap_or(0)
+            .saturating_add(self.header_size);
+
+        header_arena_end > self.data.len() as u64
+    }
+
+    /// Count of `ENTRY` objects found.
+    pub fn count_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Header field `n_entries`.
+    pub const fn n_entries_header(&self) -> u64 {
+        self.n_entries
+    }
+
+    /// Count of distinct damaged objects found so far.
+    pub fn count_objects_damaged(&self) -> Count {
+        self.damaged.len() as Count
+    }
+
+    /// Count of `ENTRY` objects recovered with missing data so far.
+    pub const fn count_entries_damaged(&self) -> Count {
+        self.entries_damaged
+    }
+
+    #[inline(always)]
+    const fn is_compact(&self) -> bool {
+        self.incompatible_flags & HEADER_INCOMPATIBLE_COMPACT != 0
+    }
+
+    fn mark_damaged(
+        &mut self,
+        offset: u64,
+    ) {
+        def1ñ!("damaged object at {}", offset);
+        self.damaged.insert(offset);
+    }
+
+    /// Smallest valid object size for the object type.
+    const fn object_size_min(
+        &self,
+        otype: u8,
+    ) -> u64 {
+        match otype {
+            OBJECT_DATA => match self.is_compact() {
+                true => DATA_PAYLOAD_OFFSET_COMPACT,
+                false => DATA_PAYLOAD_OFFSET,
+            },
+            OBJECT_FIELD => 40,
+            OBJECT_ENTRY => ENTRY_ITEMS_OFFSET,
+            OBJECT_DATA_HASH_TABLE | OBJECT_FIELD_HASH_TABLE => OBJECT_HEADER_SZ,
+            OBJECT_ENTRY_ARRAY => ENTRY_ARRAY_ITEMS_OFFSET,
+            OBJECT_TAG => 64,
+            _ => u64::MAX,
+        }
+    }
+
+    /// Return the `ObjectHeader` at `offset` if it is plausible; aligned,
+    /// within the arena, a known type, and a size that fits.
+    fn object_at(
+        &self,
+        offset: u64,
+    ) -> Option<ObjectHeader> {
+        if offset < self.header_size
+            || !offset.is_multiple_of(8)
+            || offset.checked_add(OBJECT_HEADER_SZ)? > self.arena_end
+        {
+            return None;
+        }
+        let otype = read_u8(&self.data, offset)?;
+        let flags = read_u8(&self.data, offset + 1)?;
+        let size = read_le64(&self.data, offset + 8)?;
+        if flags & !OBJECT_COMPRESSED_MASK != 0
+            || (otype != OBJECT_DATA && flags != 0)
+            || size < self.object_size_min(otype)
+            || offset.checked_add(size)? > self.arena_end
+        {
+            return None;
+        }
+        // the six reserved bytes are always zero
+        if self.data[offset as usize + 2..offset as usize + 8].iter().any(|b| *b != 0) {
+            return None;
+        }
+
+        Some(ObjectHeader { otype, flags, size })
+    }
+
+    /// Return the `seqnum` of the `ENTRY` object at `offset`.
+    fn entry_seqnum_at(
+        &self,
+        offset: u64,
+    ) -> Option<u64> {
+        match self.object_at(offset) {
+            Some(oh) if oh.otype == OBJECT_ENTRY => read_le64(&self.data, offset + 16),
+            _ => None,
+        }
+    }
+
+    /// Follow the chain of `ENTRY_ARRAY` objects, collecting the
+    /// `ENTRY` objects referenced.
+    fn walk_entry_arrays(
+        &mut self,
+        entry_array_offset: u64,
+        found: &mut BTreeSet<(u64, u64)>,
+    ) {
+        def1n!("({})", entry_array_offset);
+        let item_sz: u64 = if self.is_compact() { 4 } else { 8 };
+        let mut visited: HashSet<u64> = HashSet::new();
+        let mut array_offset = entry_array_offset;
+        while array_offset != 0 && visited.insert(array_offset) {
+            let oh = match self.object_at(array_offset) {
+                Some(oh) if oh.otype == OBJECT_ENTRY_ARRAY => oh,
+                _ => {
+                    def1o!("bad ENTRY_ARRAY at {}", array_offset);
+                    self.mark_damaged(array_offset);
+                    break;
+                }
+            };
+            let items: u64 = (oh.size - ENTRY_ARRAY_ITEMS_OFFSET) / item_sz;
+            for i in 0..items {
+                let at = array_offset + ENTRY_ARRAY_ITEMS_OFFSET + i * item_sz;
+                let entry_offset: u64 = match self.is_compact() {
+                    true => read_le32(&self.data, at).unwrap_or(0) as u64,
+                    false => read_le64(&self.data, at).unwrap_or(0),
+                };
+                // unused trailing items are zero
+                if entry_offset == 0 {
+                    break;
+                }
+                match self.entry_seqnum_at(entry_offset) {
+                    Some(seqnum) => {
+                        found.insert((seqnum, entry_offset));
+                    }
+                    None => {
+                        def1o!("bad ENTRY at {} referenced by ENTRY_ARRAY at {}", entry_offset, array_offset);
+                        self.mark_damaged(entry_offset);
+                    }
+                }
+            }
+            array_offset = read_le64(&self.data, array_offset + 16).unwrap_or(0);
+        }
+        def1x!("found {} entries", found.len());
+    }
+
+    /// Scan the arena object-by-object collecting `ENTRY` objects.
+    ///
+    /// Upon finding an implausible object, search forward at the object
+    /// alignment for the next plausible object. The implausible region is
+    /// counted as one damaged object unless it is entirely zero bytes
+    /// (unused preallocated space).
+    fn scan_arena(
+        &mut self,
+        found: &mut BTreeSet<(u64, u64)>,
+    ) {
+        def1n!();
+        let mut offset: u64 = (self.header_size + 7) & !7;
+        let mut bad_region_start: Option<u64> = None;
+        let mut bad_region_nonzero: bool = false;
+        while offset + OBJECT_HEADER_SZ <= self.arena_end {
+            match self.object_at(offset) {
+                Some(oh) => {
+                    if let Some(start) = bad_region_start.take() {
+                        if bad_region_nonzero {
+                            self.mark_damaged(start);
+                        }
+                        bad_region_nonzero = false;
+                    }
+                    if oh.otype == OBJECT_ENTRY
+                        && let Some(seqnum) = read_le64(&self.data, offset + 16)
+                    {
+                        found.insert((seqnum, offset));
+                    }
+                    offset += (oh.size + 7) & !7;
+                }
+                None => {
+                    if bad_region_start.is_none() {
+                        bad_region_start = Some(offset);
+                    }
+                    let a = offset as usize;
+                    if self.data[a..a + 8].iter().any(|b| *b != 0) {
+                        bad_region_nonzero = true;
+                    }
+                    offset += 8;
+                }
+            }
+        }
+        if let Some(start) = bad_region_start
+            && bad_region_nonzero
+        {
+            self.mark_damaged(start);
+        }
+        def1x!("found {} entries", found.len());
+    }
+
+    /// Return the decompressed payload of the `DATA` object at `offset` if
+    /// the object is valid and the payload matches the stored hash.
+    fn data_payload(
+        &mut self,
+        offset: u64,
+    ) -> Option<Vec<u8>> {
+        let oh = match self.object_at(offset) {
+            Some(oh) if oh.otype == OBJECT_DATA => oh,
+            _ => {
+                self.mark_damaged(offset);
+                return None;
+            }
+        };
+        let payload_offset: u64 = match self.is_compact() {
+            true => DATA_PAYLOAD_OFFSET_COMPACT,
+            false => DATA_PAYLOAD_OFFSET,
+        };
+        let a: usize = (offset + payload_offset) as usize;
+        let b: usize = (offset + oh.size) as usize;
+        let raw: &[u8] = &self.data[a..b];
+        let payload: Vec<u8> = match Self::decompress(oh.flags, raw) {
+            Some(p) => p,
+            None => {
+                self.mark_damaged(offset);
+                return None;
+            }
+        };
+        let hash_stored: u64 = read_le64(&self.data, offset + 16).unwrap_or(0);
+        let hash: u64 = match self.incompatible_flags & HEADER_INCOMPATIBLE_KEYED_HASH {
+            0 => jenkins_hash64(&payload),
+            _ => siphash24_keyed(&payload, &self.file_id),
+        };
+        if hash != hash_stored {
+            def1o!("DATA at {} hash {:#x} != stored {:#x}", offset, hash, hash_stored);
+            self.mark_damaged(offset);
+            return None;
+        }
+
+        Some(payload)
+    }
+
+    /// Decompress a `DATA` payload according to the object `flags`.
+    fn decompress(
+        flags: u8,
+        raw: &[u8],
+    ) -> Option<Vec<u8>> {
+        match flags & OBJECT_COMPRESSED_MASK {
+            0 => Some(raw.to_vec()),
+            OBJECT_COMPRESSED_XZ => {
+                let mut out: Vec<u8> = Vec::new();
+                let mut input: &[u8] = raw;
+                match lzma_rs::xz_decompress(&mut input, &mut out) {
+                    Ok(_) if out.len() <= DATA_DECOMPRESSED_SZ_MAX => Some(out),
+                    _ => None,
+                }
+            }
+            OBJECT_COMPRESSED_LZ4 => {
+                // systemd prefixes the LZ4 block with the little-endian
+                // 64-bit decompressed size
+                let size = read_le64(raw, 0)? as usize;
+                if size > DATA_DECOMPRESSED_SZ_MAX {
+                    return None;
+                }
+                ::lz4_flex::block::decompress(&raw[8..], size).ok()
+            }
+            OBJECT_COMPRESSED_ZSTD => {
+                let mut out: Vec<u8> = Vec::new();
+                let decoder = ::ruzstd::decoding::StreamingDecoder::new(raw).ok()?;
+                match decoder
+                    .take(DATA_DECOMPRESSED_SZ_MAX as u64 + 1)
+                    .read_to_end(&mut out)
+                {
+                    Ok(_) if out.len() <= DATA_DECOMPRESSED_SZ_MAX => Some(out),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Index of the entry after the last entry read by `libsystemd`.
+    ///
+    /// `libsystemd` and `JournalRecovery` both read entries in sequence
+    /// number order. The last entry read by `libsystemd` is the `count`th
+    /// consecutive entry having `realtime`. If that entry cannot be found then
+    /// return the index of the first entry after `realtime`.
+    pub fn index_after(
+        &self,
+        realtime: EpochMicroseconds,
+        count: Count,
+    ) -> usize {
+        let mut run: Count = 0;
+        for index in 0..self.entries.len() {
+            match self.realtime_at(index) {
+                Some(rt) if rt == realtime => {
+                    run += 1;
+                    if run == count {
+                        return index + 1;
+                    }
+                }
+                _ => run = 0,
+            }
+        }
+        for index in 0..self.entries.len() {
+            if let Some(rt) = self.realtime_at(index)
+                && rt > realtime
+            {
+                return index;
+            }
+        }
+
+        self.entries.len()
+    }
+
+    /// Return the `realtime` of the entry at `index` without reading
+    /// the entry data.
+    pub fn realtime_at(
+        &self,
+        index: usize,
+    ) -> Option<EpochMicroseconds> {
+        let offset = *self.entries.get(index)?;
+
+        read_le64(&self.data, offset + 24)
+    }
+
+    /// Return the entry at `index` with all `DATA` objects that could be
+    /// recovered. Returns `None` if `index` is past the last entry.
+    ///
+    /// Damaged `DATA` objects are skipped and counted.
+    pub fn entry(
+        &mut self,
+        index: usize,
+    ) -> Option<RecoveredEntry> {
+        def1n!("({})", index);
+        let offset: u64 = *self.entries.get(index)?;
+        // object validity was checked when collected
+        let oh = self.object_at(offset)?;
+        let mut entry = RecoveredEntry {
+            seqnum: read_le64(&self.data, offset + 16)?,
+            realtime: read_le64(&self.data, offset + 24)?,
+            monotonic: read_le64(&self.data, offset + 32)?,
+            boot_id: read_id128(&self.data, offset + 40)?,
+            xor_hash: read_le64(&self.data, offset + 56)?,
+            data: Vec::new(),
+        };
+        let item_sz: u64 = if self.is_compact() { 4 } else { 16 };
+        let items: u64 = (oh.size - ENTRY_ITEMS_OFFSET) / item_sz;
+        let mut damaged: bool = false;
+        for i in 0..items {
+            let at = offset + ENTRY_ITEMS_OFFSET + i * item_sz;
+            let data_offset: u64 = match self.is_compact() {
+                true => read_le32(&self.data, at).unwrap_or(0) as u64,
+                false => read_le64(&self.data, at).unwrap_or(0),
+            };
+            match self.data_payload(data_offset) {
+                Some(payload) => entry.data.push(payload),
+                None => damaged = true,
+            }
+        }
+        if damaged {
+            self.entries_damaged += 1;
+        }
+        def1x!("seqnum {}, realtime {}, {} data", entry.seqnum, entry.realtime, entry.data.len());
+
+        Some(entry)
+    }
+}
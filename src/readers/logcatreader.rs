@@ -0,0 +1,761 @@
+// src/readers/logcatreader.rs
+
+//! Implements a [`LogcatReader`], the driver of deriving [`PyDataEvent`s]
+//! from an [Android logcat] binary log buffer file, e.g. the output of
+//! `logcat -B`.
+//!
+//! The file format is read natively. A binary log buffer is a sequence of
+//! `logger_entry` structs, versions 1 to 4, each followed by the entry
+//! payload. The payload of the text buffers (`main`, `system`, `crash`,
+//! `radio`, `kernel`) is a priority, a tag, and a message. The payload of the
+//! binary buffers (`events`, `stats`, `security`) is a tag number and
+//! a typed value.
+//!
+//! The names of the tag numbers of the binary buffers are read from an
+//! optional `event-log-tags` file found in the same directory as the log
+//! buffer file.
+//!
+//! The printed events are similar to those printed by
+//! `logcat -v threadtime -v year`.
+//!
+//! [`PyDataEvent`s]: crate::data::pydataevent::PyDataEvent
+//! [Android logcat]: https://android.googlesource.com/platform/system/logging/+/refs/heads/main/liblog/include/log/log_read.h
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Write as _;
+use std::io::{
+    Error,
+    ErrorKind,
+    Read,
+    Result,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use ::chrono::{
+    DateTime,
+    TimeZone,
+};
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    def1n,
+    def1o,
+    def1x,
+    def1ñ,
+    def2ñ,
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+use ::tempfile::TempPath;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileMetadata,
+    FileSz,
+    FileType,
+    PathId,
+    ResultFind4,
+    summary_stat,
+    summary_stats_enabled,
+};
+use crate::data::datetime::{
+    DateTimeL,
+    DateTimeLOpt,
+    FixedOffset,
+    Result_Filter_DateTime2,
+    SystemTime,
+    dt_pass_filters,
+};
+use crate::data::pydataevent::{
+    DtBegEndPairOpt,
+    EventBytes,
+    PyDataEvent,
+};
+use crate::de_err;
+use crate::readers::filedecompressor::decompress_to_ntf;
+use crate::readers::filehandlemanager::{
+    FILE_HANDLE_MANAGER,
+    FileHandleManaged,
+    FileHandleRole,
+    OpenOptionsManaged,
+};
+use crate::readers::helpers::path_to_fpath;
+use crate::readers::pyeventreader::{
+    PyDataEventReader,
+    ResultNextPyDataEvent,
+    SummaryPyEventReader,
+};
+use crate::readers::summary::Summary;
+
+/// Name of the file with the names of the event tag numbers, found in the
+/// directory of the log buffer file. On an Android device this is
+/// `/system/etc/event-log-tags`.
+pub const EVENT_LOG_TAGS_NAME: &str = "event-log-tags";
+
+/// Size of a version 1 `logger_entry`, which has no `hdr_size` field.
+pub const LOGGER_ENTRY_V1_SZ: usize = 20;
+/// Size of a version 2 `logger_entry` (with `euid`) or a version 3
+/// `logger_entry` (with `lid`).
+pub const LOGGER_ENTRY_V3_SZ: usize = 24;
+/// Size of a version 4 `logger_entry` (with `lid` and `uid`).
+pub const LOGGER_ENTRY_V4_SZ: usize = 28;
+/// Maximum size of a `logger_entry` and its payload.
+pub const LOGGER_ENTRY_MAX_LEN: usize = 5 * 1024;
+
+const LOGGER_ENTRY_LEN_OFFSET: usize = 0;
+const LOGGER_ENTRY_HDR_SIZE_OFFSET: usize = 2;
+const LOGGER_ENTRY_PID_OFFSET: usize = 4;
+const LOGGER_ENTRY_TID_OFFSET: usize = 8;
+const LOGGER_ENTRY_SEC_OFFSET: usize = 12;
+const LOGGER_ENTRY_NSEC_OFFSET: usize = 16;
+const LOGGER_ENTRY_LID_OFFSET: usize = 20;
+
+/// Log buffer identifiers, `log_id_t`.
+pub const LOG_ID_MAIN: u32 = 0;
+pub const LOG_ID_EVENTS: u32 = 2;
+pub const LOG_ID_SYSTEM: u32 = 3;
+pub const LOG_ID_STATS: u32 = 5;
+pub const LOG_ID_SECURITY: u32 = 6;
+/// A `lid` of a 24 byte `logger_entry` at or above this is the `euid` of
+/// a version 2 `logger_entry`.
+const LOG_ID_MAX: u32 = 8;
+
+/// Value types of the binary buffer payloads.
+pub const EVENT_TYPE_INT: u8 = 0;
+pub const EVENT_TYPE_LONG: u8 = 1;
+pub const EVENT_TYPE_STRING: u8 = 2;
+pub const EVENT_TYPE_LIST: u8 = 3;
+pub const EVENT_TYPE_FLOAT: u8 = 4;
+
+/// Maximum nesting of binary buffer lists.
+const EVENT_LIST_DEPTH_MAX: usize = 8;
+
+fn u16_le(
+    data: &[u8],
+    at: usize,
+) -> u16 {
+    let mut buf: [u8; 2] = [0; 2];
+    buf.copy_from_slice(&data[at..at + 2]);
+
+    u16::from_le_bytes(buf)
+}
+
+fn u32_le(
+    data: &[u8],
+    at: usize,
+) -> u32 {
+    let mut buf: [u8; 4] = [0; 4];
+    buf.copy_from_slice(&data[at..at + 4]);
+
+    u32::from_le_bytes(buf)
+}
+
+/// The priority character printed by `logcat`.
+pub const fn priority_char(priority: u8) -> char {
+    match priority {
+        2 => 'V',
+        3 => 'D',
+        4 => 'I',
+        5 => 'W',
+        6 => 'E',
+        7 => 'F',
+        8 => 'S',
+        _ => '?',
+    }
+}
+
+/// Is `lid` a buffer with binary payloads?
+const fn is_binary_buffer(lid: u32) -> bool {
+    matches!(lid, LOG_ID_EVENTS | LOG_ID_STATS | LOG_ID_SECURITY)
+}
+
+/// Parse the contents of an `event-log-tags` file. Each line is a tag
+/// number, a tag name, and optional value descriptions, e.g.
+/// `42 answer (to life the universe etc|3)`.
+pub fn parse_event_log_tags(data: &str) -> HashMap<u32, String> {
+    let mut tags: HashMap<u32, String> = HashMap::new();
+    for line in data.lines() {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        if let (Some(number), Some(name)) = (fields.next(), fields.next())
+            && let Ok(number) = number.parse::<u32>()
+        {
+            tags.insert(number, String::from(name));
+        }
+    }
+
+    tags
+}
+
+/// Format the binary buffer value at the start of `data` as printed by
+/// `logcat`. Return the formatted value and the bytes read, or `None` if
+/// the value is truncated or has an unknown type.
+pub fn format_event_value(
+    data: &[u8],
+    depth: usize,
+) -> Option<(String, usize)> {
+    let value_type: u8 = *data.first()?;
+    let data: &[u8] = &data[1..];
+    match value_type {
+        EVENT_TYPE_INT => {
+            let value = i32::from_le_bytes(data.get(..4)?.try_into().ok()?);
+            Some((value.to_string(), 5))
+        }
+        EVENT_TYPE_LONG => {
+            let value = i64::from_le_bytes(data.get(..8)?.try_into().ok()?);
+            Some((value.to_string(), 9))
+        }
+        EVENT_TYPE_FLOAT => {
+            let value = f32::from_le_bytes(data.get(..4)?.try_into().ok()?);
+            Some((format!("{:.6}", value), 5))
+        }
+        EVENT_TYPE_STRING => {
+            let len: usize = u32::from_le_bytes(data.get(..4)?.try_into().ok()?) as usize;
+            let value: &[u8] = data.get(4..4usize.checked_add(len)?)?;
+            Some((String::from_utf8_lossy(value).into_owned(), 5 + len))
+        }
+        EVENT_TYPE_LIST if depth < EVENT_LIST_DEPTH_MAX => {
+            let count: u8 = *data.first()?;
+            let mut s = String::from("[");
+            let mut at: usize = 1;
+            for i in 0..count {
+                let (value, len) = format_event_value(data.get(at..)?, depth + 1)?;
+                if i != 0 {
+                    s.push(',');
+                }
+                s.push_str(&value);
+                at += len;
+            }
+            s.push(']');
+            Some((s, 1 + at))
+        }
+        _ => None,
+    }
+}
+
+/// A log entry read from a binary log buffer.
+#[derive(Debug)]
+struct LogcatEntry {
+    pid: i32,
+    tid: u32,
+    sec: u32,
+    nsec: u32,
+    priority: char,
+    tag: String,
+    message: String,
+}
+
+impl LogcatEntry {
+    /// Return the event string and the end of the datetime substring.
+    /// Each line of a multi-line message is printed with the entry
+    /// prefix, the same as `logcat`.
+    fn to_event_string(
+        &self,
+        dt: &DateTimeL,
+    ) -> (String, usize) {
+        let dt_s: String = dt.format("%Y-%m-%d %H:%M:%S%.3f").to_string();
+        let message: &str = self.message.trim_end_matches(['\n', '\r']);
+        let mut s = String::with_capacity(dt_s.len() + self.tag.len() + message.len() + 32);
+        for line in message.split('\n') {
+            _ = writeln!(
+                s,
+                "{} {:5} {:5} {} {:<8}: {}",
+                dt_s, self.pid, self.tid, self.priority, self.tag, line.trim_end_matches('\r'),
+            );
+        }
+
+        (s, dt_s.len())
+    }
+}
+
+/// A reader of [Android logcat] binary log buffer files.
+///
+/// [Android logcat]: https://developer.android.com/tools/logcat
+pub struct LogcatReader {
+    /// The `FPath` of the file being read.
+    path: FPath,
+    /// Unique identifier for the file processing instance.
+    path_id: PathId,
+    /// The type of file being read.
+    file_type: FileType,
+    /// If necessary, the extracted file as a temporary file.
+    named_temp_file: Option<TempPath>,
+    /// Conversion of entry timestamps to `DateTimeL` with this timezone offset.
+    fixed_offset: FixedOffset,
+    /// The file data.
+    data: Vec<u8>,
+    /// Offset into `data` of the next entry to read.
+    fo_next: usize,
+    /// The names of the event tag numbers from the `event-log-tags` file.
+    event_tags: HashMap<u32, String>,
+    /// Version 1 and 2 entries have no `lid` so the buffer is presumed from
+    /// the file name. `true` if the file name has `events`, `stats`, or
+    /// `security`.
+    binary_default: bool,
+    /// Summary statistic.
+    /// `Count` of log entries processed.
+    events_processed: Count,
+    /// Summary statistic.
+    /// `Count` of log entries accepted by the datetime filters.
+    events_accepted: Count,
+    /// Summary statistic.
+    /// First (soonest) accepted (printed) `DateTimeL`.
+    dt_first_accepted: DateTimeLOpt,
+    /// Summary statistic.
+    /// Last (latest) accepted (printed) `DateTimeL`.
+    dt_last_accepted: DateTimeLOpt,
+    /// Summary statistic.
+    /// First (soonest) processed `DateTimeL`.
+    dt_first_processed: DateTimeLOpt,
+    /// Summary statistic.
+    /// Last (latest) processed `DateTimeL`.
+    dt_last_processed: DateTimeLOpt,
+    /// File Size of the file being read in bytes.
+    filesz: FileSz,
+    /// file Last Modified time from file-system metadata
+    mtime: SystemTime,
+    /// Out of chronological order.
+    out_of_order: Count,
+    /// The last [`Error`], if any, as a `String`
+    error: Option<String>,
+}
+
+impl fmt::Debug for LogcatReader {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        f.debug_struct("LogcatReader")
+            .field("Path", &self.path)
+            .field("data len", &self.data.len())
+            .field("fo_next", &self.fo_next)
+            .field("event_tags len", &self.event_tags.len())
+            .field("binary_default", &self.binary_default)
+            .field("Error?", &self.error)
+            .finish()
+    }
+}
+
+/// Implement the LogcatReader.
+impl LogcatReader {
+    /// Create a new `LogcatReader`.
+    ///
+    /// The file is read and the first entry header is checked. The
+    /// `event-log-tags` file is read from the directory of `path`.
+    pub fn new(
+        path_id: PathId,
+        path: FPath,
+        file_type: FileType,
+        fixed_offset: FixedOffset,
+    ) -> Result<LogcatReader> {
+        def1n!("({}, {:?}, {:?}, {:?})", path_id, path, file_type, fixed_offset);
+        debug_assert!(file_type.is_logcat(), "LogcatReader only supports FileType::Logcat, given {:?}", file_type);
+
+        let path_std: &Path = Path::new(&path);
+        let named_temp_file: Option<TempPath>;
+        let mtime_opt: Option<SystemTime>;
+        (named_temp_file, mtime_opt) = match decompress_to_ntf(path_id, path_std, &file_type) {
+            Ok(ntf_mtime) => match ntf_mtime {
+                Some((ntf, mtime_opt, _filesz)) => (Some(ntf), mtime_opt),
+                None => (None, None),
+            },
+            Err(err) => {
+                def1x!("decompress_to_ntf({:?}, {:?}) Error, return {:?}", path, file_type, err);
+                return Err(err);
+            }
+        };
+        let path_actual: &Path = match named_temp_file {
+            Some(ref ntf) => ntf.as_ref(),
+            None => path_std,
+        };
+        def1o!("path_actual {:?}", path_actual);
+        let file: FileHandleManaged = FILE_HANDLE_MANAGER.request_open_managed(
+            path_id,
+            FileHandleRole::PrimaryRead,
+            path_actual,
+            OpenOptionsManaged::read_only(),
+        )?;
+        let metadata: FileMetadata = file.metadata()?;
+        let mtime: SystemTime = match mtime_opt {
+            Some(val) => val,
+            None => match metadata.modified() {
+                Ok(val) => val,
+                Err(_err) => {
+                    de_err!("metadata.modified() failed {}", _err);
+                    SystemTime::UNIX_EPOCH
+                }
+            },
+        };
+        let filesz: FileSz = metadata.len() as FileSz;
+        def1o!("filesz {}", filesz);
+
+        let mut data: Vec<u8> = Vec::with_capacity(filesz as usize);
+        (&file).read_to_end(&mut data)?;
+        drop(file);
+        if let Err(err) = Self::entry_header(&data, 0) {
+            def1x!("bad first entry header, return Err {}", err);
+            return Err(Error::new(err.kind(), format!("{}, file {:?}", err, path)));
+        }
+
+        let event_tags: HashMap<u32, String> = match path_std.parent() {
+            Some(dir) => {
+                let path_tags: PathBuf = dir.join(EVENT_LOG_TAGS_NAME);
+                match std::fs::read(&path_tags) {
+                    Ok(data) => parse_event_log_tags(&String::from_utf8_lossy(&data)),
+                    Err(_err) => {
+                        def1o!("read({:?}) failed {}", path_tags, _err);
+                        HashMap::new()
+                    }
+                }
+            }
+            None => HashMap::new(),
+        };
+        def1o!("event_tags len {}", event_tags.len());
+        let file_name: String = path_std
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_ascii_lowercase();
+        let binary_default: bool = ["events", "stats", "security"]
+            .iter()
+            .any(|name| file_name.contains(name));
+
+        def1x!("return Ok(LogcatReader)");
+
+        Ok(LogcatReader {
+            path,
+            path_id,
+            file_type,
+            named_temp_file,
+            fixed_offset,
+            data,
+            fo_next: 0,
+            event_tags,
+            binary_default,
+            events_processed: 0,
+            events_accepted: 0,
+            dt_first_accepted: DateTimeLOpt::None,
+            dt_last_accepted: DateTimeLOpt::None,
+            dt_first_processed: DateTimeLOpt::None,
+            dt_last_processed: DateTimeLOpt::None,
+            filesz,
+            mtime,
+            out_of_order: 0,
+            error: None,
+        })
+    }
+
+    pub const fn mtime(&self) -> SystemTime {
+        self.mtime
+    }
+
+    pub const fn path_id(&self) -> PathId {
+        self.path_id
+    }
+
+    #[inline(always)]
+    pub const fn path(&self) -> &FPath {
+        &self.path
+    }
+
+    #[inline(always)]
+    pub const fn filetype(&self) -> FileType {
+        self.file_type
+    }
+
+    /// File size in bytes
+    #[inline(always)]
+    pub const fn filesz(&self) -> FileSz {
+        self.filesz
+    }
+
+    /// Count of event tag names read from the `event-log-tags` file.
+    pub fn event_tags_len(&self) -> usize {
+        self.event_tags.len()
+    }
+
+    /// Check the `logger_entry` at offset `fo` of `data`.
+    /// Return the header size and the payload size.
+    fn entry_header(
+        data: &[u8],
+        fo: usize,
+    ) -> Result<(usize, usize)> {
+        let header: &[u8] = match data.get(fo..fo + LOGGER_ENTRY_V1_SZ) {
+            Some(header) => header,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("logcat entry header is truncated at offset {}", fo),
+                ));
+            }
+        };
+        let payload_sz: usize = usize::from(u16_le(header, LOGGER_ENTRY_LEN_OFFSET));
+        let header_sz: usize = match usize::from(u16_le(header, LOGGER_ENTRY_HDR_SIZE_OFFSET)) {
+            // version 1 `__pad`
+            0 => LOGGER_ENTRY_V1_SZ,
+            sz @ (LOGGER_ENTRY_V1_SZ | LOGGER_ENTRY_V3_SZ | LOGGER_ENTRY_V4_SZ) => sz,
+            sz => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("logcat entry header size {} is invalid at offset {}", sz, fo),
+                ));
+            }
+        };
+        if header_sz + payload_sz > LOGGER_ENTRY_MAX_LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("logcat entry payload size {} is invalid at offset {}", payload_sz, fo),
+            ));
+        }
+
+        Ok((header_sz, payload_sz))
+    }
+
+    /// Read the entry at offset `fo` of `self.data`.
+    /// Return the entry and the offset of the next entry.
+    fn entry(
+        &self,
+        fo: usize,
+    ) -> Result<(LogcatEntry, usize)> {
+        def1n!("({})", fo);
+        let (header_sz, payload_sz) = Self::entry_header(&self.data, fo)?;
+        let header: &[u8] = &self.data[fo..fo + LOGGER_ENTRY_V1_SZ];
+        let payload: &[u8] = match self.data.get(fo + header_sz..fo + header_sz + payload_sz) {
+            Some(payload) => payload,
+            None => {
+                def1x!("payload truncated at {}, return Err", fo);
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    format!("logcat entry payload is truncated at offset {}", fo),
+                ));
+            }
+        };
+        let lid: Option<u32> = match header_sz {
+            LOGGER_ENTRY_V1_SZ => None,
+            _ => match u32_le(&self.data, fo + LOGGER_ENTRY_LID_OFFSET) {
+                lid if lid < LOG_ID_MAX => Some(lid),
+                // version 2 `euid`
+                _ => None,
+            },
+        };
+        let binary: bool = match lid {
+            Some(lid) => is_binary_buffer(lid),
+            None => self.binary_default,
+        };
+        let (priority, tag, message): (char, String, String) = match binary {
+            true => {
+                let tag_number: Option<u32> = payload
+                    .get(..4)
+                    .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]));
+                let tag: String = match tag_number {
+                    Some(number) => match self.event_tags.get(&number) {
+                        Some(name) => name.clone(),
+                        None => number.to_string(),
+                    },
+                    None => String::new(),
+                };
+                let message: String = match payload.get(4..).and_then(|value| format_event_value(value, 0)) {
+                    Some((value, _len)) => value,
+                    None => String::from("[truncated]"),
+                };
+                ('I', tag, message)
+            }
+            false => {
+                let priority: char = priority_char(payload.first().copied().unwrap_or_default());
+                let mut strings = payload.get(1..).unwrap_or_default().split(|b| *b == 0);
+                let tag: String = String::from_utf8_lossy(strings.next().unwrap_or_default()).into_owned();
+                let message: String = String::from_utf8_lossy(strings.next().unwrap_or_default()).into_owned();
+                (priority, tag, message)
+            }
+        };
+        let entry = LogcatEntry {
+            pid: u32_le(header, LOGGER_ENTRY_PID_OFFSET) as i32,
+            tid: u32_le(header, LOGGER_ENTRY_TID_OFFSET),
+            sec: u32_le(header, LOGGER_ENTRY_SEC_OFFSET),
+            nsec: u32_le(header, LOGGER_ENTRY_NSEC_OFFSET),
+            priority,
+            tag,
+            message,
+        };
+        let fo_next: usize = fo + header_sz + payload_sz;
+        def1x!("return entry sec {}, next {}", entry.sec, fo_next);
+
+        Ok((entry, fo_next))
+    }
+
+    /// Convert the entry seconds and nanoseconds since the Unix epoch to
+    /// a `DateTimeL`.
+    fn timestamp_to_datetime(
+        &self,
+        sec: u32,
+        nsec: u32,
+    ) -> DateTimeLOpt {
+        let dt = DateTime::from_timestamp(i64::from(sec), nsec)?;
+
+        Some(self.fixed_offset.from_utc_datetime(&dt.naive_utc()))
+    }
+
+    /// Return the next entry that passes the datetime filters.
+    pub fn next(
+        &mut self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> ResultNextPyDataEvent {
+        def1n!("({:?}, {:?})", dt_filter_after, dt_filter_before);
+        while self.fo_next < self.data.len() {
+            let (entry, fo_next) = match self.entry(self.fo_next) {
+                Ok(val) => val,
+                Err(err) => {
+                    def1x!("entry({}) error {}", self.fo_next, err);
+                    self.fo_next = self.data.len();
+                    self.error = Some(err.to_string());
+                    // the last entry of a log buffer being written may be
+                    // truncated
+                    if err.kind() == ErrorKind::UnexpectedEof {
+                        return ResultFind4::ErrIgnore(err);
+                    }
+                    return ResultFind4::Err(err);
+                }
+            };
+            self.fo_next = fo_next;
+            self.events_processed += 1;
+            let dt: DateTimeL = match self.timestamp_to_datetime(entry.sec, entry.nsec) {
+                Some(dt) => dt,
+                None => continue,
+            };
+            summary_stat!(self.dm_first_last_update_processed(&dt));
+            if dt_pass_filters(&dt, dt_filter_after, dt_filter_before) != Result_Filter_DateTime2::InRange {
+                continue;
+            }
+            let (event, dt_end) = entry.to_event_string(&dt);
+            summary_stat!(self.dm_first_last_update_accepted(&dt));
+            self.events_accepted += 1;
+            def1x!("return Found entry");
+
+            return ResultFind4::Found(PyDataEvent::new(
+                EventBytes::from(event.as_bytes()),
+                dt,
+                DtBegEndPairOpt::Some((0, dt_end)),
+            ));
+        }
+        def1x!("return Done");
+
+        ResultFind4::Done
+    }
+
+    /// Update the two statistic `DateTimeL` of
+    /// `self.dt_first_processed` and `self.dt_last_processed`.
+    fn dm_first_last_update_processed(
+        &mut self,
+        dt: &DateTimeL,
+    ) {
+        if !summary_stats_enabled() {
+            return;
+        }
+        match self.dt_first_processed {
+            Some(dt_) if &dt_ <= dt => {}
+            Some(_) => {
+                self.dt_first_processed = Some(*dt);
+                self.out_of_order += 1;
+            }
+            None => self.dt_first_processed = Some(*dt),
+        }
+        match self.dt_last_processed {
+            Some(dt_) if &dt_ >= dt => {}
+            _ => self.dt_last_processed = Some(*dt),
+        }
+    }
+
+    /// Update the two statistic `DateTimeL` of
+    /// `self.dt_first_accepted` and `self.dt_last_accepted`.
+    fn dm_first_last_update_accepted(
+        &mut self,
+        dt: &DateTimeL,
+    ) {
+        if !summary_stats_enabled() {
+            return;
+        }
+        match self.dt_first_accepted {
+            Some(dt_) if &dt_ <= dt => {}
+            _ => self.dt_first_accepted = Some(*dt),
+        }
+        match self.dt_last_accepted {
+            Some(dt_) if &dt_ >= dt => {}
+            _ => self.dt_last_accepted = Some(*dt),
+        }
+    }
+
+    /// Return an up-to-date `SummaryPyEventReader` instance for this
+    /// `LogcatReader`. The Python process statistics are left at defaults.
+    pub fn summary(&self) -> SummaryPyEventReader {
+        SummaryPyEventReader {
+            pyeventreader_events_processed: self.events_processed,
+            pyeventreader_events_accepted: self.events_accepted,
+            pyeventreader_datetime_first_accepted: self.dt_first_accepted,
+            pyeventreader_datetime_last_accepted: self.dt_last_accepted,
+            pyeventreader_datetime_first_processed: self.dt_first_processed,
+            pyeventreader_datetime_last_processed: self.dt_last_processed,
+            pyeventreader_filesz: self.filesz,
+            pyeventreader_out_of_order: self.out_of_order,
+            ..Default::default()
+        }
+    }
+
+    /// Return an up-to-date [`Summary`] instance for this `LogcatReader`.
+    ///
+    /// [`Summary`]: crate::readers::summary::Summary
+    pub fn summary_complete(&self) -> Summary {
+        let path = self.path().clone();
+        let path_ntf: Option<FPath> = self
+            .named_temp_file
+            .as_ref()
+            .map(|ntf| path_to_fpath(ntf.as_ref()));
+        let filetype = self.filetype();
+        let logmessagetype = filetype.to_logmessagetype();
+        let summarylogcatreader: SummaryPyEventReader = self.summary();
+        let error: Option<String> = self.error.clone();
+
+        Summary::new(
+            path,
+            path_ntf,
+            filetype,
+            logmessagetype,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(summarylogcatreader),
+            None,
+            None,
+            error,
+        )
+    }
+}
+
+impl PyDataEventReader for LogcatReader {
+    fn next(
+        &mut self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> ResultNextPyDataEvent {
+        LogcatReader::next(self, dt_filter_after, dt_filter_before)
+    }
+
+    fn mtime(&self) -> SystemTime {
+        LogcatReader::mtime(self)
+    }
+
+    fn summary_complete(&self) -> Summary {
+        LogcatReader::summary_complete(self)
+    }
+}
@@ -0,0 +1,374 @@
+// src/readers/logindb.rs
+
+//! Export the login records of the SQLite databases that replace `wtmp` and
+//! `lastlog` on newer Linux distributions to a temporary file of
+//! [`FixedStruct`s] readable by a [`FixedStructReader`].
+//!
+//! * [`wtmpdb`] `/var/lib/wtmpdb/wtmp.db` rows are exported as
+//!   [`linux_arm64aarch64::utmpx`] records, which have a 64-bit `ut_tv`.
+//!   A login row becomes a `USER_PROCESS` record and, if the user has logged
+//!   out, a `DEAD_PROCESS` record. A boot row becomes a `BOOT_TIME` record
+//!   and, if the system was shut down, a `RUN_LVL` "shutdown" record. This is
+//!   what `login` and `init` write to a `wtmp` file.
+//! * [`lastlog2`] `/var/lib/lastlog/lastlog2.db` rows are exported as
+//!   [`linux_sqlite::lastlog2`] records.
+//!
+//! The database is opened read-only.
+//!
+//! [`FixedStruct`s]: crate::data::fixedstruct::FixedStruct
+//! [`FixedStructReader`]: crate::readers::fixedstructreader::FixedStructReader
+//! [`wtmpdb`]: https://github.com/thkukuk/wtmpdb
+//! [`lastlog2`]: https://github.com/util-linux/util-linux/tree/master/liblastlog2
+//! [`linux_arm64aarch64::utmpx`]: crate::data::fixedstruct::linux_arm64aarch64::utmpx
+//! [`linux_sqlite::lastlog2`]: crate::data::fixedstruct::linux_sqlite::lastlog2
+
+use std::fs::{
+    File,
+    OpenOptions,
+};
+use std::io::{
+    BufWriter,
+    Error,
+    ErrorKind,
+    Result,
+    Write,
+};
+use std::net::IpAddr;
+use std::path::Path;
+
+use ::memoffset::offset_of;
+use ::rusqlite::{
+    Connection,
+    OpenFlags,
+};
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+use ::tempfile::TempPath;
+
+use crate::common::{
+    err_from_err_path,
+    Count,
+    FPath,
+    FileTypeFixedStruct,
+};
+use crate::data::fixedstruct::{
+    linux_arm64aarch64,
+    linux_sqlite,
+};
+use crate::readers::filedecompressor::create_temp_path;
+use crate::readers::helpers::fpath_to_path;
+
+/// `wtmpdb` record type `BOOT_TIME`, from `wtmpdb.h`
+pub const WTMPDB_BOOT_TIME: i64 = 1;
+/// `wtmpdb` record type `RUNLEVEL`, from `wtmpdb.h`
+pub const WTMPDB_RUNLEVEL: i64 = 2;
+/// `wtmpdb` record type `USER_PROCESS`, from `wtmpdb.h`
+pub const WTMPDB_USER_PROCESS: i64 = 3;
+
+/// Query of the `wtmp` table of a `wtmpdb` database.
+/// `Login` and `Logout` are microseconds since the Unix epoch.
+const WTMPDB_QUERY: &str =
+    "SELECT ID, Type, User, Login, Logout, TTY, RemoteHost FROM wtmp ORDER BY Login";
+
+/// Query of the `Lastlog2` table of a `lastlog2` database.
+/// `Time` is seconds since the Unix epoch.
+const LASTLOG2_QUERY: &str =
+    "SELECT Name, Time, TTY, RemoteHost, Service FROM Lastlog2 ORDER BY Time";
+
+/// Linux `ut_type` values written by [`export_wtmpdb`]
+const UT_RUN_LVL: i16 = 1;
+const UT_BOOT_TIME: i16 = 2;
+const UT_USER_PROCESS: i16 = 7;
+const UT_DEAD_PROCESS: i16 = 8;
+
+const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
+
+/// A row of the `wtmp` table of a `wtmpdb` database.
+struct WtmpdbRow {
+    id: i64,
+    type_: i64,
+    user: Option<String>,
+    login: Option<i64>,
+    logout: Option<i64>,
+    tty: Option<String>,
+    remotehost: Option<String>,
+}
+
+/// Convert a `rusqlite::Error` to a `std::io::Error` for the `path`.
+fn err_from_rusqlite(
+    error: ::rusqlite::Error,
+    path: &FPath,
+) -> Error {
+    err_from_err_path(
+        &Error::new(ErrorKind::InvalidData, error.to_string()),
+        path,
+        None,
+    )
+}
+
+/// Copy `value` into the C string `buffer[offset..offset + size]`.
+/// `value` is truncated to leave a null terminator.
+fn set_cstr(
+    buffer: &mut [u8],
+    offset: usize,
+    size: usize,
+    value: &str,
+) {
+    let len: usize = value.len().min(size - 1);
+    buffer[offset..offset + len].copy_from_slice(&value.as_bytes()[..len]);
+}
+
+/// Set the `ut_addr_v6` at `offset` of `buffer` from the `remotehost` if it
+/// is an IP address. Stored in network byte order like `inet_pton`.
+fn set_ut_addr_v6(
+    buffer: &mut [u8],
+    offset: usize,
+    remotehost: &str,
+) {
+    match remotehost.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ipv4)) => {
+            buffer[offset..offset + 4].copy_from_slice(&ipv4.octets());
+        }
+        Ok(IpAddr::V6(ipv6)) => {
+            buffer[offset..offset + 16].copy_from_slice(&ipv6.octets());
+        }
+        Err(_) => {}
+    }
+}
+
+/// Create the bytes of a [`linux_arm64aarch64::utmpx`] in native byte order.
+///
+/// [`linux_arm64aarch64::utmpx`]: crate::data::fixedstruct::linux_arm64aarch64::utmpx
+fn utmpx_bytes(
+    ut_type: i16,
+    ut_line: &str,
+    ut_id: &str,
+    ut_user: &str,
+    ut_host: &str,
+    ut_session: i64,
+    microseconds: i64,
+) -> [u8; linux_arm64aarch64::UTMPX_SZ] {
+    use linux_arm64aarch64::{
+        timeval,
+        utmpx,
+        UT_HOSTSIZE,
+        UT_IDSIZE,
+        UT_LINESIZE,
+        UT_NAMESIZE,
+    };
+
+    let mut buffer = [0; linux_arm64aarch64::UTMPX_SZ];
+    let at: usize = offset_of!(utmpx, ut_type);
+    buffer[at..at + 2].copy_from_slice(&ut_type.to_ne_bytes());
+    set_cstr(&mut buffer, offset_of!(utmpx, ut_line), UT_LINESIZE, ut_line);
+    set_cstr(&mut buffer, offset_of!(utmpx, ut_id), UT_IDSIZE, ut_id);
+    set_cstr(&mut buffer, offset_of!(utmpx, ut_user), UT_NAMESIZE, ut_user);
+    set_cstr(&mut buffer, offset_of!(utmpx, ut_host), UT_HOSTSIZE, ut_host);
+    let at: usize = offset_of!(utmpx, ut_session);
+    buffer[at..at + 8].copy_from_slice(&ut_session.to_ne_bytes());
+    let tv_sec: i64 = microseconds.div_euclid(MICROSECONDS_PER_SECOND);
+    let tv_usec: i64 = microseconds.rem_euclid(MICROSECONDS_PER_SECOND);
+    let at: usize = offset_of!(utmpx, ut_tv) + offset_of!(timeval, tv_sec);
+    buffer[at..at + 8].copy_from_slice(&tv_sec.to_ne_bytes());
+    let at: usize = offset_of!(utmpx, ut_tv) + offset_of!(timeval, tv_usec);
+    buffer[at..at + 8].copy_from_slice(&tv_usec.to_ne_bytes());
+    set_ut_addr_v6(&mut buffer, offset_of!(utmpx, ut_addr_v6), ut_host);
+
+    buffer
+}
+
+/// Create the bytes of a [`linux_sqlite::lastlog2`] in native byte order.
+///
+/// [`linux_sqlite::lastlog2`]: crate::data::fixedstruct::linux_sqlite::lastlog2
+fn lastlog2_bytes(
+    ll_time: i64,
+    ll_name: &str,
+    ll_line: &str,
+    ll_host: &str,
+    ll_service: &str,
+) -> [u8; linux_sqlite::LASTLOG2_SZ] {
+    use linux_sqlite::{
+        lastlog2,
+        LL_HOSTSIZE,
+        LL_LINESIZE,
+        LL_NAMESIZE,
+        LL_SERVICESIZE,
+    };
+
+    let mut buffer = [0; linux_sqlite::LASTLOG2_SZ];
+    let at: usize = offset_of!(lastlog2, ll_time);
+    buffer[at..at + 8].copy_from_slice(&ll_time.to_ne_bytes());
+    set_cstr(&mut buffer, offset_of!(lastlog2, ll_name), LL_NAMESIZE, ll_name);
+    set_cstr(&mut buffer, offset_of!(lastlog2, ll_line), LL_LINESIZE, ll_line);
+    set_cstr(&mut buffer, offset_of!(lastlog2, ll_host), LL_HOSTSIZE, ll_host);
+    set_cstr(&mut buffer, offset_of!(lastlog2, ll_service), LL_SERVICESIZE, ll_service);
+
+    buffer
+}
+
+/// Write the `utmpx` records of one `wtmpdb` row to `writer`.
+/// Returns the `Count` of records written.
+fn write_wtmpdb_row<W: Write>(
+    writer: &mut W,
+    row: &WtmpdbRow,
+) -> Result<Count> {
+    let user: &str = row.user.as_deref().unwrap_or_default();
+    let tty: &str = row.tty.as_deref().unwrap_or_default();
+    let remotehost: &str = row.remotehost.as_deref().unwrap_or_default();
+    let login: i64 = match row.login {
+        Some(val) => val,
+        None => return Ok(0),
+    };
+    let mut count: Count = 0;
+    match row.type_ {
+        WTMPDB_BOOT_TIME => {
+            writer.write_all(&utmpx_bytes(UT_BOOT_TIME, tty, "~~", user, remotehost, row.id, login))?;
+            count += 1;
+            if let Some(logout) = row.logout {
+                writer.write_all(&utmpx_bytes(UT_RUN_LVL, tty, "~~", "shutdown", remotehost, row.id, logout))?;
+                count += 1;
+            }
+        }
+        WTMPDB_RUNLEVEL => {
+            writer.write_all(&utmpx_bytes(UT_RUN_LVL, tty, "", user, remotehost, row.id, login))?;
+            count += 1;
+        }
+        WTMPDB_USER_PROCESS => {
+            writer.write_all(&utmpx_bytes(UT_USER_PROCESS, tty, "", user, remotehost, row.id, login))?;
+            count += 1;
+            if let Some(logout) = row.logout {
+                writer.write_all(&utmpx_bytes(UT_DEAD_PROCESS, tty, "", "", "", row.id, logout))?;
+                count += 1;
+            }
+        }
+        _ => {
+            defo!("skip wtmpdb ID {} Type {}", row.id, row.type_);
+        }
+    }
+
+    Ok(count)
+}
+
+/// Export the rows of the `wtmpdb` database `conn` to `writer`.
+/// Returns the `Count` of records written.
+fn export_wtmpdb<W: Write>(
+    conn: &Connection,
+    writer: &mut W,
+    path: &FPath,
+) -> Result<Count> {
+    let mut stmt = conn.prepare(WTMPDB_QUERY).map_err(|err| err_from_rusqlite(err, path))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(WtmpdbRow {
+                id: row.get(0)?,
+                type_: row.get(1)?,
+                user: row.get(2)?,
+                login: row.get(3)?,
+                logout: row.get(4)?,
+                tty: row.get(5)?,
+                remotehost: row.get(6)?,
+            })
+        })
+        .map_err(|err| err_from_rusqlite(err, path))?;
+    let mut count: Count = 0;
+    for row in rows {
+        let row: WtmpdbRow = row.map_err(|err| err_from_rusqlite(err, path))?;
+        count += write_wtmpdb_row(writer, &row)?;
+    }
+
+    Ok(count)
+}
+
+/// Export the rows of the `lastlog2` database `conn` to `writer`.
+/// Rows of users that never logged in are skipped.
+/// Returns the `Count` of records written.
+fn export_lastlog2<W: Write>(
+    conn: &Connection,
+    writer: &mut W,
+    path: &FPath,
+) -> Result<Count> {
+    let mut stmt = conn.prepare(LASTLOG2_QUERY).map_err(|err| err_from_rusqlite(err, path))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, Option<String>>(0)?,
+                row.get::<_, Option<i64>>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, Option<String>>(4)?,
+            ))
+        })
+        .map_err(|err| err_from_rusqlite(err, path))?;
+    let mut count: Count = 0;
+    for row in rows {
+        let (name, time, tty, remotehost, service) = row.map_err(|err| err_from_rusqlite(err, path))?;
+        let time: i64 = match time {
+            Some(val) if val != 0 => val,
+            _ => continue,
+        };
+        writer.write_all(&lastlog2_bytes(
+            time,
+            name.as_deref().unwrap_or_default(),
+            tty.as_deref().unwrap_or_default(),
+            remotehost.as_deref().unwrap_or_default(),
+            service.as_deref().unwrap_or_default(),
+        ))?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Export the login records of the `wtmpdb` or `lastlog2` database at `path`
+/// to a new temporary file. The temporary file has the same modified time as
+/// the database.
+///
+/// Returns the `TempPath` of the temporary file and the `Count` of records.
+pub fn export_logindb(
+    path: &FPath,
+    filetype_fixedstruct: &FileTypeFixedStruct,
+) -> Result<(TempPath, Count)> {
+    defn!("({:?}, {:?})", path, filetype_fixedstruct);
+    let path_std: &Path = fpath_to_path(path);
+    let suffix: &str = match filetype_fixedstruct {
+        FileTypeFixedStruct::Lastlog2 => ".lastlog2",
+        FileTypeFixedStruct::Wtmpdb => ".wtmp",
+        _ => {
+            defx!("unexpected {:?}", filetype_fixedstruct);
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("unexpected {:?} for file {:?}", filetype_fixedstruct, path),
+            ));
+        }
+    };
+    let conn: Connection = Connection::open_with_flags(
+        path_std,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .map_err(|err| err_from_rusqlite(err, path))?;
+
+    let temp_path: TempPath = create_temp_path(path_std, suffix)?;
+    let file: File = OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .open(&temp_path)?;
+    let mut writer: BufWriter<File> = BufWriter::new(file);
+    let count: Count = match filetype_fixedstruct {
+        FileTypeFixedStruct::Wtmpdb => export_wtmpdb(&conn, &mut writer, path)?,
+        _ => export_lastlog2(&conn, &mut writer, path)?,
+    };
+    let file: File = writer.into_inner().map_err(|err| err.into_error())?;
+    if let Ok(mtime) = std::fs::metadata(path_std).and_then(|m| m.modified())
+        && let Err(_err) = file.set_modified(mtime)
+    {
+        defo!("set_modified failed {:?}", _err);
+    }
+    defx!("return {:?}, {} records", temp_path, count);
+
+    Ok((temp_path, count))
+}
@@ -18,17 +18,62 @@
 //!   Avoiding `u8` to `char` conversion avoids potential errors and
 //!   significantly improves program performance.
 //! * A `LineReader` does the majority of `u8` to `char` conversions.
+//! * For syslog files with only uptime datetimes, i.e. `dmesg`, the
+//!   [`boottimes`] of other files anchor each boot to wall-clock time.
 //!
 //! ### Reading C-struct record-keeping files; acct, lastlog, utmp, etc.
 //!
 //! * A [`FixedStructReader`] drives a [`BlockReader`] to derive
 //! [`FixedStruct`s].
+//! * For `wtmpdb` and `lastlog2` SQLite databases, a `FixedStructReader`
+//!   first uses [`logindb`] to export the records to a temporary file.
+//! * For a FreeBSD `utx.log`, a `FixedStructReader` first uses [`utxlog`] to
+//!   export the variable-length records to a temporary file.
 //!
 //! <br/>
 //!
 //! ### Reading [evtx files]; Windows Event Log XML files
 //!
 //! * A [`EvtxReader`] drives a [`EvtxParser`] to derive [`Evtx`s].
+//! * In recovery mode, a `EvtxReader` drives a [`EvtxRecovery`] to carve
+//!   records from damaged evtx files.
+//! * For legacy `.evt` files, a `EvtxReader` drives a [`EvtParser`].
+//!
+//! <br/>
+//!
+//! ### Reading [Apple System Log] files
+//!
+//! * A [`AslReader`] reads `.asl` database records to derive
+//!   [`PyDataEvent`s].
+//!
+//! <br/>
+//!
+//! ### Reading [OneDrive Log] files
+//!
+//! * A [`OdlReader`] reads `.odl`, `.aodl`, `.odlgz`, and `.odlsent` records
+//!   to derive [`PyDataEvent`s].
+//!
+//! <br/>
+//!
+//! ### Reading [Apple Unified Logging] files
+//!
+//! * A [`Tracev3Reader`] reads `.tracev3` log entries, along with the
+//!   `timesync`, `uuidtext`, and `dsc` files of the log archive, to derive
+//!   [`PyDataEvent`s].
+//!
+//! <br/>
+//!
+//! ### Reading [Android logcat] binary log buffers
+//!
+//! * A [`LogcatReader`] reads `logger_entry` records of binary log buffers,
+//!   e.g. from `logcat -B`, to derive [`PyDataEvent`s].
+//!
+//! <br/>
+//!
+//! ### Reading syslog messages of [pcap] network packet captures
+//!
+//! * A [`PcapReader`] reassembles the syslog messages of UDP datagrams and
+//!   TCP streams of pcap and pcapng files to derive [`PyDataEvent`s].
 //!
 //! <br/>
 //!
@@ -36,6 +81,8 @@
 //!
 //! * A [`JournalReader`] drives a [`JournalApiPtr`] to derive
 //! [Journal entries].
+//! * A `JournalReader` falls back to a [`JournalRecovery`] to recover
+//!   entries from damaged or "online" journal files.
 //!
 //! <br/>
 //! <br/>
@@ -47,7 +94,7 @@
 //! ---
 //!
 //! The _s4_ binary program uses a [`SyslogProcessor`], a [`FixedStructReader`],
-//! a [`EvtxReader`], or a [`JournalReader`], instance,
+//! a [`EvtxReader`], a [`AslReader`], or a [`JournalReader`], instance,
 //! one per file, to drive processing of the file.
 //!
 //! <br/>
@@ -65,26 +112,54 @@
 //! [`SyslineReader`]: crate::readers::syslinereader::SyslineReader
 //! [`SyslogProcessor`]: crate::readers::syslogprocessor::SyslogProcessor
 //! [`FixedStructReader`]: crate::readers::fixedstructreader::FixedStructReader
+//! [`boottimes`]: crate::readers::boottimes
+//! [`logindb`]: crate::readers::logindb
+//! [`utxlog`]: crate::readers::utxlog
 //! [`EvtxReader`]: crate::readers::evtxreader::EvtxReader
+//! [`EvtxRecovery`]: crate::readers::evtxrecovery::EvtxRecovery
+//! [`EvtParser`]: crate::readers::evtparser::EvtParser
 //! [`systemd` journal files]: https://systemd.io/JOURNAL_FILES/
 //! [`JournalReader`]: crate::readers::journalreader::JournalReader
+//! [`JournalRecovery`]: crate::readers::journalrecovery::JournalRecovery
 //! [`JournalApiPtr`]: crate::libload::systemd_dlopen2::JournalApiPtr
 //! [Journal entries]: https://systemd.io/JOURNAL_FILE_FORMAT/
 //! [`FixedStruct`s]: crate::data::fixedstruct::FixedStruct
 //! [`EvtxParser`]: https://docs.rs/evtx/0.8.1/evtx/struct.EvtxParser.html
 //! [`Evtx`s]: crate::data::evtx::Evtx
+//! [`AslReader`]: crate::readers::aslreader::AslReader
+//! [`PyDataEvent`s]: crate::data::pydataevent::PyDataEvent
+//! [Apple System Log]: https://github.com/apple-oss-distributions/syslog/blob/syslog-377.0.1/libsystem_asl.tproj/src/asl_file.c
+//! [`OdlReader`]: crate::readers::odlreader::OdlReader
+//! [OneDrive Log]: https://github.com/ydkhatri/OneDrive/blob/9ad135ecf56cd2086256cf8440b98b5eaa50c0ab/odl.py
+//! [`LogcatReader`]: crate::readers::logcatreader::LogcatReader
+//! [Android logcat]: https://developer.android.com/tools/logcat
+//! [`PcapReader`]: crate::readers::pcapreader::PcapReader
+//! [pcap]: https://www.tcpdump.org/manpages/pcap-savefile.5.html
+//! [`Tracev3Reader`]: crate::readers::tracev3reader::Tracev3Reader
+//! [Apple Unified Logging]: https://github.com/libyal/dtformats/blob/main/documentation/Apple%20Unified%20Logging%20and%20Activity%20Tracing%20formats.asciidoc
 //! [evtx files]: https://en.wikipedia.org/w/index.php?title=Event_Viewer&oldid=1130075772#Windows_Vista
 
+pub mod aslreader;
 pub mod blockreader;
+pub mod boottimes;
+pub mod evtparser;
 pub mod evtxreader;
+pub mod evtxrecovery;
 pub mod filedecompressor;
 pub mod filehandlemanager;
 pub mod filepreprocessor;
 pub mod fixedstructreader;
 pub mod helpers;
 pub mod journalreader;
+pub mod journalrecovery;
 pub mod linereader;
+pub mod logcatreader;
+pub mod logindb;
+pub mod odlreader;
+pub mod pcapreader;
 pub mod pyeventreader;
 pub mod summary;
 pub mod syslinereader;
 pub mod syslogprocessor;
+pub mod tracev3reader;
+pub mod utxlog;
@@ -0,0 +1,1130 @@
+// src/readers/odlreader.rs
+
+//! Implements an [`OdlReader`], the driver of deriving [`PyDataEvent`s] from
+//! a [OneDrive Log] file (`.odl`, `.aodl`, `.odlgz`, `.odlsent`).
+//!
+//! The file format is read natively. The printed events are the same as
+//! those printed by the Python script `odl_reader.py` with options
+//! `--all_key_values --all_data`.
+//!
+//! Obfuscated strings are recovered using the `general.keystore` key or the
+//! `ObfuscationStringMap.txt` map found in the directory of the OneDrive
+//! Log file.
+//!
+//! [`PyDataEvent`s]: crate::data::pydataevent::PyDataEvent
+//! [OneDrive Log]: https://github.com/ydkhatri/OneDrive/blob/9ad135ecf56cd2086256cf8440b98b5eaa50c0ab/odl.py
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{
+    Error,
+    ErrorKind,
+    Read,
+    Result,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use ::aes::cipher::consts::U16;
+use ::aes::cipher::generic_array::GenericArray;
+use ::aes::cipher::{
+    BlockDecrypt,
+    BlockSizeUser,
+    KeyInit,
+};
+use ::aes::{
+    Aes128,
+    Aes192,
+    Aes256,
+};
+use ::chrono::{
+    DateTime,
+    TimeZone,
+};
+use ::flate2::read::GzDecoder;
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    def1n,
+    def1o,
+    def1x,
+    def1ñ,
+    def2ñ,
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+use ::tempfile::TempPath;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileMetadata,
+    FileSz,
+    FileType,
+    PathId,
+    ResultFind4,
+    summary_stat,
+    summary_stats_enabled,
+};
+use crate::data::datetime::{
+    DateTimeL,
+    DateTimeLOpt,
+    FixedOffset,
+    Result_Filter_DateTime2,
+    SystemTime,
+    dt_pass_filters,
+};
+use crate::data::pydataevent::{
+    DtBegEndPairOpt,
+    EventBytes,
+    PyDataEvent,
+};
+use crate::de_err;
+use crate::readers::filedecompressor::decompress_to_ntf;
+use crate::readers::filehandlemanager::{
+    FILE_HANDLE_MANAGER,
+    FileHandleManaged,
+    FileHandleRole,
+    OpenOptionsManaged,
+};
+use crate::readers::helpers::path_to_fpath;
+use crate::readers::pyeventreader::{
+    PyDataEventReader,
+    ResultNextPyDataEvent,
+    SummaryPyEventReader,
+};
+use crate::readers::summary::Summary;
+
+/// Signature at the start of an ODL file header.
+pub const ODL_SIGNATURE: &[u8; 8] = b"EBFGONED";
+
+/// Bytes of the ODL file header.
+const ODL_HEADER_SZ: usize = 0x100;
+/// Offset of the ODL version within the file header.
+const ODL_HEADER_VERSION_OFFSET: usize = 8;
+
+/// Magic bytes of the gzip data following the file header of `.odlgz` files.
+const ODL_GZIP_MAGIC: &[u8; 4] = b"\x1f\x8b\x08\x00";
+
+/// Signature at the start of each record header.
+const CDEF_SIGNATURE: &[u8; 4] = b"\xcc\xdd\xee\xff";
+/// Bytes of an ODL version 2 record header.
+const CDEF_V2_SZ: usize = 56;
+/// Offset of the data length within an ODL version 2 record header.
+const CDEF_V2_DATA_LEN_OFFSET: usize = 48;
+/// Bytes of an ODL version 3 record header.
+const CDEF_V3_SZ: usize = 32;
+/// Offset of the context data length within an ODL version 3 record header.
+const CDEF_V3_CONTEXT_LEN_OFFSET: usize = 4;
+/// Offset of the data length within an ODL version 3 record header.
+const CDEF_V3_DATA_LEN_OFFSET: usize = 24;
+/// Bytes of the GUID and unknown fields following an ODL version 3 record
+/// header that has no context data.
+const CDEF_V3_GUID_SZ: usize = 24;
+/// Offset of the timestamp within both versions of record header.
+const CDEF_TIMESTAMP_OFFSET: usize = 8;
+
+/// Name of the obfuscation map file found with the ODL files.
+pub const OBFUSCATION_MAP_NAME: &str = "ObfuscationStringMap.txt";
+/// Name of the keystore file found with the ODL files.
+pub const KEYSTORE_NAME: &str = "general.keystore";
+/// Name of the directory of newer keystore files.
+const KEYSTORE_DIR_NAME: &str = "EncryptionKeyStoreCopy";
+
+/// Characters that separate obfuscated words.
+/// Must match `tokens` in `odl_reader.py` function `tokenized_replace`.
+const TOKENS: &str = ":\\.@%#&*|{}!?<>;:~()//\"'";
+
+/// Obfuscated words shorter than this are not encrypted.
+const CIPHER_TEXT_LEN_MIN: usize = 22;
+
+const AES_BLOCK_SZ: usize = 16;
+
+#[inline(always)]
+fn u16_le(
+    buffer: &[u8],
+    at: usize,
+) -> u16 {
+    u16::from_le_bytes([buffer[at], buffer[at + 1]])
+}
+
+#[inline(always)]
+fn u32_le(
+    buffer: &[u8],
+    at: usize,
+) -> u32 {
+    let mut b: [u8; 4] = [0; 4];
+    b.copy_from_slice(&buffer[at..at + 4]);
+
+    u32::from_le_bytes(b)
+}
+
+#[inline(always)]
+fn u64_le(
+    buffer: &[u8],
+    at: usize,
+) -> u64 {
+    let mut b: [u8; 8] = [0; 8];
+    b.copy_from_slice(&buffer[at..at + 8]);
+
+    u64::from_le_bytes(b)
+}
+
+/// Same as Python `bytes.decode("utf8", "ignore")`.
+fn utf8_ignore(data: &[u8]) -> String {
+    data.utf8_chunks().map(|chunk| chunk.valid()).collect()
+}
+
+/// Is the byte within Python `string.printable`?
+#[inline(always)]
+const fn is_printable_byte(b: u8) -> bool {
+    matches!(b, b' '..=b'~' | b'\t'..=b'\r')
+}
+
+/// Same as Python `str.isprintable()` for ASCII strings.
+fn is_printable(s: &str) -> bool {
+    s.chars().all(|c| matches!(c, ' '..='~'))
+}
+
+/// Decode base64 `text` the same as Python `base64.b64decode` which
+/// discards characters outside of the base64 alphabet.
+///
+/// Returns `None` if the data characters are not a valid length or the
+/// padding is incorrect.
+pub fn odl_base64_decode(text: &str) -> Option<Vec<u8>> {
+    let mut out: Vec<u8> = Vec::with_capacity(text.len() / 4 * 3);
+    let mut quad_pos: usize = 0;
+    let mut pads: usize = 0;
+    let mut leftchar: u8 = 0;
+    for c in text.bytes() {
+        if c == b'=' {
+            pads += 1;
+            if quad_pos >= 2 && quad_pos + pads >= 4 {
+                // a pad sequence ends the data
+                return Some(out);
+            }
+            continue;
+        }
+        let val: u8 = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => continue,
+        };
+        pads = 0;
+        match quad_pos {
+            0 => {
+                leftchar = val;
+                quad_pos = 1;
+            }
+            1 => {
+                out.push((leftchar << 2) | (val >> 4));
+                leftchar = val & 0x0F;
+                quad_pos = 2;
+            }
+            2 => {
+                out.push((leftchar << 4) | (val >> 2));
+                leftchar = val & 0x03;
+                quad_pos = 3;
+            }
+            _ => {
+                out.push((leftchar << 6) | val);
+                leftchar = 0;
+                quad_pos = 0;
+            }
+        }
+    }
+    if quad_pos != 0 {
+        return None;
+    }
+
+    Some(out)
+}
+
+/// Decode `data` as utf-16le if it appears to be utf-16le, else as utf-8.
+///
+/// Must match `guess_encoding` in `odl_reader.py`.
+fn decode_guess(data: &[u8]) -> String {
+    let utf16le: bool = match data {
+        [b0, b1, b2, b3, ..] => *b1 == 0 && *b3 == 0 && *b0 != 0 && *b2 != 0,
+        _ => true,
+    };
+    if utf16le {
+        let units: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .collect();
+        return String::from_utf16_lossy(&units);
+    }
+
+    String::from_utf8_lossy(data).into_owned()
+}
+
+/// Text encoding of decrypted strings.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum OdlUtf {
+    #[default]
+    Utf16,
+    Utf32,
+}
+
+impl OdlUtf {
+    /// Same as Python `bytes.decode("utf16")` or `bytes.decode("utf32")`.
+    /// A leading byte order mark is removed. Without a byte order mark the
+    /// data is little-endian.
+    fn decode(
+        &self,
+        data: &[u8],
+    ) -> Option<String> {
+        match self {
+            OdlUtf::Utf16 => {
+                if !data.len().is_multiple_of(2) {
+                    return None;
+                }
+                let (data, be) = match data {
+                    [0xFF, 0xFE, rest @ ..] => (rest, false),
+                    [0xFE, 0xFF, rest @ ..] => (rest, true),
+                    _ => (data, false),
+                };
+                let units: Vec<u16> = data
+                    .chunks_exact(2)
+                    .map(|b| match be {
+                        true => u16::from_be_bytes([b[0], b[1]]),
+                        false => u16::from_le_bytes([b[0], b[1]]),
+                    })
+                    .collect();
+
+                String::from_utf16(&units).ok()
+            }
+            OdlUtf::Utf32 => {
+                if !data.len().is_multiple_of(4) {
+                    return None;
+                }
+                let (data, be) = match data {
+                    [0xFF, 0xFE, 0x00, 0x00, rest @ ..] => (rest, false),
+                    [0x00, 0x00, 0xFE, 0xFF, rest @ ..] => (rest, true),
+                    _ => (data, false),
+                };
+                data.chunks_exact(4)
+                    .map(|b| {
+                        let b: [u8; 4] = [b[0], b[1], b[2], b[3]];
+                        char::from_u32(match be {
+                            true => u32::from_be_bytes(b),
+                            false => u32::from_le_bytes(b),
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// AES cipher of the key recovered from a `general.keystore` file.
+enum OdlCipher {
+    Aes128(Aes128),
+    Aes192(Aes192),
+    Aes256(Aes256),
+}
+
+/// AES-CBC decrypt `data` with a zero initialization vector.
+/// The length of `data` must be a multiple of the block size.
+fn cbc_decrypt<C>(
+    cipher: &C,
+    data: &[u8],
+) -> Vec<u8>
+where
+    C: BlockDecrypt + BlockSizeUser<BlockSize = U16>,
+{
+    let mut out: Vec<u8> = Vec::with_capacity(data.len());
+    let mut prev: [u8; AES_BLOCK_SZ] = [0; AES_BLOCK_SZ];
+    for chunk in data.chunks_exact(AES_BLOCK_SZ) {
+        let mut block = GenericArray::clone_from_slice(chunk);
+        cipher.decrypt_block(&mut block);
+        out.extend(block.iter().zip(prev.iter()).map(|(b, p)| b ^ p));
+        prev.copy_from_slice(chunk);
+    }
+
+    out
+}
+
+impl OdlCipher {
+    /// Returns `None` if `key` is not a valid AES key length.
+    fn new(key: &[u8]) -> Option<OdlCipher> {
+        match key.len() {
+            16 => Aes128::new_from_slice(key).ok().map(OdlCipher::Aes128),
+            24 => Aes192::new_from_slice(key).ok().map(OdlCipher::Aes192),
+            32 => Aes256::new_from_slice(key).ok().map(OdlCipher::Aes256),
+            _ => None,
+        }
+    }
+
+    /// Decrypt `data` and remove the PKCS#7 padding.
+    fn decrypt(
+        &self,
+        data: &[u8],
+    ) -> Option<Vec<u8>> {
+        if data.is_empty() || !data.len().is_multiple_of(AES_BLOCK_SZ) {
+            return None;
+        }
+        let mut raw: Vec<u8> = match self {
+            OdlCipher::Aes128(cipher) => cbc_decrypt(cipher, data),
+            OdlCipher::Aes192(cipher) => cbc_decrypt(cipher, data),
+            OdlCipher::Aes256(cipher) => cbc_decrypt(cipher, data),
+        };
+        let pad: usize = *raw.last()? as usize;
+        if pad == 0 || pad > AES_BLOCK_SZ || !raw[raw.len() - pad..].iter().all(|b| *b as usize == pad) {
+            return None;
+        }
+        raw.truncate(raw.len() - pad);
+
+        Some(raw)
+    }
+}
+
+/// Recovers obfuscated strings of ODL records.
+#[derive(Default)]
+struct OdlUnobfuscate {
+    /// From the `general.keystore` file.
+    cipher: Option<OdlCipher>,
+    /// Text encoding of decrypted strings, from the `general.keystore` file.
+    utf: OdlUtf,
+    /// From the `ObfuscationStringMap.txt` file.
+    map: HashMap<String, String>,
+}
+
+impl OdlUnobfuscate {
+    /// Read the keystore and obfuscation map files in directory `dir`.
+    /// Files that are not found or cannot be read are ignored.
+    fn new(dir: &Path) -> OdlUnobfuscate {
+        let mut unobfuscate = OdlUnobfuscate::default();
+        let path_map: PathBuf = dir.join(OBFUSCATION_MAP_NAME);
+        if let Ok(data) = std::fs::read(&path_map) {
+            unobfuscate.map = OdlUnobfuscate::read_map(&data);
+            def1ñ!("read {} items from {:?}", unobfuscate.map.len(), path_map);
+        }
+        let mut path_keystore: PathBuf = dir.join(KEYSTORE_NAME);
+        if !path_keystore.exists() {
+            path_keystore = dir.join(KEYSTORE_DIR_NAME).join(KEYSTORE_NAME);
+        }
+        if let Ok(data) = std::fs::read(&path_keystore)
+            && let Some((cipher, utf)) = OdlUnobfuscate::read_keystore(&data)
+        {
+            def1ñ!("read key from {:?}, {:?}", path_keystore, utf);
+            unobfuscate.cipher = Some(cipher);
+            unobfuscate.utf = utf;
+        }
+
+        unobfuscate
+    }
+
+    /// Read the JSON of a keystore file.
+    ///
+    /// Must match `read_keystore` in `odl_reader.py`.
+    fn read_keystore(data: &[u8]) -> Option<(OdlCipher, OdlUtf)> {
+        let json: ::serde_json::Value = ::serde_json::from_str(&decode_guess(data)).ok()?;
+        let key: &str = json.get(0)?.get("Key")?.as_str()?;
+        let utf: OdlUtf = match key.ends_with("\0\0") {
+            true => OdlUtf::Utf32,
+            false => OdlUtf::Utf16,
+        };
+        let cipher: OdlCipher = OdlCipher::new(&odl_base64_decode(key)?)?;
+
+        Some((cipher, utf))
+    }
+
+    /// Read the tab-separated keys and values of an obfuscation map file.
+    /// Values of repeated keys are joined with `|`.
+    ///
+    /// Must match `read_obfuscation_map` in `odl_reader.py` with
+    /// `store_all_key_values`.
+    fn read_map(data: &[u8]) -> HashMap<String, String> {
+        let mut map: HashMap<String, String> = HashMap::new();
+        // same as Python universal newlines
+        let text: String = decode_guess(data)
+            .replace("\r\n", "\n")
+            .replace('\r', "\n");
+        let mut last_key: String = String::new();
+        let mut last_val: String = String::new();
+        for line in text.split_inclusive('\n') {
+            let line: &str = line.trim_end_matches('\n');
+            let terms: Vec<&str> = line.split('\t').collect();
+            if let [key, val] = terms[..] {
+                let val: String = match map.get(key) {
+                    Some(val_old) => format!("{}|{}", val_old, val),
+                    None => val.to_string(),
+                };
+                last_key = key.to_string();
+                last_val = val.clone();
+                map.insert(last_key.clone(), val);
+            } else {
+                // continuation of the prior value
+                last_val.push('\n');
+                last_val.push_str(line);
+                map.insert(last_key.clone(), last_val.clone());
+            }
+        }
+
+        map
+    }
+
+    /// Decrypt the base64 `cipher_text`.
+    /// If it cannot be decrypted then return the `cipher_text` if it is
+    /// printable.
+    ///
+    /// Must match `decrypt` in `odl_reader.py`.
+    fn decrypt(
+        &self,
+        cipher_text: &str,
+    ) -> Option<String> {
+        let fallback: Option<String> = match is_printable(cipher_text) {
+            true => Some(cipher_text.to_string()),
+            false => None,
+        };
+        let cipher: &OdlCipher = match self.cipher {
+            Some(ref cipher) => cipher,
+            None => return fallback,
+        };
+        let len: usize = cipher_text.chars().count();
+        if len < CIPHER_TEXT_LEN_MIN {
+            // most often it was not encrypted
+            return fallback;
+        }
+        let mut text: String = cipher_text.replace('_', "/").replace('-', "+");
+        match len % 4 {
+            1 => return fallback,
+            2 => text.push_str("=="),
+            3 => text.push('='),
+            _ => {}
+        }
+        let plain_text: Option<String> = odl_base64_decode(&text)
+            .and_then(|data| cipher.decrypt(&data))
+            .and_then(|data| self.utf.decode(&data));
+        match plain_text {
+            Some(plain_text) => Some(plain_text),
+            None => fallback,
+        }
+    }
+
+    /// Replace the obfuscated words of `string`.
+    ///
+    /// Must match `tokenized_replace` in `odl_reader.py`.
+    fn tokenized_replace(
+        &self,
+        string: &str,
+    ) -> String {
+        // split into words and runs of tokens
+        let mut parts: Vec<(String, bool)> = Vec::new();
+        let mut last_word: String = String::new();
+        let mut last_token: String = String::new();
+        for c in string.chars() {
+            if TOKENS.contains(c) {
+                if !last_word.is_empty() {
+                    parts.push((std::mem::take(&mut last_word), true));
+                }
+                last_token.push(c);
+            } else {
+                if !last_token.is_empty() {
+                    parts.push((std::mem::take(&mut last_token), false));
+                }
+                last_word.push(c);
+            }
+        }
+        if !last_token.is_empty() {
+            parts.push((last_token, false));
+        }
+        if !last_word.is_empty() {
+            parts.push((last_word, true));
+        }
+        // join the parts, replacing the words
+        let mut output: String = String::with_capacity(string.len());
+        for (part, is_word) in parts.iter() {
+            if !is_word {
+                output.push_str(part);
+                continue;
+            }
+            match self.decrypt(part) {
+                Some(decrypted) if !decrypted.is_empty() => output.push_str(&decrypted),
+                _ => match self.map.get(part) {
+                    Some(val) => output.push_str(val),
+                    None => output.push_str(part),
+                },
+            }
+        }
+
+        output
+    }
+}
+
+/// One record of an ODL file.
+#[derive(Debug, Default)]
+struct OdlRecord {
+    /// Milliseconds since the Unix epoch.
+    timestamp: u64,
+    code_file: String,
+    function: String,
+    /// Extracted strings of the parameters joined by a space.
+    params: String,
+}
+
+impl OdlRecord {
+    /// Return the printable record and the end index of the timestamp
+    /// substring.
+    ///
+    /// Must match `write_odl_row` in `odl_reader.py`.
+    fn to_event_string(&self) -> (String, usize) {
+        let timestamp: String = self.timestamp.to_string();
+        let dt_end: usize = timestamp.len();
+        let event: String = match self.params.is_empty() {
+            true => format!("{} {}:{};", timestamp, self.code_file, self.function),
+            false => format!("{} {}:{}; {}", timestamp, self.code_file, self.function, self.params),
+        };
+        // same as `s4_event_bytes` in the Python `s4_event_readers` package
+        let mut event: String = event.replace('\0', " ");
+        event.push('\n');
+
+        (event, dt_end)
+    }
+}
+
+/// Read the length-prefixed string at the start of `data`.
+/// Return the bytes consumed and the string.
+///
+/// Must match `read_string` in `odl_reader.py`.
+fn read_string(data: &[u8]) -> (usize, String) {
+    if data.len() >= 4 {
+        let len: usize = u32_le(data, 0) as usize;
+        if len != 0 && len <= data.len() {
+            let end: usize = (4 + len).min(data.len());
+            return (4 + len, utf8_ignore(&data[4..end]));
+        }
+    }
+
+    (4, String::new())
+}
+
+/// Extract the length-prefixed printable strings within `data` and replace
+/// their obfuscated words.
+///
+/// Must match `extract_strings` in `odl_reader.py`.
+fn extract_strings(
+    data: &[u8],
+    unobfuscate: &OdlUnobfuscate,
+) -> Vec<String> {
+    let mut extracted: Vec<String> = Vec::new();
+    let mut at: usize = 0;
+    while at < data.len() {
+        if !is_printable_byte(data[at]) {
+            at += 1;
+            continue;
+        }
+        let start: usize = at;
+        while at < data.len() && is_printable_byte(data[at]) {
+            at += 1;
+        }
+        let match_len: usize = at - start;
+        if match_len < 4 || start < 4 {
+            continue;
+        }
+        // the string length is stored before the string
+        let stored_len: usize = u32_le(data, start - 4) as usize;
+        if match_len > stored_len + 5 {
+            def2ñ!("invalid match len {} stored len {} at {}", match_len, stored_len, start);
+            continue;
+        }
+        let text: &[u8] = &data[start..start + match_len.min(stored_len)];
+        // printable bytes are ASCII
+        let text: String = String::from_utf8_lossy(text)
+            .trim_end_matches('\n')
+            .trim_end_matches('\r')
+            .replace('\r', "")
+            .replace('\n', " ");
+        extracted.push(unobfuscate.tokenized_replace(&text));
+    }
+
+    extracted
+}
+
+/// A reader of [OneDrive Log] files.
+///
+/// The file header has the ODL version, 2 or 3. For `.odlgz` files the
+/// records following the file header are gzip compressed. Each record has a
+/// header with a timestamp, followed by the source code file name, the
+/// function name, and the parameters. The parameters may have obfuscated
+/// strings.
+///
+/// [OneDrive Log]: https://github.com/ydkhatri/OneDrive/blob/9ad135ecf56cd2086256cf8440b98b5eaa50c0ab/odl.py
+pub struct OdlReader {
+    /// The `FPath` of the file being read.
+    path: FPath,
+    /// Unique identifier for the file processing instance.
+    path_id: PathId,
+    /// The type of file being read.
+    file_type: FileType,
+    /// If necessary, the extracted file as a temporary file.
+    named_temp_file: Option<TempPath>,
+    /// Conversion of record timestamps to `DateTimeL` with this timezone offset.
+    fixed_offset: FixedOffset,
+    /// ODL version from the file header.
+    odl_version: u32,
+    /// The records, decompressed if necessary.
+    data: Vec<u8>,
+    /// Offset into `data` of the next record to read. `None` when all
+    /// records are read.
+    fo_next: Option<usize>,
+    /// Recovers obfuscated strings.
+    unobfuscate: OdlUnobfuscate,
+    /// Summary statistic.
+    /// `Count` of records processed.
+    events_processed: Count,
+    /// Summary statistic.
+    /// `Count` of records accepted by the datetime filters.
+    events_accepted: Count,
+    /// Summary statistic.
+    /// First (soonest) accepted (printed) `DateTimeL`.
+    dt_first_accepted: DateTimeLOpt,
+    /// Summary statistic.
+    /// Last (latest) accepted (printed) `DateTimeL`.
+    dt_last_accepted: DateTimeLOpt,
+    /// Summary statistic.
+    /// First (soonest) processed `DateTimeL`.
+    dt_first_processed: DateTimeLOpt,
+    /// Summary statistic.
+    /// Last (latest) processed `DateTimeL`.
+    dt_last_processed: DateTimeLOpt,
+    /// File Size of the file being read in bytes.
+    filesz: FileSz,
+    /// file Last Modified time from file-system metadata
+    mtime: SystemTime,
+    /// Out of chronological order.
+    out_of_order: Count,
+    /// The last [`Error`], if any, as a `String`
+    error: Option<String>,
+}
+
+impl fmt::Debug for OdlReader {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        f.debug_struct("OdlReader")
+            .field("Path", &self.path)
+            .field("odl_version", &self.odl_version)
+            .field("data len", &self.data.len())
+            .field("key", &self.unobfuscate.cipher.is_some())
+            .field("map len", &self.unobfuscate.map.len())
+            .field("Error?", &self.error)
+            .finish()
+    }
+}
+
+/// Implement the OdlReader.
+impl OdlReader {
+    /// Create a new `OdlReader`.
+    ///
+    /// The file is read and the file header is checked. Compressed records
+    /// are decompressed. The keystore and obfuscation map files are read
+    /// from the directory of `path`.
+    pub fn new(
+        path_id: PathId,
+        path: FPath,
+        file_type: FileType,
+        fixed_offset: FixedOffset,
+    ) -> Result<OdlReader> {
+        def1n!("({}, {:?}, {:?}, {:?})", path_id, path, file_type, fixed_offset);
+        debug_assert!(file_type.is_odl(), "OdlReader only supports FileType::Odl, given {:?}", file_type);
+
+        let path_std: &Path = Path::new(&path);
+        let named_temp_file: Option<TempPath>;
+        let mtime_opt: Option<SystemTime>;
+        (named_temp_file, mtime_opt) = match decompress_to_ntf(path_id, path_std, &file_type) {
+            Ok(ntf_mtime) => match ntf_mtime {
+                Some((ntf, mtime_opt, _filesz)) => (Some(ntf), mtime_opt),
+                None => (None, None),
+            },
+            Err(err) => {
+                def1x!("decompress_to_ntf({:?}, {:?}) Error, return {:?}", path, file_type, err);
+                return Err(err);
+            }
+        };
+        let path_actual: &Path = match named_temp_file {
+            Some(ref ntf) => ntf.as_ref(),
+            None => path_std,
+        };
+        def1o!("path_actual {:?}", path_actual);
+        let file: FileHandleManaged = FILE_HANDLE_MANAGER.request_open_managed(
+            path_id,
+            FileHandleRole::PrimaryRead,
+            path_actual,
+            OpenOptionsManaged::read_only(),
+        )?;
+        let metadata: FileMetadata = file.metadata()?;
+        let mtime: SystemTime = match mtime_opt {
+            Some(val) => val,
+            None => match metadata.modified() {
+                Ok(val) => val,
+                Err(_err) => {
+                    de_err!("metadata.modified() failed {}", _err);
+                    SystemTime::UNIX_EPOCH
+                }
+            },
+        };
+        let filesz: FileSz = metadata.len() as FileSz;
+        def1o!("filesz {}", filesz);
+
+        let mut data: Vec<u8> = Vec::with_capacity(filesz as usize);
+        (&file).read_to_end(&mut data)?;
+        drop(file);
+        if data.len() < ODL_HEADER_SZ {
+            def1x!("file too small {}, return Err", data.len());
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("file size {} is too small for an ODL header, file {:?}", data.len(), path),
+            ));
+        }
+        let odl_version: u32 = u32_le(&data, ODL_HEADER_VERSION_OFFSET);
+        def1o!("odl_version {}", odl_version);
+        if !matches!(odl_version, 2 | 3) {
+            def1x!("unknown odl_version {}, return Err", odl_version);
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Unknown ODL version {}, file {:?}", odl_version, path),
+            ));
+        }
+        let mut fo: usize = match data.starts_with(ODL_SIGNATURE) {
+            true => ODL_HEADER_SZ,
+            false => 0,
+        };
+        if data[fo..].starts_with(ODL_GZIP_MAGIC) {
+            def1o!("gzip data at {}", fo);
+            let mut data_gz: Vec<u8> = Vec::new();
+            let mut decoder = GzDecoder::new(&data[fo..]);
+            if let Err(err) = decoder.read_to_end(&mut data_gz) {
+                // the last records of a file being written may be truncated
+                if data_gz.is_empty() {
+                    def1x!("GzDecoder::read_to_end error {}, return Err", err);
+                    return Err(Error::new(
+                        err.kind(),
+                        format!("gzip decompression error {} for file {:?}", err, path),
+                    ));
+                }
+                de_err!("GzDecoder::read_to_end error {} after {} bytes for {:?}", err, data_gz.len(), path);
+            }
+            def1o!("gzip decompressed {} bytes", data_gz.len());
+            data = data_gz;
+            fo = 0;
+        }
+        if !data[fo..].starts_with(CDEF_SIGNATURE) {
+            def1x!("bad CDEF header at {}, return Err", fo);
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid ODL record header, expected {:02X?}, file {:?}", CDEF_SIGNATURE, path),
+            ));
+        }
+        let unobfuscate: OdlUnobfuscate = match path_std.parent() {
+            Some(dir) => OdlUnobfuscate::new(dir),
+            None => OdlUnobfuscate::default(),
+        };
+
+        def1x!("return Ok(OdlReader)");
+
+        Ok(OdlReader {
+            path,
+            path_id,
+            file_type,
+            named_temp_file,
+            fixed_offset,
+            odl_version,
+            data,
+            fo_next: Some(fo),
+            unobfuscate,
+            events_processed: 0,
+            events_accepted: 0,
+            dt_first_accepted: DateTimeLOpt::None,
+            dt_last_accepted: DateTimeLOpt::None,
+            dt_first_processed: DateTimeLOpt::None,
+            dt_last_processed: DateTimeLOpt::None,
+            filesz,
+            mtime,
+            out_of_order: 0,
+            error: None,
+        })
+    }
+
+    pub const fn mtime(&self) -> SystemTime {
+        self.mtime
+    }
+
+    pub const fn path_id(&self) -> PathId {
+        self.path_id
+    }
+
+    #[inline(always)]
+    pub const fn path(&self) -> &FPath {
+        &self.path
+    }
+
+    #[inline(always)]
+    pub const fn filetype(&self) -> FileType {
+        self.file_type
+    }
+
+    /// File size in bytes
+    #[inline(always)]
+    pub const fn filesz(&self) -> FileSz {
+        self.filesz
+    }
+
+    /// ODL version from the file header.
+    pub const fn odl_version(&self) -> u32 {
+        self.odl_version
+    }
+
+    /// Was a key read from a `general.keystore` file?
+    pub const fn has_key(&self) -> bool {
+        self.unobfuscate.cipher.is_some()
+    }
+
+    /// Read the record at offset `fo` of `self.data`.
+    /// Return the record and the offset of the next record, or `None`
+    /// if there are no more records.
+    ///
+    /// Must match `process_odl_v2` and `process_odl_v3` in `odl_reader.py`.
+    fn record(
+        &self,
+        fo: usize,
+    ) -> Result<Option<(OdlRecord, usize)>> {
+        def1n!("({})", fo);
+        let header_sz: usize = match self.odl_version {
+            2 => CDEF_V2_SZ,
+            _ => CDEF_V3_SZ,
+        };
+        let header: &[u8] = match self.data.get(fo..fo + header_sz) {
+            Some(header) => header,
+            None => {
+                def1x!("no more headers, return None");
+                return Ok(None);
+            }
+        };
+        if !header.starts_with(CDEF_SIGNATURE) {
+            def1x!("bad CDEF header at {}, return Err", fo);
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid ODL record header at offset {}, expected {:02X?}", fo, CDEF_SIGNATURE),
+            ));
+        }
+        let timestamp: u64 = u64_le(header, CDEF_TIMESTAMP_OFFSET);
+        let data_len: usize;
+        let skip: usize;
+        match self.odl_version {
+            2 => {
+                data_len = u32_le(header, CDEF_V2_DATA_LEN_OFFSET) as usize;
+                skip = 0;
+            }
+            _ => {
+                data_len = u32_le(header, CDEF_V3_DATA_LEN_OFFSET) as usize;
+                // skip past the context data or the GUID and other unknown fields
+                skip = match u16_le(header, CDEF_V3_CONTEXT_LEN_OFFSET) as usize {
+                    0 => CDEF_V3_GUID_SZ,
+                    context_len => context_len,
+                };
+            }
+        }
+        if data_len <= 4 {
+            def1x!("data_len {} too short, return None", data_len);
+            return Ok(None);
+        }
+        let beg: usize = (fo + header_sz + skip).min(self.data.len());
+        let end: usize = match data_len.checked_sub(skip) {
+            Some(len) => (beg + len).min(self.data.len()),
+            None => self.data.len(),
+        };
+        let data: &[u8] = &self.data[beg..end];
+        let (mut at, code_file) = read_string(data);
+        // skip the flags
+        at += 4;
+        let (len, function) = read_string(data.get(at..).unwrap_or_default());
+        at += len;
+        let params: String = extract_strings(data.get(at..).unwrap_or_default(), &self.unobfuscate).join(" ");
+        let record = OdlRecord {
+            timestamp,
+            code_file,
+            function,
+            params,
+        };
+        def1x!("return record timestamp {}, next {}", record.timestamp, end);
+
+        Ok(Some((record, end)))
+    }
+
+    /// Convert milliseconds since the Unix epoch to a `DateTimeL`.
+    fn timestamp_to_datetime(
+        &self,
+        timestamp: u64,
+    ) -> DateTimeLOpt {
+        if timestamp == 0 {
+            return None;
+        }
+        let dt = DateTime::from_timestamp_millis(i64::try_from(timestamp).ok()?)?;
+
+        Some(self.fixed_offset.from_utc_datetime(&dt.naive_utc()))
+    }
+
+    /// Return the next record that passes the datetime filters.
+    pub fn next(
+        &mut self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> ResultNextPyDataEvent {
+        def1n!("({:?}, {:?})", dt_filter_after, dt_filter_before);
+        while let Some(fo) = self.fo_next {
+            let (record, fo_next) = match self.record(fo) {
+                Ok(Some(val)) => val,
+                Ok(None) => {
+                    self.fo_next = None;
+                    break;
+                }
+                Err(err) => {
+                    def1x!("record({}) error {}", fo, err);
+                    self.fo_next = None;
+                    self.error = Some(err.to_string());
+                    return ResultFind4::Err(err);
+                }
+            };
+            self.fo_next = Some(fo_next);
+            self.events_processed += 1;
+            let dt: DateTimeL = match self.timestamp_to_datetime(record.timestamp) {
+                Some(dt) => dt,
+                None => continue,
+            };
+            summary_stat!(self.dm_first_last_update_processed(&dt));
+            if dt_pass_filters(&dt, dt_filter_after, dt_filter_before) != Result_Filter_DateTime2::InRange {
+                continue;
+            }
+            let (event, dt_end) = record.to_event_string();
+            summary_stat!(self.dm_first_last_update_accepted(&dt));
+            self.events_accepted += 1;
+            def1x!("return Found record at {}", fo);
+
+            return ResultFind4::Found(PyDataEvent::new(
+                EventBytes::from(event.as_bytes()),
+                dt,
+                DtBegEndPairOpt::Some((0, dt_end)),
+            ));
+        }
+        def1x!("return Done");
+
+        ResultFind4::Done
+    }
+
+    /// Update the two statistic `DateTimeL` of
+    /// `self.dt_first_processed` and `self.dt_last_processed`.
+    fn dm_first_last_update_processed(
+        &mut self,
+        dt: &DateTimeL,
+    ) {
+        if !summary_stats_enabled() {
+            return;
+        }
+        match self.dt_first_processed {
+            Some(dt_) if &dt_ <= dt => {}
+            Some(_) => {
+                self.dt_first_processed = Some(*dt);
+                self.out_of_order += 1;
+            }
+            None => self.dt_first_processed = Some(*dt),
+        }
+        match self.dt_last_processed {
+            Some(dt_) if &dt_ >= dt => {}
+            _ => self.dt_last_processed = Some(*dt),
+        }
+    }
+
+    /// Update the two statistic `DateTimeL` of
+    /// `self.dt_first_accepted` and `self.dt_last_accepted`.
+    fn dm_first_last_update_accepted(
+        &mut self,
+        dt: &DateTimeL,
+    ) {
+        if !summary_stats_enabled() {
+            return;
+        }
+        match self.dt_first_accepted {
+            Some(dt_) if &dt_ <= dt => {}
+            _ => self.dt_first_accepted = Some(*dt),
+        }
+        match self.dt_last_accepted {
+            Some(dt_) if &dt_ >= dt => {}
+            _ => self.dt_last_accepted = Some(*dt),
+        }
+    }
+
+    /// Return an up-to-date `SummaryPyEventReader` instance for this
+    /// `OdlReader`. The Python process statistics are left at defaults.
+    pub fn summary(&self) -> SummaryPyEventReader {
+        SummaryPyEventReader {
+            pyeventreader_events_processed: self.events_processed,
+            pyeventreader_events_accepted: self.events_accepted,
+            pyeventreader_datetime_first_accepted: self.dt_first_accepted,
+            pyeventreader_datetime_last_accepted: self.dt_last_accepted,
+            pyeventreader_datetime_first_processed: self.dt_first_processed,
+            pyeventreader_datetime_last_processed: self.dt_last_processed,
+            pyeventreader_filesz: self.filesz,
+            pyeventreader_out_of_order: self.out_of_order,
+            ..Default::default()
+        }
+    }
+
+    /// Return an up-to-date [`Summary`] instance for this `OdlReader`.
+    ///
+    /// [`Summary`]: crate::readers::summary::Summary
+    pub fn summary_complete(&self) -> Summary {
+        let path = self.path().clone();
+        let path_ntf: Option<FPath> = self
+            .named_temp_file
+            .as_ref()
+            .map(|ntf| path_to_fpath(ntf.as_ref()));
+        let filetype = self.filetype();
+        let logmessagetype = filetype.to_logmessagetype();
+        let summaryodlreader: SummaryPyEventReader = self.summary();
+        let error: Option<String> = self.error.clone();
+
+        Summary::new(
+            path,
+            path_ntf,
+            filetype,
+            logmessagetype,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(summaryodlreader),
+            None,
+            None,
+            error,
+        )
+    }
+}
+
+impl PyDataEventReader for OdlReader {
+    fn next(
+        &mut self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> ResultNextPyDataEvent {
+        OdlReader::next(self, dt_filter_after, dt_filter_before)
+    }
+
+    fn mtime(&self) -> SystemTime {
+        OdlReader::mtime(self)
+    }
+
+    fn summary_complete(&self) -> Summary {
+        OdlReader::summary_complete(self)
+    }
+}
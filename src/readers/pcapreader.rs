@@ -0,0 +1,1219 @@
+// src/readers/pcapreader.rs
+
+//! Implements a [`PcapReader`], the driver of deriving [`PyDataEvent`s]
+//! from the syslog messages of a [pcap] or [pcapng] network packet capture
+//! file, e.g. the output of `tcpdump -w` or Wireshark.
+//!
+//! The file format is read natively. The syslog payloads of UDP datagrams
+//! ([RFC 5426]) and of TCP streams ([RFC 6587]), octet-counted or
+//! newline-framed, are reassembled into syslog messages. A TCP stream is
+//! reassembled in capture order; a lost segment ends the pending message.
+//! IP fragments are not reassembled.
+//!
+//! A datagram or stream is syslog if the destination port is a syslog port
+//! (514, 601, or 6514 without TLS) or if the payload begins with a syslog
+//! `<PRI>`.
+//!
+//! Each printed event is the syslog message prepended with the sender IP
+//! address. The datetime of an event is the packet capture timestamp, or,
+//! see [`PcapTime`], the timestamp embedded in the [RFC 3164] or [RFC 5424]
+//! syslog message.
+//!
+//! [`PyDataEvent`s]: crate::data::pydataevent::PyDataEvent
+//! [pcap]: https://www.tcpdump.org/manpages/pcap-savefile.5.html
+//! [pcapng]: https://datatracker.ietf.org/doc/draft-ietf-opsawg-pcapng/
+//! [RFC 3164]: https://www.rfc-editor.org/rfc/rfc3164
+//! [RFC 5424]: https://www.rfc-editor.org/rfc/rfc5424
+//! [RFC 5426]: https://www.rfc-editor.org/rfc/rfc5426
+//! [RFC 6587]: https://www.rfc-editor.org/rfc/rfc6587
+
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+use std::fmt;
+use std::io::{
+    Error,
+    ErrorKind,
+    Read,
+    Result,
+};
+use std::net::{
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr,
+};
+use std::path::Path;
+
+use ::chrono::{
+    DateTime,
+    Datelike,
+    Duration,
+    NaiveDateTime,
+    TimeZone,
+};
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    def1n,
+    def1o,
+    def1x,
+    def1ñ,
+    def2ñ,
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+use ::tempfile::TempPath;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileMetadata,
+    FileSz,
+    FileType,
+    PathId,
+    ResultFind4,
+    summary_stat,
+    summary_stats_enabled,
+};
+use crate::data::datetime::{
+    DateTimeL,
+    DateTimeLOpt,
+    FixedOffset,
+    Result_Filter_DateTime2,
+    SystemTime,
+    dt_pass_filters,
+};
+use crate::data::pydataevent::{
+    DtBegEndPairOpt,
+    EventBytes,
+    PyDataEvent,
+};
+use crate::de_err;
+use crate::readers::filedecompressor::decompress_to_ntf;
+use crate::readers::filehandlemanager::{
+    FILE_HANDLE_MANAGER,
+    FileHandleManaged,
+    FileHandleRole,
+    OpenOptionsManaged,
+};
+use crate::readers::helpers::path_to_fpath;
+use crate::readers::pyeventreader::{
+    PyDataEventReader,
+    ResultNextPyDataEvent,
+    SummaryPyEventReader,
+};
+use crate::readers::summary::Summary;
+
+/// pcap file magic number of microsecond timestamps.
+pub const PCAP_MAGIC_USEC: u32 = 0xA1B2_C3D4;
+/// pcap file magic number of nanosecond timestamps.
+pub const PCAP_MAGIC_NSEC: u32 = 0xA1B2_3C4D;
+/// Size of the pcap file header.
+pub const PCAP_HEADER_SZ: usize = 24;
+/// Size of a pcap packet record header.
+pub const PCAP_RECORD_HEADER_SZ: usize = 16;
+
+/// pcapng Section Header Block type.
+pub const PCAPNG_BLOCK_SHB: u32 = 0x0A0D_0D0A;
+/// pcapng Interface Description Block type.
+pub const PCAPNG_BLOCK_IDB: u32 = 0x0000_0001;
+/// pcapng obsolete Packet Block type.
+pub const PCAPNG_BLOCK_PB: u32 = 0x0000_0002;
+/// pcapng Enhanced Packet Block type.
+pub const PCAPNG_BLOCK_EPB: u32 = 0x0000_0006;
+/// pcapng Section Header Block byte-order magic number.
+pub const PCAPNG_BYTE_ORDER_MAGIC: u32 = 0x1A2B_3C4D;
+/// Interface Description Block option `if_tsresol`.
+const PCAPNG_OPTION_IF_TSRESOL: u16 = 9;
+/// Interface Description Block option `if_tsoffset`.
+const PCAPNG_OPTION_IF_TSOFFSET: u16 = 14;
+
+/// Link-layer header types, `LINKTYPE_*`.
+pub const LINKTYPE_NULL: u32 = 0;
+pub const LINKTYPE_ETHERNET: u32 = 1;
+pub const LINKTYPE_RAW: u32 = 101;
+pub const LINKTYPE_LOOP: u32 = 108;
+pub const LINKTYPE_LINUX_SLL: u32 = 113;
+pub const LINKTYPE_IPV4: u32 = 228;
+pub const LINKTYPE_IPV6: u32 = 229;
+pub const LINKTYPE_LINUX_SLL2: u32 = 276;
+
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_IPV6: u16 = 0x86DD;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+const ETHERTYPE_QINQ: u16 = 0x88A8;
+
+const IP_PROTOCOL_TCP: u8 = 6;
+const IP_PROTOCOL_UDP: u8 = 17;
+
+const TCP_FLAG_FIN: u8 = 0x01;
+const TCP_FLAG_SYN: u8 = 0x02;
+const TCP_FLAG_RST: u8 = 0x04;
+
+/// Destination ports of syslog; UDP and TCP 514, RFC 3195 601, and
+/// RFC 5425 6514 without TLS.
+pub const SYSLOG_PORTS: [u16; 3] = [514, 601, 6514];
+
+/// Maximum count of digits of an RFC 6587 octet count.
+const SYSLOG_OCTET_COUNT_DIGITS_MAX: usize = 9;
+
+/// Which datetime of a syslog message is the event datetime.
+///
+/// Set by the `--pcap-time` command-line option.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ::clap::ValueEnum)]
+pub enum PcapTime {
+    /// The packet capture timestamp.
+    #[default]
+    Capture,
+    /// The RFC 3164 or RFC 5424 timestamp of the syslog message, or the
+    /// packet capture timestamp if the message has no timestamp.
+    Message,
+}
+
+impl fmt::Display for PcapTime {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            PcapTime::Capture => write!(f, "capture"),
+            PcapTime::Message => write!(f, "message"),
+        }
+    }
+}
+
+fn u16_at(
+    data: &[u8],
+    at: usize,
+    big_endian: bool,
+) -> Option<u16> {
+    let buf: [u8; 2] = data.get(at..at + 2)?.try_into().ok()?;
+
+    Some(match big_endian {
+        true => u16::from_be_bytes(buf),
+        false => u16::from_le_bytes(buf),
+    })
+}
+
+fn u32_at(
+    data: &[u8],
+    at: usize,
+    big_endian: bool,
+) -> Option<u32> {
+    let buf: [u8; 4] = data.get(at..at + 4)?.try_into().ok()?;
+
+    Some(match big_endian {
+        true => u32::from_be_bytes(buf),
+        false => u32::from_le_bytes(buf),
+    })
+}
+
+/// Return the end of the syslog `<PRI>` at the start of `data`.
+pub fn syslog_pri_end(data: &[u8]) -> Option<usize> {
+    if data.first() != Some(&b'<') {
+        return None;
+    }
+    let digits: usize = data[1..].iter().take(4).take_while(|b| b.is_ascii_digit()).count();
+    if !(1..=3).contains(&digits) || data.get(1 + digits) != Some(&b'>') {
+        return None;
+    }
+
+    Some(2 + digits)
+}
+
+/// Does `data`, the first payload of a datagram or stream, begin with a
+/// syslog message, optionally after an RFC 6587 octet count?
+fn syslog_payload_begins(data: &[u8]) -> bool {
+    let digits: usize = data.iter().take_while(|b| b.is_ascii_digit()).count();
+    match digits {
+        1..=SYSLOG_OCTET_COUNT_DIGITS_MAX if data.get(digits) == Some(&b' ') => {
+            syslog_pri_end(&data[digits + 1..]).is_some()
+        }
+        _ => syslog_pri_end(data).is_some(),
+    }
+}
+
+/// Split the syslog messages from the start of `data`, the reassembled
+/// payload of a TCP stream. A message is framed by an RFC 6587 octet count,
+/// e.g. `11 <13>message`, or ended by a newline or a NUL.
+/// Return the messages and the count of bytes of `data` split.
+pub fn syslog_frames(data: &[u8]) -> (Vec<&[u8]>, usize) {
+    let mut frames: Vec<&[u8]> = Vec::new();
+    let mut at: usize = 0;
+    loop {
+        while at < data.len() && matches!(data[at], b'\n' | b'\r' | b'\0') {
+            at += 1;
+        }
+        let rest: &[u8] = &data[at..];
+        if rest.is_empty() {
+            break;
+        }
+        let digits: usize = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+        if (1..=SYSLOG_OCTET_COUNT_DIGITS_MAX).contains(&digits) && rest.get(digits) == Some(&b' ') {
+            // octet counting
+            let count: usize = match std::str::from_utf8(&rest[..digits]).ok().and_then(|s| s.parse().ok()) {
+                Some(count) => count,
+                None => break,
+            };
+            let beg: usize = digits + 1;
+            match rest.get(beg..beg + count) {
+                Some(frame) => {
+                    frames.push(frame);
+                    at += beg + count;
+                }
+                None => break,
+            }
+            continue;
+        }
+        // non-transparent framing
+        match rest.iter().position(|b| matches!(b, b'\n' | b'\0')) {
+            Some(end) => {
+                frames.push(&rest[..end]);
+                at += end + 1;
+            }
+            None => break,
+        }
+    }
+
+    (frames, at)
+}
+
+/// Parse the [RFC 5424] or [RFC 3164] timestamp of syslog message `text`.
+/// An RFC 3164 timestamp has no year or timezone; the year is of
+/// `dt_capture` and the timezone is `tz_offset`.
+/// Return the byte offsets of the timestamp within `text` and the datetime.
+///
+/// [RFC 3164]: https://www.rfc-editor.org/rfc/rfc3164
+/// [RFC 5424]: https://www.rfc-editor.org/rfc/rfc5424
+pub fn syslog_message_datetime(
+    text: &str,
+    dt_capture: &DateTimeL,
+    tz_offset: &FixedOffset,
+) -> Option<(usize, usize, DateTimeL)> {
+    let beg: usize = syslog_pri_end(text.as_bytes())?;
+    let rest: &str = &text[beg..];
+    // RFC 5424 `VERSION SP TIMESTAMP`
+    let version: usize = rest.bytes().take_while(|b| b.is_ascii_digit()).count();
+    if (1..=2).contains(&version) && rest.as_bytes().get(version) == Some(&b' ') {
+        let beg: usize = beg + version + 1;
+        let timestamp: &str = text[beg..].split(' ').next()?;
+        let dt: DateTimeL = DateTime::parse_from_rfc3339(timestamp).ok()?;
+        return Some((beg, beg + timestamp.len(), dt));
+    }
+    // RFC 3164 `Mmm dd hh:mm:ss`
+    let timestamp: &str = rest.get(..15)?;
+    let dt_local: NaiveDateTime = dt_capture.with_timezone(tz_offset).naive_local();
+    let parse = |year: i32| -> Option<DateTimeL> {
+        let data: String = format!("{} {}", year, timestamp);
+        let naive: NaiveDateTime = NaiveDateTime::parse_from_str(&data, "%Y %b %e %H:%M:%S").ok()?;
+        tz_offset.from_local_datetime(&naive).single()
+    };
+    let mut dt: DateTimeL = parse(dt_local.year())?;
+    // a message of December captured in January
+    if dt - *dt_capture > Duration::days(30) {
+        dt = parse(dt_local.year() - 1)?;
+    }
+
+    Some((beg, beg + timestamp.len(), dt))
+}
+
+/// The IP packet of link-layer `frame` of `linktype`.
+fn frame_ip_packet(
+    linktype: u32,
+    frame: &[u8],
+) -> Option<&[u8]> {
+    match linktype {
+        LINKTYPE_ETHERNET => {
+            let mut at: usize = 12;
+            let mut ethertype: u16 = u16_at(frame, at, true)?;
+            while matches!(ethertype, ETHERTYPE_VLAN | ETHERTYPE_QINQ) {
+                at += 4;
+                ethertype = u16_at(frame, at, true)?;
+            }
+            match ethertype {
+                ETHERTYPE_IPV4 | ETHERTYPE_IPV6 => frame.get(at + 2..),
+                _ => None,
+            }
+        }
+        // the address family is checked by the IP version
+        LINKTYPE_NULL | LINKTYPE_LOOP => frame.get(4..),
+        LINKTYPE_RAW | LINKTYPE_IPV4 | LINKTYPE_IPV6 => Some(frame),
+        LINKTYPE_LINUX_SLL => match u16_at(frame, 14, true)? {
+            ETHERTYPE_IPV4 | ETHERTYPE_IPV6 => frame.get(16..),
+            _ => None,
+        },
+        LINKTYPE_LINUX_SLL2 => match u16_at(frame, 0, true)? {
+            ETHERTYPE_IPV4 | ETHERTYPE_IPV6 => frame.get(20..),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The source and destination addresses, the protocol, and the payload of
+/// IP `packet`. A fragment returns `None`.
+fn ip_packet_payload(packet: &[u8]) -> Option<(IpAddr, IpAddr, u8, &[u8])> {
+    match packet.first()? >> 4 {
+        4 => {
+            let header_sz: usize = usize::from(packet[0] & 0x0F) * 4;
+            let total_sz: usize = usize::from(u16_at(packet, 2, true)?);
+            let fragment: u16 = u16_at(packet, 6, true)?;
+            // "more fragments" flag or a fragment offset
+            if fragment & 0x3FFF != 0 || header_sz < 20 {
+                return None;
+            }
+            let src: [u8; 4] = packet.get(12..16)?.try_into().ok()?;
+            let dst: [u8; 4] = packet.get(16..20)?.try_into().ok()?;
+            let payload: &[u8] = packet.get(header_sz..total_sz.min(packet.len()))?;
+
+            Some((IpAddr::V4(Ipv4Addr::from(src)), IpAddr::V4(Ipv4Addr::from(dst)), packet[9], payload))
+        }
+        6 => {
+            let payload_sz: usize = usize::from(u16_at(packet, 4, true)?);
+            let src: [u8; 16] = packet.get(8..24)?.try_into().ok()?;
+            let dst: [u8; 16] = packet.get(24..40)?.try_into().ok()?;
+            let mut next: u8 = *packet.get(6)?;
+            let mut payload: &[u8] = packet.get(40..(40 + payload_sz).min(packet.len()))?;
+            // hop-by-hop, routing, and destination options extension headers
+            while matches!(next, 0 | 43 | 60) {
+                let header_sz: usize = (usize::from(*payload.get(1)?) + 1) * 8;
+                next = payload[0];
+                payload = payload.get(header_sz..)?;
+            }
+
+            Some((IpAddr::V6(Ipv6Addr::from(src)), IpAddr::V6(Ipv6Addr::from(dst)), next, payload))
+        }
+        _ => None,
+    }
+}
+
+/// A TCP stream, the sender address and port and the receiver address and
+/// port.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+struct PcapFlow {
+    src: IpAddr,
+    src_port: u16,
+    dst: IpAddr,
+    dst_port: u16,
+}
+
+/// The reassembled payload of a TCP stream not yet split into syslog
+/// messages.
+#[derive(Debug, Default)]
+struct PcapStream {
+    data: Vec<u8>,
+    /// capture datetime of the first byte of `data`
+    dt: DateTimeLOpt,
+    /// sequence number of the next expected byte
+    seq_next: Option<u32>,
+}
+
+/// A syslog message read from a packet capture.
+#[derive(Debug)]
+struct PcapMessage {
+    dt_capture: DateTimeL,
+    sender: IpAddr,
+    text: String,
+}
+
+/// An Interface Description Block of a pcapng file.
+#[derive(Clone, Copy, Debug)]
+struct PcapngInterface {
+    linktype: u32,
+    /// timestamp units per second
+    units: u64,
+    /// seconds added to each timestamp
+    offset: i64,
+}
+
+/// The format of a packet capture file.
+#[derive(Clone, Copy, Debug)]
+enum PcapFormat {
+    Pcap { nsec: bool, linktype: u32 },
+    Pcapng,
+}
+
+/// A packet read from a packet capture file; the capture datetime, the
+/// link-layer header type, and the offsets of the packet data.
+#[derive(Debug)]
+struct PcapPacket {
+    dt: DateTimeL,
+    linktype: u32,
+    beg: usize,
+    end: usize,
+}
+
+/// A reader of the syslog messages of [pcap] and [pcapng] network packet
+/// capture files.
+///
+/// [pcap]: https://www.tcpdump.org/manpages/pcap-savefile.5.html
+/// [pcapng]: https://datatracker.ietf.org/doc/draft-ietf-opsawg-pcapng/
+pub struct PcapReader {
+    /// The `FPath` of the file being read.
+    path: FPath,
+    /// Unique identifier for the file processing instance.
+    path_id: PathId,
+    /// The type of file being read.
+    file_type: FileType,
+    /// If necessary, the extracted file as a temporary file.
+    named_temp_file: Option<TempPath>,
+    /// Conversion of timestamps to `DateTimeL` with this timezone offset.
+    fixed_offset: FixedOffset,
+    /// Which datetime of a syslog message is the event datetime.
+    pcap_time: PcapTime,
+    /// The file data.
+    data: Vec<u8>,
+    /// Offset into `data` of the next record or block to read.
+    fo_next: usize,
+    /// pcap or pcapng
+    format: PcapFormat,
+    /// Byte order of the file or of the current pcapng section.
+    big_endian: bool,
+    /// The interfaces of the current pcapng section.
+    interfaces: Vec<PcapngInterface>,
+    /// The TCP streams being reassembled.
+    streams: HashMap<PcapFlow, PcapStream>,
+    /// Syslog messages read and not yet returned.
+    messages: VecDeque<PcapMessage>,
+    /// Summary statistic.
+    /// `Count` of packets read.
+    packets: Count,
+    /// Summary statistic.
+    /// `Count` of syslog messages processed.
+    events_processed: Count,
+    /// Summary statistic.
+    /// `Count` of syslog messages accepted by the datetime filters.
+    events_accepted: Count,
+    /// Summary statistic.
+    /// First (soonest) accepted (printed) `DateTimeL`.
+    dt_first_accepted: DateTimeLOpt,
+    /// Summary statistic.
+    /// Last (latest) accepted (printed) `DateTimeL`.
+    dt_last_accepted: DateTimeLOpt,
+    /// Summary statistic.
+    /// First (soonest) processed `DateTimeL`.
+    dt_first_processed: DateTimeLOpt,
+    /// Summary statistic.
+    /// Last (latest) processed `DateTimeL`.
+    dt_last_processed: DateTimeLOpt,
+    /// File Size of the file being read in bytes.
+    filesz: FileSz,
+    /// file Last Modified time from file-system metadata
+    mtime: SystemTime,
+    /// Out of chronological order.
+    out_of_order: Count,
+    /// The last [`Error`], if any, as a `String`
+    error: Option<String>,
+}
+
+impl fmt::Debug for PcapReader {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        f.debug_struct("PcapReader")
+            .field("Path", &self.path)
+            .field("data len", &self.data.len())
+            .field("fo_next", &self.fo_next)
+            .field("format", &self.format)
+            .field("big_endian", &self.big_endian)
+            .field("pcap_time", &self.pcap_time)
+            .field("streams len", &self.streams.len())
+            .field("Error?", &self.error)
+            .finish()
+    }
+}
+
+/// Implement the PcapReader.
+impl PcapReader {
+    /// Create a new `PcapReader`.
+    ///
+    /// The file is read and the file header is checked.
+    pub fn new(
+        path_id: PathId,
+        path: FPath,
+        file_type: FileType,
+        fixed_offset: FixedOffset,
+        pcap_time: PcapTime,
+    ) -> Result<PcapReader> {
+        def1n!("({}, {:?}, {:?}, {:?}, {:?})", path_id, path, file_type, fixed_offset, pcap_time);
+        debug_assert!(file_type.is_pcap(), "PcapReader only supports FileType::Pcap, given {:?}", file_type);
+
+        let path_std: &Path = Path::new(&path);
+        let named_temp_file: Option<TempPath>;
+        let mtime_opt: Option<SystemTime>;
+        (named_temp_file, mtime_opt) = match decompress_to_ntf(path_id, path_std, &file_type) {
+            Ok(ntf_mtime) => match ntf_mtime {
+                Some((ntf, mtime_opt, _filesz)) => (Some(ntf), mtime_opt),
+                None => (None, None),
+            },
+            Err(err) => {
+                def1x!("decompress_to_ntf({:?}, {:?}) Error, return {:?}", path, file_type, err);
+                return Err(err);
+            }
+        };
+        let path_actual: &Path = match named_temp_file {
+            Some(ref ntf) => ntf.as_ref(),
+            None => path_std,
+        };
+        def1o!("path_actual {:?}", path_actual);
+        let file: FileHandleManaged = FILE_HANDLE_MANAGER.request_open_managed(
+            path_id,
+            FileHandleRole::PrimaryRead,
+            path_actual,
+            OpenOptionsManaged::read_only(),
+        )?;
+        let metadata: FileMetadata = file.metadata()?;
+        let mtime: SystemTime = match mtime_opt {
+            Some(val) => val,
+            None => match metadata.modified() {
+                Ok(val) => val,
+                Err(_err) => {
+                    de_err!("metadata.modified() failed {}", _err);
+                    SystemTime::UNIX_EPOCH
+                }
+            },
+        };
+        let filesz: FileSz = metadata.len() as FileSz;
+        def1o!("filesz {}", filesz);
+
+        let mut data: Vec<u8> = Vec::with_capacity(filesz as usize);
+        (&file).read_to_end(&mut data)?;
+        drop(file);
+        let (format, big_endian, fo_next) = match Self::file_header(&data) {
+            Some(val) => val,
+            None => {
+                def1x!("bad file header, return Err");
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("not a pcap or pcapng file {:?}", path),
+                ));
+            }
+        };
+        def1o!("format {:?}, big_endian {}", format, big_endian);
+
+        def1x!("return Ok(PcapReader)");
+
+        Ok(PcapReader {
+            path,
+            path_id,
+            file_type,
+            named_temp_file,
+            fixed_offset,
+            pcap_time,
+            data,
+            fo_next,
+            format,
+            big_endian,
+            interfaces: Vec::new(),
+            streams: HashMap::new(),
+            messages: VecDeque::new(),
+            packets: 0,
+            events_processed: 0,
+            events_accepted: 0,
+            dt_first_accepted: DateTimeLOpt::None,
+            dt_last_accepted: DateTimeLOpt::None,
+            dt_first_processed: DateTimeLOpt::None,
+            dt_last_processed: DateTimeLOpt::None,
+            filesz,
+            mtime,
+            out_of_order: 0,
+            error: None,
+        })
+    }
+
+    pub const fn mtime(&self) -> SystemTime {
+        self.mtime
+    }
+
+    pub const fn path_id(&self) -> PathId {
+        self.path_id
+    }
+
+    #[inline(always)]
+    pub const fn path(&self) -> &FPath {
+        &self.path
+    }
+
+    #[inline(always)]
+    pub const fn filetype(&self) -> FileType {
+        self.file_type
+    }
+
+    /// File size in bytes
+    #[inline(always)]
+    pub const fn filesz(&self) -> FileSz {
+        self.filesz
+    }
+
+    /// Count of packets read.
+    pub const fn packets(&self) -> Count {
+        self.packets
+    }
+
+    /// Check the pcap file header or the first pcapng Section Header Block
+    /// of `data`. Return the format, the byte order, and the offset of the
+    /// first record or block.
+    fn file_header(data: &[u8]) -> Option<(PcapFormat, bool, usize)> {
+        if u32_at(data, 0, true)? == PCAPNG_BLOCK_SHB {
+            let big_endian: bool = match u32_at(data, 8, true)? {
+                PCAPNG_BYTE_ORDER_MAGIC => true,
+                magic if magic.swap_bytes() == PCAPNG_BYTE_ORDER_MAGIC => false,
+                _ => return None,
+            };
+            return Some((PcapFormat::Pcapng, big_endian, 0));
+        }
+        if data.len() < PCAP_HEADER_SZ {
+            return None;
+        }
+        let (nsec, big_endian): (bool, bool) = match u32_at(data, 0, true)? {
+            PCAP_MAGIC_USEC => (false, true),
+            PCAP_MAGIC_NSEC => (true, true),
+            magic if magic.swap_bytes() == PCAP_MAGIC_USEC => (false, false),
+            magic if magic.swap_bytes() == PCAP_MAGIC_NSEC => (true, false),
+            _ => return None,
+        };
+        // the upper bits are the FCS length
+        let linktype: u32 = u32_at(data, 20, big_endian)? & 0x0FFF_FFFF;
+
+        Some((PcapFormat::Pcap { nsec, linktype }, big_endian, PCAP_HEADER_SZ))
+    }
+
+    /// Convert seconds and nanoseconds since the Unix epoch to a
+    /// `DateTimeL`.
+    fn timestamp_to_datetime(
+        &self,
+        sec: i64,
+        nsec: u32,
+    ) -> DateTimeLOpt {
+        let dt = DateTime::from_timestamp(sec, nsec)?;
+
+        Some(self.fixed_offset.from_utc_datetime(&dt.naive_utc()))
+    }
+
+    fn error_truncated(
+        &self,
+        what: &str,
+    ) -> Error {
+        Error::new(
+            ErrorKind::UnexpectedEof,
+            format!("pcap {} is truncated at offset {}", what, self.fo_next),
+        )
+    }
+
+    /// Read the next packet of a pcap file.
+    fn next_packet_pcap(
+        &mut self,
+        nsec: bool,
+        linktype: u32,
+    ) -> Result<Option<PcapPacket>> {
+        let fo: usize = self.fo_next;
+        let header: &[u8] = match self.data.get(fo..fo + PCAP_RECORD_HEADER_SZ) {
+            Some(header) => header,
+            None => return Err(self.error_truncated("record header")),
+        };
+        let sec: u32 = u32_at(header, 0, self.big_endian).unwrap_or_default();
+        let frac: u32 = u32_at(header, 4, self.big_endian).unwrap_or_default();
+        let caplen: usize = u32_at(header, 8, self.big_endian).unwrap_or_default() as usize;
+        let beg: usize = fo + PCAP_RECORD_HEADER_SZ;
+        let end: usize = beg.saturating_add(caplen);
+        if end > self.data.len() {
+            return Err(self.error_truncated("record"));
+        }
+        self.fo_next = end;
+        let nsec: u32 = match nsec {
+            true => frac,
+            false => frac.saturating_mul(1000),
+        };
+        let dt: DateTimeL = match self.timestamp_to_datetime(i64::from(sec), nsec) {
+            Some(dt) => dt,
+            None => return Ok(None),
+        };
+
+        Ok(Some(PcapPacket { dt, linktype, beg, end }))
+    }
+
+    /// Read the Interface Description Block `body`.
+    fn pcapng_interface(
+        &self,
+        body: &[u8],
+    ) -> PcapngInterface {
+        let mut interface = PcapngInterface {
+            linktype: u32::from(u16_at(body, 0, self.big_endian).unwrap_or_default()),
+            units: 1_000_000,
+            offset: 0,
+        };
+        let mut at: usize = 8;
+        while let (Some(code), Some(len)) = (u16_at(body, at, self.big_endian), u16_at(body, at + 2, self.big_endian)) {
+            let len: usize = usize::from(len);
+            let value: &[u8] = match body.get(at + 4..at + 4 + len) {
+                Some(value) => value,
+                None => break,
+            };
+            match (code, value.len()) {
+                (0, _) => break,
+                (PCAPNG_OPTION_IF_TSRESOL, 1) => {
+                    let exponent: u32 = u32::from(value[0] & 0x7F);
+                    let base: u64 = if value[0] & 0x80 != 0 { 2 } else { 10 };
+                    if let Some(units) = base.checked_pow(exponent) {
+                        interface.units = units;
+                    }
+                }
+                (PCAPNG_OPTION_IF_TSOFFSET, 8) => {
+                    let buf: [u8; 8] = value.try_into().unwrap_or_default();
+                    interface.offset = match self.big_endian {
+                        true => i64::from_be_bytes(buf),
+                        false => i64::from_le_bytes(buf),
+                    };
+                }
+                _ => {}
+            }
+            at += 4 + len.next_multiple_of(4);
+        }
+
+        interface
+    }
+
+    /// Read the next packet of a pcapng file.
+    fn next_packet_pcapng(&mut self) -> Result<Option<PcapPacket>> {
+        loop {
+            let fo: usize = self.fo_next;
+            if fo >= self.data.len() {
+                return Ok(None);
+            }
+            let block_type: u32 = match u32_at(&self.data, fo, self.big_endian) {
+                Some(block_type) => block_type,
+                None => return Err(self.error_truncated("block header")),
+            };
+            if block_type == PCAPNG_BLOCK_SHB {
+                // a new section may have another byte order
+                match u32_at(&self.data, fo + 8, true) {
+                    Some(PCAPNG_BYTE_ORDER_MAGIC) => self.big_endian = true,
+                    Some(magic) if magic.swap_bytes() == PCAPNG_BYTE_ORDER_MAGIC => self.big_endian = false,
+                    _ => return Err(self.error_truncated("section header block")),
+                }
+                self.interfaces.clear();
+            }
+            let block_sz: usize = match u32_at(&self.data, fo + 4, self.big_endian) {
+                Some(sz) => sz as usize,
+                None => return Err(self.error_truncated("block header")),
+            };
+            if block_sz < 12 || fo.saturating_add(block_sz) > self.data.len() {
+                return Err(self.error_truncated("block"));
+            }
+            self.fo_next = fo + block_sz;
+            let body_beg: usize = fo + 8;
+            let body_end: usize = fo + block_sz - 4;
+            def2ñ!("block type {:#X} at {}, size {}", block_type, fo, block_sz);
+            let (interface_id, ts_high, ts_low, caplen): (usize, u32, u32, usize) = match block_type {
+                PCAPNG_BLOCK_IDB => {
+                    let interface: PcapngInterface = self.pcapng_interface(&self.data[body_beg..body_end]);
+                    self.interfaces.push(interface);
+                    continue;
+                }
+                PCAPNG_BLOCK_EPB => {
+                    let body: &[u8] = &self.data[body_beg..body_end];
+                    match (
+                        u32_at(body, 0, self.big_endian),
+                        u32_at(body, 4, self.big_endian),
+                        u32_at(body, 8, self.big_endian),
+                        u32_at(body, 12, self.big_endian),
+                    ) {
+                        (Some(id), Some(high), Some(low), Some(caplen)) => (id as usize, high, low, caplen as usize),
+                        _ => continue,
+                    }
+                }
+                PCAPNG_BLOCK_PB => {
+                    let body: &[u8] = &self.data[body_beg..body_end];
+                    match (
+                        u16_at(body, 0, self.big_endian),
+                        u32_at(body, 4, self.big_endian),
+                        u32_at(body, 8, self.big_endian),
+                        u32_at(body, 12, self.big_endian),
+                    ) {
+                        (Some(id), Some(high), Some(low), Some(caplen)) => {
+                            (usize::from(id), high, low, caplen as usize)
+                        }
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+            let interface: PcapngInterface = match self.interfaces.get(interface_id) {
+                Some(interface) => *interface,
+                None => continue,
+            };
+            let beg: usize = body_beg + 20;
+            let end: usize = beg.saturating_add(caplen);
+            if end > body_end {
+                continue;
+            }
+            let ts: u64 = (u64::from(ts_high) << 32) | u64::from(ts_low);
+            let sec: i64 = (ts / interface.units) as i64 + interface.offset;
+            let nsec: u32 = (u128::from(ts % interface.units) * 1_000_000_000 / u128::from(interface.units)) as u32;
+            let dt: DateTimeL = match self.timestamp_to_datetime(sec, nsec) {
+                Some(dt) => dt,
+                None => continue,
+            };
+
+            return Ok(Some(PcapPacket { dt, linktype: interface.linktype, beg, end }));
+        }
+    }
+
+    /// Read the next packet.
+    fn next_packet(&mut self) -> Result<Option<PcapPacket>> {
+        if self.fo_next >= self.data.len() {
+            return Ok(None);
+        }
+        match self.format {
+            PcapFormat::Pcap { nsec, linktype } => self.next_packet_pcap(nsec, linktype),
+            PcapFormat::Pcapng => self.next_packet_pcapng(),
+        }
+    }
+
+    /// Queue syslog message `frame` from `sender` captured at `dt`.
+    fn push_message(
+        &mut self,
+        dt: DateTimeL,
+        sender: IpAddr,
+        frame: &[u8],
+    ) {
+        let text: String = String::from_utf8_lossy(frame)
+            .trim_end_matches(['\n', '\r', '\0'])
+            .to_string();
+        if text.is_empty() {
+            return;
+        }
+        self.messages.push_back(PcapMessage {
+            dt_capture: dt,
+            sender,
+            text,
+        });
+    }
+
+    /// Queue the syslog messages of the TCP segment `payload` of `flow`.
+    fn tcp_segment(
+        &mut self,
+        dt: DateTimeL,
+        flow: PcapFlow,
+        seq: u32,
+        flags: u8,
+        payload: &[u8],
+    ) {
+        let is_syslog: bool = SYSLOG_PORTS.contains(&flow.dst_port)
+            || self.streams.contains_key(&flow)
+            || syslog_payload_begins(payload);
+        if !is_syslog {
+            return;
+        }
+        let mut stream: PcapStream = self.streams.remove(&flow).unwrap_or_default();
+        if flags & TCP_FLAG_SYN != 0 {
+            stream.seq_next = Some(seq.wrapping_add(1));
+        }
+        let mut payload: &[u8] = payload;
+        match stream.seq_next {
+            Some(seq_next) if seq != seq_next => {
+                let behind: u32 = seq_next.wrapping_sub(seq);
+                if (behind as i32) > 0 {
+                    // a retransmission
+                    payload = payload.get(behind as usize..).unwrap_or_default();
+                } else {
+                    // a lost segment ends the pending message
+                    def1o!("flow {:?} lost segment at seq {}", flow, seq_next);
+                    let data: Vec<u8> = std::mem::take(&mut stream.data);
+                    if let Some(dt_stream) = stream.dt.take() {
+                        self.push_message(dt_stream, flow.src, &data);
+                    }
+                }
+            }
+            _ => {}
+        }
+        if !payload.is_empty() {
+            if stream.data.is_empty() {
+                stream.dt = Some(dt);
+            }
+            stream.data.extend_from_slice(payload);
+            let seq_end: u32 = seq.wrapping_add(payload.len() as u32);
+            stream.seq_next = Some(match stream.seq_next {
+                Some(seq_next) if (seq_next.wrapping_sub(seq_end) as i32) > 0 => seq_next,
+                _ => seq_end,
+            });
+        }
+        let (frames, split): (Vec<Vec<u8>>, usize) = {
+            let (frames, split) = syslog_frames(&stream.data);
+            (frames.into_iter().map(|frame| frame.to_vec()).collect(), split)
+        };
+        if !frames.is_empty() {
+            let dt_stream: DateTimeL = stream.dt.unwrap_or(dt);
+            for frame in frames.iter() {
+                self.push_message(dt_stream, flow.src, frame);
+            }
+            stream.data.drain(..split);
+            // the rest of the data arrived with this segment
+            stream.dt = match stream.data.is_empty() {
+                true => None,
+                false => Some(dt),
+            };
+        }
+        if flags & (TCP_FLAG_FIN | TCP_FLAG_RST) != 0 {
+            // the stream is closed; the rest is an unframed message
+            if let Some(dt_stream) = stream.dt.take() {
+                self.push_message(dt_stream, flow.src, &stream.data);
+            }
+            return;
+        }
+        self.streams.insert(flow, stream);
+    }
+
+    /// Queue the syslog messages of the link-layer `frame` of `packet`.
+    fn packet_messages(
+        &mut self,
+        packet: &PcapPacket,
+    ) {
+        let frame: &[u8] = &self.data[packet.beg..packet.end];
+        let (src, dst, protocol, transport) = match frame_ip_packet(packet.linktype, frame)
+            .and_then(ip_packet_payload)
+        {
+            Some(val) => val,
+            None => return,
+        };
+        let (src_port, dst_port) = match (u16_at(transport, 0, true), u16_at(transport, 2, true)) {
+            (Some(src_port), Some(dst_port)) => (src_port, dst_port),
+            _ => return,
+        };
+        match protocol {
+            IP_PROTOCOL_UDP => {
+                let udp_sz: usize = usize::from(u16_at(transport, 4, true).unwrap_or_default());
+                let payload: Vec<u8> = match transport.get(8..udp_sz.clamp(8, transport.len())) {
+                    Some(payload) => payload.to_vec(),
+                    None => return,
+                };
+                if SYSLOG_PORTS.contains(&dst_port) || syslog_payload_begins(&payload) {
+                    self.push_message(packet.dt, src, &payload);
+                }
+            }
+            IP_PROTOCOL_TCP => {
+                let seq: u32 = u32_at(transport, 4, true).unwrap_or_default();
+                let header_sz: usize = match transport.get(12) {
+                    Some(b) => usize::from(b >> 4) * 4,
+                    None => return,
+                };
+                let flags: u8 = transport.get(13).copied().unwrap_or_default();
+                let payload: Vec<u8> = transport.get(header_sz..).unwrap_or_default().to_vec();
+                let flow = PcapFlow {
+                    src,
+                    src_port,
+                    dst,
+                    dst_port,
+                };
+                self.tcp_segment(packet.dt, flow, seq, flags, &payload);
+            }
+            _ => {}
+        }
+    }
+
+    /// Queue the pending data of all TCP streams as syslog messages, in
+    /// capture order.
+    fn flush_streams(&mut self) {
+        let mut streams: Vec<(PcapFlow, PcapStream)> = self.streams.drain().collect();
+        streams.sort_by_key(|(_flow, stream)| stream.dt);
+        for (flow, stream) in streams.into_iter() {
+            if let Some(dt) = stream.dt {
+                self.push_message(dt, flow.src, &stream.data);
+            }
+        }
+    }
+
+    /// Create the event of `message`. Return the event, the event datetime,
+    /// and the byte offsets of the datetime within the event.
+    fn message_to_event(
+        &self,
+        message: &PcapMessage,
+    ) -> (String, DateTimeL, (usize, usize)) {
+        let sender: String = message.sender.to_string();
+        if self.pcap_time == PcapTime::Message
+            && let Some((beg, end, dt)) =
+                syslog_message_datetime(&message.text, &message.dt_capture, &self.fixed_offset)
+        {
+            let at: usize = sender.len() + 1;
+            let event: String = format!("{} {}\n", sender, message.text);
+            return (event, dt, (at + beg, at + end));
+        }
+        let dt_s: String = message
+            .dt_capture
+            .format("%Y-%m-%d %H:%M:%S%.6f")
+            .to_string();
+        let event: String = format!("{} {} {}\n", dt_s, sender, message.text);
+
+        (event, message.dt_capture, (0, dt_s.len()))
+    }
+
+    /// Return the next syslog message that passes the datetime filters.
+    pub fn next(
+        &mut self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> ResultNextPyDataEvent {
+        def1n!("({:?}, {:?})", dt_filter_after, dt_filter_before);
+        loop {
+            let message: PcapMessage = match self.messages.pop_front() {
+                Some(message) => message,
+                None if self.fo_next < self.data.len() => {
+                    match self.next_packet() {
+                        Ok(Some(packet)) => {
+                            self.packets += 1;
+                            self.packet_messages(&packet);
+                        }
+                        Ok(None) => {}
+                        Err(err) => {
+                            def1o!("next_packet() error {}", err);
+                            self.fo_next = self.data.len();
+                            self.error = Some(err.to_string());
+                            self.flush_streams();
+                            // the last packet of a capture being written may
+                            // be truncated
+                            if err.kind() == ErrorKind::UnexpectedEof {
+                                def1x!("return ErrIgnore");
+                                return ResultFind4::ErrIgnore(err);
+                            }
+                            def1x!("return Err");
+                            return ResultFind4::Err(err);
+                        }
+                    }
+                    if self.fo_next >= self.data.len() {
+                        self.flush_streams();
+                    }
+                    continue;
+                }
+                None => break,
+            };
+            self.events_processed += 1;
+            let (event, dt, dt_beg_end) = self.message_to_event(&message);
+            summary_stat!(self.dm_first_last_update_processed(&dt));
+            if dt_pass_filters(&dt, dt_filter_after, dt_filter_before) != Result_Filter_DateTime2::InRange {
+                continue;
+            }
+            summary_stat!(self.dm_first_last_update_accepted(&dt));
+            self.events_accepted += 1;
+            def1x!("return Found message");
+
+            return ResultFind4::Found(PyDataEvent::new(
+                EventBytes::from(event.as_bytes()),
+                dt,
+                DtBegEndPairOpt::Some(dt_beg_end),
+            ));
+        }
+        def1x!("return Done");
+
+        ResultFind4::Done
+    }
+
+    /// Update the two statistic `DateTimeL` of
+    /// `self.dt_first_processed` and `self.dt_last_processed`.
+    fn dm_first_last_update_processed(
+        &mut self,
+        dt: &DateTimeL,
+    ) {
+        if !summary_stats_enabled() {
+            return;
+        }
+        match self.dt_first_processed {
+            Some(dt_) if &dt_ <= dt => {}
+            Some(_) => {
+                self.dt_first_processed = Some(*dt);
+                self.out_of_order += 1;
+            }
+            None => self.dt_first_processed = Some(*dt),
+        }
+        match self.dt_last_processed {
+            Some(dt_) if &dt_ >= dt => {}
+            _ => self.dt_last_processed = Some(*dt),
+        }
+    }
+
+    /// Update the two statistic `DateTimeL` of
+    /// `self.dt_first_accepted` and `self.dt_last_accepted`.
+    fn dm_first_last_update_accepted(
+        &mut self,
+        dt: &DateTimeL,
+    ) {
+        if !summary_stats_enabled() {
+            return;
+        }
+        match self.dt_first_accepted {
+            Some(dt_) if &dt_ <= dt => {}
+            _ => self.dt_first_accepted = Some(*dt),
+        }
+        match self.dt_last_accepted {
+            Some(dt_) if &dt_ >= dt => {}
+            _ => self.dt_last_accepted = Some(*dt),
+        }
+    }
+
+    /// Return an up-to-date `SummaryPyEventReader` instance for this
+    /// `PcapReader`. The Python process statistics are left at defaults.
+    pub fn summary(&self) -> SummaryPyEventReader {
+        SummaryPyEventReader {
+            pyeventreader_events_processed: self.events_processed,
+            pyeventreader_events_accepted: self.events_accepted,
+            pyeventreader_datetime_first_accepted: self.dt_first_accepted,
+            pyeventreader_datetime_last_accepted: self.dt_last_accepted,
+            pyeventreader_datetime_first_processed: self.dt_first_processed,
+            pyeventreader_datetime_last_processed: self.dt_last_processed,
+            pyeventreader_filesz: self.filesz,
+            pyeventreader_out_of_order: self.out_of_order,
+            ..Default::default()
+        }
+    }
+
+    /// Return an up-to-date [`Summary`] instance for this `PcapReader`.
+    ///
+    /// [`Summary`]: crate::readers::summary::Summary
+    pub fn summary_complete(&self) -> Summary {
+        let path = self.path().clone();
+        let path_ntf: Option<FPath> = self
+            .named_temp_file
+            .as_ref()
+            .map(|ntf| path_to_fpath(ntf.as_ref()));
+        let filetype = self.filetype();
+        let logmessagetype = filetype.to_logmessagetype();
+        let summarypcapreader: SummaryPyEventReader = self.summary();
+        let error: Option<String> = self.error.clone();
+
+        Summary::new(
+            path,
+            path_ntf,
+            filetype,
+            logmessagetype,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(summarypcapreader),
+            None,
+            None,
+            error,
+        )
+    }
+}
+
+impl PyDataEventReader for PcapReader {
+    fn next(
+        &mut self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> ResultNextPyDataEvent {
+        PcapReader::next(self, dt_filter_after, dt_filter_before)
+    }
+
+    fn mtime(&self) -> SystemTime {
+        PcapReader::mtime(self)
+    }
+
+    fn summary_complete(&self) -> Summary {
+        PcapReader::summary_complete(self)
+    }
+}
@@ -130,6 +130,9 @@ pub enum PyEventType {
     Asl,
     Etl,
     Odl,
+    Logcat,
+    Pcap,
+    Tracev3,
 }
 
 /// A wrapper for running a `PyRunner` instance that calls
@@ -260,6 +263,26 @@ pub struct SummaryPyEventReader {
     pub pyeventreader_duration_proc_run: Duration,
 }
 
+/// A native reader that returns [`PyDataEvent`]s without a Python process,
+/// e.g. [`AslReader`].
+/// The events are printed the same as the events of a [`PyEventReader`].
+///
+/// [`AslReader`]: crate::readers::aslreader::AslReader
+pub trait PyDataEventReader {
+    /// Return the next event that passes the datetime filters.
+    fn next(
+        &mut self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> ResultNextPyDataEvent;
+
+    /// Modified time of the file.
+    fn mtime(&self) -> SystemTime;
+
+    /// Return an up-to-date [`Summary`] instance for this reader.
+    fn summary_complete(&self) -> Summary;
+}
+
 /// Implement the PyEventReader.
 impl PyEventReader {
     /// Entry buffer size.
@@ -407,6 +430,36 @@ impl PyEventReader {
                 extra_args.push("--all_key_values");
                 extra_args.push("--all_data");
             }
+            PyEventType::Logcat => {
+                debug_panic!("PyEventReader does not read logcat files");
+                def1x!("PyEventType::Logcat has no Python script, return Error");
+                return Err(
+                    Error::new(
+                        ErrorKind::Unsupported,
+                        "logcat files are read by the LogcatReader",
+                    )
+                );
+            }
+            PyEventType::Pcap => {
+                debug_panic!("PyEventReader does not read pcap files");
+                def1x!("PyEventType::Pcap has no Python script, return Error");
+                return Err(
+                    Error::new(
+                        ErrorKind::Unsupported,
+                        "pcap files are read by the PcapReader",
+                    )
+                );
+            }
+            PyEventType::Tracev3 => {
+                debug_panic!("PyEventReader does not read tracev3 files");
+                def1x!("PyEventType::Tracev3 has no Python script, return Error");
+                return Err(
+                    Error::new(
+                        ErrorKind::Unsupported,
+                        "tracev3 files are read by the Tracev3Reader",
+                    )
+                );
+            }
         };
         let wait_input_per_prints: String = format!("--wait-input-per-prints={}", WAIT_INPUT_PER_PRINTS + 1);
         let mut args = vec![
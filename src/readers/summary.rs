@@ -543,7 +543,8 @@ impl Summary {
             SummaryReaderData::Etvx(summaryevtxreader) => {
                 max!(
                     summaryevtxreader.evtxreader_events_accepted,
-                    summaryevtxreader.evtxreader_events_processed
+                    summaryevtxreader.evtxreader_events_processed,
+                    summaryevtxreader.evtxreader_events_filtered
                 )
             }
             SummaryReaderData::Journal(summaryjournalreader) => {
@@ -731,9 +732,13 @@ impl fmt::Debug for Summary {
                             // Summary::default()
                             FileType::Asl { archival_type: _ }
                             | FileType::Etl { archival_type: _ }
+                            | FileType::Evt { archival_type: _ }
                             | FileType::Evtx { archival_type: _ }
                             | FileType::Journal { archival_type: _ }
                             | FileType::Odl { archival_type: _, odl_sub_type: _ }
+                            | FileType::Logcat { archival_type: _ }
+                            | FileType::Pcap { archival_type: _ }
+                            | FileType::Tracev3 { archival_type: _ }
                             | FileType::Unparsable => f
                                 .debug_struct("Summary::Default")
                                 .finish(),
@@ -776,9 +781,11 @@ impl fmt::Debug for Summary {
                         .finish()
                 }
                 Some(filetype_) => match filetype_ {
-                    FileType::Evtx { .. } => f
+                    FileType::Evt { .. }
+                    | FileType::Evtx { .. } => f
                         .debug_struct("")
                         .field("evtx events processed", &summaryevtxreader.evtxreader_events_processed)
+                        .field("evtx events filtered", &summaryevtxreader.evtxreader_events_filtered)
                         .field("evtx events accepted", &summaryevtxreader.evtxreader_events_accepted)
                         .finish(),
                     ft => {
@@ -813,6 +820,11 @@ impl fmt::Debug for Summary {
                         .finish()
                 }
                 Some(filetype_) => match filetype_ {
+                    FileType::Asl { .. } => f
+                        .debug_struct("")
+                        .field("asl events processed", &summarypyeventreader.pyeventreader_events_processed)
+                        .field("asl events accepted", &summarypyeventreader.pyeventreader_events_accepted)
+                        .finish(),
                     FileType::Etl { .. } => f
                         .debug_struct("")
                         .field("etl events processed", &summarypyeventreader.pyeventreader_events_processed)
@@ -823,6 +835,21 @@ impl fmt::Debug for Summary {
                         .field("odl events processed", &summarypyeventreader.pyeventreader_events_processed)
                         .field("odl events accepted", &summarypyeventreader.pyeventreader_events_accepted)
                         .finish(),
+                    FileType::Logcat { .. } => f
+                        .debug_struct("")
+                        .field("logcat events processed", &summarypyeventreader.pyeventreader_events_processed)
+                        .field("logcat events accepted", &summarypyeventreader.pyeventreader_events_accepted)
+                        .finish(),
+                    FileType::Pcap { .. } => f
+                        .debug_struct("")
+                        .field("pcap events processed", &summarypyeventreader.pyeventreader_events_processed)
+                        .field("pcap events accepted", &summarypyeventreader.pyeventreader_events_accepted)
+                        .finish(),
+                    FileType::Tracev3 { .. } => f
+                        .debug_struct("")
+                        .field("tracev3 events processed", &summarypyeventreader.pyeventreader_events_processed)
+                        .field("tracev3 events accepted", &summarypyeventreader.pyeventreader_events_accepted)
+                        .finish(),
                     ft => {
                         debug_panic!("Unpexected filetype {}; path {:?}", ft, self.path);
 
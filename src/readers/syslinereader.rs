@@ -92,6 +92,18 @@ use crate::data::datetime::{
     DATETIME_PARSE_DATAS,
     DATETIME_PARSE_DATAS_LEN,
 };
+use crate::data::columnar::{
+    ColumnarHeader,
+    ColumnarTime,
+};
+use crate::data::containerlog::{
+    containerlog_parse,
+    ContainerLogLine,
+    ContainerLogMode,
+    CONTAINERLOG_DOCKER_PREFIX,
+};
+use crate::data::jsonline::JsonLineTime;
+use crate::data::keyvalue::KeyValueTime;
 use crate::data::line::{
     Line,
     LineIndex,
@@ -107,6 +119,11 @@ use crate::data::sysline::{
     Sysline,
     SyslineP,
 };
+use crate::data::timestampline::{
+    timestampline_parse,
+    TimestampLineFormat,
+    TimestampLineMode,
+};
 #[allow(unused_imports)]
 use crate::debug::printers::{
     de_err,
@@ -160,6 +177,26 @@ pub type FindDateTimeData = (
     DateTimeParseInstrsIndex,
 );
 
+/// The "index" of `FindDateTimeData` for a datetime found by
+/// [`JsonLineTime`], not an index into `DATETIME_PARSE_DATAS`.
+pub const DATETIME_PARSE_DATAS_INDEX_JSONLINE: DateTimeParseInstrsIndex = DateTimeParseInstrsIndex::MAX;
+
+/// The "index" of `FindDateTimeData` for a datetime found by
+/// [`KeyValueTime`], not an index into `DATETIME_PARSE_DATAS`.
+pub const DATETIME_PARSE_DATAS_INDEX_KEYVALUE: DateTimeParseInstrsIndex = DateTimeParseInstrsIndex::MAX - 1;
+
+/// The "index" of `FindDateTimeData` for a datetime found by a
+/// [`ColumnarHeader`], not an index into `DATETIME_PARSE_DATAS`.
+pub const DATETIME_PARSE_DATAS_INDEX_COLUMNAR: DateTimeParseInstrsIndex = DateTimeParseInstrsIndex::MAX - 2;
+
+/// The "index" of `FindDateTimeData` for a datetime found in a CRI or Docker
+/// json-file container log line, not an index into `DATETIME_PARSE_DATAS`.
+pub const DATETIME_PARSE_DATAS_INDEX_CONTAINERLOG: DateTimeParseInstrsIndex = DateTimeParseInstrsIndex::MAX - 3;
+
+/// The "index" of `FindDateTimeData` for a datetime found in a timestamp
+/// line, not an index into `DATETIME_PARSE_DATAS`.
+pub const DATETIME_PARSE_DATAS_INDEX_TIMESTAMPLINE: DateTimeParseInstrsIndex = DateTimeParseInstrsIndex::MAX - 4;
+
 /// Return type for `SyslineReader::find_datetime_in_line`.
 pub type ResultFindDateTime = Result<FindDateTimeData>;
 
@@ -685,6 +722,43 @@ pub struct SyslineReader {
     /// Datetime of uptime value 0.
     /// Only for syslog files with uptime/dmesg format.
     pub(super) systemtime_at_uptime_zero: Option<SystemTime>,
+    /// Datetime of uptime value 0 for each boot, paired with the
+    /// `FileOffset` where the boot's syslines begin, in file order.
+    /// Only for syslog files with uptime/dmesg format.
+    pub(super) systemtime_at_uptime_zeros: Vec<(FileOffset, SystemTime)>,
+    /// Find the datetime of a JSON line by the datetime field, before
+    /// trying `DATETIME_PARSE_DATAS`.
+    jsonline_time: Option<JsonLineTime>,
+    /// `Count` of datetimes found by `jsonline_time`.
+    pub(super) jsonline_count: Count,
+    /// Find the datetime of a logfmt, CEF, or LEEF line by the datetime key,
+    /// before trying `DATETIME_PARSE_DATAS`.
+    keyvalue_time: Option<KeyValueTime>,
+    /// `Count` of datetimes found by `keyvalue_time`.
+    pub(super) keyvalue_count: Count,
+    /// Select the datetime columns of W3C extended log and CSV files.
+    columnar_time: Option<ColumnarTime>,
+    /// The `ColumnarHeader`s of the file and the `FileOffset` where each
+    /// begins, in file order. Set by the `SyslogProcessor` during block zero
+    /// analysis. When not empty, only the datetime columns are searched.
+    pub(super) columnar_headers: Vec<(FileOffset, ColumnarHeader)>,
+    /// `Count` of datetimes found by `columnar_headers`.
+    pub(super) columnar_count: Count,
+    /// Find the datetime of a CRI or Docker json-file container log line,
+    /// before trying `jsonline_time`.
+    containerlog_mode: ContainerLogMode,
+    /// `Count` of datetimes found in container log lines.
+    pub(super) containerlog_count: Count,
+    /// How timestamp lines, e.g. bash history `#1700000000`, begin a
+    /// `Sysline`.
+    timestampline_mode: TimestampLineMode,
+    /// The format of the timestamp line of the first line of the file. Set by
+    /// the `SyslogProcessor` during block zero analysis.
+    /// When set, or when `timestampline_mode` is `Always`, only timestamp
+    /// lines are searched.
+    pub(super) timestampline_format: Option<TimestampLineFormat>,
+    /// `Count` of datetimes found in timestamp lines.
+    pub(super) timestampline_count: Count,
     /// Reusable UTF-8 transcode buffer for non-UTF8 line data.
     transcode_buffer: Bytes,
     /// Enable or disable the internal LRU cache for `find_sysline()`.
@@ -994,6 +1068,19 @@ impl SyslineReader {
             tz_offset,
             tz_offset_string: tz_offset.to_string(),
             systemtime_at_uptime_zero: None,
+            systemtime_at_uptime_zeros: Vec::with_capacity(0),
+            jsonline_time: None,
+            jsonline_count: 0,
+            keyvalue_time: None,
+            keyvalue_count: 0,
+            columnar_time: None,
+            columnar_headers: Vec::with_capacity(0),
+            columnar_count: 0,
+            containerlog_mode: ContainerLogMode::Never,
+            containerlog_count: 0,
+            timestampline_mode: TimestampLineMode::Never,
+            timestampline_format: None,
+            timestampline_count: 0,
             transcode_buffer: Bytes::with_capacity(0),
             find_sysline_lru_cache_enabled: SyslineReader::CACHE_ENABLE_DEFAULT,
             fileoffset_last: 0,
@@ -1217,6 +1304,126 @@ impl SyslineReader {
             .count_lines_stored()
     }
 
+    /// Set the [`JsonLineTime`] to find the datetime of JSON lines.
+    /// `None` does not parse lines as JSON, the default.
+    pub fn set_jsonline_time(
+        &mut self,
+        jsonline_time: Option<JsonLineTime>,
+    ) {
+        self.jsonline_time = jsonline_time;
+    }
+
+    /// The [`JsonLineTime`] set by `set_jsonline_time`.
+    pub const fn jsonline_time(&self) -> Option<&JsonLineTime> {
+        self.jsonline_time.as_ref()
+    }
+
+    /// Were all datetimes found by `self.jsonline_time`?
+    pub fn dt_pattern_jsonline(&self) -> bool {
+        self.jsonline_count != 0
+            && self
+                .dt_patterns_counts
+                .values()
+                .all(|count| *count == 0)
+    }
+
+    /// Set the [`KeyValueTime`] to find the datetime of logfmt, CEF, and
+    /// LEEF lines.
+    /// `None` does not parse lines as key-value lines, the default.
+    pub fn set_keyvalue_time(
+        &mut self,
+        keyvalue_time: Option<KeyValueTime>,
+    ) {
+        self.keyvalue_time = keyvalue_time;
+    }
+
+    /// The [`KeyValueTime`] set by `set_keyvalue_time`.
+    pub const fn keyvalue_time(&self) -> Option<&KeyValueTime> {
+        self.keyvalue_time.as_ref()
+    }
+
+    /// Were all datetimes found by `self.keyvalue_time`?
+    pub fn dt_pattern_keyvalue(&self) -> bool {
+        self.keyvalue_count != 0
+            && self
+                .dt_patterns_counts
+                .values()
+                .all(|count| *count == 0)
+    }
+
+    /// Set the [`ColumnarTime`] to select the datetime columns of W3C
+    /// extended log and CSV files.
+    /// `None` does not parse lines as columns, the default.
+    pub fn set_columnar_time(
+        &mut self,
+        columnar_time: Option<ColumnarTime>,
+    ) {
+        self.columnar_time = columnar_time;
+    }
+
+    /// The [`ColumnarTime`] set by `set_columnar_time`.
+    pub const fn columnar_time(&self) -> Option<&ColumnarTime> {
+        self.columnar_time.as_ref()
+    }
+
+    /// Were all datetimes found by `self.columnar_headers`?
+    pub fn dt_pattern_columnar(&self) -> bool {
+        self.columnar_count != 0
+            && self
+                .dt_patterns_counts
+                .values()
+                .all(|count| *count == 0)
+    }
+
+    /// Set the [`ContainerLogMode`] of CRI and Docker json-file container
+    /// log lines.
+    /// [`ContainerLogMode::Never`] does not parse lines as container log
+    /// lines, the default.
+    pub fn set_containerlog_mode(
+        &mut self,
+        containerlog_mode: ContainerLogMode,
+    ) {
+        self.containerlog_mode = containerlog_mode;
+    }
+
+    /// The [`ContainerLogMode`] set by `set_containerlog_mode`.
+    pub const fn containerlog_mode(&self) -> ContainerLogMode {
+        self.containerlog_mode
+    }
+
+    /// Were all datetimes found in container log lines?
+    pub fn dt_pattern_containerlog(&self) -> bool {
+        self.containerlog_count != 0
+            && self
+                .dt_patterns_counts
+                .values()
+                .all(|count| *count == 0)
+    }
+
+    /// Set the [`TimestampLineMode`] of timestamp lines.
+    /// [`TimestampLineMode::Never`] does not parse lines as timestamp lines,
+    /// the default.
+    pub fn set_timestampline_mode(
+        &mut self,
+        timestampline_mode: TimestampLineMode,
+    ) {
+        self.timestampline_mode = timestampline_mode;
+    }
+
+    /// The [`TimestampLineMode`] set by `set_timestampline_mode`.
+    pub const fn timestampline_mode(&self) -> TimestampLineMode {
+        self.timestampline_mode
+    }
+
+    /// Were all datetimes found in timestamp lines?
+    pub fn dt_pattern_timestampline(&self) -> bool {
+        self.timestampline_count != 0
+            && self
+                .dt_patterns_counts
+                .values()
+                .all(|count| *count == 0)
+    }
+
     /// Does the `dt_pattern` have a year? e.g. specificer `%Y` or `%y`.
     pub fn dt_pattern_has_year(&self) -> bool {
         #[cfg(any(debug_assertions, test))]
@@ -1225,6 +1432,17 @@ impl SyslineReader {
                 e_wrn!("called dt_pattern_has_year() without having processed {} syslines", self.syslines.len());
             }
         }
+        // a JSON line, key-value line, columnar, container log, or timestamp
+        // line datetime always has a year
+        if self.dt_pattern_jsonline()
+            || self.dt_pattern_keyvalue()
+            || self.dt_pattern_columnar()
+            || self.dt_pattern_containerlog()
+            || self.dt_pattern_timestampline()
+        {
+            defñ!("jsonline or keyvalue or columnar or containerlog or timestampline return true");
+            return true;
+        }
         let dtpd: &DateTimeParseInstr = self.datetime_parse_data();
         defñ!("dtpd line {:?} return {}", dtpd._line_num, dtpd.dtfs.has_year());
 
@@ -1239,6 +1457,15 @@ impl SyslineReader {
                 e_wrn!("called dt_pattern_uptime() without having processed {} syslines", self.syslines.len());
             }
         }
+        if self.dt_pattern_jsonline()
+            || self.dt_pattern_keyvalue()
+            || self.dt_pattern_columnar()
+            || self.dt_pattern_containerlog()
+            || self.dt_pattern_timestampline()
+        {
+            defñ!("jsonline or keyvalue or columnar or containerlog or timestampline return false");
+            return false;
+        }
         let dtpd: &DateTimeParseInstr = self.datetime_parse_data();
         defñ!("dtpd line {:?} return {}", dtpd._line_num, dtpd.dtfs.has_uptime());
 
@@ -1961,6 +2188,183 @@ impl SyslineReader {
         )
     }
 
+    /// If `line` is a JSON object with a datetime field of `jsonline_time`
+    /// then return the offsets of the datetime field value and the datetime,
+    /// with index [`DATETIME_PARSE_DATAS_INDEX_JSONLINE`].
+    ///
+    /// Only for UTF-8 and ASCII encoded lines.
+    pub(crate) fn find_datetime_in_jsonline(
+        line: &Line,
+        encoding: FileTypeTextEncoding,
+        jsonline_time: &JsonLineTime,
+        tz_offset: &FixedOffset,
+    ) -> Option<FindDateTimeData> {
+        if encoding != FileTypeTextEncoding::Utf8Ascii {
+            return None;
+        }
+        // skip an easy case; not a JSON object
+        let first: u8 = line
+            .lineparts
+            .iter()
+            .flat_map(|linepart| linepart.as_slice().iter())
+            .copied()
+            .find(|b| !b.is_ascii_whitespace())?;
+        if first != b'{' {
+            return None;
+        }
+        let mut bytes: Bytes = Bytes::with_capacity(line.len());
+        for linepart in line.lineparts.iter() {
+            bytes.extend_from_slice(linepart.as_slice());
+        }
+        let text: &str = std::str::from_utf8(&bytes).ok()?;
+        let (beg, end, dt) = jsonline_time.find_datetime(text, tz_offset)?;
+
+        Some((beg, end, beg, end, dt, DATETIME_PARSE_DATAS_INDEX_JSONLINE))
+    }
+
+    /// If `line` is a logfmt, CEF, or LEEF line with a datetime key of
+    /// `keyvalue_time` then return the offsets of the datetime value and the
+    /// datetime, with index [`DATETIME_PARSE_DATAS_INDEX_KEYVALUE`].
+    ///
+    /// Only for UTF-8 and ASCII encoded lines.
+    pub(crate) fn find_datetime_in_keyvalue(
+        line: &Line,
+        encoding: FileTypeTextEncoding,
+        keyvalue_time: &KeyValueTime,
+        tz_offset: &FixedOffset,
+    ) -> Option<FindDateTimeData> {
+        if encoding != FileTypeTextEncoding::Utf8Ascii {
+            return None;
+        }
+        let mut buffer: Bytes = Bytes::with_capacity(0);
+        let bytes: &[u8] = SyslineReader::line_bytes(line, &mut buffer);
+        // skip an easy case; the first word is not a logfmt `key=value` and
+        // there is no CEF or LEEF header
+        let logfmt: bool = bytes
+            .iter()
+            .skip_while(|b| b.is_ascii_whitespace())
+            .take_while(|b| !b.is_ascii_whitespace())
+            .any(|b| *b == b'=');
+        if !logfmt
+            && ::memchr::memmem::find(bytes, b"CEF:").is_none()
+            && ::memchr::memmem::find(bytes, b"LEEF:").is_none()
+        {
+            return None;
+        }
+        let text: &str = std::str::from_utf8(bytes).ok()?;
+        let (beg, end, dt) = keyvalue_time.find_datetime(text, tz_offset)?;
+
+        Some((beg, end, beg, end, dt, DATETIME_PARSE_DATAS_INDEX_KEYVALUE))
+    }
+
+    /// If `line` has the datetime columns of `header` then return the offsets
+    /// of the datetime columns and the datetime, with index
+    /// [`DATETIME_PARSE_DATAS_INDEX_COLUMNAR`].
+    ///
+    /// Only for UTF-8 and ASCII encoded lines.
+    pub(crate) fn find_datetime_in_columnar(
+        line: &Line,
+        encoding: FileTypeTextEncoding,
+        header: &ColumnarHeader,
+        tz_offset: &FixedOffset,
+    ) -> Option<FindDateTimeData> {
+        if encoding != FileTypeTextEncoding::Utf8Ascii {
+            return None;
+        }
+        let mut buffer: Bytes = Bytes::with_capacity(0);
+        let bytes: &[u8] = SyslineReader::line_bytes(line, &mut buffer);
+        let text: &str = std::str::from_utf8(bytes).ok()?;
+        let (beg, end, dt) = header.find_datetime(text, tz_offset)?;
+
+        Some((beg, end, beg, end, dt, DATETIME_PARSE_DATAS_INDEX_COLUMNAR))
+    }
+
+    /// If `line` is a timestamp line of `format`, or of any format if `None`,
+    /// then return the offsets of the datetime and the datetime, with index
+    /// [`DATETIME_PARSE_DATAS_INDEX_TIMESTAMPLINE`], and the format.
+    ///
+    /// Only for UTF-8 and ASCII encoded lines.
+    pub(crate) fn find_datetime_in_timestampline(
+        line: &Line,
+        encoding: FileTypeTextEncoding,
+        format: Option<TimestampLineFormat>,
+        tz_offset: &FixedOffset,
+    ) -> Option<(FindDateTimeData, TimestampLineFormat)> {
+        if encoding != FileTypeTextEncoding::Utf8Ascii {
+            return None;
+        }
+        let mut buffer: Bytes = Bytes::with_capacity(0);
+        let bytes: &[u8] = SyslineReader::line_bytes(line, &mut buffer);
+        let text: &str = std::str::from_utf8(bytes).ok()?;
+        let (beg, end, dt, format_) = timestampline_parse(text, format, tz_offset)?;
+
+        Some(((beg, end, beg, end, dt, DATETIME_PARSE_DATAS_INDEX_TIMESTAMPLINE), format_))
+    }
+
+    /// Parse `line` as a CRI or Docker json-file container log line.
+    ///
+    /// Only for UTF-8 and ASCII encoded lines.
+    fn containerlog_parse_line(
+        line: &Line,
+        encoding: FileTypeTextEncoding,
+        tz_offset: &FixedOffset,
+    ) -> Option<ContainerLogLine> {
+        if encoding != FileTypeTextEncoding::Utf8Ascii {
+            return None;
+        }
+        let mut buffer: Bytes = Bytes::with_capacity(0);
+        let bytes: &[u8] = SyslineReader::line_bytes(line, &mut buffer);
+        // skip an easy case; not a CRI datetime or a Docker JSON object
+        if !bytes.first()?.is_ascii_digit() && !bytes.starts_with(CONTAINERLOG_DOCKER_PREFIX.as_bytes()) {
+            return None;
+        }
+        let text: &str = std::str::from_utf8(bytes).ok()?;
+
+        containerlog_parse(text, tz_offset)
+    }
+
+    /// If `line` is a CRI or Docker json-file container log line then return
+    /// the offsets of the datetime and the datetime, with index
+    /// [`DATETIME_PARSE_DATAS_INDEX_CONTAINERLOG`].
+    ///
+    /// Each container log line begins a `Sysline`, including a line
+    /// continuing a partial line; the partial lines of a stream are joined
+    /// by a [`ContainerLogJoiner`].
+    ///
+    /// Only for UTF-8 and ASCII encoded lines.
+    ///
+    /// [`ContainerLogJoiner`]: crate::data::containerlog::ContainerLogJoiner
+    pub(crate) fn find_datetime_in_containerlog(
+        line: &Line,
+        encoding: FileTypeTextEncoding,
+        tz_offset: &FixedOffset,
+    ) -> Option<FindDateTimeData> {
+        let containerlogline: ContainerLogLine =
+            SyslineReader::containerlog_parse_line(line, encoding, tz_offset)?;
+        let beg: usize = containerlogline.dt_beg;
+        let end: usize = containerlogline.dt_end;
+
+        Some((beg, end, beg, end, containerlogline.dt, DATETIME_PARSE_DATAS_INDEX_CONTAINERLOG))
+    }
+
+    /// The bytes of `line`; the one `LinePart` of a `Line` within one block,
+    /// otherwise the `LinePart`s copied into `buffer`.
+    pub(crate) fn line_bytes<'a>(
+        line: &'a Line,
+        buffer: &'a mut Bytes,
+    ) -> &'a [u8] {
+        if line.lineparts.len() == 1 {
+            return line.lineparts[0].as_slice();
+        }
+        buffer.clear();
+        buffer.reserve(line.len());
+        for linepart in line.lineparts.iter() {
+            buffer.extend_from_slice(linepart.as_slice());
+        }
+
+        buffer.as_slice()
+    }
+
     /// Update the two statistic `DateTimeL` of
     /// `self.dt_first` and `self.dt_last`.
     fn dt_first_last_update(
@@ -2120,6 +2524,19 @@ impl SyslineReader {
         self.dt_patterns_counts_debug_print();
 
         if self.dt_patterns_counts.is_empty() {
+            if self.jsonline_count != 0
+                || self.keyvalue_count != 0
+                || self.columnar_count != 0
+                || self.containerlog_count != 0
+                || self.timestampline_count != 0
+            {
+                // only JSON line, key-value line, columnar, container log, or
+                // timestamp line datetimes were found; no pattern is needed
+                self.dt_patterns_indexes.clear();
+                self.analyzed = true;
+                defx!("only JSON line or key-value line datetimes were found; return true");
+                return true;
+            }
             // no datetime patterns were found
             defx!("no datetime patterns were found; return false");
             return false;
@@ -2202,6 +2619,21 @@ impl SyslineReader {
         }
     }
 
+    /// Datetime of uptime value 0 for the boot holding the `Line` at
+    /// `fileoffset`.
+    fn systemtime_at_uptime_zero_at(
+        &self,
+        fileoffset: FileOffset,
+    ) -> Option<SystemTime> {
+        match self
+            .systemtime_at_uptime_zeros
+            .partition_point(|(fo, _)| *fo <= fileoffset)
+        {
+            0 => self.systemtime_at_uptime_zero,
+            at => Some(self.systemtime_at_uptime_zeros[at - 1].1),
+        }
+    }
+
     /// Attempt to parse a DateTime substring in the passed `Line`.
     ///
     /// Wraps call to `self.find_datetime_in_line` according to status of
@@ -2219,6 +2651,139 @@ impl SyslineReader {
     ) -> ResultParseDateTime {
         defn!("(…, {}, year_opt {:?}) line: {:?}", charsz, year_opt, line.to_string_noraw());
 
+        // only a timestamp line begins a sysline of a file of timestamp lines
+        let timestampline: bool = match self.timestampline_mode {
+            TimestampLineMode::Auto => self.timestampline_format.is_some(),
+            TimestampLineMode::Always => true,
+            TimestampLineMode::Never => false,
+        };
+        if timestampline {
+            match SyslineReader::find_datetime_in_timestampline(
+                line,
+                self.encoding_type(),
+                self.timestampline_format,
+                &self.tz_offset,
+            ) {
+                Some((data, _format)) => {
+                    self.timestampline_count += 1;
+                    self.dt_first_last_update(&data.4, line.fileoffset_begin());
+                    defx!("return timestampline {:?}", data);
+                    return ResultParseDateTime::Ok(data);
+                }
+                None => {
+                    defx!("return Err(ErrorKind::NotFound) timestampline");
+                    return ResultParseDateTime::Err(Error::new(
+                        ErrorKind::NotFound,
+                        format!("No timestamp line datetime found in Line! file {:?}", self.path()),
+                    ));
+                }
+            }
+        }
+
+        // the lines of a columnar file only have datetimes in the datetime
+        // columns of the prior header
+        if !self.columnar_headers.is_empty() {
+            let fileoffset: FileOffset = line.fileoffset_begin();
+            let at: usize = self
+                .columnar_headers
+                .partition_point(|(fileoffset_, _)| *fileoffset_ <= fileoffset);
+            let data: Option<FindDateTimeData> = match at {
+                0 => None,
+                _ => SyslineReader::find_datetime_in_columnar(
+                    line,
+                    self.encoding_type(),
+                    &self.columnar_headers[at - 1].1,
+                    &self.tz_offset,
+                ),
+            };
+            match data {
+                Some(data) => {
+                    self.columnar_count += 1;
+                    self.dt_first_last_update(&data.4, line.fileoffset_begin());
+                    defx!("return columnar {:?}", data);
+                    return ResultParseDateTime::Ok(data);
+                }
+                None => {
+                    defx!("return Err(ErrorKind::NotFound) columnar");
+                    return ResultParseDateTime::Err(Error::new(
+                        ErrorKind::NotFound,
+                        format!("No columnar datetime found in Line! file {:?}", self.path()),
+                    ));
+                }
+            }
+        }
+
+        if self.containerlog_mode != ContainerLogMode::Never {
+            match SyslineReader::find_datetime_in_containerlog(
+                line,
+                self.encoding_type(),
+                &self.tz_offset,
+            ) {
+                Some(data) => {
+                    self.containerlog_count += 1;
+                    self.dt_first_last_update(&data.4, line.fileoffset_begin());
+                    defx!("return containerlog {:?}", data);
+                    return ResultParseDateTime::Ok(data);
+                }
+                None if self.containerlog_mode == ContainerLogMode::Always => {
+                    defx!("return Err(ErrorKind::NotFound) containerlog");
+                    return ResultParseDateTime::Err(Error::new(
+                        ErrorKind::NotFound,
+                        format!("No container log line datetime found in Line! file {:?}", self.path()),
+                    ));
+                }
+                None => {}
+            }
+        }
+
+        if let Some(jsonline_time) = self.jsonline_time.as_ref() {
+            match SyslineReader::find_datetime_in_jsonline(
+                line,
+                self.encoding_type(),
+                jsonline_time,
+                &self.tz_offset,
+            ) {
+                Some(data) => {
+                    self.jsonline_count += 1;
+                    self.dt_first_last_update(&data.4, line.fileoffset_begin());
+                    defx!("return jsonline {:?}", data);
+                    return ResultParseDateTime::Ok(data);
+                }
+                None if jsonline_time.always => {
+                    defx!("return Err(ErrorKind::NotFound) jsonline");
+                    return ResultParseDateTime::Err(Error::new(
+                        ErrorKind::NotFound,
+                        format!("No JSON line datetime found in Line! file {:?}", self.path()),
+                    ));
+                }
+                None => {}
+            }
+        }
+
+        if let Some(keyvalue_time) = self.keyvalue_time.as_ref() {
+            match SyslineReader::find_datetime_in_keyvalue(
+                line,
+                self.encoding_type(),
+                keyvalue_time,
+                &self.tz_offset,
+            ) {
+                Some(data) => {
+                    self.keyvalue_count += 1;
+                    self.dt_first_last_update(&data.4, line.fileoffset_begin());
+                    defx!("return keyvalue {:?}", data);
+                    return ResultParseDateTime::Ok(data);
+                }
+                None if keyvalue_time.always => {
+                    defx!("return Err(ErrorKind::NotFound) keyvalue");
+                    return ResultParseDateTime::Err(Error::new(
+                        ErrorKind::NotFound,
+                        format!("No key-value line datetime found in Line! file {:?}", self.path()),
+                    ));
+                }
+                None => {}
+            }
+        }
+
         // have already determined DateTime formatting for this file, so
         // no need to try *all* built-in DateTime formats, just try the known good formats
         // `self.dt_patterns`
@@ -2236,6 +2801,9 @@ impl SyslineReader {
                 .then_with(|| a.cmp(b))           // deterministic tie-breaker
         });
         defo!("indexes {:?}", indexes);
+        let systemtime_at_uptime_zero: Option<SystemTime> = self.systemtime_at_uptime_zero_at(
+            line.fileoffset_begin()
+        );
         let result: ResultFindDateTime = SyslineReader::find_datetime_in_line(
             line,
             self.encoding_type(),
@@ -2243,7 +2811,7 @@ impl SyslineReader {
             &indexes,
             charsz,
             year_opt,
-            &self.systemtime_at_uptime_zero,
+            &systemtime_at_uptime_zero,
             &self.tz_offset,
             &self.tz_offset_string,
             &mut self.get_boxptrs_singleptr,
@@ -48,6 +48,7 @@ use ::si_trace_print::{
 };
 
 use crate::common::{
+    Bytes,
     CharSz,
     Count,
     FPath,
@@ -72,7 +73,24 @@ use crate::data::datetime::{
     Year,
     UPTIME_DEFAULT_OFFSET,
 };
+use crate::data::columnar::{
+    is_w3c_directive,
+    ColumnarHeader,
+    ColumnarTime,
+    W3C_FIELDS,
+};
+use crate::data::containerlog::ContainerLogMode;
+use crate::data::jsonline::JsonLineTime;
+use crate::data::keyvalue::KeyValueTime;
+use crate::data::line::{
+    Line,
+    LineP,
+};
 use crate::data::sysline::SyslineP;
+use crate::data::timestampline::{
+    TimestampLineFormat,
+    TimestampLineMode,
+};
 #[cfg(test)]
 use crate::readers::blockreader::SetDroppedBlocks;
 use crate::readers::blockreader::{
@@ -82,6 +100,12 @@ use crate::readers::blockreader::{
     BlockSz,
     ResultFindReadBlock,
 };
+use crate::readers::boottimes::{
+    uptime_zeros,
+    xorg_header_time,
+    BootTimes,
+    UptimeSegment,
+};
 #[doc(hidden)]
 pub use crate::readers::linereader::ResultFindLine;
 #[cfg(test)]
@@ -214,6 +238,20 @@ lazy_static! {
 // XXX: cannot make `const` because `secs` is a private field
 const BACKWARDS_TIME_JUMP_MEANS_NEW_YEAR: Duration = Duration::try_seconds(60 * 60 * 25).unwrap();
 
+/// 1 second.
+/// For processing syslog files with uptime datetimes, i.e. `dmesg`.
+/// If the uptime jumps backwards more than this value then the system
+/// rebooted and a new boot began.
+///
+/// The kernel may print messages from different CPUs slightly out of order
+/// so a tiny jump backwards is not a reboot.
+const BACKWARDS_UPTIME_JUMP_MEANS_NEW_BOOT: StdDuration = StdDuration::from_secs(1);
+
+/// For processing syslog files with uptime datetimes, i.e. `Xorg.0.log`.
+/// The X.Org server log header line with the server start time is
+/// searched for within this many syslines of the start of each boot.
+const XORG_HEADER_SYSLINES_MAX: Count = 50;
+
 /// The `SyslogProcessor` uses [`SyslineReader`] to find [`Sysline`s] in a file.
 ///
 /// A `SyslogProcessor` has knowledge of:
@@ -253,6 +291,9 @@ pub struct SyslogProcessor {
     /// Optional `Year` value used to start `process_missing_year()`.
     /// Only needed for syslog files with datetime format without a year.
     missing_year: Option<Year>,
+    /// System boot times gathered from other files.
+    /// Only needed for syslog files with uptime datetime format.
+    boot_times: BootTimes,
     /// The last [`Error`], if any, as a `String`. Set by [`set_error`].
     ///
     /// Annoyingly, cannot [Clone or Copy `Error`].
@@ -280,6 +321,7 @@ impl Debug for SyslogProcessor {
             .field("filetype", &self.filetype())
             .field("Reprocessed missing year?", &self.did_process_missing_year())
             .field("Missing Year", &self.missing_year)
+            .field("Boot Times", &self.boot_times.len())
             .field("Error?", &self.error)
             .finish()
     }
@@ -323,6 +365,11 @@ impl SyslogProcessor {
     /// Same as [`SyslogProcessor::BLOCKZERO_ANALYSIS_BYTES_NULL_MAX`] but for 0xFF bytes.
     pub const BLOCKZERO_ANALYSIS_BYTES_FF_MAX: usize = Self::BLOCKZERO_ANALYSIS_BYTES_NULL_MAX;
 
+    /// Maximum number of `Line`s of block zero sampled to detect a file of
+    /// JSON lines, key-value lines, container log lines, or bash or zsh
+    /// history.
+    pub const BLOCKZERO_ANALYSIS_FORMAT_LINES_MAX: usize = 32;
+
     /// Allow "streaming stage" to drop data?
     /// Compile-time "option" to aid manual debugging.
     #[doc(hidden)]
@@ -387,6 +434,7 @@ impl SyslogProcessor {
             blockzero_analysis_done: false,
             drop_block_last: 0,
             missing_year: None,
+            boot_times: BootTimes::with_capacity(0),
             error: None,
         })
     }
@@ -546,6 +594,114 @@ impl SyslogProcessor {
         self.missing_year.is_some()
     }
 
+    /// Set the system boot times used by `process_uptime()` to anchor each
+    /// boot of a syslog file with uptime datetimes.
+    ///
+    /// See [`boottimes`].
+    ///
+    /// [`boottimes`]: crate::readers::boottimes
+    pub fn set_boot_times(
+        &mut self,
+        boot_times: BootTimes,
+    ) {
+        self.boot_times = boot_times;
+    }
+
+    /// Set the [`JsonLineTime`] to find the datetime of JSON lines.
+    ///
+    /// See [`SyslineReader::set_jsonline_time`].
+    ///
+    /// [`JsonLineTime`]: crate::data::jsonline::JsonLineTime
+    /// [`SyslineReader::set_jsonline_time`]: crate::readers::syslinereader::SyslineReader#method.set_jsonline_time
+    pub fn set_jsonline_time(
+        &mut self,
+        jsonline_time: Option<JsonLineTime>,
+    ) {
+        self.syslinereader.set_jsonline_time(jsonline_time);
+    }
+
+    /// See [`SyslineReader::jsonline_time`].
+    ///
+    /// [`SyslineReader::jsonline_time`]: crate::readers::syslinereader::SyslineReader#method.jsonline_time
+    pub const fn jsonline_time(&self) -> Option<&JsonLineTime> {
+        self.syslinereader.jsonline_time()
+    }
+
+    /// Set the [`KeyValueTime`] to find the datetime of logfmt, CEF, and
+    /// LEEF lines.
+    ///
+    /// See [`SyslineReader::set_keyvalue_time`].
+    ///
+    /// [`KeyValueTime`]: crate::data::keyvalue::KeyValueTime
+    /// [`SyslineReader::set_keyvalue_time`]: crate::readers::syslinereader::SyslineReader#method.set_keyvalue_time
+    pub fn set_keyvalue_time(
+        &mut self,
+        keyvalue_time: Option<KeyValueTime>,
+    ) {
+        self.syslinereader.set_keyvalue_time(keyvalue_time);
+    }
+
+    /// See [`SyslineReader::keyvalue_time`].
+    ///
+    /// [`SyslineReader::keyvalue_time`]: crate::readers::syslinereader::SyslineReader#method.keyvalue_time
+    pub const fn keyvalue_time(&self) -> Option<&KeyValueTime> {
+        self.syslinereader.keyvalue_time()
+    }
+
+    /// Set the [`ColumnarTime`] to select the datetime columns of W3C
+    /// extended log and CSV files.
+    ///
+    /// See [`SyslineReader::set_columnar_time`].
+    ///
+    /// [`ColumnarTime`]: crate::data::columnar::ColumnarTime
+    /// [`SyslineReader::set_columnar_time`]: crate::readers::syslinereader::SyslineReader#method.set_columnar_time
+    pub fn set_columnar_time(
+        &mut self,
+        columnar_time: Option<ColumnarTime>,
+    ) {
+        self.syslinereader.set_columnar_time(columnar_time);
+    }
+
+    /// Set the [`ContainerLogMode`] of CRI and Docker json-file container
+    /// log lines.
+    ///
+    /// See [`SyslineReader::set_containerlog_mode`].
+    ///
+    /// [`SyslineReader::set_containerlog_mode`]: crate::readers::syslinereader::SyslineReader#method.set_containerlog_mode
+    pub fn set_containerlog_mode(
+        &mut self,
+        containerlog_mode: ContainerLogMode,
+    ) {
+        self.syslinereader.set_containerlog_mode(containerlog_mode);
+    }
+
+    /// See [`SyslineReader::containerlog_mode`].
+    ///
+    /// [`SyslineReader::containerlog_mode`]: crate::readers::syslinereader::SyslineReader#method.containerlog_mode
+    pub const fn containerlog_mode(&self) -> ContainerLogMode {
+        self.syslinereader.containerlog_mode()
+    }
+
+    /// Set the [`TimestampLineMode`] of timestamp lines, e.g. bash history
+    /// `#1700000000`.
+    ///
+    /// See [`SyslineReader::set_timestampline_mode`].
+    ///
+    /// [`SyslineReader::set_timestampline_mode`]: crate::readers::syslinereader::SyslineReader#method.set_timestampline_mode
+    pub fn set_timestampline_mode(
+        &mut self,
+        timestampline_mode: TimestampLineMode,
+    ) {
+        self.syslinereader.set_timestampline_mode(timestampline_mode);
+    }
+
+    /// See [`SyslineReader::dt_pattern_uptime`].
+    ///
+    /// [`SyslineReader::dt_pattern_uptime`]: crate::readers::syslinereader::SyslineReader#method.dt_pattern_uptime
+    pub fn dt_pattern_uptime(&self) -> bool {
+        self.syslinereader.dt_pattern_uptime()
+    }
+
     /// Did this `SyslogProcessor` run `process_uptime()` ?
     fn did_process_uptime(&self) -> bool {
         self.systemtime_at_uptime_zero().is_some()
@@ -739,68 +895,110 @@ impl SyslogProcessor {
         self.syslinereader.systemtime_at_uptime_zero
     }
 
+    /// For syslog files with uptime datetimes, i.e. `dmesg`, find the
+    /// `SystemTime` at uptime zero of each boot within the file.
+    ///
+    /// The file is read linearly and in its entirety. Where the uptime
+    /// jumps backwards more than [`BACKWARDS_UPTIME_JUMP_MEANS_NEW_BOOT`]
+    /// then a new boot began.
+    /// Each boot is anchored to wall-clock time by [`uptime_zeros`] using an
+    /// X.Org server log header time, the file `mtime`, and any boot times
+    /// passed to [`set_boot_times`].
+    ///
+    /// [`uptime_zeros`]: crate::readers::boottimes::uptime_zeros
+    /// [`set_boot_times`]: self::SyslogProcessor#method.set_boot_times
     pub fn process_uptime(
         &mut self,
     ) -> FileProcessingResultBlockZero {
         defn!();
         debug_assert!(!self.did_process_uptime(), "did_process_uptime() must only be called once");
 
-        let fo_last = self.fileoffset_last();
-        defo!("find_sysline(fo_last={})", fo_last);
-        let syslinep = match self.find_sysline(fo_last) {
-            ResultFindSysline::Found((_fo, syslinep_)) => {
-                defo!("found sysline at fo_last={} {:?}", fo_last, syslinep_);
+        // read all syslines, noting the greatest uptime of each boot
+        let mut segments: Vec<UptimeSegment> = Vec::new();
+        // syslines of the current boot
+        let mut segment_syslines: Count = 0;
+        let mut fo: FileOffset = 0;
+        loop {
+            let syslinep: SyslineP = match self.find_sysline(fo) {
+                ResultFindSysline::Found((fo_next, syslinep)) => {
+                    fo = fo_next;
 
-                syslinep_
+                    syslinep
+                }
+                ResultFindSysline::Done => {
+                    defo!("Done, break;");
+                    break;
+                }
+                ResultFindSysline::Err(err) => {
+                    defx!("error finding sysline: {:?}", err);
+                    return FileProcessingResultBlockZero::FileErrIo(err);
+                }
+            };
+            // the uptime value of the sysline; the stand-in
+            // `systemtime_at_uptime_zero` is `UPTIME_DEFAULT_OFFSET`
+            let uptime: StdDuration = datetime_minus_systemtime(syslinep.dt(), &UPTIME_DEFAULT_OFFSET)
+                .to_std()
+                .unwrap_or(StdDuration::ZERO);
+            match segments.last_mut() {
+                Some(segment)
+                    if uptime + BACKWARDS_UPTIME_JUMP_MEANS_NEW_BOOT >= segment.uptime_max =>
+                {
+                    segment.uptime_max = std::cmp::max(segment.uptime_max, uptime);
+                    segment_syslines += 1;
+                }
+                Some(_) => {
+                    defo!("new boot at fileoffset {} uptime {:?}", syslinep.fileoffset_begin(), uptime);
+                    segments.push(UptimeSegment {
+                        fileoffset: syslinep.fileoffset_begin(),
+                        uptime_max: uptime,
+                        zero: None,
+                    });
+                    segment_syslines = 1;
+                }
+                None => {
+                    segments.push(UptimeSegment {
+                        fileoffset: 0,
+                        uptime_max: uptime,
+                        zero: None,
+                    });
+                    segment_syslines = 1;
+                }
             }
-            ResultFindSysline::Done => {
-                defx!("No sysline found");
-                return FileProcessingResultBlockZero::FileErrNoSyslinesFound;
+            // an X.Org server log header line near the start of the boot
+            // has the wall-clock time at this uptime
+            if let Some(segment) = segments.last_mut()
+                && segment.zero.is_none()
+                && segment_syslines <= XORG_HEADER_SYSLINES_MAX
+                && let Some(header_time) = xorg_header_time(&syslinep.to_bytes(), &self.tz_offset)
+            {
+                // keep the fractional uptime; the header time of whole
+                // seconds is the best known wall-clock time of the line
+                segment.zero = header_time.checked_sub(uptime);
+                defo!("X.Org header time {:?} at uptime {:?}; zero {:?}", header_time, uptime, segment.zero);
             }
-            ResultFindSysline::Err(err) => {
-                defx!("error finding sysline: {:?}", err);
-                return FileProcessingResultBlockZero::FileErrIo(err);
+            if self.is_sysline_last(&syslinep) {
+                break;
             }
-        };
-        let dt = syslinep.dt();
-        let diff_ = datetime_minus_systemtime(&dt, &UPTIME_DEFAULT_OFFSET);
-        defo!("diff_ from UPTIME_DEFAULT_OFFSET {:?}", diff_);
-        let diff_secs = diff_.num_seconds();
-        defo!("diff_secs {:?}", diff_secs);
-        let mut diff_nanos = diff_.subsec_nanos();
-        defo!("diff_nanos {:?}", diff_nanos);
-        if diff_nanos < 0 {
-            diff_nanos = 0;
         }
-        let diffs: StdDuration = StdDuration::new(diff_secs as u64, diff_nanos as u32);
-        defo!("diffs {:?}", diffs);
-        defo!("mtime()");
+        defo!("segments {:?}", segments);
+        if segments.is_empty() {
+            defx!("No sysline found");
+            return FileProcessingResultBlockZero::FileErrNoSyslinesFound;
+        }
+
         let mtime = self.mtime();
         defo!("mtime {:?} (as DateTime {:?})", mtime, systemtime_to_datetime(&self.tz_offset, &mtime));
-        // std::time::Duration is unsigned whereas chrono::Duration is signed.
-        let st_at_zero = if diff_secs > 0 {
-            defo!("checked_sub({:?})", diffs);
-            match mtime.checked_sub(diffs) {
-                Some(st) => st,
-                None => {
-                    defx!("failed to calculate systemtime at uptime zero");
-                    return FileProcessingResultBlockZero::FileErrIo(std::io::Error::other(
-                        "failed to calculate systemtime at uptime zero",
-                    ));
-                }
-            }
-        } else {
-            defo!("checked_add({:?})", diffs);
-            match mtime.checked_add(diffs) {
-                Some(st) => st,
-                None => {
-                    defx!("failed to calculate systemtime at uptime zero");
-                    return FileProcessingResultBlockZero::FileErrIo(std::io::Error::other(
-                        "failed to calculate systemtime at uptime zero",
-                    ));
-                }
+        let zeros: Vec<(FileOffset, SystemTime)> = match uptime_zeros(&segments, mtime, &self.boot_times) {
+            Some(zeros) => zeros,
+            None => {
+                defx!("failed to calculate systemtime at uptime zero");
+                return FileProcessingResultBlockZero::FileErrIo(std::io::Error::other(
+                    "failed to calculate systemtime at uptime zero",
+                ));
             }
         };
+        // the last boot is the fallback
+        let st_at_zero: SystemTime = zeros[zeros.len() - 1].1;
         self.syslinereader.systemtime_at_uptime_zero = Some(st_at_zero);
         defo!("systemtime_at_uptime_zero is  {:?}", self.syslinereader.systemtime_at_uptime_zero);
         #[cfg(debug_assertions)]
@@ -811,6 +1009,9 @@ impl SyslogProcessor {
             );
             defo!("systemtime_at_uptime_zero as DateTime {:?}", d);
         }
+        if zeros.len() > 1 {
+            self.syslinereader.systemtime_at_uptime_zeros = zeros;
+        }
 
         // The systemtime at uptime zero has been discovered.
         // So clear the lines that previously used the stand-in value for
@@ -1464,12 +1665,342 @@ impl SyslogProcessor {
             return result;
         };
 
+        let result: FileProcessingResultBlockZero = self.blockzero_analysis_columnar();
+        if !result.is_ok() {
+            defx!("syslinereader.blockzero_analysis_columnar() was !is_ok(), return {:?}", result);
+            return result;
+        };
+
+        let result: FileProcessingResultBlockZero = self.blockzero_analysis_timestampline();
+        if !result.is_ok() {
+            defx!("syslinereader.blockzero_analysis_timestampline() was !is_ok(), return {:?}", result);
+            return result;
+        };
+
+        let result: FileProcessingResultBlockZero = self.blockzero_analysis_containerlog();
+        if !result.is_ok() {
+            defx!("syslinereader.blockzero_analysis_containerlog() was !is_ok(), return {:?}", result);
+            return result;
+        };
+
+        let result: FileProcessingResultBlockZero = self.blockzero_analysis_jsonline();
+        if !result.is_ok() {
+            defx!("syslinereader.blockzero_analysis_jsonline() was !is_ok(), return {:?}", result);
+            return result;
+        };
+
+        let result: FileProcessingResultBlockZero = self.blockzero_analysis_keyvalue();
+        if !result.is_ok() {
+            defx!("syslinereader.blockzero_analysis_keyvalue() was !is_ok(), return {:?}", result);
+            return result;
+        };
+
         let result: FileProcessingResultBlockZero = self.blockzero_analysis_syslines();
         defx!("return {:?}", result);
 
         result
     }
 
+    /// If the file is a W3C extended log file, i.e. the first line is a
+    /// directive, then read all lines to find each `#Fields` directive.
+    /// Else if CSV datetime columns were selected then the first line is the
+    /// CSV header row.
+    /// The [`ColumnarHeader`]s are passed to the `SyslineReader`.
+    ///
+    /// A W3C extended log file is read an extra time.
+    ///
+    /// [`ColumnarHeader`]: crate::data::columnar::ColumnarHeader
+    pub(super) fn blockzero_analysis_columnar(&mut self) -> FileProcessingResultBlockZero {
+        defn!();
+        self.assert_stage(ProcessingStage::Stage1BlockzeroAnalysis);
+
+        let columnar_time: ColumnarTime = match self.syslinereader.columnar_time() {
+            Some(columnar_time) => columnar_time.clone(),
+            None => {
+                defx!("no columnar_time; return FileOk");
+                return FileProcessingResultBlockZero::FileOk;
+            }
+        };
+        if self.syslinereader.encoding_type() != FileTypeTextEncoding::Utf8Ascii {
+            defx!("encoding {:?}; return FileOk", self.syslinereader.encoding_type());
+            return FileProcessingResultBlockZero::FileOk;
+        }
+
+        let mut headers: Vec<(FileOffset, ColumnarHeader)> = Vec::new();
+        let mut buffer: Bytes = Bytes::with_capacity(0);
+        let mut fo: FileOffset = 0;
+        loop {
+            let (fo_next, linep) = match self
+                .syslinereader
+                .linereader
+                .find_line(fo)
+            {
+                ResultFindLine::Found((fo_next, linep)) => (fo_next, linep),
+                ResultFindLine::Done => {
+                    defo!("Done, break;");
+                    break;
+                }
+                ResultFindLine::Err(err) => {
+                    self.set_error(&err);
+                    defx!("return FileErrIo({:?})", err);
+                    return FileProcessingResultBlockZero::FileErrIoPath(err);
+                }
+            };
+            let bytes: &[u8] = SyslineReader::line_bytes(&linep, &mut buffer);
+            let text: &str = std::str::from_utf8(bytes).unwrap_or_default();
+            if fo == 0 && !is_w3c_directive(text) {
+                // a CSV header row is only the first line
+                if let Some(header) = ColumnarHeader::from_csv(text, &columnar_time) {
+                    headers.push((0, header));
+                }
+                break;
+            }
+            if text.starts_with(W3C_FIELDS) {
+                match ColumnarHeader::from_w3c_fields(text, &columnar_time) {
+                    Some(header) => headers.push((linep.fileoffset_begin(), header)),
+                    None => {
+                        de_wrn!("no datetime columns in W3C {:?} at fileoffset {}", text, linep.fileoffset_begin());
+                    }
+                }
+            }
+            fo = fo_next;
+        }
+        defo!("headers {:?}", headers);
+        self.syslinereader.columnar_headers = headers;
+
+        defx!("return FileOk");
+
+        FileProcessingResultBlockZero::FileOk
+    }
+
+    /// If the sampled lines of block zero are bash or zsh history, then pass
+    /// the timestamp line format to the `SyslineReader`; only timestamp lines
+    /// of the format begin a `Sysline`.
+    ///
+    /// The first line must be a bash or zsh history timestamp line, e.g.
+    /// `#1700000000`, and at least a quarter of the sampled lines must be
+    /// timestamp lines of the same format. A bash history timestamp line is
+    /// followed by the command, so about half of the lines of bash history
+    /// are timestamp lines.
+    pub(super) fn blockzero_analysis_timestampline(&mut self) -> FileProcessingResultBlockZero {
+        defn!();
+        self.assert_stage(ProcessingStage::Stage1BlockzeroAnalysis);
+
+        if self.syslinereader.timestampline_mode() != TimestampLineMode::Auto {
+            defx!("timestampline_mode {:?}; return FileOk", self.syslinereader.timestampline_mode());
+            return FileProcessingResultBlockZero::FileOk;
+        }
+        let lines: Vec<LineP> = match self.blockzero_analysis_sample_lines() {
+            Ok(lines) => lines,
+            Err(err) => {
+                self.set_error(&err);
+                defx!("return FileErrIo({:?})", err);
+                return FileProcessingResultBlockZero::FileErrIoPath(err);
+            }
+        };
+        let encoding: FileTypeTextEncoding = self.syslinereader.encoding_type();
+        let tz_offset: FixedOffset = self.tz_offset;
+        let format: TimestampLineFormat = match lines
+            .first()
+            .and_then(|linep| SyslineReader::find_datetime_in_timestampline(linep, encoding, None, &tz_offset))
+            .map(|(_, format)| format)
+            .filter(|format| format.is_auto())
+        {
+            Some(format) => format,
+            None => {
+                defx!("first line is not a history timestamp line; return FileOk");
+                return FileProcessingResultBlockZero::FileOk;
+            }
+        };
+        let count: usize = lines
+            .iter()
+            .filter(|linep| {
+                SyslineReader::find_datetime_in_timestampline(linep, encoding, Some(format), &tz_offset).is_some()
+            })
+            .count();
+        defo!("format {:?}, {} of {} lines", format, count, lines.len());
+        if count * 4 >= lines.len() {
+            self.syslinereader.timestampline_format = Some(format);
+        }
+        defo!("timestampline_format {:?}", self.syslinereader.timestampline_format);
+
+        defx!("return FileOk");
+
+        FileProcessingResultBlockZero::FileOk
+    }
+
+    /// Return up to [`BLOCKZERO_ANALYSIS_FORMAT_LINES_MAX`] whole `Line`s of
+    /// block zero.
+    ///
+    /// [`BLOCKZERO_ANALYSIS_FORMAT_LINES_MAX`]: SyslogProcessor::BLOCKZERO_ANALYSIS_FORMAT_LINES_MAX
+    fn blockzero_analysis_sample_lines(&mut self) -> Result<Vec<LineP>> {
+        let mut lines: Vec<LineP> = Vec::with_capacity(Self::BLOCKZERO_ANALYSIS_FORMAT_LINES_MAX);
+        let mut fo: FileOffset = 0;
+        while lines.len() < Self::BLOCKZERO_ANALYSIS_FORMAT_LINES_MAX {
+            fo = match self
+                .syslinereader
+                .linereader
+                .find_line_in_block(fo)
+            {
+                (ResultFindLine::Found((fo_next, linep)), _) => {
+                    lines.push(linep);
+
+                    fo_next
+                }
+                (ResultFindLine::Done, _) => break,
+                (ResultFindLine::Err(err), _) => return Err(err),
+            };
+            if 0 != self
+                .syslinereader
+                .linereader
+                .block_offset_at_file_offset(fo)
+            {
+                break;
+            }
+        }
+
+        Ok(lines)
+    }
+
+    /// Are the sampled `lines` of a format? The first line and at least half
+    /// of the `lines` must be of the format.
+    fn blockzero_analysis_sample_is_format<F>(
+        lines: &[LineP],
+        is_format: F,
+    ) -> bool
+    where
+        F: Fn(&Line) -> bool,
+    {
+        match lines.first() {
+            Some(linep) if is_format(linep) => {}
+            _ => return false,
+        }
+        let count: usize = lines
+            .iter()
+            .filter(|linep| is_format(linep))
+            .count();
+
+        count * 2 >= lines.len()
+    }
+
+    /// If the `ContainerLogMode` is `Auto` and the sampled lines of block
+    /// zero are not CRI or Docker json-file container log lines then do not
+    /// parse lines as container log lines.
+    /// A text log file with an occasional line that looks like a CRI line
+    /// is then searched only with the built-in datetime patterns.
+    pub(super) fn blockzero_analysis_containerlog(&mut self) -> FileProcessingResultBlockZero {
+        defn!();
+        self.assert_stage(ProcessingStage::Stage1BlockzeroAnalysis);
+
+        if self.syslinereader.containerlog_mode() != ContainerLogMode::Auto {
+            defx!("containerlog_mode {:?}; return FileOk", self.syslinereader.containerlog_mode());
+            return FileProcessingResultBlockZero::FileOk;
+        }
+        let lines: Vec<LineP> = match self.blockzero_analysis_sample_lines() {
+            Ok(lines) => lines,
+            Err(err) => {
+                self.set_error(&err);
+                defx!("return FileErrIo({:?})", err);
+                return FileProcessingResultBlockZero::FileErrIoPath(err);
+            }
+        };
+        let encoding: FileTypeTextEncoding = self.syslinereader.encoding_type();
+        let tz_offset: FixedOffset = self.tz_offset;
+        let detected: bool = Self::blockzero_analysis_sample_is_format(&lines, |line| {
+            SyslineReader::find_datetime_in_containerlog(line, encoding, &tz_offset).is_some()
+        });
+        defo!("detected {} in {} lines", detected, lines.len());
+        if !detected {
+            self.syslinereader.set_containerlog_mode(ContainerLogMode::Never);
+        }
+
+        defx!("return FileOk");
+
+        FileProcessingResultBlockZero::FileOk
+    }
+
+    /// If the `JsonLinesMode` is `Auto` and the sampled lines of block zero
+    /// are not JSON lines then do not parse lines as JSON lines.
+    /// A text log file with an occasional line beginning with `{` is then
+    /// searched only with the built-in datetime patterns.
+    pub(super) fn blockzero_analysis_jsonline(&mut self) -> FileProcessingResultBlockZero {
+        defn!();
+        self.assert_stage(ProcessingStage::Stage1BlockzeroAnalysis);
+
+        let jsonline_time: JsonLineTime = match self.syslinereader.jsonline_time() {
+            Some(jsonline_time) if !jsonline_time.always => jsonline_time.clone(),
+            _ => {
+                defx!("jsonline_time not auto; return FileOk");
+                return FileProcessingResultBlockZero::FileOk;
+            }
+        };
+        let lines: Vec<LineP> = match self.blockzero_analysis_sample_lines() {
+            Ok(lines) => lines,
+            Err(err) => {
+                self.set_error(&err);
+                defx!("return FileErrIo({:?})", err);
+                return FileProcessingResultBlockZero::FileErrIoPath(err);
+            }
+        };
+        let encoding: FileTypeTextEncoding = self.syslinereader.encoding_type();
+        let tz_offset: FixedOffset = self.tz_offset;
+        let detected: bool = Self::blockzero_analysis_sample_is_format(&lines, |line| {
+            SyslineReader::find_datetime_in_jsonline(line, encoding, &jsonline_time, &tz_offset).is_some()
+        });
+        defo!("detected {} in {} lines", detected, lines.len());
+        if !detected {
+            self.syslinereader.set_jsonline_time(None);
+        }
+
+        defx!("return FileOk");
+
+        FileProcessingResultBlockZero::FileOk
+    }
+
+    /// If the `KeyValueMode` is `Auto` and the sampled lines of block zero
+    /// are not logfmt, CEF, or LEEF lines then do not parse lines as
+    /// key-value lines.
+    /// A text log file with an occasional `key=value` line is then searched
+    /// only with the built-in datetime patterns.
+    pub(super) fn blockzero_analysis_keyvalue(&mut self) -> FileProcessingResultBlockZero {
+        defn!();
+        self.assert_stage(ProcessingStage::Stage1BlockzeroAnalysis);
+
+        let keyvalue_time: KeyValueTime = match self.syslinereader.keyvalue_time() {
+            Some(keyvalue_time) if !keyvalue_time.always => keyvalue_time.clone(),
+            _ => {
+                defx!("keyvalue_time not auto; return FileOk");
+                return FileProcessingResultBlockZero::FileOk;
+            }
+        };
+        let lines: Vec<LineP> = match self.blockzero_analysis_sample_lines() {
+            Ok(lines) => lines,
+            Err(err) => {
+                self.set_error(&err);
+                defx!("return FileErrIo({:?})", err);
+                return FileProcessingResultBlockZero::FileErrIoPath(err);
+            }
+        };
+        let encoding: FileTypeTextEncoding = self.syslinereader.encoding_type();
+        let tz_offset: FixedOffset = self.tz_offset;
+        let detected: bool = Self::blockzero_analysis_sample_is_format(&lines, |line| {
+            SyslineReader::find_datetime_in_keyvalue(line, encoding, &keyvalue_time, &tz_offset).is_some()
+        });
+        defo!("detected {} in {} lines", detected, lines.len());
+        if !detected {
+            self.syslinereader.set_keyvalue_time(None);
+        }
+
+        defx!("return FileOk");
+
+        FileProcessingResultBlockZero::FileOk
+    }
+
+    #[cfg(test)]
+    pub(crate) fn timestampline_format(&self) -> Option<TimestampLineFormat> {
+        self.syslinereader.timestampline_format
+    }
+
     #[cfg(test)]
     pub(crate) fn dropped_blocks(&self) -> SetDroppedBlocks {
         self.syslinereader
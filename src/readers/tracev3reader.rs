@@ -0,0 +1,1943 @@
+// src/readers/tracev3reader.rs
+
+//! Implements a [`Tracev3Reader`], the driver of deriving [`PyDataEvent`s]
+//! from an [Apple Unified Logging] file (`.tracev3`).
+//!
+//! The file format is read natively. A `.tracev3` file is read along with the
+//! supporting files of the log archive:
+//! - the `timesync` files convert the boot-relative times of log entries to
+//!   wall-clock times
+//! - the `uuidtext` files and the `dsc` shared-string cache files hold the
+//!   format strings and the image paths of the log entries
+//!
+//! The supporting files are found by searching the directories above the
+//! `.tracev3` file for a `timesync` directory. That directory is either a
+//! `.logarchive` directory, or the `/private/var/db/diagnostics` directory
+//! which has a sibling `/private/var/db/uuidtext` directory.
+//!
+//! Printed events are similar to those printed by `log show`.
+//!
+//! [`PyDataEvent`s]: crate::data::pydataevent::PyDataEvent
+//! [Apple Unified Logging]: https://github.com/libyal/dtformats/blob/main/documentation/Apple%20Unified%20Logging%20and%20Activity%20Tracing%20formats.asciidoc
+
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+use std::fmt;
+use std::fmt::Write as _;
+use std::io::{
+    Error,
+    ErrorKind,
+    Read,
+    Result,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use ::chrono::{
+    DateTime,
+    TimeZone,
+};
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    def1n,
+    def1o,
+    def1x,
+    def1ñ,
+    def2ñ,
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+use ::tempfile::TempPath;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileMetadata,
+    FileSz,
+    FileType,
+    PathId,
+    ResultFind4,
+    summary_stat,
+    summary_stats_enabled,
+};
+use crate::data::datetime::{
+    DateTimeL,
+    DateTimeLOpt,
+    FixedOffset,
+    Result_Filter_DateTime2,
+    SystemTime,
+    dt_pass_filters,
+};
+use crate::data::pydataevent::{
+    DtBegEndPairOpt,
+    EventBytes,
+    PyDataEvent,
+};
+use crate::de_err;
+use crate::readers::filedecompressor::decompress_to_ntf;
+use crate::readers::filehandlemanager::{
+    FILE_HANDLE_MANAGER,
+    FileHandleManaged,
+    FileHandleRole,
+    OpenOptionsManaged,
+};
+use crate::readers::helpers::path_to_fpath;
+use crate::readers::pyeventreader::{
+    PyDataEventReader,
+    ResultNextPyDataEvent,
+    SummaryPyEventReader,
+};
+use crate::readers::summary::Summary;
+
+/// A UUID as stored in the files, big-endian.
+pub type Uuid = [u8; 16];
+
+/// Tag of the header chunk at the start of a `.tracev3` file.
+pub const CHUNK_TAG_HEADER: u32 = 0x1000;
+/// Tag of a chunk of firehose log entries.
+pub const CHUNK_TAG_FIREHOSE: u32 = 0x6001;
+/// Tag of a chunk with the message data of one large firehose log entry.
+pub const CHUNK_TAG_OVERSIZE: u32 = 0x6002;
+/// Tag of a chunk with a state dump.
+pub const CHUNK_TAG_STATEDUMP: u32 = 0x6003;
+/// Tag of a chunk with a simple dump.
+pub const CHUNK_TAG_SIMPLEDUMP: u32 = 0x6004;
+/// Tag of a catalog chunk which describes the processes of the following
+/// chunksets.
+pub const CHUNK_TAG_CATALOG: u32 = 0x600b;
+/// Tag of a chunkset chunk, a compressed sequence of chunks.
+pub const CHUNK_TAG_CHUNKSET: u32 = 0x600d;
+/// Bytes of the tag, sub-tag, and data size preceding the data of every chunk.
+pub const CHUNK_PREAMBLE_SZ: usize = 16;
+
+/// Offset of the mach timebase numerator within the header chunk data.
+const HEADER_NUMERATOR_OFFSET: usize = 0;
+/// Offset of the mach timebase denominator within the header chunk data.
+const HEADER_DENOMINATOR_OFFSET: usize = 4;
+/// Offset of the boot UUID within the header chunk data.
+const HEADER_BOOT_UUID_OFFSET: usize = 128;
+
+/// Signature of an LZ4 compressed block of a chunkset.
+pub const CHUNKSET_LZ4: &[u8; 4] = b"bv41";
+/// Signature of an uncompressed block of a chunkset.
+pub const CHUNKSET_RAW: &[u8; 4] = b"bv4-";
+/// Signature of the end of a chunkset.
+pub const CHUNKSET_END: &[u8; 4] = b"bv4$";
+/// LZ4 blocks may refer back this many bytes into the prior blocks.
+const LZ4_WINDOW_SZ: usize = 0x10000;
+
+/// Signature at the start of a `uuidtext` file.
+pub const UUIDTEXT_SIGNATURE: u32 = 0x66778899;
+/// Signature at the start of a `dsc` shared-string cache file.
+pub const DSC_SIGNATURE: &[u8; 4] = b"hcsd";
+/// Signature of a boot record of a `.timesync` file.
+pub const TIMESYNC_BOOT_SIGNATURE: u16 = 0xbbb0;
+/// Signature of a time synchronization record of a `.timesync` file.
+pub const TIMESYNC_RECORD_SIGNATURE: &[u8; 4] = b"Ts \0";
+const TIMESYNC_BOOT_SZ: usize = 48;
+const TIMESYNC_RECORD_SZ: usize = 32;
+
+/// Bytes of the firehose chunk data preceding the log entries.
+const FIREHOSE_PREAMBLE_SZ: usize = 32;
+/// Bytes of a firehose log entry preceding the entry data.
+const FIREHOSE_ENTRY_SZ: usize = 24;
+/// The firehose `public_data_size` includes this many bytes of the preamble.
+const FIREHOSE_PUBLIC_DATA_SIZE_BASE: usize = 16;
+/// A firehose `private_data_virtual_offset` of this value means there is no
+/// private data.
+const FIREHOSE_PRIVATE_DATA_NONE: usize = 0x1000;
+
+/// Firehose log entry activity types.
+const ACTIVITY_TYPE_ACTIVITY: u8 = 0x2;
+const ACTIVITY_TYPE_NONACTIVITY: u8 = 0x4;
+const ACTIVITY_TYPE_SIGNPOST: u8 = 0x6;
+const ACTIVITY_TYPE_LOSS: u8 = 0x7;
+
+/// An activity log type without the first activity identifier.
+const LOG_TYPE_USERACTION: u8 = 0x3;
+
+/// Firehose log entry flags.
+const FLAG_HAS_CURRENT_AID: u16 = 0x0001;
+const FLAG_FORMATTER_MASK: u16 = 0x000e;
+const FLAG_HAS_UNIQUE_PID: u16 = 0x0010;
+const FLAG_HAS_LARGE_OFFSET: u16 = 0x0020;
+const FLAG_HAS_PRIVATE_DATA: u16 = 0x0100;
+/// For activities this is the "has other current activity identifier" flag.
+const FLAG_HAS_SUBSYSTEM: u16 = 0x0200;
+const FLAG_HAS_RULES: u16 = 0x0400;
+const FLAG_HAS_OVERSIZE: u16 = 0x0800;
+const FLAG_HAS_SIGNPOST_NAME: u16 = 0x8000;
+
+/// Values of the flags masked by `FLAG_FORMATTER_MASK`, where the format
+/// string of the log entry is found.
+const FORMATTER_MAIN_EXE: u16 = 0x2;
+const FORMATTER_SHARED_CACHE: u16 = 0x4;
+const FORMATTER_ABSOLUTE: u16 = 0x8;
+const FORMATTER_UUID_RELATIVE: u16 = 0xa;
+const FORMATTER_LARGE_SHARED_CACHE: u16 = 0xc;
+
+/// A format string location with this bit set and no format string is a
+/// dynamic format string `"%s"`.
+const FORMAT_STRING_DYNAMIC: u32 = 0x8000_0000;
+
+/// Signature of the backtrace data that may precede the message strings.
+const BACKTRACE_SIGNATURE: &[u8; 3] = b"\x01\x00\x18";
+
+/// Printed for message values that are private.
+const PRIVATE: &str = "<private>";
+/// Printed when a message value is missing.
+const MISSING_DATA: &str = "<decode: missing data>";
+
+/// Read little-endian values from a slice, advancing the offset.
+/// A read past the end of the slice returns `None`.
+struct LeReader<'a> {
+    data: &'a [u8],
+    at: usize,
+}
+
+impl<'a> LeReader<'a> {
+    const fn new(data: &'a [u8]) -> LeReader<'a> {
+        LeReader { data, at: 0 }
+    }
+
+    fn bytes(
+        &mut self,
+        len: usize,
+    ) -> Option<&'a [u8]> {
+        let bytes: &[u8] = self.data.get(self.at..self.at.checked_add(len)?)?;
+        self.at += len;
+
+        Some(bytes)
+    }
+
+    fn skip(
+        &mut self,
+        len: usize,
+    ) -> Option<()> {
+        self.bytes(len).map(|_| ())
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.bytes(2)?.try_into().ok()?))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.bytes(4)?.try_into().ok()?))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.bytes(8)?.try_into().ok()?))
+    }
+
+    fn uuid(&mut self) -> Option<Uuid> {
+        self.bytes(16)?.try_into().ok()
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        self.data.get(self.at..).unwrap_or_default()
+    }
+}
+
+/// Bytes of padding after `len` bytes for 8 byte alignment.
+const fn padding8(len: usize) -> usize {
+    (8 - (len % 8)) % 8
+}
+
+/// The NUL-terminated string at offset `at` of `data`.
+fn cstring(
+    data: &[u8],
+    at: usize,
+) -> String {
+    let data: &[u8] = data.get(at..).unwrap_or_default();
+    let end: usize = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+
+    String::from_utf8_lossy(&data[..end]).into_owned()
+}
+
+/// The UUID as 32 uppercase hexadecimal digits, the form used in the
+/// `uuidtext` and `dsc` file paths.
+pub fn uuid_hex(uuid: &Uuid) -> String {
+    let mut s = String::with_capacity(32);
+    for b in uuid.iter() {
+        _ = write!(s, "{:02X}", b);
+    }
+
+    s
+}
+
+/// The UUID as uppercase hexadecimal digits in the usual 8-4-4-4-12 form.
+fn uuid_dashed(uuid: &Uuid) -> String {
+    let s: String = uuid_hex(uuid);
+
+    format!("{}-{}-{}-{}-{}", &s[0..8], &s[8..12], &s[12..16], &s[16..20], &s[20..32])
+}
+
+/// The file name of an image path.
+fn image_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+/// Decompress the data of a chunkset chunk.
+pub fn chunkset_decompress(data: &[u8]) -> Result<Vec<u8>> {
+    let mut out: Vec<u8> = Vec::new();
+    let mut reader = LeReader::new(data);
+    let err_truncated = || Error::new(ErrorKind::InvalidData, "chunkset is truncated");
+    while let Some(signature) = reader.bytes(4) {
+        if signature == CHUNKSET_LZ4 {
+            let uncompressed_sz: usize = reader.u32().ok_or_else(err_truncated)? as usize;
+            let compressed_sz: usize = reader.u32().ok_or_else(err_truncated)? as usize;
+            let block: &[u8] = reader.bytes(compressed_sz).ok_or_else(err_truncated)?;
+            let dict: &[u8] = &out[out.len().saturating_sub(LZ4_WINDOW_SZ)..];
+            let decompressed: Vec<u8> = ::lz4_flex::block::decompress_with_dict(block, uncompressed_sz, dict)
+                .map_err(|err| Error::new(ErrorKind::InvalidData, format!("chunkset LZ4 error {}", err)))?;
+            out.extend_from_slice(&decompressed);
+        } else if signature == CHUNKSET_RAW {
+            let uncompressed_sz: usize = reader.u32().ok_or_else(err_truncated)? as usize;
+            out.extend_from_slice(reader.bytes(uncompressed_sz).ok_or_else(err_truncated)?);
+        } else if signature == CHUNKSET_END {
+            break;
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown chunkset block signature {:02X?}", signature),
+            ));
+        }
+    }
+
+    Ok(out)
+}
+
+/// Iterate the chunks of `data`, returning the tag and the chunk data of
+/// each chunk. Iteration stops at a truncated chunk.
+fn chunks(data: &[u8]) -> impl Iterator<Item = (u32, &[u8])> {
+    let mut at: usize = 0;
+    std::iter::from_fn(move || {
+        let mut reader = LeReader::new(data.get(at..)?);
+        let tag: u32 = reader.u32()?;
+        reader.skip(4)?;
+        let size: usize = usize::try_from(reader.u64()?).ok()?;
+        let chunk: &[u8] = reader.bytes(size)?;
+        at += CHUNK_PREAMBLE_SZ + size + padding8(size);
+
+        Some((tag, chunk))
+    })
+}
+
+/// An image of a `dsc` shared-string cache file.
+#[derive(Debug)]
+struct DscImage {
+    text_offset: u64,
+    text_size: u64,
+    path: String,
+}
+
+/// A range of format strings of a `dsc` shared-string cache file.
+#[derive(Debug)]
+struct DscRange {
+    range_offset: u64,
+    range_size: u64,
+    data_offset: usize,
+    image_index: usize,
+}
+
+/// A `dsc` shared-string cache file with the format strings of the
+/// libraries of the dyld shared cache.
+#[derive(Debug)]
+struct Dsc {
+    ranges: Vec<DscRange>,
+    images: Vec<DscImage>,
+    data: Vec<u8>,
+}
+
+impl Dsc {
+    fn parse(data: Vec<u8>) -> Option<Dsc> {
+        let mut reader = LeReader::new(&data);
+        if reader.bytes(4)? != DSC_SIGNATURE {
+            return None;
+        }
+        let major_version: u16 = reader.u16()?;
+        reader.skip(2)?;
+        let range_count: u32 = reader.u32()?;
+        let image_count: u32 = reader.u32()?;
+        let mut ranges: Vec<DscRange> = Vec::new();
+        for _ in 0..range_count {
+            let range: DscRange = match major_version {
+                1 => {
+                    let image_index: usize = reader.u32()? as usize;
+                    let range_offset: u64 = u64::from(reader.u32()?);
+                    let data_offset: usize = reader.u32()? as usize;
+                    let range_size: u64 = u64::from(reader.u32()?);
+                    DscRange { range_offset, range_size, data_offset, image_index }
+                }
+                _ => {
+                    let range_offset: u64 = reader.u64()?;
+                    let data_offset: usize = reader.u32()? as usize;
+                    let range_size: u64 = u64::from(reader.u32()?);
+                    let image_index: usize = usize::try_from(reader.u64()?).unwrap_or(usize::MAX);
+                    DscRange { range_offset, range_size, data_offset, image_index }
+                }
+            };
+            ranges.push(range);
+        }
+        let mut images: Vec<DscImage> = Vec::new();
+        for _ in 0..image_count {
+            let text_offset: u64 = match major_version {
+                1 => u64::from(reader.u32()?),
+                _ => reader.u64()?,
+            };
+            let text_size: u64 = u64::from(reader.u32()?);
+            reader.skip(16)?;
+            let path_offset: usize = reader.u32()? as usize;
+            images.push(DscImage { text_offset, text_size, path: cstring(&data, path_offset) });
+        }
+
+        Some(Dsc { ranges, images, data })
+    }
+
+    /// The format string at `offset` and the path of its image.
+    fn format_string(
+        &self,
+        offset: u64,
+    ) -> Option<(String, Option<&str>)> {
+        let range: &DscRange = self
+            .ranges
+            .iter()
+            .find(|r| r.range_offset <= offset && offset - r.range_offset < r.range_size)?;
+        let at: usize = range.data_offset + usize::try_from(offset - range.range_offset).ok()?;
+        let path: Option<&str> = self
+            .images
+            .get(range.image_index)
+            .map(|image| image.path.as_str());
+
+        Some((cstring(&self.data, at), path))
+    }
+
+    /// The path of the image with the code at `pc`.
+    fn image_path(
+        &self,
+        pc: u64,
+    ) -> Option<&str> {
+        self.images
+            .iter()
+            .find(|i| i.text_offset <= pc && pc - i.text_offset < i.text_size)
+            .map(|image| image.path.as_str())
+    }
+}
+
+/// A range of format strings of a `uuidtext` file.
+#[derive(Debug)]
+struct UuidTextRange {
+    range_offset: u64,
+    range_size: u64,
+    data_offset: usize,
+}
+
+/// A `uuidtext` file with the format strings and the image path of one
+/// executable or library.
+#[derive(Debug)]
+struct UuidText {
+    ranges: Vec<UuidTextRange>,
+    data: Vec<u8>,
+    path: String,
+}
+
+impl UuidText {
+    fn parse(data: Vec<u8>) -> Option<UuidText> {
+        let mut reader = LeReader::new(&data);
+        if reader.u32()? != UUIDTEXT_SIGNATURE {
+            return None;
+        }
+        // skip the major and minor versions
+        reader.skip(8)?;
+        let range_count: usize = reader.u32()? as usize;
+        let mut data_offset: usize = 16usize.checked_add(range_count.checked_mul(8)?)?;
+        let mut ranges: Vec<UuidTextRange> = Vec::new();
+        for _ in 0..range_count {
+            let range_offset: u64 = u64::from(reader.u32()?);
+            let range_size: u32 = reader.u32()?;
+            ranges.push(UuidTextRange { range_offset, range_size: u64::from(range_size), data_offset });
+            data_offset = data_offset.checked_add(range_size as usize)?;
+        }
+        let path: String = cstring(&data, data_offset);
+
+        Some(UuidText { ranges, data, path })
+    }
+
+    /// The format string at `offset`.
+    fn format_string(
+        &self,
+        offset: u64,
+    ) -> Option<String> {
+        let range: &UuidTextRange = self
+            .ranges
+            .iter()
+            .find(|r| r.range_offset <= offset && offset - r.range_offset < r.range_size)?;
+        let at: usize = range.data_offset + usize::try_from(offset - range.range_offset).ok()?;
+
+        Some(cstring(&self.data, at))
+    }
+}
+
+/// The time synchronization records of one boot.
+#[derive(Debug, Default)]
+struct TimesyncBoot {
+    timebase_numerator: u32,
+    timebase_denominator: u32,
+    /// Nanoseconds since the Unix epoch at boot.
+    boot_time: i64,
+    /// Pairs of mach continuous time and the nanoseconds since the Unix epoch
+    /// at that time, sorted by mach continuous time.
+    records: Vec<(u64, i64)>,
+}
+
+impl TimesyncBoot {
+    /// Convert mach continuous time `continuous_time` to nanoseconds since
+    /// the Unix epoch, relative to the nearest prior time synchronization
+    /// record.
+    fn to_unix_nanos(
+        &self,
+        continuous_time: u64,
+    ) -> Option<i64> {
+        let i: usize = self
+            .records
+            .partition_point(|(kernel_time, _)| *kernel_time <= continuous_time);
+        let (kernel_time, walltime) = match i {
+            0 => (0, self.boot_time),
+            i => self.records[i - 1],
+        };
+        let denominator: i128 = match self.timebase_denominator {
+            0 => 1,
+            d => i128::from(d),
+        };
+        let elapsed: i128 = (i128::from(continuous_time) - i128::from(kernel_time))
+            * i128::from(self.timebase_numerator.max(1))
+            / denominator;
+
+        i64::try_from(i128::from(walltime) + elapsed).ok()
+    }
+}
+
+/// Read the time synchronization records of a `.timesync` file into `boots`.
+fn timesync_parse(
+    data: &[u8],
+    boots: &mut HashMap<Uuid, TimesyncBoot>,
+) {
+    let mut at: usize = 0;
+    let mut boot_uuid: Option<Uuid> = None;
+    while at < data.len() {
+        let mut reader = LeReader::new(&data[at..]);
+        if data[at..].starts_with(&TIMESYNC_BOOT_SIGNATURE.to_le_bytes()) {
+            let mut boot = || -> Option<(Uuid, TimesyncBoot)> {
+                reader.skip(8)?;
+                let uuid: Uuid = reader.uuid()?;
+                let timebase_numerator: u32 = reader.u32()?;
+                let timebase_denominator: u32 = reader.u32()?;
+                let boot_time: i64 = reader.u64()? as i64;
+                Some((uuid, TimesyncBoot { timebase_numerator, timebase_denominator, boot_time, records: Vec::new() }))
+            };
+            let (uuid, timesyncboot) = match boot() {
+                Some(val) => val,
+                None => break,
+            };
+            boots.entry(uuid).or_insert(timesyncboot);
+            boot_uuid = Some(uuid);
+            at += TIMESYNC_BOOT_SZ;
+        } else if data[at..].starts_with(TIMESYNC_RECORD_SIGNATURE) {
+            let mut record = || -> Option<(u64, i64)> {
+                reader.skip(8)?;
+                let kernel_time: u64 = reader.u64()?;
+                let walltime: i64 = reader.u64()? as i64;
+                reader.skip(8)?;
+                Some((kernel_time, walltime))
+            };
+            let record: (u64, i64) = match record() {
+                Some(val) => val,
+                None => break,
+            };
+            if let Some(boot) = boot_uuid.as_ref().and_then(|uuid| boots.get_mut(uuid)) {
+                boot.records.push(record);
+            }
+            at += TIMESYNC_RECORD_SZ;
+        } else {
+            de_err!("unknown timesync record at {}", at);
+            break;
+        }
+    }
+}
+
+/// Read the `.timesync` files in directory `dir`.
+fn timesync_read_dir(dir: &Path) -> HashMap<Uuid, TimesyncBoot> {
+    let mut boots: HashMap<Uuid, TimesyncBoot> = HashMap::new();
+    let mut paths: Vec<PathBuf> = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "timesync"))
+            .collect(),
+        Err(_err) => {
+            de_err!("read_dir({:?}) failed {}", dir, _err);
+            return boots;
+        }
+    };
+    paths.sort();
+    for path in paths.iter() {
+        match std::fs::read(path) {
+            Ok(data) => timesync_parse(&data, &mut boots),
+            Err(_err) => de_err!("read({:?}) failed {}", path, _err),
+        }
+    }
+    for boot in boots.values_mut() {
+        boot.records.sort_by_key(|(kernel_time, _)| *kernel_time);
+    }
+
+    boots
+}
+
+/// Find the directory with the `timesync` directory and the directory with
+/// the `uuidtext` and `dsc` files for the `.tracev3` file `path`.
+pub fn find_archive_dirs(path: &Path) -> Option<(PathBuf, PathBuf)> {
+    let root: &Path = path
+        .ancestors()
+        .skip(1)
+        .take(4)
+        .find(|dir| dir.join("timesync").is_dir())?;
+    let uuidtext_dir: PathBuf = match root.parent() {
+        Some(parent) if !root.join("dsc").is_dir() && parent.join("uuidtext").is_dir() => parent.join("uuidtext"),
+        _ => root.to_path_buf(),
+    };
+
+    Some((root.join("timesync"), uuidtext_dir))
+}
+
+/// A process described by a catalog chunk.
+#[derive(Debug, Default)]
+struct CatalogProcess {
+    main_uuid: Option<Uuid>,
+    dsc_uuid: Option<Uuid>,
+    pid: u32,
+    /// Load address, size, and UUID of the images of the process.
+    images: Vec<(u64, u64, Uuid)>,
+    /// Subsystem and category names by subsystem identifier.
+    subsystems: HashMap<u16, (String, String)>,
+}
+
+/// The processes of the chunksets following a catalog chunk, by the
+/// first and second process identifiers of the firehose chunks.
+type Catalog = HashMap<(u64, u32), CatalogProcess>;
+
+/// Parse the data of a catalog chunk.
+fn catalog_parse(data: &[u8]) -> Option<Catalog> {
+    let mut reader = LeReader::new(data);
+    let subsystem_strings_offset: usize = usize::from(reader.u16()?);
+    let process_entries_offset: usize = usize::from(reader.u16()?);
+    let process_count: u16 = reader.u16()?;
+    // skip the sub-chunks offset and count, unknown data, and the earliest
+    // firehose timestamp
+    reader.skip(18)?;
+    let base: usize = reader.at;
+    let uuids: Vec<Uuid> = reader
+        .bytes(subsystem_strings_offset)?
+        .chunks_exact(16)
+        .filter_map(|uuid| uuid.try_into().ok())
+        .collect();
+    let strings: &[u8] = reader.bytes(process_entries_offset.checked_sub(subsystem_strings_offset)?)?;
+    let mut reader = LeReader::new(data.get(base + process_entries_offset..)?);
+    let mut catalog = Catalog::new();
+    for _ in 0..process_count {
+        let mut process = CatalogProcess::default();
+        reader.skip(4)?;
+        process.main_uuid = uuids.get(usize::from(reader.u16()?)).copied();
+        process.dsc_uuid = uuids.get(usize::from(reader.u16()?)).copied();
+        let first_proc_id: u64 = reader.u64()?;
+        let second_proc_id: u32 = reader.u32()?;
+        process.pid = reader.u32()?;
+        // skip the effective user ID and unknown data
+        reader.skip(8)?;
+        let image_count: u32 = reader.u32()?;
+        reader.skip(4)?;
+        for _ in 0..image_count {
+            let size: u64 = u64::from(reader.u32()?);
+            reader.skip(4)?;
+            let uuid_index: usize = usize::from(reader.u16()?);
+            let mut load_address: [u8; 8] = [0; 8];
+            load_address[..6].copy_from_slice(reader.bytes(6)?);
+            if let Some(uuid) = uuids.get(uuid_index) {
+                process.images.push((u64::from_le_bytes(load_address), size, *uuid));
+            }
+        }
+        let subsystem_count: usize = reader.u32()? as usize;
+        reader.skip(4)?;
+        for _ in 0..subsystem_count {
+            let identifier: u16 = reader.u16()?;
+            let subsystem: String = cstring(strings, usize::from(reader.u16()?));
+            let category: String = cstring(strings, usize::from(reader.u16()?));
+            process.subsystems.insert(identifier, (subsystem, category));
+        }
+        _ = reader.skip(padding8(subsystem_count * 6));
+        catalog.insert((first_proc_id, second_proc_id), process);
+    }
+
+    Some(catalog)
+}
+
+/// A value of a log message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Tracev3Value {
+    /// An integer or floating point number, the raw bits and the byte size.
+    Number(u64, u8),
+    /// The precision or width of the next value, i.e. `%.*s`.
+    Precision(u64),
+    String(String),
+    Bytes(Vec<u8>),
+    Private,
+    Null,
+}
+
+/// Is the message item type a string, object, or binary data stored after
+/// the item headers?
+const fn is_item_type_data(item_type: u8) -> bool {
+    matches!(
+        item_type,
+        0x20 | 0x21 | 0x22 | 0x25 | 0x30 | 0x31 | 0x32 | 0x35 | 0x40 | 0x41 | 0x42 | 0x45 | 0x81 | 0x85 | 0xf1 | 0xf2
+    )
+}
+
+/// Parse the message values of a firehose log entry, oversize chunk, or
+/// similar. Private values are read from `private_data`.
+fn items_parse(
+    data: &[u8],
+    private_data: &[u8],
+) -> Vec<Tracev3Value> {
+    let mut reader = LeReader::new(data);
+    // skip unknown
+    if reader.skip(1).is_none() {
+        return Vec::new();
+    }
+    let item_count: u8 = match reader.u8() {
+        Some(val) => val,
+        None => return Vec::new(),
+    };
+    // the item type and the string offset and size, filled in later
+    let mut strings: Vec<(usize, u8, usize, usize)> = Vec::new();
+    let mut values: Vec<Tracev3Value> = Vec::with_capacity(usize::from(item_count));
+    for _ in 0..item_count {
+        let (item_type, item_size) = match (reader.u8(), reader.u8()) {
+            (Some(t), Some(s)) => (t, s),
+            _ => break,
+        };
+        if is_item_type_data(item_type) {
+            let (offset, size) = match (reader.u16(), reader.u16()) {
+                (Some(o), Some(s)) => (usize::from(o), usize::from(s)),
+                _ => break,
+            };
+            strings.push((values.len(), item_type, offset, size));
+            values.push(Tracev3Value::Null);
+            continue;
+        }
+        // private number values have no data
+        if item_type == 0x01 {
+            values.push(Tracev3Value::Private);
+            continue;
+        }
+        let bytes: &[u8] = match reader.bytes(usize::from(item_size)) {
+            Some(val) => val,
+            None => break,
+        };
+        let mut number: [u8; 8] = [0; 8];
+        let len: usize = bytes.len().min(8);
+        number[..len].copy_from_slice(&bytes[..len]);
+        let number: u64 = u64::from_le_bytes(number);
+        match item_type {
+            0x10 | 0x12 => values.push(Tracev3Value::Precision(number)),
+            _ => values.push(Tracev3Value::Number(number, item_size)),
+        }
+    }
+    // skip the backtrace
+    if reader.rest().starts_with(BACKTRACE_SIGNATURE) {
+        let mut skip_backtrace = || -> Option<()> {
+            reader.skip(BACKTRACE_SIGNATURE.len())?;
+            let uuid_count: usize = usize::from(reader.u8()?);
+            let offset_count: usize = usize::from(reader.u16()?);
+            reader.skip(uuid_count * 16 + offset_count * 5)?;
+            reader.skip((4 - (offset_count % 4)) % 4)
+        };
+        _ = skip_backtrace();
+    }
+    let string_data: &[u8] = reader.rest();
+    for (index, item_type, offset, size) in strings.into_iter() {
+        let private: bool = item_type & 0x1 != 0;
+        let sensitive: bool = matches!(item_type, 0x45 | 0x85);
+        let source: &[u8] = if private { private_data } else { string_data };
+        let bytes: Option<&[u8]> = source.get(offset..offset + size);
+        values[index] = match bytes {
+            _ if sensitive => Tracev3Value::Private,
+            None if private => Tracev3Value::Private,
+            None => Tracev3Value::String(String::from(MISSING_DATA)),
+            Some([]) if private => Tracev3Value::Private,
+            Some([]) => Tracev3Value::Null,
+            Some(bytes) => match item_type {
+                0x30 | 0x31 | 0x32 | 0xf1 | 0xf2 => Tracev3Value::Bytes(bytes.to_vec()),
+                _ => {
+                    let end: usize = bytes.iter().rposition(|b| *b != 0).map_or(0, |p| p + 1);
+                    Tracev3Value::String(String::from_utf8_lossy(&bytes[..end]).into_owned())
+                }
+            },
+        };
+    }
+
+    values
+}
+
+/// Sign extend the `size` byte value `number`.
+const fn sign_extend(
+    number: u64,
+    size: u8,
+) -> i64 {
+    match size {
+        1 => number as u8 as i8 as i64,
+        2 => number as u16 as i16 as i64,
+        4 => number as u32 as i32 as i64,
+        _ => number as i64,
+    }
+}
+
+/// Pad `s` to `width` per the printf `flags`.
+fn pad(
+    s: String,
+    width: usize,
+    flags: &str,
+    numeric: bool,
+) -> String {
+    let len: usize = s.chars().count();
+    if len >= width {
+        return s;
+    }
+    let fill: usize = width - len;
+    if flags.contains('-') {
+        format!("{}{}", s, " ".repeat(fill))
+    } else if numeric && flags.contains('0') {
+        let (sign, digits) = match s.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", s.as_str()),
+        };
+        format!("{}{}{}", sign, "0".repeat(fill), digits)
+    } else {
+        format!("{}{}", " ".repeat(fill), s)
+    }
+}
+
+/// Format one printf conversion `conversion` of `value`.
+fn format_value(
+    value: &Tracev3Value,
+    annotation: &str,
+    flags: &str,
+    precision: Option<usize>,
+    conversion: char,
+) -> (String, bool) {
+    let number: Option<(u64, u8)> = match value {
+        Tracev3Value::Number(n, size) => Some((*n, *size)),
+        Tracev3Value::Precision(n) => Some((*n, 8)),
+        _ => None,
+    };
+    let s: String = match (value, number) {
+        (Tracev3Value::Private, _) => return (String::from(PRIVATE), false),
+        (Tracev3Value::Null, _) => return (String::from("(null)"), false),
+        (Tracev3Value::String(s), _) => match precision {
+            Some(p) if matches!(conversion, 's' | 'S' | '@') => s.chars().take(p).collect(),
+            _ => s.clone(),
+        },
+        (Tracev3Value::Bytes(bytes), _) if annotation.contains("uuid_t") && bytes.len() == 16 => {
+            uuid_dashed(&<Uuid>::try_from(bytes.as_slice()).unwrap_or_default())
+        }
+        (Tracev3Value::Bytes(bytes), _) => bytes.iter().fold(String::with_capacity(bytes.len() * 2), |mut s, b| {
+            _ = write!(s, "{:02x}", b);
+            s
+        }),
+        (_, Some((n, size))) => {
+            let signed: i64 = sign_extend(n, size);
+            let unsigned: u64 = match size {
+                1 => n & 0xff,
+                2 => n & 0xffff,
+                4 => n & 0xffff_ffff,
+                _ => n,
+            };
+            let alt: bool = flags.contains('#');
+            match conversion {
+                _ if annotation.contains("BOOL") => String::from(if n != 0 { "YES" } else { "NO" }),
+                _ if annotation.contains("bool") => String::from(if n != 0 { "true" } else { "false" }),
+                _ if annotation.contains("time_t") => match DateTime::from_timestamp(signed, 0) {
+                    Some(dt) => dt.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    None => signed.to_string(),
+                },
+                'd' | 'i' => signed.to_string(),
+                'u' => unsigned.to_string(),
+                'x' if alt => format!("{:#x}", unsigned),
+                'x' => format!("{:x}", unsigned),
+                'X' if alt => format!("{:#X}", unsigned),
+                'X' => format!("{:X}", unsigned),
+                'o' if alt => format!("0{:o}", unsigned),
+                'o' => format!("{:o}", unsigned),
+                'p' => format!("0x{:x}", unsigned),
+                'c' | 'C' => char::from_u32(unsigned as u32).unwrap_or(char::REPLACEMENT_CHARACTER).to_string(),
+                'f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A' => {
+                    let f: f64 = match size {
+                        4 => f64::from(f32::from_bits(unsigned as u32)),
+                        _ => f64::from_bits(n),
+                    };
+                    match conversion {
+                        'e' => format!("{:.*e}", precision.unwrap_or(6), f),
+                        'E' => format!("{:.*E}", precision.unwrap_or(6), f),
+                        'g' | 'G' => f.to_string(),
+                        _ => format!("{:.*}", precision.unwrap_or(6), f),
+                    }
+                }
+                _ => signed.to_string(),
+            }
+        }
+        _ => String::from(MISSING_DATA),
+    };
+
+    (s, number.is_some())
+}
+
+/// Format the printf-style `format` string of a log message with the
+/// message `values`. The Apple `%{annotation}` extensions are supported.
+pub fn format_message(
+    format: &str,
+    values: &[Tracev3Value],
+) -> String {
+    let mut out = String::with_capacity(format.len() + 16);
+    let mut values = values.iter().peekable();
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            out.push('%');
+            continue;
+        }
+        let mut spec = String::from("%");
+        let mut annotation = String::new();
+        if chars.peek() == Some(&'{') {
+            chars.next();
+            for c in chars.by_ref() {
+                if c == '}' {
+                    break;
+                }
+                annotation.push(c);
+            }
+        }
+        let mut flags = String::new();
+        while let Some(c) = chars.next_if(|c| matches!(c, '-' | '+' | ' ' | '#' | '0' | '\'')) {
+            flags.push(c);
+        }
+        // the width or precision from `*` or digits
+        let mut star_or_digits = |chars: &mut std::iter::Peekable<std::str::Chars>| -> Option<usize> {
+            if chars.next_if_eq(&'*').is_some() {
+                return match values.next_if(|v| matches!(v, Tracev3Value::Precision(_) | Tracev3Value::Number(..))) {
+                    Some(Tracev3Value::Precision(n)) | Some(Tracev3Value::Number(n, _)) => Some(*n as usize),
+                    _ => None,
+                };
+            }
+            let mut digits = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+                digits.push(c);
+            }
+            digits.parse::<usize>().ok()
+        };
+        let width: usize = star_or_digits(&mut chars).unwrap_or(0);
+        let precision: Option<usize> = chars
+            .next_if_eq(&'.')
+            .map(|_| star_or_digits(&mut chars).unwrap_or(0));
+        while let Some(c) = chars.next_if(|c| matches!(c, 'h' | 'l' | 'q' | 'j' | 'z' | 't' | 'L')) {
+            spec.push(c);
+        }
+        let conversion: char = match chars.next() {
+            Some(c) => c,
+            None => {
+                out.push_str(&spec);
+                break;
+            }
+        };
+        if conversion == 'n' {
+            continue;
+        }
+        // precision values not used by a `*` do not have a conversion
+        while values.next_if(|v| matches!(v, Tracev3Value::Precision(_))).is_some() {}
+        let value: &Tracev3Value = match values.next() {
+            Some(value) => value,
+            None => {
+                out.push_str(MISSING_DATA);
+                continue;
+            }
+        };
+        let (s, numeric) = format_value(value, &annotation, &flags, precision, conversion);
+        out.push_str(&pad(s, width, &flags, numeric));
+    }
+
+    out
+}
+
+/// The message data of an oversize chunk, by the first and second process
+/// identifiers and the data reference index.
+type Oversize = HashMap<(u64, u32, u32), Vec<Tracev3Value>>;
+
+/// A log entry read from a `.tracev3` file.
+#[derive(Debug)]
+pub struct Tracev3Entry {
+    /// Mach continuous time.
+    pub continuous_time: u64,
+    pub thread_id: u64,
+    pub log_type: &'static str,
+    pub activity_id: u32,
+    pub pid: u32,
+    pub ttl: u8,
+    pub process: String,
+    pub library: String,
+    pub subsystem: Option<(String, String)>,
+    pub message: String,
+}
+
+impl Tracev3Entry {
+    /// Return the event string and the end of the datetime substring.
+    /// The event string is similar to that printed by `log show`.
+    pub fn to_event_string(
+        &self,
+        dt: &DateTimeL,
+    ) -> (String, usize) {
+        let mut s: String = dt.format("%Y-%m-%d %H:%M:%S%.6f%z").to_string();
+        let dt_end: usize = s.len();
+        _ = write!(
+            s,
+            " {:#x} {} {:#x} {} {} {}:",
+            self.thread_id, self.log_type, self.activity_id, self.pid, self.ttl, self.process,
+        );
+        if !self.library.is_empty() && self.library != self.process {
+            _ = write!(s, " ({})", self.library);
+        }
+        if let Some((subsystem, category)) = self.subsystem.as_ref() {
+            _ = write!(s, " [{}:{}]", subsystem, category);
+        }
+        s.push(' ');
+        s.push_str(&self.message);
+        s.push('\n');
+
+        (s, dt_end)
+    }
+}
+
+/// The `uuidtext` and `dsc` files of a log archive, read as needed.
+#[derive(Default)]
+struct Tracev3Strings {
+    uuidtext_dir: PathBuf,
+    uuidtexts: HashMap<Uuid, Option<UuidText>>,
+    dscs: HashMap<Uuid, Option<Dsc>>,
+}
+
+impl Tracev3Strings {
+    fn uuidtext(
+        &mut self,
+        uuid: &Uuid,
+    ) -> Option<&UuidText> {
+        let dir: &Path = &self.uuidtext_dir;
+        self.uuidtexts
+            .entry(*uuid)
+            .or_insert_with(|| {
+                let hex: String = uuid_hex(uuid);
+                let path: PathBuf = dir.join(&hex[..2]).join(&hex[2..]);
+                def1o!("read uuidtext {:?}", path);
+                std::fs::read(path).ok().and_then(UuidText::parse)
+            })
+            .as_ref()
+    }
+
+    fn dsc(
+        &mut self,
+        uuid: &Uuid,
+    ) -> Option<&Dsc> {
+        let dir: &Path = &self.uuidtext_dir;
+        self.dscs
+            .entry(*uuid)
+            .or_insert_with(|| {
+                let path: PathBuf = dir.join("dsc").join(uuid_hex(uuid));
+                def1o!("read dsc {:?}", path);
+                std::fs::read(path).ok().and_then(Dsc::parse)
+            })
+            .as_ref()
+    }
+
+    /// The process name from the image path of the main executable.
+    fn process_name(
+        &mut self,
+        process: Option<&CatalogProcess>,
+    ) -> String {
+        process
+            .and_then(|p| p.main_uuid)
+            .and_then(|uuid| self.uuidtext(&uuid))
+            .map(|uuidtext| String::from(image_name(&uuidtext.path)))
+            .unwrap_or_default()
+    }
+}
+
+/// The formatter fields of a firehose log entry.
+#[derive(Debug, Default)]
+struct Formatter {
+    formatter: u16,
+    large_offset: u16,
+    large_shared_cache: u16,
+    alt_index: u16,
+    uuid: Option<Uuid>,
+}
+
+impl Formatter {
+    fn parse(
+        reader: &mut LeReader,
+        flags: u16,
+    ) -> Option<Formatter> {
+        let mut formatter = Formatter { formatter: flags & FLAG_FORMATTER_MASK, ..Default::default() };
+        match formatter.formatter {
+            FORMATTER_MAIN_EXE | FORMATTER_SHARED_CACHE if flags & FLAG_HAS_LARGE_OFFSET != 0 => {
+                formatter.large_offset = reader.u16()?;
+            }
+            FORMATTER_ABSOLUTE => formatter.alt_index = reader.u16()?,
+            FORMATTER_UUID_RELATIVE => formatter.uuid = Some(reader.uuid()?),
+            FORMATTER_LARGE_SHARED_CACHE => {
+                if flags & FLAG_HAS_LARGE_OFFSET != 0 {
+                    formatter.large_offset = reader.u16()?;
+                }
+                formatter.large_shared_cache = reader.u16()?;
+            }
+            _ => {}
+        }
+
+        Some(formatter)
+    }
+}
+
+/// Resolve the format string and the library name of a log entry.
+fn format_string_resolve(
+    strings: &mut Tracev3Strings,
+    process: Option<&CatalogProcess>,
+    formatter: &Formatter,
+    location: u32,
+    pc: u32,
+) -> (Option<String>, String) {
+    let location64: u64 = u64::from(location);
+    let (format, library): (Option<String>, Option<String>) = match formatter.formatter {
+        FORMATTER_SHARED_CACHE | FORMATTER_LARGE_SHARED_CACHE => {
+            let offset: u64 = match formatter.formatter {
+                FORMATTER_LARGE_SHARED_CACHE => (u64::from(formatter.large_shared_cache) << 31) | location64,
+                _ => (u64::from(formatter.large_offset) << 31) | location64,
+            };
+            match process.and_then(|p| p.dsc_uuid).and_then(|uuid| strings.dsc(&uuid)) {
+                Some(dsc) => match dsc.format_string(offset) {
+                    Some((format, path)) => {
+                        let library = dsc.image_path(u64::from(pc)).or(path).map(|p| String::from(image_name(p)));
+                        (Some(format), library)
+                    }
+                    None => (None, None),
+                },
+                None => (None, None),
+            }
+        }
+        FORMATTER_MAIN_EXE | FORMATTER_ABSOLUTE | FORMATTER_UUID_RELATIVE => {
+            let uuid: Option<Uuid> = match formatter.formatter {
+                FORMATTER_UUID_RELATIVE => formatter.uuid,
+                FORMATTER_ABSOLUTE => {
+                    let pc: u64 = (u64::from(formatter.alt_index) << 32) | u64::from(pc);
+                    process.and_then(|p| {
+                        p.images
+                            .iter()
+                            .find(|(load_address, size, _)| *load_address <= pc && pc - *load_address < *size)
+                            .map(|(_, _, uuid)| *uuid)
+                            .or(p.main_uuid)
+                    })
+                }
+                _ => process.and_then(|p| p.main_uuid),
+            };
+            let offset: u64 = (u64::from(formatter.large_offset) << 31) | location64;
+            match uuid.and_then(|uuid| strings.uuidtext(&uuid)) {
+                Some(uuidtext) => (uuidtext.format_string(offset), Some(String::from(image_name(&uuidtext.path)))),
+                None => (None, None),
+            }
+        }
+        _ => (None, None),
+    };
+    let format: Option<String> = match format {
+        None if location & FORMAT_STRING_DYNAMIC != 0 => Some(String::from("%s")),
+        format => format,
+    };
+
+    (format, library.unwrap_or_default())
+}
+
+/// Parse the log entries of a firehose chunk.
+fn firehose_parse(
+    chunk: &[u8],
+    catalog: &Catalog,
+    oversize: &Oversize,
+    strings: &mut Tracev3Strings,
+    entries: &mut Vec<Tracev3Entry>,
+) -> Option<()> {
+    let mut reader = LeReader::new(chunk);
+    let first_proc_id: u64 = reader.u64()?;
+    let second_proc_id: u32 = reader.u32()?;
+    let ttl: u8 = reader.u8()?;
+    reader.skip(3)?;
+    let public_data_size: usize = usize::from(reader.u16()?);
+    let private_data_virtual_offset: usize = usize::from(reader.u16()?);
+    reader.skip(4)?;
+    let base_continuous_time: u64 = reader.u64()?;
+    let public_data: &[u8] = chunk.get(
+        FIREHOSE_PREAMBLE_SZ..FIREHOSE_PREAMBLE_SZ + public_data_size.checked_sub(FIREHOSE_PUBLIC_DATA_SIZE_BASE)?,
+    )?;
+    // the private data is at the end of the chunk
+    let private_data: &[u8] = match FIREHOSE_PRIVATE_DATA_NONE.checked_sub(private_data_virtual_offset) {
+        Some(0) | None => &[],
+        Some(len) => chunk.get(chunk.len().saturating_sub(len)..).unwrap_or_default(),
+    };
+    let process: Option<&CatalogProcess> = catalog.get(&(first_proc_id, second_proc_id));
+    let process_name: String = strings.process_name(process);
+    let pid: u32 = process.map_or(0, |p| p.pid);
+
+    let mut reader = LeReader::new(public_data);
+    while reader.rest().len() >= FIREHOSE_ENTRY_SZ {
+        let activity_type: u8 = reader.u8()?;
+        if activity_type == 0 {
+            break;
+        }
+        let log_type: u8 = reader.u8()?;
+        let flags: u16 = reader.u16()?;
+        let location: u32 = reader.u32()?;
+        let thread_id: u64 = reader.u64()?;
+        let delta: u64 = u64::from(reader.u32()?) | (u64::from(reader.u16()?) << 32);
+        let data_size: usize = usize::from(reader.u16()?);
+        let data: &[u8] = reader.bytes(data_size)?;
+        _ = reader.skip(padding8(data_size));
+        let continuous_time: u64 = base_continuous_time.wrapping_add(delta);
+
+        let mut entry_reader = LeReader::new(data);
+        let mut activity_id: u32 = 0;
+        let mut subsystem_id: Option<u16> = None;
+        let mut data_ref: Option<u16> = None;
+        let mut private_range: Option<(usize, usize)> = None;
+        let pc: u32;
+        let formatter: Formatter;
+        let log_type_name: &'static str;
+        let mut parse_entry = || -> Option<()> {
+            match activity_type {
+                ACTIVITY_TYPE_NONACTIVITY | ACTIVITY_TYPE_SIGNPOST => {
+                    if flags & FLAG_HAS_CURRENT_AID != 0 {
+                        activity_id = entry_reader.u32()?;
+                        entry_reader.skip(4)?;
+                    }
+                    if flags & FLAG_HAS_PRIVATE_DATA != 0 {
+                        private_range = Some((usize::from(entry_reader.u16()?), usize::from(entry_reader.u16()?)));
+                    }
+                }
+                _ => {
+                    if log_type != LOG_TYPE_USERACTION {
+                        activity_id = entry_reader.u32()?;
+                        entry_reader.skip(4)?;
+                    }
+                    if flags & FLAG_HAS_UNIQUE_PID != 0 {
+                        entry_reader.skip(8)?;
+                    }
+                    if flags & FLAG_HAS_CURRENT_AID != 0 {
+                        entry_reader.skip(8)?;
+                    }
+                    // the "has other current activity identifier" flag
+                    if flags & FLAG_HAS_SUBSYSTEM != 0 {
+                        entry_reader.skip(8)?;
+                    }
+                }
+            }
+            Some(())
+        };
+        match activity_type {
+            ACTIVITY_TYPE_NONACTIVITY | ACTIVITY_TYPE_SIGNPOST | ACTIVITY_TYPE_ACTIVITY => {
+                if parse_entry().is_none() {
+                    continue;
+                }
+                pc = match entry_reader.u32() {
+                    Some(val) => val,
+                    None => continue,
+                };
+                formatter = match Formatter::parse(&mut entry_reader, flags) {
+                    Some(val) => val,
+                    None => continue,
+                };
+                let mut parse_tail = || -> Option<()> {
+                    if activity_type != ACTIVITY_TYPE_ACTIVITY {
+                        if flags & FLAG_HAS_SUBSYSTEM != 0 {
+                            subsystem_id = Some(entry_reader.u16()?);
+                        }
+                        if activity_type == ACTIVITY_TYPE_SIGNPOST {
+                            // skip the signpost identifier
+                            entry_reader.skip(8)?;
+                        }
+                        if flags & FLAG_HAS_RULES != 0 {
+                            entry_reader.skip(1)?;
+                        }
+                        if flags & FLAG_HAS_OVERSIZE != 0 {
+                            data_ref = Some(entry_reader.u16()?);
+                        }
+                        if activity_type == ACTIVITY_TYPE_SIGNPOST && flags & FLAG_HAS_SIGNPOST_NAME != 0 {
+                            entry_reader.skip(4)?;
+                            if formatter.large_shared_cache != 0 {
+                                entry_reader.skip(2)?;
+                            }
+                        }
+                    }
+                    Some(())
+                };
+                if parse_tail().is_none() {
+                    continue;
+                }
+                log_type_name = match activity_type {
+                    ACTIVITY_TYPE_ACTIVITY => "Activity",
+                    ACTIVITY_TYPE_SIGNPOST => "Signpost",
+                    _ => match log_type {
+                        0x01 => "Info",
+                        0x02 => "Debug",
+                        0x10 => "Error",
+                        0x11 => "Fault",
+                        _ => "Default",
+                    },
+                };
+            }
+            ACTIVITY_TYPE_LOSS => {
+                let count: u64 = match (entry_reader.skip(16), entry_reader.u64()) {
+                    (Some(_), Some(count)) => count,
+                    _ => continue,
+                };
+                entries.push(Tracev3Entry {
+                    continuous_time,
+                    thread_id,
+                    log_type: "Loss",
+                    activity_id: 0,
+                    pid,
+                    ttl,
+                    process: process_name.clone(),
+                    library: String::new(),
+                    subsystem: None,
+                    message: format!("lost {} unreliable messages", count),
+                });
+                continue;
+            }
+            _ => {
+                def1o!("skip activity_type {:#x}", activity_type);
+                continue;
+            }
+        }
+        let entry_private_data: &[u8] = match private_range {
+            Some((offset, size)) => offset
+                .checked_sub(private_data_virtual_offset)
+                .and_then(|beg| private_data.get(beg..beg + size))
+                .unwrap_or_default(),
+            None => &[],
+        };
+        let values: Vec<Tracev3Value> = match data_ref {
+            Some(data_ref) => match oversize.get(&(first_proc_id, second_proc_id, u32::from(data_ref))) {
+                Some(values) => values.clone(),
+                None => items_parse(entry_reader.rest(), entry_private_data),
+            },
+            None => items_parse(entry_reader.rest(), entry_private_data),
+        };
+        let (format, library) = format_string_resolve(strings, process, &formatter, location, pc);
+        let message: String = match format {
+            Some(format) => format_message(&format, &values),
+            None => format!("<decode: missing format string at {:#x}>", location),
+        };
+        let subsystem: Option<(String, String)> =
+            subsystem_id.and_then(|id| process.and_then(|p| p.subsystems.get(&id)).cloned());
+        entries.push(Tracev3Entry {
+            continuous_time,
+            thread_id,
+            log_type: log_type_name,
+            activity_id,
+            pid,
+            ttl,
+            process: process_name.clone(),
+            library,
+            subsystem,
+            message,
+        });
+    }
+
+    Some(())
+}
+
+/// Parse an oversize chunk into `oversize`.
+fn oversize_parse(
+    chunk: &[u8],
+    oversize: &mut Oversize,
+) -> Option<()> {
+    let mut reader = LeReader::new(chunk);
+    let first_proc_id: u64 = reader.u64()?;
+    let second_proc_id: u32 = reader.u32()?;
+    // skip the TTL, unknown data, and continuous time
+    reader.skip(12)?;
+    let data_ref_index: u32 = reader.u32()?;
+    let public_data_size: usize = usize::from(reader.u16()?);
+    let private_data_size: usize = usize::from(reader.u16()?);
+    let public_data: &[u8] = reader.bytes(public_data_size)?;
+    let private_data: &[u8] = reader.bytes(private_data_size).unwrap_or_default();
+    oversize.insert((first_proc_id, second_proc_id, data_ref_index), items_parse(public_data, private_data));
+
+    Some(())
+}
+
+/// Parse a state dump chunk.
+fn statedump_parse(
+    chunk: &[u8],
+    catalog: &Catalog,
+    strings: &mut Tracev3Strings,
+) -> Option<Tracev3Entry> {
+    let mut reader = LeReader::new(chunk);
+    let first_proc_id: u64 = reader.u64()?;
+    let second_proc_id: u32 = reader.u32()?;
+    let ttl: u8 = reader.u8()?;
+    reader.skip(3)?;
+    let continuous_time: u64 = reader.u64()?;
+    let activity_id: u64 = reader.u64()?;
+    // skip the UUID, data type, data size, decoder library and decoder type
+    reader.skip(16 + 8 + 64 + 64)?;
+    let title: String = cstring(reader.bytes(64)?, 0);
+    let process: Option<&CatalogProcess> = catalog.get(&(first_proc_id, second_proc_id));
+
+    Some(Tracev3Entry {
+        continuous_time,
+        thread_id: 0,
+        log_type: "State",
+        activity_id: activity_id as u32,
+        pid: process.map_or(0, |p| p.pid),
+        ttl,
+        process: strings.process_name(process),
+        library: String::new(),
+        subsystem: None,
+        message: format!("state dump: {}", title),
+    })
+}
+
+/// Parse a simple dump chunk.
+fn simpledump_parse(
+    chunk: &[u8],
+    catalog: &Catalog,
+    strings: &mut Tracev3Strings,
+) -> Option<Tracev3Entry> {
+    let mut reader = LeReader::new(chunk);
+    let first_proc_id: u64 = reader.u64()?;
+    let second_proc_id: u64 = reader.u64()?;
+    let continuous_time: u64 = reader.u64()?;
+    let thread_id: u64 = reader.u64()?;
+    // skip unknown offset, TTL, type, sender UUID, and dsc UUID
+    reader.skip(8 + 32)?;
+    reader.skip(4)?;
+    let subsystem_size: usize = reader.u32()? as usize;
+    let message_size: usize = reader.u32()? as usize;
+    let subsystem: String = cstring(reader.bytes(subsystem_size)?, 0);
+    let message: String = cstring(reader.bytes(message_size)?, 0);
+    let process: Option<&CatalogProcess> = catalog.get(&(first_proc_id, second_proc_id as u32));
+
+    Some(Tracev3Entry {
+        continuous_time,
+        thread_id,
+        log_type: "Default",
+        activity_id: 0,
+        pid: process.map_or(0, |p| p.pid),
+        ttl: 0,
+        process: strings.process_name(process),
+        library: String::new(),
+        subsystem: match subsystem.is_empty() {
+            true => None,
+            false => Some((subsystem, String::new())),
+        },
+        message,
+    })
+}
+
+/// A reader of [Apple Unified Logging] `.tracev3` files.
+///
+/// A `.tracev3` file is a sequence of chunks. The first chunk is the header
+/// chunk with the boot UUID. A catalog chunk describes the processes of the
+/// following chunkset chunks. A chunkset chunk is a LZ4 compressed sequence
+/// of firehose chunks, oversize chunks, state dump chunks, and simple dump
+/// chunks. The log entries of each catalog and its chunksets are sorted by
+/// time before they are returned.
+///
+/// [Apple Unified Logging]: https://github.com/libyal/dtformats/blob/main/documentation/Apple%20Unified%20Logging%20and%20Activity%20Tracing%20formats.asciidoc
+pub struct Tracev3Reader {
+    /// The `FPath` of the file being read.
+    path: FPath,
+    /// Unique identifier for the file processing instance.
+    path_id: PathId,
+    /// The type of file being read.
+    file_type: FileType,
+    /// If necessary, the extracted file as a temporary file.
+    named_temp_file: Option<TempPath>,
+    /// Conversion of entry timestamps to `DateTimeL` with this timezone offset.
+    fixed_offset: FixedOffset,
+    /// The file data.
+    data: Vec<u8>,
+    /// Offset into `data` of the next chunk to read.
+    fo_next: usize,
+    /// Boot UUID from the header chunk.
+    boot_uuid: Uuid,
+    /// Time synchronization records of the boot of this file.
+    timesync: TimesyncBoot,
+    /// The `uuidtext` and `dsc` files of the log archive.
+    strings: Tracev3Strings,
+    /// The most recent catalog.
+    catalog: Catalog,
+    /// Oversize message data read so far.
+    oversize: Oversize,
+    /// Log entries of the current catalog, sorted by time.
+    entries: VecDeque<Tracev3Entry>,
+    /// Summary statistic.
+    /// `Count` of log entries processed.
+    events_processed: Count,
+    /// Summary statistic.
+    /// `Count` of log entries accepted by the datetime filters.
+    events_accepted: Count,
+    /// Summary statistic.
+    /// First (soonest) accepted (printed) `DateTimeL`.
+    dt_first_accepted: DateTimeLOpt,
+    /// Summary statistic.
+    /// Last (latest) accepted (printed) `DateTimeL`.
+    dt_last_accepted: DateTimeLOpt,
+    /// Summary statistic.
+    /// First (soonest) processed `DateTimeL`.
+    dt_first_processed: DateTimeLOpt,
+    /// Summary statistic.
+    /// Last (latest) processed `DateTimeL`.
+    dt_last_processed: DateTimeLOpt,
+    /// File Size of the file being read in bytes.
+    filesz: FileSz,
+    /// file Last Modified time from file-system metadata
+    mtime: SystemTime,
+    /// Out of chronological order.
+    out_of_order: Count,
+    /// The last [`Error`], if any, as a `String`
+    error: Option<String>,
+}
+
+impl fmt::Debug for Tracev3Reader {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        f.debug_struct("Tracev3Reader")
+            .field("Path", &self.path)
+            .field("boot_uuid", &uuid_dashed(&self.boot_uuid))
+            .field("data len", &self.data.len())
+            .field("fo_next", &self.fo_next)
+            .field("uuidtext_dir", &self.strings.uuidtext_dir)
+            .field("Error?", &self.error)
+            .finish()
+    }
+}
+
+/// Implement the Tracev3Reader.
+impl Tracev3Reader {
+    /// Create a new `Tracev3Reader`.
+    ///
+    /// The file is read and the header chunk is checked. The `timesync`
+    /// files of the log archive are read.
+    pub fn new(
+        path_id: PathId,
+        path: FPath,
+        file_type: FileType,
+        fixed_offset: FixedOffset,
+    ) -> Result<Tracev3Reader> {
+        def1n!("({}, {:?}, {:?}, {:?})", path_id, path, file_type, fixed_offset);
+        debug_assert!(file_type.is_tracev3(), "Tracev3Reader only supports FileType::Tracev3, given {:?}", file_type);
+
+        let path_std: &Path = Path::new(&path);
+        let named_temp_file: Option<TempPath>;
+        let mtime_opt: Option<SystemTime>;
+        (named_temp_file, mtime_opt) = match decompress_to_ntf(path_id, path_std, &file_type) {
+            Ok(ntf_mtime) => match ntf_mtime {
+                Some((ntf, mtime_opt, _filesz)) => (Some(ntf), mtime_opt),
+                None => (None, None),
+            },
+            Err(err) => {
+                def1x!("decompress_to_ntf({:?}, {:?}) Error, return {:?}", path, file_type, err);
+                return Err(err);
+            }
+        };
+        let path_actual: &Path = match named_temp_file {
+            Some(ref ntf) => ntf.as_ref(),
+            None => path_std,
+        };
+        def1o!("path_actual {:?}", path_actual);
+        let file: FileHandleManaged = FILE_HANDLE_MANAGER.request_open_managed(
+            path_id,
+            FileHandleRole::PrimaryRead,
+            path_actual,
+            OpenOptionsManaged::read_only(),
+        )?;
+        let metadata: FileMetadata = file.metadata()?;
+        let mtime: SystemTime = match mtime_opt {
+            Some(val) => val,
+            None => match metadata.modified() {
+                Ok(val) => val,
+                Err(_err) => {
+                    de_err!("metadata.modified() failed {}", _err);
+                    SystemTime::UNIX_EPOCH
+                }
+            },
+        };
+        let filesz: FileSz = metadata.len() as FileSz;
+        def1o!("filesz {}", filesz);
+
+        let mut data: Vec<u8> = Vec::with_capacity(filesz as usize);
+        (&file).read_to_end(&mut data)?;
+        drop(file);
+
+        let (fo_next, header): (usize, &[u8]) = match chunks(&data).next() {
+            Some((CHUNK_TAG_HEADER, header)) => (CHUNK_PREAMBLE_SZ + header.len() + padding8(header.len()), header),
+            _ => {
+                def1x!("no header chunk, return Err");
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("no tracev3 header chunk, file {:?}", path),
+                ));
+            }
+        };
+        let mut header_reader = LeReader::new(header);
+        let numerator: Option<u32> = header_reader.u32();
+        let denominator: Option<u32> = header_reader.u32();
+        debug_assert_eq!(HEADER_DENOMINATOR_OFFSET, HEADER_NUMERATOR_OFFSET + 4);
+        let boot_uuid: Uuid = match (numerator, denominator, header.get(HEADER_BOOT_UUID_OFFSET..HEADER_BOOT_UUID_OFFSET + 16)) {
+            (Some(_), Some(_), Some(uuid)) => uuid.try_into().unwrap_or_default(),
+            _ => {
+                def1x!("header chunk too small {}, return Err", header.len());
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("tracev3 header chunk size {} is too small, file {:?}", header.len(), path),
+                ));
+            }
+        };
+        def1o!("boot_uuid {}", uuid_dashed(&boot_uuid));
+
+        let (timesync_dir, uuidtext_dir) = match find_archive_dirs(path_std) {
+            Some(dirs) => dirs,
+            None => {
+                def1x!("no timesync directory, return Err");
+                return Err(Error::new(
+                    ErrorKind::NotFound,
+                    format!("no timesync directory found above file {:?}", path),
+                ));
+            }
+        };
+        def1o!("timesync_dir {:?}, uuidtext_dir {:?}", timesync_dir, uuidtext_dir);
+        let mut boots: HashMap<Uuid, TimesyncBoot> = timesync_read_dir(&timesync_dir);
+        let timesync: TimesyncBoot = match boots.remove(&boot_uuid) {
+            Some(timesync) => timesync,
+            None => {
+                def1x!("no timesync boot record, return Err");
+                return Err(Error::new(
+                    ErrorKind::NotFound,
+                    format!(
+                        "no timesync boot record for boot UUID {} in {:?}, file {:?}",
+                        uuid_dashed(&boot_uuid), timesync_dir, path,
+                    ),
+                ));
+            }
+        };
+
+        def1x!("return Ok(Tracev3Reader)");
+
+        Ok(Tracev3Reader {
+            path,
+            path_id,
+            file_type,
+            named_temp_file,
+            fixed_offset,
+            data,
+            fo_next,
+            boot_uuid,
+            timesync,
+            strings: Tracev3Strings { uuidtext_dir, ..Default::default() },
+            catalog: Catalog::new(),
+            oversize: Oversize::new(),
+            entries: VecDeque::new(),
+            events_processed: 0,
+            events_accepted: 0,
+            dt_first_accepted: DateTimeLOpt::None,
+            dt_last_accepted: DateTimeLOpt::None,
+            dt_first_processed: DateTimeLOpt::None,
+            dt_last_processed: DateTimeLOpt::None,
+            filesz,
+            mtime,
+            out_of_order: 0,
+            error: None,
+        })
+    }
+
+    pub const fn mtime(&self) -> SystemTime {
+        self.mtime
+    }
+
+    pub const fn path_id(&self) -> PathId {
+        self.path_id
+    }
+
+    #[inline(always)]
+    pub const fn path(&self) -> &FPath {
+        &self.path
+    }
+
+    #[inline(always)]
+    pub const fn filetype(&self) -> FileType {
+        self.file_type
+    }
+
+    /// File size in bytes
+    #[inline(always)]
+    pub const fn filesz(&self) -> FileSz {
+        self.filesz
+    }
+
+    /// Boot UUID from the header chunk.
+    pub fn boot_uuid(&self) -> String {
+        uuid_dashed(&self.boot_uuid)
+    }
+
+    /// Read the next catalog chunk and its chunksets into `self.entries`.
+    /// Return `false` if there are no more chunks.
+    fn read_catalog(&mut self) -> Result<bool> {
+        def1n!("fo_next {}", self.fo_next);
+        let mut chunksets: Vec<Vec<u8>> = Vec::new();
+        let mut fo: usize = self.fo_next;
+        let mut read_any: bool = false;
+        while let Some((tag, chunk)) = chunks(self.data.get(fo..).unwrap_or_default()).next() {
+            if tag == CHUNK_TAG_CATALOG && !chunksets.is_empty() {
+                break;
+            }
+            read_any = true;
+            match tag {
+                CHUNK_TAG_CATALOG => match catalog_parse(chunk) {
+                    Some(catalog) => self.catalog = catalog,
+                    None => de_err!("bad catalog chunk at {}", fo),
+                },
+                CHUNK_TAG_CHUNKSET => match chunkset_decompress(chunk) {
+                    Ok(chunkset) => chunksets.push(chunkset),
+                    Err(err) => {
+                        def1x!("chunkset at {} error {}", fo, err);
+                        self.fo_next = self.data.len();
+                        return Err(Error::new(err.kind(), format!("{} at offset {}", err, fo)));
+                    }
+                },
+                _ => def1o!("skip chunk tag {:#x} at {}", tag, fo),
+            }
+            fo += CHUNK_PREAMBLE_SZ + chunk.len() + padding8(chunk.len());
+        }
+        self.fo_next = fo;
+        for chunkset in chunksets.iter() {
+            for (tag, chunk) in chunks(chunkset) {
+                if tag == CHUNK_TAG_OVERSIZE {
+                    oversize_parse(chunk, &mut self.oversize);
+                }
+            }
+        }
+        let mut entries: Vec<Tracev3Entry> = Vec::new();
+        for chunkset in chunksets.iter() {
+            for (tag, chunk) in chunks(chunkset) {
+                match tag {
+                    CHUNK_TAG_FIREHOSE => {
+                        firehose_parse(chunk, &self.catalog, &self.oversize, &mut self.strings, &mut entries);
+                    }
+                    CHUNK_TAG_STATEDUMP => {
+                        entries.extend(statedump_parse(chunk, &self.catalog, &mut self.strings));
+                    }
+                    CHUNK_TAG_SIMPLEDUMP => {
+                        entries.extend(simpledump_parse(chunk, &self.catalog, &mut self.strings));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        entries.sort_by_key(|entry| entry.continuous_time);
+        def1x!("read {} entries, fo_next {}", entries.len(), self.fo_next);
+        self.entries.extend(entries);
+
+        Ok(read_any)
+    }
+
+    /// Convert mach continuous time to a `DateTimeL`.
+    fn continuous_time_to_datetime(
+        &self,
+        continuous_time: u64,
+    ) -> DateTimeLOpt {
+        let nanos: i64 = self.timesync.to_unix_nanos(continuous_time)?;
+        let dt = DateTime::from_timestamp_nanos(nanos);
+
+        Some(self.fixed_offset.from_utc_datetime(&dt.naive_utc()))
+    }
+
+    /// Return the next log entry that passes the datetime filters.
+    pub fn next(
+        &mut self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> ResultNextPyDataEvent {
+        def1n!("({:?}, {:?})", dt_filter_after, dt_filter_before);
+        loop {
+            let entry: Tracev3Entry = match self.entries.pop_front() {
+                Some(entry) => entry,
+                None => match self.read_catalog() {
+                    Ok(true) => continue,
+                    Ok(false) => break,
+                    Err(err) => {
+                        def1x!("read_catalog error {}", err);
+                        self.error = Some(err.to_string());
+                        return ResultFind4::Err(err);
+                    }
+                },
+            };
+            self.events_processed += 1;
+            let dt: DateTimeL = match self.continuous_time_to_datetime(entry.continuous_time) {
+                Some(dt) => dt,
+                None => continue,
+            };
+            summary_stat!(self.dm_first_last_update_processed(&dt));
+            if dt_pass_filters(&dt, dt_filter_after, dt_filter_before) != Result_Filter_DateTime2::InRange {
+                continue;
+            }
+            let (event, dt_end) = entry.to_event_string(&dt);
+            summary_stat!(self.dm_first_last_update_accepted(&dt));
+            self.events_accepted += 1;
+            def1x!("return Found entry");
+
+            return ResultFind4::Found(PyDataEvent::new(
+                EventBytes::from(event.as_bytes()),
+                dt,
+                DtBegEndPairOpt::Some((0, dt_end)),
+            ));
+        }
+        def1x!("return Done");
+
+        ResultFind4::Done
+    }
+
+    /// Update the two statistic `DateTimeL` of
+    /// `self.dt_first_processed` and `self.dt_last_processed`.
+    fn dm_first_last_update_processed(
+        &mut self,
+        dt: &DateTimeL,
+    ) {
+        if !summary_stats_enabled() {
+            return;
+        }
+        match self.dt_first_processed {
+            Some(dt_) if &dt_ <= dt => {}
+            Some(_) => {
+                self.dt_first_processed = Some(*dt);
+                self.out_of_order += 1;
+            }
+            None => self.dt_first_processed = Some(*dt),
+        }
+        match self.dt_last_processed {
+            Some(dt_) if &dt_ >= dt => {}
+            _ => self.dt_last_processed = Some(*dt),
+        }
+    }
+
+    /// Update the two statistic `DateTimeL` of
+    /// `self.dt_first_accepted` and `self.dt_last_accepted`.
+    fn dm_first_last_update_accepted(
+        &mut self,
+        dt: &DateTimeL,
+    ) {
+        if !summary_stats_enabled() {
+            return;
+        }
+        match self.dt_first_accepted {
+            Some(dt_) if &dt_ <= dt => {}
+            _ => self.dt_first_accepted = Some(*dt),
+        }
+        match self.dt_last_accepted {
+            Some(dt_) if &dt_ >= dt => {}
+            _ => self.dt_last_accepted = Some(*dt),
+        }
+    }
+
+    /// Return an up-to-date `SummaryPyEventReader` instance for this
+    /// `Tracev3Reader`. The Python process statistics are left at defaults.
+    pub fn summary(&self) -> SummaryPyEventReader {
+        SummaryPyEventReader {
+            pyeventreader_events_processed: self.events_processed,
+            pyeventreader_events_accepted: self.events_accepted,
+            pyeventreader_datetime_first_accepted: self.dt_first_accepted,
+            pyeventreader_datetime_last_accepted: self.dt_last_accepted,
+            pyeventreader_datetime_first_processed: self.dt_first_processed,
+            pyeventreader_datetime_last_processed: self.dt_last_processed,
+            pyeventreader_filesz: self.filesz,
+            pyeventreader_out_of_order: self.out_of_order,
+            ..Default::default()
+        }
+    }
+
+    /// Return an up-to-date [`Summary`] instance for this `Tracev3Reader`.
+    ///
+    /// [`Summary`]: crate::readers::summary::Summary
+    pub fn summary_complete(&self) -> Summary {
+        let path = self.path().clone();
+        let path_ntf: Option<FPath> = self
+            .named_temp_file
+            .as_ref()
+            .map(|ntf| path_to_fpath(ntf.as_ref()));
+        let filetype = self.filetype();
+        let logmessagetype = filetype.to_logmessagetype();
+        let summarytracev3reader: SummaryPyEventReader = self.summary();
+        let error: Option<String> = self.error.clone();
+
+        Summary::new(
+            path,
+            path_ntf,
+            filetype,
+            logmessagetype,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(summarytracev3reader),
+            None,
+            None,
+            error,
+        )
+    }
+}
+
+impl PyDataEventReader for Tracev3Reader {
+    fn next(
+        &mut self,
+        dt_filter_after: &DateTimeLOpt,
+        dt_filter_before: &DateTimeLOpt,
+    ) -> ResultNextPyDataEvent {
+        Tracev3Reader::next(self, dt_filter_after, dt_filter_before)
+    }
+
+    fn mtime(&self) -> SystemTime {
+        Tracev3Reader::mtime(self)
+    }
+
+    fn summary_complete(&self) -> Summary {
+        Tracev3Reader::summary_complete(self)
+    }
+}
@@ -0,0 +1,205 @@
+// src/readers/utxlog.rs
+
+//! Export the variable-length records of a FreeBSD `utx.log` to a temporary
+//! file of [`FixedStruct`s] readable by a [`FixedStructReader`].
+//!
+//! FreeBSD `/var/log/utx.log` is a sequence of [`freebsd_x8664::futx`]
+//! records. Each record is prefixed by a big-endian `uint16_t` length and the
+//! trailing zero bytes of the record are not written. See `utx_log_add` in
+//! [`lib/libc/gen/pututxline.c`] and `getfutxent` in
+//! [`lib/libc/gen/getutxent.c`].
+//!
+//! Each `futx` is exported as a [`freebsd_x8664::utmpx`] so the records are
+//! printed the same as other FreeBSD `utmpx` records.
+//!
+//! [`FixedStruct`s]: crate::data::fixedstruct::FixedStruct
+//! [`FixedStructReader`]: crate::readers::fixedstructreader::FixedStructReader
+//! [`freebsd_x8664::futx`]: crate::data::fixedstruct::freebsd_x8664::futx
+//! [`freebsd_x8664::utmpx`]: crate::data::fixedstruct::freebsd_x8664::utmpx
+//! [`lib/libc/gen/pututxline.c`]: https://cgit.freebsd.org/src/tree/lib/libc/gen/pututxline.c?h=stable/14
+//! [`lib/libc/gen/getutxent.c`]: https://cgit.freebsd.org/src/tree/lib/libc/gen/getutxent.c?h=stable/14
+
+use std::fs::{
+    File,
+    OpenOptions,
+};
+use std::io::{
+    BufReader,
+    BufWriter,
+    ErrorKind,
+    Read,
+    Result,
+    Write,
+};
+use std::path::Path;
+
+use ::memoffset::offset_of;
+#[allow(unused_imports)]
+use ::si_trace_print::{
+    defn,
+    defo,
+    defx,
+    defñ,
+};
+use ::tempfile::TempPath;
+
+use crate::common::{
+    Count,
+    FPath,
+};
+use crate::data::fixedstruct::freebsd_x8664::{
+    self,
+    futx,
+    timeval,
+    utmpx,
+    FUTX_SZ,
+    UTMPX_SZ,
+};
+use crate::readers::filedecompressor::create_temp_path;
+use crate::readers::helpers::fpath_to_path;
+
+/// FreeBSD `ut_type` `EMPTY`
+const UT_EMPTY: u8 = 0;
+/// FreeBSD `ut_type` `SHUTDOWN_TIME`, the greatest known `ut_type`
+const UT_SHUTDOWN_TIME: u8 = 8;
+
+const MICROSECONDS_PER_SECOND: u64 = 1_000_000;
+
+/// Convert the big-endian `futx` record `fu` to the bytes of a
+/// [`freebsd_x8664::utmpx`] in native byte order.
+///
+/// [`freebsd_x8664::utmpx`]: crate::data::fixedstruct::freebsd_x8664::utmpx
+pub fn futx_to_utmpx(fu: &[u8; FUTX_SZ]) -> [u8; UTMPX_SZ] {
+    let mut buffer = [0; UTMPX_SZ];
+
+    let ut_type: i16 = fu[offset_of!(futx, fu_type)] as i16;
+    let at: usize = offset_of!(utmpx, ut_type);
+    buffer[at..at + 2].copy_from_slice(&ut_type.to_ne_bytes());
+
+    let at: usize = offset_of!(futx, fu_tv);
+    let fu_tv: u64 = u64::from_be_bytes(fu[at..at + 8].try_into().unwrap());
+    let tv_sec: i64 = (fu_tv / MICROSECONDS_PER_SECOND) as i64;
+    let tv_usec: i64 = (fu_tv % MICROSECONDS_PER_SECOND) as i64;
+    let at: usize = offset_of!(utmpx, ut_tv) + offset_of!(timeval, tv_sec);
+    buffer[at..at + 8].copy_from_slice(&tv_sec.to_ne_bytes());
+    let at: usize = offset_of!(utmpx, ut_tv) + offset_of!(timeval, tv_usec);
+    buffer[at..at + 8].copy_from_slice(&tv_usec.to_ne_bytes());
+
+    let at: usize = offset_of!(futx, fu_pid);
+    let fu_pid: u32 = u32::from_be_bytes(fu[at..at + 4].try_into().unwrap());
+    let at: usize = offset_of!(utmpx, ut_pid);
+    buffer[at..at + 4].copy_from_slice(&(fu_pid as i32).to_ne_bytes());
+
+    // the character arrays are the same size in `futx` and `utmpx`
+    for (fu_at, ut_at, size) in [
+        (offset_of!(futx, fu_id), offset_of!(utmpx, ut_id), freebsd_x8664::UT_IDSIZE),
+        (offset_of!(futx, fu_user), offset_of!(utmpx, ut_user), freebsd_x8664::UT_USERSIZE),
+        (offset_of!(futx, fu_line), offset_of!(utmpx, ut_line), freebsd_x8664::UT_LINESIZE),
+        (offset_of!(futx, fu_host), offset_of!(utmpx, ut_host), freebsd_x8664::UT_HOSTSIZE),
+    ] {
+        buffer[ut_at..ut_at + size].copy_from_slice(&fu[fu_at..fu_at + size]);
+    }
+    // a `utmpx` C string must be null terminated
+    let at: usize = offset_of!(utmpx, ut_user) + freebsd_x8664::UT_USERSIZE - 1;
+    buffer[at] = 0;
+    let at: usize = offset_of!(utmpx, ut_line) + freebsd_x8664::UT_LINESIZE - 1;
+    buffer[at] = 0;
+    let at: usize = offset_of!(utmpx, ut_host) + freebsd_x8664::UT_HOSTSIZE - 1;
+    buffer[at] = 0;
+
+    buffer
+}
+
+/// Read the length-prefixed `futx` records of a `utx.log` from `reader` and
+/// write them as `utmpx` records to `writer`.
+///
+/// Like FreeBSD `getfutxent`, a zero length is skipped one byte at a time, a
+/// record longer than a `futx` is truncated, and a record shorter than a
+/// `futx` is padded with zeros. Records of `ut_type` `EMPTY` or an unknown
+/// `ut_type` are skipped. A truncated last record is ignored.
+///
+/// Returns the `Count` of records written.
+pub fn export_utxlog_records<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<Count> {
+    let mut count: Count = 0;
+    let mut len_buf: [u8; 2] = [0; 2];
+    match reader.read_exact(&mut len_buf) {
+        Ok(_) => {}
+        Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Ok(0),
+        Err(err) => return Err(err),
+    }
+    loop {
+        let len: usize = u16::from_be_bytes(len_buf) as usize;
+        if len == 0 {
+            // seek one byte forward to find the next record
+            len_buf[0] = len_buf[1];
+            match reader.read_exact(&mut len_buf[1..]) {
+                Ok(_) => continue,
+                Err(err) if err.kind() == ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err),
+            }
+        }
+        let mut fu: [u8; FUTX_SZ] = [0; FUTX_SZ];
+        let len_fu: usize = len.min(FUTX_SZ);
+        match reader.read_exact(&mut fu[..len_fu]) {
+            Ok(_) => {}
+            Err(err) if err.kind() == ErrorKind::UnexpectedEof => {
+                defo!("truncated record of len {}", len);
+                break;
+            }
+            Err(err) => return Err(err),
+        }
+        if len > FUTX_SZ {
+            // forward compatibility with a larger future `futx`
+            let skip: u64 = (len - FUTX_SZ) as u64;
+            if std::io::copy(&mut reader.take(skip), &mut std::io::sink())? != skip {
+                defo!("truncated record of len {}", len);
+                break;
+            }
+        }
+        let fu_type: u8 = fu[offset_of!(futx, fu_type)];
+        if fu_type == UT_EMPTY || fu_type > UT_SHUTDOWN_TIME {
+            defo!("skip record of fu_type {}", fu_type);
+        } else {
+            writer.write_all(&futx_to_utmpx(&fu))?;
+            count += 1;
+        }
+        match reader.read_exact(&mut len_buf) {
+            Ok(_) => {}
+            Err(err) if err.kind() == ErrorKind::UnexpectedEof => break,
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(count)
+}
+
+/// Export the records of the FreeBSD `utx.log` at `path` to a new temporary
+/// file. The temporary file has the same modified time as the `utx.log`.
+///
+/// Returns the `TempPath` of the temporary file and the `Count` of records.
+pub fn export_utxlog(path: &FPath) -> Result<(TempPath, Count)> {
+    defn!("({:?})", path);
+    let path_std: &Path = fpath_to_path(path);
+    let file_src: File = File::open(path_std)?;
+    let mut reader: BufReader<File> = BufReader::new(file_src);
+
+    let temp_path: TempPath = create_temp_path(path_std, ".utx")?;
+    let file: File = OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .open(&temp_path)?;
+    let mut writer: BufWriter<File> = BufWriter::new(file);
+    let count: Count = export_utxlog_records(&mut reader, &mut writer)?;
+    let file: File = writer.into_inner().map_err(|err| err.into_error())?;
+    if let Ok(mtime) = std::fs::metadata(path_std).and_then(|m| m.modified())
+        && let Err(_err) = file.set_modified(mtime)
+    {
+        defo!("set_modified failed {:?}", _err);
+    }
+    defx!("return {:?}, {} records", temp_path, count);
+
+    Ok((temp_path, count))
+}
@@ -107,6 +107,7 @@ cfg_if::cfg_if! {
 use std::collections::{
     BTreeMap,
     HashMap,
+    HashSet,
 };
 use std::fmt;
 use std::io::{
@@ -169,6 +170,7 @@ use ::s4lib::common::{
     FileOffset,
     FileProcessingResult,
     FileType,
+    FileTypeFixedStruct,
     FileTypeTextEncoding,
     LogMessageType,
     NLu8a,
@@ -184,6 +186,42 @@ use ::s4lib::common::{
 #[cfg(test)]
 use ::s4lib::common::FIXEDOFFSETp0900;
 
+use ::s4lib::data::acctsummary::AcctSummary;
+use ::s4lib::data::audit::{
+    AuditEvent,
+    AuditFilter,
+    AuditGrouper,
+    AuditInterpreter,
+};
+use ::s4lib::data::strace::{
+    strace_path_pid,
+    StraceCall,
+    StraceFilter,
+    StraceGrouper,
+};
+use ::s4lib::data::jsonline::{
+    JsonLine,
+    JsonLineFilter,
+    JsonLineOutput,
+    JsonLineTime,
+    JsonLinesMode,
+};
+use ::s4lib::data::columnar::ColumnarTime;
+use ::s4lib::data::containerlog::{
+    containerlog_parse_sysline,
+    ContainerLog,
+    ContainerLogJoiner,
+    ContainerLogMode,
+    ContainerStream,
+};
+use ::s4lib::data::timestampline::TimestampLineMode;
+use ::s4lib::data::keyvalue::{
+    keyvalue_parse,
+    KeyValueFilter,
+    KeyValueMode,
+    KeyValueTime,
+    KeyValues,
+};
 use ::s4lib::data::common::LogMessage;
 use ::s4lib::data::datetime::{
     datetime_parse_from_str,
@@ -195,10 +233,21 @@ use ::s4lib::data::datetime::{
     MAP_TZZ_TO_TZz,
     Utc,
 };
+use ::s4lib::data::evtx::{
+    EvtxFilter,
+    EvtxOutput,
+};
 use ::s4lib::data::pydataevent::EtlParserUsed;
 use ::s4lib::data::fixedstruct::ENTRY_SZ_MAX;
 use ::s4lib::data::journal::datetimelopt_to_realtime_timestamp_opt;
 use ::s4lib::data::sysline::SyslineP;
+use ::s4lib::data::utmpsession::{
+    fpath_is_btmp,
+    utmpsessions_still_open,
+    FailedLogins,
+    UtmpSession,
+    UtmpSessionTracker,
+};
 use ::s4lib::debug::printers::{
     de_err,
     de_wrn,
@@ -226,6 +275,7 @@ use ::s4lib::printer::printers::{
     ColorTheme,
     ColorThemeGlobal,
     PrinterLogMessage,
+    PrinterLogMessageResult,
     COLOR_THEME_DEFAULT,
 };
 use ::s4lib::printer::summary::{
@@ -253,11 +303,18 @@ use ::s4lib::python::venv::{
     PYTHON_VENV_PATH_DEFAULT,
     create as venv_create,
 };
+use ::s4lib::readers::aslreader::AslReader;
 use ::s4lib::readers::blockreader::{
     BlockSz,
     blocksz_def,
 };
+use ::s4lib::readers::boottimes::{
+    boot_times_merge,
+    boot_times_path,
+    BootTimes,
+};
 use ::s4lib::readers::pyeventreader::{
+    PyDataEventReader,
     PyEventReader,
     PyEventType,
     ResultNextPyDataEvent,
@@ -290,6 +347,12 @@ use ::s4lib::readers::journalreader::{
     JournalReader,
     ResultNext,
 };
+use ::s4lib::readers::logcatreader::LogcatReader;
+use ::s4lib::readers::odlreader::OdlReader;
+use ::s4lib::readers::pcapreader::{
+    PcapReader,
+    PcapTime,
+};
 use ::s4lib::readers::summary::{
     Summary,
     SummaryOpt,
@@ -299,6 +362,7 @@ use ::s4lib::readers::syslogprocessor::{
     FileProcessingResultBlockZero,
     SyslogProcessor,
 };
+use ::s4lib::readers::tracev3reader::Tracev3Reader;
 
 #[cfg(feature = "alloc_tracker")]
 use crate::alloc_tracker;
@@ -3118,6 +3182,371 @@ is the local system timezone offset. [Default: "#, CLI_OPT_PREPEND_FMT, "]"),
     )]
     log_message_separator: String,
 
+    /// Print login sessions paired from utmp, utmpx, and wtmp records instead
+    /// of each record, similar to program `last`.
+    /// Login and logout records are paired by ut_line, ut_id, and ut_pid
+    /// across all files. A boot record ends open sessions as a "crash", a
+    /// shutdown record ends open sessions as "down".
+    /// A session is printed when it ends, at the datetime of its end among
+    /// other log messages. So the login datetime of a printed session may be
+    /// earlier than the datetimes of log messages printed before it.
+    /// A session that did not end is printed at the datetime of its login.
+    /// Records from btmp files are printed as a summary of failed logins,
+    /// after the last record of the btmp files.
+    #[clap(
+        long = "utmp-sessions",
+        verbatim_doc_comment,
+        default_value_t = false,
+        env="S4_UTMP_SESSIONS",
+    )]
+    utmp_sessions: bool,
+
+    /// Also print a summary of process accounting acct and pacct records per
+    /// command and per user, similar to program `sa`.
+    /// The summary is the count of processes, total and average CPU seconds,
+    /// elapsed seconds, average memory, and count of abnormal exits, i.e.
+    /// killed by a signal (AXSIG) or dumped core (ACORE).
+    /// Only records within --after and --before are summarized.
+    /// The summary is printed after all other log messages.
+    #[clap(
+        long = "acct-summary",
+        verbatim_doc_comment,
+        default_value_t = false,
+        env="S4_ACCT_SUMMARY",
+    )]
+    acct_summary: bool,
+
+    /// Group Linux audit records into audit events, similar to program
+    /// ausearch. Consecutive records of a file with the same audit serial
+    /// number, e.g. the SYSCALL, EXECVE, CWD, PATH, and PROCTITLE records of
+    /// "msg=audit(1681160194.293:6)", are printed together as one log
+    /// message. Lines that are not audit records are printed as usual.
+    #[clap(
+        long = "audit",
+        verbatim_doc_comment,
+        default_value_t = false,
+        env="S4_AUDIT",
+    )]
+    audit: bool,
+
+    /// Interpret audit records, similar to "ausearch --interpret".
+    /// Decode hex-encoded values, e.g. "proctitle" and the arguments of
+    /// EXECVE records, name x86_64 system call numbers and "arch" values,
+    /// and name user IDs and group IDs from --audit-passwd-file and
+    /// --audit-group-file. Interpreted fields of the "enriched" log format
+    /// are used when present.
+    #[clap(
+        long = "audit-interpret",
+        verbatim_doc_comment,
+        requires = "audit",
+        default_value_t = false,
+        env="S4_AUDIT_INTERPRET",
+    )]
+    audit_interpret: bool,
+
+    /// Only print audit events with a record in this comma-separated list of
+    /// record types, e.g. "SYSCALL,USER_LOGIN".
+    #[clap(
+        long = "audit-type",
+        required = false,
+        verbatim_doc_comment,
+        requires = "audit",
+        env="S4_AUDIT_TYPE",
+    )]
+    audit_type: Option<String>,
+
+    /// Only print audit events with a key in this comma-separated list of
+    /// audit rule keys, i.e. "auditctl -k KEY".
+    #[clap(
+        long = "audit-key",
+        required = false,
+        verbatim_doc_comment,
+        requires = "audit",
+        env="S4_AUDIT_KEY",
+    )]
+    audit_key: Option<String>,
+
+    /// A passwd file for naming user IDs with --audit-interpret,
+    /// e.g. the "/etc/passwd" of the system that wrote the audit log.
+    #[clap(
+        long = "audit-passwd-file",
+        required = false,
+        verbatim_doc_comment,
+        requires = "audit_interpret",
+        env="S4_AUDIT_PASSWD_FILE",
+    )]
+    audit_passwd_file: Option<String>,
+
+    /// A group file for naming group IDs with --audit-interpret,
+    /// e.g. the "/etc/group" of the system that wrote the audit log.
+    #[clap(
+        long = "audit-group-file",
+        required = false,
+        verbatim_doc_comment,
+        requires = "audit_interpret",
+        env="S4_AUDIT_GROUP_FILE",
+    )]
+    audit_group_file: Option<String>,
+
+    /// Reassemble strace output, i.e. of "strace -ttt".
+    /// The "<unfinished ...>" and "<... resumed>" halves of a system call
+    /// interrupted by another thread are joined into one log message,
+    /// with the duration of the system call, printed at the datetime of the
+    /// "<... resumed>" half.
+    /// Lines of files written by "strace -ff -o NAME", i.e. files "NAME.PID",
+    /// are prefixed with the PID of the file name "[pid PID]".
+    /// Lines that are not strace output are printed as usual.
+    #[clap(
+        long = "strace",
+        verbatim_doc_comment,
+        default_value_t = false,
+        conflicts_with = "audit",
+        env="S4_STRACE",
+    )]
+    strace: bool,
+
+    /// Only print strace system calls in this comma-separated list of
+    /// system call names, e.g. "open,openat".
+    #[clap(
+        long = "strace-syscall",
+        required = false,
+        verbatim_doc_comment,
+        requires = "strace",
+        env="S4_STRACE_SYSCALL",
+    )]
+    strace_syscall: Option<String>,
+
+    /// Only print strace lines of a PID in this comma-separated list of
+    /// PIDs, e.g. "1234,1235".
+    #[clap(
+        long = "strace-pid",
+        required = false,
+        verbatim_doc_comment,
+        requires = "strace",
+        env="S4_STRACE_PID",
+    )]
+    strace_pid: Option<String>,
+
+    /// Only print strace system calls that returned an error,
+    /// e.g. "= -1 ENOENT (No such file or directory)".
+    #[clap(
+        long = "strace-failed",
+        verbatim_doc_comment,
+        requires = "strace",
+        default_value_t = false,
+        env="S4_STRACE_FAILED",
+    )]
+    strace_failed: bool,
+
+    /// Only print strace system calls that returned an error in this
+    /// comma-separated list of error names, e.g. "ENOENT,EACCES".
+    #[clap(
+        long = "strace-errno",
+        required = false,
+        verbatim_doc_comment,
+        requires = "strace",
+        env="S4_STRACE_ERRNO",
+    )]
+    strace_errno: Option<String>,
+
+    /// Parse lines of text files as JSON lines, i.e. one JSON object per
+    /// line, and find the datetime by the datetime field, see --json-time.
+    /// "auto" parses lines that are a JSON object if the first lines of the
+    /// file are mostly JSON lines, other lines are searched for a datetime as
+    /// usual.
+    /// "always" only begins a log message with a JSON object line, other
+    /// lines are part of the prior log message.
+    /// "never" does not parse lines as JSON.
+    #[clap(
+        long = "json-lines",
+        required = false,
+        verbatim_doc_comment,
+        value_enum,
+        default_value_t = JsonLinesMode::Auto,
+        env="S4_JSON_LINES",
+    )]
+    json_lines: JsonLinesMode,
+
+    /// Comma-separated list of JSON pointers or top-level keys of the JSON
+    /// lines datetime field, tried in order, e.g. "/@timestamp,/event/created".
+    /// The datetime field may be a datetime string, or a Unix epoch number
+    /// in seconds, milliseconds, microseconds, or nanoseconds.
+    /// [Default: "@timestamp,time,ts,timestamp"]
+    #[clap(
+        long = "json-time",
+        required = false,
+        verbatim_doc_comment,
+        env="S4_JSON_TIME",
+    )]
+    json_time: Option<String>,
+
+    /// Only print JSON lines with a field value, e.g. "level=error" or
+    /// "/http/status=500". The field is a JSON pointer or a top-level key.
+    /// May be passed more than once; all must match.
+    #[clap(
+        long = "json-field",
+        required = false,
+        verbatim_doc_comment,
+    )]
+    json_field: Vec<String>,
+
+    /// The format for JSON lines log messages.
+    /// "json" is the line as it is, "key-value" is the fields as key=value
+    /// pairs, nested keys joined with ".",
+    /// e.g. 'level=info msg="hello world" http.status=200'.
+    #[clap(
+        long = "json-output",
+        required = false,
+        verbatim_doc_comment,
+        value_enum,
+        default_value_t = JsonLineOutput::Json,
+        env="S4_JSON_OUTPUT",
+    )]
+    json_output: JsonLineOutput,
+
+    /// Parse lines of text files as key-value lines, i.e. logfmt, ArcSight CEF,
+    /// or IBM LEEF, and find the datetime by the datetime key, see --kv-time.
+    /// "auto" parses lines that are key-value lines if the first lines of the
+    /// file are mostly key-value lines, other lines are searched for a datetime
+    /// as usual.
+    /// "always" only begins a log message with a key-value line, other
+    /// lines are part of the prior log message.
+    /// "never" does not parse lines as key-value lines.
+    #[clap(
+        long = "kv-lines",
+        required = false,
+        verbatim_doc_comment,
+        value_enum,
+        default_value_t = KeyValueMode::Auto,
+        env="S4_KV_LINES",
+    )]
+    kv_lines: KeyValueMode,
+
+    /// Comma-separated list of keys of the key-value lines datetime value,
+    /// tried in order, e.g. "rt,end".
+    /// The datetime value may be a datetime string, or a Unix epoch number
+    /// in seconds, milliseconds, microseconds, or nanoseconds.
+    /// A LEEF "devTimeFormat" attribute is used for the "devTime" value.
+    /// [Default: "time,ts,timestamp,rt,devTime"]
+    #[clap(
+        long = "kv-time",
+        required = false,
+        verbatim_doc_comment,
+        env="S4_KV_TIME",
+    )]
+    kv_time: Option<String>,
+
+    /// Only print key-value lines with a key value, e.g. "level=error" or
+    /// "src=10.0.0.1". The CEF header fields are keys "cefVersion",
+    /// "deviceVendor", "deviceProduct", "deviceVersion",
+    /// "deviceEventClassId", "name", and "severity". The LEEF header fields
+    /// are keys "leefVersion", "vendor", "product", "version", and "eventId".
+    /// May be passed more than once; all must match.
+    #[clap(
+        long = "kv-field",
+        required = false,
+        verbatim_doc_comment,
+    )]
+    kv_field: Vec<String>,
+
+    /// Comma-separated list of the column names, or column numbers beginning
+    /// at 1, of the datetime of CSV files with a header row, e.g. "date,time"
+    /// or "2". The datetime columns are joined by a space.
+    /// Also selects the datetime columns of W3C extended log files, e.g. IIS
+    /// logs and Windows Firewall "pfirewall.log", when found in the "#Fields"
+    /// directive, otherwise the "date" and "time" columns are used.
+    /// A "#Fields" directive later in the file changes the columns of the
+    /// following lines.
+    #[clap(
+        long = "csv-time-columns",
+        required = false,
+        verbatim_doc_comment,
+        env="S4_CSV_TIME_COLUMNS",
+    )]
+    csv_time_columns: Option<String>,
+
+    /// The column delimiter of CSV files, e.g. "\t" for a tab.
+    /// [Default: ","]
+    #[clap(
+        long = "csv-delimiter",
+        required = false,
+        verbatim_doc_comment,
+        value_parser = cli_parse_csv_delimiter,
+        env="S4_CSV_DELIMITER",
+    )]
+    csv_delimiter: Option<char>,
+
+    /// Parse lines of text files as container log lines, i.e. Kubernetes CRI
+    /// "2024-01-02T03:04:05.123456789Z stdout P …" lines, e.g. under
+    /// "/var/log/pods", and Docker json-file lines. A partial line, i.e. a
+    /// CRI line tagged "P" or a Docker line split at 16 KiB, is joined with
+    /// the following lines of the same stream into one log message, printed
+    /// as one line.
+    /// "auto" parses lines that are container log lines if the first lines of
+    /// the file are mostly container log lines, other lines are searched for a
+    /// datetime as usual.
+    /// "always" only begins a log message with a container log line, other
+    /// lines are part of the prior log message.
+    /// "never" does not parse lines as container log lines.
+    #[clap(
+        long = "container-logs",
+        required = false,
+        verbatim_doc_comment,
+        value_enum,
+        default_value_t = ContainerLogMode::Auto,
+        env="S4_CONTAINER_LOGS",
+    )]
+    container_logs: ContainerLogMode,
+
+    /// Only print container log lines of the stream.
+    #[clap(
+        long = "container-stream",
+        required = false,
+        verbatim_doc_comment,
+        value_enum,
+        env="S4_CONTAINER_STREAM",
+    )]
+    container_stream: Option<ContainerStream>,
+
+    /// Begin a log message with a timestamp line, a line that introduces the
+    /// following lines, e.g. a bash history "#1700000000" line written with
+    /// HISTTIMEFORMAT set, or a zsh history ": 1700000000:0;command" line
+    /// written with EXTENDED_HISTORY set. Only timestamp lines begin a log
+    /// message, so a command with a datetime does not begin a log message.
+    /// "auto" finds a file of bash or zsh history by the first lines of the
+    /// file; the first line and at least a quarter of the first lines must be
+    /// timestamp lines of one format.
+    /// "always" only begins a log message with a timestamp line of any
+    /// format, including a line of only a datetime, e.g.
+    /// "2024-01-02 03:04:05", other lines are part of the prior log message.
+    /// "never" does not parse lines as timestamp lines.
+    #[clap(
+        long = "timestamp-lines",
+        required = false,
+        verbatim_doc_comment,
+        value_enum,
+        default_value_t = TimestampLineMode::Auto,
+        env="S4_TIMESTAMP_LINES",
+    )]
+    timestamp_lines: TimestampLineMode,
+
+    /// Anchor each boot of syslog files with uptime datetimes, e.g. dmesg
+    /// "[  123.456789] …", to the system boot times found in the other
+    /// passed files; utmp, utmpx, and wtmp BOOT_TIME records, the first
+    /// journal entry of each _BOOT_ID, and syslog "kernel: Linux version"
+    /// lines.
+    /// The other files are read an extra time.
+    /// Without this, the last line of an uptime file is presumed written at
+    /// the file modified time.
+    /// Regardless, a jump backwards in uptime is a new boot.
+    #[clap(
+        long = "uptime-boot-times",
+        verbatim_doc_comment,
+        default_value_t = false,
+        env="S4_UPTIME_BOOT_TIMES",
+    )]
+    uptime_boot_times: bool,
+
     /// The format for .journal file log messages.
     /// Matches journalctl --output options.
     #[clap(
@@ -3130,6 +3559,91 @@ is the local system timezone offset. [Default: "#, CLI_OPT_PREPEND_FMT, "]"),
     )]
     journal_output: JournalOutput,
 
+    /// The format for .evtx file log messages.
+    /// "xml" is the XML of the record, "json" and "json-pretty" are the
+    /// record as JSON, "short" is one line per record
+    /// "TIMESTAMP COMPUTER PROVIDER[EventID]: key=value ...".
+    #[clap(
+        long = "evtx-output",
+        required = false,
+        verbatim_doc_comment,
+        value_enum,
+        default_value_t = EvtxOutput::Xml,
+        env="S4_EVTX_OUTPUT",
+    )]
+    evtx_output: EvtxOutput,
+
+    /// Only print .evtx records with an EventID in this comma-separated list
+    /// of EventIDs or EventID ranges, e.g. "4624,4625,4700-4799".
+    #[clap(
+        long = "evtx-event-id",
+        required = false,
+        verbatim_doc_comment,
+        env="S4_EVTX_EVENT_ID",
+    )]
+    evtx_event_id: Option<String>,
+
+    /// Only print .evtx records with a Provider Name in this comma-separated
+    /// list, e.g. "Microsoft-Windows-Security-Auditing".
+    #[clap(
+        long = "evtx-provider",
+        required = false,
+        verbatim_doc_comment,
+        env="S4_EVTX_PROVIDER",
+    )]
+    evtx_provider: Option<String>,
+
+    /// Only print .evtx records with a Channel in this comma-separated list,
+    /// e.g. "Security,System".
+    #[clap(
+        long = "evtx-channel",
+        required = false,
+        verbatim_doc_comment,
+        env="S4_EVTX_CHANNEL",
+    )]
+    evtx_channel: Option<String>,
+
+    /// Only print .evtx records with a Level in this comma-separated list of
+    /// Level numbers or names "logalways", "critical", "error", "warning",
+    /// "information", "verbose", e.g. "critical,error".
+    #[clap(
+        long = "evtx-level",
+        required = false,
+        verbatim_doc_comment,
+        env="S4_EVTX_LEVEL",
+    )]
+    evtx_level: Option<String>,
+
+    /// Only print .evtx records with a Computer in this comma-separated list.
+    #[clap(
+        long = "evtx-computer",
+        required = false,
+        verbatim_doc_comment,
+        env="S4_EVTX_COMPUTER",
+    )]
+    evtx_computer: Option<String>,
+
+    /// Only print .evtx records with an EventData value, e.g.
+    /// "EventData/Data[@Name='TargetUserName']=bob" or "TargetUserName=bob".
+    /// May be passed more than once; all must match.
+    #[clap(
+        long = "evtx-data",
+        required = false,
+        verbatim_doc_comment,
+    )]
+    evtx_data: Vec<String>,
+
+    /// Recover records from damaged .evtx files. Ignore the file header,
+    /// skip chunks with bad checksums, and carve records from damaged or
+    /// truncated chunks, chunk slack space, and areas outside of any chunk.
+    #[clap(
+        long = "evtx-recover",
+        verbatim_doc_comment,
+        default_value_t = false,
+        env="S4_EVTX_RECOVER",
+    )]
+    evtx_recover: bool,
+
     /// For parsing Windows Event Tracing Log (.etl) files, use Python library
     /// etl-parser. By default, Python library dissect.etl is used.
     /// The etl-parser library may have more complete information but is slower
@@ -3145,6 +3659,21 @@ is the local system timezone offset. [Default: "#, CLI_OPT_PREPEND_FMT, "]"),
     )]
     etl_parser: bool,
 
+    /// For pcap and pcapng packet capture files, the datetime of a syslog
+    /// message.
+    /// "capture" is the packet capture timestamp.
+    /// "message" is the RFC 3164 or RFC 5424 timestamp of the syslog message,
+    /// or the packet capture timestamp if the message has no timestamp.
+    #[clap(
+        long = "pcap-time",
+        required = false,
+        verbatim_doc_comment,
+        value_enum,
+        default_value_t = PcapTime::Capture,
+        env="S4_PCAP_TIME",
+    )]
+    pcap_time: PcapTime,
+
     /// Choose to print using colors.
     #[clap(
         required = false,
@@ -3171,8 +3700,6 @@ is the local system timezone offset. [Default: "#, CLI_OPT_PREPEND_FMT, "]"),
 
     /// Create a Python virtual environment exclusively for s4.
     /// This is only necessary for parsing
-    /// Apple System Log (.asl) files,
-    /// OneDrive Log (.odl, .aodl, .odlgz, .odlsent) files, and
     /// Windows Event Tracing Log (.etl) files.
     /// This only needs to be created once.
     /// When this option is used, no other options may be passed.
@@ -3268,6 +3795,21 @@ pub(crate) fn cli_process_tz_offset(tzo: &str) -> std::result::Result<FixedOffse
     Err(format!("Unable to parse a timezone offset for --tz-offset {tzo:?}"))
 }
 
+/// `clap` argument validator for `--csv-delimiter`.
+/// Accepts one character or one backslash escape sequence, e.g. `"\t"`.
+fn cli_parse_csv_delimiter(csv_delimiter: &str) -> std::result::Result<char, String> {
+    defñ!("cli_parse_csv_delimiter({:?})", csv_delimiter);
+    let unescaped: String = match unescape::unescape_str(csv_delimiter) {
+        Ok(val) => val,
+        Err(err) => return Err(format!("{err}")),
+    };
+    let mut chars = unescaped.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii() => Ok(c),
+        _ => Err(format!("Unable to parse one ASCII character for --csv-delimiter {:?}", csv_delimiter)),
+    }
+}
+
 /// `clap` argument validator for `--prepend-dt-format`.
 ///
 /// Returning `Ok(None)` means that the user did not pass a value for
@@ -3896,6 +4438,27 @@ fn cli_process_args() -> (
     EtlParserUsed,
     bool,
     JournalOutput,
+    EvtxOutput,
+    EvtxFilter,
+    bool,
+    bool,
+    bool,
+    bool,
+    AuditFilter,
+    Option<AuditInterpreter>,
+    bool,
+    StraceFilter,
+    Option<JsonLineTime>,
+    JsonLineFilter,
+    JsonLineOutput,
+    Option<KeyValueTime>,
+    KeyValueFilter,
+    ColumnarTime,
+    ContainerLogMode,
+    Option<ContainerStream>,
+    TimestampLineMode,
+    PcapTime,
+    bool,
     bool,
 ) {
     let args = CLI_Args::parse();
@@ -4113,6 +4676,109 @@ fn cli_process_args() -> (
     defo!("etl_parser_used {:?}", etl_parser_used);
     defo!("python_venv {:?}", args.python_venv);
     defo!("journal_output {:?}", args.journal_output);
+    defo!("evtx_output {:?}", args.evtx_output);
+
+    let evtx_filter: EvtxFilter = match EvtxFilter::new(
+        args.evtx_event_id.as_deref(),
+        args.evtx_provider.as_deref(),
+        args.evtx_channel.as_deref(),
+        args.evtx_level.as_deref(),
+        args.evtx_computer.as_deref(),
+        &args.evtx_data,
+    ) {
+        Ok(evtx_filter) => evtx_filter,
+        Err(err) => {
+            e_err!("{}", err);
+            std::process::exit(EXIT_ERR);
+        }
+    };
+    defo!("evtx_filter {:?}", evtx_filter);
+    defo!("evtx_recover {:?}", args.evtx_recover);
+    defo!("utmp_sessions {:?}", args.utmp_sessions);
+    defo!("acct_summary {:?}", args.acct_summary);
+
+    let audit_filter: AuditFilter = AuditFilter::new(
+        args.audit_type.as_deref(),
+        args.audit_key.as_deref(),
+    );
+    let audit_interpreter: Option<AuditInterpreter> = match args.audit_interpret {
+        true => {
+            let mut datas: [Option<String>; 2] = [None, None];
+            for (data, path) in datas
+                .iter_mut()
+                .zip([&args.audit_passwd_file, &args.audit_group_file])
+            {
+                if let Some(path) = path {
+                    match std::fs::read(path) {
+                        Ok(bytes) => *data = Some(String::from_utf8_lossy(&bytes).into_owned()),
+                        Err(err) => {
+                            e_err!("{} for {:?}", err, path);
+                            std::process::exit(EXIT_ERR);
+                        }
+                    }
+                }
+            }
+            Some(AuditInterpreter::new(datas[0].as_deref(), datas[1].as_deref()))
+        }
+        false => None,
+    };
+    defo!("audit {:?}", args.audit);
+    defo!("audit_filter {:?}", audit_filter);
+    defo!("audit_interpreter {:?}", audit_interpreter.is_some());
+
+    let strace_filter: StraceFilter = match StraceFilter::new(
+        args.strace_syscall.as_deref(),
+        args.strace_pid.as_deref(),
+        args.strace_failed,
+        args.strace_errno.as_deref(),
+    ) {
+        Ok(strace_filter) => strace_filter,
+        Err(err) => {
+            e_err!("{}", err);
+            std::process::exit(EXIT_ERR);
+        }
+    };
+    defo!("strace {:?}", args.strace);
+    defo!("strace_filter {:?}", strace_filter);
+
+    let jsonline_time: Option<JsonLineTime> = JsonLineTime::new(args.json_lines, args.json_time.as_deref());
+    let jsonline_filter: JsonLineFilter = match JsonLineFilter::new(&args.json_field) {
+        Ok(jsonline_filter) => jsonline_filter,
+        Err(err) => {
+            e_err!("{}", err);
+            std::process::exit(EXIT_ERR);
+        }
+    };
+    defo!("json_lines {:?}", args.json_lines);
+    defo!("jsonline_time {:?}", jsonline_time);
+    defo!("jsonline_filter {:?}", jsonline_filter);
+    defo!("json_output {:?}", args.json_output);
+
+    let keyvalue_time: Option<KeyValueTime> = KeyValueTime::new(args.kv_lines, args.kv_time.as_deref());
+    let keyvalue_filter: KeyValueFilter = match KeyValueFilter::new(&args.kv_field) {
+        Ok(keyvalue_filter) => keyvalue_filter,
+        Err(err) => {
+            e_err!("{}", err);
+            std::process::exit(EXIT_ERR);
+        }
+    };
+    defo!("kv_lines {:?}", args.kv_lines);
+    defo!("keyvalue_time {:?}", keyvalue_time);
+    defo!("keyvalue_filter {:?}", keyvalue_filter);
+
+    let columnar_time: ColumnarTime = match ColumnarTime::new(args.csv_time_columns.as_deref(), args.csv_delimiter) {
+        Ok(columnar_time) => columnar_time,
+        Err(err) => {
+            e_err!("{}", err);
+            std::process::exit(EXIT_ERR);
+        }
+    };
+    defo!("columnar_time {:?}", columnar_time);
+    defo!("container_logs {:?}", args.container_logs);
+    defo!("container_stream {:?}", args.container_stream);
+    defo!("timestamp_lines {:?}", args.timestamp_lines);
+    defo!("pcap_time {:?}", args.pcap_time);
+    defo!("uptime_boot_times {:?}", args.uptime_boot_times);
     defo!("summary {:?}", args.summary);
 
     (
@@ -4132,6 +4798,27 @@ fn cli_process_args() -> (
         etl_parser_used,
         args.python_venv,
         args.journal_output,
+        args.evtx_output,
+        evtx_filter,
+        args.evtx_recover,
+        args.utmp_sessions,
+        args.acct_summary,
+        args.audit,
+        audit_filter,
+        audit_interpreter,
+        args.strace,
+        strace_filter,
+        jsonline_time,
+        jsonline_filter,
+        args.json_output,
+        keyvalue_time,
+        keyvalue_filter,
+        columnar_time,
+        args.container_logs,
+        args.container_stream,
+        args.timestamp_lines,
+        args.pcap_time,
+        args.uptime_boot_times,
         args.summary,
     )
 }
@@ -4171,6 +4858,27 @@ pub fn main() -> ExitCode {
         etl_parser_used,
         python_venv,
         journal_output,
+        evtx_output,
+        evtx_filter,
+        evtx_recover,
+        cli_opt_utmp_sessions,
+        cli_opt_acct_summary,
+        cli_opt_audit,
+        audit_filter,
+        audit_interpreter,
+        cli_opt_strace,
+        strace_filter,
+        jsonline_time,
+        jsonline_filter,
+        jsonline_output,
+        keyvalue_time,
+        keyvalue_filter,
+        columnar_time,
+        containerlog_mode,
+        container_stream,
+        timestampline_mode,
+        pcap_time,
+        cli_opt_uptime_boot_times,
         cli_opt_summary,
     ) = cli_process_args();
 
@@ -4219,6 +4927,27 @@ pub fn main() -> ExitCode {
         log_message_separator,
         etl_parser_used,
         journal_output,
+        evtx_output,
+        evtx_filter,
+        evtx_recover,
+        cli_opt_utmp_sessions,
+        cli_opt_acct_summary,
+        cli_opt_audit,
+        audit_filter,
+        audit_interpreter,
+        cli_opt_strace,
+        strace_filter,
+        jsonline_time,
+        jsonline_filter,
+        jsonline_output,
+        keyvalue_time,
+        keyvalue_filter,
+        columnar_time,
+        containerlog_mode,
+        container_stream,
+        timestampline_mode,
+        pcap_time,
+        cli_opt_uptime_boot_times,
         cli_opt_summary,
         start_time,
     );
@@ -4271,9 +5000,19 @@ pub enum FileTypeExecData {
     None,
     /// Journal processing thread needs to know the journal output format
     Journal(JournalOutput),
+    /// Evt and Evtx processing thread needs to know the evtx output format,
+    /// the evtx record filters, and if in recovery mode
+    Evtx(EvtxOutput, EvtxFilter, bool),
     /// Windows Event Trace Log processing thread needs to know the
     /// python library to use
     Etl(EtlParserUsed),
+    /// Syslog processing thread needs to know the system boot times for
+    /// files with uptime datetimes, and how to find the datetime of
+    /// JSON lines, key-value lines, and columnar files
+    Syslog(BootTimes, Option<JsonLineTime>, Option<KeyValueTime>, ColumnarTime, ContainerLogMode, TimestampLineMode),
+    /// Pcap processing thread needs to know which datetime of a syslog
+    /// message is the event datetime
+    Pcap(PcapTime),
 }
 
 /// Data to initialize a file processing thread.
@@ -4396,6 +5135,47 @@ fn chan_send(
     }
 }
 
+/// Helper to send a [`Sysline`] in a [`ChanDatum::NewMessage`] to the main
+/// printing thread. With a [`ContainerLogJoiner`], send the `Sysline`s it
+/// releases instead; the last released `Sysline` is sent with `is_last`.
+///
+/// [`Sysline`]: s4lib::data::sysline::Sysline
+fn chan_send_sysline(
+    chan_send_dt: &ChanSendDatum,
+    containerlog_joiner: Option<&mut ContainerLogJoiner>,
+    syslinep: SyslineP,
+    is_last: IsLastLogMessage,
+    path: &FPath,
+) -> bool {
+    let containerlog_joiner: &mut ContainerLogJoiner = match containerlog_joiner {
+        Some(containerlog_joiner) => containerlog_joiner,
+        None => {
+            return chan_send(
+                chan_send_dt,
+                ChanDatum::NewMessage(LogMessage::Sysline(syslinep), is_last),
+                path,
+            );
+        }
+    };
+    let mut syslineps: Vec<SyslineP> = containerlog_joiner.push(syslinep);
+    if is_last {
+        syslineps.extend(containerlog_joiner.finish());
+    }
+    let count: usize = syslineps.len();
+    for (at, syslinep) in syslineps.into_iter().enumerate() {
+        let is_last_: IsLastLogMessage = is_last && at + 1 == count;
+        if !chan_send(
+            chan_send_dt,
+            ChanDatum::NewMessage(LogMessage::Sysline(syslinep), is_last_),
+            path,
+        ) {
+            return false;
+        }
+    }
+
+    true
+}
+
 /// This creates a [`SyslogProcessor`] and processes the file.<br/>
 /// If it is a syslog file, then continues processing by sending each
 /// processed [`Sysline`]  in a [`ChanDatum`] through a [channel] to the main
@@ -4420,14 +5200,14 @@ fn exec_syslogprocessor(
         path,
         pathid,
         filetype,
-        _filetypeexecdata,
+        filetypeexecdata,
         blocksz,
         filter_dt_after_opt,
         filter_dt_before_opt,
         tz_offset,
     ) = thread_init_data;
     defn!("({:?})", path);
-    debug_assert!(matches!(_filetypeexecdata, FileTypeExecData::None));
+    debug_assert!(matches!(filetypeexecdata, FileTypeExecData::None | FileTypeExecData::Syslog(..)));
 
     exit_early_return!();
 
@@ -4474,6 +5254,22 @@ fn exec_syslogprocessor(
             return;
         }
     };
+    if let FileTypeExecData::Syslog(
+        boot_times,
+        jsonline_time,
+        keyvalue_time,
+        columnar_time,
+        containerlog_mode,
+        timestampline_mode,
+    ) = filetypeexecdata
+    {
+        syslogproc.set_boot_times(boot_times);
+        syslogproc.set_jsonline_time(jsonline_time);
+        syslogproc.set_keyvalue_time(keyvalue_time);
+        syslogproc.set_columnar_time(Some(columnar_time));
+        syslogproc.set_containerlog_mode(containerlog_mode);
+        syslogproc.set_timestampline_mode(timestampline_mode);
+    }
     deo!("{:?}({}): syslogproc {:?}", _tid, _tname, syslogproc);
 
     // send `ChanDatum::FileInfo`
@@ -4508,6 +5304,12 @@ fn exec_syslogprocessor(
         }
     }
 
+    // the partial lines of a container log file are joined before sending
+    let mut containerlog_joiner: Option<ContainerLogJoiner> = match syslogproc.containerlog_mode() {
+        ContainerLogMode::Never => None,
+        ContainerLogMode::Auto | ContainerLogMode::Always => Some(ContainerLogJoiner::new()),
+    };
+
     deo!("{:?}({}): processing stage 2", _tid, _tname);
 
     // find first sysline acceptable to the passed filters
@@ -4537,12 +5339,11 @@ fn exec_syslogprocessor(
             fo1 = fo;
             let is_last: IsLastLogMessage = syslogproc.is_sysline_last(&syslinep) as IsLastLogMessage;
             deo!("{:?}({}): Found, chan_send_dt.send({:p}, None, {});", _tid, _tname, syslinep, is_last);
-            if !chan_send(
+            if !chan_send_sysline(
                 &chan_send_dt,
-                ChanDatum::NewMessage(
-                    LogMessage::Sysline(syslinep),
-                    is_last,
-                ),
+                containerlog_joiner.as_mut(),
+                syslinep,
+                is_last,
                 &path
             ) {
                 defx!("({:?}) return early during stage 2, chan_send_dt.send failed", path);
@@ -4602,12 +5403,11 @@ fn exec_syslogprocessor(
             ResultFindSysline::Found((fo, syslinep)) => {
                 let syslinep_tmp = syslinep.clone();
                 let is_last: IsLastLogMessage = syslogproc.is_sysline_last(&syslinep);
-                if !chan_send(
+                if !chan_send_sysline(
                     &chan_send_dt,
-                    ChanDatum::NewMessage(
-                        LogMessage::Sysline(syslinep),
-                        is_last,
-                    ),
+                    containerlog_joiner.as_mut(),
+                    syslinep,
+                    is_last,
                     &path
                 ) {
                     defx!("({:?}) return early during stage 3, chan_send_dt.send failed", path);
@@ -4641,6 +5441,23 @@ fn exec_syslogprocessor(
         }
     }
 
+    // send the container log messages still held
+    if let Some(containerlog_joiner) = containerlog_joiner.as_mut() {
+        for syslinep in containerlog_joiner.finish() {
+            if !chan_send(
+                &chan_send_dt,
+                ChanDatum::NewMessage(
+                    LogMessage::Sysline(syslinep),
+                    false,
+                ),
+                &path
+            ) {
+                defx!("({:?}) return early during stage 3, chan_send_dt.send failed", path);
+                return;
+            }
+        }
+    }
+
     deo!("{:?}({}): processing stage 4", _tid, _tname);
 
     syslogproc.process_stage4_summary();
@@ -4941,20 +5758,31 @@ fn exec_evtxprocessor(
         path,
         pathid,
         filetype,
-        _filetypeexecdata,
+        filetypeexecdata,
         _blocksz,
         filter_dt_after_opt,
         filter_dt_before_opt,
         tz_offset,
     ) = thread_init_data;
     defn!("{:?}({}): ({:?}, {:?}, {:?})", _tid, _tname, path, filetype, tz_offset);
-    debug_assert!(filetype.is_evtx());
-    debug_assert!(matches!(_filetypeexecdata, FileTypeExecData::None));
+    debug_assert!(filetype.is_evt() || filetype.is_evtx());
+    debug_assert!(matches!(filetypeexecdata, FileTypeExecData::Evtx(..)));
     exit_early_return!();
 
+    let (evtx_output, evtx_filter, evtx_recover): (EvtxOutput, EvtxFilter, bool) = match filetypeexecdata {
+        FileTypeExecData::Evtx(evtx_output, evtx_filter, evtx_recover) => (evtx_output, evtx_filter, evtx_recover),
+        _ => {
+            e_err!("filetypeexecdata is not Evtx which is unexpected");
+            defx!("({:?}) return early due filetypeexecdata is not Evtx", path);
+            return;
+        }
+    };
+
     let mut evtxreader: EvtxReader = match EvtxReader::new(
         pathid,
         path.clone(),
+        evtx_output,
+        evtx_recover,
         filetype,
         tz_offset,
     ) {
@@ -5001,6 +5829,7 @@ fn exec_evtxprocessor(
     evtxreader.analyze(
         &filter_dt_after_opt,
         &filter_dt_before_opt,
+        &evtx_filter,
     );
 
     while !exit_early() && let Some(evtx) = evtxreader.next()
@@ -5034,6 +5863,240 @@ fn exec_evtxprocessor(
     defx!("({:?})", path);
 }
 
+/// This function drives a [`PyDataEventReader`] instance through it's
+/// processing. The reader is created by `new_reader` and each event is sent
+/// as a [`LogMessage::PyEvent`] of `pyeventtype`.
+/// Similar to [`exec_pyeventprocessor`].
+fn exec_pydataeventprocessor<R, F>(
+    chan_send_dt: ChanSendDatum,
+    thread_init_data: ThreadInitData,
+    pyeventtype: PyEventType,
+    new_reader: F,
+    _tname: &str,
+    _tid: thread::ThreadId,
+)
+where
+    R: PyDataEventReader + std::fmt::Debug,
+    F: FnOnce(PathId, FPath, FileType, FixedOffset) -> Result<R, Error>,
+{
+    let (
+        path,
+        pathid,
+        filetype,
+        _filetypeexecdata,
+        _blocksz,
+        filter_dt_after_opt,
+        filter_dt_before_opt,
+        tz_offset,
+    ) = thread_init_data;
+    defn!("{:?}({}): ({:?}, {:?}, {:?}, {:?})", _tid, _tname, path, filetype, pyeventtype, tz_offset);
+
+    exit_early_return!();
+
+    let mut reader: R = match new_reader(
+        pathid,
+        path.clone(),
+        filetype,
+        tz_offset,
+    ) {
+        Ok(val) => val,
+        Err(err) => {
+            let err_string = err.to_string();
+            // send `ChanDatum::FileInfo`
+            chan_send(
+                &chan_send_dt,
+                ChanDatum::FileInfo(
+                    DateTimeLOpt::None,
+                    FileProcessingResultBlockZero::FileErrIo(err)
+                ),
+                &path
+            );
+            // send `ChanDatum::FileSummary`
+            let summary = Summary::new_failed(
+                path.clone(),
+                filetype,
+                LogMessageType::PyEvent,
+                0,
+                Some(err_string)
+            );
+            chan_send(
+                &chan_send_dt,
+                ChanDatum::FileSummary(Some(summary), FILEERRSTUB),
+                &path
+            );
+            defx!("({:?}) thread will return early due to error", path);
+            return;
+        }
+    };
+    defo!("{:?}({}): reader {:?}", _tid, _tname, reader);
+
+    // send `ChanDatum::FileInfo`
+    let mtime = reader.mtime();
+    let dt = systemtime_to_datetime(&tz_offset, &mtime);
+    chan_send(
+        &chan_send_dt,
+        ChanDatum::FileInfo(DateTimeLOpt::Some(dt), FILEOK),
+        &path
+    );
+
+    let mut result_err: Option<FileProcessingResult<Error>> = None;
+    // call reader.next() until exhausted
+    loop {
+        exit_early_return!();
+        match reader.next(&filter_dt_after_opt, &filter_dt_before_opt) {
+            ResultNextPyDataEvent::Found(event) => {
+                def1o!("ResultNextPyDataEvent::Found({} bytes); chan_send()…", event.len());
+                if !chan_send(
+                    &chan_send_dt,
+                    ChanDatum::NewMessage(
+                        LogMessage::PyEvent(event, pyeventtype),
+                        false,
+                    ),
+                    &path
+                ) {
+                    defx!("({:?}) return early during reader.next() loop, chan_send_dt.send failed", path);
+                    return;
+                }
+            }
+            ResultNextPyDataEvent::Done => {
+                def1o!("ResultNextPyDataEvent::Done");
+                break;
+            }
+            ResultNextPyDataEvent::Err(err) => {
+                def1o!("ResultNextPyDataEvent::Err({:?})", err);
+                de_err!("{:?} reader.next(…) returned {}", pyeventtype, err);
+                result_err = Some(FileProcessingResult::FileErrIo(err));
+                break;
+            }
+            ResultNextPyDataEvent::ErrIgnore(_err) => {
+                def1o!("ResultNextPyDataEvent::ErrIgnore({:?})", _err);
+                de_err!("{:?} reader.next(…) returned {} (Ignored)", pyeventtype, _err);
+            }
+        }
+    };
+
+    exit_early_return!();
+
+    let summary = reader.summary_complete();
+    chan_send(
+        &chan_send_dt,
+        ChanDatum::FileSummary(
+            Some(summary),
+            result_err.unwrap_or(FILEOK),
+        ),
+        &path
+    );
+
+    defx!("({:?})", path);
+}
+
+/// This function drives a [`AslReader`] instance through it's processing.
+fn exec_aslprocessor(
+    chan_send_dt: ChanSendDatum,
+    thread_init_data: ThreadInitData,
+    tname: &str,
+    tid: thread::ThreadId,
+) {
+    debug_assert!(thread_init_data.2.is_asl());
+
+    exec_pydataeventprocessor(
+        chan_send_dt,
+        thread_init_data,
+        PyEventType::Asl,
+        AslReader::new,
+        tname,
+        tid,
+    );
+}
+
+/// This function drives a [`OdlReader`] instance through it's processing.
+fn exec_odlprocessor(
+    chan_send_dt: ChanSendDatum,
+    thread_init_data: ThreadInitData,
+    tname: &str,
+    tid: thread::ThreadId,
+) {
+    debug_assert!(thread_init_data.2.is_odl());
+
+    exec_pydataeventprocessor(
+        chan_send_dt,
+        thread_init_data,
+        PyEventType::Odl,
+        OdlReader::new,
+        tname,
+        tid,
+    );
+}
+
+/// This function drives a [`LogcatReader`] instance through it's processing.
+fn exec_logcatprocessor(
+    chan_send_dt: ChanSendDatum,
+    thread_init_data: ThreadInitData,
+    tname: &str,
+    tid: thread::ThreadId,
+) {
+    debug_assert!(thread_init_data.2.is_logcat());
+
+    exec_pydataeventprocessor(
+        chan_send_dt,
+        thread_init_data,
+        PyEventType::Logcat,
+        LogcatReader::new,
+        tname,
+        tid,
+    );
+}
+
+/// This function drives a [`PcapReader`] instance through it's processing.
+fn exec_pcapprocessor(
+    chan_send_dt: ChanSendDatum,
+    thread_init_data: ThreadInitData,
+    tname: &str,
+    tid: thread::ThreadId,
+) {
+    debug_assert!(thread_init_data.2.is_pcap());
+    debug_assert!(matches!(thread_init_data.3, FileTypeExecData::Pcap(_)));
+
+    let pcap_time: PcapTime = match thread_init_data.3 {
+        FileTypeExecData::Pcap(pcap_time) => pcap_time,
+        _ => {
+            e_err!("filetypeexecdata is not Pcap which is unexpected");
+            defñ!("({:?}) return early due filetypeexecdata is not Pcap", thread_init_data.0);
+            return;
+        }
+    };
+
+    exec_pydataeventprocessor(
+        chan_send_dt,
+        thread_init_data,
+        PyEventType::Pcap,
+        |pathid, path, filetype, tz_offset| {
+            PcapReader::new(pathid, path, filetype, tz_offset, pcap_time)
+        },
+        tname,
+        tid,
+    );
+}
+
+/// This function drives a [`Tracev3Reader`] instance through it's processing.
+fn exec_tracev3processor(
+    chan_send_dt: ChanSendDatum,
+    thread_init_data: ThreadInitData,
+    tname: &str,
+    tid: thread::ThreadId,
+) {
+    debug_assert!(thread_init_data.2.is_tracev3());
+
+    exec_pydataeventprocessor(
+        chan_send_dt,
+        thread_init_data,
+        PyEventType::Tracev3,
+        Tracev3Reader::new,
+        tname,
+        tid,
+    );
+}
+
 /// This function drives a [`PyEventReader`] instance through it's processing.
 /// Similar to [`exec_syslogprocessor`].
 fn exec_pyeventprocessor(
@@ -5053,14 +6116,17 @@ fn exec_pyeventprocessor(
         tz_offset,
     ) = thread_init_data;
     defn!("{:?}({}): ({:?}, {:?}, {:?})", _tid, _tname, path, filetype, tz_offset);
-    debug_assert!(filetype.is_etl() || filetype.is_odl() || filetype.is_asl());
+    debug_assert!(filetype.is_etl());
 
     exit_early_return!();
 
     let etl_parser_used: Option<EtlParserUsed> = match filetypeexecdata {
         FileTypeExecData::Etl(etl_parser_used) => Some(etl_parser_used),
         FileTypeExecData::None => None,
-        FileTypeExecData::Journal { .. } => {
+        FileTypeExecData::Journal { .. }
+        | FileTypeExecData::Evtx { .. }
+        | FileTypeExecData::Pcap { .. }
+        | FileTypeExecData::Syslog { .. } => {
             debug_panic!(
                 "exec_pyeventprocessor called with filetypeexecdata {:?} for path {:?}",
                 filetypeexecdata, path
@@ -5343,6 +6409,29 @@ fn exec_journalprocessor(
     defx!("({:?})", path);
 }
 
+/// Return a [`PrinterLogMessage`] for log messages printed after the main
+/// processing loop. These log messages are not from any one file so there is
+/// no prepended file name and the color is the default color.
+fn printer_after_loop(
+    color_choice: ColorChoice,
+    cli_prepend_dt_format: &Option<String>,
+    cli_prepend_separator: &str,
+    cli_opt_prepend_offset: FixedOffset,
+) -> PrinterLogMessage {
+    let prepend_date_format: Option<String> = cli_prepend_dt_format
+        .clone()
+        .map(|s| s + cli_prepend_separator);
+
+    PrinterLogMessage::new(
+        color_choice,
+        color_default(),
+        FileTypeTextEncoding::Utf8Ascii,
+        None,
+        prepend_date_format,
+        cli_opt_prepend_offset,
+    )
+}
+
 /// Thread entry point for processing one file. Calls the correct `exec_*` function.
 fn exec_fileprocessor_thread(
     chan_send_dt: ChanSendDatum,
@@ -5369,13 +6458,17 @@ fn exec_fileprocessor_thread(
     let tname: &str = "";
 
     match thread_init_data.2 {
-        FileType::Asl { .. } => exec_pyeventprocessor(chan_send_dt, thread_init_data, tname, tid),
+        FileType::Asl { .. } => exec_aslprocessor(chan_send_dt, thread_init_data, tname, tid),
         FileType::FixedStruct { .. } => exec_fixedstructprocessor(chan_send_dt, thread_init_data, tname, tid),
         FileType::Etl { .. } => exec_pyeventprocessor(chan_send_dt, thread_init_data, tname, tid),
+        FileType::Evt { .. } => exec_evtxprocessor(chan_send_dt, thread_init_data, tname, tid),
         FileType::Evtx { .. } => exec_evtxprocessor(chan_send_dt, thread_init_data, tname, tid),
         FileType::Journal { .. } => exec_journalprocessor(chan_send_dt, thread_init_data, tname, tid),
-        FileType::Odl { .. } => exec_pyeventprocessor(chan_send_dt, thread_init_data, tname, tid),
+        FileType::Logcat { .. } => exec_logcatprocessor(chan_send_dt, thread_init_data, tname, tid),
+        FileType::Odl { .. } => exec_odlprocessor(chan_send_dt, thread_init_data, tname, tid),
+        FileType::Pcap { .. } => exec_pcapprocessor(chan_send_dt, thread_init_data, tname, tid),
         FileType::Text { .. } => exec_syslogprocessor(chan_send_dt, thread_init_data, tname, tid),
+        FileType::Tracev3 { .. } => exec_tracev3processor(chan_send_dt, thread_init_data, tname, tid),
         FileType::Unparsable
         => {
             debug_panic!("exec_fileprocessor_thread called with unexpected filetype {:?}", thread_init_data.2);
@@ -5423,11 +6516,32 @@ fn processing_loop(
     log_message_separator: String,
     etl_parser_used: EtlParserUsed,
     journal_output: JournalOutput,
+    evtx_output: EvtxOutput,
+    evtx_filter: EvtxFilter,
+    evtx_recover: bool,
+    cli_opt_utmp_sessions: bool,
+    cli_opt_acct_summary: bool,
+    cli_opt_audit: bool,
+    audit_filter: AuditFilter,
+    audit_interpreter: Option<AuditInterpreter>,
+    cli_opt_strace: bool,
+    strace_filter: StraceFilter,
+    jsonline_time: Option<JsonLineTime>,
+    jsonline_filter: JsonLineFilter,
+    jsonline_output: JsonLineOutput,
+    keyvalue_time: Option<KeyValueTime>,
+    keyvalue_filter: KeyValueFilter,
+    columnar_time: ColumnarTime,
+    containerlog_mode: ContainerLogMode,
+    container_stream: Option<ContainerStream>,
+    timestampline_mode: TimestampLineMode,
+    pcap_time: PcapTime,
+    cli_opt_uptime_boot_times: bool,
     cli_opt_summary: bool,
     start_time: Instant,
 ) -> bool {
     defn!(
-        "({:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?})",
+        "({:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?})",
         paths_results,
         blocksz,
         filter_dt_after_opt,
@@ -5436,6 +6550,26 @@ fn processing_loop(
         cli_opt_prepend_offset,
         cli_prepend_dt_format,
         journal_output,
+        evtx_output,
+        evtx_filter,
+        evtx_recover,
+        cli_opt_utmp_sessions,
+        cli_opt_acct_summary,
+        cli_opt_audit,
+        audit_filter,
+        cli_opt_strace,
+        strace_filter,
+        jsonline_time,
+        jsonline_filter,
+        jsonline_output,
+        keyvalue_time,
+        keyvalue_filter,
+        columnar_time,
+        containerlog_mode,
+        container_stream,
+        timestampline_mode,
+        pcap_time,
+        cli_opt_uptime_boot_times,
         cli_opt_summary
     );
 
@@ -5626,6 +6760,43 @@ fn processing_loop(
         }
     }
 
+    // gather system boot times for syslog files with uptime datetimes
+    let boot_times: BootTimes = match cli_opt_uptime_boot_times {
+        true => {
+            let mut boot_times = BootTimes::new();
+            for (pathid, processpathresult) in map_pathid_results.iter() {
+                if let ProcessPathResult::FileValid(path, filetype) = processpathresult {
+                    match boot_times_path(*pathid, path, *filetype, blocksz, tz_offset) {
+                        Ok(boot_times_) => boot_times.extend(boot_times_),
+                        // the file processing thread will report the error
+                        Err(_err) => de_err!("boot_times_path({:?}) failed {}", path, _err),
+                    }
+                }
+            }
+            boot_times_merge(boot_times)
+        }
+        false => BootTimes::with_capacity(0),
+    };
+    defo!("boot_times {:?}", boot_times);
+
+    // gather the `--utmp-sessions` that never end so they are printed at
+    // their login
+    let utmpsessions_still_open_: Vec<UtmpSession> = match cli_opt_utmp_sessions {
+        true => {
+            let paths: Vec<(PathId, FPath, FileType)> = map_pathid_results
+                .iter()
+                .filter_map(|(pathid, processpathresult)| match processpathresult {
+                    ProcessPathResult::FileValid(path, filetype @ FileType::FixedStruct { .. })
+                        if !fpath_is_btmp(path) => Some((*pathid, path.clone(), *filetype)),
+                    _ => None,
+                })
+                .collect();
+            utmpsessions_still_open(&paths, blocksz, tz_offset, filter_dt_after_opt, filter_dt_before_opt)
+        }
+        false => Vec::with_capacity(0),
+    };
+    defo!("utmpsessions_still_open_ {:?}", utmpsessions_still_open_);
+
     // special channel for signal handling, i.e. ctrl+c handling
     let (signal_send, signal_recv) = crossbeam_channel::bounded::<()>(1);
     if !map_pathid_path.is_empty() {
@@ -5715,6 +6886,17 @@ fn processing_loop(
         let filetypeexecdata = match filetype {
             FileType::Etl { .. } => FileTypeExecData::Etl(etl_parser_used),
             FileType::Journal { .. } => FileTypeExecData::Journal(journal_output),
+            FileType::Pcap { .. } => FileTypeExecData::Pcap(pcap_time),
+            FileType::Evt { .. }
+            | FileType::Evtx { .. } => FileTypeExecData::Evtx(evtx_output, evtx_filter.clone(), evtx_recover),
+            FileType::Text { .. } => FileTypeExecData::Syslog(
+                boot_times.clone(),
+                jsonline_time.clone(),
+                keyvalue_time.clone(),
+                columnar_time.clone(),
+                containerlog_mode,
+                timestampline_mode,
+            ),
             _ => FileTypeExecData::None,
         };
         let thread_data: ThreadInitData = (
@@ -5738,10 +6920,14 @@ fn processing_loop(
                 12 * 1024 * 1024, // 12 MB
             (false, FileType::Asl {..})
             | (false, FileType::Etl {..})
-            | (false, FileType::Odl {..}) =>
+            | (false, FileType::Odl {..})
+            | (false, FileType::Logcat {..})
+            | (false, FileType::Pcap {..})
+            | (false, FileType::Tracev3 {..}) =>
                 // experiments showed 114Ki, not compressed
                 1024 * 134,
-            (false, FileType::Evtx {..}) =>
+            (false, FileType::Evt {..})
+            | (false, FileType::Evtx {..}) =>
                 // experiments showed 108 KiB, not compressed
                 1024 * 128,
             (false, FileType::FixedStruct {..}) =>
@@ -5988,6 +7174,38 @@ fn processing_loop(
     // buffer to assist printing FixedStruct; passed to `FixedStruct::as_bytes`
     let mut buffer_utmp: [u8; ENTRY_SZ_MAX * 2] = [0; ENTRY_SZ_MAX * 2];
 
+    // `--utmp-sessions` pairs utmp records of all files into sessions,
+    // and summarizes btmp records
+    let mut utmpsession_tracker: UtmpSessionTracker = UtmpSessionTracker::new();
+    utmpsession_tracker.set_still_open(utmpsessions_still_open_);
+    let mut failed_logins: FailedLogins = FailedLogins::new();
+    // btmp files not yet done; the failed logins are printed when the last
+    // btmp file is done
+    let mut btmp_pathids: HashSet<PathId> = match cli_opt_utmp_sessions {
+        true => map_pathid_path
+            .iter()
+            .filter(|(pathid, path)| {
+                fpath_is_btmp(path)
+                && matches!(map_pathid_filetype.get(pathid), Some(FileType::FixedStruct { .. }))
+            })
+            .map(|(pathid, _path)| *pathid)
+            .collect(),
+        false => HashSet::with_capacity(0),
+    };
+    // `--acct-summary` summarizes acct records of all files
+    let mut acct_summary: AcctSummary = AcctSummary::new();
+    // `--audit` groups audit records of each file into audit events
+    let mut audit_grouper: AuditGrouper = AuditGrouper::new();
+    // `--strace` joins interrupted system calls of each file
+    let mut strace_grouper: StraceGrouper = StraceGrouper::new();
+    // is the file a container log file? set by the first `Sysline` of the file
+    let mut map_pathid_containerlog = HashMap::<PathId, bool>::with_capacity(file_count);
+    if cli_opt_strace {
+        for (pathid, path) in map_pathid_path.iter() {
+            strace_grouper.set_path_pid(*pathid, strace_path_pid(path));
+        }
+    }
+
     loop {
         disconnect.clear();
 
@@ -6511,8 +7729,64 @@ fn processing_loop(
                         summaryprinted.summaryprint_update_evtx(evtx, printed, flushed);
                     }
                 }
+                LogMessage::FixedStruct(entry)
+                    if cli_opt_utmp_sessions
+                    && matches!(
+                        entry.filetypefixedstruct,
+                        FileTypeFixedStruct::Utmp | FileTypeFixedStruct::Utmpx
+                    )
+                => {
+                    defo!("A3 FixedStruct utmp session PathId: {:?}", pathid);
+                    if map_pathid_path.get(pathid).is_some_and(fpath_is_btmp) {
+                        failed_logins.process(entry);
+                    }
+                    else {
+                        let sessions: Vec<UtmpSession> = utmpsession_tracker.process(entry);
+                        for session in sessions.iter() {
+                            let (printed, flushed): (Count, Count) = match printer.print_utmpsession(session) {
+                                Ok((printed_, flushed_)) => (printed_ as Count, flushed_ as Count),
+                                Err(_err) => {
+                                    // Only print a printing error once and only for debug builds.
+                                    if !has_print_err {
+                                        has_print_err = true;
+                                        // BUG: Issue #3 colorization settings in the context of a pipe
+                                        de_err!("failed to print {}", _err);
+                                    }
+                                    defo!("print error, cancel processing at channel {:?}", pathid);
+                                    EXIT_EARLY.store(true, Ordering::Relaxed);
+                                    disconnect.push(*pathid);
+                                    break;
+                                }
+                            };
+                            if sepb_print {
+                                write_stdout(sepb);
+                                if cli_opt_summary {
+                                    summaryprinted.bytes += sepb.len() as Count;
+                                    summaryprinted.flushed += 1;
+                                }
+                            }
+                            _messages_printed += 1;
+                            if cli_opt_summary {
+                                paths_printed_logmessages.insert(*pathid);
+                                // update the per processing file `SummaryPrinted`
+                                SummaryPrinted::summaryprint_map_update_fixedstruct(
+                                    entry,
+                                    pathid,
+                                    &mut map_pathid_sumpr,
+                                    printed,
+                                    flushed,
+                                );
+                                // update the single total program `SummaryPrinted`
+                                summaryprinted.summaryprint_update_fixedstruct(entry, printed, flushed);
+                            }
+                        }
+                    }
+                }
                 LogMessage::FixedStruct(entry) => {
                     defo!("A3 FixedStruct printing PathId: {:?}", pathid);
+                    if cli_opt_acct_summary {
+                        acct_summary.process(entry);
+                    }
                     let mut printed: Count = 0;
                     let mut flushed: Count = 0;
                     match printer.print_fixedstruct(entry, &mut buffer_utmp) {
@@ -6597,6 +7871,209 @@ fn processing_loop(
                         summaryprinted.summaryprint_update_journalentry(journalentry, printed, flushed);
                     }
                 }
+                LogMessage::Sysline(syslinep) if cli_opt_audit => {
+                    defo!("A3 Sysline audit PathId: {:?}", pathid);
+                    let mut events: Vec<AuditEvent> = audit_grouper.process(*pathid, syslinep);
+                    if is_last {
+                        events.extend(audit_grouper.finish_path(*pathid));
+                    }
+                    for event in events.into_iter() {
+                        if !audit_filter.matches(&event) {
+                            continue;
+                        }
+                        let event: AuditEvent = match audit_interpreter.as_ref() {
+                            Some(audit_interpreter) => audit_interpreter.interpret_event(event),
+                            None => event,
+                        };
+                        let (printed, flushed): (Count, Count) = match printer.print_auditevent(&event) {
+                            Ok((printed_, flushed_)) => (printed_ as Count, flushed_ as Count),
+                            Err(_err) => {
+                                // Only print a printing error once and only for debug builds.
+                                if !has_print_err {
+                                    has_print_err = true;
+                                    // BUG: Issue #3 colorization settings in the context of a pipe
+                                    de_err!("failed to print {}", _err);
+                                }
+                                defo!("print error, cancel processing at channel {:?}", pathid);
+                                EXIT_EARLY.store(true, Ordering::Relaxed);
+                                disconnect.push(*pathid);
+                                break;
+                            }
+                        };
+                        if sepb_print {
+                            write_stdout(sepb);
+                            if cli_opt_summary {
+                                summaryprinted.bytes += sepb.len() as Count;
+                                summaryprinted.flushed += 1;
+                            }
+                        }
+                        _messages_printed += 1;
+                        if cli_opt_summary {
+                            paths_printed_logmessages.insert(*pathid);
+                            // update the per processing file `SummaryPrinted`
+                            SummaryPrinted::summaryprint_map_update_sysline(
+                                syslinep,
+                                pathid,
+                                &mut map_pathid_sumpr,
+                                printed,
+                                flushed,
+                            );
+                            // update the single total program `SummaryPrinted`
+                            summaryprinted.summaryprint_update_sysline(syslinep, printed, flushed);
+                        }
+                    }
+                }
+                LogMessage::Sysline(syslinep) if cli_opt_strace => {
+                    defo!("A3 Sysline strace PathId: {:?}", pathid);
+                    let mut calls: Vec<StraceCall> = strace_grouper.process(*pathid, syslinep);
+                    if is_last {
+                        calls.extend(strace_grouper.finish_path(*pathid));
+                    }
+                    for call in calls.into_iter() {
+                        if !strace_filter.matches(&call) {
+                            continue;
+                        }
+                        let (printed, flushed): (Count, Count) = match printer.print_stracecall(&call) {
+                            Ok((printed_, flushed_)) => (printed_ as Count, flushed_ as Count),
+                            Err(_err) => {
+                                // Only print a printing error once and only for debug builds.
+                                if !has_print_err {
+                                    has_print_err = true;
+                                    // BUG: Issue #3 colorization settings in the context of a pipe
+                                    de_err!("failed to print {}", _err);
+                                }
+                                defo!("print error, cancel processing at channel {:?}", pathid);
+                                EXIT_EARLY.store(true, Ordering::Relaxed);
+                                disconnect.push(*pathid);
+                                break;
+                            }
+                        };
+                        if sepb_print {
+                            write_stdout(sepb);
+                            if cli_opt_summary {
+                                summaryprinted.bytes += sepb.len() as Count;
+                                summaryprinted.flushed += 1;
+                            }
+                        }
+                        _messages_printed += 1;
+                        if cli_opt_summary {
+                            paths_printed_logmessages.insert(*pathid);
+                            // update the per processing file `SummaryPrinted`
+                            SummaryPrinted::summaryprint_map_update_sysline(
+                                syslinep,
+                                pathid,
+                                &mut map_pathid_sumpr,
+                                printed,
+                                flushed,
+                            );
+                            // update the single total program `SummaryPrinted`
+                            summaryprinted.summaryprint_update_sysline(syslinep, printed, flushed);
+                        }
+                    }
+                }
+                LogMessage::Sysline(syslinep)
+                    if !jsonline_filter.is_empty()
+                        || jsonline_output == JsonLineOutput::KeyValue
+                        || !keyvalue_filter.is_empty()
+                        || container_stream.is_some()
+                        // only a container log message of several lines may
+                        // have partial lines to join
+                        || (containerlog_mode != ContainerLogMode::Never
+                            && *map_pathid_containerlog
+                                .entry(*pathid)
+                                .or_insert_with(|| containerlog_parse_sysline(syslinep).is_some())
+                            && syslinep.count_lines() > 1) =>
+                {
+                    defo!("A3 Sysline jsonline or keyvalue or containerlog PathId: {:?}", pathid);
+                    let mut jsonline: JsonLine = JsonLine::from_syslinep(syslinep);
+                    let keyvalues: Option<KeyValues> = match keyvalue_filter.is_empty() {
+                        true => None,
+                        false => jsonline
+                            .lines
+                            .first()
+                            .and_then(|line| keyvalue_parse(line)),
+                    };
+                    let containerlog_path: bool = containerlog_mode != ContainerLogMode::Never
+                        && *map_pathid_containerlog
+                            .entry(*pathid)
+                            .or_insert_with(|| containerlog_parse_sysline(syslinep).is_some());
+                    let containerlog: Option<ContainerLog> = match containerlog_path {
+                        true => Some(ContainerLog::from_syslinep(syslinep)),
+                        false => None,
+                    };
+                    let containerlog_matches: bool = match container_stream {
+                        Some(_) => containerlog
+                            .as_ref()
+                            .is_some_and(|containerlog| containerlog.matches(container_stream)),
+                        None => true,
+                    };
+                    if jsonline_filter.matches(jsonline.object.as_ref())
+                        && keyvalue_filter.matches(keyvalues.as_ref())
+                        && containerlog_matches
+                    {
+                        let keyvalue: bool = jsonline_output == JsonLineOutput::KeyValue && jsonline.object.is_some();
+                        let joined: Option<&ContainerLog> = containerlog
+                            .as_ref()
+                            .filter(|containerlog| containerlog.joined);
+                        // a joined container log message is printed as one line
+                        let result: PrinterLogMessageResult = match (keyvalue, joined) {
+                            (_, Some(containerlog)) => printer.print_containerlog(containerlog),
+                            (true, None) => {
+                                jsonline.to_keyvalue();
+                                printer.print_jsonline(&jsonline)
+                            }
+                            (false, None) => printer.print_sysline(syslinep),
+                        };
+                        let mut printed: Count = 0;
+                        let mut flushed: Count = 0;
+                        match result {
+                            Ok((printed_, flushed_)) => {
+                                printed = printed_ as Count;
+                                flushed = flushed_ as Count;
+                            }
+                            Err(_err) => {
+                                // Only print a printing error once and only for debug builds.
+                                if !has_print_err {
+                                    has_print_err = true;
+                                    // BUG: Issue #3 colorization settings in the context of a pipe
+                                    de_err!("failed to print {}", _err);
+                                }
+                                defo!("print error, cancel processing at channel {:?}", pathid);
+                                EXIT_EARLY.store(true, Ordering::Relaxed);
+                                disconnect.push(*pathid);
+                            }
+                        }
+                        if sepb_print {
+                            write_stdout(sepb);
+                            if cli_opt_summary {
+                                summaryprinted.bytes += sepb.len() as Count;
+                                summaryprinted.flushed += 1;
+                            }
+                        }
+                        // see the same case of printing a `Sysline` below
+                        if is_last && !keyvalue && joined.is_none() && !(*syslinep).ends_with_newline() {
+                            write_stdout(&NLu8a);
+                            if cli_opt_summary {
+                                summaryprinted.bytes += NLu8a.len() as Count;
+                                summaryprinted.flushed += 1;
+                            }
+                        }
+                        _messages_printed += 1;
+                        if cli_opt_summary {
+                            paths_printed_logmessages.insert(*pathid);
+                            // update the per processing file `SummaryPrinted`
+                            SummaryPrinted::summaryprint_map_update_sysline(
+                                syslinep,
+                                pathid,
+                                &mut map_pathid_sumpr,
+                                printed,
+                                flushed,
+                            );
+                            // update the single total program `SummaryPrinted`
+                            summaryprinted.summaryprint_update_sysline(syslinep, printed, flushed);
+                        }
+                    }
+                }
                 LogMessage::Sysline(syslinep) => {
                     defo!(
                         "A3 Sysline printing SyslineP @[{}, {}] PathId: {:?}",
@@ -6685,6 +8162,21 @@ fn processing_loop(
             pathid_to_prependname.remove(pathid);
             defo!("D map_pathid_printer.remove({:?});", pathid);
             map_pathid_printer.remove(pathid);
+            // all log messages of a file are printed before the channel of
+            // the file is disconnected
+            if btmp_pathids.remove(pathid)
+                && btmp_pathids.is_empty()
+                && !failed_logins.is_empty()
+                && !exit_early()
+            {
+                defo!("D print failed logins");
+                let s: String = failed_logins.to_string();
+                write_stdout(s.as_bytes());
+                if cli_opt_summary {
+                    summaryprinted.bytes += s.len() as Count;
+                    summaryprinted.flushed += 1;
+                }
+            }
         }
         // are there any channels to receive from?
         if active_pathids.is_empty() {
@@ -6733,6 +8225,80 @@ fn processing_loop(
         return true;
     }
 
+    // `--utmp-sessions` that did not end and were not printed at their login
+    // are printed after all other log messages. Usually there are none;
+    // `utmpsessions_still_open` found them beforehand.
+    if cli_opt_utmp_sessions {
+        let sessions: Vec<UtmpSession> = utmpsession_tracker.finish();
+        if !sessions.is_empty() {
+            let mut printer: PrinterLogMessage = printer_after_loop(
+                color_choice,
+                &cli_prepend_dt_format,
+                &cli_prepend_separator,
+                cli_opt_prepend_offset,
+            );
+            for session in sessions.iter() {
+                match printer.print_utmpsession(session) {
+                    Ok((printed, flushed)) => {
+                        if cli_opt_summary {
+                            summaryprinted.bytes += printed as Count;
+                            summaryprinted.flushed += flushed as Count;
+                        }
+                    }
+                    Err(_err) => {
+                        de_err!("failed to print {}", _err);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    // `--audit` events of files that did not send a last log message are
+    // printed after all other log messages
+    if cli_opt_audit {
+        let events: Vec<AuditEvent> = audit_grouper.finish();
+        if !events.is_empty() {
+            let mut printer: PrinterLogMessage = printer_after_loop(
+                color_choice,
+                &cli_prepend_dt_format,
+                &cli_prepend_separator,
+                cli_opt_prepend_offset,
+            );
+            for event in events.into_iter() {
+                if !audit_filter.matches(&event) {
+                    continue;
+                }
+                let event: AuditEvent = match audit_interpreter.as_ref() {
+                    Some(audit_interpreter) => audit_interpreter.interpret_event(event),
+                    None => event,
+                };
+                match printer.print_auditevent(&event) {
+                    Ok((printed, flushed)) => {
+                        if cli_opt_summary {
+                            summaryprinted.bytes += printed as Count;
+                            summaryprinted.flushed += flushed as Count;
+                        }
+                    }
+                    Err(_err) => {
+                        de_err!("failed to print {}", _err);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    // `--acct-summary` is printed after all other log messages
+    if cli_opt_acct_summary && !acct_summary.is_empty() {
+        let s: String = acct_summary.to_string();
+        write_stdout(s.as_bytes());
+        if cli_opt_summary {
+            summaryprinted.bytes += s.len() as Count;
+            summaryprinted.flushed += 1;
+        }
+    }
+
     // Getting here means main program processing has completed.
     // Now to print the `--summary` (if it was requested).
 
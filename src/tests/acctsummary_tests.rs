@@ -0,0 +1,135 @@
+// src/tests/acctsummary_tests.rs
+
+//! tests for `acctsummary.rs`
+
+#![allow(non_snake_case)]
+
+use ::test_case::test_case;
+
+use crate::data::acctsummary::{
+    AcctSummary,
+    AcctTotals,
+};
+use crate::data::fixedstruct::{
+    AcctFields,
+    FixedStruct,
+    FixedStructType,
+    ACCT_ACORE,
+    ACCT_AXSIG,
+};
+use crate::tests::common::{
+    FO_0,
+    LINUX_X86_ACCT_BUFFER1,
+    LINUX_X86_UTMPX_BUFFER1,
+};
+
+fn fields(
+    comm: &str,
+    uid: u32,
+    flag: u8,
+    cpu: f64,
+    mem: u64,
+) -> AcctFields {
+    AcctFields {
+        comm: String::from(comm),
+        uid,
+        flag,
+        exitcode: Some(0),
+        cpu,
+        elapsed: cpu * 2.0,
+        mem,
+    }
+}
+
+#[test_case(0, false)]
+#[test_case(0x02, false; "ASU")]
+#[test_case(ACCT_AXSIG, true; "AXSIG")]
+#[test_case(ACCT_ACORE, true; "ACORE")]
+#[test_case(ACCT_AXSIG | ACCT_ACORE, true; "AXSIG ACORE")]
+fn test_AcctFields_is_abnormal(flag: u8, expect: bool) {
+    assert_eq!(fields("a", 0, flag, 0.0, 0).is_abnormal(), expect);
+}
+
+#[test]
+fn test_AcctTotals() {
+    let mut totals = AcctTotals::default();
+    assert_eq!(totals.cpu_avg(), 0.0);
+    assert_eq!(totals.mem_avg(), 0);
+    totals.add(&fields("a", 0, 0, 1.0, 100));
+    totals.add(&fields("a", 0, ACCT_AXSIG, 2.0, 300));
+    assert_eq!(totals.count, 2);
+    assert_eq!(totals.cpu, 3.0);
+    assert_eq!(totals.elapsed, 6.0);
+    assert_eq!(totals.cpu_avg(), 1.5);
+    assert_eq!(totals.mem_avg(), 200);
+    assert_eq!(totals.abnormal, 1);
+}
+
+#[test]
+fn test_AcctSummary() {
+    let mut summary = AcctSummary::new();
+    assert!(summary.is_empty());
+    summary.process_fields(&fields("bash", 1000, 0, 0.5, 1000));
+    summary.process_fields(&fields("gcc", 1000, 0, 2.0, 4000));
+    summary.process_fields(&fields("gcc", 0, ACCT_ACORE, 1.0, 2000));
+    summary.process_fields(&fields("ls", 0, 0, 0.0, 500));
+    assert!(!summary.is_empty());
+
+    let commands = summary.commands();
+    let names: Vec<&str> = commands.iter().map(|(comm, _)| comm.as_str()).collect();
+    assert_eq!(names, vec!["gcc", "bash", "ls"]);
+    assert_eq!(commands[0].1.count, 2);
+    assert_eq!(commands[0].1.cpu, 3.0);
+    assert_eq!(commands[0].1.mem_avg(), 3000);
+    assert_eq!(commands[0].1.abnormal, 1);
+
+    let users = summary.users();
+    let uids: Vec<u32> = users.iter().map(|(uid, _)| **uid).collect();
+    assert_eq!(uids, vec![1000, 0]);
+    assert_eq!(users[0].1.count, 2);
+    assert_eq!(users[1].1.abnormal, 1);
+
+    let s: String = summary.to_string();
+    let lines: Vec<&str> = s.lines().collect();
+    assert_eq!(lines.len(), 9, "{}", s);
+    assert_eq!(lines[0], "Process accounting by command:");
+    assert_eq!(
+        lines[1],
+        "   count      cpu sec    avg cpu  elapsed sec   avg mem abnormal command"
+    );
+    assert_eq!(
+        lines[2],
+        "       2         3.00       1.50         6.00     3000k        1 gcc"
+    );
+    assert_eq!(lines[5], "Process accounting by user:");
+    assert_eq!(
+        lines[7],
+        "       2         2.50       1.25         5.00     2500k        0 1000"
+    );
+}
+
+#[test]
+fn test_AcctSummary_process() {
+    let mut summary = AcctSummary::new();
+    let utmpx: FixedStruct = FixedStruct::new(
+        0,
+        &FO_0,
+        &LINUX_X86_UTMPX_BUFFER1,
+        FixedStructType::Fs_Linux_x86_Utmpx,
+    ).unwrap();
+    summary.process(&utmpx);
+    assert!(summary.is_empty());
+
+    let acct: FixedStruct = FixedStruct::new(
+        0,
+        &FO_0,
+        &LINUX_X86_ACCT_BUFFER1,
+        FixedStructType::Fs_Linux_x86_Acct,
+    ).unwrap();
+    summary.process(&acct);
+    summary.process(&acct);
+    let commands = summary.commands();
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].0, "accton");
+    assert_eq!(commands[0].1.count, 2);
+}
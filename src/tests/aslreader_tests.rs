@@ -0,0 +1,200 @@
+// src/tests/aslreader_tests.rs
+
+//! tests for [`src/readers/aslreader.rs`]
+//!
+//! [`src/readers/aslreader.rs`]: crate::readers::aslreader
+
+#![allow(non_snake_case)]
+
+#[allow(unused_imports)]
+use ::si_trace_print::printers::{
+    defn,
+    defo,
+    defx,
+};
+use ::std::io::ErrorKind;
+use ::test_case::test_case;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileType,
+    FileTypeArchive,
+};
+use crate::data::datetime::{
+    DateTimeLOpt,
+    ymdhmsl,
+};
+use crate::debug::helpers::{
+    create_temp_file_bytes,
+    ntf_fpath,
+};
+use crate::readers::aslreader::{
+    asl_time_to_micros,
+    AslReader,
+};
+use crate::readers::pyeventreader::ResultNextPyDataEvent;
+use crate::tests::common::{
+    path_id_generator,
+    ASL_1_EVENT_COUNT,
+    ASL_1_FILESZ,
+    ASL_1_FPATH,
+    ASL_1_GZ_FPATH,
+    FO_0,
+    NTF_LOG_EMPTY_FPATH,
+};
+
+const FT_ASL: FileType = FileType::Asl { archival_type: FileTypeArchive::Normal };
+const FT_ASL_GZ: FileType = FileType::Asl { archival_type: FileTypeArchive::Gz };
+
+/// first record of `ASL_1_FPATH` as printed by `ccl_asldb.py -t s4`
+const ASL_1_EVENT_0: &str = "2023-10-26T04:27:25.731612  id=3548  level=Notice  pid=68  gid=0  \
+read_uid=4294967295  read_gid=80  host=localhost  sender=powerd  facility=com.apple.iokit.power  \
+message='SMC shutdown cause: 3: Power button pressed for > 4 sec'  \
+com.apple.iokit.domain=ShutdownCause  SenderMachUUID=3E9B82F2-2CF1-3E44-9D8E-6B7A7CE52831\n";
+
+// expected values are the same as the Python
+// `timedelta(seconds=sec + (nano / 1000000000))`
+#[test_case(0, 0, Some(0))]
+#[test_case(1698294445, 731612000, Some(1698294445731612))]
+#[test_case(1698294445, 731612499, Some(1698294445731612))]
+#[test_case(1698294445, 731612501, Some(1698294445731612))]
+#[test_case(1698294445, 731612600, Some(1698294445731613))]
+#[test_case(0, 2500, Some(2))]
+#[test_case(0, 3500, Some(4))]
+#[test_case(1, 999999999, Some(2000000))]
+#[test_case(u64::MAX, 0, None)]
+fn test_asl_time_to_micros(
+    sec: u64,
+    nano: u32,
+    expect: Option<i64>,
+) {
+    assert_eq!(asl_time_to_micros(sec, nano), expect);
+}
+
+#[test_case(ASL_1_FPATH.clone(), FT_ASL; "asl")]
+#[test_case(ASL_1_GZ_FPATH.clone(), FT_ASL_GZ; "asl_gz")]
+fn test_AslReader_new(
+    path: FPath,
+    filetype: FileType,
+) {
+    let path_id = path_id_generator();
+    let aslreader = AslReader::new(path_id, path, filetype, FO_0).unwrap();
+    defo!("aslreader: {:?}", aslreader);
+    assert_eq!(aslreader.path_id(), path_id);
+    assert_eq!(aslreader.filesz(), ASL_1_FILESZ);
+    assert_eq!(aslreader.filetype(), filetype);
+    assert_eq!(aslreader.fileoffset_first(), 299);
+    assert_eq!(aslreader.fileoffset_last(), 3084);
+}
+
+#[test]
+fn test_AslReader_new_not_asl() {
+    let path_id = path_id_generator();
+    let result = AslReader::new(path_id, NTF_LOG_EMPTY_FPATH.clone(), FT_ASL, FO_0);
+    assert!(result.is_err(), "expected Err, got {:?}", result);
+}
+
+#[test_case(ASL_1_FPATH.clone(), FT_ASL; "asl")]
+#[test_case(ASL_1_GZ_FPATH.clone(), FT_ASL_GZ; "asl_gz")]
+fn test_AslReader_next_event(
+    path: FPath,
+    filetype: FileType,
+) {
+    let mut aslreader = AslReader::new(path_id_generator(), path, filetype, FO_0).unwrap();
+    let event = match aslreader.next(&None, &None) {
+        ResultNextPyDataEvent::Found(event) => event,
+        result => panic!("expected Found, got {:?}", result),
+    };
+    assert_eq!(String::from_utf8_lossy(event.as_bytes()), ASL_1_EVENT_0);
+    assert_eq!(event.dt(), &ymdhmsl(&FO_0, 2023, 10, 26, 4, 27, 25, 731));
+    assert_eq!(event.dt_beg_end(), &Some((0, 26)));
+}
+
+#[test_case(None, None, ASL_1_EVENT_COUNT, ASL_1_EVENT_COUNT; "no filters")]
+#[test_case(
+    Some(ymdhmsl(&FO_0, 2023, 10, 26, 4, 28, 0, 0)),
+    Some(ymdhmsl(&FO_0, 2023, 10, 26, 4, 29, 0, 0)),
+    ASL_1_EVENT_COUNT,
+    4;
+    "in range"
+)]
+#[test_case(
+    Some(ymdhmsl(&FO_0, 2023, 10, 26, 4, 31, 32, 0)),
+    None,
+    0,
+    0;
+    "after last record"
+)]
+#[test_case(
+    None,
+    Some(ymdhmsl(&FO_0, 2023, 10, 26, 4, 27, 25, 0)),
+    0,
+    0;
+    "before first record"
+)]
+fn test_AslReader_next_filters(
+    dt_filter_after: DateTimeLOpt,
+    dt_filter_before: DateTimeLOpt,
+    expect_processed: Count,
+    expect_accepted: Count,
+) {
+    let mut aslreader = AslReader::new(path_id_generator(), ASL_1_FPATH.clone(), FT_ASL, FO_0).unwrap();
+    let mut count: Count = 0;
+    loop {
+        match aslreader.next(&dt_filter_after, &dt_filter_before) {
+            ResultNextPyDataEvent::Found(_event) => count += 1,
+            ResultNextPyDataEvent::Done => break,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+    assert_eq!(count, expect_accepted);
+    let summary = aslreader.summary();
+    assert_eq!(summary.pyeventreader_events_processed, expect_processed);
+    assert_eq!(summary.pyeventreader_events_accepted, expect_accepted);
+    assert!(summary.pyeventreader_python_arguments.is_empty());
+}
+
+/// Return an ASL database holding one record at offset 45 with
+/// `kv_count` key/value references and a host string reference to a
+/// string claiming `string_len` bytes.
+fn asl_bytes_one_record(
+    kv_count: u32,
+    string_len: u32,
+) -> Vec<u8> {
+    let fo_record: u64 = 45;
+    let fo_string: u64 = fo_record + 114;
+    let mut data: Vec<u8> = vec![0; fo_string as usize];
+    data[..12].copy_from_slice(b"ASL DB\0\0\0\0\0\0");
+    data[16..24].copy_from_slice(&fo_record.to_be_bytes());
+    data[37..45].copy_from_slice(&fo_record.to_be_bytes());
+    let record: &mut [u8] = &mut data[fo_record as usize..];
+    record[22..30].copy_from_slice(&1698294445u64.to_be_bytes());
+    record[62..66].copy_from_slice(&kv_count.to_be_bytes());
+    if string_len != 0 {
+        record[66..74].copy_from_slice(&fo_string.to_be_bytes());
+        data.extend_from_slice(b"\x00\x01");
+        data.extend_from_slice(&string_len.to_be_bytes());
+    }
+
+    data
+}
+
+#[test_case(0, 0, None; "valid")]
+#[test_case(u32::MAX, 0, Some(ErrorKind::InvalidData); "kv_count beyond file")]
+#[test_case(2, 0, Some(ErrorKind::InvalidData); "kv_count one beyond file")]
+#[test_case(0, u32::MAX, Some(ErrorKind::InvalidData); "string beyond file")]
+#[test_case(0, 2, Some(ErrorKind::InvalidData); "string one beyond file")]
+fn test_AslReader_next_lengths(
+    kv_count: u32,
+    string_len: u32,
+    expect_err: Option<ErrorKind>,
+) {
+    let ntf = create_temp_file_bytes(&asl_bytes_one_record(kv_count, string_len));
+    let mut aslreader = AslReader::new(path_id_generator(), ntf_fpath(&ntf), FT_ASL, FO_0).unwrap();
+    match (aslreader.next(&None, &None), expect_err) {
+        (ResultNextPyDataEvent::Found(_event), None) => {}
+        (ResultNextPyDataEvent::Err(err), Some(kind)) => assert_eq!(err.kind(), kind, "{}", err),
+        (result, _) => panic!("expected {:?}, got {:?}", expect_err, result),
+    }
+}
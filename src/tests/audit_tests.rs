@@ -0,0 +1,281 @@
+// src/tests/audit_tests.rs
+
+//! tests for `audit.rs`
+
+#![allow(non_snake_case)]
+
+use std::collections::HashMap;
+
+use ::test_case::test_case;
+
+use crate::common::PathId;
+use crate::data::audit::{
+    arch_name,
+    audit_fields,
+    audit_header,
+    audit_keys,
+    hex_decode,
+    parse_id_file,
+    syscall_name_x86_64,
+    AuditEvent,
+    AuditFilter,
+    AuditGrouper,
+    AuditInterpreter,
+};
+use crate::data::datetime::{
+    ymdhms,
+    DateTimeL,
+};
+use crate::tests::common::{
+    new_syslinep,
+    FO_0,
+};
+
+const SYSCALL6: &str = "type=SYSCALL msg=audit(1681160194.293:6): arch=c000003e syscall=44 success=yes exit=60 \
+a0=3 a1=7ffc748c1930 items=0 ppid=723 pid=733 auid=4294967295 uid=0 gid=0 euid=1000 ses=4294967295 \
+comm=\"auditctl\" exe=\"/usr/sbin/auditctl\" key=(null)";
+const PROCTITLE6: &str = "type=PROCTITLE msg=audit(1681160194.293:6): \
+proctitle=2F7362696E2F617564697463746C002D52002F6574632F61756469742F61756469742E72756C6573";
+const EXECVE7: &str = "type=EXECVE msg=audit(1681160195.001:7): argc=3 a0=\"ls\" a1=2D6C20 a2=\"/tmp\"";
+const SYSCALL7: &str = "type=SYSCALL msg=audit(1681160195.001:7): arch=c000003e syscall=59 success=yes exit=0 \
+key=\"exec\"";
+const EOE7: &str = "type=EOE msg=audit(1681160195.001:7): ";
+const LOGIN8: &str = "node=host1 type=USER_LOGIN msg=audit(1681160196.500:8): pid=1 uid=0 auid=1000 ses=3 \
+msg='op=login acct=\"bob\" exe=\"/usr/sbin/sshd\" res=success'";
+/// an `enriched` record; interpreted fields follow the `\x1d`
+const SYSCALL9: &str = "type=SYSCALL msg=audit(1681160197.000:9): arch=c000003e syscall=2 uid=0 \
+key=6B657931016B657932\x1dARCH=x86_64 SYSCALL=open UID=\"root\"";
+
+const PASSWD: &str = "root:x:0:0:root:/root:/bin/bash\n# comment\nbob:x:1000:1000::/home/bob:/bin/sh\n";
+const GROUP: &str = "root:x:0:\nbob:x:1000:\n";
+
+fn dt(sec: u32) -> DateTimeL {
+    ymdhms(&FO_0, 2023, 4, 10, 20, 56, sec)
+}
+
+#[test_case(SYSCALL6, Some(("SYSCALL", 6, 42)); "SYSCALL")]
+#[test_case(EOE7, Some(("EOE", 7, 38)); "EOE")]
+#[test_case(LOGIN8, Some(("USER_LOGIN", 8, 56)); "node")]
+#[test_case("type=SYSCALL msg=audit(1681160194.293): a=1", None; "no serial")]
+#[test_case("Apr 10 20:56:34 host sshd[1]: hello", None; "not audit")]
+fn test_audit_header(
+    record: &str,
+    expect: Option<(&str, u64, usize)>,
+) {
+    assert_eq!(audit_header(record), expect);
+}
+
+#[test]
+fn test_audit_fields() {
+    let fields = audit_fields("a=1 b=\"x y\" c='d=2 e=\"f g\"' word  h=");
+    assert_eq!(
+        fields,
+        vec![
+            ("a", "1"),
+            ("b", "\"x y\""),
+            ("c", "'d=2 e=\"f g\"'"),
+            ("", "word"),
+            ("h", ""),
+        ]
+    );
+}
+
+#[test_case("2F62696E", Some("/bin"); "path")]
+#[test_case("2d6c", None; "lower case")]
+#[test_case("2F6", None; "odd length")]
+#[test_case("\"ls\"", None; "quoted")]
+#[test_case("(null)", None; "null")]
+fn test_hex_decode(
+    value: &str,
+    expect: Option<&str>,
+) {
+    assert_eq!(hex_decode(value).as_deref(), expect);
+}
+
+#[test_case(0, Some("read"))]
+#[test_case(59, Some("execve"))]
+#[test_case(257, Some("openat"))]
+#[test_case(334, Some("rseq"))]
+#[test_case(335, None)]
+#[test_case(435, Some("clone3"))]
+#[test_case(450, Some("set_mempolicy_home_node"))]
+#[test_case(451, None)]
+fn test_syscall_name_x86_64(
+    syscall: u32,
+    expect: Option<&str>,
+) {
+    assert_eq!(syscall_name_x86_64(syscall), expect);
+}
+
+#[test_case("c000003e", Some("x86_64"))]
+#[test_case("C00000B7", Some("aarch64"))]
+#[test_case("40000028", Some("arm"))]
+#[test_case("28", Some("armeb"))]
+#[test_case("00000028", Some("armeb"))]
+#[test_case("c0000028", None)]
+#[test_case("80000016", Some("s390x"))]
+#[test_case("16", Some("s390"))]
+#[test_case("80000015", Some("ppc64"))]
+#[test_case("c0000015", Some("ppc64le"))]
+#[test_case("0", None)]
+fn test_arch_name(
+    arch: &str,
+    expect: Option<&str>,
+) {
+    assert_eq!(arch_name(arch), expect);
+}
+
+#[test_case(SYSCALL6, &[]; "null key")]
+#[test_case(SYSCALL7, &["exec"]; "quoted key")]
+#[test_case(SYSCALL9, &["key1", "key2"]; "hex keys")]
+#[test_case("hello", &[]; "not audit")]
+fn test_audit_keys(
+    record: &str,
+    expect: &[&str],
+) {
+    assert_eq!(audit_keys(record), expect);
+}
+
+#[test]
+fn test_parse_id_file() {
+    let expect: HashMap<u32, String> = HashMap::from([
+        (0, String::from("root")),
+        (1000, String::from("bob")),
+    ]);
+    assert_eq!(parse_id_file(PASSWD), expect);
+}
+
+#[test]
+fn test_AuditGrouper_process_record() {
+    let pathid1: PathId = 1;
+    let pathid2: PathId = 2;
+    let mut grouper = AuditGrouper::new();
+    assert!(grouper.process_record(pathid1, &dt(34), SYSCALL6).is_empty());
+    // records of another file are grouped separately
+    assert!(grouper.process_record(pathid2, &dt(35), SYSCALL7).is_empty());
+    assert!(grouper.process_record(pathid1, &dt(34), PROCTITLE6).is_empty());
+    assert_eq!(grouper.count_open(), 2);
+    // a different serial ends the event
+    let ended: Vec<AuditEvent> = grouper.process_record(pathid1, &dt(35), EXECVE7);
+    assert_eq!(
+        ended,
+        vec![AuditEvent {
+            dt: dt(34),
+            serial: Some(6),
+            records: vec![String::from(SYSCALL6), String::from(PROCTITLE6)],
+        }]
+    );
+    // an EOE record ends the event
+    let ended: Vec<AuditEvent> = grouper.process_record(pathid1, &dt(35), EOE7);
+    assert_eq!(ended.len(), 1);
+    assert_eq!(ended[0].records, vec![EXECVE7, EOE7]);
+    assert_eq!(ended[0].types(), vec!["EXECVE", "EOE"]);
+    assert_eq!(grouper.count_open(), 1);
+    // a line that is not an audit record is an event by itself
+    let ended: Vec<AuditEvent> = grouper.process_record(pathid2, &dt(36), "hello");
+    assert_eq!(ended.len(), 2);
+    assert_eq!(ended[0].records, vec![SYSCALL7]);
+    assert_eq!(ended[1].serial, None);
+    assert_eq!(ended[1].records, vec!["hello"]);
+    assert_eq!(grouper.count_open(), 0);
+    assert!(grouper.finish().is_empty());
+}
+
+#[test]
+fn test_AuditGrouper_process_finish() {
+    let pathid: PathId = 1;
+    let mut grouper = AuditGrouper::new();
+    let data: String = format!("{}\n{}\n", SYSCALL6, PROCTITLE6);
+    assert!(grouper.process(pathid, &new_syslinep(&data, dt(34))).is_empty());
+    assert!(grouper.finish_path(2).is_none());
+    let event: AuditEvent = grouper.finish_path(pathid).unwrap();
+    assert_eq!(event.dt(), &dt(34));
+    assert_eq!(event.to_string(), data);
+    assert!(grouper.finish().is_empty());
+}
+
+#[test_case(None, None, true; "no filter")]
+#[test_case(Some("syscall"), None, true; "type lower case")]
+#[test_case(Some("PATH,EXECVE"), None, true; "type list")]
+#[test_case(Some("PATH"), None, false; "type no match")]
+#[test_case(None, Some("exec"), true; "key")]
+#[test_case(None, Some("other, exec"), true; "key list")]
+#[test_case(None, Some("Exec"), false; "key case")]
+#[test_case(Some("SYSCALL"), Some("nope"), false; "type and key")]
+fn test_AuditFilter_matches(
+    types: Option<&str>,
+    keys: Option<&str>,
+    expect: bool,
+) {
+    let event = AuditEvent {
+        dt: dt(35),
+        serial: Some(7),
+        records: vec![String::from(EXECVE7), String::from(SYSCALL7)],
+    };
+    let filter = AuditFilter::new(types, keys);
+    assert_eq!(filter.is_empty(), types.is_none() && keys.is_none());
+    assert_eq!(filter.matches(&event), expect);
+}
+
+#[test_case(
+    SYSCALL6,
+    "type=SYSCALL msg=audit(1681160194.293:6): arch=x86_64 syscall=sendto success=yes exit=60 \
+    a0=3 a1=7ffc748c1930 items=0 ppid=723 pid=733 auid=unset uid=root gid=root euid=bob ses=unset \
+    comm=auditctl exe=/usr/sbin/auditctl key=(null)";
+    "SYSCALL"
+)]
+#[test_case(
+    PROCTITLE6,
+    "type=PROCTITLE msg=audit(1681160194.293:6): proctitle=/sbin/auditctl -R /etc/audit/audit.rules";
+    "PROCTITLE"
+)]
+#[test_case(
+    EXECVE7,
+    "type=EXECVE msg=audit(1681160195.001:7): argc=3 a0=ls a1=-l  a2=/tmp";
+    "EXECVE"
+)]
+#[test_case(
+    LOGIN8,
+    "node=host1 type=USER_LOGIN msg=audit(1681160196.500:8): pid=1 uid=root auid=bob ses=3 \
+    msg='op=login acct=bob exe=/usr/sbin/sshd res=success'";
+    "USER_LOGIN"
+)]
+#[test_case(
+    SYSCALL9,
+    "type=SYSCALL msg=audit(1681160197.000:9): arch=x86_64 syscall=open uid=root key=key1,key2";
+    "enriched"
+)]
+#[test_case(EOE7, "type=EOE msg=audit(1681160195.001:7): "; "EOE")]
+#[test_case("hello", "hello"; "not audit")]
+fn test_AuditInterpreter_interpret(
+    record: &str,
+    expect: &str,
+) {
+    let interpreter = AuditInterpreter::new(Some(PASSWD), Some(GROUP));
+    assert_eq!(interpreter.interpret(record), expect);
+}
+
+#[test]
+fn test_AuditInterpreter_interpret_no_files() {
+    let interpreter = AuditInterpreter::new(None, None);
+    assert_eq!(
+        interpreter.interpret("type=CRED_ACQ msg=audit(1681160196.500:8): pid=1 uid=0 auid=1000 ses=4294967295"),
+        "type=CRED_ACQ msg=audit(1681160196.500:8): pid=1 uid=0 auid=1000 ses=unset",
+    );
+}
+
+#[test]
+fn test_AuditInterpreter_interpret_event() {
+    let interpreter = AuditInterpreter::new(None, None);
+    let event = AuditEvent {
+        dt: dt(34),
+        serial: Some(6),
+        records: vec![String::from(PROCTITLE6)],
+    };
+    let event = interpreter.interpret_event(event);
+    assert_eq!(event.serial, Some(6));
+    assert_eq!(
+        event.records,
+        vec!["type=PROCTITLE msg=audit(1681160194.293:6): proctitle=/sbin/auditctl -R /etc/audit/audit.rules"]
+    );
+}
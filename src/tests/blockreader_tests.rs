@@ -6,6 +6,11 @@
 #![allow(non_camel_case_types)]
 #![allow(clippy::too_many_arguments)]
 
+use std::io::{
+    Seek,
+    SeekFrom,
+    Write,
+};
 use std::time::SystemTime;
 
 use ::chrono::Datelike;
@@ -1616,6 +1621,55 @@ fn test_read_data_to_buffer(
     assert_eq!(at, expect_data.len(), "failed to match all bytes, matched {}, expected {}", at, expect_data.len());
 }
 
+/// Create a temporary file of `filesz` null bytes with a non-zero byte at
+/// each of `fileoffsets`. If `sparse` then the null bytes are not written,
+/// i.e. the file has holes (on file systems that support sparse files).
+fn create_temp_file_nonzero(
+    filesz: FileSz,
+    fileoffsets: &[FileOffset],
+    sparse: bool,
+) -> NamedTempFile {
+    let ntf = create_temp_file_bytes_with_suffix(&[], &String::from(".sparse"));
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .open(ntf.path())
+        .unwrap();
+    if sparse {
+        file.set_len(filesz).unwrap();
+    } else {
+        file.write_all(&vec![0; filesz as usize]).unwrap();
+    }
+    for fo in fileoffsets.iter() {
+        file.seek(SeekFrom::Start(*fo)).unwrap();
+        file.write_all(&[0xFF]).unwrap();
+    }
+
+    ntf
+}
+
+#[test_case(true, 0, Some(0x100); "sparse 0")]
+#[test_case(true, 0x100, Some(0x100); "sparse 0x100")]
+#[test_case(true, 0x101, Some(0x8000); "sparse 0x101")]
+#[test_case(true, 0x8000, Some(0x8000); "sparse 0x8000")]
+#[test_case(true, 0x8001, Some(0x2F000); "sparse 0x8001")]
+#[test_case(true, 0x2F001, None; "sparse 0x2F001")]
+#[test_case(true, 0x30000, None; "sparse 0x30000")]
+#[test_case(false, 0, Some(0x100); "zeros 0")]
+#[test_case(false, 0x101, Some(0x8000); "zeros 0x101")]
+#[test_case(false, 0x8001, Some(0x2F000); "zeros 0x8001")]
+#[test_case(false, 0x2F001, None; "zeros 0x2F001")]
+fn test_fileoffset_nonzero(
+    sparse: bool,
+    fileoffset: FileOffset,
+    expect: Option<FileOffset>,
+) {
+    let ntf = create_temp_file_nonzero(0x30000, &[0x100, 0x8000, 0x2F000], sparse);
+    let fpath = ntf_fpath(&ntf);
+    let mut br1 = new_BlockReader(&fpath, FILETYPE_UTF8, 0x400);
+    let fo = br1.fileoffset_nonzero(fileoffset).unwrap();
+    assert_eq!(fo, expect, "fileoffset_nonzero({:#X})", fileoffset);
+}
+
 /// Test `BlockReader.mtime()`.
 /// Only precisely compare returned `SystemTime` for the "temporary" files with
 /// known `SystemTime`s. Otherwise, just compare that the year returned is the
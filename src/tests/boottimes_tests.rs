@@ -0,0 +1,406 @@
+// src/tests/boottimes_tests.rs
+
+//! tests for `boottimes.rs`
+
+#![allow(non_snake_case)]
+
+use std::time::Duration as StdDuration;
+
+use ::filetime;
+use ::test_case::test_case;
+
+use crate::common::{
+    FPath,
+    FileOffset,
+    FileType,
+    RegexId,
+};
+use crate::data::datetime::{
+    ymdhms,
+    ymdhmsm,
+    DateTimeL,
+    SystemTime,
+    regex_id_compiled,
+};
+use crate::debug::helpers::{
+    create_temp_file,
+    ntf_fpath,
+};
+use crate::readers::boottimes::{
+    boot_times_merge,
+    boot_times_path,
+    is_kernel_linux_version,
+    journal_export_boot_time,
+    uptime_zeros,
+    xorg_header_time,
+    BootTimes,
+    UptimeSegment,
+};
+use crate::readers::filepreprocessor::{
+    fpath_to_filetype,
+    PathToFiletypeResult,
+};
+use crate::readers::syslinereader::ResultFindSysline;
+use crate::readers::syslogprocessor::{
+    FileProcessingResultBlockZero,
+    SyslogProcessor,
+};
+use crate::tests::common::{
+    path_id_generator,
+    FILETYPE_UTF8,
+    FO_0,
+    FO_P8,
+};
+
+/// `dmesg` uptime datetime regex
+const REGEX_ID_UPTIME: RegexId = 161;
+
+/// `YYYY-MM-DD HH:MM:SS` syslog datetime regex
+const REGEX_ID_YMDHMS: RegexId = 80;
+
+fn st(secs: u64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + StdDuration::from_secs(secs)
+}
+
+fn seg(
+    fileoffset: FileOffset,
+    secs: u64,
+) -> UptimeSegment {
+    UptimeSegment {
+        fileoffset,
+        uptime_max: StdDuration::from_secs(secs),
+        zero: None,
+    }
+}
+
+fn seg_zero(
+    fileoffset: FileOffset,
+    secs: u64,
+    zero: SystemTime,
+) -> UptimeSegment {
+    UptimeSegment {
+        zero: Some(zero),
+        ..seg(fileoffset, secs)
+    }
+}
+
+#[test_case(vec![], vec![]; "empty")]
+#[test_case(vec![st(100)], vec![st(100)]; "one")]
+#[test_case(vec![st(300), st(100)], vec![st(100), st(300)]; "sorted")]
+#[test_case(vec![st(110), st(100), st(125)], vec![st(100)]; "merged within window")]
+#[test_case(vec![st(100), st(131), st(1000), st(1005)], vec![st(100), st(131), st(1000)]; "merged some")]
+fn test_boot_times_merge(
+    boot_times: BootTimes,
+    expect: BootTimes,
+) {
+    assert_eq!(boot_times_merge(boot_times), expect);
+}
+
+#[test_case(
+    &[seg(0, 50)], st(1000), &[],
+    &[(0, st(950))];
+    "one boot mtime"
+)]
+#[test_case(
+    &[seg(0, 50), seg(200, 30)], st(1000), &[],
+    &[(0, st(920)), (200, st(970))];
+    "two boots mtime"
+)]
+#[test_case(
+    &[seg(0, 50)], st(9000), &[st(100), st(500)],
+    &[(0, st(500))];
+    "one boot anchored to latest boot time"
+)]
+#[test_case(
+    &[seg(0, 50), seg(200, 30)], st(9000), &[st(100), st(500)],
+    &[(0, st(100)), (200, st(500))];
+    "two boots anchored"
+)]
+#[test_case(
+    &[seg(0, 500), seg(200, 30)], st(9000), &[st(100), st(500)],
+    &[(0, st(0)), (200, st(500))];
+    "first boot too long for boot time"
+)]
+#[test_case(
+    &[seg(0, 50)], st(120), &[st(100), st(500)],
+    &[(0, st(100))];
+    "boot time after mtime ignored"
+)]
+#[test_case(
+    &[seg(0, 50), seg(200, 30)], st(520), &[st(500)],
+    &[(0, st(450)), (200, st(500))];
+    "boot time used once"
+)]
+#[test_case(
+    &[seg_zero(0, 50, st(300))], st(9000), &[st(500)],
+    &[(0, st(300))];
+    "header zero"
+)]
+#[test_case(
+    &[seg(0, 50), seg_zero(200, 30, st(700))], st(9000), &[st(100), st(500), st(700)],
+    &[(0, st(500)), (200, st(700))];
+    "header zero ends prior boot"
+)]
+fn test_uptime_zeros(
+    segments: &[UptimeSegment],
+    mtime: SystemTime,
+    boot_times: &[SystemTime],
+    expect: &[(FileOffset, SystemTime)],
+) {
+    let zeros = uptime_zeros(segments, mtime, boot_times).unwrap();
+    assert_eq!(zeros.as_slice(), expect);
+}
+
+const JOURNAL_EXPORT: &str = "\
+__CURSOR=s=e992f143877046059b264a0f907056b6;i=6ff;b=26d74a46deff4872be6d4ca6e885a198
+__REALTIME_TIMESTAMP=1680331472784185
+__MONOTONIC_TIMESTAMP=74212842
+_BOOT_ID=26d74a46deff4872be6d4ca6e885a198
+_TRANSPORT=journal
+MESSAGE=unable to update icon for livepatch
+";
+
+#[test]
+fn test_journal_export_boot_time() {
+    let (boot_id, boot_time) = journal_export_boot_time(JOURNAL_EXPORT.as_bytes()).unwrap();
+    assert_eq!(boot_id, b"26d74a46deff4872be6d4ca6e885a198");
+    assert_eq!(
+        boot_time,
+        SystemTime::UNIX_EPOCH + StdDuration::from_micros(1680331472784185 - 74212842)
+    );
+}
+
+#[test_case("__REALTIME_TIMESTAMP=100\n_BOOT_ID=a\n"; "no monotonic")]
+#[test_case("__REALTIME_TIMESTAMP=100\n__MONOTONIC_TIMESTAMP=10\n"; "no boot id")]
+#[test_case("__REALTIME_TIMESTAMP=10\n__MONOTONIC_TIMESTAMP=100\n_BOOT_ID=a\n"; "monotonic too large")]
+#[test_case("__REALTIME_TIMESTAMP=x\n__MONOTONIC_TIMESTAMP=10\n_BOOT_ID=a\n"; "bad number")]
+fn test_journal_export_boot_time_none(data: &str) {
+    assert_eq!(journal_export_boot_time(data.as_bytes()), None);
+}
+
+#[test_case("Apr 19 01:33:05 host kernel: Linux version 6.2.9-1-default", true; "plain")]
+#[test_case("Oct 10 23:07:16 host kernel: [    0.10034] Linux version 5.10.0-11-amd64", true; "uptime")]
+#[test_case("Oct 10 23:07:16 host kernel: [0.0]Linux version 5.10.0-11-amd64", true; "uptime no space")]
+#[test_case("Oct 10 23:07:16 host kernel: [    0.10034 Linux version 5.10.0", false; "uptime unterminated")]
+#[test_case("Oct 10 23:07:16 host kernel: Command line: ro", false; "other kernel message")]
+#[test_case("Oct 10 23:07:16 host sshd[1]: Linux version 5.10.0", false; "not kernel")]
+fn test_is_kernel_linux_version(
+    data: &str,
+    expect: bool,
+) {
+    assert_eq!(is_kernel_linux_version(data.as_bytes()), expect);
+}
+
+#[test_case(
+    "[    91.203] (==) Log file: \"/var/log/Xorg.0.log\", Time: Mon Feb 17 12:50:58 2020",
+    Some(ymdhms(&FO_0, 2020, 2, 17, 12, 50, 58));
+    "Xorg"
+)]
+#[test_case(
+    "[    17.584] (++) Log file: \"/tmp/X.log\", Time: Tue Nov  1 21:03:04 2022\n",
+    Some(ymdhms(&FO_0, 2022, 11, 1, 21, 3, 4));
+    "anaconda padded day"
+)]
+#[test_case(
+    "[    91.203] (==) Log file: \"/var/log/Xorg.0.log\", Time: Mon Feb 17 12:50:58 2020",
+    Some(ymdhms(&FO_P8, 2020, 2, 17, 12, 50, 58));
+    "tz offset"
+)]
+#[test_case(
+    "[    12.345] (==) Log file: \"/var/log/lightdm/x-0.log\", Time: Mon Apr  3 10:00:00 2023",
+    Some(ymdhms(&FO_0, 2023, 4, 3, 10, 0, 0));
+    "lightdm"
+)]
+#[test_case(
+    "[    91.203] (==) Log file: \"/var/log/Xorg.0.log\", Time: yesterday",
+    None;
+    "bad time"
+)]
+#[test_case(
+    "[    91.203] (==) Log file: \"/var/log/Xorg.0.log\"",
+    None;
+    "no time"
+)]
+#[test_case(
+    "[    91.204] (==) Using config directory: \"/etc/X11/xorg.conf.d\"",
+    None;
+    "not header"
+)]
+fn test_xorg_header_time(
+    data: &str,
+    expect: Option<DateTimeL>,
+) {
+    let tz_offset = match expect {
+        Some(dt) => *dt.offset(),
+        None => FO_0,
+    };
+    assert_eq!(
+        xorg_header_time(data.as_bytes(), &tz_offset),
+        expect.map(SystemTime::from),
+    );
+}
+
+fn filetype_path(path: &FPath) -> FileType {
+    match fpath_to_filetype(path, true) {
+        PathToFiletypeResult::Filetype(ft) => ft,
+        PathToFiletypeResult::Archive(..) => panic!("unexpected Archive for {:?}", path),
+    }
+}
+
+#[test]
+fn test_boot_times_path_wtmp() {
+    let path: FPath = FPath::from("./logs/OpenSUSE15/wtmp");
+    let filetype = filetype_path(&path);
+    let boot_times = boot_times_path(path_id_generator(), &path, filetype, 0x1000, FO_0).unwrap();
+    assert_eq!(boot_times.len(), 10, "{:?}", boot_times);
+    assert_eq!(
+        boot_times[0],
+        SystemTime::from(ymdhmsm(&FO_0, 2023, 4, 19, 1, 32, 58, 904000)),
+    );
+}
+
+#[test]
+fn test_boot_times_path_syslog() {
+    if !regex_id_compiled(REGEX_ID_YMDHMS) {
+        eprintln!("Regex #{} not compiled; skip test", REGEX_ID_YMDHMS);
+        return;
+    }
+    let ntf = create_temp_file("\
+2023-04-19 01:33:05 host kernel: Linux version 6.2.9-1-default
+2023-04-19 01:33:05 host kernel: Command line: ro
+2023-04-19 01:40:00 host sshd[1]: Accepted password
+2023-04-22 19:26:50 host kernel: [    0.000000] Linux version 6.2.10-1-default
+");
+    let path: FPath = ntf_fpath(&ntf);
+    let boot_times = boot_times_path(path_id_generator(), &path, FILETYPE_UTF8, 0x100, FO_0).unwrap();
+    assert_eq!(
+        boot_times,
+        vec![
+            SystemTime::from(ymdhms(&FO_0, 2023, 4, 19, 1, 33, 5)),
+            SystemTime::from(ymdhms(&FO_0, 2023, 4, 22, 19, 26, 50)),
+        ]
+    );
+}
+
+/// two boots of a `dmesg` file, the uptime resets at the fourth line
+const DMESG_2BOOTS: &str = "\
+[    0.000000] kernel: Linux version 6.2.9-1-default
+[    1.500000] kernel: Command line: ro
+[  100.000000] kernel: eth0: link up
+[    0.000000] kernel: Linux version 6.2.10-1-default
+[   20.000000] kernel: eth0: link up
+";
+
+/// the datetimes of all syslines of the uptime file at `path`
+fn process_uptime_dts(
+    path: &FPath,
+    boot_times: BootTimes,
+) -> Vec<DateTimeL> {
+    let mut slp = SyslogProcessor::new(
+        path_id_generator(), path.clone(), FILETYPE_UTF8, 0x40, FO_0, None, None,
+    ).unwrap();
+    slp.set_boot_times(boot_times);
+    assert_eq!(slp.process_stage0_valid_file_check(), FileProcessingResultBlockZero::FileOk);
+    assert_eq!(slp.process_stage1_blockzero_analysis(), FileProcessingResultBlockZero::FileOk);
+    assert!(slp.dt_pattern_uptime());
+    assert_eq!(slp.process_stage2_find_dt(&None), FileProcessingResultBlockZero::FileOk);
+    let mut dts: Vec<DateTimeL> = Vec::new();
+    let mut fo: FileOffset = 0;
+    loop {
+        match slp.find_sysline(fo) {
+            ResultFindSysline::Found((fo_next, syslinep)) => {
+                dts.push(*syslinep.dt());
+                fo = fo_next;
+            }
+            ResultFindSysline::Done => break,
+            ResultFindSysline::Err(err) => panic!("find_sysline({}) failed {}", fo, err),
+        }
+    }
+
+    dts
+}
+
+#[test]
+fn test_process_uptime_2boots_mtime() {
+    if !regex_id_compiled(REGEX_ID_UPTIME) {
+        eprintln!("Regex #{} not compiled; skip test", REGEX_ID_UPTIME);
+        return;
+    }
+    let ntf = create_temp_file(DMESG_2BOOTS);
+    // mtime 2023-04-22 19:27:00 UTC
+    let mtime = filetime::FileTime::from_unix_time(1682191620, 0);
+    filetime::set_file_mtime(ntf.path(), mtime).unwrap();
+    let dts = process_uptime_dts(&ntf_fpath(&ntf), BootTimes::new());
+    // second boot ends at the mtime; first boot ends when the second begins
+    assert_eq!(
+        dts,
+        vec![
+            ymdhms(&FO_0, 2023, 4, 22, 19, 25, 0),
+            ymdhmsm(&FO_0, 2023, 4, 22, 19, 25, 1, 500000),
+            ymdhms(&FO_0, 2023, 4, 22, 19, 26, 40),
+            ymdhms(&FO_0, 2023, 4, 22, 19, 26, 40),
+            ymdhms(&FO_0, 2023, 4, 22, 19, 27, 0),
+        ]
+    );
+}
+
+#[test]
+fn test_process_uptime_2boots_boot_times() {
+    if !regex_id_compiled(REGEX_ID_UPTIME) {
+        eprintln!("Regex #{} not compiled; skip test", REGEX_ID_UPTIME);
+        return;
+    }
+    let ntf = create_temp_file(DMESG_2BOOTS);
+    // mtime unrelated to the boots, e.g. a copied file
+    let mtime = filetime::FileTime::from_unix_time(1700000000, 0);
+    filetime::set_file_mtime(ntf.path(), mtime).unwrap();
+    let boot_times: BootTimes = vec![
+        SystemTime::from(ymdhms(&FO_0, 2023, 4, 19, 1, 32, 58)),
+        SystemTime::from(ymdhms(&FO_0, 2023, 4, 22, 19, 26, 40)),
+    ];
+    let dts = process_uptime_dts(&ntf_fpath(&ntf), boot_times);
+    assert_eq!(
+        dts,
+        vec![
+            ymdhms(&FO_0, 2023, 4, 19, 1, 32, 58),
+            ymdhmsm(&FO_0, 2023, 4, 19, 1, 32, 59, 500000),
+            ymdhms(&FO_0, 2023, 4, 19, 1, 34, 38),
+            ymdhms(&FO_0, 2023, 4, 22, 19, 26, 40),
+            ymdhms(&FO_0, 2023, 4, 22, 19, 27, 0),
+        ]
+    );
+}
+
+/// an `Xorg.0.log` with the server start time in the header line
+const XORG_HEADER: &str = "\
+[    91.203] 
+X.Org X Server 1.20.5
+[    91.203] (==) Log file: \"/var/log/Xorg.0.log\", Time: Mon Feb 17 12:50:58 2020
+[    91.205] (==) ServerLayout \"Layout0\"
+[   120.500] (II) event1 closed
+";
+
+#[test]
+fn test_process_uptime_xorg_header() {
+    if !regex_id_compiled(REGEX_ID_UPTIME) {
+        eprintln!("Regex #{} not compiled; skip test", REGEX_ID_UPTIME);
+        return;
+    }
+    let ntf = create_temp_file(XORG_HEADER);
+    // mtime and boot times unrelated to the server start, e.g. a copied file
+    let mtime = filetime::FileTime::from_unix_time(1700000000, 0);
+    filetime::set_file_mtime(ntf.path(), mtime).unwrap();
+    let boot_times: BootTimes = vec![
+        SystemTime::from(ymdhms(&FO_0, 2020, 2, 17, 8, 0, 0)),
+    ];
+    let dts = process_uptime_dts(&ntf_fpath(&ntf), boot_times);
+    assert_eq!(
+        dts,
+        vec![
+            ymdhms(&FO_0, 2020, 2, 17, 12, 50, 58),
+            ymdhms(&FO_0, 2020, 2, 17, 12, 50, 58),
+            ymdhmsm(&FO_0, 2020, 2, 17, 12, 50, 58, 2000),
+            ymdhmsm(&FO_0, 2020, 2, 17, 12, 51, 27, 297000),
+        ]
+    );
+}
@@ -0,0 +1,155 @@
+// src/tests/columnar_tests.rs
+
+//! tests for `columnar.rs`
+
+#![allow(non_snake_case)]
+
+use ::test_case::test_case;
+
+use crate::data::columnar::{
+    columnar_split,
+    is_w3c_directive,
+    ColumnarColumn,
+    ColumnarFormat,
+    ColumnarHeader,
+    ColumnarTime,
+};
+use crate::data::datetime::{
+    ymdhms,
+    DateTimeL,
+};
+use crate::tests::common::FO_P8;
+
+#[test_case("#Software: Microsoft Internet Information Services 10.0", true)]
+#[test_case("#Fields: date time s-ip", true)]
+#[test_case("#Start-Date: 2024-01-02 03:04:05", true)]
+#[test_case("# comment", false)]
+#[test_case("2024-01-02 03:04:05 10.0.0.1", false)]
+fn test_is_w3c_directive(
+    line: &str,
+    expect: bool,
+) {
+    assert_eq!(is_w3c_directive(line), expect);
+}
+
+#[test_case("a,b,c", Some(b','), &[(0, 1), (2, 3), (4, 5)]; "simple")]
+#[test_case(r#"1,"hello, world",x"#, Some(b','), &[(0, 1), (3, 15), (17, 18)]; "quoted")]
+#[test_case(r#""say ""hi""",x"#, Some(b','), &[(1, 11), (13, 14)]; "escaped quote")]
+#[test_case("a,,b,", Some(b','), &[(0, 1), (2, 2), (3, 4), (5, 5)]; "empty columns")]
+#[test_case("a\tb c", Some(b'\t'), &[(0, 1), (2, 5)]; "tab")]
+#[test_case("  a  b\tc", None, &[(2, 3), (5, 6), (7, 8)]; "whitespace")]
+#[test_case("", Some(b','), &[]; "empty")]
+fn test_columnar_split(
+    line: &str,
+    delimiter: Option<u8>,
+    expect: &[(usize, usize)],
+) {
+    assert_eq!(columnar_split(line, delimiter), expect.to_vec());
+}
+
+#[test]
+fn test_ColumnarTime_new() {
+    let ct = ColumnarTime::new(None, None).unwrap();
+    assert_eq!(ct, ColumnarTime::default());
+    assert!(!ct.is_csv());
+
+    let ct = ColumnarTime::new(Some(" date, 3 ,"), Some('\t')).unwrap();
+    assert!(ct.is_csv());
+    assert_eq!(ct.columns, vec![ColumnarColumn::Name(String::from("date")), ColumnarColumn::Number(3)]);
+    assert_eq!(ct.delimiter, b'\t');
+
+    assert!(ColumnarTime::new(Some("0"), None).is_err());
+    assert!(ColumnarTime::new(None, Some('"')).is_err());
+    assert!(ColumnarTime::new(None, Some('é')).is_err());
+}
+
+#[test_case(None, "#Fields: date time s-ip cs-method", Some(vec![0, 1]); "default date time")]
+#[test_case(None, "#Fields: s-ip TIME cs-method Date", Some(vec![3, 1]); "default reordered")]
+#[test_case(None, "#Fields: s-ip datetime", Some(vec![1]); "default datetime")]
+#[test_case(Some("s-ip"), "#Fields: date time s-ip", Some(vec![2]); "selected")]
+#[test_case(Some("when"), "#Fields: date time s-ip", Some(vec![0, 1]); "selected not found")]
+#[test_case(None, "#Fields: s-ip cs-method", None; "no datetime columns")]
+#[test_case(None, "#Date: 2024-01-02 03:04:05", None; "not fields")]
+fn test_ColumnarHeader_from_w3c_fields(
+    columns: Option<&str>,
+    line: &str,
+    expect: Option<Vec<usize>>,
+) {
+    let ct = ColumnarTime::new(columns, None).unwrap();
+    let header = ColumnarHeader::from_w3c_fields(line, &ct);
+    assert_eq!(header.as_ref().map(|header| header.time_indexes.clone()), expect);
+    if let Some(header) = header {
+        assert_eq!(header.format, ColumnarFormat::W3c);
+        assert_eq!(header.delimiter, None);
+    }
+}
+
+#[test_case(None, ',', "id,when,level", None; "not selected")]
+#[test_case(Some("when"), ',', "id,When,level\r\n", Some(vec![1]); "name")]
+#[test_case(Some("day,hour"), ',', r#""hour","day""#, Some(vec![1, 0]); "quoted names")]
+#[test_case(Some("2"), '\t', "1\t2024-01-02 03:04:05", Some(vec![1]); "number without header")]
+#[test_case(Some("when"), ',', "id,level", None; "name not found")]
+fn test_ColumnarHeader_from_csv(
+    columns: Option<&str>,
+    delimiter: char,
+    line: &str,
+    expect: Option<Vec<usize>>,
+) {
+    let ct = ColumnarTime::new(columns, Some(delimiter)).unwrap();
+    let header = ColumnarHeader::from_csv(line, &ct);
+    assert_eq!(header.as_ref().map(|header| header.time_indexes.clone()), expect);
+    if let Some(header) = header {
+        assert_eq!(header.format, ColumnarFormat::Csv);
+        assert_eq!(header.delimiter, Some(delimiter as u8));
+    }
+}
+
+#[test_case(
+    "#Fields: date time s-ip cs-method",
+    "2024-01-02 03:04:05 10.0.0.1 GET\n",
+    Some((0, 19, ymdhms(&FO_P8, 2024, 1, 2, 3, 4, 5)));
+    "W3C"
+)]
+#[test_case(
+    "#Fields: s-ip time cs-method date",
+    "10.0.0.1 03:04:05 GET 2024-01-02",
+    Some((9, 32, ymdhms(&FO_P8, 2024, 1, 2, 3, 4, 5)));
+    "W3C reordered"
+)]
+#[test_case("#Fields: date time s-ip", "#Date: 2024-01-02 03:04:05", None; "W3C directive")]
+#[test_case("#Fields: date time s-ip", "2024-01-02", None; "W3C missing column")]
+#[test_case("#Fields: date time s-ip", "today now 10.0.0.1", None; "W3C not a datetime")]
+fn test_ColumnarHeader_find_datetime_w3c(
+    fields: &str,
+    line: &str,
+    expect: Option<(usize, usize, DateTimeL)>,
+) {
+    let header = ColumnarHeader::from_w3c_fields(fields, &ColumnarTime::default()).unwrap();
+    assert_eq!(header.find_datetime(line, &FO_P8), expect);
+}
+
+#[test_case(
+    "when",
+    "id,when,level,message",
+    r#"1,2024-01-02 03:04:05,info,"hello, world""#,
+    Some((2, 21, ymdhms(&FO_P8, 2024, 1, 2, 3, 4, 5)));
+    "name"
+)]
+#[test_case(
+    "day,hour",
+    "id,day,hour,x",
+    r#"1,"2024-01-02","03:04:05",x"#,
+    Some((3, 24, ymdhms(&FO_P8, 2024, 1, 2, 3, 4, 5)));
+    "quoted columns"
+)]
+#[test_case("when", "id,when,level", "1,,info", None; "empty column")]
+fn test_ColumnarHeader_find_datetime_csv(
+    columns: &str,
+    header: &str,
+    line: &str,
+    expect: Option<(usize, usize, DateTimeL)>,
+) {
+    let ct = ColumnarTime::new(Some(columns), None).unwrap();
+    let header = ColumnarHeader::from_csv(header, &ct).unwrap();
+    assert_eq!(header.find_datetime(line, &FO_P8), expect);
+}
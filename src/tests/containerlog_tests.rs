@@ -0,0 +1,259 @@
+// src/tests/containerlog_tests.rs
+
+//! tests for `containerlog.rs`
+
+#![allow(non_snake_case)]
+
+use ::test_case::test_case;
+
+use crate::data::containerlog::{
+    containerlog_parse,
+    ContainerLog,
+    ContainerLogFormat,
+    ContainerLogJoiner,
+    ContainerStream,
+};
+use crate::data::datetime::{
+    ymdhms,
+    ymdhmsm,
+};
+use crate::data::sysline::SyslineP;
+use crate::tests::common::{
+    new_syslinep,
+    FO_0,
+    FO_P8,
+};
+
+#[test]
+fn test_containerlog_parse_cri() {
+    let line = "2024-01-02T03:04:05.123456Z stdout P hello world\n";
+    let parsed = containerlog_parse(line, &FO_P8).unwrap();
+    assert_eq!(parsed.format, ContainerLogFormat::Cri);
+    assert_eq!(parsed.stream, ContainerStream::Stdout);
+    assert!(parsed.partial);
+    assert_eq!(parsed.dt, ymdhmsm(&FO_0, 2024, 1, 2, 3, 4, 5, 123456));
+    assert_eq!((parsed.dt_beg, parsed.dt_end), (0, 27));
+    assert_eq!(parsed.content, "hello world");
+    assert_eq!(parsed.to_line(), "2024-01-02T03:04:05.123456Z stdout P hello world");
+}
+
+#[test]
+fn test_containerlog_parse_docker() {
+    let line = r#"{"log":"hello\n","stream":"stderr","time":"2024-01-02T03:04:05.5Z"}"#;
+    let parsed = containerlog_parse(line, &FO_P8).unwrap();
+    assert_eq!(parsed.format, ContainerLogFormat::Docker);
+    assert_eq!(parsed.stream, ContainerStream::Stderr);
+    assert!(!parsed.partial);
+    assert_eq!(parsed.dt, ymdhmsm(&FO_0, 2024, 1, 2, 3, 4, 5, 500000));
+    assert_eq!(&line[parsed.dt_beg..parsed.dt_end], "2024-01-02T03:04:05.5Z");
+    assert_eq!(parsed.content, "hello");
+    assert_eq!(parsed.to_line(), line);
+}
+
+#[test_case("2024-01-02T03:04:05Z stdout F", false, ""; "CRI empty")]
+#[test_case("2024-01-02T03:04:05+01:00 stderr F:x  two  spaces", false, " two  spaces"; "CRI tags")]
+#[test_case(r#"{"log":"split","stream":"stdout","time":"2024-01-02T03:04:05Z"}"#, true, "split"; "Docker partial")]
+fn test_containerlog_parse_content(
+    line: &str,
+    partial: bool,
+    content: &str,
+) {
+    let parsed = containerlog_parse(line, &FO_P8).unwrap();
+    assert_eq!(parsed.partial, partial);
+    assert_eq!(parsed.content, content);
+}
+
+#[test_case("2024-01-02T03:04:05Z stdin F text"; "CRI bad stream")]
+#[test_case("2024-01-02T03:04:05Z stdout X text"; "CRI bad tag")]
+#[test_case("2024-01-02 03:04:05 stdout F text"; "CRI bad datetime")]
+#[test_case("Jan  2 03:04:05 host prog: stdout F"; "syslog")]
+#[test_case(r#"{"log":"a\n","stream":"stdout"}"#; "Docker no time")]
+#[test_case(r#"{"msg":"a","stream":"stdout","time":"2024-01-02T03:04:05Z"}"#; "JSON line")]
+#[test_case(""; "empty")]
+fn test_containerlog_parse_none(line: &str) {
+    assert_eq!(containerlog_parse(line, &FO_P8), None);
+}
+
+#[test]
+fn test_ContainerLog_new_cri() {
+    let dt = ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5);
+    let text = "\
+2024-01-02T03:04:05Z stdout P a
+2024-01-02T03:04:05Z stderr F error
+2024-01-02T03:04:05Z stdout P b
+2024-01-02T03:04:05Z stdout F c
+not a container line
+";
+    let containerlog = ContainerLog::new(dt, text);
+    assert_eq!(containerlog.dt(), &dt);
+    assert_eq!(containerlog.stream, Some(ContainerStream::Stdout));
+    assert!(containerlog.joined);
+    assert_eq!(
+        containerlog.lines,
+        vec![
+            "2024-01-02T03:04:05Z stdout P a",
+            "2024-01-02T03:04:05Z stderr F error",
+            "2024-01-02T03:04:05Z stdout F bc",
+            "not a container line",
+        ]
+    );
+    assert!(containerlog.matches(None));
+    assert!(containerlog.matches(Some(ContainerStream::Stdout)));
+    assert!(!containerlog.matches(Some(ContainerStream::Stderr)));
+}
+
+#[test]
+fn test_ContainerLog_new_docker() {
+    let dt = ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5);
+    let text = concat!(
+        r#"{"log":"a \"b","stream":"stdout","time":"2024-01-02T03:04:05Z"}"#,
+        "\n",
+        r#"{"log":"\" c\n","stream":"stdout","time":"2024-01-02T03:04:05Z"}"#,
+        "\n",
+    );
+    let containerlog = ContainerLog::new(dt, text);
+    assert!(containerlog.joined);
+    assert_eq!(
+        containerlog.lines,
+        vec![r#"{"log":"a \"b\" c\n","stream":"stdout","time":"2024-01-02T03:04:05Z"}"#]
+    );
+}
+
+#[test]
+fn test_ContainerLog_new_not_joined() {
+    let dt = ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5);
+    let containerlog = ContainerLog::new(dt, "Jan  2 03:04:05 host prog: hello\n  continued\n");
+    assert_eq!(containerlog.stream, None);
+    assert!(!containerlog.joined);
+    assert_eq!(containerlog.lines, vec!["Jan  2 03:04:05 host prog: hello", "  continued"]);
+    assert!(containerlog.matches(None));
+    assert!(!containerlog.matches(Some(ContainerStream::Stdout)));
+}
+
+/// push each line of `data` to a `ContainerLogJoiner`, return the text of
+/// each released `Sysline` after each push, and after `finish`
+fn joiner_push_lines(data: &[&str]) -> (Vec<Vec<String>>, Vec<String>) {
+    let dt = ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5);
+    let mut joiner = ContainerLogJoiner::new();
+    let mut released: Vec<Vec<String>> = Vec::new();
+    for line in data.iter() {
+        let syslinep = new_syslinep(&format!("{}\n", line), dt);
+        released.push(
+            joiner
+                .push(syslinep)
+                .iter()
+                .map(|syslinep| syslinep.to_String())
+                .collect(),
+        );
+    }
+    let finished: Vec<String> = joiner
+        .finish()
+        .iter()
+        .map(|syslinep| syslinep.to_String())
+        .collect();
+    assert_eq!(joiner.count_held(), 0);
+
+    (released, finished)
+}
+
+#[test]
+fn test_ContainerLogJoiner_interleaved() {
+    let (released, finished) = joiner_push_lines(&[
+        "2024-01-02T03:04:05Z stdout P a",
+        "2024-01-02T03:04:05Z stderr F error",
+        "2024-01-02T03:04:05Z stdout P b",
+        "2024-01-02T03:04:05Z stdout F c",
+        "2024-01-02T03:04:06Z stdout F d",
+    ]);
+    assert_eq!(
+        released,
+        vec![
+            vec![],
+            vec![],
+            vec![],
+            vec![
+                String::from(
+                    "2024-01-02T03:04:05Z stdout P a\n2024-01-02T03:04:05Z stdout P b\n2024-01-02T03:04:05Z stdout F c\n"
+                ),
+                String::from("2024-01-02T03:04:05Z stderr F error\n"),
+            ],
+            vec![String::from("2024-01-02T03:04:06Z stdout F d\n")],
+        ]
+    );
+    assert!(finished.is_empty());
+}
+
+#[test]
+fn test_ContainerLogJoiner_streams_pending() {
+    let (released, finished) = joiner_push_lines(&[
+        "2024-01-02T03:04:05Z stdout P a",
+        "2024-01-02T03:04:05Z stderr P x",
+        "2024-01-02T03:04:05Z stdout F b",
+        "2024-01-02T03:04:05Z stderr P y",
+    ]);
+    assert_eq!(
+        released,
+        vec![
+            vec![],
+            vec![],
+            vec![String::from("2024-01-02T03:04:05Z stdout P a\n2024-01-02T03:04:05Z stdout F b\n")],
+            vec![],
+        ]
+    );
+    // a partial line never ended is released by `finish`
+    assert_eq!(
+        finished,
+        vec![String::from("2024-01-02T03:04:05Z stderr P x\n2024-01-02T03:04:05Z stderr P y\n")]
+    );
+}
+
+#[test]
+fn test_ContainerLogJoiner_held_max() {
+    let mut data: Vec<&str> = vec!["2024-01-02T03:04:05Z stdout P a"];
+    data.extend(std::iter::repeat_n("2024-01-02T03:04:05Z stderr F error", ContainerLogJoiner::HELD_MAX));
+    let (released, finished) = joiner_push_lines(&data);
+    // the partial line is released as it is rather than hold more
+    // `Sysline`s
+    let count: usize = released
+        .iter()
+        .take(ContainerLogJoiner::HELD_MAX)
+        .map(|released| released.len())
+        .sum();
+    assert_eq!(count, 0);
+    let last: &Vec<String> = released.last().unwrap();
+    assert_eq!(last.len(), ContainerLogJoiner::HELD_MAX + 1);
+    assert_eq!(last[0], "2024-01-02T03:04:05Z stdout P a\n");
+    assert!(finished.is_empty());
+}
+
+#[test]
+fn test_ContainerLogJoiner_joined_ContainerLog() {
+    let dt = ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5);
+    let mut joiner = ContainerLogJoiner::new();
+    assert!(joiner.push(new_syslinep("2024-01-02T03:04:05Z stdout P a\n", dt)).is_empty());
+    assert!(joiner.push(new_syslinep("2024-01-02T03:04:05Z stderr F error\n", dt)).is_empty());
+    let released: Vec<SyslineP> = joiner.push(new_syslinep("2024-01-02T03:04:05Z stdout F b\n", dt));
+    assert_eq!(released.len(), 2);
+    assert_eq!(released[0].count_lines(), 2);
+    assert_eq!(released[0].dt(), &dt);
+    // the fragments are one log message of one line
+    let containerlog = ContainerLog::from_syslinep(&released[0]);
+    assert!(containerlog.joined);
+    assert_eq!(containerlog.lines, vec!["2024-01-02T03:04:05Z stdout F ab"]);
+}
+
+#[test]
+fn test_ContainerLogJoiner_not_containerlog() {
+    let (released, finished) = joiner_push_lines(&[
+        "Jan  2 03:04:05 host prog: hello",
+        "2024-01-02 03:04:06 stdout P world",
+    ]);
+    assert_eq!(
+        released,
+        vec![
+            vec![String::from("Jan  2 03:04:05 host prog: hello\n")],
+            vec![String::from("2024-01-02 03:04:06 stdout P world\n")],
+        ]
+    );
+    assert!(finished.is_empty());
+}
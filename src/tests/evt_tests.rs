@@ -0,0 +1,230 @@
+// src/tests/evt_tests.rs
+
+//! tests for `evt.rs`
+
+#![allow(non_snake_case)]
+
+use ::test_case::test_case;
+
+use crate::data::evt::{
+    sid_to_string,
+    EvtRecord,
+    EVT_RECORD_HEADER_SIZE,
+    EVT_SIGNATURE,
+};
+use crate::data::evtx::{
+    Evtx,
+    EvtxFilter,
+    EvtxOutput,
+};
+use crate::tests::common::FO_0;
+
+/// binary SID `S-1-5-18`
+pub const SID_LOCAL_SYSTEM: &[u8] = &[1, 1, 0, 0, 0, 0, 0, 5, 18, 0, 0, 0];
+
+/// `EventType` `EVENTLOG_ERROR_TYPE`
+pub const EVT_ERROR: u16 = 0x0001;
+/// `EventType` `EVENTLOG_INFORMATION_TYPE`
+pub const EVT_INFORMATION: u16 = 0x0004;
+
+fn utf16z(s: &str) -> Vec<u8> {
+    let mut b: Vec<u8> = Vec::new();
+    for unit in s.encode_utf16().chain(std::iter::once(0)) {
+        b.extend_from_slice(&unit.to_le_bytes());
+    }
+
+    b
+}
+
+/// Create the bytes of an `EVENTLOGRECORD`.
+#[allow(clippy::too_many_arguments)]
+pub fn evt_record_bytes(
+    record_number: u32,
+    time_generated: u32,
+    event_id: u32,
+    event_type: u16,
+    source_name: &str,
+    computer_name: &str,
+    sid: &[u8],
+    strings: &[&str],
+    data: &[u8],
+) -> Vec<u8> {
+    let mut body: Vec<u8> = Vec::new();
+    body.extend(utf16z(source_name));
+    body.extend(utf16z(computer_name));
+    while !(EVT_RECORD_HEADER_SIZE + body.len()).is_multiple_of(4) {
+        body.push(0);
+    }
+    let sid_offset: usize = EVT_RECORD_HEADER_SIZE + body.len();
+    body.extend_from_slice(sid);
+    let string_offset: usize = EVT_RECORD_HEADER_SIZE + body.len();
+    for s in strings.iter() {
+        body.extend(utf16z(s));
+    }
+    let data_offset: usize = EVT_RECORD_HEADER_SIZE + body.len();
+    body.extend_from_slice(data);
+    while !(EVT_RECORD_HEADER_SIZE + body.len()).is_multiple_of(4) {
+        body.push(0);
+    }
+    let length: u32 = (EVT_RECORD_HEADER_SIZE + body.len() + 4) as u32;
+
+    let mut r: Vec<u8> = Vec::new();
+    r.extend_from_slice(&length.to_le_bytes());
+    r.extend_from_slice(EVT_SIGNATURE);
+    r.extend_from_slice(&record_number.to_le_bytes());
+    r.extend_from_slice(&time_generated.to_le_bytes());
+    r.extend_from_slice(&(time_generated + 1).to_le_bytes());
+    r.extend_from_slice(&event_id.to_le_bytes());
+    r.extend_from_slice(&event_type.to_le_bytes());
+    r.extend_from_slice(&(strings.len() as u16).to_le_bytes());
+    // EventCategory, ReservedFlags
+    r.extend_from_slice(&3u16.to_le_bytes());
+    r.extend_from_slice(&0u16.to_le_bytes());
+    // ClosingRecordNumber
+    r.extend_from_slice(&0u32.to_le_bytes());
+    r.extend_from_slice(&(string_offset as u32).to_le_bytes());
+    r.extend_from_slice(&(sid.len() as u32).to_le_bytes());
+    r.extend_from_slice(&(sid_offset as u32).to_le_bytes());
+    r.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    r.extend_from_slice(&(data_offset as u32).to_le_bytes());
+    assert_eq!(r.len(), EVT_RECORD_HEADER_SIZE);
+    r.extend(body);
+    r.extend_from_slice(&length.to_le_bytes());
+
+    r
+}
+
+/// A simple record with record number `n` generated at time `1000000000 + n`.
+pub fn evt_record_n(n: u32) -> Vec<u8> {
+    evt_record_bytes(
+        n,
+        1000000000 + n,
+        0x40001B58,
+        EVT_INFORMATION,
+        "Service Control Manager",
+        "HOST1",
+        SID_LOCAL_SYSTEM,
+        &["Telephony", "running"],
+        &[],
+    )
+}
+
+#[test_case(SID_LOCAL_SYSTEM => Some(String::from("S-1-5-18")))]
+#[test_case(&[1, 2, 0, 0, 0, 0, 0, 5, 32, 0, 0, 0, 32, 2, 0, 0] => Some(String::from("S-1-5-32-544")); "builtin administrators")]
+#[test_case(&[1, 0, 0, 0, 0, 0, 0, 1] => Some(String::from("S-1-1")); "no subauthority")]
+#[test_case(&[1, 2, 0, 0, 0, 0, 0, 5, 32, 0, 0, 0] => None; "short")]
+#[test_case(&[] => None; "empty")]
+fn test_sid_to_string(sid: &[u8]) -> Option<String> {
+    sid_to_string(sid)
+}
+
+#[test]
+fn test_EvtRecord_from_bytes() {
+    let data: Vec<u8> = evt_record_bytes(
+        7,
+        1200000000,
+        0xC0001B58,
+        EVT_ERROR,
+        "Service Control Manager",
+        "HOST1",
+        SID_LOCAL_SYSTEM,
+        &["a <b>", "c"],
+        &[0x0A, 0xFF],
+    );
+    let record: EvtRecord = EvtRecord::from_bytes(&data).unwrap();
+    assert_eq!(record.record_number, 7);
+    assert_eq!(record.time_generated, 1200000000);
+    assert_eq!(record.time_written, 1200000001);
+    assert_eq!(record.event_id_code(), 7000);
+    assert_eq!(record.event_id_qualifiers(), 0xC000);
+    assert_eq!(record.level(), 2);
+    assert_eq!(record.event_category, 3);
+    assert_eq!(record.source_name, "Service Control Manager");
+    assert_eq!(record.computer_name, "HOST1");
+    assert_eq!(record.user_sid.as_deref(), Some("S-1-5-18"));
+    assert_eq!(record.strings, vec!["a <b>", "c"]);
+    assert_eq!(record.data, vec![0x0A, 0xFF]);
+
+    let xml: String = record.to_xml("System");
+    assert!(xml.contains(r#"<EventID Qualifiers="49152">7000</EventID>"#), "{}", xml);
+    assert!(xml.contains(r#"<TimeCreated SystemTime="2008-01-10T21:20:00Z">"#), "{}", xml);
+    assert!(xml.contains("<Data>a &lt;b&gt;</Data>"), "{}", xml);
+    assert!(xml.contains("<Binary>0AFF</Binary>"), "{}", xml);
+    assert!(xml.ends_with("</Event>\n"), "{}", xml);
+}
+
+#[test_case(0, 1; "length zero")]
+#[test_case(4, 0xFF; "bad signature")]
+#[test_case(37, 0xFF; "string offset past end")]
+#[test_case(80, 0xFF; "source name not terminated")]
+fn test_EvtRecord_from_bytes_invalid(
+    index: usize,
+    xor: u8,
+) {
+    let mut data: Vec<u8> = evt_record_n(1);
+    if index == 80 {
+        // overwrite the NULs of the source name and computer name
+        let len: usize = data.len();
+        for b in data[EVT_RECORD_HEADER_SIZE..len - 4].iter_mut() {
+            if *b == 0 {
+                *b = b'x';
+            }
+        }
+    } else {
+        data[index] ^= xor;
+    }
+    assert_eq!(EvtRecord::from_bytes(&data), None);
+}
+
+#[test]
+fn test_EvtRecord_from_bytes_length_copy() {
+    let mut data: Vec<u8> = evt_record_n(1);
+    let len: usize = data.len();
+    data[len - 4] ^= 0x04;
+    assert_eq!(EvtRecord::from_bytes(&data), None);
+}
+
+#[test_case(EvtxOutput::Xml, "<Channel>System</Channel>")]
+#[test_case(EvtxOutput::Json, r##""Data":{"#text":["Telephony","running"]}"##)]
+#[test_case(EvtxOutput::JsonPretty, "\"EventRecordID\": 2,")]
+#[test_case(EvtxOutput::Short, "2001-09-09T01:46:42Z HOST1 Service Control Manager[7000]: Data=Telephony,running\n")]
+fn test_EvtRecord_to_evtxrs(
+    evtx_output: EvtxOutput,
+    expect: &str,
+) {
+    let record: EvtRecord = EvtRecord::from_bytes(&evt_record_n(2)).unwrap();
+    let evtxrs = record
+        .to_evtxrs("System", evtx_output, &EvtxFilter::default())
+        .unwrap();
+    assert_eq!(evtxrs.event_record_id, 2);
+    assert!(evtxrs.data.contains(expect), "{}", evtxrs.data);
+    assert!(evtxrs.data.ends_with('\n'));
+    // the datetime substring is found
+    let evtx: Evtx = Evtx::from_evtxrs(None, &FO_0, evtxrs, evtx_output);
+    let (beg, end) = evtx.dt_beg_end().unwrap();
+    assert_eq!(&evtx.as_bytes()[beg..end], b"2001-09-09T01:46:42Z");
+}
+
+#[test_case(Some("7000"), None, None, None => true; "event id")]
+#[test_case(Some("7001"), None, None, None => false; "event id other")]
+#[test_case(None, Some("service control manager"), None, None => true; "provider")]
+#[test_case(None, None, Some("System"), None => true; "channel")]
+#[test_case(None, None, Some("Application"), None => false; "channel other")]
+#[test_case(None, None, None, Some("information") => true; "level")]
+#[test_case(None, None, None, Some("error") => false; "level other")]
+fn test_EvtRecord_to_evtxrs_filter(
+    event_ids: Option<&str>,
+    providers: Option<&str>,
+    channels: Option<&str>,
+    levels: Option<&str>,
+) -> bool {
+    let record: EvtRecord = EvtRecord::from_bytes(&evt_record_n(2)).unwrap();
+    let filter = EvtxFilter::new(event_ids, providers, channels, levels, None, &[]).unwrap();
+    let mut results: Vec<bool> = Vec::new();
+    for evtx_output in EvtxOutput::iterator() {
+        results.push(record.to_evtxrs("System", *evtx_output, &filter).is_some());
+    }
+    assert!(results.iter().all(|r| *r == results[0]), "{:?}", results);
+
+    results[0]
+}
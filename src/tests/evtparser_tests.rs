@@ -0,0 +1,266 @@
+// src/tests/evtparser_tests.rs
+
+//! tests for `evtparser.rs`
+
+#![allow(non_snake_case)]
+
+use ::test_case::test_case;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileType,
+    FileTypeArchive,
+    summary_stats_enable,
+};
+use crate::data::evt::EvtRecord;
+use crate::data::evtx::{
+    EvtxFilter,
+    EvtxOutput,
+};
+use crate::debug::helpers::{
+    create_temp_file_bytes_with_suffix,
+    ntf_fpath,
+};
+use crate::readers::evtparser::{
+    evt_channel,
+    EvtEofRecord,
+    EvtFileHeader,
+    EvtParser,
+    ELF_LOGFILE_HEADER_DIRTY,
+    ELF_LOGFILE_HEADER_WRAP,
+    EVT_EOF_RECORD_SIZE,
+    EVT_EOF_SIGNATURE,
+    EVT_FILE_HEADER_SIZE,
+};
+use crate::readers::evtxreader::EvtxReader;
+use crate::tests::common::{
+    path_id_generator,
+    FO_0,
+};
+use crate::tests::evt_tests::evt_record_n;
+
+const FT_EVT: FileType = FileType::Evt { archival_type: FileTypeArchive::Normal };
+
+/// Create the bytes of an `.evt` file of `size` bytes with `records` written
+/// to the circular buffer beginning at `begin`.
+/// The file header offsets are `header_offsets` if given.
+fn evt_file_bytes(
+    size: usize,
+    begin: usize,
+    records: &[Vec<u8>],
+    flags: u32,
+    header_offsets: Option<(u32, u32)>,
+) -> Vec<u8> {
+    let mut data: Vec<u8> = vec![0; size];
+    let mut at: usize = begin;
+    // write to the circular buffer
+    let write = |data: &mut Vec<u8>, at: &mut usize, bytes: &[u8]| {
+        for b in bytes.iter() {
+            data[*at] = *b;
+            *at += 1;
+            if *at == size {
+                *at = EVT_FILE_HEADER_SIZE;
+            }
+        }
+    };
+    for record in records.iter() {
+        write(&mut data, &mut at, record);
+    }
+    let end: usize = at;
+    let mut eof: Vec<u8> = Vec::new();
+    eof.extend_from_slice(&(EVT_EOF_RECORD_SIZE as u32).to_le_bytes());
+    eof.extend_from_slice(EVT_EOF_SIGNATURE);
+    eof.extend_from_slice(&(begin as u32).to_le_bytes());
+    eof.extend_from_slice(&(end as u32).to_le_bytes());
+    eof.extend_from_slice(&(records.len() as u32 + 1).to_le_bytes());
+    eof.extend_from_slice(&1u32.to_le_bytes());
+    eof.extend_from_slice(&(EVT_EOF_RECORD_SIZE as u32).to_le_bytes());
+    write(&mut data, &mut at, &eof);
+
+    let (start_offset, end_offset) = header_offsets.unwrap_or((begin as u32, end as u32));
+    let mut header: Vec<u8> = Vec::new();
+    header.extend_from_slice(&(EVT_FILE_HEADER_SIZE as u32).to_le_bytes());
+    header.extend_from_slice(b"LfLe");
+    header.extend_from_slice(&1u32.to_le_bytes());
+    header.extend_from_slice(&1u32.to_le_bytes());
+    header.extend_from_slice(&start_offset.to_le_bytes());
+    header.extend_from_slice(&end_offset.to_le_bytes());
+    header.extend_from_slice(&(records.len() as u32 + 1).to_le_bytes());
+    header.extend_from_slice(&1u32.to_le_bytes());
+    header.extend_from_slice(&(size as u32).to_le_bytes());
+    header.extend_from_slice(&flags.to_le_bytes());
+    header.extend_from_slice(&0u32.to_le_bytes());
+    header.extend_from_slice(&(EVT_FILE_HEADER_SIZE as u32).to_le_bytes());
+    data[..EVT_FILE_HEADER_SIZE].copy_from_slice(&header);
+
+    data
+}
+
+fn records_1_to(n: u32) -> Vec<Vec<u8>> {
+    (1..=n).map(evt_record_n).collect()
+}
+
+fn record_numbers(records: &[EvtRecord]) -> Vec<u32> {
+    records.iter().map(|r| r.record_number).collect()
+}
+
+#[test_case("SysEvent.Evt", "System")]
+#[test_case("/x/APPEVENT.EVT", "Application")]
+#[test_case("secevent.evt.gz", "Security")]
+#[test_case("/x/a.tar\0SysEvent.Evt", "System"; "tar")]
+#[test_case("DnsEvent.Evt", "DnsEvent")]
+fn test_evt_channel(path: &str, expect: &str) {
+    assert_eq!(evt_channel(&FPath::from(path)), expect);
+}
+
+#[test]
+fn test_EvtFileHeader_from_bytes() {
+    let data: Vec<u8> = evt_file_bytes(0x1000, EVT_FILE_HEADER_SIZE, &records_1_to(1), ELF_LOGFILE_HEADER_DIRTY, None);
+    let header: EvtFileHeader = EvtFileHeader::from_bytes(&data).unwrap();
+    assert_eq!(header.start_offset as usize, EVT_FILE_HEADER_SIZE);
+    assert_eq!(header.max_size, 0x1000);
+    assert!(header.is_dirty());
+    assert!(!header.is_wrapped());
+    let eof: EvtEofRecord = EvtEofRecord::find(&data).unwrap();
+    assert_eq!(eof.offset, header.end_offset as usize);
+    assert_eq!(eof.begin_record, header.start_offset);
+
+    assert_eq!(EvtFileHeader::from_bytes(&data[..EVT_FILE_HEADER_SIZE - 1]), None);
+    assert_eq!(EvtFileHeader::from_bytes(&[0; EVT_FILE_HEADER_SIZE]), None);
+}
+
+/// the records fill the file from the header
+#[test]
+fn test_EvtParser_records_linear() {
+    let data: Vec<u8> = evt_file_bytes(0x2000, EVT_FILE_HEADER_SIZE, &records_1_to(5), 0, None);
+    let mut parser = EvtParser::from_vec(data, false).unwrap();
+    let records: Vec<EvtRecord> = parser.records();
+    assert_eq!(record_numbers(&records), vec![1, 2, 3, 4, 5]);
+    assert_eq!(parser.records_dropped, 0);
+    assert_eq!(parser.records_recovered, 0);
+}
+
+/// the oldest record is near the end of the file and the third record is
+/// split by the end of the file
+#[test]
+fn test_EvtParser_records_wrapped() {
+    let records: Vec<Vec<u8>> = records_1_to(5);
+    let size: usize = 0x400;
+    let begin: usize = size - records[0].len() - records[1].len() - 20;
+    let data: Vec<u8> = evt_file_bytes(size, begin, &records, ELF_LOGFILE_HEADER_WRAP, None);
+    assert!(EvtFileHeader::from_bytes(&data).unwrap().is_wrapped());
+    let mut parser = EvtParser::from_vec(data.clone(), false).unwrap();
+    assert_eq!(record_numbers(&parser.records()), vec![1, 2, 3, 4, 5]);
+    assert_eq!(parser.records_dropped, 0);
+
+    // recovery mode finds no more records and the split record is not dropped
+    let mut parser = EvtParser::from_vec(data, true).unwrap();
+    assert_eq!(record_numbers(&parser.records()), vec![1, 2, 3, 4, 5]);
+    assert_eq!(parser.records_recovered, 0);
+    assert_eq!(parser.records_dropped, 0);
+}
+
+/// a dirty file header has stale offsets; the "end of file" record is used
+#[test]
+fn test_EvtParser_records_dirty() {
+    let stale: (u32, u32) = (EVT_FILE_HEADER_SIZE as u32, EVT_FILE_HEADER_SIZE as u32);
+    let data: Vec<u8> = evt_file_bytes(0x2000, EVT_FILE_HEADER_SIZE, &records_1_to(4), ELF_LOGFILE_HEADER_DIRTY, Some(stale));
+    let mut parser = EvtParser::from_vec(data, false).unwrap();
+    assert_eq!(record_numbers(&parser.records()), vec![1, 2, 3, 4]);
+}
+
+/// a damaged record is skipped
+#[test]
+fn test_EvtParser_records_damaged() {
+    let records: Vec<Vec<u8>> = records_1_to(3);
+    let mut data: Vec<u8> = evt_file_bytes(0x2000, EVT_FILE_HEADER_SIZE, &records, 0, None);
+    // damage the trailing length copy of record 2
+    let at: usize = EVT_FILE_HEADER_SIZE + records[0].len() + records[1].len() - 4;
+    data[at] ^= 0xFF;
+    let mut parser = EvtParser::from_vec(data, false).unwrap();
+    assert_eq!(record_numbers(&parser.records()), vec![1, 3]);
+    assert_eq!(parser.records_dropped, 1);
+}
+
+/// an old record outside of the live records is found in recovery mode
+#[test]
+fn test_EvtParser_records_recover() {
+    let records: Vec<Vec<u8>> = records_1_to(3);
+    let begin: usize = EVT_FILE_HEADER_SIZE + 0x100;
+    let mut data: Vec<u8> = evt_file_bytes(0x2000, begin, &records, 0, None);
+    let old: Vec<u8> = evt_record_n(99);
+    data[EVT_FILE_HEADER_SIZE..EVT_FILE_HEADER_SIZE + old.len()].copy_from_slice(&old);
+
+    let mut parser = EvtParser::from_vec(data.clone(), false).unwrap();
+    assert_eq!(record_numbers(&parser.records()), vec![1, 2, 3]);
+
+    let mut parser = EvtParser::from_vec(data.clone(), true).unwrap();
+    assert_eq!(record_numbers(&parser.records()), vec![1, 2, 3, 99]);
+    assert_eq!(parser.records_recovered, 1);
+    assert_eq!(parser.records_dropped, 0);
+
+    // without a file header only recovery mode reads records
+    data[4] = b'X';
+    assert!(EvtParser::from_vec(data.clone(), false).is_err());
+    let mut parser = EvtParser::from_vec(data, true).unwrap();
+    assert_eq!(record_numbers(&parser.records()), vec![99, 1, 2, 3]);
+}
+
+#[test_case(&[]; "empty")]
+#[test_case(&[0u8; 512]; "zeros")]
+#[test_case(b"LfLe"; "signature only")]
+fn test_EvtParser_invalid(data: &[u8]) {
+    assert!(EvtParser::from_vec(data.to_vec(), false).is_err());
+    let mut parser = EvtParser::from_vec(data.to_vec(), true).unwrap();
+    assert!(parser.records().is_empty());
+}
+
+/// An `EvtxReader` reads a `.evt` file.
+#[test_case(EvtxOutput::Xml, false)]
+#[test_case(EvtxOutput::Short, false)]
+#[test_case(EvtxOutput::Json, true)]
+fn test_EvtxReader_evt(
+    evtx_output: EvtxOutput,
+    evtx_recover: bool,
+) {
+    summary_stats_enable();
+    let data: Vec<u8> = evt_file_bytes(0x2000, EVT_FILE_HEADER_SIZE, &records_1_to(6), 0, None);
+    let ntf = create_temp_file_bytes_with_suffix(&data, &String::from(".evt"));
+    let fpath = ntf_fpath(&ntf);
+
+    let mut evtxreader = EvtxReader::new(
+        path_id_generator(),
+        fpath,
+        evtx_output,
+        evtx_recover,
+        FT_EVT,
+        FO_0,
+    ).unwrap();
+    let filter = EvtxFilter::new(Some("7000"), None, None, None, None, &[]).unwrap();
+    evtxreader.analyze(&None, &None, &filter);
+    let mut count: Count = 0;
+    while let Some(evtx) = evtxreader.next() {
+        assert!(evtx.dt_beg_end().is_some());
+        assert_eq!(evtx.id(), count + 1);
+        count += 1;
+    }
+    assert_eq!(count, 6, "count");
+    let summary = evtxreader.summary();
+    assert_eq!(summary.evtxreader_events_accepted, 6);
+    assert_eq!(summary.evtxreader_records_dropped, 0);
+
+    // a file that is not `.evt` has an error
+    let ntf = create_temp_file_bytes_with_suffix(&[0u8; 512], &String::from(".evt"));
+    let mut evtxreader = EvtxReader::new(
+        path_id_generator(),
+        ntf_fpath(&ntf),
+        evtx_output,
+        false,
+        FT_EVT,
+        FO_0,
+    ).unwrap();
+    evtxreader.analyze(&None, &None, &filter);
+    assert!(evtxreader.next().is_none());
+    assert!(evtxreader.summary_complete().error.is_some());
+}
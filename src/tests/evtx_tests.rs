@@ -14,9 +14,12 @@ use ::test_case::test_case;
 use crate::data::common::DtBegEndPairOpt;
 use crate::data::evtx::{
     Evtx,
+    EvtxFilter,
+    EvtxOutput,
     EvtxRS,
     RecordId,
     Timestamp,
+    json_value_to_short,
 };
 use crate::tests::common::{
     EVTX_KPNP_DATA1_S,
@@ -46,17 +49,151 @@ lazy_static! {
     );
 }
 
-#[test_case("", None)]
-#[test_case(EVTX_KPNP_DATA1_S, DtBegEndPairOpt::Some((420, 447)))]
-#[test_case(EVTX_KPNP_DATA1_S_E, None)]
+#[test_case("", EvtxOutput::Xml, None)]
+#[test_case(EVTX_KPNP_DATA1_S, EvtxOutput::Xml, DtBegEndPairOpt::Some((420, 447)))]
+#[test_case(EVTX_KPNP_DATA1_S_E, EvtxOutput::Xml, None)]
+#[test_case("", EvtxOutput::Json, None)]
+#[test_case(EVTX_KPNP_DATA1_S, EvtxOutput::Json, None)]
+#[test_case(
+    r##"{"Event":{"System":{"TimeCreated":{"#attributes":{"SystemTime":"2023-03-10T03:49:43.558721Z"}}}}}"##,
+    EvtxOutput::Json,
+    DtBegEndPairOpt::Some((64, 91))
+)]
+#[test_case(
+    r##"{"Event":{"System":{"TimeCreated":{"#attributes":{"SystemTime": "2023-03-10T03:49:43.558721Z"}}}}}"##,
+    EvtxOutput::JsonPretty,
+    DtBegEndPairOpt::Some((65, 92))
+)]
+#[test_case(r#"{"SystemTime":"2023"#, EvtxOutput::Json, None)]
+#[test_case("", EvtxOutput::Short, None)]
+#[test_case(" host", EvtxOutput::Short, None)]
+#[test_case("2023-03-10T03:49:43.558721Z host", EvtxOutput::Short, DtBegEndPairOpt::Some((0, 27)))]
 fn test_get_dt_beg_end(
     input: &str,
+    evtx_output: EvtxOutput,
     expect: DtBegEndPairOpt,
 ) {
-    let result = Evtx::get_dt_beg_end(input);
+    let result = Evtx::get_dt_beg_end(input, evtx_output);
     assert_eq!(result, expect);
 }
 
+#[test_case(
+    r##"{"Event":{"System":{"Provider":{"#attributes":{"Name":"OpenSSH"}},"EventID":2,"TimeCreated":{"#attributes":{"SystemTime":"2023-03-16T20:20:23.130640Z"}},"Computer":"host1"},"EventData":{"process":"sshd.exe","payload":"error: Connection closed"}}}"##,
+    "2023-03-16T20:20:23.130640Z host1 OpenSSH[2]: process=sshd.exe payload=\"error: Connection closed\"\n";
+    "EventData"
+)]
+#[test_case(
+    r##"{"Event":{"System":{"Provider":{"#attributes":{"Name":"Security"}},"EventID":{"#attributes":{"Qualifiers":0},"#text":4624},"Computer":"host2"},"UserData":{"LogFileCleared":{"SubjectUserName":"bob","Path":"a\tb"}}}}"##,
+    "2023-03-10T03:49:43Z host2 Security[4624]: SubjectUserName=bob Path=\"a\\tb\"\n";
+    "UserData and qualified EventID"
+)]
+#[test_case(
+    r##"{"Event":{"System":{"Provider":{"#attributes":{"Name":"P"}},"EventID":7,"Computer":"host3"}}}"##,
+    "2023-03-10T03:49:43Z host3 P[7]\n";
+    "no data"
+)]
+#[test_case(
+    r##"{"Event":{"System":{"EventID":7,"Computer":"host4"}}}"##,
+    "2023-03-10T03:49:43Z host4 [7]\n";
+    "no Provider"
+)]
+#[test_case(
+    r##"{"Event":{"System":{"Computer":"host5"}}}"##,
+    "2023-03-10T03:49:43Z host5\n";
+    "no Provider no EventID"
+)]
+#[test_case(
+    r##"{"Event":{"System":{"EventID":7},"EventData":{"Data":["a","b c"],"Binary":null}}}"##,
+    "2023-03-10T03:49:43Z [7]: Data=\"a,b c\" Binary=\"\"\n";
+    "unnamed Data"
+)]
+fn test_json_value_to_short(
+    json: &str,
+    expect: &str,
+) {
+    let value: ::serde_json::Value = ::serde_json::from_str(json).unwrap();
+    let short: String = json_value_to_short(&value, &EVTX_KPNP_DATA1_TIMESTAMP);
+    assert_eq!(short, expect);
+}
+
+#[test_case(EvtxOutput::Xml, "xml")]
+#[test_case(EvtxOutput::Json, "json")]
+#[test_case(EvtxOutput::JsonPretty, "json-pretty")]
+#[test_case(EvtxOutput::Short, "short")]
+fn test_EvtxOutput_str(
+    evtx_output: EvtxOutput,
+    s: &str,
+) {
+    assert_eq!(evtx_output.to_string(), s);
+    assert_eq!(s.parse::<EvtxOutput>(), Ok(evtx_output));
+    assert!(EvtxOutput::iterator().any(|e| *e == evtx_output));
+}
+
+#[test_case(None, None, None, None, None, &[] => Ok(EvtxFilter::default()); "empty")]
+#[test_case(Some("4624, 4700-4799,"), None, None, None, None, &[]
+    => Ok(EvtxFilter { event_ids: vec![(4624, 4624), (4700, 4799)], ..Default::default() }); "event_ids")]
+#[test_case(None, Some("P1,P2"), Some("Security"), Some("2,Warning"), Some("host1"), &[]
+    => Ok(EvtxFilter {
+        providers: vec![String::from("P1"), String::from("P2")],
+        channels: vec![String::from("Security")],
+        levels: vec![2, 3],
+        computers: vec![String::from("host1")],
+        ..Default::default()
+    }); "system")]
+#[test_case(None, None, None, None, None,
+    &[String::from("EventData/Data[@Name='TargetUserName']=bob"), String::from("Data[@Name=\"A\"]='x=y'"), String::from("B=")]
+    => Ok(EvtxFilter {
+        event_data: vec![
+            (String::from("TargetUserName"), String::from("bob")),
+            (String::from("A"), String::from("x=y")),
+            (String::from("B"), String::new()),
+        ],
+        ..Default::default()
+    }); "event_data")]
+#[test_case(Some("46x"), None, None, None, None, &[] => matches Err(_); "bad event_id")]
+#[test_case(Some("10-2"), None, None, None, None, &[] => matches Err(_); "bad event_id range")]
+#[test_case(None, None, None, Some("loud"), None, &[] => matches Err(_); "bad level")]
+#[test_case(None, None, None, None, None, &[String::from("TargetUserName")] => matches Err(_); "bad event_data")]
+#[test_case(None, None, None, None, None, &[String::from("Data[@Name='A'")] => matches Err(_); "bad event_data bracket")]
+fn test_EvtxFilter_new(
+    event_ids: Option<&str>,
+    providers: Option<&str>,
+    channels: Option<&str>,
+    levels: Option<&str>,
+    computers: Option<&str>,
+    event_data: &[String],
+) -> Result<EvtxFilter, String> {
+    EvtxFilter::new(event_ids, providers, channels, levels, computers, event_data)
+}
+
+const EVTX_FILTER_JSON: &str = r##"{"Event":{"System":{"Provider":{"#attributes":{"Name":"Microsoft-Windows-Security-Auditing"}},"EventID":{"#attributes":{"Qualifiers":0},"#text":4624},"Level":0,"Channel":"Security","Computer":"host1"},"EventData":{"TargetUserName":"bob","LogonType":3}}}"##;
+
+#[test_case(EvtxFilter::default() => true; "empty")]
+#[test_case(EvtxFilter { event_ids: vec![(4624, 4624)], ..Default::default() } => true; "event_id")]
+#[test_case(EvtxFilter { event_ids: vec![(1, 2), (4600, 4699)], ..Default::default() } => true; "event_id range")]
+#[test_case(EvtxFilter { event_ids: vec![(4625, 4625)], ..Default::default() } => false; "event_id no")]
+#[test_case(EvtxFilter { providers: vec![String::from("microsoft-windows-security-auditing")], ..Default::default() } => true; "provider")]
+#[test_case(EvtxFilter { providers: vec![String::from("Other")], ..Default::default() } => false; "provider no")]
+#[test_case(EvtxFilter { channels: vec![String::from("System"), String::from("Security")], ..Default::default() } => true; "channel")]
+#[test_case(EvtxFilter { levels: vec![0], ..Default::default() } => true; "level")]
+#[test_case(EvtxFilter { levels: vec![2, 3], ..Default::default() } => false; "level no")]
+#[test_case(EvtxFilter { computers: vec![String::from("HOST1")], ..Default::default() } => true; "computer")]
+#[test_case(EvtxFilter {
+    event_data: vec![(String::from("TargetUserName"), String::from("bob")), (String::from("LogonType"), String::from("3"))],
+    ..Default::default()
+} => true; "event_data")]
+#[test_case(EvtxFilter { event_data: vec![(String::from("TargetUserName"), String::from("alice"))], ..Default::default() } => false; "event_data no")]
+#[test_case(EvtxFilter { event_data: vec![(String::from("SubjectUserName"), String::from("bob"))], ..Default::default() } => false; "event_data missing")]
+#[test_case(EvtxFilter {
+    event_ids: vec![(4624, 4624)],
+    computers: vec![String::from("host2")],
+    ..Default::default()
+} => false; "event_id and computer no")]
+fn test_EvtxFilter_matches(evtx_filter: EvtxFilter) -> bool {
+    let value: ::serde_json::Value = ::serde_json::from_str(EVTX_FILTER_JSON).unwrap();
+    evtx_filter.matches(&value)
+}
+
 #[test_case(&EVTX_1, 1, true)]
 fn test_evtx(
     evtx: &Evtx,
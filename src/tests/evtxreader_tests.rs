@@ -33,6 +33,8 @@ use crate::data::common::DtBegEndPairOpt;
 use crate::data::datetime::DateTimeLOpt;
 use crate::data::evtx::{
     Evtx,
+    EvtxFilter,
+    EvtxOutput,
     EvtxRS,
     RecordId,
     Timestamp,
@@ -118,7 +120,7 @@ fn test_EvtxReader_new(
     path: &FPath,
     ok: bool,
 ) {
-    match EvtxReader::new(path_id_generator(), path.clone(), FT_NORM, FO_E8) {
+    match EvtxReader::new(path_id_generator(), path.clone(), EvtxOutput::Xml, false, FT_NORM, FO_E8) {
         Ok(_) => {
             assert!(ok, "EvtxReader::new({:?}) should have failed", path);
         }
@@ -134,7 +136,7 @@ fn test_new_EvtxReader_no_file_permissions() {
     let ntf = create_temp_file_no_permissions(".evtx");
     let path = ntf.path();
     let fpath = path_to_fpath(path);
-    match EvtxReader::new(path_id_generator(), fpath.clone(), FT_NORM, FO_E8) {
+    match EvtxReader::new(path_id_generator(), fpath.clone(), EvtxOutput::Xml, false, FT_NORM, FO_E8) {
         Ok(_) => {
             panic!("no permissions to read {:?}", path);
         }
@@ -148,7 +150,7 @@ fn test_new_EvtxReader_no_file_permissions() {
 #[test_case(&EVTX_NE_FPATH)]
 #[test_case(&EVTX_KPNP_FPATH)]
 fn test_mtime(path: &FPath) {
-    let er1 = EvtxReader::new(path_id_generator(), path.clone(), FT_NORM, FO_E8).unwrap();
+    let er1 = EvtxReader::new(path_id_generator(), path.clone(), EvtxOutput::Xml, false, FT_NORM, FO_E8).unwrap();
     // merely run the function
     _ = er1.mtime();
 }
@@ -158,7 +160,7 @@ fn test_mtime(path: &FPath) {
 #[test_case(&EVTX_NE_FPATH)]
 #[test_case(&EVTX_KPNP_FPATH)]
 fn test_EvtxReader_summary_empty(path: &FPath) {
-    let evtxreader = EvtxReader::new(path_id_generator(), path.clone(), FT_NORM, FO_E8).unwrap();
+    let evtxreader = EvtxReader::new(path_id_generator(), path.clone(), EvtxOutput::Xml, false, FT_NORM, FO_E8).unwrap();
     let summary = evtxreader.summary();
     assert_eq!(summary.evtxreader_event_largest_processed, 0, "summary.event_largest_processed");
     assert_eq!(summary.evtxreader_event_largest_accepted, 0, "summary.event_largest_accepted");
@@ -293,10 +295,12 @@ fn test_EvtxReader_next_summary(
     let mut evtxreader = EvtxReader::new(
         path_id_generator(),
         path.clone(),
+        EvtxOutput::Xml,
+        false,
         filetype,
         *EVTX_KPNP_ENTRY_FO,
     ).unwrap();
-    evtxreader.analyze(&None, &None);
+    evtxreader.analyze(&None, &None, &EvtxFilter::default());
     while let Some(evtx_) = evtxreader.next() {
         black_box(evtx_);
     }
@@ -359,3 +363,95 @@ fn test_EvtxReader_next_summary(
         }
     }
 }
+
+/// test each type of `--evtx-output`
+#[test_case(EvtxOutput::Xml, "<Event ", 7)]
+#[test_case(EvtxOutput::Json, "{\"Event\":{", 1)]
+#[test_case(EvtxOutput::JsonPretty, "{\n  \"Event\": {", 7)]
+#[test_case(EvtxOutput::Short, "2023-03-10T03:49:43.558721Z minwinpc Microsoft-Windows-Kernel-PnP[403]: DeviceInstanceId=ROOT\\ACPI_HAL\\0000 DriverName=hal.inf ", 1)]
+fn test_EvtxReader_evtx_output(
+    evtx_output: EvtxOutput,
+    contains: &str,
+    lines_min: usize,
+) {
+    let mut evtxreader = EvtxReader::new(
+        path_id_generator(),
+        EVTX_KPNP_FPATH.clone(),
+        evtx_output,
+        false,
+        FT_NORM,
+        *EVTX_KPNP_ENTRY_FO,
+    ).unwrap();
+    assert_eq!(evtxreader.evtx_output(), evtx_output);
+    evtxreader.analyze(&None, &None, &EvtxFilter::default());
+    let mut count: Count = 0;
+    let mut evtx1: Option<Evtx> = None;
+    while let Some(evtx) = evtxreader.next() {
+        assert!(evtx.ends_with_newline(), "record {} does not end with newline", evtx.id());
+        let (a, b) = evtx.dt_beg_end().unwrap();
+        let dts: &str = &evtx.as_str()[a..b];
+        assert!(dts.starts_with("2023-03-"), "bad dt_beg_end {:?} for record {}", dts, evtx.id());
+        if evtx1.is_none() {
+            evtx1 = Some(evtx);
+        }
+        count += 1;
+    }
+    assert_eq!(count, *EVTX_KPNP_EVENT_COUNT, "count");
+    let evtx1: Evtx = evtx1.unwrap();
+    let data1: &str = std::str::from_utf8(evtx1.as_bytes()).unwrap();
+    assert!(data1.contains(contains), "expected {:?} in\n{}", contains, data1);
+    let lines: usize = data1.lines().count();
+    match evtx_output {
+        EvtxOutput::Json | EvtxOutput::Short => assert_eq!(lines, lines_min, "lines in\n{}", data1),
+        EvtxOutput::Xml | EvtxOutput::JsonPretty => assert!(lines >= lines_min, "lines in\n{}", data1),
+    }
+}
+
+/// test `EvtxFilter` passed to `EvtxReader::analyze`
+#[test_case(EvtxFilter::default(), 227; "none")]
+#[test_case(EvtxFilter::new(Some("403"), None, None, None, None, &[]).unwrap(), 2; "event_id")]
+#[test_case(EvtxFilter::new(Some("400-410"), None, None, None, None, &[]).unwrap(), 223; "event_id range")]
+#[test_case(EvtxFilter::new(Some("403,411"), None, None, None, None, &[]).unwrap(), 3; "event_id list")]
+#[test_case(EvtxFilter::new(None, Some("microsoft-windows-kernel-pnp"), None, None, None, &[]).unwrap(), 227; "provider")]
+#[test_case(EvtxFilter::new(None, Some("Other"), None, None, None, &[]).unwrap(), 0; "provider no")]
+#[test_case(EvtxFilter::new(None, None, Some("Microsoft-Windows-Kernel-PnP/Configuration"), None, None, &[]).unwrap(), 227; "channel")]
+#[test_case(EvtxFilter::new(None, None, None, Some("error,warning"), None, &[]).unwrap(), 3; "level")]
+#[test_case(EvtxFilter::new(None, None, None, None, Some("minwinpc"), &[]).unwrap(), 153; "computer")]
+#[test_case(EvtxFilter::new(Some("400"), None, None, None, Some("minwinpc"), &[]).unwrap(), 74; "event_id and computer")]
+#[test_case(
+    EvtxFilter::new(None, None, None, None, None, &[String::from("EventData/Data[@Name='DriverName']=cpu.inf")]).unwrap(), 104;
+    "event_data"
+)]
+#[test_case(
+    EvtxFilter::new(Some("400"), None, None, None, None, &[String::from("DriverName=cpu.inf")]).unwrap(), 52;
+    "event_id and event_data"
+)]
+fn test_EvtxReader_evtx_filter(
+    evtx_filter: EvtxFilter,
+    events_accepted: Count,
+) {
+    summary_stats_enable();
+
+    for evtx_output in EvtxOutput::iterator() {
+        let mut evtxreader = EvtxReader::new(
+            path_id_generator(),
+            EVTX_KPNP_FPATH.clone(),
+            *evtx_output,
+            false,
+            FT_NORM,
+            *EVTX_KPNP_ENTRY_FO,
+        ).unwrap();
+        evtxreader.analyze(&None, &None, &evtx_filter);
+        let mut count: Count = 0;
+        while let Some(evtx) = evtxreader.next() {
+            black_box(evtx);
+            count += 1;
+        }
+        assert_eq!(count, events_accepted, "count {:?}", evtx_output);
+        let summary = evtxreader.summary();
+        assert_eq!(summary.evtxreader_events_accepted, events_accepted,
+            "summary.evtxreader_events_accepted {:?}", evtx_output);
+        assert_eq!(summary.evtxreader_events_filtered, *EVTX_KPNP_EVENT_COUNT - events_accepted,
+            "summary.evtxreader_events_filtered {:?}", evtx_output);
+    }
+}
@@ -0,0 +1,200 @@
+// src/tests/evtxrecovery_tests.rs
+
+//! tests for `evtxrecovery.rs`
+
+#![allow(non_snake_case)]
+
+use std::sync::Arc;
+
+use ::evtx::ParserSettings;
+use ::test_case::test_case;
+
+use crate::common::{
+    Count,
+    FileType,
+    FileTypeArchive,
+    summary_stats_enable,
+};
+use crate::data::evtx::{
+    EvtxFilter,
+    EvtxOutput,
+};
+use crate::debug::helpers::{
+    create_temp_file_bytes_with_suffix,
+    ntf_fpath,
+};
+use crate::readers::evtxreader::EvtxReader;
+use crate::readers::evtxrecovery::{
+    record_size_at,
+    EvtxRecovery,
+    EVTX_CHUNK_HEADER_SIZE,
+    EVTX_CHUNK_SIZE,
+    EVTX_FILE_HEADER_SIZE,
+};
+use crate::tests::common::{
+    path_id_generator,
+    EVTX_KPNP_ENTRY_FO,
+    EVTX_KPNP_EVENT_COUNT,
+    EVTX_KPNP_FPATH,
+};
+
+const FT_NORM: FileType = FileType::Evtx { archival_type: FileTypeArchive::Normal };
+
+/// file offset of the second chunk of `EVTX_KPNP_FPATH`
+const CHUNK2: usize = EVTX_FILE_HEADER_SIZE + EVTX_CHUNK_SIZE;
+/// file offset of the third chunk of `EVTX_KPNP_FPATH`
+const CHUNK3: usize = EVTX_FILE_HEADER_SIZE + EVTX_CHUNK_SIZE * 2;
+/// count of records in the second chunk of `EVTX_KPNP_FPATH`
+const CHUNK2_RECORDS: Count = 90;
+
+fn kpnp_bytes() -> Vec<u8> {
+    std::fs::read(EVTX_KPNP_FPATH.as_str()).unwrap()
+}
+
+#[allow(clippy::ptr_arg)]
+fn damage_none(_data: &mut Vec<u8>) {}
+
+#[allow(clippy::ptr_arg)]
+fn damage_file_header(data: &mut Vec<u8>) {
+    data[..8].copy_from_slice(b"XXXXXXXX");
+}
+
+/// change the stored chunk header checksum
+#[allow(clippy::ptr_arg)]
+fn damage_chunk_checksum(data: &mut Vec<u8>) {
+    data[CHUNK2 + 124] ^= 0xFF;
+}
+
+/// remove the chunk signature so the chunk records are orphaned
+#[allow(clippy::ptr_arg)]
+fn damage_chunk_signature(data: &mut Vec<u8>) {
+    data[CHUNK2..CHUNK2 + 8].fill(0);
+}
+
+/// truncate within the third chunk
+fn damage_truncate(data: &mut Vec<u8>) {
+    data.truncate(CHUNK3 + 30000);
+}
+
+#[test_case(damage_none, 227, 0, 0; "none")]
+#[test_case(damage_file_header, 227, 0, 0; "file header")]
+#[test_case(damage_chunk_checksum, 227, CHUNK2_RECORDS, 1; "chunk checksum")]
+#[test_case(damage_chunk_signature, 227, CHUNK2_RECORDS, 1; "chunk signature")]
+#[test_case(damage_truncate, 205, 37, 1; "truncated")]
+fn test_EvtxRecovery_records(
+    damage: fn(&mut Vec<u8>),
+    records_expect: Count,
+    recovered_expect: Count,
+    chunks_damaged_expect: Count,
+) {
+    let mut data = kpnp_bytes();
+    damage(&mut data);
+    let mut er = EvtxRecovery::from_vec(data);
+    let records = er.records(
+        Arc::new(ParserSettings::default().num_threads(0)),
+        |record| record.into_xml(),
+    );
+    let mut ids: Vec<u64> = Vec::with_capacity(records.len());
+    for result in records.into_iter() {
+        let record = result.unwrap();
+        assert!(record.data.contains("<Event "), "{}", record.data);
+        ids.push(record.event_record_id);
+    }
+    assert_eq!(ids.len() as Count, records_expect, "records");
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len() as Count, records_expect, "records are not unique");
+    assert_eq!(er.records_recovered, recovered_expect, "records_recovered");
+    assert_eq!(er.chunks_damaged, chunks_damaged_expect, "chunks_damaged");
+    assert_eq!(er.records_dropped, 0, "records_dropped");
+}
+
+/// The records of the third chunk without its chunk header follow the file
+/// header so the first record is within the first `EVTX_CHUNK_HEADER_SIZE`
+/// bytes of the orphaned area at `EVTX_FILE_HEADER_SIZE`.
+#[test]
+fn test_EvtxRecovery_records_orphan_head() {
+    let kpnp = kpnp_bytes();
+    let first: usize = CHUNK3 + EVTX_CHUNK_HEADER_SIZE;
+    let first_id = u64::from_le_bytes(kpnp[first + 8..first + 16].try_into().unwrap());
+    let mut data: Vec<u8> = kpnp[..EVTX_FILE_HEADER_SIZE].to_vec();
+    data.extend_from_slice(&kpnp[first..CHUNK3 + EVTX_CHUNK_SIZE]);
+    let mut er = EvtxRecovery::from_vec(data);
+    let records = er.records(
+        Arc::new(ParserSettings::default().num_threads(0)),
+        |record| record.into_xml(),
+    );
+    let ids: Vec<u64> = records
+        .into_iter()
+        .filter_map(|result| result.ok())
+        .map(|record| record.event_record_id)
+        .collect();
+    assert!(ids.contains(&first_id), "record {} not in {:?}", first_id, ids);
+    assert_eq!(er.chunks_damaged, 1, "chunks_damaged");
+}
+
+#[test_case(&[]; "empty")]
+#[test_case(&[0u8; 4096]; "zeros")]
+#[test_case(b"ElfChnk\x00"; "chunk signature only")]
+#[test_case(b"**\x00\x00\x1C\x00\x00\x00"; "record signature only")]
+fn test_EvtxRecovery_invalid(data: &[u8]) {
+    let mut er = EvtxRecovery::from_vec(data.to_vec());
+    let records = er.records(
+        Arc::new(ParserSettings::default()),
+        |record| record.into_xml(),
+    );
+    assert!(records.is_empty());
+}
+
+#[test_case(b"**\x00\x00\x1C\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x1C\x00\x00\x00", 0 => Some(28); "minimal")]
+#[test_case(b"**\x00\x00\x1C\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x1D\x00\x00\x00", 0 => None; "bad size copy")]
+#[test_case(b"**\x00\x00\x1D\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x1D\x00\x00\x00", 0 => None; "past end")]
+#[test_case(b"**\x00\x00\x04\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x04\x00\x00\x00", 0 => None; "too small")]
+#[test_case(b"*\x00\x00\x00\x1C\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x1C\x00\x00\x00", 0 => None; "bad signature")]
+fn test_record_size_at(data: &[u8], offset: usize) -> Option<usize> {
+    record_size_at(data, offset)
+}
+
+/// An `EvtxReader` in recovery mode reads an evtx file with a damaged file
+/// header that an `EvtxReader` in normal mode cannot open.
+#[test_case(EvtxOutput::Xml)]
+#[test_case(EvtxOutput::Short)]
+fn test_EvtxReader_recover(evtx_output: EvtxOutput) {
+    summary_stats_enable();
+    let mut data = kpnp_bytes();
+    damage_file_header(&mut data);
+    damage_chunk_checksum(&mut data);
+    let ntf = create_temp_file_bytes_with_suffix(&data, &String::from(".evtx"));
+    let fpath = ntf_fpath(&ntf);
+
+    assert!(EvtxReader::new(
+        path_id_generator(),
+        fpath.clone(),
+        evtx_output,
+        false,
+        FT_NORM,
+        *EVTX_KPNP_ENTRY_FO,
+    ).is_err());
+
+    let mut evtxreader = EvtxReader::new(
+        path_id_generator(),
+        fpath,
+        evtx_output,
+        true,
+        FT_NORM,
+        *EVTX_KPNP_ENTRY_FO,
+    ).unwrap();
+    assert!(evtxreader.evtx_recover());
+    evtxreader.analyze(&None, &None, &EvtxFilter::default());
+    let mut count: Count = 0;
+    while let Some(evtx) = evtxreader.next() {
+        assert!(evtx.dt_beg_end().is_some());
+        count += 1;
+    }
+    assert_eq!(count, *EVTX_KPNP_EVENT_COUNT, "count");
+    let summary = evtxreader.summary();
+    assert_eq!(summary.evtxreader_events_accepted, *EVTX_KPNP_EVENT_COUNT);
+    assert_eq!(summary.evtxreader_records_recovered, CHUNK2_RECORDS);
+    assert_eq!(summary.evtxreader_chunks_damaged, 1);
+    assert_eq!(summary.evtxreader_records_dropped, 0);
+}
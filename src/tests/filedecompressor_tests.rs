@@ -187,10 +187,14 @@ fn test_decompress_to_ntf_ok_some(
         }
         FileType::Asl { archival_type }
         | FileType::Etl { archival_type }
+        | FileType::Evt { archival_type }
         | FileType::Evtx { archival_type }
         | FileType::FixedStruct { archival_type, .. }
         | FileType::Journal { archival_type }
+        | FileType::Logcat { archival_type, .. }
         | FileType::Odl { archival_type, .. }
+        | FileType::Pcap { archival_type, .. }
+        | FileType::Tracev3 { archival_type, .. }
         | FileType::Text { archival_type, .. }
         => {
             match archival_type {
@@ -269,10 +273,14 @@ fn test_decompress_to_ntf_ok_none(
         }
         FileType::Asl { archival_type }
         | FileType::Etl { archival_type }
+        | FileType::Evt { archival_type }
         | FileType::Evtx { archival_type }
         | FileType::FixedStruct { archival_type, .. }
         | FileType::Journal { archival_type }
+        | FileType::Logcat { archival_type, .. }
         | FileType::Odl { archival_type, .. }
+        | FileType::Pcap { archival_type, .. }
+        | FileType::Tracev3 { archival_type, .. }
         | FileType::Text { archival_type, .. }
         => {
             match archival_type {
@@ -339,10 +347,14 @@ fn test_decompress_to_ntf_panic(fpath: &FPath, filetype: FileType) {
         FileType::Unparsable => {}
         FileType::Asl { archival_type }
         | FileType::Etl { archival_type }
+        | FileType::Evt { archival_type }
         | FileType::Evtx { archival_type }
         | FileType::FixedStruct { archival_type, .. }
         | FileType::Journal { archival_type }
+        | FileType::Logcat { archival_type, .. }
         | FileType::Odl { archival_type, .. }
+        | FileType::Pcap { archival_type, .. }
+        | FileType::Tracev3 { archival_type, .. }
         | FileType::Text { archival_type, .. }
         => {
             match archival_type {
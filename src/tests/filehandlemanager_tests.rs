@@ -146,18 +146,42 @@ fn test_filetype_handle_counts() {
         }),
         (1, 0),
     );
+    assert_eq!(
+        filetype_handle_counts(FileType::Asl {
+            archival_type: FileTypeArchive::Normal,
+        }),
+        (1, 0),
+    );
     assert_eq!(
         filetype_handle_counts(FileType::Etl {
             archival_type: FileTypeArchive::Normal,
         }),
         (1, FILE_HANDLE_UNMANAGED_PYRUNNER_COUNT),
     );
+    assert_eq!(
+        filetype_handle_counts(FileType::Logcat {
+            archival_type: FileTypeArchive::Normal,
+        }),
+        (1, 0),
+    );
+    assert_eq!(
+        filetype_handle_counts(FileType::Pcap {
+            archival_type: FileTypeArchive::Normal,
+        }),
+        (1, 0),
+    );
     assert_eq!(
         filetype_handle_counts(FileType::Odl {
             archival_type: FileTypeArchive::Normal,
             odl_sub_type: OdlSubType::Odl,
         }),
-        (1, FILE_HANDLE_UNMANAGED_PYRUNNER_COUNT),
+        (1, 0),
+    );
+    assert_eq!(
+        filetype_handle_counts(FileType::Tracev3 {
+            archival_type: FileTypeArchive::Normal,
+        }),
+        (1, 0),
     );
     assert_eq!(
         filetype_handle_counts(FileType::Journal {
@@ -185,6 +185,27 @@ const FTASLLZ4: PathToFiletypeResult = PathToFiletypeResult::Filetype(
 const FTASLXZ: PathToFiletypeResult = PathToFiletypeResult::Filetype(
     FileType::Asl { archival_type: FileTypeArchive::Xz }
 );
+// Logcat
+const FTLOGCAT: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::Logcat { archival_type: FileTypeArchive::Normal }
+);
+const FTLOGCATGZ: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::Logcat { archival_type: FileTypeArchive::Gz }
+);
+// Pcap
+const FTPCAP: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::Pcap { archival_type: FileTypeArchive::Normal }
+);
+const FTPCAPGZ: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::Pcap { archival_type: FileTypeArchive::Gz }
+);
+// Tracev3
+const FTTRACEV3: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::Tracev3 { archival_type: FileTypeArchive::Normal }
+);
+const FTTRACEV3GZ: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::Tracev3 { archival_type: FileTypeArchive::Gz }
+);
 // Etl
 const FTETL: PathToFiletypeResult = PathToFiletypeResult::Filetype(
     FileType::Etl { archival_type: FileTypeArchive::Normal }
@@ -201,6 +222,17 @@ const FTETLLZ4: PathToFiletypeResult = PathToFiletypeResult::Filetype(
 const FTETLXZ: PathToFiletypeResult = PathToFiletypeResult::Filetype(
     FileType::Etl { archival_type: FileTypeArchive::Xz }
 );
+// Evt
+const FTEVTN: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::Evt {
+        archival_type: FileTypeArchive::Normal,
+    }
+);
+const FTEVTG: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::Evt {
+        archival_type: FileTypeArchive::Gz,
+    }
+);
 // Evtx
 const FTEVTXN: PathToFiletypeResult = PathToFiletypeResult::Filetype(
     FileType::Evtx {
@@ -394,6 +426,36 @@ const FTLASTLOGXN: PathToFiletypeResult = PathToFiletypeResult::Filetype(
         fixedstruct_type: FileTypeFixedStruct::Lastlogx,
     }
 );
+const FTLASTLOG2N: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::FixedStruct {
+        archival_type: FileTypeArchive::Normal,
+        fixedstruct_type: FileTypeFixedStruct::Lastlog2,
+    }
+);
+const FTWTMPDBN: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::FixedStruct {
+        archival_type: FileTypeArchive::Normal,
+        fixedstruct_type: FileTypeFixedStruct::Wtmpdb,
+    }
+);
+const FTUTXLOGN: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::FixedStruct {
+        archival_type: FileTypeArchive::Normal,
+        fixedstruct_type: FileTypeFixedStruct::UtxLog,
+    }
+);
+const FTUTXLOGX: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::FixedStruct {
+        archival_type: FileTypeArchive::Xz,
+        fixedstruct_type: FileTypeFixedStruct::UtxLog,
+    }
+);
+const FTWTMPDBG: PathToFiletypeResult = PathToFiletypeResult::Filetype(
+    FileType::FixedStruct {
+        archival_type: FileTypeArchive::Gz,
+        fixedstruct_type: FileTypeFixedStruct::Wtmpdb,
+    }
+);
 const FTUTMPN: PathToFiletypeResult = PathToFiletypeResult::Filetype(
     FileType::FixedStruct {
         archival_type: FileTypeArchive::Normal,
@@ -618,6 +680,32 @@ const FTUTMPXX: PathToFiletypeResult = PathToFiletypeResult::Filetype(
 #[test_case("2023.10.26.asl.xz", FTASLXZ, true; "asl.xz true")]
 #[test_case("2023.10.26.asl.xz", FTASLXZ, false; "asl.xz false")]
 //
+// LOGCAT
+//
+#[test_case("logcat.bin", FTLOGCAT, true; "logcat.bin true")]
+#[test_case("logcat.bin", FTLOGCAT, false; "logcat.bin false")]
+#[test_case("events.logcat.bin", FTLOGCAT, false; "events.logcat.bin false")]
+#[test_case("main.logcatb", FTLOGCAT, false; "main.logcatb false")]
+#[test_case("logcat.bin.gz", FTLOGCATGZ, true; "logcat.bin.gz true")]
+#[test_case("logcat.bin.gz", FTLOGCATGZ, false; "logcat.bin.gz false")]
+#[test_case("firmware.bin", FTUNPARSABLE, false; "firmware.bin false")]
+#[test_case("event-log-tags", FTUNPARSABLE, false; "event-log-tags false")]
+#[test_case("event-log-tags", FTTN8, true; "event-log-tags true")]
+//
+// PCAP
+//
+#[test_case("syslog.pcap", FTPCAP, true; "pcap true")]
+#[test_case("syslog.pcap", FTPCAP, false; "pcap false")]
+#[test_case("syslog.pcapng", FTPCAP, false; "pcapng false")]
+#[test_case("syslog.pcap.gz", FTPCAPGZ, false; "pcap.gz false")]
+//
+// TRACEV3
+//
+#[test_case("0000000000000001.tracev3", FTTRACEV3, true; ".tracev3 true")]
+#[test_case("0000000000000001.tracev3", FTTRACEV3, false; ".tracev3 false")]
+#[test_case("0000000000000001.tracev3.gz", FTTRACEV3GZ, true; ".tracev3.gz true")]
+#[test_case("0000000000000001.tracev3.gz", FTTRACEV3GZ, false; ".tracev3.gz false")]
+//
 // ETL
 //
 #[test_case("SIH.20230422.034724.362.1.etl", FTETL, true; ".etl true")]
@@ -726,6 +814,21 @@ const FTUTMPXX: PathToFiletypeResult = PathToFiletypeResult::Filetype(
 #[test_case("lastlog.gz", FTLASTLOGG, true)]
 #[test_case("lastlog.XZ", FTLASTLOG_X, true)]
 #[test_case("lastlog.1.XZ", FTLASTLOG_X, true)]
+// FixedStruct UtxLog
+#[test_case("utx.log", FTUTXLOGN, true; "utx.log")]
+#[test_case("UTX.LOG", FTUTXLOGN, true; "UTX.LOG ALLCAPS")]
+#[test_case("utx.log.0", FTUTXLOGN, true; "utx.log.0")]
+#[test_case("utx.log.xz", FTUTXLOGX, true; "utx.log.xz")]
+#[test_case("utx.log.txt", FTTN8, true; "utx.log.txt")]
+#[test_case("other.utx.log", FTTN8, true; "other.utx.log")]
+// FixedStruct Wtmpdb, Lastlog2
+#[test_case("wtmp.db", FTWTMPDBN, true; "wtmp.db")]
+#[test_case("WTMP.DB", FTWTMPDBN, true; "WTMP.DB ALLCAPS")]
+#[test_case("wtmp.db.1", FTWTMPDBN, true; "wtmp.db.1")]
+#[test_case("wtmp.db.gz", FTWTMPDBG, true; "wtmp.db.gz")]
+#[test_case("lastlog2.db", FTLASTLOG2N, true; "lastlog2.db")]
+#[test_case("other.db", FTTN8, true; "other.db true")]
+#[test_case("other.db", FTTN8, false; "other.db false")]
 // FixedStruct Acct
 #[test_case("acct", FTACCTN, true)]
 #[test_case("acct.2", FTACCTN, true)]
@@ -738,12 +841,19 @@ const FTUTMPXX: PathToFiletypeResult = PathToFiletypeResult::Filetype(
 #[test_case("pacct.20220101.gz", FTACCTV3G, true)]
 #[test_case("pacct.xz", FTACCTV3X, true)]
 // on FreeBSD 13, there is a log file `utx.log` that is a variable-length utmpx-ish format file
-#[test_case("utx.log", FTTN8, true)]
-#[test_case("utx.log-", FTTN8, true; "utx.log dash")]
+#[test_case("utx.log-", FTUTXLOGN, true; "utx.log dash")]
 #[test_case("utx.active", FTTN8, true)]
 // File `utx.lastlogin` exists on FreeBSD 13.   
 #[test_case("utx.lastlogin", FTTN8, true)]
 //
+// EVT
+//
+#[test_case("file.evt", FTEVTN, true)]
+#[test_case("SysEvent.Evt", FTEVTN, true)]
+#[test_case("SECEVENT.EVT", FTEVTN, true; "SECEVENT.EVT ALLCAPS")]
+#[test_case("AppEvent.Evt.1", FTEVTN, true)]
+#[test_case("AppEvent.Evt.gz", FTEVTG, true)]
+//
 // EVTX
 //
 #[test_case("file.evtx", FTEVTXN, true)]
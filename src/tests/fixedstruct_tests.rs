@@ -33,22 +33,28 @@ use crate::data::datetime::{
 };
 use crate::data::fixedstruct::{
     buffer_to_fixedstructptr,
+    comp_t_to_u64,
     convert_datetime_tvpair,
     convert_tvpair_to_datetime,
     freebsd_x8664,
     linux_arm64aarch64,
     linux_x86,
+    macos_x8664,
     netbsd_x8632,
     netbsd_x8664,
     openbsd_x86,
     tv_pair_type,
     tv_sec_type,
     tv_usec_type,
+    AcctFields,
+    Endian,
     FixedStruct,
     FixedStructDynPtr,
     FixedStructType,
     InfoAsBytes,
     Score,
+    UtmpFields,
+    UtmpKind,
     ENTRY_SZ_MAX,
 };
 use crate::readers::blockreader::{
@@ -67,6 +73,8 @@ use crate::tests::common::{
     LINUX_X86_UTMPX_BUFFER2,
     LINUX_X86_UTMPX_BUFFER_00,
     LINUX_X86_UTMPX_BUFFER_FF,
+    MACOS_X8664_LASTLOGX_BUFFER1,
+    MACOS_X8664_UTMPX_BUFFER1,
     NETBSD_X8632_ACCT_BUFFER1,
     NETBSD_X8632_LASTLOGX_BUFFER1,
     NETBSD_X8632_UTMPX_BUFFER1,
@@ -271,6 +279,56 @@ fn test_FixedStruct_dt() {
     assert_eq!(UTMPX2.dt(), &*UTMPX2_DT, "dt");
 }
 
+#[test]
+fn test_FixedStruct_utmp_fields() {
+    let fields: UtmpFields = UTMPX2.utmp_fields().unwrap();
+    assert_eq!(fields.kind, UtmpKind::UserProcess, "kind");
+    assert_eq!(fields.pid, 0x33b4, "pid");
+    assert_eq!(fields.line, "pts/0", "line");
+    assert_eq!(fields.id, "ts/0", "id");
+    assert_eq!(fields.user, "root", "user");
+    assert_eq!(fields.host, "192.168.1.4", "host");
+
+    let lastlog: FixedStruct = FixedStruct::new(
+        0,
+        &FO_0,
+        &LINUX_X86_LASTLOG_BUFFER1,
+        FixedStructType::Fs_Linux_x86_Lastlog,
+    ).unwrap();
+    assert!(lastlog.utmp_fields().is_none(), "lastlog");
+}
+
+#[test_case(&LINUX_X86_ACCT_BUFFER1, FixedStructType::Fs_Linux_x86_Acct, Some(0), 2776; "linux_x86 acct")]
+#[test_case(&NETBSD_X8632_ACCT_BUFFER1, FixedStructType::Fs_Netbsd_x8632_Acct, None, 0; "netbsd_x8632 acct")]
+fn test_FixedStruct_acct_fields(
+    buffer: &[u8],
+    fixedstructtype: FixedStructType,
+    exitcode: Option<u32>,
+    mem: u64,
+) {
+    let entry: FixedStruct = FixedStruct::new(0, &FO_0, buffer, fixedstructtype).unwrap();
+    let fields: AcctFields = entry.acct_fields().unwrap();
+    assert_eq!(fields.comm, "accton", "comm");
+    assert_eq!(fields.uid, 0, "uid");
+    assert_eq!(fields.flag, 0x02, "flag");
+    assert!(!fields.is_abnormal(), "is_abnormal");
+    assert_eq!(fields.exitcode, exitcode, "exitcode");
+    assert_eq!(fields.cpu, 0.0, "cpu");
+    assert_eq!(fields.elapsed, 0.0, "elapsed");
+    assert_eq!(fields.mem, mem, "mem");
+
+    assert!(UTMPX2.acct_fields().is_none(), "utmpx");
+}
+
+#[test_case(0, 0)]
+#[test_case(0x1FFF, 0x1FFF; "max fraction")]
+#[test_case(0x2001, 8; "exponent 1")]
+#[test_case(0x4003, 192; "exponent 2")]
+#[test_case(0xFFFF, 0x1FFF << 21; "max")]
+fn test_comp_t_to_u64(comp: u16, expect: u64) {
+    assert_eq!(comp_t_to_u64(comp), expect);
+}
+
 #[test]
 fn test_FixedStruct_as_bytes() {
     eprintln!("UTMPX2: {}", *UTMPX2_STRING_NORAW);
@@ -319,6 +377,8 @@ fn test_buffer_to_fixedstructptr_FF() {
 #[test_case(&LINUX_X86_ACCT_V3_BUFFER1, FixedStructType::Fs_Linux_x86_Acct_v3)]
 #[test_case(&LINUX_X86_LASTLOG_BUFFER1, FixedStructType::Fs_Linux_x86_Lastlog)]
 #[test_case(&LINUX_X86_UTMPX_BUFFER1, FixedStructType::Fs_Linux_x86_Utmpx)]
+#[test_case(&MACOS_X8664_LASTLOGX_BUFFER1, FixedStructType::Fs_Macos_x8664_Lastlogx)]
+#[test_case(&MACOS_X8664_UTMPX_BUFFER1, FixedStructType::Fs_Macos_x8664_Utmpx)]
 #[test_case(&NETBSD_X8632_ACCT_BUFFER1, FixedStructType::Fs_Netbsd_x8632_Acct)]
 #[test_case(&NETBSD_X8632_LASTLOGX_BUFFER1, FixedStructType::Fs_Netbsd_x8632_Lastlogx)]
 #[test_case(&NETBSD_X8632_UTMPX_BUFFER1, FixedStructType::Fs_Netbsd_x8632_Utmpx)]
@@ -351,6 +411,9 @@ fn test_buffer_to_fixedstructptr(
 #[test_case(&LINUX_X86_ACCT_V3_BUFFER1, FixedStructType::Fs_Linux_x86_Acct_v3, 0, -75)]
 #[test_case(&LINUX_X86_LASTLOG_BUFFER1, FixedStructType::Fs_Linux_x86_Lastlog, 0, 60)]
 #[test_case(&LINUX_X86_UTMPX_BUFFER1, FixedStructType::Fs_Linux_x86_Utmpx, 0, 129)]
+#[test_case(&MACOS_X8664_LASTLOGX_BUFFER1, FixedStructType::Fs_Macos_x8664_Lastlogx, 0, 68)]
+#[test_case(&MACOS_X8664_UTMPX_BUFFER1, FixedStructType::Fs_Macos_x8664_Utmpx, 0, 147)]
+#[test_case(&MACOS_X8664_LASTLOGX_BUFFER1, FixedStructType::Fs_Openbsd_x86_Utmp, 0, -156; "macos_x8664 lastlogx as openbsd_x86 utmp")]
 #[test_case(&NETBSD_X8632_ACCT_BUFFER1, FixedStructType::Fs_Netbsd_x8632_Acct, 0, 58)]
 #[test_case(&NETBSD_X8632_LASTLOGX_BUFFER1, FixedStructType::Fs_Netbsd_x8632_Lastlogx, 0, 72)]
 #[test_case(&NETBSD_X8632_UTMPX_BUFFER1, FixedStructType::Fs_Netbsd_x8632_Utmpx, 0, 140)]
@@ -378,6 +441,81 @@ fn test_score_fixedstruct(
     );
 }
 
+#[test_case(Endian::Little, cfg!(target_endian = "little"))]
+#[test_case(Endian::Big, cfg!(target_endian = "big"))]
+fn test_endian_is_native(endian: Endian, expect: bool) {
+    assert_eq!(endian.is_native(), expect);
+}
+
+#[test_case(FixedStructType::Fs_Freebsd_x8664_Utmpx)]
+#[test_case(FixedStructType::Fs_Linux_Arm64Aarch64_Lastlog)]
+#[test_case(FixedStructType::Fs_Linux_Arm64Aarch64_Utmpx)]
+#[test_case(FixedStructType::Fs_Linux_x86_Acct)]
+#[test_case(FixedStructType::Fs_Linux_x86_Acct_v3)]
+#[test_case(FixedStructType::Fs_Linux_x86_Lastlog)]
+#[test_case(FixedStructType::Fs_Linux_x86_Utmpx)]
+#[test_case(FixedStructType::Fs_Macos_x8664_Lastlogx)]
+#[test_case(FixedStructType::Fs_Macos_x8664_Utmpx)]
+#[test_case(FixedStructType::Fs_Netbsd_x8632_Acct)]
+#[test_case(FixedStructType::Fs_Netbsd_x8632_Lastlogx)]
+#[test_case(FixedStructType::Fs_Netbsd_x8632_Utmpx)]
+#[test_case(FixedStructType::Fs_Netbsd_x8664_Lastlog)]
+#[test_case(FixedStructType::Fs_Netbsd_x8664_Lastlogx)]
+#[test_case(FixedStructType::Fs_Netbsd_x8664_Utmp)]
+#[test_case(FixedStructType::Fs_Netbsd_x8664_Utmpx)]
+#[test_case(FixedStructType::Fs_Openbsd_x86_Lastlog)]
+#[test_case(FixedStructType::Fs_Openbsd_x86_Utmp)]
+fn test_numeric_fields(fixedstructtype: FixedStructType) {
+    let tv_beg = fixedstructtype.offset_tv();
+    let tv_end = tv_beg + fixedstructtype.size_tv();
+    let mut tv_bytes: usize = 0;
+    for (offset, size) in fixedstructtype.numeric_fields().iter() {
+        assert!(
+            [1, 2, 4, 8].contains(size),
+            "{:?} field at {} has size {}", fixedstructtype, offset, size,
+        );
+        assert!(
+            offset + size <= fixedstructtype.size(),
+            "{:?} field at {} size {} beyond struct size {}",
+            fixedstructtype, offset, size, fixedstructtype.size(),
+        );
+        if *offset >= tv_beg && offset + size <= tv_end {
+            tv_bytes += size;
+        }
+    }
+    assert_gt!(tv_bytes, 0, "{:?} time value is not a numeric field", fixedstructtype);
+}
+
+/// a big-endian `linux_x86::utmpx`, e.g. from Linux s390x, is swapped to the
+/// same entry as the little-endian `LINUX_X86_UTMPX_BUFFER2`
+#[test]
+fn test_swap_endian_linux_x86_utmpx() {
+    let fixedstructtype = FixedStructType::Fs_Linux_x86_Utmpx;
+    let mut buffer: [u8; linux_x86::UTMPX_SZ] = LINUX_X86_UTMPX_BUFFER2;
+    fixedstructtype.swap_endian(&mut buffer, 0);
+    assert_ne!(buffer, LINUX_X86_UTMPX_BUFFER2, "swapped buffer");
+    // `ut_type` 7 is now big-endian
+    assert_eq!(&buffer[0..2], &[0x00, 0x07], "ut_type");
+    // strings are not swapped
+    assert_eq!(&buffer[8..13], b"pts/0", "ut_line");
+
+    // swap back
+    let mut buffer2 = buffer;
+    fixedstructtype.swap_endian(&mut buffer2, 0);
+    assert_eq!(buffer2, LINUX_X86_UTMPX_BUFFER2, "round-trip");
+
+    // swap only the time value
+    let tv_offset = fixedstructtype.offset_tv();
+    let tv_sz = fixedstructtype.size_tv();
+    let mut tv_buffer: Vec<u8> = buffer[tv_offset..tv_offset + tv_sz].to_vec();
+    fixedstructtype.swap_endian(&mut tv_buffer, tv_offset);
+    assert_eq!(
+        fixedstructtype.tv_pair_from_buffer(&tv_buffer),
+        Some(*UTMPX2.tv_pair()),
+        "tv_pair_from_buffer",
+    );
+}
+
 // individual FixedStruct tests
 
 #[test]
@@ -588,6 +726,65 @@ fn test_linux_x86_utmpx() {
     _ = fs.as_bytes(&mut buffer);
 }
 
+#[test]
+fn test_macos_x8664_lastlogx() {
+    let entry = buffer_to_fixedstructptr(
+        &MACOS_X8664_LASTLOGX_BUFFER1,
+        FixedStructType::Fs_Macos_x8664_Lastlogx,
+    ).unwrap();
+    let lastlogx: &macos_x8664::lastlogx = entry.as_macos_x8664_lastlogx();
+    assert_eq!(lastlogx.ll_tv.tv_sec, 1577880000, "ll_tv.tv_sec");
+    assert_eq!(lastlogx.ll_tv.tv_usec, 0, "ll_tv.tv_usec");
+    assert_eq!(lastlogx.ll_line(), CString::new("ttys000").unwrap().as_c_str(), "ll_line");
+    assert_eq!(lastlogx.ll_host(), CString::new("192.168.1.5").unwrap().as_c_str(), "ll_host");
+
+    eprintln!("macos_x8664::lastlogx: {:?}", lastlogx);
+
+    let fs = FixedStruct::from_fixedstructptr(
+        0,
+        &FO_0,
+        entry,
+    ).unwrap();
+    assert_eq!(fs.tv_pair(), &tv_pair_type(1577880000, 0), "tv_pair");
+    assert!(fs.utmp_fields().is_none(), "utmp_fields");
+    let mut buffer: [u8; ENTRY_SZ_MAX] = [0; ENTRY_SZ_MAX];
+    _ = fs.as_bytes(&mut buffer);
+}
+
+#[test]
+fn test_macos_x8664_utmpx() {
+    let entry = buffer_to_fixedstructptr(
+        &MACOS_X8664_UTMPX_BUFFER1,
+        FixedStructType::Fs_Macos_x8664_Utmpx,
+    ).unwrap();
+    let utmpx: &macos_x8664::utmpx = entry.as_macos_x8664_utmpx();
+    assert_eq!(utmpx.ut_user(), CString::new("admin").unwrap().as_c_str(), "ut_user");
+    assert_eq!(utmpx.ut_line(), CString::new("ttys000").unwrap().as_c_str(), "ut_line");
+    assert_eq!(utmpx.ut_pid, 4141, "ut_pid");
+    assert_eq!(utmpx.ut_type, 7, "ut_type");
+    assert_eq!(utmpx.ut_tv.tv_sec, 1577880002, "ut_tv.tv_sec");
+    assert_eq!(utmpx.ut_tv.tv_usec, 123636, "ut_tv.tv_usec");
+    assert_eq!(utmpx.ut_host(), CString::new("192.168.1.5").unwrap().as_c_str(), "ut_host");
+
+    eprintln!("macos_x8664::utmpx: {:?}", utmpx);
+
+    let fs = FixedStruct::from_fixedstructptr(
+        0,
+        &FO_0,
+        entry,
+    ).unwrap();
+    assert_eq!(fs.dt(), &*UTMPX2_DT, "dt");
+    let fields: UtmpFields = fs.utmp_fields().unwrap();
+    assert_eq!(fields.kind, UtmpKind::UserProcess, "kind");
+    assert_eq!(fields.pid, 4141, "pid");
+    assert_eq!(fields.line, "ttys000", "line");
+    assert_eq!(fields.id, "s000", "id");
+    assert_eq!(fields.user, "admin", "user");
+    assert_eq!(fields.host, "192.168.1.5", "host");
+    let mut buffer: [u8; ENTRY_SZ_MAX] = [0; ENTRY_SZ_MAX];
+    _ = fs.as_bytes(&mut buffer);
+}
+
 #[test]
 fn test_netbsd_x8632_acct() {
     let entry = buffer_to_fixedstructptr(
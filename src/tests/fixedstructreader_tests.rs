@@ -7,6 +7,11 @@
 
 use std::any::Any;
 use std::collections::HashSet;
+use std::io::{
+    Seek,
+    SeekFrom,
+    Write,
+};
 
 use ::chrono::Duration;
 #[allow(unused_imports)]
@@ -31,11 +36,18 @@ use crate::common::{
 use crate::data::datetime::FixedOffset;
 use crate::data::fixedstruct::{
     linux_x86,
+    Endian,
     FixedStruct,
     FixedStructType,
+    InfoAsBytes,
+    UtmpFields,
     ENTRY_SZ_MAX,
 };
-use crate::debug::helpers::create_temp_file_no_permissions;
+use crate::debug::helpers::{
+    create_temp_file_bytes_with_suffix,
+    create_temp_file_no_permissions,
+    ntf_fpath,
+};
 use crate::readers::blockreader::{
     BlockOffset,
     BlockSz,
@@ -54,12 +66,17 @@ use crate::tests::common::{
     path_id_generator,
     FO_0,
     FO_P8,
+    LINUX_X86_LASTLOG_BUFFER1,
     LINUX_X86_LASTLOG_BUFFER1_DTO,
     LINUX_X86_UTMPX_2ENTRY_FILESZ,
     LINUX_X86_UTMPX_BUFFER1_DT,
+    LINUX_X86_UTMPX_BUFFER1,
     LINUX_X86_UTMPX_BUFFER1_DTO,
+    LINUX_X86_UTMPX_BUFFER2,
+    LINUX_X86_UTMPX_BUFFER2_DT,
     LINUX_X86_UTMPX_BUFFER2_DTO,
     LINUX_X86_UTMPX_BUFFER3_DTO,
+    MACOS_X8664_UTMPX_BUFFER1,
     NTF_LINUX_X86_LASTLOG_1ENTRY_FPATH,
     NTF_LINUX_X86_UTMPX_00_ENTRY_FPATH,
     NTF_LINUX_X86_UTMPX_1ENTRY_FPATH,
@@ -238,6 +255,120 @@ fn test_FixedStructReader_helpers() {
     _ = fsr.get_fileoffsets();
 }
 
+/// `linux_x86::utmpx` entries from a big-endian Linux, e.g. s390x, are
+/// detected and swapped
+#[test]
+fn test_FixedStructReader_big_endian_linux_x86_utmpx() {
+    let fixedstructtype = FixedStructType::Fs_Linux_x86_Utmpx;
+    let mut data: Vec<u8> = Vec::with_capacity(LINUX_X86_UTMPX_2ENTRY_FILESZ as usize);
+    for buffer in [LINUX_X86_UTMPX_BUFFER1, LINUX_X86_UTMPX_BUFFER2] {
+        let mut buffer = buffer;
+        fixedstructtype.swap_endian(&mut buffer, 0);
+        data.extend_from_slice(&buffer);
+    }
+    let ntf = create_temp_file_bytes_with_suffix(data.as_slice(), &String::from(".utmp"));
+    let fpath = ntf_fpath(&ntf);
+
+    let mut buffer: [u8; ENTRY_SZ_MAX] = [0; ENTRY_SZ_MAX];
+    let mut fsr = new_FixedStructReader(&fpath, BSZ, FO_0);
+    assert_eq!(fsr.fixedstruct_type(), fixedstructtype);
+    assert_eq!(fsr.endian(), Endian::Big);
+
+    let (fo_next, fs) = match fsr.process_entry_at(0, &mut buffer) {
+        ResultFindFixedStruct::Found((fo, fs)) => (fo, fs),
+        _ => panic!("process_entry_at(0) failed"),
+    };
+    assert_eq!(fs.dt(), &*LINUX_X86_UTMPX_BUFFER1_DT);
+    let fields: UtmpFields = fs.utmp_fields().unwrap();
+    assert_eq!(fields.pid, 41908, "pid");
+    assert_eq!(fields.user, "admin", "user");
+    let (_, fs) = match fsr.process_entry_at(fo_next, &mut buffer) {
+        ResultFindFixedStruct::Found((fo, fs)) => (fo, fs),
+        _ => panic!("process_entry_at({}) failed", fo_next),
+    };
+    assert_eq!(fs.dt(), &*LINUX_X86_UTMPX_BUFFER2_DT);
+    assert_eq!(fsr.summary().fixedstructreader_endian, Endian::Big);
+}
+
+/// macOS `utmpx` is detected among the other 640 byte sized structs
+#[test]
+fn test_FixedStructReader_macos_x8664_utmpx() {
+    let ntf = create_temp_file_bytes_with_suffix(
+        MACOS_X8664_UTMPX_BUFFER1.as_slice(), &String::from(".utmpx"),
+    );
+    let fpath = ntf_fpath(&ntf);
+
+    let mut buffer: [u8; ENTRY_SZ_MAX] = [0; ENTRY_SZ_MAX];
+    let mut fsr = new_FixedStructReader(&fpath, BSZ, FO_0);
+    assert_eq!(fsr.fixedstruct_type(), FixedStructType::Fs_Macos_x8664_Utmpx);
+    assert_eq!(fsr.endian(), Endian::Little);
+    let fs = match fsr.process_entry_at(0, &mut buffer) {
+        ResultFindFixedStruct::Found((_fo, fs)) => fs,
+        _ => panic!("process_entry_at(0) failed"),
+    };
+    assert_eq!(fs.dt(), &*LINUX_X86_UTMPX_BUFFER2_DT);
+}
+
+/// A sparse `lastlog` file with entries for a low and a high UID is read and
+/// each entry reports the UID implied by its file offset.
+#[test_case(true; "sparse")]
+#[test_case(false; "zeros")]
+fn test_FixedStructReader_lastlog_sparse(sparse: bool) {
+    const UID1: FileOffset = 1000;
+    const UID2: FileOffset = 60_000;
+    let ntf = create_temp_file_bytes_with_suffix(&[], &String::from(".lastlog"));
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .open(ntf.path())
+        .unwrap();
+    if sparse {
+        file.set_len((UID2 + 1) * L1SZ).unwrap();
+    } else {
+        file.write_all(&vec![0; ((UID2 + 1) * L1SZ) as usize]).unwrap();
+    }
+    file.seek(SeekFrom::Start(UID1 * L1SZ)).unwrap();
+    file.write_all(&LINUX_X86_LASTLOG_BUFFER1).unwrap();
+    // the second entry is one minute later
+    let mut buffer2 = LINUX_X86_LASTLOG_BUFFER1;
+    let ll_time: i32 = i32::from_le_bytes(buffer2[..4].try_into().unwrap()) + 60;
+    buffer2[..4].copy_from_slice(&ll_time.to_le_bytes());
+    file.seek(SeekFrom::Start(UID2 * L1SZ)).unwrap();
+    file.write_all(&buffer2).unwrap();
+    drop(file);
+    let fpath = ntf_fpath(&ntf);
+
+    let filetype = FileType::FixedStruct {
+        archival_type: FileTypeArchive::Normal,
+        fixedstruct_type: LFS,
+    };
+    let mut fsr = match FixedStructReader::new(path_id_generator(), fpath.clone(), filetype, 0x1000, FO_0, None, None) {
+        ResultFixedStructReaderNewError::FileOk(val) => val,
+        result => panic!("FixedStructReader::new({:?}) failed: {:?}", fpath, result),
+    };
+    assert_eq!(fsr.fixedstruct_type(), FixedStructType::Fs_Linux_x86_Lastlog);
+
+    let mut buffer: [u8; ENTRY_SZ_MAX] = [0; ENTRY_SZ_MAX];
+    let mut out: [u8; 1024] = [0; 1024];
+    let mut fo: FileOffset = fsr.fileoffset_first().unwrap();
+    assert_eq!(fo, UID1 * L1SZ);
+    for uid in [UID1, UID2] {
+        let fs = match fsr.process_entry_at(fo, &mut buffer) {
+            ResultFindFixedStruct::Found((fo_next, fs)) => {
+                fo = fo_next;
+                fs
+            }
+            result => panic!("process_entry_at({}) returned {:?}", fo, result),
+        };
+        assert_eq!(fs.lastlog_uid(), Some(uid));
+        let s: String = match fs.as_bytes(&mut out) {
+            InfoAsBytes::Ok(at, _, _) => String::from_utf8_lossy(&out[..at]).to_string(),
+            _ => panic!("as_bytes failed"),
+        };
+        assert!(s.starts_with(&format!("uid {} ll_time ", uid)), "{:?}", s);
+    }
+    assert!(matches!(fsr.process_entry_at(fo, &mut buffer), ResultFindFixedStruct::Done));
+}
+
 #[derive(Debug, Eq, PartialEq)]
 enum ResultFindFixedStruct_Test {
     Found,
@@ -612,6 +743,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 2,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -634,6 +766,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 2,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -656,6 +789,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 2,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -678,6 +812,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 2,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -700,6 +835,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 2,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -722,6 +858,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 2,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -745,6 +882,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -767,6 +905,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -789,6 +928,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -811,6 +951,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -833,6 +974,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -855,6 +997,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 144,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -878,6 +1021,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 140,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -900,6 +1044,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 140,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -922,6 +1067,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 140,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -944,6 +1090,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 140,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -966,6 +1113,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 140,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -988,6 +1136,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(TYU),
         fixedstructreader_filetypefixedstruct_opt: Some(FTU),
         fixedstructreader_fixedstruct_size: U1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 140,
         fixedstructreader_utmp_entries: 3,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -1011,6 +1160,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(FixedStructType::Fs_Linux_x86_Lastlog),
         fixedstructreader_filetypefixedstruct_opt: Some(FileTypeFixedStruct::Lastlog),
         fixedstructreader_fixedstruct_size: L1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 60,
         fixedstructreader_utmp_entries: 1,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -1033,6 +1183,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(FixedStructType::Fs_Linux_x86_Lastlog),
         fixedstructreader_filetypefixedstruct_opt: Some(FileTypeFixedStruct::Lastlog),
         fixedstructreader_fixedstruct_size: L1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 60,
         fixedstructreader_utmp_entries: 1,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -1055,6 +1206,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(FixedStructType::Fs_Linux_x86_Lastlog),
         fixedstructreader_filetypefixedstruct_opt: Some(FileTypeFixedStruct::Lastlog),
         fixedstructreader_fixedstruct_size: L1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 60,
         fixedstructreader_utmp_entries: 1,
         fixedstructreader_first_entry_fileoffset: 0,
@@ -1077,6 +1229,7 @@ const fn SummaryBlockReader_new(dropped_blocks_ok: Count, dropped_blocks_err: Co
         fixedstructreader_fixedstructtype_opt: Some(FixedStructType::Fs_Linux_x86_Lastlog),
         fixedstructreader_filetypefixedstruct_opt: Some(FileTypeFixedStruct::Lastlog),
         fixedstructreader_fixedstruct_size: L1SZ as usize,
+        fixedstructreader_endian: Endian::Little,
         fixedstructreader_high_score: 60,
         fixedstructreader_utmp_entries: 1,
         fixedstructreader_first_entry_fileoffset: 0,
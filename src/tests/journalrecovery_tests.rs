@@ -0,0 +1,266 @@
+// src/tests/journalrecovery_tests.rs
+
+//! tests for `journalrecovery.rs`
+
+#![allow(non_snake_case)]
+
+use std::path::Path;
+
+use ::bstr::ByteSlice;
+use ::more_asserts::assert_le;
+use ::si_trace_print::defñ;
+use ::test_case::test_case;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileType,
+    FileTypeArchive,
+    summary_stats_enable,
+};
+use crate::debug::helpers::{
+    create_temp_file_bytes_with_suffix,
+    ntf_fpath,
+};
+use crate::libload::systemd_dlopen2::{
+    load_library_systemd,
+    LoadLibraryError,
+};
+use crate::readers::journalreader::{
+    JournalOutput,
+    JournalReader,
+    ResultNext,
+};
+use crate::readers::journalrecovery::{
+    jenkins_hash64,
+    JournalHeader,
+    JournalRecovery,
+    STATE_ARCHIVED,
+    STATE_ONLINE,
+};
+use crate::subprojects::lzma_rs;
+use crate::tests::common::{
+    path_id_generator,
+    FO_0,
+    JOURNAL_FILE_RHE_91_SYSTEM_EVENT_COUNT,
+    JOURNAL_FILE_RHE_91_SYSTEM_XZ_FPATH,
+    JOURNAL_FILE_UBUNTU_22_SYSTEM_EVENT_COUNT,
+    SYSTEMD_NOT_AVAILABLE,
+};
+
+const JOURNAL_FILE_UBUNTU_22_SYSTEM_XZ_FPATH: &str =
+    "./logs/programs/journal/Ubuntu22-user-1000x3.journal.xz";
+
+/// journal file with `COMPRESSED_XZ` `DATA` objects and Jenkins hashes
+const JOURNAL_FILE_UBUNTU_16_FPATH: &str =
+    "./logs/Ubuntu16/6c6ab73d82464b9493892c81fc732b3a/system.journal";
+
+/// read the journal file at `fpath`; decompress if it is `.xz`
+fn journal_bytes(fpath: &FPath) -> Vec<u8> {
+    let data: Vec<u8> = std::fs::read(fpath).unwrap();
+    if !fpath.ends_with(".xz") {
+        return data;
+    }
+    let mut out: Vec<u8> = Vec::new();
+    lzma_rs::xz_decompress(&mut data.as_slice(), &mut out).unwrap();
+
+    out
+}
+
+// test vectors from `lookup3.c` `driver5`
+#[test_case(b"", 0xdeadbeefdeadbeef)]
+#[test_case(b"Four score and seven years ago", 0x17770551ce7226e6)]
+fn test_jenkins_hash64(data: &[u8], expect: u64) {
+    assert_eq!(jenkins_hash64(data), expect, "jenkins_hash64({:?})", data.as_bstr());
+}
+
+// the IP addresses within some `MESSAGE` fields of the sample journal files
+// were scrubbed, so those `DATA` objects fail the hash check
+#[test_case(
+    &JOURNAL_FILE_RHE_91_SYSTEM_XZ_FPATH,
+    STATE_ONLINE,
+    *JOURNAL_FILE_RHE_91_SYSTEM_EVENT_COUNT,
+    16;
+    "RHE91 zstd keyed"
+)]
+#[test_case(
+    &FPath::from(JOURNAL_FILE_UBUNTU_22_SYSTEM_XZ_FPATH),
+    STATE_ARCHIVED,
+    *JOURNAL_FILE_UBUNTU_22_SYSTEM_EVENT_COUNT,
+    0;
+    "UBUNTU22"
+)]
+#[test_case(
+    &FPath::from(JOURNAL_FILE_UBUNTU_16_FPATH),
+    STATE_ONLINE,
+    289,
+    26;
+    "UBUNTU16 xz jenkins"
+)]
+fn test_JournalRecovery_undamaged(
+    fpath: &FPath,
+    state: u8,
+    entries: Count,
+    objects_damaged: Count,
+) {
+    let mut jr = JournalRecovery::from_vec(journal_bytes(fpath)).unwrap();
+    assert_eq!(jr.state(), state, "state");
+    assert!(!jr.is_truncated(), "is_truncated");
+    assert_eq!(jr.count_entries() as Count, entries, "count_entries");
+    for index in 0..jr.count_entries() {
+        let entry = jr.entry(index).unwrap();
+        assert!(!entry.data.is_empty(), "entry {} has no data", index);
+    }
+    assert_eq!(jr.count_objects_damaged(), objects_damaged, "count_objects_damaged");
+    assert_le!(jr.count_entries_damaged(), entries, "count_entries_damaged");
+    assert!(jr.entry(jr.count_entries()).is_none());
+}
+
+#[test]
+fn test_JournalRecovery_truncated() {
+    let data = journal_bytes(&JOURNAL_FILE_RHE_91_SYSTEM_XZ_FPATH);
+    let data_half = data[..data.len() / 2].to_vec();
+    let mut jr = JournalRecovery::from_vec(data_half).unwrap();
+    assert!(jr.is_truncated(), "is_truncated");
+    assert!(jr.is_unclean(), "is_unclean");
+    let count = jr.count_entries();
+    assert!(count > 0, "no entries recovered");
+    assert!((count as Count) < *JOURNAL_FILE_RHE_91_SYSTEM_EVENT_COUNT, "too many entries {}", count);
+    // entries referencing `DATA` objects past the truncation are still
+    // returned with the remaining data
+    for index in 0..count {
+        jr.entry(index).unwrap();
+    }
+}
+
+#[test]
+fn test_JournalRecovery_open() {
+    let jr = JournalRecovery::open(Path::new(JOURNAL_FILE_UBUNTU_16_FPATH)).unwrap();
+    assert_eq!(jr.state(), STATE_ONLINE, "state");
+    assert_eq!(jr.count_entries(), 289, "count_entries");
+}
+
+#[test]
+fn test_JournalHeader_read() {
+    let header = JournalHeader::read(Path::new(JOURNAL_FILE_UBUNTU_16_FPATH)).unwrap();
+    assert_eq!(header.state, STATE_ONLINE, "state");
+    assert_eq!(header.filesz, std::fs::metadata(JOURNAL_FILE_UBUNTU_16_FPATH).unwrap().len());
+    assert!(!header.is_truncated(), "is_truncated");
+    // an online file read to the last entry is not missing entries
+    assert!(!header.is_missing_entries(Some(header.tail_entry_realtime), None));
+}
+
+#[test]
+fn test_JournalHeader_truncated() {
+    let data = journal_bytes(&JOURNAL_FILE_RHE_91_SYSTEM_XZ_FPATH);
+    let header = JournalHeader::from_bytes(&data, data.len() as u64).unwrap();
+    assert!(!header.is_truncated(), "is_truncated");
+    let header_half = JournalHeader::from_bytes(&data, data.len() as u64 / 2).unwrap();
+    assert!(header_half.is_truncated(), "is_truncated");
+    assert!(header_half.is_missing_entries(Some(header_half.tail_entry_realtime), None));
+}
+
+#[test]
+fn test_JournalHeader_is_missing_entries() {
+    let data = journal_bytes(&JOURNAL_FILE_RHE_91_SYSTEM_XZ_FPATH);
+    let header = JournalHeader::from_bytes(&data, data.len() as u64).unwrap();
+    let tail = header.tail_entry_realtime;
+    assert_ne!(header.n_entries, 0, "n_entries");
+    assert!(!header.is_missing_entries(Some(tail), None), "read to tail");
+    assert!(header.is_missing_entries(Some(tail - 1), None), "stopped before tail");
+    assert!(header.is_missing_entries(None, None), "nothing read");
+    assert!(header.is_missing_entries(None, Some(tail)), "nothing read, filter at tail");
+    assert!(!header.is_missing_entries(None, Some(tail + 1)), "nothing read, filter after tail");
+}
+
+#[test_case(b""; "empty")]
+#[test_case(b"LPKSHHRH"; "signature only")]
+#[test_case(&[0u8; 512]; "zeros")]
+fn test_JournalHeader_invalid(data: &[u8]) {
+    assert!(JournalHeader::from_bytes(data, data.len() as u64).is_err());
+}
+
+#[test]
+fn test_JournalRecovery_bad_hash() {
+    let mut data = journal_bytes(&FPath::from(JOURNAL_FILE_UBUNTU_16_FPATH));
+    let mut jr = JournalRecovery::from_vec(data.clone()).unwrap();
+    let entry0 = jr.entry(0).unwrap();
+    // find an uncompressed `MESSAGE` of the first entry and change one byte
+    let message: &Vec<u8> = entry0.data.iter().find(|d| d.starts_with(b"MESSAGE=")).unwrap();
+    let at: usize = data.find(message.as_slice()).unwrap();
+    data[at + 8] ^= 0x20;
+
+    let mut jr = JournalRecovery::from_vec(data).unwrap();
+    let entry0_damaged = jr.entry(0).unwrap();
+    assert_eq!(entry0_damaged.data.len() + 1, entry0.data.len(), "damaged MESSAGE should be skipped");
+    assert!(!entry0_damaged.data.iter().any(|d| d.starts_with(b"MESSAGE=")));
+    assert_eq!(jr.count_objects_damaged(), 1, "count_objects_damaged");
+    assert_eq!(jr.count_entries_damaged(), 1, "count_entries_damaged");
+}
+
+#[test_case(b""; "empty")]
+#[test_case(b"LPKSHHRH"; "signature only")]
+#[test_case(&[0u8; 512]; "zeros")]
+fn test_JournalRecovery_invalid(data: &[u8]) {
+    assert!(JournalRecovery::from_vec(data.to_vec()).is_err());
+}
+
+#[test]
+fn test_JournalRecovery_cursor() {
+    let mut jr = JournalRecovery::from_vec(
+        journal_bytes(&JOURNAL_FILE_RHE_91_SYSTEM_XZ_FPATH)
+    ).unwrap();
+    let seqnum_id = *jr.seqnum_id();
+    let entry = jr.entry(0).unwrap();
+    let cursor = entry.cursor(&seqnum_id);
+    assert!(cursor.starts_with("s="), "{}", cursor);
+    assert!(cursor.contains(";i=1;"), "{}", cursor);
+    assert!(cursor.contains(&format!(";t={:x};", entry.realtime)), "{}", cursor);
+}
+
+/// A `JournalReader` reading a truncated journal file must return the
+/// entries found by `libsystemd` and then the remaining recovered entries.
+#[test_case(JournalOutput::Short)]
+#[test_case(JournalOutput::ShortMonotonic)]
+#[test_case(JournalOutput::Verbose)]
+#[test_case(JournalOutput::Export)]
+#[test_case(JournalOutput::Cat)]
+fn test_JournalReader_recovery_truncated(journal_output: JournalOutput) {
+    if SYSTEMD_NOT_AVAILABLE {
+        defñ!("skip");
+        return;
+    }
+    assert!(matches!(load_library_systemd(), LoadLibraryError::Ok));
+    summary_stats_enable();
+    let data = journal_bytes(&JOURNAL_FILE_RHE_91_SYSTEM_XZ_FPATH);
+    let data_half = data[..data.len() / 2].to_vec();
+    let entries_expect = JournalRecovery::from_vec(data_half.clone()).unwrap().count_entries() as Count;
+    let ntf = create_temp_file_bytes_with_suffix(&data_half, &String::from(".journal"));
+    let fpath = ntf_fpath(&ntf);
+    let mut journalreader = JournalReader::new(
+        path_id_generator(),
+        fpath,
+        journal_output,
+        FO_0,
+        FileType::Journal { archival_type: FileTypeArchive::Normal },
+    ).unwrap();
+    journalreader.analyze(&None).unwrap();
+    let mut found: Count = 0;
+    loop {
+        match journalreader.next(&None) {
+            ResultNext::Found(je) => {
+                assert!(!je.as_bytes().is_empty());
+                found += 1;
+            }
+            ResultNext::Done => break,
+            ResultNext::ErrIgnore(_) => {}
+            ResultNext::Err(err) => panic!("journalreader.next() failed: {}", err),
+        }
+    }
+    assert_eq!(found, entries_expect, "entries found");
+    let summary = journalreader.summary();
+    assert_eq!(summary.journalreader_events_processed, entries_expect);
+    assert_eq!(summary.journalreader_events_accepted, entries_expect);
+    assert!(summary.journalreader_entries_recovered <= entries_expect);
+    assert!(Path::new(journalreader.path()).exists());
+}
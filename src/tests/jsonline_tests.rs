@@ -0,0 +1,187 @@
+// src/tests/jsonline_tests.rs
+
+//! tests for `jsonline.rs`
+
+#![allow(non_snake_case)]
+
+use ::serde_json::Value;
+use ::test_case::test_case;
+
+use crate::data::datetime::{
+    ymdhms,
+    ymdhmsm,
+    DateTimeL,
+};
+use crate::data::jsonline::{
+    jsonline_keyvalue,
+    jsonline_parse,
+    jsonline_pointer,
+    jsonline_value_span,
+    jsonline_value_to_datetime,
+    JsonLine,
+    JsonLineFilter,
+    JsonLineTime,
+    JsonLinesMode,
+    JSONLINE_TIME_POINTERS_DEFAULT,
+};
+use crate::tests::common::{
+    FO_0,
+    FO_P8,
+};
+
+#[test_case("@timestamp", "/@timestamp")]
+#[test_case("/http/status", "/http/status")]
+#[test_case("a/b", "/a~1b")]
+#[test_case("a~b", "/a~0b")]
+fn test_jsonline_pointer(
+    key: &str,
+    expect: &str,
+) {
+    assert_eq!(jsonline_pointer(key), expect);
+}
+
+#[test_case(r#""2024-01-02T03:04:05.678Z""#, Some(ymdhmsm(&FO_0, 2024, 1, 2, 3, 4, 5, 678000)); "rfc3339 Z")]
+#[test_case(r#""2024-01-02T03:04:05+08:00""#, Some(ymdhms(&FO_P8, 2024, 1, 2, 3, 4, 5)); "rfc3339 offset")]
+#[test_case(r#""2024-01-02 03:04:05.678""#, Some(ymdhmsm(&FO_P8, 2024, 1, 2, 3, 4, 5, 678000)); "naive")]
+#[test_case(r#""2024/01/02 03:04:05""#, Some(ymdhms(&FO_P8, 2024, 1, 2, 3, 4, 5)); "naive slashes")]
+#[test_case("1704164645", Some(ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5)); "epoch seconds")]
+#[test_case("1704164645678", Some(ymdhmsm(&FO_0, 2024, 1, 2, 3, 4, 5, 678000)); "epoch milliseconds")]
+#[test_case("1704164645678901000", Some(ymdhmsm(&FO_0, 2024, 1, 2, 3, 4, 5, 678901)); "epoch nanoseconds")]
+#[test_case("1704164645.5", Some(ymdhmsm(&FO_0, 2024, 1, 2, 3, 4, 5, 500000)); "epoch float")]
+#[test_case(r#""1704164645""#, Some(ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5)); "epoch string")]
+#[test_case(r#""yesterday""#, None; "not a datetime")]
+#[test_case("true", None; "bool")]
+#[test_case("null", None; "null")]
+fn test_jsonline_value_to_datetime(
+    value: &str,
+    expect: Option<DateTimeL>,
+) {
+    let value: Value = ::serde_json::from_str(value).unwrap();
+    let actual = jsonline_value_to_datetime(&value, &FO_P8);
+    assert_eq!(actual, expect);
+}
+
+#[test_case(r#"{"time":"2024-01-02","a":1}"#, "/time", Some((9, 19)); "string")]
+#[test_case(r#"{"a":1, "ts" : 1704164645}"#, "/ts", Some((15, 25)); "number")]
+#[test_case(r#"{"msg":"ts","ts":1}"#, "/ts", Some((17, 18)); "key text in value")]
+#[test_case(r#"{"http":{"status":500}}"#, "/http/status", Some((18, 21)); "nested")]
+#[test_case(r#"{"a":1}"#, "/ts", None; "missing")]
+fn test_jsonline_value_span(
+    line: &str,
+    pointer: &str,
+    expect: Option<(usize, usize)>,
+) {
+    assert_eq!(jsonline_value_span(line, pointer), expect);
+}
+
+#[test]
+fn test_JsonLineTime_new() {
+    assert_eq!(JsonLineTime::new(JsonLinesMode::Never, None), None);
+    assert_eq!(JsonLineTime::new(JsonLinesMode::Never, Some("time")), None);
+
+    let jlt = JsonLineTime::new(JsonLinesMode::Auto, None).unwrap();
+    assert!(!jlt.always);
+    assert_eq!(jlt.pointers, JSONLINE_TIME_POINTERS_DEFAULT.to_vec());
+
+    let jlt = JsonLineTime::new(JsonLinesMode::Always, Some(" when, /event/created ,")).unwrap();
+    assert!(jlt.always);
+    assert_eq!(jlt.pointers, vec!["/when", "/event/created"]);
+}
+
+#[test_case(
+    None,
+    r#"{"@timestamp":"2024-01-02T03:04:05Z","msg":"at 2023-01-01 00:00:00"}"#,
+    Some((15, 35, ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5)));
+    "default pointer"
+)]
+#[test_case(
+    None,
+    r#"  {"level":"info","ts":1704164645}"#,
+    Some((23, 33, ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5)));
+    "indented"
+)]
+#[test_case(
+    None,
+    r#"{"time":"not a date","ts":1704164645}"#,
+    Some((26, 36, ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5)));
+    "skip invalid datetime field"
+)]
+#[test_case(
+    Some("/event/created"),
+    r#"{"time":1,"event":{"created":"2024-01-02 03:04:05"}}"#,
+    Some((30, 49, ymdhms(&FO_P8, 2024, 1, 2, 3, 4, 5)));
+    "custom pointer"
+)]
+#[test_case(None, r#"{"level":"info"}"#, None; "no datetime field")]
+#[test_case(None, "2024-01-02 03:04:05 hello", None; "not json")]
+#[test_case(None, r#"{"time":"2024-01-02"#, None; "invalid json")]
+fn test_JsonLineTime_find_datetime(
+    pointers: Option<&str>,
+    line: &str,
+    expect: Option<(usize, usize, DateTimeL)>,
+) {
+    let jlt = JsonLineTime::new(JsonLinesMode::Auto, pointers).unwrap();
+    assert_eq!(jlt.find_datetime(line, &FO_P8), expect);
+}
+
+#[test]
+fn test_JsonLineFilter_new() {
+    let filter = JsonLineFilter::new(&[]).unwrap();
+    assert!(filter.is_empty());
+    let filter = JsonLineFilter::new(&[String::from("level=error"), String::from("/http/status=5=0")]).unwrap();
+    assert_eq!(
+        filter.fields,
+        vec![
+            (String::from("/level"), String::from("error")),
+            (String::from("/http/status"), String::from("5=0")),
+        ]
+    );
+    assert!(JsonLineFilter::new(&[String::from("level")]).is_err());
+    assert!(JsonLineFilter::new(&[String::from("=error")]).is_err());
+}
+
+#[test_case(&[], "not json", true; "empty filter passes all")]
+#[test_case(&["level=error"], r#"{"level":"error"}"#, true; "string")]
+#[test_case(&["level=error"], r#"{"level":"info"}"#, false; "string mismatch")]
+#[test_case(&["level=error"], "level=error", false; "not json")]
+#[test_case(&["/http/status=500"], r#"{"http":{"status":500}}"#, true; "nested number")]
+#[test_case(&["/tags/1=b"], r#"{"tags":["a","b"]}"#, true; "array index")]
+#[test_case(&["ok=true"], r#"{"ok":true}"#, true; "bool")]
+#[test_case(&["level=error", "host=a"], r#"{"level":"error","host":"b"}"#, false; "all fields")]
+#[test_case(&["host=a"], r#"{"level":"error"}"#, false; "missing field")]
+fn test_JsonLineFilter_matches(
+    fields: &[&str],
+    line: &str,
+    expect: bool,
+) {
+    let fields: Vec<String> = fields.iter().map(|s| String::from(*s)).collect();
+    let filter = JsonLineFilter::new(&fields).unwrap();
+    assert_eq!(filter.matches(jsonline_parse(line).as_ref()), expect);
+}
+
+#[test_case(r#"{"a":1,"b":"x"}"#, "a=1 b=x"; "simple")]
+#[test_case(r#"{"msg":"hello world","e":""}"#, r#"msg="hello world" e="""#; "quoted")]
+#[test_case(r#"{"q":"a=b","s":"say \"hi\""}"#, r#"q="a=b" s="say \"hi\"""#; "escaped")]
+#[test_case(r#"{"http":{"status":500,"method":"GET"},"n":null}"#, "http.status=500 http.method=GET n=null"; "nested")]
+#[test_case(r#"{"z":1,"a":2}"#, "z=1 a=2"; "key order")]
+fn test_jsonline_keyvalue(
+    line: &str,
+    expect: &str,
+) {
+    let object = jsonline_parse(line).unwrap();
+    assert_eq!(jsonline_keyvalue(&object), expect);
+}
+
+#[test]
+fn test_JsonLine_to_keyvalue() {
+    let dt = ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5);
+    let mut jsonline = JsonLine::new(dt, "{\"ts\":1704164645,\"msg\":\"a b\"}\r\n  trailing\n");
+    assert_eq!(jsonline.dt(), &dt);
+    jsonline.to_keyvalue();
+    assert_eq!(jsonline.lines, vec![r#"ts=1704164645 msg="a b""#, "  trailing"]);
+
+    let mut jsonline = JsonLine::new(dt, "2024-01-02 03:04:05 plain\n");
+    assert_eq!(jsonline.object, None);
+    jsonline.to_keyvalue();
+    assert_eq!(jsonline.lines, vec!["2024-01-02 03:04:05 plain"]);
+}
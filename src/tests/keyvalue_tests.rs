@@ -0,0 +1,223 @@
+// src/tests/keyvalue_tests.rs
+
+//! tests for `keyvalue.rs`
+
+#![allow(non_snake_case)]
+
+use ::test_case::test_case;
+
+use crate::data::datetime::{
+    ymdhms,
+    ymdhmsm,
+    DateTimeL,
+};
+use crate::data::keyvalue::{
+    keyvalue_java_to_strftime,
+    keyvalue_parse,
+    keyvalue_parse_logfmt,
+    keyvalue_replace_tz_name,
+    keyvalue_value_to_datetime,
+    KeyValueFilter,
+    KeyValueFormat,
+    KeyValueMode,
+    KeyValueTime,
+    KEYVALUE_TIME_KEYS_DEFAULT,
+};
+use crate::tests::common::{
+    FO_0,
+    FO_P8,
+};
+
+/// `(key, value, beg, end)` of each pair
+type Pairs<'a> = &'a [(&'a str, &'a str, usize, usize)];
+
+#[test_case("a=1 b=x", Some(&[("a", "1", 2, 3), ("b", "x", 6, 7)]); "simple")]
+#[test_case(r#"msg="hello \"world\"" e="""#, Some(&[("msg", r#"hello "world""#, 5, 20), ("e", "", 25, 25)]); "quoted")]
+#[test_case("  a=1 flag b=c=d", Some(&[("a", "1", 4, 5), ("flag", "", 10, 10), ("b", "c=d", 13, 16)]); "bare key")]
+#[test_case("flag a=1", None; "first key without value")]
+#[test_case(r#"a="unterminated"#, None; "unterminated quote")]
+#[test_case("=1", None; "empty key")]
+#[test_case("", None; "empty")]
+fn test_keyvalue_parse_logfmt(
+    line: &str,
+    expect: Option<Pairs>,
+) {
+    let actual = keyvalue_parse_logfmt(line).map(|pairs| {
+        pairs
+            .into_iter()
+            .map(|pair| (pair.key, pair.value, pair.beg, pair.end))
+            .collect::<Vec<(String, String, usize, usize)>>()
+    });
+    let expect = expect.map(|pairs| {
+        pairs
+            .iter()
+            .map(|(k, v, b, e)| (String::from(*k), String::from(*v), *b, *e))
+            .collect::<Vec<(String, String, usize, usize)>>()
+    });
+    assert_eq!(actual, expect);
+}
+
+#[test]
+fn test_keyvalue_parse_cef() {
+    let line = r"Jan 02 03:04:05 fw1 CEF:0|Sec\|urity|tm|1.0|100|worm stopped|10|rt=1704164646000 msg=a b\=c src=10.0.0.1";
+    let keyvalues = keyvalue_parse(line).unwrap();
+    assert_eq!(keyvalues.format, KeyValueFormat::Cef);
+    let get = |key: &str| keyvalues.get(key).map(|pair| pair.value.as_str());
+    assert_eq!(get("cefVersion"), Some("0"));
+    assert_eq!(get("deviceVendor"), Some("Sec|urity"));
+    assert_eq!(get("name"), Some("worm stopped"));
+    assert_eq!(get("severity"), Some("10"));
+    assert_eq!(get("rt"), Some("1704164646000"));
+    assert_eq!(get("msg"), Some("a b=c"));
+    assert_eq!(get("src"), Some("10.0.0.1"));
+    let rt = keyvalues.get("rt").unwrap();
+    assert_eq!(&line[rt.beg..rt.end], "1704164646000");
+    let msg = keyvalues.get("msg").unwrap();
+    assert_eq!(&line[msg.beg..msg.end], r"a b\=c");
+}
+
+#[test_case("LEEF:1.0|V|P|1.0|E1|devTime=Jan 02 2024 03:04:05\tsrc=10.0.0.1"; "tab")]
+#[test_case("LEEF:2.0|V|P|1.0|E1|^|devTime=Jan 02 2024 03:04:05^src=10.0.0.1"; "caret")]
+#[test_case("LEEF:2.0|V|P|1.0|E1|x7C|devTime=Jan 02 2024 03:04:05|src=10.0.0.1"; "hex")]
+#[test_case("LEEF:2.0|V|P|1.0|E1|devTime=Jan 02 2024 03:04:05\tsrc=10.0.0.1"; "no delimiter field")]
+#[test_case("LEEF:1.0|V|P|1.0|E1|devTime=Jan 02 2024 03:04:05 src=10.0.0.1"; "space")]
+fn test_keyvalue_parse_leef(line: &str) {
+    let keyvalues = keyvalue_parse(line).unwrap();
+    assert_eq!(keyvalues.format, KeyValueFormat::Leef);
+    let get = |key: &str| keyvalues.get(key).map(|pair| pair.value.as_str());
+    assert_eq!(get("vendor"), Some("V"));
+    assert_eq!(get("eventId"), Some("E1"));
+    assert_eq!(get("devTime"), Some("Jan 02 2024 03:04:05"));
+    assert_eq!(get("src"), Some("10.0.0.1"));
+    let devtime = keyvalues.get("devTime").unwrap();
+    assert_eq!(&line[devtime.beg..devtime.end], "Jan 02 2024 03:04:05");
+}
+
+#[test_case("Jan 02 03:04:05 host sshd[1]: Accepted password"; "syslog")]
+#[test_case("2024-01-02 03:04:05 hello"; "datetime")]
+#[test_case("host xCEF:0|a|b|c|d|e|f|rt=1"; "marker within word")]
+fn test_keyvalue_parse_none(line: &str) {
+    assert_eq!(keyvalue_parse(line), None);
+}
+
+#[test_case("MMM dd yyyy HH:mm:ss.SSS z", Some("%b %d %Y %H:%M:%S.%3f %Z"))]
+#[test_case("yyyy-MM-dd'T'HH:mm:ssZ", Some("%Y-%m-%dT%H:%M:%S%z"))]
+#[test_case("dd/MM/yy hh:mm a", Some("%d/%m/%y %I:%M %p"))]
+#[test_case("'at '''HH", Some("at '%H"))]
+#[test_case("yyyy G", None)]
+fn test_keyvalue_java_to_strftime(
+    format: &str,
+    expect: Option<&str>,
+) {
+    assert_eq!(keyvalue_java_to_strftime(format).as_deref(), expect);
+}
+
+#[test_case("Jan 02 2024 03:04:05 UTC", Some("Jan 02 2024 03:04:05 +00:00"))]
+#[test_case("Jan 02 2024 03:04:05 PST", Some("Jan 02 2024 03:04:05 -08:00"))]
+#[test_case("Jan 02 2024 03:04:05 +0100", None)]
+#[test_case("Jan 02 2024 03:04:05 NOTATZ", None)]
+fn test_keyvalue_replace_tz_name(
+    value: &str,
+    expect: Option<&str>,
+) {
+    assert_eq!(keyvalue_replace_tz_name(value).as_deref(), expect);
+}
+
+#[test_case("1704164646000", None, Some(ymdhms(&FO_0, 2024, 1, 2, 3, 4, 6)); "epoch milliseconds")]
+#[test_case("1704164646.5", None, Some(ymdhmsm(&FO_0, 2024, 1, 2, 3, 4, 6, 500000)); "epoch float")]
+#[test_case("2024-01-02T03:04:05Z", None, Some(ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5)); "rfc3339")]
+#[test_case("Jan 02 2024 03:04:05.678 UTC", None, Some(ymdhmsm(&FO_0, 2024, 1, 2, 3, 4, 5, 678000)); "CEF named timezone")]
+#[test_case("Jan 02 2024 03:04:05 +01:00", None, Some(ymdhms(&FO_P8, 2024, 1, 2, 10, 4, 5)); "CEF offset")]
+#[test_case("Jan 02 2024 03:04:05", None, Some(ymdhms(&FO_P8, 2024, 1, 2, 3, 4, 5)); "CEF naive")]
+#[test_case("02.01.2024 03-04-05", Some("dd.MM.yyyy HH-mm-ss"), Some(ymdhms(&FO_P8, 2024, 1, 2, 3, 4, 5)); "java format")]
+#[test_case("02.01.2024 03-04-05 GMT", Some("dd.MM.yyyy HH-mm-ss z"), Some(ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5)); "java format named timezone")]
+#[test_case("yesterday", None, None; "not a datetime")]
+#[test_case("", None, None; "empty")]
+fn test_keyvalue_value_to_datetime(
+    value: &str,
+    format: Option<&str>,
+    expect: Option<DateTimeL>,
+) {
+    assert_eq!(keyvalue_value_to_datetime(value, format, &FO_P8), expect);
+}
+
+#[test]
+fn test_KeyValueTime_new() {
+    assert_eq!(KeyValueTime::new(KeyValueMode::Never, None), None);
+
+    let kvt = KeyValueTime::new(KeyValueMode::Auto, None).unwrap();
+    assert!(!kvt.always);
+    assert_eq!(kvt.keys, KEYVALUE_TIME_KEYS_DEFAULT.to_vec());
+
+    let kvt = KeyValueTime::new(KeyValueMode::Always, Some(" end, start ,")).unwrap();
+    assert!(kvt.always);
+    assert_eq!(kvt.keys, vec!["end", "start"]);
+}
+
+#[test_case(
+    None,
+    r#"time=2024-01-02T03:04:05Z level=info msg="at 2023-01-01 00:00:00""#,
+    Some((5, 25, ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5)));
+    "logfmt"
+)]
+#[test_case(
+    None,
+    "Jan 01 00:00:00 h CEF:0|V|P|1|2|N|3|src=1.2.3.4 rt=1704164645000",
+    Some((51, 64, ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5)));
+    "CEF rt"
+)]
+#[test_case(
+    None,
+    "LEEF:2.0|V|P|1|E|^|devTimeFormat=dd.MM.yyyy HH:mm:ss^devTime=02.01.2024 03:04:05",
+    Some((61, 80, ymdhms(&FO_P8, 2024, 1, 2, 3, 4, 5)));
+    "LEEF devTimeFormat"
+)]
+#[test_case(
+    Some("end"),
+    "CEF:0|V|P|1|2|N|3|rt=1704164645000 end=1704164646000",
+    Some((39, 52, ymdhms(&FO_0, 2024, 1, 2, 3, 4, 6)));
+    "custom key"
+)]
+#[test_case(None, "level=info msg=hello", None; "no datetime key")]
+#[test_case(None, "Jan 02 03:04:05 host prog: time=2024-01-02T03:04:05Z", None; "not key-value")]
+fn test_KeyValueTime_find_datetime(
+    keys: Option<&str>,
+    line: &str,
+    expect: Option<(usize, usize, DateTimeL)>,
+) {
+    let kvt = KeyValueTime::new(KeyValueMode::Auto, keys).unwrap();
+    assert_eq!(kvt.find_datetime(line, &FO_P8), expect);
+}
+
+#[test]
+fn test_KeyValueFilter_new() {
+    let filter = KeyValueFilter::new(&[]).unwrap();
+    assert!(filter.is_empty());
+    let filter = KeyValueFilter::new(&[String::from(" level =error"), String::from("q=a=b")]).unwrap();
+    assert_eq!(
+        filter.fields,
+        vec![
+            (String::from("level"), String::from("error")),
+            (String::from("q"), String::from("a=b")),
+        ]
+    );
+    assert!(KeyValueFilter::new(&[String::from("level")]).is_err());
+    assert!(KeyValueFilter::new(&[String::from("=error")]).is_err());
+}
+
+#[test_case(&[], "not key-value", true; "empty filter passes all")]
+#[test_case(&["level=error"], "level=error msg=x", true; "logfmt")]
+#[test_case(&["level=error"], "level=info msg=x", false; "logfmt mismatch")]
+#[test_case(&["level=error"], "Jan 02 03:04:05 level=error", false; "not key-value")]
+#[test_case(&["severity=10", "src=1.2.3.4"], "CEF:0|V|P|1|2|N|10|src=1.2.3.4", true; "CEF header and extension")]
+#[test_case(&["eventId=E1"], "LEEF:1.0|V|P|1|E1|src=1.2.3.4", true; "LEEF header")]
+#[test_case(&["dst=1.2.3.4"], "CEF:0|V|P|1|2|N|10|src=1.2.3.4", false; "missing key")]
+fn test_KeyValueFilter_matches(
+    fields: &[&str],
+    line: &str,
+    expect: bool,
+) {
+    let fields: Vec<String> = fields.iter().map(|s| String::from(*s)).collect();
+    let filter = KeyValueFilter::new(&fields).unwrap();
+    assert_eq!(filter.matches(keyvalue_parse(line).as_ref()), expect);
+}
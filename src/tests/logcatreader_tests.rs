@@ -0,0 +1,350 @@
+// src/tests/logcatreader_tests.rs
+
+//! tests for [`src/readers/logcatreader.rs`]
+//!
+//! [`src/readers/logcatreader.rs`]: crate::readers::logcatreader
+
+#![allow(non_snake_case)]
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[allow(unused_imports)]
+use ::si_trace_print::printers::{
+    defn,
+    defo,
+    defx,
+};
+use ::test_case::test_case;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileType,
+    FileTypeArchive,
+};
+use crate::data::datetime::{
+    DateTimeLOpt,
+    ymdhmsm,
+    ymdhmsn,
+};
+use crate::debug::helpers::{
+    create_temp_dir,
+    TempDir,
+};
+use crate::readers::helpers::path_to_fpath;
+use crate::readers::logcatreader::{
+    format_event_value,
+    parse_event_log_tags,
+    LogcatReader,
+    EVENT_LOG_TAGS_NAME,
+    EVENT_TYPE_FLOAT,
+    EVENT_TYPE_INT,
+    EVENT_TYPE_LIST,
+    EVENT_TYPE_LONG,
+    EVENT_TYPE_STRING,
+    LOGGER_ENTRY_V1_SZ,
+    LOGGER_ENTRY_V3_SZ,
+    LOGGER_ENTRY_V4_SZ,
+    LOG_ID_EVENTS,
+    LOG_ID_MAIN,
+    LOG_ID_SYSTEM,
+};
+use crate::readers::pyeventreader::ResultNextPyDataEvent;
+use crate::tests::common::{
+    path_id_generator,
+    FO_0,
+    FO_E8,
+    NTF_LOG_EMPTY_FPATH,
+};
+
+const FT_LOGCAT: FileType = FileType::Logcat { archival_type: FileTypeArchive::Normal };
+
+/// 2023-11-14 22:13:20 UTC
+const SEC: u32 = 1_700_000_000;
+
+const EVENT_LOG_TAGS: &str = "# comment\n\n42 answer (to life the universe etc|3)\n2718 e\n";
+
+/// an `EVENT_TYPE_LIST` value of an int, a long, a string, and a float
+fn event_list() -> Vec<u8> {
+    let mut out: Vec<u8> = vec![EVENT_TYPE_LIST, 4, EVENT_TYPE_INT];
+    out.extend_from_slice(&(-1i32).to_le_bytes());
+    out.push(EVENT_TYPE_LONG);
+    out.extend_from_slice(&2i64.to_le_bytes());
+    out.push(EVENT_TYPE_STRING);
+    out.extend_from_slice(&3u32.to_le_bytes());
+    out.extend_from_slice(b"abc");
+    out.push(EVENT_TYPE_FLOAT);
+    out.extend_from_slice(&1.5f32.to_le_bytes());
+
+    out
+}
+
+#[test_case(vec![EVENT_TYPE_INT, 7, 0, 0, 0], Some((String::from("7"), 5)); "int")]
+#[test_case(vec![EVENT_TYPE_INT, 7, 0], None; "int truncated")]
+#[test_case(
+    [vec![EVENT_TYPE_LONG], 5_000_000_000i64.to_le_bytes().to_vec()].concat(),
+    Some((String::from("5000000000"), 9));
+    "long"
+)]
+#[test_case(vec![EVENT_TYPE_STRING, 2, 0, 0, 0, b'h', b'i'], Some((String::from("hi"), 7)); "string")]
+#[test_case(vec![EVENT_TYPE_STRING, 9, 0, 0, 0, b'h', b'i'], None; "string truncated")]
+#[test_case(event_list(), Some((String::from("[-1,2,abc,1.500000]"), 29)); "list")]
+#[test_case(vec![EVENT_TYPE_LIST, 0], Some((String::from("[]"), 2)); "list empty")]
+#[test_case(vec![9], None; "unknown type")]
+#[test_case(vec![], None; "empty")]
+fn test_format_event_value(
+    data: Vec<u8>,
+    expect: Option<(String, usize)>,
+) {
+    assert_eq!(format_event_value(&data, 0), expect);
+}
+
+#[test]
+fn test_parse_event_log_tags() {
+    let expect: HashMap<u32, String> = HashMap::from([
+        (42, String::from("answer")),
+        (2718, String::from("e")),
+    ]);
+    assert_eq!(parse_event_log_tags(EVENT_LOG_TAGS), expect);
+}
+
+/// a `logger_entry` of `header_sz` with `payload`. For a version 1
+/// `logger_entry`, `lid` is ignored.
+fn logger_entry(
+    header_sz: usize,
+    lid: u32,
+    pid: i32,
+    tid: u32,
+    sec: u32,
+    nsec: u32,
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut out: Vec<u8> = Vec::new();
+    out.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+    match header_sz {
+        LOGGER_ENTRY_V1_SZ => out.extend_from_slice(&0u16.to_le_bytes()),
+        _ => out.extend_from_slice(&(header_sz as u16).to_le_bytes()),
+    }
+    out.extend_from_slice(&pid.to_le_bytes());
+    out.extend_from_slice(&tid.to_le_bytes());
+    out.extend_from_slice(&sec.to_le_bytes());
+    out.extend_from_slice(&nsec.to_le_bytes());
+    if header_sz >= LOGGER_ENTRY_V3_SZ {
+        out.extend_from_slice(&lid.to_le_bytes());
+    }
+    if header_sz == LOGGER_ENTRY_V4_SZ {
+        // uid
+        out.extend_from_slice(&10_000u32.to_le_bytes());
+    }
+    out.extend_from_slice(payload);
+
+    out
+}
+
+/// a text buffer payload
+fn text_payload(
+    priority: u8,
+    tag: &str,
+    message: &str,
+) -> Vec<u8> {
+    let mut out: Vec<u8> = vec![priority];
+    out.extend_from_slice(tag.as_bytes());
+    out.push(0);
+    out.extend_from_slice(message.as_bytes());
+    out.push(0);
+
+    out
+}
+
+/// a binary buffer payload
+fn event_payload(
+    tag: u32,
+    value: &[u8],
+) -> Vec<u8> {
+    let mut out: Vec<u8> = tag.to_le_bytes().to_vec();
+    out.extend_from_slice(value);
+
+    out
+}
+
+/// the log buffer of version 4 and version 3 entries, with main, system,
+/// and events buffer entries
+fn log_buffer_v4() -> Vec<u8> {
+    let mut data: Vec<u8> = Vec::new();
+    data.extend(logger_entry(
+        LOGGER_ENTRY_V4_SZ, LOG_ID_MAIN, 1234, 1240, SEC, 123_456_789, &text_payload(4, "MyTag", "hello world\n"),
+    ));
+    data.extend(logger_entry(
+        LOGGER_ENTRY_V4_SZ, LOG_ID_EVENTS, 567, 570, SEC + 1, 0, &event_payload(42, &event_list()),
+    ));
+    data.extend(logger_entry(
+        LOGGER_ENTRY_V4_SZ, LOG_ID_EVENTS, 567, 570, SEC + 2, 0, &event_payload(7, &[EVENT_TYPE_INT, 1, 0, 0, 0]),
+    ));
+    data.extend(logger_entry(
+        LOGGER_ENTRY_V3_SZ, LOG_ID_SYSTEM, 1, 2, SEC + 3, 5_000_000, &text_payload(6, "LongTagName", "line one\nline two"),
+    ));
+
+    data
+}
+
+const LOG_BUFFER_V4_EVENTS: [&str; 4] = [
+    "2023-11-14 22:13:20.123  1234  1240 I MyTag   : hello world\n",
+    "2023-11-14 22:13:21.000   567   570 I answer  : [-1,2,abc,1.500000]\n",
+    "2023-11-14 22:13:22.000   567   570 I 7       : 1\n",
+    "2023-11-14 22:13:23.005     1     2 E LongTagName: line one\n\
+     2023-11-14 22:13:23.005     1     2 E LongTagName: line two\n",
+];
+
+/// the log buffer of version 1 and version 2 entries of the events buffer
+fn log_buffer_v1() -> Vec<u8> {
+    let mut data: Vec<u8> = Vec::new();
+    data.extend(logger_entry(
+        LOGGER_ENTRY_V1_SZ, 0, 10, 11, SEC, 0, &event_payload(2718, &[EVENT_TYPE_STRING, 1, 0, 0, 0, b'x']),
+    ));
+    // version 2 with `euid` 1000 where the `lid` is in a version 3 entry
+    data.extend(logger_entry(
+        LOGGER_ENTRY_V3_SZ, 1000, 10, 11, SEC + 1, 0, &event_payload(42, &[EVENT_TYPE_INT, 3, 0, 0, 0]),
+    ));
+
+    data
+}
+
+const LOG_BUFFER_V1_EVENTS: [&str; 2] = [
+    "2023-11-14 22:13:20.000    10    11 I e       : x\n",
+    "2023-11-14 22:13:21.000    10    11 I answer  : 3\n",
+];
+
+/// write `data` to file `name` in `tempdir`, with an `event-log-tags` file
+fn create_log_buffer(
+    tempdir: &TempDir,
+    name: &str,
+    data: &[u8],
+) -> FPath {
+    let path: PathBuf = tempdir.path().join(name);
+    std::fs::write(&path, data).unwrap();
+    std::fs::write(tempdir.path().join(EVENT_LOG_TAGS_NAME), EVENT_LOG_TAGS).unwrap();
+
+    path_to_fpath(&path)
+}
+
+/// read all events of `logcatreader`
+fn read_all(logcatreader: &mut LogcatReader) -> Vec<String> {
+    let mut events: Vec<String> = Vec::new();
+    loop {
+        match logcatreader.next(&None, &None) {
+            ResultNextPyDataEvent::Found(event) => events.push(String::from_utf8_lossy(event.as_bytes()).into_owned()),
+            ResultNextPyDataEvent::Done => break,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    events
+}
+
+#[test]
+fn test_LogcatReader_new() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_log_buffer(&tempdir, "logcat.bin", &log_buffer_v4());
+    let path_id = path_id_generator();
+    let logcatreader = LogcatReader::new(path_id, path, FT_LOGCAT, FO_0).unwrap();
+    defo!("logcatreader: {:?}", logcatreader);
+    assert_eq!(logcatreader.path_id(), path_id);
+    assert_eq!(logcatreader.filetype(), FT_LOGCAT);
+    assert_eq!(logcatreader.filesz(), log_buffer_v4().len() as u64);
+    assert_eq!(logcatreader.event_tags_len(), 2);
+}
+
+#[test]
+fn test_LogcatReader_new_empty() {
+    let result = LogcatReader::new(path_id_generator(), NTF_LOG_EMPTY_FPATH.clone(), FT_LOGCAT, FO_0);
+    assert!(result.is_err(), "expected Err, got {:?}", result);
+}
+
+#[test]
+fn test_LogcatReader_new_bad_header_size() {
+    let tempdir = create_temp_dir();
+    let mut data: Vec<u8> = log_buffer_v4();
+    data[2] = 99;
+    let path: FPath = create_log_buffer(&tempdir, "logcat.bin", &data);
+    let result = LogcatReader::new(path_id_generator(), path, FT_LOGCAT, FO_0);
+    assert!(result.is_err(), "expected Err, got {:?}", result);
+}
+
+#[test]
+fn test_LogcatReader_next_v4() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_log_buffer(&tempdir, "logcat.bin", &log_buffer_v4());
+    let mut logcatreader = LogcatReader::new(path_id_generator(), path, FT_LOGCAT, FO_0).unwrap();
+    let event = match logcatreader.next(&None, &None) {
+        ResultNextPyDataEvent::Found(event) => event,
+        result => panic!("expected Found, got {:?}", result),
+    };
+    assert_eq!(event.dt(), &ymdhmsn(&FO_0, 2023, 11, 14, 22, 13, 20, 123_456_789));
+    assert_eq!(event.dt_beg_end(), &Some((0, 23)));
+    let mut events: Vec<String> = vec![String::from_utf8_lossy(event.as_bytes()).into_owned()];
+    events.extend(read_all(&mut logcatreader));
+    assert_eq!(events, LOG_BUFFER_V4_EVENTS);
+}
+
+#[test]
+fn test_LogcatReader_next_v1_events() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_log_buffer(&tempdir, "events.logcat.bin", &log_buffer_v1());
+    let mut logcatreader = LogcatReader::new(path_id_generator(), path, FT_LOGCAT, FO_0).unwrap();
+    assert_eq!(read_all(&mut logcatreader), LOG_BUFFER_V1_EVENTS);
+}
+
+#[test]
+fn test_LogcatReader_next_fixed_offset() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_log_buffer(&tempdir, "logcat.bin", &log_buffer_v4());
+    let mut logcatreader = LogcatReader::new(path_id_generator(), path, FT_LOGCAT, FO_E8).unwrap();
+    let events: Vec<String> = read_all(&mut logcatreader);
+    assert_eq!(events[0], "2023-11-15 06:13:20.123  1234  1240 I MyTag   : hello world\n");
+}
+
+#[test]
+fn test_LogcatReader_next_truncated() {
+    let tempdir = create_temp_dir();
+    let mut data: Vec<u8> = log_buffer_v4();
+    data.truncate(data.len() - 4);
+    let path: FPath = create_log_buffer(&tempdir, "logcat.bin", &data);
+    let mut logcatreader = LogcatReader::new(path_id_generator(), path, FT_LOGCAT, FO_0).unwrap();
+    let mut count: Count = 0;
+    loop {
+        match logcatreader.next(&None, &None) {
+            ResultNextPyDataEvent::Found(_event) => count += 1,
+            ResultNextPyDataEvent::ErrIgnore(_err) => {}
+            ResultNextPyDataEvent::Done => break,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+    assert_eq!(count, 3);
+    assert!(logcatreader.summary_complete().error.is_some());
+}
+
+#[test_case(None, None, 4; "no filters")]
+#[test_case(Some(ymdhmsm(&FO_0, 2023, 11, 14, 22, 13, 21, 0)), None, 3; "after")]
+#[test_case(None, Some(ymdhmsm(&FO_0, 2023, 11, 14, 22, 13, 20, 0)), 0; "before first entry")]
+fn test_LogcatReader_next_filters(
+    dt_filter_after: DateTimeLOpt,
+    dt_filter_before: DateTimeLOpt,
+    expect_accepted: Count,
+) {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_log_buffer(&tempdir, "logcat.bin", &log_buffer_v4());
+    let mut logcatreader = LogcatReader::new(path_id_generator(), path, FT_LOGCAT, FO_0).unwrap();
+    let mut count: Count = 0;
+    loop {
+        match logcatreader.next(&dt_filter_after, &dt_filter_before) {
+            ResultNextPyDataEvent::Found(_event) => count += 1,
+            ResultNextPyDataEvent::Done => break,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+    assert_eq!(count, expect_accepted);
+    let summary = logcatreader.summary();
+    assert_eq!(summary.pyeventreader_events_processed, 4);
+    assert_eq!(summary.pyeventreader_events_accepted, expect_accepted);
+}
@@ -0,0 +1,213 @@
+// src/tests/logindb_tests.rs
+
+//! tests for `logindb.rs`
+
+#![allow(non_snake_case)]
+
+use std::path::PathBuf;
+
+use ::rusqlite::Connection;
+use ::test_case::test_case;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileType,
+    FileTypeArchive,
+    FileTypeFixedStruct,
+};
+use crate::data::datetime::ymdhms;
+use crate::data::fixedstruct::{
+    linux_arm64aarch64,
+    linux_sqlite,
+    FixedStructType,
+    InfoAsBytes,
+    UtmpFields,
+    UtmpKind,
+    ENTRY_SZ_MAX,
+};
+use crate::debug::helpers::{
+    create_temp_dir,
+    TempDir,
+};
+use crate::readers::fixedstructreader::{
+    FixedStructReader,
+    ResultFindFixedStruct,
+    ResultFixedStructReaderNewError,
+};
+use crate::readers::helpers::path_to_fpath;
+use crate::readers::logindb::{
+    export_logindb,
+    WTMPDB_BOOT_TIME,
+    WTMPDB_USER_PROCESS,
+};
+use crate::tests::common::{
+    path_id_generator,
+    FO_0,
+};
+
+/// 2023-11-14 22:13:20 UTC
+const T0: i64 = 1700000000;
+
+/// create a `wtmp.db` in `tempdir` with one boot row, one user that logged
+/// out and one user that is still logged in
+fn create_wtmpdb(tempdir: &TempDir) -> FPath {
+    let path: PathBuf = tempdir.path().join("wtmp.db");
+    let conn = Connection::open(&path).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE wtmp(ID INTEGER PRIMARY KEY, Type INTEGER, User TEXT NOT NULL, \
+         Login INTEGER, Logout INTEGER, TTY TEXT, RemoteHost TEXT, Service TEXT);",
+    )
+    .unwrap();
+    let usec: i64 = T0 * 1_000_000;
+    conn.execute(
+        "INSERT INTO wtmp VALUES (1, ?1, 'reboot', ?2, ?3, '~', '6.8.0-generic', NULL)",
+        (WTMPDB_BOOT_TIME, usec, usec + 9_000_000_000),
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO wtmp VALUES (2, ?1, 'alice', ?2, ?3, 'pts/0', '192.168.1.5', 'sshd')",
+        (WTMPDB_USER_PROCESS, usec + 100_123_456, usec + 700_000_000),
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO wtmp VALUES (3, ?1, 'bob', ?2, NULL, 'tty1', NULL, 'login')",
+        (WTMPDB_USER_PROCESS, usec + 200_000_000),
+    )
+    .unwrap();
+
+    path_to_fpath(&path)
+}
+
+/// create a `lastlog2.db` in `tempdir` with two users that have logged in
+/// and one user that never logged in
+fn create_lastlog2db(tempdir: &TempDir) -> FPath {
+    let path: PathBuf = tempdir.path().join("lastlog2.db");
+    let conn = Connection::open(&path).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE Lastlog2(Name TEXT PRIMARY KEY, Time INTEGER NOT NULL, \
+         TTY TEXT, RemoteHost TEXT, Service TEXT);",
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO Lastlog2 VALUES ('alice', ?1, 'pts/0', '192.168.1.5', 'sshd')",
+        (T0 + 100,),
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO Lastlog2 VALUES ('bob', ?1, 'tty1', '', 'login')",
+        (T0 + 200,),
+    )
+    .unwrap();
+    conn.execute("INSERT INTO Lastlog2 VALUES ('nobody', 0, NULL, NULL, NULL)", ())
+        .unwrap();
+
+    path_to_fpath(&path)
+}
+
+#[test_case(FileTypeFixedStruct::Wtmpdb, 5, linux_arm64aarch64::UTMPX_SZ)]
+#[test_case(FileTypeFixedStruct::Lastlog2, 2, linux_sqlite::LASTLOG2_SZ)]
+fn test_export_logindb(
+    filetype_fixedstruct: FileTypeFixedStruct,
+    expect_count: Count,
+    entry_sz: usize,
+) {
+    let tempdir = create_temp_dir();
+    let path: FPath = match filetype_fixedstruct {
+        FileTypeFixedStruct::Wtmpdb => create_wtmpdb(&tempdir),
+        _ => create_lastlog2db(&tempdir),
+    };
+    let (temp_path, count) = export_logindb(&path, &filetype_fixedstruct).unwrap();
+    assert_eq!(count, expect_count, "count");
+    let len = std::fs::metadata(&temp_path).unwrap().len();
+    assert_eq!(len, expect_count * entry_sz as u64, "file size");
+}
+
+#[test]
+fn test_export_logindb_not_a_database() {
+    let tempdir = create_temp_dir();
+    let path: PathBuf = tempdir.path().join("wtmp.db");
+    std::fs::write(&path, b"this is not a SQLite database").unwrap();
+    let path: FPath = path_to_fpath(&path);
+    assert!(export_logindb(&path, &FileTypeFixedStruct::Wtmpdb).is_err());
+}
+
+#[test]
+fn test_export_logindb_unexpected_type() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_wtmpdb(&tempdir);
+    assert!(export_logindb(&path, &FileTypeFixedStruct::Utmpx).is_err());
+}
+
+fn new_FixedStructReader(
+    path: &FPath,
+    fixedstruct_type: FileTypeFixedStruct,
+) -> FixedStructReader {
+    let filetype = FileType::FixedStruct {
+        archival_type: FileTypeArchive::Normal,
+        fixedstruct_type,
+    };
+    match FixedStructReader::new(path_id_generator(), path.clone(), filetype, 0x400, FO_0, None, None) {
+        ResultFixedStructReaderNewError::FileOk(val) => val,
+        result => panic!("FixedStructReader::new({:?}) failed: {:?}", path, result),
+    }
+}
+
+#[test]
+fn test_FixedStructReader_wtmpdb() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_wtmpdb(&tempdir);
+    let mut fsr = new_FixedStructReader(&path, FileTypeFixedStruct::Wtmpdb);
+    assert_eq!(fsr.path(), &path);
+    assert_eq!(fsr.fixedstruct_type(), FixedStructType::Fs_Linux_Arm64Aarch64_Utmpx);
+
+    // records are processed in time order
+    let expect: [(UtmpKind, &str, &str, i64); 5] = [
+        (UtmpKind::BootTime, "reboot", "~", 0),
+        (UtmpKind::UserProcess, "alice", "pts/0", 100),
+        (UtmpKind::UserProcess, "bob", "tty1", 200),
+        (UtmpKind::DeadProcess, "", "pts/0", 700),
+        (UtmpKind::Shutdown, "shutdown", "~", 9000),
+    ];
+    let mut buffer: [u8; ENTRY_SZ_MAX] = [0; ENTRY_SZ_MAX];
+    let mut fo = 0;
+    for (kind, user, line, offset) in expect.iter() {
+        let fs = match fsr.process_entry_at(fo, &mut buffer) {
+            ResultFindFixedStruct::Found((fo_next, fs)) => {
+                fo = fo_next;
+                fs
+            }
+            result => panic!("process_entry_at({}) returned {:?}", fo, result),
+        };
+        let fields: UtmpFields = fs.utmp_fields().unwrap();
+        assert_eq!(&fields.kind, kind);
+        assert_eq!(fields.user, *user);
+        assert_eq!(fields.line, *line);
+        let secs: i64 = T0 + offset;
+        assert_eq!(fs.dt().timestamp(), secs, "{:?} {:?}", kind, fs.dt());
+    }
+    assert!(matches!(fsr.process_entry_at(fo, &mut buffer), ResultFindFixedStruct::Done));
+}
+
+#[test]
+fn test_FixedStructReader_lastlog2() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_lastlog2db(&tempdir);
+    let mut fsr = new_FixedStructReader(&path, FileTypeFixedStruct::Lastlog2);
+    assert_eq!(fsr.path(), &path);
+    assert_eq!(fsr.fixedstruct_type(), FixedStructType::Fs_Linux_Sqlite_Lastlog2);
+
+    let mut buffer: [u8; ENTRY_SZ_MAX] = [0; ENTRY_SZ_MAX];
+    let fs = match fsr.process_entry_at(0, &mut buffer) {
+        ResultFindFixedStruct::Found((_fo, fs)) => fs,
+        result => panic!("process_entry_at(0) returned {:?}", result),
+    };
+    assert_eq!(fs.dt(), &ymdhms(&FO_0, 2023, 11, 14, 22, 15, 0));
+    let mut out: [u8; 1024] = [0; 1024];
+    let s: String = match fs.as_bytes(&mut out) {
+        InfoAsBytes::Ok(at, _, _) => String::from_utf8_lossy(&out[..at]).to_string(),
+        _ => panic!("as_bytes failed"),
+    };
+    assert!(s.contains("ll_name 'alice'"), "{:?}", s);
+    assert!(s.contains("ll_service 'sshd'"), "{:?}", s);
+}
@@ -10,26 +10,47 @@
 //! testing, in practice that often makes tests difficult or impossible to
 //! implement.
 
+pub mod acctsummary_tests;
+pub mod aslreader_tests;
+pub mod audit_tests;
 pub mod blockreader_tests;
+pub mod boottimes_tests;
+pub mod columnar_tests;
 pub mod common;
+pub mod containerlog_tests;
 pub mod datetime_tests;
+pub mod evt_tests;
+pub mod evtparser_tests;
 pub mod evtx_tests;
 pub mod evtxreader_tests;
+pub mod evtxrecovery_tests;
 pub mod filedecompressor_tests;
 pub mod filehandlemanager_tests;
 pub mod filepreprocessor_tests;
 pub mod fixedstruct_tests;
 pub mod fixedstructreader_tests;
 pub mod helpers_tests;
+pub mod jsonline_tests;
+pub mod keyvalue_tests;
 pub mod journal_tests;
 pub mod journalreader_tests;
+pub mod journalrecovery_tests;
 pub mod line_tests;
 pub mod linereader_tests;
+pub mod logcatreader_tests;
+pub mod logindb_tests;
+pub mod odlreader_tests;
+pub mod pcapreader_tests;
 pub mod printers_tests;
 pub mod pydataevent_tests;
 pub mod pyeventreader_tests;
 pub mod pyrunner_tests;
+pub mod strace_tests;
 pub mod sysline_tests;
 pub mod syslinereader_tests;
 pub mod syslogprocessor_tests;
+pub mod timestampline_tests;
+pub mod tracev3reader_tests;
+pub mod utmpsession_tests;
+pub mod utxlog_tests;
 pub mod venv_tests;
@@ -0,0 +1,266 @@
+// src/tests/odlreader_tests.rs
+
+//! tests for [`src/readers/odlreader.rs`]
+//!
+//! [`src/readers/odlreader.rs`]: crate::readers::odlreader
+
+#![allow(non_snake_case)]
+
+use std::io::Write;
+use std::path::PathBuf;
+
+use ::flate2::write::GzEncoder;
+use ::flate2::Compression;
+#[allow(unused_imports)]
+use ::si_trace_print::printers::{
+    defn,
+    defo,
+    defx,
+};
+use ::test_case::test_case;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileType,
+    FileTypeArchive,
+    OdlSubType,
+};
+use crate::data::datetime::{
+    DateTimeLOpt,
+    ymdhmsl,
+};
+use crate::debug::helpers::{
+    create_temp_dir,
+    TempDir,
+};
+use crate::readers::helpers::path_to_fpath;
+use crate::readers::odlreader::{
+    odl_base64_decode,
+    OdlReader,
+    KEYSTORE_NAME,
+    OBFUSCATION_MAP_NAME,
+    ODL_SIGNATURE,
+};
+use crate::readers::pyeventreader::ResultNextPyDataEvent;
+use crate::tests::common::{
+    path_id_generator,
+    FO_0,
+    NTF_LOG_EMPTY_FPATH,
+    ODL_1_EVENT_COUNT,
+    ODL_1_FILESZ,
+    ODL_1_FPATH,
+    ODL_1_GZ_FPATH,
+};
+
+const FT_ODL: FileType = FileType::Odl { archival_type: FileTypeArchive::Normal, odl_sub_type: OdlSubType::Odl };
+const FT_ODL_GZ: FileType = FileType::Odl { archival_type: FileTypeArchive::Gz, odl_sub_type: OdlSubType::Odl };
+const FT_AODL: FileType = FileType::Odl { archival_type: FileTypeArchive::Normal, odl_sub_type: OdlSubType::Aodl };
+const FT_ODLGZ: FileType = FileType::Odl { archival_type: FileTypeArchive::Normal, odl_sub_type: OdlSubType::Odlgz };
+
+/// first record of `ODL_1_FPATH` as printed by `odl_reader.py`
+const ODL_1_EVENT_0: &str = "1766319245875 TelemetryProxyConfigurationFile.cpp:\
+TelemetryProxyConfigurationFile::Initialize; JokeYakLog:\\RodOafGad\\PieFarAwe\\TaxZigPen\\\
+WarPewMuch\\PenFoxRag\\MuchGadSea\\WolfSeaJoy\\ForthRatWry\\QuillTheCan.txt\n";
+
+/// AES key bytes `0x00` to `0x1F` in the keystore JSON
+const KEYSTORE_JSON: &str = r#"[{"Key":"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=","Version":1}]"#;
+/// `"alice"` encrypted with the `KEYSTORE_JSON` key
+const ALICE_ENCRYPTED: &str = "nkdoWcwe6L24AoGUEsmczw";
+const OBFUSCATION_MAP: &str = "key ab\x0bcd end\tMapped\nother\tx\n";
+
+/// records of the ODL files created by `create_odl`, as printed by
+/// `odl_reader.py`
+const SYNTHETIC_EVENTS: [&str; 3] = [
+    "1700000000000 Main.cpp:Start; Path C:\\Users\\alice\\file.txt\n",
+    "1700000001000 Map.cpp:Lookup; Mapped more text\n",
+    "1700000002000 Empty.cpp:NoParams;\n",
+];
+
+#[test_case("AQID", Some(vec![1, 2, 3]))]
+#[test_case("AQI=", Some(vec![1, 2]))]
+#[test_case("AQ==", Some(vec![1]))]
+#[test_case("AQ==AQID", Some(vec![1]); "pad ends data")]
+#[test_case("A!Q?I D", Some(vec![1, 2, 3]); "non-alphabet discarded")]
+#[test_case("AQI", None; "incorrect padding")]
+#[test_case("AQIDB", None; "one more than multiple of four")]
+#[test_case("", Some(vec![]))]
+fn test_odl_base64_decode(
+    text: &str,
+    expect: Option<Vec<u8>>,
+) {
+    assert_eq!(odl_base64_decode(text), expect);
+}
+
+fn length_prefixed(data: &[u8]) -> Vec<u8> {
+    let mut out: Vec<u8> = (data.len() as u32).to_le_bytes().to_vec();
+    out.extend_from_slice(data);
+
+    out
+}
+
+/// create an ODL file named `name` in `tempdir` with the records printed
+/// as `SYNTHETIC_EVENTS`, along with a keystore file and an obfuscation map
+/// file
+fn create_odl(
+    tempdir: &TempDir,
+    name: &str,
+    odl_version: u32,
+    gzip: bool,
+) -> FPath {
+    let param0: String = format!("Path C:\\Users\\{}\\file.txt", ALICE_ENCRYPTED);
+    let records: [(u64, &str, &str, Vec<&str>); 3] = [
+        (1700000000000, "Main.cpp", "Start", vec![param0.as_str()]),
+        (1700000001000, "Map.cpp", "Lookup", vec!["key ab\x0bcd end", "more text"]),
+        (1700000002000, "Empty.cpp", "NoParams", vec![]),
+    ];
+    let mut body: Vec<u8> = Vec::new();
+    for (i, (timestamp, code_file, function, params)) in records.iter().enumerate() {
+        let mut data: Vec<u8> = length_prefixed(code_file.as_bytes());
+        data.extend_from_slice(&[0; 4]);
+        data.extend(length_prefixed(function.as_bytes()));
+        for param in params.iter() {
+            data.extend(length_prefixed(param.as_bytes()));
+        }
+        body.extend_from_slice(b"\xcc\xdd\xee\xff");
+        match odl_version {
+            2 => {
+                body.extend_from_slice(&[0; 4]);
+                body.extend_from_slice(&timestamp.to_le_bytes());
+                body.extend_from_slice(&[0; 28]);
+                body.extend_from_slice(&1u32.to_le_bytes());
+                body.extend_from_slice(&(data.len() as u32).to_le_bytes());
+                body.extend_from_slice(&[0; 4]);
+            }
+            _ => {
+                // the second record has context data, the others do not
+                let context: &[u8] = if i == 1 { b"ctx!" } else { &[0; 24] };
+                let context_len: u16 = if i == 1 { context.len() as u16 } else { 0 };
+                body.extend_from_slice(&context_len.to_le_bytes());
+                body.extend_from_slice(&[0; 2]);
+                body.extend_from_slice(&timestamp.to_le_bytes());
+                body.extend_from_slice(&[0; 8]);
+                body.extend_from_slice(&((data.len() + context.len()) as u32).to_le_bytes());
+                body.extend_from_slice(&[0; 4]);
+                body.extend_from_slice(context);
+            }
+        }
+        body.extend(data);
+    }
+    if gzip {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&body).unwrap();
+        body = encoder.finish().unwrap();
+    }
+    let mut odl: Vec<u8> = ODL_SIGNATURE.to_vec();
+    odl.extend_from_slice(&odl_version.to_le_bytes());
+    odl.resize(0x100, 0);
+    odl.extend(body);
+
+    let path: PathBuf = tempdir.path().join(name);
+    std::fs::write(&path, odl).unwrap();
+    std::fs::write(tempdir.path().join(KEYSTORE_NAME), KEYSTORE_JSON).unwrap();
+    std::fs::write(tempdir.path().join(OBFUSCATION_MAP_NAME), OBFUSCATION_MAP).unwrap();
+
+    path_to_fpath(&path)
+}
+
+#[test_case(ODL_1_FPATH.clone(), FT_ODL; "odl")]
+#[test_case(ODL_1_GZ_FPATH.clone(), FT_ODL_GZ; "odl_gz")]
+fn test_OdlReader_new(
+    path: FPath,
+    filetype: FileType,
+) {
+    let path_id = path_id_generator();
+    let odlreader = OdlReader::new(path_id, path, filetype, FO_0).unwrap();
+    defo!("odlreader: {:?}", odlreader);
+    assert_eq!(odlreader.path_id(), path_id);
+    assert_eq!(odlreader.filesz(), ODL_1_FILESZ);
+    assert_eq!(odlreader.filetype(), filetype);
+    assert_eq!(odlreader.odl_version(), 3);
+}
+
+#[test]
+fn test_OdlReader_new_not_odl() {
+    let path_id = path_id_generator();
+    let result = OdlReader::new(path_id, NTF_LOG_EMPTY_FPATH.clone(), FT_ODL, FO_0);
+    assert!(result.is_err(), "expected Err, got {:?}", result);
+}
+
+#[test_case(ODL_1_FPATH.clone(), FT_ODL; "odl")]
+#[test_case(ODL_1_GZ_FPATH.clone(), FT_ODL_GZ; "odl_gz")]
+fn test_OdlReader_next_event(
+    path: FPath,
+    filetype: FileType,
+) {
+    let mut odlreader = OdlReader::new(path_id_generator(), path, filetype, FO_0).unwrap();
+    let event = match odlreader.next(&None, &None) {
+        ResultNextPyDataEvent::Found(event) => event,
+        result => panic!("expected Found, got {:?}", result),
+    };
+    assert_eq!(String::from_utf8_lossy(event.as_bytes()), ODL_1_EVENT_0);
+    assert_eq!(event.dt(), &ymdhmsl(&FO_0, 2025, 12, 21, 12, 14, 5, 875));
+    assert_eq!(event.dt_beg_end(), &Some((0, 13)));
+}
+
+#[test_case(None, None, *ODL_1_EVENT_COUNT, *ODL_1_EVENT_COUNT; "no filters")]
+#[test_case(
+    Some(ymdhmsl(&FO_0, 2025, 12, 21, 12, 14, 5, 876)),
+    None,
+    *ODL_1_EVENT_COUNT,
+    8;
+    "after"
+)]
+#[test_case(
+    None,
+    Some(ymdhmsl(&FO_0, 2025, 12, 21, 12, 14, 5, 0)),
+    *ODL_1_EVENT_COUNT,
+    0;
+    "before first record"
+)]
+fn test_OdlReader_next_filters(
+    dt_filter_after: DateTimeLOpt,
+    dt_filter_before: DateTimeLOpt,
+    expect_processed: Count,
+    expect_accepted: Count,
+) {
+    let mut odlreader = OdlReader::new(path_id_generator(), ODL_1_FPATH.clone(), FT_ODL, FO_0).unwrap();
+    let mut count: Count = 0;
+    loop {
+        match odlreader.next(&dt_filter_after, &dt_filter_before) {
+            ResultNextPyDataEvent::Found(_event) => count += 1,
+            ResultNextPyDataEvent::Done => break,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+    assert_eq!(count, expect_accepted);
+    let summary = odlreader.summary();
+    assert_eq!(summary.pyeventreader_events_processed, expect_processed);
+    assert_eq!(summary.pyeventreader_events_accepted, expect_accepted);
+    assert!(summary.pyeventreader_python_arguments.is_empty());
+}
+
+#[test_case("Test-2.odl", 2, false, FT_ODL; "v2 odl")]
+#[test_case("Test-3.aodl", 3, false, FT_AODL; "v3 aodl")]
+#[test_case("Test-3.odlgz", 3, true, FT_ODLGZ; "v3 odlgz")]
+fn test_OdlReader_unobfuscate(
+    name: &str,
+    odl_version: u32,
+    gzip: bool,
+    filetype: FileType,
+) {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_odl(&tempdir, name, odl_version, gzip);
+    let mut odlreader = OdlReader::new(path_id_generator(), path, filetype, FO_0).unwrap();
+    assert_eq!(odlreader.odl_version(), odl_version);
+    assert!(odlreader.has_key());
+    let mut events: Vec<String> = Vec::new();
+    loop {
+        match odlreader.next(&None, &None) {
+            ResultNextPyDataEvent::Found(event) => events.push(String::from_utf8_lossy(event.as_bytes()).into_owned()),
+            ResultNextPyDataEvent::Done => break,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+    assert_eq!(events, SYNTHETIC_EVENTS);
+}
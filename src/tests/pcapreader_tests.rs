@@ -0,0 +1,447 @@
+// src/tests/pcapreader_tests.rs
+
+//! tests for [`src/readers/pcapreader.rs`]
+//!
+//! [`src/readers/pcapreader.rs`]: crate::readers::pcapreader
+
+#![allow(non_snake_case)]
+
+use std::net::Ipv4Addr;
+use std::path::PathBuf;
+
+#[allow(unused_imports)]
+use ::si_trace_print::printers::{
+    defn,
+    defo,
+    defx,
+};
+use ::test_case::test_case;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileType,
+    FileTypeArchive,
+};
+use crate::data::datetime::{
+    DateTimeL,
+    DateTimeLOpt,
+    ymdhms,
+    ymdhmsm,
+};
+use crate::debug::helpers::{
+    create_temp_dir,
+    TempDir,
+};
+use crate::readers::helpers::path_to_fpath;
+use crate::readers::pcapreader::{
+    syslog_frames,
+    syslog_message_datetime,
+    syslog_pri_end,
+    PcapReader,
+    PcapTime,
+    LINKTYPE_ETHERNET,
+    LINKTYPE_RAW,
+    PCAPNG_BLOCK_EPB,
+    PCAPNG_BLOCK_IDB,
+    PCAPNG_BLOCK_SHB,
+    PCAPNG_BYTE_ORDER_MAGIC,
+    PCAP_MAGIC_USEC,
+};
+use crate::readers::pyeventreader::ResultNextPyDataEvent;
+use crate::tests::common::{
+    path_id_generator,
+    FO_0,
+    FO_E8,
+    NTF_LOG_EMPTY_FPATH,
+};
+
+const FT_PCAP: FileType = FileType::Pcap { archival_type: FileTypeArchive::Normal };
+
+/// 2023-11-14 22:13:20 UTC
+const SEC: u32 = 1_700_000_000;
+
+const SENDER: Ipv4Addr = Ipv4Addr::new(192, 0, 2, 10);
+const RECEIVER: Ipv4Addr = Ipv4Addr::new(192, 0, 2, 1);
+
+const MSG_3164: &[u8] = b"<34>Nov 14 22:13:00 host su: 'su root' failed";
+const MSG_5424: &[u8] = b"<165>1 2023-11-14T22:12:00.5Z host app 42 ID47 - an event";
+
+#[test_case(b"<34>Oct", Some(4); "pri 2 digits")]
+#[test_case(b"<0>", Some(3); "pri 1 digit")]
+#[test_case(b"<191>1", Some(5); "pri 3 digits")]
+#[test_case(b"<1914>", None; "pri 4 digits")]
+#[test_case(b"<>", None; "pri empty")]
+#[test_case(b"<34 ", None; "pri unclosed")]
+#[test_case(b"34>", None; "no pri")]
+#[test_case(b"", None; "empty")]
+fn test_syslog_pri_end(
+    data: &[u8],
+    expect: Option<usize>,
+) {
+    assert_eq!(syslog_pri_end(data), expect);
+}
+
+#[test_case(b"5 <1>ab3 <2>", vec![b"<1>ab".as_slice(), b"<2>"], 12; "octet counted")]
+#[test_case(b"5 <1>ab3 <2", vec![b"<1>ab".as_slice()], 7; "octet counted partial")]
+#[test_case(b"<1>a\n<2>b\n<3>c", vec![b"<1>a".as_slice(), b"<2>b"], 10; "newline framed partial")]
+#[test_case(b"<1>a\0\r\n<2>b\n", vec![b"<1>a".as_slice(), b"<2>b"], 12; "nul framed")]
+#[test_case(b"4 <1>a<2>b\n", vec![b"<1>a".as_slice(), b"<2>b"], 11; "mixed")]
+#[test_case(b"\n\n", vec![], 2; "newlines")]
+#[test_case(b"", vec![], 0; "empty")]
+fn test_syslog_frames(
+    data: &[u8],
+    expect_frames: Vec<&[u8]>,
+    expect_split: usize,
+) {
+    let (frames, split) = syslog_frames(data);
+    assert_eq!(frames, expect_frames);
+    assert_eq!(split, expect_split);
+}
+
+#[test_case(MSG_5424, Some((7, 29, ymdhmsm(&FO_0, 2023, 11, 14, 22, 12, 0, 500_000))); "RFC 5424")]
+#[test_case(MSG_3164, Some((4, 19, ymdhms(&FO_E8, 2023, 11, 14, 22, 13, 0))); "RFC 3164")]
+#[test_case(b"<34>Dec 31 23:59:59 host a", Some((4, 19, ymdhms(&FO_E8, 2022, 12, 31, 23, 59, 59))); "RFC 3164 last year")]
+#[test_case(b"<34>Nov  2 01:02:03 host a", Some((4, 19, ymdhms(&FO_E8, 2023, 11, 2, 1, 2, 3))); "RFC 3164 space padded")]
+#[test_case(b"<165>1 - host app - - - no time", None; "RFC 5424 nil")]
+#[test_case(b"<34>host: no time", None; "no time")]
+#[test_case(b"Nov 14 22:13:00 host no pri", None; "no pri")]
+fn test_syslog_message_datetime(
+    text: &[u8],
+    expect: Option<(usize, usize, DateTimeL)>,
+) {
+    let dt_capture: DateTimeL = ymdhms(&FO_0, 2023, 11, 14, 22, 13, 20);
+    let text: &str = std::str::from_utf8(text).unwrap();
+    assert_eq!(syslog_message_datetime(text, &dt_capture, &FO_E8), expect);
+}
+
+/// the 16 bit one's complement sum is not checked so it is zero
+fn ipv4(
+    protocol: u8,
+    transport: &[u8],
+) -> Vec<u8> {
+    let total: u16 = 20 + transport.len() as u16;
+    let mut out: Vec<u8> = vec![0x45, 0];
+    out.extend_from_slice(&total.to_be_bytes());
+    out.extend_from_slice(&[0, 0, 0x40, 0, 64, protocol, 0, 0]);
+    out.extend_from_slice(&SENDER.octets());
+    out.extend_from_slice(&RECEIVER.octets());
+    out.extend_from_slice(transport);
+
+    out
+}
+
+fn udp(
+    dst_port: u16,
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut out: Vec<u8> = Vec::new();
+    out.extend_from_slice(&40000u16.to_be_bytes());
+    out.extend_from_slice(&dst_port.to_be_bytes());
+    out.extend_from_slice(&(8 + payload.len() as u16).to_be_bytes());
+    out.extend_from_slice(&[0, 0]);
+    out.extend_from_slice(payload);
+
+    ipv4(17, &out)
+}
+
+fn tcp(
+    seq: u32,
+    flags: u8,
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut out: Vec<u8> = Vec::new();
+    out.extend_from_slice(&40001u16.to_be_bytes());
+    out.extend_from_slice(&514u16.to_be_bytes());
+    out.extend_from_slice(&seq.to_be_bytes());
+    out.extend_from_slice(&0u32.to_be_bytes());
+    out.extend_from_slice(&[0x50, flags, 0xFF, 0xFF, 0, 0, 0, 0]);
+    out.extend_from_slice(payload);
+
+    ipv4(6, &out)
+}
+
+fn ethernet(ip: &[u8]) -> Vec<u8> {
+    let mut out: Vec<u8> = vec![2, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 2, 0x08, 0x00];
+    out.extend_from_slice(ip);
+    // minimum frame padding
+    out.extend_from_slice(&[0; 4]);
+
+    out
+}
+
+/// a pcap file of `frames` of `linktype`, each frame is the seconds after
+/// `SEC`, the microseconds, and the frame
+fn pcap(
+    linktype: u32,
+    frames: &[(u32, u32, Vec<u8>)],
+) -> Vec<u8> {
+    let mut out: Vec<u8> = Vec::new();
+    out.extend_from_slice(&PCAP_MAGIC_USEC.to_le_bytes());
+    out.extend_from_slice(&2u16.to_le_bytes());
+    out.extend_from_slice(&4u16.to_le_bytes());
+    out.extend_from_slice(&[0; 8]);
+    out.extend_from_slice(&65535u32.to_le_bytes());
+    out.extend_from_slice(&linktype.to_le_bytes());
+    for (sec, usec, frame) in frames.iter() {
+        out.extend_from_slice(&(SEC + sec).to_le_bytes());
+        out.extend_from_slice(&usec.to_le_bytes());
+        out.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        out.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        out.extend_from_slice(frame);
+    }
+
+    out
+}
+
+/// a big-endian pcapng block of `block_type` with `body`
+fn pcapng_block(
+    block_type: u32,
+    body: &[u8],
+) -> Vec<u8> {
+    let mut body: Vec<u8> = body.to_vec();
+    body.resize(body.len().next_multiple_of(4), 0);
+    let total: u32 = 12 + body.len() as u32;
+    let mut out: Vec<u8> = Vec::new();
+    out.extend_from_slice(&block_type.to_be_bytes());
+    out.extend_from_slice(&total.to_be_bytes());
+    out.extend_from_slice(&body);
+    out.extend_from_slice(&total.to_be_bytes());
+
+    out
+}
+
+/// a big-endian pcapng file of `frames` of an Ethernet interface with
+/// nanosecond timestamps
+fn pcapng(frames: &[(u32, u32, Vec<u8>)]) -> Vec<u8> {
+    let mut shb: Vec<u8> = Vec::new();
+    shb.extend_from_slice(&PCAPNG_BYTE_ORDER_MAGIC.to_be_bytes());
+    shb.extend_from_slice(&[0, 1, 0, 0]);
+    shb.extend_from_slice(&(-1i64).to_be_bytes());
+    let mut idb: Vec<u8> = Vec::new();
+    idb.extend_from_slice(&(LINKTYPE_ETHERNET as u16).to_be_bytes());
+    idb.extend_from_slice(&[0, 0, 0, 0, 0xFF, 0xFF]);
+    // option `if_tsresol` of 10^-9
+    idb.extend_from_slice(&[0, 9, 0, 1, 9, 0, 0, 0]);
+    idb.extend_from_slice(&[0, 0, 0, 0]);
+    let mut out: Vec<u8> = pcapng_block(PCAPNG_BLOCK_SHB, &shb);
+    out.extend(pcapng_block(PCAPNG_BLOCK_IDB, &idb));
+    for (sec, nsec, frame) in frames.iter() {
+        let ts: u64 = u64::from(SEC + sec) * 1_000_000_000 + u64::from(*nsec);
+        let mut epb: Vec<u8> = Vec::new();
+        epb.extend_from_slice(&0u32.to_be_bytes());
+        epb.extend_from_slice(&((ts >> 32) as u32).to_be_bytes());
+        epb.extend_from_slice(&(ts as u32).to_be_bytes());
+        epb.extend_from_slice(&(frame.len() as u32).to_be_bytes());
+        epb.extend_from_slice(&(frame.len() as u32).to_be_bytes());
+        epb.extend_from_slice(frame);
+        out.extend(pcapng_block(PCAPNG_BLOCK_EPB, &epb));
+    }
+
+    out
+}
+
+/// UDP datagrams; a DNS query to port 53 is not syslog
+fn pcap_udp() -> Vec<u8> {
+    pcap(
+        LINKTYPE_ETHERNET,
+        &[
+            (0, 250_000, ethernet(&udp(514, MSG_3164))),
+            (1, 0, ethernet(&udp(53, b"\x12\x34\x01\x00"))),
+            (2, 0, ethernet(&udp(5140, b"<13>Nov 14 22:13:22 host b\n"))),
+        ],
+    )
+}
+
+const PCAP_UDP_EVENTS: [&str; 2] = [
+    "2023-11-14 22:13:20.250000 192.0.2.10 <34>Nov 14 22:13:00 host su: 'su root' failed\n",
+    "2023-11-14 22:13:22.000000 192.0.2.10 <13>Nov 14 22:13:22 host b\n",
+];
+
+/// a TCP stream of octet-counted messages split across segments, a
+/// retransmitted segment, and an unframed message at the end of the stream
+fn pcap_tcp() -> Vec<u8> {
+    let msg: Vec<u8> = [format!("{} ", MSG_5424.len()).as_bytes(), MSG_5424].concat();
+    let (msg_a, msg_b) = msg.split_at(10);
+    let seq: u32 = 1000;
+    let seq_b: u32 = seq + 1 + msg_a.len() as u32;
+    let seq_c: u32 = seq_b + msg_b.len() as u32;
+    pcap(
+        LINKTYPE_RAW,
+        &[
+            (0, 0, tcp(seq, 0x02, b"")),
+            (1, 0, tcp(seq + 1, 0x18, msg_a)),
+            (2, 0, tcp(seq_b, 0x18, msg_b)),
+            (3, 0, tcp(seq_b, 0x18, msg_b)),
+            (4, 0, tcp(seq_c, 0x19, b"<14>1 - host app - - - bye")),
+        ],
+    )
+}
+
+const PCAP_TCP_EVENTS: [&str; 2] = [
+    "2023-11-14 22:13:21.000000 192.0.2.10 <165>1 2023-11-14T22:12:00.5Z host app 42 ID47 - an event\n",
+    "2023-11-14 22:13:24.000000 192.0.2.10 <14>1 - host app - - - bye\n",
+];
+
+/// write `data` to file `name` in `tempdir`
+fn create_capture(
+    tempdir: &TempDir,
+    name: &str,
+    data: &[u8],
+) -> FPath {
+    let path: PathBuf = tempdir.path().join(name);
+    std::fs::write(&path, data).unwrap();
+
+    path_to_fpath(&path)
+}
+
+/// read all events of `pcapreader`
+fn read_all(pcapreader: &mut PcapReader) -> Vec<String> {
+    let mut events: Vec<String> = Vec::new();
+    loop {
+        match pcapreader.next(&None, &None) {
+            ResultNextPyDataEvent::Found(event) => events.push(String::from_utf8_lossy(event.as_bytes()).into_owned()),
+            ResultNextPyDataEvent::Done => break,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    events
+}
+
+#[test]
+fn test_PcapReader_new() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_capture(&tempdir, "syslog.pcap", &pcap_udp());
+    let path_id = path_id_generator();
+    let pcapreader = PcapReader::new(path_id, path, FT_PCAP, FO_0, PcapTime::Capture).unwrap();
+    defo!("pcapreader: {:?}", pcapreader);
+    assert_eq!(pcapreader.path_id(), path_id);
+    assert_eq!(pcapreader.filetype(), FT_PCAP);
+    assert_eq!(pcapreader.filesz(), pcap_udp().len() as u64);
+}
+
+#[test]
+fn test_PcapReader_new_empty() {
+    let result = PcapReader::new(path_id_generator(), NTF_LOG_EMPTY_FPATH.clone(), FT_PCAP, FO_0, PcapTime::Capture);
+    assert!(result.is_err(), "expected Err, got {:?}", result);
+}
+
+#[test]
+fn test_PcapReader_new_bad_magic() {
+    let tempdir = create_temp_dir();
+    let mut data: Vec<u8> = pcap_udp();
+    data[0] = 0;
+    let path: FPath = create_capture(&tempdir, "syslog.pcap", &data);
+    let result = PcapReader::new(path_id_generator(), path, FT_PCAP, FO_0, PcapTime::Capture);
+    assert!(result.is_err(), "expected Err, got {:?}", result);
+}
+
+#[test]
+fn test_PcapReader_next_udp() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_capture(&tempdir, "syslog.pcap", &pcap_udp());
+    let mut pcapreader = PcapReader::new(path_id_generator(), path, FT_PCAP, FO_0, PcapTime::Capture).unwrap();
+    let event = match pcapreader.next(&None, &None) {
+        ResultNextPyDataEvent::Found(event) => event,
+        result => panic!("expected Found, got {:?}", result),
+    };
+    assert_eq!(event.dt(), &ymdhmsm(&FO_0, 2023, 11, 14, 22, 13, 20, 250_000));
+    assert_eq!(event.dt_beg_end(), &Some((0, 26)));
+    let mut events: Vec<String> = vec![String::from_utf8_lossy(event.as_bytes()).into_owned()];
+    events.extend(read_all(&mut pcapreader));
+    assert_eq!(events, PCAP_UDP_EVENTS);
+    assert_eq!(pcapreader.packets(), 3);
+}
+
+#[test]
+fn test_PcapReader_next_tcp() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_capture(&tempdir, "syslog.pcap", &pcap_tcp());
+    let mut pcapreader = PcapReader::new(path_id_generator(), path, FT_PCAP, FO_0, PcapTime::Capture).unwrap();
+    assert_eq!(read_all(&mut pcapreader), PCAP_TCP_EVENTS);
+}
+
+#[test]
+fn test_PcapReader_next_message_time() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_capture(&tempdir, "syslog.pcap", &pcap_tcp());
+    let mut pcapreader = PcapReader::new(path_id_generator(), path, FT_PCAP, FO_0, PcapTime::Message).unwrap();
+    let event = match pcapreader.next(&None, &None) {
+        ResultNextPyDataEvent::Found(event) => event,
+        result => panic!("expected Found, got {:?}", result),
+    };
+    let text: String = String::from_utf8_lossy(event.as_bytes()).into_owned();
+    assert_eq!(text, "192.0.2.10 <165>1 2023-11-14T22:12:00.5Z host app 42 ID47 - an event\n");
+    assert_eq!(event.dt(), &ymdhmsm(&FO_0, 2023, 11, 14, 22, 12, 0, 500_000));
+    let (beg, end) = event.dt_beg_end().unwrap();
+    assert_eq!(&text[beg..end], "2023-11-14T22:12:00.5Z");
+    // the message without a timestamp has the capture timestamp
+    assert_eq!(read_all(&mut pcapreader), [PCAP_TCP_EVENTS[1]]);
+}
+
+#[test]
+fn test_PcapReader_next_pcapng() {
+    let tempdir = create_temp_dir();
+    let data: Vec<u8> = pcapng(&[
+        (0, 123_456_789, ethernet(&udp(514, MSG_3164))),
+        (1, 0, ethernet(&udp(514, b"<13>Nov 14 22:13:21 host b"))),
+    ]);
+    let path: FPath = create_capture(&tempdir, "syslog.pcapng", &data);
+    let mut pcapreader = PcapReader::new(path_id_generator(), path, FT_PCAP, FO_E8, PcapTime::Capture).unwrap();
+    let event = match pcapreader.next(&None, &None) {
+        ResultNextPyDataEvent::Found(event) => event,
+        result => panic!("expected Found, got {:?}", result),
+    };
+    assert_eq!(event.dt().timestamp_nanos_opt(), Some(i64::from(SEC) * 1_000_000_000 + 123_456_789));
+    assert_eq!(
+        String::from_utf8_lossy(event.as_bytes()),
+        "2023-11-15 06:13:20.123456 192.0.2.10 <34>Nov 14 22:13:00 host su: 'su root' failed\n",
+    );
+    assert_eq!(read_all(&mut pcapreader).len(), 1);
+}
+
+#[test]
+fn test_PcapReader_next_truncated() {
+    let tempdir = create_temp_dir();
+    let mut data: Vec<u8> = pcap_udp();
+    data.truncate(data.len() - 4);
+    let path: FPath = create_capture(&tempdir, "syslog.pcap", &data);
+    let mut pcapreader = PcapReader::new(path_id_generator(), path, FT_PCAP, FO_0, PcapTime::Capture).unwrap();
+    let mut count: Count = 0;
+    loop {
+        match pcapreader.next(&None, &None) {
+            ResultNextPyDataEvent::Found(_event) => count += 1,
+            ResultNextPyDataEvent::ErrIgnore(_err) => {}
+            ResultNextPyDataEvent::Done => break,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+    assert_eq!(count, 1);
+    assert!(pcapreader.summary_complete().error.is_some());
+}
+
+#[test_case(None, None, 2; "no filters")]
+#[test_case(Some(ymdhms(&FO_0, 2023, 11, 14, 22, 13, 21)), None, 1; "after")]
+#[test_case(None, Some(ymdhms(&FO_0, 2023, 11, 14, 22, 13, 20)), 0; "before first message")]
+fn test_PcapReader_next_filters(
+    dt_filter_after: DateTimeLOpt,
+    dt_filter_before: DateTimeLOpt,
+    expect_accepted: Count,
+) {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_capture(&tempdir, "syslog.pcap", &pcap_udp());
+    let mut pcapreader = PcapReader::new(path_id_generator(), path, FT_PCAP, FO_0, PcapTime::Capture).unwrap();
+    let mut count: Count = 0;
+    loop {
+        match pcapreader.next(&dt_filter_after, &dt_filter_before) {
+            ResultNextPyDataEvent::Found(_event) => count += 1,
+            ResultNextPyDataEvent::Done => break,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+    assert_eq!(count, expect_accepted);
+    let summary = pcapreader.summary();
+    assert_eq!(summary.pyeventreader_events_processed, 2);
+    assert_eq!(summary.pyeventreader_events_accepted, expect_accepted);
+}
@@ -36,6 +36,10 @@ use crate::data::datetime::{
     Utc,
     regex_id_compiled,
 };
+use crate::data::evtx::{
+    EvtxFilter,
+    EvtxOutput,
+};
 use crate::data::fixedstruct::ENTRY_SZ_MAX;
 use crate::debug::helpers::{
     create_temp_file,
@@ -568,7 +572,7 @@ const FILEU: &str = "foo.utmp";
 #[test_case(&*NTF_LINUX_X86_UTMPX_2ENTRY_FPATH, 2, CCA, CLR, Some(FILEU), Some(DATE), None, 424, 17; "g")]
 #[test_case(&*NTF_LINUX_X86_UTMPX_2ENTRY_FPATH, 2, CCU, CLR, Some(FILEU), Some(DATE), Some(FO_P8), 424, 17; "h")]
 #[test_case(&*NTF_LINUX_X86_UTMPX_2ENTRY_FPATH, 2, CCN, CLR, None, Some(DATE), Some(FO_P8), 408, 2; "i")]
-#[test_case(&*NTF_LINUX_X86_LASTLOG_1ENTRY_FPATH, 1, CCN, CLR, None, Some(DATE), Some(FO_P8), 71, 1; "j")]
+#[test_case(&*NTF_LINUX_X86_LASTLOG_1ENTRY_FPATH, 1, CCN, CLR, None, Some(DATE), Some(FO_P8), 77, 1; "j")]
 fn test_PrinterLogMessage_print_fixedstruct(
     path: &FPath,
     print_count_expect: usize,
@@ -677,13 +681,15 @@ fn test_PrinterLogMessage_print_evtx(
     let mut er = EvtxReader::new(
         path_id_generator(),
         EVTX_KPNP_FPATH.clone(),
+        EvtxOutput::Xml,
+        false,
         FT_EVTX_NORM,
         FO_P8,
     ).unwrap();
     let mut prints: usize = 0;
     let mut printed_bytes: usize = 0;
     let mut printed_flushed: usize = 0;
-    er.analyze(&None, &None);
+    er.analyze(&None, &None, &EvtxFilter::default());
     while let Some(evtx) = er.next() {
         match plm.print_evtx(&evtx) {
             Ok((bytes_, flushed_)) => {
@@ -0,0 +1,305 @@
+// src/tests/strace_tests.rs
+
+//! tests for `strace.rs`
+
+#![allow(non_snake_case)]
+
+use ::test_case::test_case;
+
+use crate::common::PathId;
+use crate::data::datetime::{
+    ymdhmsm,
+    DateTimeL,
+};
+use crate::data::strace::{
+    strace_duration,
+    strace_kind,
+    strace_path_pid,
+    strace_prefix,
+    strace_return,
+    StraceCall,
+    StraceFilter,
+    StraceGrouper,
+    StraceKind,
+};
+use crate::tests::common::{
+    new_syslinep,
+    FO_0,
+};
+
+const READ_UNFINISHED: &str = "[pid  1234] 1716854961.676098 read(3,  <unfinished ...>";
+const CLOSE: &str = "[pid  1235] 1716854961.676100 close(4) = 0";
+const READ_RESUMED: &str = "[pid  1234] 1716854961.676205 <... read resumed>\"abc\", 4096) = 3";
+const ACCESS: &str = "1716854961.676098 access(\"/etc/ld.so.preload\", R_OK) = -1 ENOENT (No such file or directory)";
+const EXITED: &str = "[pid  1234] 1716854961.676300 +++ exited with 0 +++";
+
+/// datetime 2024-05-28 00:09:21 plus `micro` microseconds, the datetime of
+/// epoch `1716854961`
+fn dt(micro: i64) -> DateTimeL {
+    ymdhmsm(&FO_0, 2024, 5, 28, 0, 9, 21, micro)
+}
+
+#[test_case(READ_UNFINISHED, (Some(1234), 30); "pid brackets")]
+#[test_case("1234  1716854961.676098 close(3) = 0", (Some(1234), 24); "pid -o")]
+#[test_case(ACCESS, (None, 18); "no pid")]
+#[test_case("1716854990 brk(NULL) = 0x560d2f251000", (None, 11); "seconds")]
+#[test_case("1716856320.776810 [  12] [00007ff7aa737a8b] brk(NULL) = 0x564250168000", (None, 44); "-n -i")]
+#[test_case(" > /usr/lib/libc.so.6(__read+0x11) [0x10e1f1]", (None, 0); "backtrace")]
+fn test_strace_prefix(
+    line: &str,
+    expect: (Option<u32>, usize),
+) {
+    assert_eq!(strace_prefix(line), expect);
+}
+
+#[test_case("close(3) = 0", (StraceKind::Call, Some("close")); "call")]
+#[test_case("read(3,  <unfinished ...>", (StraceKind::Unfinished, Some("read")); "unfinished")]
+#[test_case("<... read resumed>\"abc\", 4096) = 3", (StraceKind::Resumed, Some("read")); "resumed")]
+#[test_case("--- SIGCHLD {si_signo=SIGCHLD} ---", (StraceKind::Signal, None); "signal")]
+#[test_case("+++ killed by SIGKILL +++", (StraceKind::Exit, None); "exit")]
+#[test_case("hello (world)", (StraceKind::Other, None); "other")]
+fn test_strace_kind(
+    call: &str,
+    expect: (StraceKind, Option<&str>),
+) {
+    assert_eq!(strace_kind(call), expect);
+}
+
+#[test_case("close(3) = 0", Some(("0", None)); "ok")]
+#[test_case("access(\"/x\", R_OK) = -1 ENOENT (No such file or directory)", Some(("-1", Some("ENOENT"))); "errno")]
+#[test_case("read(3, ) = ? ERESTARTSYS (To be restarted if SA_RESTART is set)", Some(("?", Some("ERESTARTSYS"))); "restart")]
+#[test_case("poll([{fd=3}], 1, 0) = 1 ([{fd=3, revents=POLLIN}])", Some(("1", None)); "detail")]
+#[test_case("exit_group(0) = ?", Some(("?", None)); "no return")]
+#[test_case("read(3,  <unfinished ...>", None; "unfinished")]
+fn test_strace_return(
+    call: &str,
+    expect: Option<(&str, Option<&str>)>,
+) {
+    assert_eq!(strace_return(call), expect);
+}
+
+#[test_case("close(3) = 0 <0.000012>", Some("0.000012"))]
+#[test_case("close(3) = 0", None)]
+#[test_case("close(3) = 0 <unfinished>", None)]
+fn test_strace_duration(
+    call: &str,
+    expect: Option<&str>,
+) {
+    assert_eq!(strace_duration(call), expect);
+}
+
+#[test_case("/tmp/trace.1234", Some(1234))]
+#[test_case("trace.1234", Some(1234))]
+#[test_case("/tmp/trace.out", None)]
+#[test_case("/tmp/1234", None)]
+fn test_strace_path_pid(
+    path: &str,
+    expect: Option<u32>,
+) {
+    assert_eq!(strace_path_pid(path), expect);
+}
+
+#[test]
+fn test_StraceCall_new_pid() {
+    let call = StraceCall::new(dt(676098), ACCESS, Some(42));
+    assert_eq!(call.pid, Some(42));
+    assert_eq!(call.lines, vec![format!("[pid 42] {}", ACCESS)]);
+    assert_eq!(call.syscall.as_deref(), Some("access"));
+    assert_eq!(call.retval(), Some("-1"));
+    assert_eq!(call.errno(), Some("ENOENT"));
+    // a PID prefix is not replaced
+    let call = StraceCall::new(dt(676100), CLOSE, Some(42));
+    assert_eq!(call.pid, Some(1235));
+    assert_eq!(call.call(), "close(4) = 0");
+    // a line that is not strace output is not prefixed
+    let call = StraceCall::new(dt(0), "hello", Some(42));
+    assert_eq!(call.pid, None);
+    assert_eq!(call.kind, StraceKind::Other);
+    assert_eq!(call.lines, vec!["hello"]);
+}
+
+#[test]
+fn test_StraceGrouper_process_text() {
+    let pathid1: PathId = 1;
+    let pathid2: PathId = 2;
+    let mut grouper = StraceGrouper::new();
+    // the unfinished half is kept
+    assert!(grouper.process_text(pathid1, &dt(676098), READ_UNFINISHED).is_empty());
+    // a call after an unfinished call is not held
+    let calls: Vec<StraceCall> = grouper.process_text(pathid1, &dt(676100), CLOSE);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].lines, vec![CLOSE]);
+    let calls: Vec<StraceCall> = grouper.process_text(pathid2, &dt(676098), ACCESS);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].lines, vec![ACCESS]);
+    assert_eq!(grouper.count_open(), 1);
+    // the resumed half is joined to the unfinished half
+    let calls: Vec<StraceCall> = grouper.process_text(pathid1, &dt(676205), READ_RESUMED);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].dt(), &dt(676205));
+    assert_eq!(calls[0].kind, StraceKind::Call);
+    assert_eq!(calls[0].syscall.as_deref(), Some("read"));
+    assert_eq!(
+        calls[0].lines,
+        vec!["[pid  1234] 1716854961.676098 read(3, \"abc\", 4096) = 3 <0.000107>"]
+    );
+    assert_eq!(calls[0].call(), "read(3, \"abc\", 4096) = 3 <0.000107>");
+    assert_eq!(calls[0].retval(), Some("3"));
+    assert_eq!(calls[0].duration(), Some("0.000107"));
+    assert_eq!(grouper.count_open(), 0);
+}
+
+#[test]
+fn test_StraceGrouper_process_duration() {
+    let pathid: PathId = 1;
+    let mut grouper = StraceGrouper::new();
+    assert!(grouper.process_text(pathid, &dt(676098), READ_UNFINISHED).is_empty());
+    // the duration of option `-T` is kept
+    let resumed: String = format!("{} <0.000110>", READ_RESUMED);
+    let calls: Vec<StraceCall> = grouper.process_text(pathid, &dt(676205), &resumed);
+    assert_eq!(
+        calls[0].lines,
+        vec!["[pid  1234] 1716854961.676098 read(3, \"abc\", 4096) = 3 <0.000110>"]
+    );
+}
+
+#[test]
+fn test_StraceGrouper_process_exit() {
+    let pathid: PathId = 1;
+    let mut grouper = StraceGrouper::new();
+    assert!(grouper.process_text(pathid, &dt(676098), READ_UNFINISHED).is_empty());
+    // an unfinished call of an exited process is returned before the exit
+    let calls: Vec<StraceCall> = grouper.process_text(pathid, &dt(676300), EXITED);
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[0].kind, StraceKind::Unfinished);
+    assert_eq!(calls[0].lines, vec![READ_UNFINISHED]);
+    assert_eq!(calls[1].kind, StraceKind::Exit);
+    assert_eq!(grouper.count_open(), 0);
+    // a resumed half without an unfinished half is not joined
+    let calls: Vec<StraceCall> = grouper.process_text(pathid, &dt(676205), READ_RESUMED);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].kind, StraceKind::Resumed);
+    assert_eq!(calls[0].lines, vec![READ_RESUMED]);
+}
+
+#[test]
+fn test_StraceGrouper_process_abandoned() {
+    let pathid: PathId = 1;
+    let mut grouper = StraceGrouper::new();
+    assert!(grouper.process_text(pathid, &dt(676098), READ_UNFINISHED).is_empty());
+    // a second unfinished call of the same PID abandons the first
+    let write_unfinished: &str = "[pid  1234] 1716854961.676150 write(1, \"x\", 1 <unfinished ...>";
+    let calls: Vec<StraceCall> = grouper.process_text(pathid, &dt(676150), write_unfinished);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].lines, vec![READ_UNFINISHED]);
+    assert_eq!(grouper.count_open(), 1);
+}
+
+#[test]
+fn test_StraceGrouper_process_other_syscall() {
+    let pathid: PathId = 1;
+    let mut grouper = StraceGrouper::new();
+    assert!(grouper.process_text(pathid, &dt(676098), READ_UNFINISHED).is_empty());
+    // a resumed half of another system call is not joined
+    let write_resumed: &str = "[pid  1234] 1716854961.676200 <... write resumed>) = 1";
+    let calls: Vec<StraceCall> = grouper.process_text(pathid, &dt(676200), write_resumed);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].lines, vec![write_resumed]);
+    assert_eq!(grouper.count_open(), 1);
+    let calls: Vec<StraceCall> = grouper.process_text(pathid, &dt(676205), READ_RESUMED);
+    assert_eq!(calls[0].duration(), Some("0.000107"));
+}
+
+#[test]
+fn test_StraceGrouper_process_many() {
+    let pathid: PathId = 1;
+    let mut grouper = StraceGrouper::new();
+    assert!(grouper.process_text(pathid, &dt(676098), READ_UNFINISHED).is_empty());
+    // calls are never held while a call is unfinished
+    for micro in 676100..676200 {
+        let calls: Vec<StraceCall> = grouper.process_text(pathid, &dt(micro), CLOSE);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].dt(), &dt(micro));
+    }
+    assert_eq!(grouper.count_open(), 1);
+}
+
+#[test]
+fn test_StraceGrouper_process_path_pid() {
+    let pathid: PathId = 1;
+    let mut grouper = StraceGrouper::new();
+    grouper.set_path_pid(pathid, strace_path_pid("/tmp/trace.1234"));
+    let data: String = format!("{}\n", "1716854961.676098 close(3) = 0");
+    let calls: Vec<StraceCall> = grouper.process(pathid, &new_syslinep(&data, dt(676098)));
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].pid, Some(1234));
+    assert_eq!(calls[0].to_string(), "[pid 1234] 1716854961.676098 close(3) = 0\n");
+}
+
+#[test]
+fn test_StraceGrouper_process_join_lines() {
+    let pathid: PathId = 1;
+    let mut grouper = StraceGrouper::new();
+    let unfinished: String = format!("{}\n > /usr/lib/libc.so.6(__read+0x11) [0x10e1f1]\n", READ_UNFINISHED);
+    assert!(grouper.process(pathid, &new_syslinep(&unfinished, dt(676098))).is_empty());
+    let resumed: String = format!("{}\n > /usr/lib/libc.so.6(__read+0x22) [0x10e202]\n", READ_RESUMED);
+    let calls: Vec<StraceCall> = grouper.process(pathid, &new_syslinep(&resumed, dt(676205)));
+    assert_eq!(
+        calls[0].lines,
+        vec![
+            "[pid  1234] 1716854961.676098 read(3, \"abc\", 4096) = 3 <0.000107>",
+            " > /usr/lib/libc.so.6(__read+0x11) [0x10e1f1]",
+            " > /usr/lib/libc.so.6(__read+0x22) [0x10e202]",
+        ]
+    );
+}
+
+#[test]
+fn test_StraceGrouper_finish_path() {
+    let pathid: PathId = 1;
+    let mut grouper = StraceGrouper::new();
+    let data: String = format!("{}\n > /usr/lib/libc.so.6(__read+0x11) [0x10e1f1]\n", READ_UNFINISHED);
+    assert!(grouper.process(pathid, &new_syslinep(&data, dt(676098))).is_empty());
+    assert!(grouper.finish_path(2).is_empty());
+    let calls: Vec<StraceCall> = grouper.finish_path(pathid);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].to_string(), data);
+    assert_eq!(grouper.count_open(), 0);
+    assert!(grouper.finish_path(pathid).is_empty());
+}
+
+#[test_case(None, None, false, None, true; "no filter")]
+#[test_case(Some("open,access"), None, false, None, true; "syscall list")]
+#[test_case(Some("open"), None, false, None, false; "syscall no match")]
+#[test_case(None, Some("42"), false, None, true; "pid")]
+#[test_case(None, Some("1, 2"), false, None, false; "pid no match")]
+#[test_case(None, None, true, None, true; "failed")]
+#[test_case(None, None, false, Some("enoent"), true; "errno lower case")]
+#[test_case(None, None, false, Some("EACCES"), false; "errno no match")]
+#[test_case(Some("access"), Some("42"), true, Some("EACCES,ENOENT"), true; "all")]
+fn test_StraceFilter_matches(
+    syscalls: Option<&str>,
+    pids: Option<&str>,
+    failed: bool,
+    errnos: Option<&str>,
+    expect: bool,
+) {
+    let call = StraceCall::new(dt(676098), ACCESS, Some(42));
+    let filter = StraceFilter::new(syscalls, pids, failed, errnos).unwrap();
+    assert_eq!(filter.is_empty(), syscalls.is_none() && pids.is_none() && !failed && errnos.is_none());
+    assert_eq!(filter.matches(&call), expect);
+}
+
+#[test]
+fn test_StraceFilter_matches_ok() {
+    let call = StraceCall::new(dt(676100), CLOSE, None);
+    let filter = StraceFilter::new(None, None, true, None).unwrap();
+    assert!(!filter.matches(&call));
+    let filter = StraceFilter::new(Some("close"), Some("1235"), false, None).unwrap();
+    assert!(filter.matches(&call));
+}
+
+#[test]
+fn test_StraceFilter_new_err() {
+    assert!(StraceFilter::new(None, Some("12a"), false, None).is_err());
+}
@@ -236,3 +236,9 @@ fn test_sysline_get_slices() {
     let buf_str = String::from_utf8_lossy(&buffer);
     assert_eq!(DATA_STR0, buf_str);
 }
+
+#[test]
+fn test_sysline_to_bytes() {
+    let sysline: Sysline = new_sysline0();
+    assert_eq!(sysline.to_bytes(), DATA_STR0.as_bytes());
+}
@@ -420,7 +420,7 @@ fn helper_extract_dtpi_info(
 }
 
 /// test `SyslineReader::find_datetime_in_line`
-#[test_matrix(0..191)] // XXX: keep in sync with DATETIME_PARSE_DATAS_LEN_MAX + 1
+#[test_matrix(0..193)] // XXX: keep in sync with DATETIME_PARSE_DATAS_LEN_MAX + 1
 fn test_find_datetime_in_line(regex_id: RegexId) {
     let mut transcode_buffer: Bytes = Bytes::with_capacity(0);
     let mut regex_captures_attempted: Count = 0;
@@ -529,7 +529,7 @@ fn test_find_datetime_in_line(regex_id: RegexId) {
     }
 }
 
-#[test_matrix(0..191, (true, false))] // XXX: keep in sync with DATETIME_PARSE_DATAS_LEN_MAX + 1
+#[test_matrix(0..193, (true, false))] // XXX: keep in sync with DATETIME_PARSE_DATAS_LEN_MAX + 1
 /// test `SyslineReader.parse_datetime_in_line_cached`
 fn test_parse_datetime_in_line_cached(regex_id: RegexId, cache: bool) {
     let mut slr = new_SyslineReader(&*NTF_LOG_EMPTY_FPATH, 0x100, *FO_L);
@@ -41,7 +41,20 @@ use crate::data::datetime::{
     REGEX_ALL_COMPILED,
     regex_id_compiled,
 };
+use crate::data::containerlog::ContainerLogMode;
+use crate::data::jsonline::{
+    JsonLineTime,
+    JsonLinesMode,
+};
+use crate::data::keyvalue::{
+    KeyValueMode,
+    KeyValueTime,
+};
 use crate::data::sysline::SyslineP;
+use crate::data::timestampline::{
+    TimestampLineFormat,
+    TimestampLineMode,
+};
 use crate::debug::helpers::{
     create_temp_file,
     create_temp_file_data,
@@ -1313,3 +1326,210 @@ fn test_Reader_summary(
         expect_summarysyslogprocessor,
     );
 }
+
+// -------------------------------------------------------------------------------------------------
+
+const JSONLINES_DATA: &str = r#"{"time":"2024-01-02T03:04:05Z","msg":"one"}
+{"time":"2024-01-02T03:04:06Z","msg":"two"}
+{"time":"2024-01-02T03:04:07Z","msg":"three"}
+"#;
+
+const JSONLINES_ONE_DATA: &str = r#"2024-01-02 03:04:05 one
+2024-01-02 03:04:06 two {
+{"time":"2024-01-02T03:04:07Z","msg":"three"}
+}
+2024-01-02 03:04:08 four
+"#;
+
+const JSONLINES_FIRST_DATA: &str = r#"{"time":"2024-01-02T03:04:05Z","msg":"one"}
+2024-01-02 03:04:06 two
+2024-01-02 03:04:07 three
+2024-01-02 03:04:08 four
+"#;
+
+/// test `SyslogProcessor::blockzero_analysis_jsonline` only keeps the
+/// `JsonLineTime` of `JsonLinesMode::Auto` for a file of JSON lines
+#[test_case(JSONLINES_DATA, JsonLinesMode::Auto, true; "auto JSON lines")]
+#[test_case(JSONLINES_ONE_DATA, JsonLinesMode::Auto, false; "auto one JSON line")]
+#[test_case(JSONLINES_FIRST_DATA, JsonLinesMode::Auto, false; "auto first JSON line")]
+#[test_case(JSONLINES_ONE_DATA, JsonLinesMode::Always, true; "always one JSON line")]
+#[test_case(JSONLINES_DATA, JsonLinesMode::Never, false; "never JSON lines")]
+fn test_process_stage1_blockzero_analysis_jsonline(
+    data: &str,
+    mode: JsonLinesMode,
+    expect_jsonline_time: bool,
+) {
+    let ntf = create_temp_file(data);
+    let path = ntf_fpath(&ntf);
+    let mut slp = new_SyslogProcessor(&path, 0x400);
+    slp.set_jsonline_time(JsonLineTime::new(mode, None));
+
+    match slp.process_stage0_valid_file_check() {
+        FileProcessingResultBlockZero::FileOk => {}
+        result => {
+            panic!("Unexpected result stage0 {:?}; expected FileOk", result);
+        }
+    }
+    _ = slp.process_stage1_blockzero_analysis();
+
+    assert_eq!(
+        slp.jsonline_time().is_some(),
+        expect_jsonline_time,
+        "jsonline_time {:?}",
+        slp.jsonline_time(),
+    );
+}
+
+// -------------------------------------------------------------------------------------------------
+
+const KEYVALUE_DATA: &str = r#"time=2024-01-02T03:04:05Z level=info msg="one"
+time=2024-01-02T03:04:06Z level=info msg="two"
+time=2024-01-02T03:04:07Z level=warn msg="three"
+"#;
+
+const KEYVALUE_ONE_DATA: &str = r#"2024-01-02 03:04:05 one
+2024-01-02 03:04:06 two
+time=2024-01-02T03:04:07Z level=info msg="three"
+2024-01-02 03:04:08 four
+"#;
+
+const KEYVALUE_FIRST_DATA: &str = r#"time=2024-01-02T03:04:05Z level=info msg="one"
+2024-01-02 03:04:06 two
+2024-01-02 03:04:07 three
+2024-01-02 03:04:08 four
+"#;
+
+/// test `SyslogProcessor::blockzero_analysis_keyvalue` only keeps the
+/// `KeyValueTime` of `KeyValueMode::Auto` for a file of key-value lines
+#[test_case(KEYVALUE_DATA, KeyValueMode::Auto, true; "auto key-value lines")]
+#[test_case(KEYVALUE_ONE_DATA, KeyValueMode::Auto, false; "auto one key-value line")]
+#[test_case(KEYVALUE_FIRST_DATA, KeyValueMode::Auto, false; "auto first key-value line")]
+#[test_case(KEYVALUE_ONE_DATA, KeyValueMode::Always, true; "always one key-value line")]
+#[test_case(KEYVALUE_DATA, KeyValueMode::Never, false; "never key-value lines")]
+fn test_process_stage1_blockzero_analysis_keyvalue(
+    data: &str,
+    mode: KeyValueMode,
+    expect_keyvalue_time: bool,
+) {
+    let ntf = create_temp_file(data);
+    let path = ntf_fpath(&ntf);
+    let mut slp = new_SyslogProcessor(&path, 0x400);
+    slp.set_keyvalue_time(KeyValueTime::new(mode, None));
+
+    match slp.process_stage0_valid_file_check() {
+        FileProcessingResultBlockZero::FileOk => {}
+        result => {
+            panic!("Unexpected result stage0 {:?}; expected FileOk", result);
+        }
+    }
+    _ = slp.process_stage1_blockzero_analysis();
+
+    assert_eq!(
+        slp.keyvalue_time().is_some(),
+        expect_keyvalue_time,
+        "keyvalue_time {:?}",
+        slp.keyvalue_time(),
+    );
+}
+
+// -------------------------------------------------------------------------------------------------
+
+const CONTAINERLOG_DATA: &str = r#"2024-01-02T03:04:05.000000001Z stdout P one
+2024-01-02T03:04:05.000000002Z stderr F two
+2024-01-02T03:04:05.000000003Z stdout F three
+"#;
+
+const CONTAINERLOG_ONE_DATA: &str = r#"2024-01-02 03:04:05 one
+2024-01-02 03:04:06 two
+2024-01-02T03:04:07Z stdout F three
+2024-01-02 03:04:08 four
+"#;
+
+/// test `SyslogProcessor::blockzero_analysis_containerlog` only keeps
+/// `ContainerLogMode::Auto` for a file of container log lines
+#[test_case(CONTAINERLOG_DATA, ContainerLogMode::Auto, ContainerLogMode::Auto; "auto container log lines")]
+#[test_case(CONTAINERLOG_ONE_DATA, ContainerLogMode::Auto, ContainerLogMode::Never; "auto one container log line")]
+#[test_case(CONTAINERLOG_ONE_DATA, ContainerLogMode::Always, ContainerLogMode::Always; "always one container log line")]
+#[test_case(CONTAINERLOG_DATA, ContainerLogMode::Never, ContainerLogMode::Never; "never container log lines")]
+fn test_process_stage1_blockzero_analysis_containerlog(
+    data: &str,
+    mode: ContainerLogMode,
+    expect_mode: ContainerLogMode,
+) {
+    let ntf = create_temp_file(data);
+    let path = ntf_fpath(&ntf);
+    let mut slp = new_SyslogProcessor(&path, 0x400);
+    slp.set_containerlog_mode(mode);
+
+    match slp.process_stage0_valid_file_check() {
+        FileProcessingResultBlockZero::FileOk => {}
+        result => {
+            panic!("Unexpected result stage0 {:?}; expected FileOk", result);
+        }
+    }
+    _ = slp.process_stage1_blockzero_analysis();
+
+    assert_eq!(slp.containerlog_mode(), expect_mode);
+}
+
+// -------------------------------------------------------------------------------------------------
+
+const BASH_HISTORY_DATA: &str = "\
+#1716853121
+ls -l
+#1716853125
+cat /etc/hosts
+#1716853130
+echo '2024-01-02 03:04:05'
+";
+
+const ZSH_HISTORY_DATA: &str = "\
+: 1716853121:0;ls -l
+: 1716853125:0;cat /etc/hosts
+: 1716853130:2;sleep 2
+";
+
+const TIMESTAMPLINE_FIRST_DATA: &str = "\
+#1716853121
+2024-01-02 03:04:05 one
+2024-01-02 03:04:06 two
+2024-01-02 03:04:07 three
+2024-01-02 03:04:08 four
+2024-01-02 03:04:09 five
+";
+
+const TIMESTAMPLINE_NOT_FIRST_DATA: &str = "\
+2024-01-02 03:04:05 one
+#1716853121
+ls -l
+#1716853125
+cat /etc/hosts
+";
+
+/// test `SyslogProcessor::blockzero_analysis_timestampline` only finds the
+/// `TimestampLineFormat` of `TimestampLineMode::Auto` for bash or zsh history
+#[test_case(BASH_HISTORY_DATA, TimestampLineMode::Auto, Some(TimestampLineFormat::Epoch); "auto bash")]
+#[test_case(ZSH_HISTORY_DATA, TimestampLineMode::Auto, Some(TimestampLineFormat::Zsh); "auto zsh")]
+#[test_case(TIMESTAMPLINE_FIRST_DATA, TimestampLineMode::Auto, None; "auto first timestamp line")]
+#[test_case(TIMESTAMPLINE_NOT_FIRST_DATA, TimestampLineMode::Auto, None; "auto not first timestamp line")]
+#[test_case(BASH_HISTORY_DATA, TimestampLineMode::Never, None; "never bash")]
+fn test_process_stage1_blockzero_analysis_timestampline(
+    data: &str,
+    mode: TimestampLineMode,
+    expect_format: Option<TimestampLineFormat>,
+) {
+    let ntf = create_temp_file(data);
+    let path = ntf_fpath(&ntf);
+    let mut slp = new_SyslogProcessor(&path, 0x400);
+    slp.set_timestampline_mode(mode);
+
+    match slp.process_stage0_valid_file_check() {
+        FileProcessingResultBlockZero::FileOk => {}
+        result => {
+            panic!("Unexpected result stage0 {:?}; expected FileOk", result);
+        }
+    }
+    _ = slp.process_stage1_blockzero_analysis();
+
+    assert_eq!(slp.timestampline_format(), expect_format);
+}
@@ -0,0 +1,86 @@
+// src/tests/timestampline_tests.rs
+
+//! tests for `timestampline.rs`
+
+#![allow(non_snake_case)]
+
+use ::test_case::test_case;
+
+use crate::data::datetime::{
+    ymdhms,
+    DateTimeL,
+};
+use crate::data::timestampline::{
+    timestampline_parse,
+    TimestampLineFormat,
+};
+use crate::tests::common::{
+    FO_0,
+    FO_P8,
+};
+
+#[test_case("#1700000000\n", 1, 11; "bash")]
+#[test_case("# 1700000000\r\n", 2, 12; "bash space")]
+#[test_case("#1700000000", 1, 11; "bash no newline")]
+fn test_timestampline_parse_epoch(
+    line: &str,
+    beg: usize,
+    end: usize,
+) {
+    let (beg_, end_, dt, format) = timestampline_parse(line, None, &FO_0).unwrap();
+    assert_eq!((beg_, end_), (beg, end));
+    assert_eq!(&line[beg_..end_], "1700000000");
+    assert_eq!(dt, ymdhms(&FO_0, 2023, 11, 14, 22, 13, 20));
+    assert_eq!(format, TimestampLineFormat::Epoch);
+}
+
+#[test]
+fn test_timestampline_parse_zsh() {
+    let line = ": 1700000000:12;date -d 2024-01-02\n";
+    let (beg, end, dt, format) = timestampline_parse(line, None, &FO_0).unwrap();
+    assert_eq!(&line[beg..end], "1700000000");
+    assert_eq!(dt, ymdhms(&FO_0, 2023, 11, 14, 22, 13, 20));
+    assert_eq!(format, TimestampLineFormat::Zsh);
+    assert!(format.is_auto());
+}
+
+#[test_case("2024-01-02 03:04:05\n", "2024-01-02 03:04:05"; "datetime")]
+#[test_case("# 2024-01-02T03:04:05\n", "2024-01-02T03:04:05"; "comment datetime")]
+fn test_timestampline_parse_datetime(
+    line: &str,
+    value: &str,
+) {
+    let (beg, end, dt, format) = timestampline_parse(line, None, &FO_P8).unwrap();
+    assert_eq!(&line[beg..end], value);
+    assert_eq!(dt, ymdhms(&FO_P8, 2024, 1, 2, 3, 4, 5));
+    assert_eq!(format, TimestampLineFormat::DateTime);
+    assert!(!format.is_auto());
+}
+
+#[test_case("1700000000"; "epoch without comment")]
+#[test_case("#17000"; "short epoch")]
+#[test_case("#"; "comment only")]
+#[test_case("# a comment"; "comment")]
+#[test_case("date -d 2024-01-02"; "command")]
+#[test_case("Jan  2 03:04:05 host prog: message"; "syslog")]
+#[test_case(": 1700000000;cmd"; "zsh no elapsed")]
+#[test_case(": 1700000000:x;cmd"; "zsh bad elapsed")]
+#[test_case(""; "empty")]
+fn test_timestampline_parse_none(line: &str) {
+    assert_eq!(timestampline_parse(line, None, &FO_0), None);
+}
+
+#[test_case("#1700000000", TimestampLineFormat::Epoch, true)]
+#[test_case("#1700000000", TimestampLineFormat::Zsh, false)]
+#[test_case(": 1700000000:0;ls", TimestampLineFormat::Zsh, true)]
+#[test_case(": 1700000000:0;ls", TimestampLineFormat::Epoch, false)]
+#[test_case("2024-01-02 03:04:05", TimestampLineFormat::DateTime, true)]
+#[test_case("2024-01-02 03:04:05", TimestampLineFormat::Epoch, false)]
+fn test_timestampline_parse_format(
+    line: &str,
+    format: TimestampLineFormat,
+    found: bool,
+) {
+    let dt: Option<DateTimeL> = timestampline_parse(line, Some(format), &FO_0).map(|(_, _, dt, _)| dt);
+    assert_eq!(dt.is_some(), found);
+}
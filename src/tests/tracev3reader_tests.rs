@@ -0,0 +1,450 @@
+// src/tests/tracev3reader_tests.rs
+
+//! tests for [`src/readers/tracev3reader.rs`]
+//!
+//! [`src/readers/tracev3reader.rs`]: crate::readers::tracev3reader
+
+#![allow(non_snake_case)]
+
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+#[allow(unused_imports)]
+use ::si_trace_print::printers::{
+    defn,
+    defo,
+    defx,
+};
+use ::test_case::test_case;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileType,
+    FileTypeArchive,
+};
+use crate::data::datetime::{
+    DateTimeLOpt,
+    ymdhmsn,
+};
+use crate::debug::helpers::{
+    create_temp_dir,
+    TempDir,
+};
+use crate::readers::helpers::path_to_fpath;
+use crate::readers::pyeventreader::ResultNextPyDataEvent;
+use crate::readers::tracev3reader::{
+    chunkset_decompress,
+    find_archive_dirs,
+    format_message,
+    uuid_hex,
+    Tracev3Reader,
+    Tracev3Value,
+    Uuid,
+    CHUNKSET_END,
+    CHUNKSET_LZ4,
+    CHUNKSET_RAW,
+    CHUNK_TAG_CATALOG,
+    CHUNK_TAG_CHUNKSET,
+    CHUNK_TAG_FIREHOSE,
+    CHUNK_TAG_HEADER,
+    DSC_SIGNATURE,
+    TIMESYNC_BOOT_SIGNATURE,
+    TIMESYNC_RECORD_SIGNATURE,
+    UUIDTEXT_SIGNATURE,
+};
+use crate::tests::common::{
+    path_id_generator,
+    FO_0,
+    NTF_LOG_EMPTY_FPATH,
+};
+
+const FT_TRACEV3: FileType = FileType::Tracev3 { archival_type: FileTypeArchive::Normal };
+
+const BOOT_UUID: Uuid = [0x11; 16];
+const MAIN_UUID: Uuid = [0xAB; 16];
+const DSC_UUID: Uuid = [0xCD; 16];
+
+/// Unix epoch nanoseconds of the timesync record, 2023-11-14 22:15:00 UTC
+const TIMESYNC_WALLTIME: i64 = 1_700_000_100_000_000_000;
+/// mach continuous time of the timesync record
+const TIMESYNC_KERNEL_TIME: u64 = 1_000_000_000;
+/// base mach continuous time of the firehose chunk, one second after the
+/// timesync record
+const FIREHOSE_BASE_TIME: u64 = 2_000_000_000;
+
+/// log entries of the `.tracev3` file created by `create_logarchive`,
+/// sorted by time
+const SYNTHETIC_EVENTS: [&str; 3] = [
+    "2023-11-14 22:15:01.000250+0000 0x11 Error 0x0 123 0 testd: (libfoo.dylib) dsc 42\n",
+    "2023-11-14 22:15:01.000500+0000 0x10 Default 0x5 123 0 testd: [com.test:cat] hello world -7\n",
+    "2023-11-14 22:15:01.000750+0000 0x12 Info 0x0 123 0 testd: secret <private>\n",
+];
+
+#[test_case("%d items", vec![Tracev3Value::Number(3, 4)], "3 items"; "number")]
+#[test_case("%{public}s", vec![Tracev3Value::String(String::from("a"))], "a"; "public annotation")]
+#[test_case(
+    "%5d|%-3s|",
+    vec![Tracev3Value::Number(42, 4), Tracev3Value::String(String::from("x"))],
+    "   42|x  |";
+    "width"
+)]
+#[test_case(
+    "%{bool}d %{BOOL}d",
+    vec![Tracev3Value::Number(1, 4), Tracev3Value::Number(0, 4)],
+    "true NO";
+    "bool"
+)]
+#[test_case(
+    "%x %#x %08X",
+    vec![Tracev3Value::Number(255, 4), Tracev3Value::Number(255, 4), Tracev3Value::Number(255, 4)],
+    "ff 0xff 000000FF";
+    "hex"
+)]
+#[test_case(
+    "%.*s",
+    vec![Tracev3Value::Precision(2), Tracev3Value::String(String::from("abcdef"))],
+    "ab";
+    "precision star"
+)]
+#[test_case("%s %s", vec![Tracev3Value::String(String::from("a"))], "a <decode: missing data>"; "missing")]
+#[test_case("100%%", vec![], "100%"; "percent")]
+#[test_case("%{private}s", vec![Tracev3Value::Private], "<private>"; "private")]
+#[test_case("%s", vec![Tracev3Value::Null], "(null)"; "null")]
+#[test_case("%lld", vec![Tracev3Value::Number(-5i64 as u64, 8)], "-5"; "negative")]
+#[test_case("%d", vec![Tracev3Value::Number(0xffff_fffe, 4)], "-2"; "negative 32 bit")]
+#[test_case("%.2f", vec![Tracev3Value::Number(1.5f64.to_bits(), 8)], "1.50"; "float")]
+#[test_case(
+    "%{uuid_t}.16P",
+    vec![Tracev3Value::Bytes((0..16).collect())],
+    "00010203-0405-0607-0809-0A0B0C0D0E0F";
+    "uuid"
+)]
+fn test_format_message(
+    format: &str,
+    values: Vec<Tracev3Value>,
+    expect: &str,
+) {
+    assert_eq!(format_message(format, &values), expect);
+}
+
+#[test]
+fn test_chunkset_decompress() {
+    let first: &[u8] = b"raw block data ";
+    let second: &[u8] = b"lz4 block data lz4 block data lz4 block data";
+    let compressed: Vec<u8> = ::lz4_flex::block::compress(second);
+    let mut chunkset: Vec<u8> = CHUNKSET_RAW.to_vec();
+    chunkset.extend_from_slice(&(first.len() as u32).to_le_bytes());
+    chunkset.extend_from_slice(first);
+    chunkset.extend_from_slice(CHUNKSET_LZ4);
+    chunkset.extend_from_slice(&(second.len() as u32).to_le_bytes());
+    chunkset.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+    chunkset.extend_from_slice(&compressed);
+    chunkset.extend_from_slice(CHUNKSET_END);
+    let expect: Vec<u8> = [first, second].concat();
+    assert_eq!(chunkset_decompress(&chunkset).unwrap(), expect);
+}
+
+#[test]
+fn test_chunkset_decompress_bad_signature() {
+    assert!(chunkset_decompress(b"bv4?0000").is_err());
+}
+
+/// append a chunk with `tag` and `data` to `out`, padded to 8 bytes
+fn push_chunk(
+    out: &mut Vec<u8>,
+    tag: u32,
+    data: &[u8],
+) {
+    out.extend_from_slice(&tag.to_le_bytes());
+    out.extend_from_slice(&0u32.to_le_bytes());
+    out.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    out.extend_from_slice(data);
+    out.resize(out.len().next_multiple_of(8), 0);
+}
+
+/// a firehose log entry with `data`
+fn firehose_entry(
+    activity_type: u8,
+    log_type: u8,
+    flags: u16,
+    location: u32,
+    thread_id: u64,
+    delta: u32,
+    data: &[u8],
+) -> Vec<u8> {
+    let mut out: Vec<u8> = vec![activity_type, log_type];
+    out.extend_from_slice(&flags.to_le_bytes());
+    out.extend_from_slice(&location.to_le_bytes());
+    out.extend_from_slice(&thread_id.to_le_bytes());
+    out.extend_from_slice(&delta.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out.extend_from_slice(&(data.len() as u16).to_le_bytes());
+    out.extend_from_slice(data);
+    out.resize(out.len().next_multiple_of(8), 0);
+
+    out
+}
+
+/// the `.tracev3` file data with the log entries printed as
+/// `SYNTHETIC_EVENTS`
+fn tracev3_data(boot_uuid: &Uuid) -> Vec<u8> {
+    let mut out: Vec<u8> = Vec::new();
+
+    // header chunk; timebase 1/1
+    let mut header: Vec<u8> = vec![0; 144];
+    header[0..4].copy_from_slice(&1u32.to_le_bytes());
+    header[4..8].copy_from_slice(&1u32.to_le_bytes());
+    header[128..144].copy_from_slice(boot_uuid);
+    push_chunk(&mut out, CHUNK_TAG_HEADER, &header);
+
+    // catalog chunk with one process with one subsystem
+    let strings: &[u8] = b"com.test\0cat\0";
+    let mut catalog: Vec<u8> = Vec::new();
+    catalog.extend_from_slice(&32u16.to_le_bytes());
+    catalog.extend_from_slice(&((32 + strings.len()) as u16).to_le_bytes());
+    catalog.extend_from_slice(&1u16.to_le_bytes());
+    catalog.extend_from_slice(&[0; 18]);
+    catalog.extend_from_slice(&MAIN_UUID);
+    catalog.extend_from_slice(&DSC_UUID);
+    catalog.extend_from_slice(strings);
+    catalog.extend_from_slice(&[0; 4]);
+    catalog.extend_from_slice(&0u16.to_le_bytes());
+    catalog.extend_from_slice(&1u16.to_le_bytes());
+    catalog.extend_from_slice(&1u64.to_le_bytes());
+    catalog.extend_from_slice(&2u32.to_le_bytes());
+    catalog.extend_from_slice(&123u32.to_le_bytes());
+    catalog.extend_from_slice(&[0; 8]);
+    catalog.extend_from_slice(&[0; 8]);
+    catalog.extend_from_slice(&1u32.to_le_bytes());
+    catalog.extend_from_slice(&[0; 4]);
+    catalog.extend_from_slice(&1u16.to_le_bytes());
+    catalog.extend_from_slice(&0u16.to_le_bytes());
+    catalog.extend_from_slice(&9u16.to_le_bytes());
+    catalog.extend_from_slice(&[0; 2]);
+    push_chunk(&mut out, CHUNK_TAG_CATALOG, &catalog);
+
+    // firehose log entries, not in time order
+    let mut entries: Vec<u8> = Vec::new();
+    let mut data: Vec<u8> = Vec::new();
+    data.extend_from_slice(&5u32.to_le_bytes());
+    data.extend_from_slice(&0x8000_0000u32.to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&1u16.to_le_bytes());
+    data.extend_from_slice(&[0, 2, 0x20, 4, 0, 0, 6, 0, 0x00, 4]);
+    data.extend_from_slice(&(-7i32).to_le_bytes());
+    data.extend_from_slice(b"world\0");
+    entries.extend(firehose_entry(0x4, 0x0, 0x0203, 0x100, 0x10, 500_000, &data));
+    let mut data: Vec<u8> = Vec::new();
+    data.extend_from_slice(&0x100u32.to_le_bytes());
+    data.extend_from_slice(&[0, 1, 0x00, 4]);
+    data.extend_from_slice(&42u32.to_le_bytes());
+    entries.extend(firehose_entry(0x4, 0x10, 0x0004, 0x2000, 0x11, 250_000, &data));
+    let mut data: Vec<u8> = Vec::new();
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&[0, 1, 0x21, 4, 0, 0, 0, 0]);
+    entries.extend(firehose_entry(0x4, 0x1, 0x0002, 0x10c, 0x12, 750_000, &data));
+
+    let mut firehose: Vec<u8> = Vec::new();
+    firehose.extend_from_slice(&1u64.to_le_bytes());
+    firehose.extend_from_slice(&2u32.to_le_bytes());
+    firehose.extend_from_slice(&[0; 4]);
+    firehose.extend_from_slice(&((16 + entries.len()) as u16).to_le_bytes());
+    firehose.extend_from_slice(&0x1000u16.to_le_bytes());
+    firehose.extend_from_slice(&[0; 4]);
+    firehose.extend_from_slice(&FIREHOSE_BASE_TIME.to_le_bytes());
+    firehose.extend(entries);
+    let mut chunks: Vec<u8> = Vec::new();
+    push_chunk(&mut chunks, CHUNK_TAG_FIREHOSE, &firehose);
+
+    // chunkset chunk
+    let compressed: Vec<u8> = ::lz4_flex::block::compress(&chunks);
+    let mut chunkset: Vec<u8> = CHUNKSET_LZ4.to_vec();
+    chunkset.extend_from_slice(&(chunks.len() as u32).to_le_bytes());
+    chunkset.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+    chunkset.extend_from_slice(&compressed);
+    chunkset.extend_from_slice(CHUNKSET_END);
+    push_chunk(&mut out, CHUNK_TAG_CHUNKSET, &chunkset);
+
+    out
+}
+
+/// the `.timesync` file data for `BOOT_UUID`
+fn timesync_data() -> Vec<u8> {
+    let mut out: Vec<u8> = Vec::new();
+    out.extend_from_slice(&TIMESYNC_BOOT_SIGNATURE.to_le_bytes());
+    out.extend_from_slice(&48u16.to_le_bytes());
+    out.extend_from_slice(&[0; 4]);
+    out.extend_from_slice(&BOOT_UUID);
+    out.extend_from_slice(&1u32.to_le_bytes());
+    out.extend_from_slice(&1u32.to_le_bytes());
+    out.extend_from_slice(&(TIMESYNC_WALLTIME - 100_000_000_000).to_le_bytes());
+    out.extend_from_slice(&[0; 8]);
+    out.extend_from_slice(TIMESYNC_RECORD_SIGNATURE);
+    out.extend_from_slice(&[0; 4]);
+    out.extend_from_slice(&TIMESYNC_KERNEL_TIME.to_le_bytes());
+    out.extend_from_slice(&TIMESYNC_WALLTIME.to_le_bytes());
+    out.extend_from_slice(&[0; 8]);
+
+    out
+}
+
+/// the `uuidtext` file data of the main executable
+fn uuidtext_data() -> Vec<u8> {
+    let strings: &[u8] = b"hello %s %d\0secret %s\0";
+    let mut out: Vec<u8> = Vec::new();
+    out.extend_from_slice(&UUIDTEXT_SIGNATURE.to_le_bytes());
+    out.extend_from_slice(&2u32.to_le_bytes());
+    out.extend_from_slice(&1u32.to_le_bytes());
+    out.extend_from_slice(&1u32.to_le_bytes());
+    out.extend_from_slice(&0x100u32.to_le_bytes());
+    out.extend_from_slice(&(strings.len() as u32).to_le_bytes());
+    out.extend_from_slice(strings);
+    out.extend_from_slice(b"/usr/bin/testd\0");
+
+    out
+}
+
+/// the version 2 `dsc` file data of the shared cache
+fn dsc_data() -> Vec<u8> {
+    let mut out: Vec<u8> = DSC_SIGNATURE.to_vec();
+    out.extend_from_slice(&2u16.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out.extend_from_slice(&1u32.to_le_bytes());
+    out.extend_from_slice(&1u32.to_le_bytes());
+    // range
+    out.extend_from_slice(&0x2000u64.to_le_bytes());
+    out.extend_from_slice(&72u32.to_le_bytes());
+    out.extend_from_slice(&7u32.to_le_bytes());
+    out.extend_from_slice(&0u64.to_le_bytes());
+    // image
+    out.extend_from_slice(&0u64.to_le_bytes());
+    out.extend_from_slice(&0x10000u32.to_le_bytes());
+    out.extend_from_slice(&[0xEF; 16]);
+    out.extend_from_slice(&79u32.to_le_bytes());
+    assert_eq!(out.len(), 72);
+    out.extend_from_slice(b"dsc %u\0/usr/lib/libfoo.dylib\0");
+
+    out
+}
+
+/// create a log archive in `tempdir` with one `.tracev3` file with
+/// `boot_uuid`.
+/// If `diagnostics` then the layout is that of `/private/var/db`, where
+/// the `uuidtext` directory is a sibling of the `diagnostics` directory.
+/// Return the path of the `.tracev3` file.
+fn create_logarchive(
+    tempdir: &TempDir,
+    diagnostics: bool,
+    boot_uuid: &Uuid,
+) -> FPath {
+    let (root, uuidtext_dir): (PathBuf, PathBuf) = match diagnostics {
+        true => (tempdir.path().join("diagnostics"), tempdir.path().join("uuidtext")),
+        false => (tempdir.path().join("system_logs.logarchive"), tempdir.path().join("system_logs.logarchive")),
+    };
+    let write = |path: PathBuf, data: Vec<u8>| {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, data).unwrap();
+    };
+    write(root.join("timesync").join("0000000000000001.timesync"), timesync_data());
+    let main_hex: String = uuid_hex(&MAIN_UUID);
+    write(uuidtext_dir.join(&main_hex[..2]).join(&main_hex[2..]), uuidtext_data());
+    write(uuidtext_dir.join("dsc").join(uuid_hex(&DSC_UUID)), dsc_data());
+    let path: PathBuf = root.join("Persist").join("0000000000000001.tracev3");
+    write(path.clone(), tracev3_data(boot_uuid));
+
+    path_to_fpath(&path)
+}
+
+#[test_case(false; "logarchive")]
+#[test_case(true; "diagnostics")]
+fn test_find_archive_dirs(diagnostics: bool) {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_logarchive(&tempdir, diagnostics, &BOOT_UUID);
+    let (timesync_dir, uuidtext_dir) = find_archive_dirs(Path::new(&path)).unwrap();
+    let (expect_timesync_dir, expect_uuidtext_dir): (PathBuf, PathBuf) = match diagnostics {
+        true => (tempdir.path().join("diagnostics").join("timesync"), tempdir.path().join("uuidtext")),
+        false => (
+            tempdir.path().join("system_logs.logarchive").join("timesync"),
+            tempdir.path().join("system_logs.logarchive"),
+        ),
+    };
+    assert_eq!(timesync_dir, expect_timesync_dir);
+    assert_eq!(uuidtext_dir, expect_uuidtext_dir);
+}
+
+#[test]
+fn test_Tracev3Reader_new() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_logarchive(&tempdir, false, &BOOT_UUID);
+    let path_id = path_id_generator();
+    let tracev3reader = Tracev3Reader::new(path_id, path, FT_TRACEV3, FO_0).unwrap();
+    defo!("tracev3reader: {:?}", tracev3reader);
+    assert_eq!(tracev3reader.path_id(), path_id);
+    assert_eq!(tracev3reader.filetype(), FT_TRACEV3);
+    assert_eq!(tracev3reader.boot_uuid(), "11111111-1111-1111-1111-111111111111");
+}
+
+#[test]
+fn test_Tracev3Reader_new_not_tracev3() {
+    let result = Tracev3Reader::new(path_id_generator(), NTF_LOG_EMPTY_FPATH.clone(), FT_TRACEV3, FO_0);
+    assert!(result.is_err(), "expected Err, got {:?}", result);
+}
+
+#[test]
+fn test_Tracev3Reader_new_no_timesync_boot() {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_logarchive(&tempdir, false, &[0x22; 16]);
+    let result = Tracev3Reader::new(path_id_generator(), path, FT_TRACEV3, FO_0);
+    assert!(result.is_err(), "expected Err, got {:?}", result);
+}
+
+#[test_case(false; "logarchive")]
+#[test_case(true; "diagnostics")]
+fn test_Tracev3Reader_next(diagnostics: bool) {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_logarchive(&tempdir, diagnostics, &BOOT_UUID);
+    let mut tracev3reader = Tracev3Reader::new(path_id_generator(), path, FT_TRACEV3, FO_0).unwrap();
+    let mut events: Vec<String> = Vec::new();
+    loop {
+        match tracev3reader.next(&None, &None) {
+            ResultNextPyDataEvent::Found(event) => {
+                if events.is_empty() {
+                    assert_eq!(event.dt(), &ymdhmsn(&FO_0, 2023, 11, 14, 22, 15, 1, 250_000));
+                    assert_eq!(event.dt_beg_end(), &Some((0, 31)));
+                }
+                events.push(String::from_utf8_lossy(event.as_bytes()).into_owned());
+            }
+            ResultNextPyDataEvent::Done => break,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+    assert_eq!(events, SYNTHETIC_EVENTS);
+}
+
+#[test_case(None, None, 3; "no filters")]
+#[test_case(Some(ymdhmsn(&FO_0, 2023, 11, 14, 22, 15, 1, 400_000)), None, 2; "after")]
+#[test_case(None, Some(ymdhmsn(&FO_0, 2023, 11, 14, 22, 15, 1, 0)), 0; "before first entry")]
+fn test_Tracev3Reader_next_filters(
+    dt_filter_after: DateTimeLOpt,
+    dt_filter_before: DateTimeLOpt,
+    expect_accepted: Count,
+) {
+    let tempdir = create_temp_dir();
+    let path: FPath = create_logarchive(&tempdir, false, &BOOT_UUID);
+    let mut tracev3reader = Tracev3Reader::new(path_id_generator(), path, FT_TRACEV3, FO_0).unwrap();
+    let mut count: Count = 0;
+    loop {
+        match tracev3reader.next(&dt_filter_after, &dt_filter_before) {
+            ResultNextPyDataEvent::Found(_event) => count += 1,
+            ResultNextPyDataEvent::Done => break,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+    assert_eq!(count, expect_accepted);
+    let summary = tracev3reader.summary();
+    assert_eq!(summary.pyeventreader_events_processed, 3);
+    assert_eq!(summary.pyeventreader_events_accepted, expect_accepted);
+}
@@ -0,0 +1,255 @@
+// src/tests/utmpsession_tests.rs
+
+//! tests for `utmpsession.rs`
+
+#![allow(non_snake_case)]
+
+use ::chrono::Duration;
+use ::test_case::test_case;
+
+use crate::common::FPath;
+use crate::data::datetime::{
+    ymdhms,
+    DateTimeL,
+};
+use crate::data::fixedstruct::{
+    UtmpFields,
+    UtmpKind,
+};
+use crate::data::utmpsession::{
+    duration_to_string,
+    fpath_is_btmp,
+    FailedLogins,
+    UtmpSession,
+    UtmpSessionEnd,
+    UtmpSessionTracker,
+};
+use crate::tests::common::FO_0;
+
+fn fields(
+    kind: UtmpKind,
+    pid: i64,
+    line: &str,
+    id: &str,
+    user: &str,
+    host: &str,
+) -> UtmpFields {
+    UtmpFields {
+        kind,
+        pid,
+        line: String::from(line),
+        id: String::from(id),
+        user: String::from(user),
+        host: String::from(host),
+    }
+}
+
+fn login(pid: i64, line: &str, user: &str) -> UtmpFields {
+    fields(UtmpKind::UserProcess, pid, line, "", user, "10.0.0.1")
+}
+
+fn logout(pid: i64, line: &str, id: &str) -> UtmpFields {
+    fields(UtmpKind::DeadProcess, pid, line, id, "", "")
+}
+
+/// 2024-01-01 at `hour`:`min`
+fn dt(hour: u32, min: u32) -> DateTimeL {
+    ymdhms(&FO_0, 2024, 1, 1, hour, min, 0)
+}
+
+#[test_case(0, "00:00:00")]
+#[test_case(59, "00:00:59")]
+#[test_case(3661, "01:01:01")]
+#[test_case(86399, "23:59:59")]
+#[test_case(86400, "1+00:00:00")]
+#[test_case(2 * 86400 + 7200, "2+02:00:00")]
+#[test_case(-5, "00:00:00"; "negative")]
+fn test_duration_to_string(secs: i64, expect: &str) {
+    assert_eq!(duration_to_string(&Duration::seconds(secs)), expect);
+}
+
+#[test_case("/var/log/btmp", true)]
+#[test_case("/var/log/btmp.1", true)]
+#[test_case("/var/log/BTMP-20240101.gz", true)]
+#[test_case("/var/log/wtmp", false)]
+#[test_case("/var/log/btmp/wtmp", false)]
+#[test_case("/x/logs.tar\0var/log/btmp", true; "tar")]
+#[test_case("/x/btmp.tar\0var/log/wtmp", false; "tar wtmp")]
+fn test_fpath_is_btmp(path: &str, expect: bool) {
+    assert_eq!(fpath_is_btmp(&FPath::from(path)), expect);
+}
+
+/// a `DEAD_PROCESS` ends the session matched by `ut_pid` and `ut_line`,
+/// else `ut_line`, else `ut_id`
+#[test_case(logout(100, "pts/0", ""), Some("alice"); "pid line")]
+#[test_case(logout(999, "pts/1", ""), Some("bob"); "line")]
+#[test_case(logout(999, "", "ts/2"), Some("carol"); "id")]
+#[test_case(logout(999, "pts/9", "ts/9"), None; "none")]
+fn test_UtmpSessionTracker_logout(dead: UtmpFields, expect: Option<&str>) {
+    let mut tracker = UtmpSessionTracker::new();
+    assert!(tracker.process_fields(&login(100, "pts/0", "alice"), &dt(1, 0)).is_empty());
+    assert!(tracker.process_fields(&login(101, "pts/1", "bob"), &dt(2, 0)).is_empty());
+    let mut carol = login(102, "pts/2", "carol");
+    carol.id = String::from("ts/2");
+    assert!(tracker.process_fields(&carol, &dt(3, 0)).is_empty());
+    assert_eq!(tracker.count_open(), 3);
+
+    let ended: Vec<UtmpSession> = tracker.process_fields(&dead, &dt(4, 30));
+    match expect {
+        Some(user) => {
+            assert_eq!(ended.len(), 1, "{:?}", ended);
+            assert_eq!(ended[0].user, user);
+            assert_eq!(ended[0].end, UtmpSessionEnd::Logout);
+            assert_eq!(ended[0].logout, Some(dt(4, 30)));
+            assert_eq!(tracker.count_open(), 2);
+        }
+        None => {
+            assert!(ended.is_empty(), "{:?}", ended);
+            assert_eq!(tracker.count_open(), 3);
+        }
+    }
+}
+
+#[test]
+fn test_UtmpSessionTracker_boot_shutdown() {
+    let mut tracker = UtmpSessionTracker::new();
+    let boot = fields(UtmpKind::BootTime, 0, "~", "~~", "reboot", "5.10.0");
+    assert!(tracker.process_fields(&boot, &dt(0, 0)).is_empty());
+    assert!(tracker.process_fields(&login(100, "pts/0", "alice"), &dt(1, 0)).is_empty());
+    // a second login on the same line means the first was never logged out
+    let ended = tracker.process_fields(&login(200, "pts/0", "bob"), &dt(2, 0));
+    assert_eq!(ended.len(), 1);
+    assert_eq!(ended[0].user, "alice");
+    assert_eq!(ended[0].end, UtmpSessionEnd::Gone);
+
+    // a boot ends all sessions as a crash
+    let ended = tracker.process_fields(&boot, &dt(3, 0));
+    assert_eq!(ended.len(), 2);
+    assert!(ended[0].is_boot());
+    assert_eq!(ended[0].host, "5.10.0");
+    assert_eq!(ended[0].duration(), Some(Duration::hours(3)));
+    assert_eq!(ended[1].user, "bob");
+    assert!(ended.iter().all(|s| s.end == UtmpSessionEnd::Crash));
+
+    // a shutdown ends all sessions
+    assert!(tracker.process_fields(&login(300, "tty1", "carol"), &dt(4, 0)).is_empty());
+    let shutdown = fields(UtmpKind::Shutdown, 0, "~", "~~", "shutdown", "5.10.0");
+    let ended = tracker.process_fields(&shutdown, &dt(5, 0));
+    assert_eq!(ended.len(), 2);
+    assert!(ended.iter().all(|s| s.end == UtmpSessionEnd::Down));
+    assert_eq!(tracker.count_open(), 0);
+    assert!(tracker.finish().is_empty());
+}
+
+#[test]
+fn test_UtmpSessionTracker_finish() {
+    let mut tracker = UtmpSessionTracker::new();
+    let boot = fields(UtmpKind::BootTime, 0, "~", "~~", "reboot", "5.10.0");
+    tracker.process_fields(&boot, &dt(0, 0));
+    tracker.process_fields(&login(100, "pts/0", "alice"), &dt(1, 0));
+    // ignored entries
+    tracker.process_fields(&fields(UtmpKind::LoginProcess, 7, "tty1", "1", "LOGIN", ""), &dt(1, 1));
+    tracker.process_fields(&login(101, "pts/1", ""), &dt(1, 2));
+    let open: Vec<UtmpSession> = tracker.finish();
+    assert_eq!(open.len(), 2);
+    assert!(open[0].is_boot());
+    assert_eq!(open[1].user, "alice");
+    assert!(open.iter().all(|s| s.end == UtmpSessionEnd::StillLoggedIn && s.logout.is_none()));
+    assert_eq!(open[1].dt(), &dt(1, 0));
+    assert_eq!(tracker.count_open(), 0);
+    assert_eq!(
+        open[0].to_string(),
+        "reboot   system boot  5.10.0           2024-01-01 00:00:00 +00:00   still running"
+    );
+    assert_eq!(
+        open[1].to_string(),
+        "alice    pts/0        10.0.0.1         2024-01-01 01:00:00 +00:00   still logged in"
+    );
+}
+
+#[test]
+fn test_UtmpSessionTracker_set_still_open() {
+    let boot = fields(UtmpKind::BootTime, 0, "~", "~~", "reboot", "5.10.0");
+    let entries: [(UtmpFields, DateTimeL); 4] = [
+        (boot, dt(0, 0)),
+        (login(100, "pts/0", "alice"), dt(1, 0)),
+        (login(101, "pts/1", "bob"), dt(1, 5)),
+        (logout(101, "pts/1", ""), dt(1, 10)),
+    ];
+    let mut tracker = UtmpSessionTracker::new();
+    for (fields_, dt_) in entries.iter() {
+        tracker.process_fields(fields_, dt_);
+    }
+    let still_open: Vec<UtmpSession> = tracker.finish();
+    assert_eq!(still_open.len(), 2);
+
+    // the sessions that never end are returned at their login
+    let mut tracker = UtmpSessionTracker::new();
+    tracker.set_still_open(still_open.clone());
+    let returned: Vec<Vec<UtmpSession>> = entries
+        .iter()
+        .map(|(fields_, dt_)| tracker.process_fields(fields_, dt_))
+        .collect();
+    assert_eq!(returned[0], vec![still_open[0].clone()]);
+    assert_eq!(returned[1], vec![still_open[1].clone()]);
+    assert!(returned[2].is_empty());
+    assert_eq!(returned[3].len(), 1);
+    assert_eq!(returned[3][0].user, "bob");
+    assert_eq!(returned[3][0].end, UtmpSessionEnd::Logout);
+    // and not returned again
+    assert!(tracker.finish().is_empty());
+}
+
+#[test_case(UtmpSessionEnd::Logout, "")]
+#[test_case(UtmpSessionEnd::Gone, " gone - no logout")]
+#[test_case(UtmpSessionEnd::Crash, " crash")]
+#[test_case(UtmpSessionEnd::Down, " down")]
+fn test_UtmpSession_Display(end: UtmpSessionEnd, expect_end: &str) {
+    let mut tracker = UtmpSessionTracker::new();
+    tracker.process_fields(&login(100, "pts/0", "alice"), &dt(1, 0));
+    let mut session: UtmpSession = tracker.finish().remove(0);
+    session.logout = Some(ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5));
+    session.end = end;
+    assert_eq!(session.dt(), &ymdhms(&FO_0, 2024, 1, 2, 3, 4, 5));
+    assert_eq!(
+        session.to_string(),
+        format!(
+            "alice    pts/0        10.0.0.1         2024-01-01 01:00:00 +00:00 - 2024-01-02 03:04:05 +00:00 (1+02:04:05){}",
+            expect_end,
+        )
+    );
+}
+
+#[test]
+fn test_FailedLogins() {
+    let mut failed_logins = FailedLogins::new();
+    assert!(failed_logins.is_empty());
+    let root_ssh = fields(UtmpKind::LoginProcess, 1, "ssh:notty", "", "root", "203.0.113.5");
+    let admin_tty = fields(UtmpKind::LoginProcess, 2, "tty1", "1", "admin", "");
+    failed_logins.process_fields(&admin_tty, &dt(1, 0));
+    failed_logins.process_fields(&root_ssh, &dt(2, 0));
+    failed_logins.process_fields(&root_ssh, &dt(1, 30));
+    failed_logins.process_fields(&root_ssh, &dt(3, 0));
+    // ignored entry
+    failed_logins.process_fields(&fields(UtmpKind::BootTime, 0, "~", "~~", "reboot", ""), &dt(4, 0));
+    assert!(!failed_logins.is_empty());
+
+    let fls = failed_logins.failed_logins();
+    assert_eq!(fls.len(), 2);
+    assert_eq!(fls[0].user, "root");
+    assert_eq!(fls[0].from, "203.0.113.5");
+    assert_eq!(fls[0].count, 3);
+    assert_eq!(fls[0].first, dt(1, 30));
+    assert_eq!(fls[0].last, dt(3, 0));
+    assert_eq!(fls[1].from, "tty1");
+    assert_eq!(fls[1].count, 1);
+
+    let s: String = failed_logins.to_string();
+    let lines: Vec<&str> = s.lines().collect();
+    assert_eq!(lines.len(), 4, "{}", s);
+    assert_eq!(lines[0], "Failed logins:");
+    assert_eq!(
+        lines[2],
+        "       3 root             203.0.113.5              2024-01-01 01:30:00 +00:00 2024-01-01 03:00:00 +00:00"
+    );
+}
@@ -0,0 +1,173 @@
+// src/tests/utxlog_tests.rs
+
+//! tests for `utxlog.rs`
+
+#![allow(non_snake_case)]
+
+use std::io::Cursor;
+
+use ::test_case::test_case;
+
+use crate::common::{
+    Count,
+    FPath,
+    FileType,
+    FileTypeArchive,
+    FileTypeFixedStruct,
+};
+use crate::data::fixedstruct::{
+    freebsd_x8664,
+    FixedStructType,
+    UtmpFields,
+    UtmpKind,
+    ENTRY_SZ_MAX,
+};
+use crate::data::fixedstruct::freebsd_x8664::FUTX_SZ;
+use crate::readers::fixedstructreader::{
+    FixedStructReader,
+    ResultFindFixedStruct,
+    ResultFixedStructReaderNewError,
+};
+use crate::readers::utxlog::{
+    export_utxlog,
+    export_utxlog_records,
+    futx_to_utmpx,
+};
+use crate::tests::common::{
+    path_id_generator,
+    FO_0,
+};
+
+const UTXLOG_FREEBSD14_STR_PATH: &str = "./logs/FreeBSD14.0/x86_64/utx.log";
+const UTXLOG_FREEBSD14_COUNT: Count = 7;
+
+/// 2024-02-02 18:05:04.785635 UTC in microseconds
+const FU_TV: u64 = 1706897104785635;
+
+/// create a `futx` of type `fu_type`
+fn futx(
+    fu_type: u8,
+    fu_pid: u32,
+    fu_user: &str,
+    fu_line: &str,
+    fu_host: &str,
+) -> [u8; FUTX_SZ] {
+    let mut fu: [u8; FUTX_SZ] = [0; FUTX_SZ];
+    fu[0] = fu_type;
+    fu[1..9].copy_from_slice(&FU_TV.to_be_bytes());
+    fu[9..13].copy_from_slice(b"ab12");
+    fu[17..21].copy_from_slice(&fu_pid.to_be_bytes());
+    fu[21..21 + fu_user.len()].copy_from_slice(fu_user.as_bytes());
+    fu[53..53 + fu_line.len()].copy_from_slice(fu_line.as_bytes());
+    fu[69..69 + fu_host.len()].copy_from_slice(fu_host.as_bytes());
+
+    fu
+}
+
+/// a `utx.log` record of `fu` with the trailing zero bytes removed
+fn record(fu: &[u8; FUTX_SZ]) -> Vec<u8> {
+    let len: usize = fu.iter().rposition(|b| *b != 0).map_or(0, |at| at + 1);
+    let mut data: Vec<u8> = Vec::from((len as u16).to_be_bytes());
+    data.extend_from_slice(&fu[..len]);
+
+    data
+}
+
+#[test]
+fn test_futx_to_utmpx() {
+    let fu = futx(4, 850, "root", "pts/0", "host.net");
+    let buffer = futx_to_utmpx(&fu);
+    let utmpx: freebsd_x8664::utmpx = unsafe { std::ptr::read_unaligned(buffer.as_ptr() as *const _) };
+    assert_eq!(utmpx.ut_type, 4);
+    assert_eq!(utmpx.ut_tv.tv_sec, 1706897104);
+    assert_eq!(utmpx.ut_tv.tv_usec, 785635);
+    assert_eq!(utmpx.ut_pid, 850);
+    assert_eq!(utmpx.ut_id().to_str().unwrap(), "ab12");
+    assert_eq!(utmpx.ut_user().to_str().unwrap(), "root");
+    assert_eq!(utmpx.ut_line().to_str().unwrap(), "pts/0");
+    assert_eq!(utmpx.ut_host().to_str().unwrap(), "host.net");
+}
+
+#[test_case(&[], 0; "empty")]
+#[test_case(&[0], 0; "one byte")]
+#[test_case(&[0, 0, 0, 0], 0; "zeros")]
+#[test_case(&[0, 9, 1], 0; "truncated")]
+fn test_export_utxlog_records_data(
+    data: &[u8],
+    expect_count: Count,
+) {
+    let mut writer: Vec<u8> = Vec::new();
+    let count = export_utxlog_records(&mut Cursor::new(data), &mut writer).unwrap();
+    assert_eq!(count, expect_count);
+    assert_eq!(writer.len(), expect_count as usize * freebsd_x8664::UTMPX_SZ);
+}
+
+#[test]
+fn test_export_utxlog_records() {
+    let mut data: Vec<u8> = Vec::new();
+    // BOOT_TIME
+    data.extend(record(&futx(1, 0, "", "", "")));
+    // zero length padding is skipped
+    data.extend([0, 0, 0]);
+    // USER_PROCESS
+    data.extend(record(&futx(4, 850, "root", "ttyv0", "")));
+    // EMPTY is skipped
+    data.extend(record(&futx(0, 1, "", "", "")));
+    // unknown type is skipped
+    data.extend(record(&futx(99, 1, "", "", "")));
+    // a longer record from a future `futx` is truncated
+    let fu = futx(7, 850, "", "ttyv0", "");
+    data.extend(((FUTX_SZ + 3) as u16).to_be_bytes());
+    data.extend(fu);
+    data.extend([0xFF, 0xFF, 0xFF]);
+    // SHUTDOWN_TIME
+    data.extend(record(&futx(8, 0, "", "", "")));
+
+    let mut writer: Vec<u8> = Vec::new();
+    let count = export_utxlog_records(&mut Cursor::new(data), &mut writer).unwrap();
+    assert_eq!(count, 4);
+    assert_eq!(writer.len(), 4 * freebsd_x8664::UTMPX_SZ);
+    let ut_types: Vec<u8> = writer
+        .chunks(freebsd_x8664::UTMPX_SZ)
+        .map(|buffer| buffer[0])
+        .collect();
+    assert_eq!(ut_types, vec![1, 4, 7, 8]);
+}
+
+#[test]
+fn test_export_utxlog() {
+    let path: FPath = FPath::from(UTXLOG_FREEBSD14_STR_PATH);
+    let (temp_path, count) = export_utxlog(&path).unwrap();
+    assert_eq!(count, UTXLOG_FREEBSD14_COUNT);
+    let len = std::fs::metadata(&temp_path).unwrap().len();
+    assert_eq!(len, count * freebsd_x8664::UTMPX_SZ as u64);
+}
+
+#[test]
+fn test_FixedStructReader_utxlog() {
+    let path: FPath = FPath::from(UTXLOG_FREEBSD14_STR_PATH);
+    let filetype = FileType::FixedStruct {
+        archival_type: FileTypeArchive::Normal,
+        fixedstruct_type: FileTypeFixedStruct::UtxLog,
+    };
+    let mut fsr = match FixedStructReader::new(path_id_generator(), path.clone(), filetype, 0x200, FO_0, None, None) {
+        ResultFixedStructReaderNewError::FileOk(val) => val,
+        result => panic!("FixedStructReader::new({:?}) failed: {:?}", path, result),
+    };
+    assert_eq!(fsr.path(), &path);
+    assert_eq!(fsr.fixedstruct_type(), FixedStructType::Fs_Freebsd_x8664_Utmpx);
+
+    let mut buffer: [u8; ENTRY_SZ_MAX] = [0; ENTRY_SZ_MAX];
+    let mut kinds: Vec<UtmpKind> = Vec::new();
+    let mut fo = 0;
+    while let ResultFindFixedStruct::Found((fo_next, fs)) = fsr.process_entry_at(fo, &mut buffer) {
+        let fields: UtmpFields = fs.utmp_fields().unwrap();
+        kinds.push(fields.kind);
+        fo = fo_next;
+    }
+    assert_eq!(kinds.len() as Count, UTXLOG_FREEBSD14_COUNT);
+    assert_eq!(kinds[0], UtmpKind::BootTime);
+    assert_eq!(kinds[1], UtmpKind::UserProcess);
+    assert_eq!(kinds[4], UtmpKind::DeadProcess);
+    assert_eq!(kinds[5], UtmpKind::Shutdown);
+}
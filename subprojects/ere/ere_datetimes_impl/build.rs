@@ -26,7 +26,7 @@ const REGEX_ALL: &str = "ALL";
 const REGEX_TEST: &str = "TEST";
 const CONFIG_REGEX: &str = "regex";
 /// This must match `datetime.rs` value `DATETIME_PARSE_DATAS_LEN_MAX`
-pub const DATETIME_PARSE_DATAS_LEN: usize = 190;
+pub const DATETIME_PARSE_DATAS_LEN: usize = 192;
 
 fn is_env_var_truthy(env_var: &str) -> bool {
     match std::env::var(env_var) {
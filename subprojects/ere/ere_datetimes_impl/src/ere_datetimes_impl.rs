@@ -6476,6 +6476,48 @@ pub const DATETIME_PARSE_DATAS: [DateTimeParseInstr; DATETIME_PARSE_DATAS_LEN] =
         ],
         line!(),
     ),
+    // ---------------------------------------------------------------------------------------------
+    //
+    // strace `-f` formats, prefixed with the PID
+    //
+    //                1         2         3
+    //      0123456789012345678901234567890
+    //      $ strace -f -ttt ls 2> FILE
+    //      [pid  1235] 1716853121.780157 read(3,  <unfinished ...>
+    //      $ strace -f -ttt -o FILE ls
+    //      1235  1716853121.780157 read(3,  <unfinished ...>
+    //
+    #[cfg(any(regex = "190", regex = "ALL"))]
+    ERE_REGEX_DATETIME!(
+        190,
+        counter!(DP_KEY),
+        concat!(r"^\[pid", RP_BLANKS, r"[[:digit:]]{1,10}\]", RP_BLANK, CGP_EPOCH, "[.,]", CGP_FRACTIONAL369, RP_BLANK, RP_BLANK_NO),
+        DfaU8,
+        DTFSS_sf,
+        0, 45,
+        CGN_EPOCH, CGN_FRACTIONAL,
+        &[
+            (12, 29, (O_L, 2024, 5, 27, 23, 38, 41, 780157000), br#"[pid  1235] 1716853121.780157 read(3,  <unfinished ...>"#),
+            (16, 30, (O_L, 2024, 5, 27, 23, 38, 41, 780000000), br#"[pid 123456789] 1716853121.780 <... read resumed>"abc", 4096) = 3"#),
+            (11, 31, (O_L, 2024, 5, 27, 23, 38, 41, 780157012), br#"[pid 1235] 1716853121.780157012 close(3) = 0"#),
+        ],
+        line!(),
+    ),
+    #[cfg(any(regex = "191", regex = "ALL"))]
+    ERE_REGEX_DATETIME!(
+        191,
+        counter!(DP_KEY),
+        concat!("^", r"[[:digit:]]{1,10}", RP_BLANKS, CGP_EPOCH, "[.,]", CGP_FRACTIONAL369, RP_BLANK, RP_BLANK_NO),
+        DfaU8,
+        DTFSS_sf,
+        0, 45,
+        CGN_EPOCH, CGN_FRACTIONAL,
+        &[
+            (6, 23, (O_L, 2024, 5, 27, 23, 38, 41, 780157000), br#"1235  1716853121.780157 read(3,  <unfinished ...>"#),
+            (7, 21, (O_L, 2024, 5, 27, 23, 38, 41, 780000000), br#"123456 1716853121.780 exit_group(0) = ?"#),
+        ],
+        line!(),
+    ),
 ];
 
 /// proc-macro generated count of compiled regex.
@@ -6484,7 +6526,7 @@ pub const DATETIME_PARSE_DATAS: [DateTimeParseInstr; DATETIME_PARSE_DATAS_LEN] =
 pub const DATETIME_PARSE_DATAS_LEN: usize = counter_last!(DP_KEY);
 
 /// the maximum possible length of `DATETIME_PARSE_DATAS`, i.e. last regex_id + 1.
-pub const DATETIME_PARSE_DATAS_LEN_MAX: usize = 190;
+pub const DATETIME_PARSE_DATAS_LEN_MAX: usize = 192;
 
 /// Check if the `regex_id` is in the compiled `DATETIME_PARSE_DATAS`.
 /// `DATETIME_PARSE_DATAS` may vary depending upon build cfg of env var `REGEX`.